@@ -0,0 +1,193 @@
+//! Measures the time and the allocations of the grid layout and of the parsed input events.
+//!
+//! Run with `cargo bench --bench layout --features test-utils`.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    num::ParseIntError,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+use iced::{
+    Element, Length, Point, Rectangle, Size, Theme,
+    advanced::{Shell, clipboard, layout::Limits, mouse, widget::Tree},
+    keyboard::{self, key},
+    widget::Space,
+};
+use more_iced_aw::{
+    grid::Grid,
+    harness::Headless,
+    parsed_input::{Content, Parsed, ParsedInput},
+};
+
+/// An allocator counting the allocations.
+struct Counting;
+
+/// The number of allocations since the start.
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// Runs `f` repeatedly for about a second, and prints its mean duration and allocations.
+fn bench(name: &str, mut f: impl FnMut()) {
+    // Warm up, letting the scratch buffers grow.
+    for _ in 0..10 {
+        f();
+    }
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    let mut iterations = 0u32;
+
+    while start.elapsed() < Duration::from_secs(1) {
+        f();
+        iterations += 1;
+    }
+
+    let elapsed = start.elapsed() / iterations;
+    let allocations =
+        (ALLOCATIONS.load(Ordering::Relaxed) - allocations) as f64 / iterations as f64;
+
+    println!("{name:<32} {elapsed:>12?} {allocations:>10.1} allocations");
+}
+
+/// A grid of 100 rows of 10 cells, some of them filling their column.
+fn grid<'a>() -> Element<'a, (), Theme, Headless> {
+    Grid::with_rows((0..100).map(|i| {
+        (0..10).map(move |j| {
+            if (i + j) % 7 == 0 {
+                Space::new(Length::Fill, 12)
+            } else {
+                Space::new((i * j % 40) as f32, 12)
+            }
+        })
+    }))
+    .width(Length::Fill)
+    .column_spacing(4)
+    .row_spacing(2)
+    .into()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Input(#[allow(dead_code)] Parsed<u32, ParseIntError>),
+}
+
+fn main() {
+    let limits = Limits::new(Size::ZERO, Size::new(1920., 1080.));
+
+    let grid = grid();
+    let mut tree = Tree::new(&grid);
+    bench("grid 100x10 layout", || {
+        black_box(grid.as_widget().layout(&mut tree, &Headless, &limits));
+    });
+
+    let content = Content::<u32, ParseIntError>::new(42);
+    let mut input: Element<'_, Message, Theme, Headless> = ParsedInput::new("Number", &content)
+        .on_input(Message::Input)
+        .into();
+    let mut tree = Tree::new(&input);
+    let node = input.as_widget().layout(&mut tree, &Headless, &limits);
+    let layout = iced::advanced::Layout::new(&node);
+    let viewport = Rectangle::with_size(limits.max());
+    let mut messages = Vec::new();
+
+    let events = [
+        iced::Event::Mouse(mouse::Event::CursorMoved {
+            position: Point::new(10., 10.),
+        }),
+        iced::Event::Keyboard(keyboard::Event::ModifiersChanged(
+            keyboard::Modifiers::SHIFT,
+        )),
+        iced::Event::Keyboard(keyboard::Event::KeyReleased {
+            key: keyboard::Key::Named(key::Named::Shift),
+            location: keyboard::Location::Left,
+            modifiers: keyboard::Modifiers::empty(),
+        }),
+    ];
+
+    bench("parsed input events", || {
+        for event in &events {
+            let mut shell = Shell::new(&mut messages);
+            black_box(input.as_widget_mut().on_event(
+                &mut tree,
+                event.clone(),
+                layout,
+                mouse::Cursor::Available(Point::new(10., 10.)),
+                &Headless,
+                &mut clipboard::Null,
+                &mut shell,
+                &viewport,
+            ));
+        }
+        messages.clear();
+    });
+
+    let mut shell = Shell::new(&mut messages);
+    input.as_widget_mut().on_event(
+        &mut tree,
+        iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+        layout,
+        mouse::Cursor::Available(Point::new(10., 10.)),
+        &Headless,
+        &mut clipboard::Null,
+        &mut shell,
+        &viewport,
+    );
+
+    // Typing then erasing a digit, so that the value of the input stays the same.
+    let typing = [
+        iced::Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Character("1".into()),
+            modified_key: keyboard::Key::Character("1".into()),
+            physical_key: key::Physical::Unidentified(key::NativeCode::Unidentified),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::empty(),
+            text: Some("1".into()),
+        }),
+        iced::Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key::Named::Backspace),
+            modified_key: keyboard::Key::Named(key::Named::Backspace),
+            physical_key: key::Physical::Unidentified(key::NativeCode::Unidentified),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::empty(),
+            text: None,
+        }),
+    ];
+
+    bench("parsed input typing", || {
+        for event in &typing {
+            let mut shell = Shell::new(&mut messages);
+            black_box(input.as_widget_mut().on_event(
+                &mut tree,
+                event.clone(),
+                layout,
+                mouse::Cursor::Available(Point::new(10., 10.)),
+                &Headless,
+                &mut clipboard::Null,
+                &mut shell,
+                &viewport,
+            ));
+        }
+        messages.clear();
+    });
+}
@@ -1,14 +1,10 @@
-use std::{
-    fmt::Display,
-    num::{ParseFloatError, ParseIntError},
-    ops::Deref,
-};
+use std::num::{ParseFloatError, ParseIntError};
 
 use iced::{
     Element,
-    Length::{self, *},
+    Length::*,
     alignment::{
-        Horizontal::{self, Left},
+        Horizontal::Left,
         Vertical::{self, Top},
     },
     color,
@@ -19,6 +15,7 @@ use iced::{
 };
 use more_iced_aw::{
     grid,
+    helpers::{DispHorizontal, DispLength, DispVertical, labeled},
     parsed_input::{self, ParsedInput},
 };
 
@@ -233,57 +230,50 @@ impl App {
         let side_panel = column![
             Element::from(self.cell.width_line()).map(Message::Cell),
             Element::from(self.cell.height_line()).map(Message::Cell),
-            row![
+            labeled(
                 "Padding",
                 parsed_input::ParsedInput::new("Padding", &self.padding)
                     .on_input(Message::Padding)
-                    .style(parsed_input::danger_on_err(text_input::default)),
-            ]
-            .spacing(10),
-            row![
+                    .style(parsed_input::danger_on_err(text_input::default))
+            ),
+            labeled(
                 "Align x",
                 combo_box::ComboBox::new(
                     &self.horiz_state,
                     "",
                     Some(&self.align_x),
                     Message::AlignX
-                ),
-            ]
-            .spacing(10),
-            row![
+                )
+            ),
+            labeled(
                 "Align y",
                 combo_box::ComboBox::new(
                     &self.verti_state,
                     "",
                     Some(&self.align_y),
                     Message::AlignY
-                ),
-            ]
-            .spacing(10),
-            row![
+                )
+            ),
+            labeled(
                 "Column spacing",
                 parsed_input::ParsedInput::new("Column spacing", &self.column_spacing)
                     .on_input(Message::ColumnSpacing)
-                    .style(parsed_input::danger_on_err(text_input::default)),
-            ]
-            .spacing(10),
-            row![
+                    .style(parsed_input::danger_on_err(text_input::default))
+            ),
+            labeled(
                 "Row spacing",
                 parsed_input::ParsedInput::new("Row spacing", &self.row_spacing)
                     .on_input(Message::RowSpacing)
-                    .style(parsed_input::danger_on_err(text_input::default)),
-            ]
-            .spacing(10),
-            row![
+                    .style(parsed_input::danger_on_err(text_input::default))
+            ),
+            labeled(
                 "Main axis",
-                combo_box::ComboBox::new(&self.axis_state, "", Some(&self.axis), Message::Axis),
-            ]
-            .spacing(10),
-            row![
+                combo_box::ComboBox::new(&self.axis_state, "", Some(&self.axis), Message::Axis)
+            ),
+            labeled(
                 "Explain",
                 checkbox("", self.explain).on_toggle(Message::Explain)
-            ]
-            .spacing(10),
+            ),
         ]
         .spacing(10)
         .width(300)
@@ -304,7 +294,7 @@ impl Default for Cell {
             width_int_parsed: parsed_input::Content::new(1),
             height_int_parsed: parsed_input::Content::new(1),
 
-            length_state: combo_box::State::new(LENGTH_OPTIONS.to_vec()),
+            length_state: combo_box::State::new(DispLength::ALL.to_vec()),
         }
     }
 }
@@ -324,152 +314,11 @@ impl Default for App {
             column_spacing: Default::default(),
             row_spacing: Default::default(),
             axis: grid::Axis::Horizontal,
-            horiz_state: combo_box::State::new(vec![
-                Horizontal::Left.into(),
-                Horizontal::Center.into(),
-                Horizontal::Right.into(),
-            ]),
-            verti_state: combo_box::State::new(
-                vec![
-                    Vertical::Top.into(),
-                    Vertical::Center.into(),
-                    Vertical::Bottom.into(),
-                ]
-                .into(),
-            ),
+            horiz_state: combo_box::State::new(DispHorizontal::ALL.to_vec()),
+            verti_state: combo_box::State::new(DispVertical::ALL.to_vec()),
             axis_state: combo_box::State::new(vec![grid::Axis::Horizontal, grid::Axis::Vertical]),
 
             explain: true,
         }
     }
 }
-
-/// A [`Length`] that can be displayed.
-#[derive(Debug, Clone, Copy, PartialEq)]
-struct DispLength {
-    length: Length,
-}
-
-impl Display for DispLength {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self.length {
-                Length::Fill => "Fill",
-                Length::FillPortion(_) => "Fill Portion",
-                Shrink => "Shrink",
-                Length::Fixed(_) => "Fixed",
-            }
-        )
-    }
-}
-
-impl From<Length> for DispLength {
-    fn from(value: Length) -> Self {
-        Self { length: value }
-    }
-}
-
-impl From<DispLength> for Length {
-    fn from(value: DispLength) -> Self {
-        value.length
-    }
-}
-
-impl Deref for DispLength {
-    type Target = Length;
-
-    fn deref(&self) -> &Self::Target {
-        &self.length
-    }
-}
-
-const LENGTH_OPTIONS: [DispLength; 4] = [
-    DispLength { length: Shrink },
-    DispLength {
-        length: Fixed(1f32),
-    },
-    DispLength {
-        length: FillPortion(1),
-    },
-    DispLength { length: Fill },
-];
-
-#[derive(Debug, Clone, PartialEq, Eq, Copy)]
-struct DispHorizontal {
-    v: Horizontal,
-}
-
-impl From<Horizontal> for DispHorizontal {
-    fn from(value: Horizontal) -> Self {
-        Self { v: value }
-    }
-}
-
-impl From<DispHorizontal> for Horizontal {
-    fn from(value: DispHorizontal) -> Self {
-        value.v
-    }
-}
-
-impl Deref for DispHorizontal {
-    type Target = Horizontal;
-
-    fn deref(&self) -> &Self::Target {
-        &self.v
-    }
-}
-
-impl Display for DispHorizontal {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self.deref() {
-                Left => "Left",
-                Horizontal::Center => "Center",
-                Horizontal::Right => "Right",
-            }
-        )
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Copy)]
-struct DispVertical {
-    v: Vertical,
-}
-
-impl From<Vertical> for DispVertical {
-    fn from(value: Vertical) -> Self {
-        Self { v: value }
-    }
-}
-
-impl From<DispVertical> for Vertical {
-    fn from(value: DispVertical) -> Self {
-        value.v
-    }
-}
-
-impl Deref for DispVertical {
-    type Target = Vertical;
-
-    fn deref(&self) -> &Self::Target {
-        &self.v
-    }
-}
-
-impl Display for DispVertical {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self.deref() {
-                Top => "Top",
-                Vertical::Center => "Center",
-                Vertical::Bottom => "Bottom",
-            }
-        )
-    }
-}
@@ -0,0 +1,406 @@
+//! Animations of values over time, shared by the animated widgets of the crate.
+//!
+//! Animations advance when they are ticked with the time of a frame: widgets tick them on
+//! `window::Event::RedrawRequested` and request the next frame while they are moving, and
+//! applications can tick them with the instants of the `window::frames` subscription.
+//!
+//! * A [`Timeline`] goes back and forth between two states in a fixed duration, with an [`Easing`].
+//! * A [`Spring`] follows a target with a physical motion, and can be thrown with a velocity.
+//! * [`approach`] smoothly follows a moving target, frame after frame.
+//! * A [`Clock`] measures the time between frames, for continuous animations.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use iced::{Element, Subscription, time::Instant, widget::{button, column, progress_bar}, window};
+//! use more_iced_aw::animation::{Easing, Timeline};
+//!
+//! struct App {
+//!     expanded: Timeline,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Toggle,
+//!     Frame(Instant),
+//! }
+//!
+//! impl App {
+//!     fn update(&mut self, message: Message) {
+//!         match message {
+//!             Message::Toggle => self.expanded.go(!self.expanded.is_forward()),
+//!             Message::Frame(now) => {
+//!                 self.expanded.tick(now);
+//!             }
+//!         }
+//!     }
+//!
+//!     fn view(&self) -> Element<'_, Message> {
+//!         column![
+//!             button("Toggle").on_press(Message::Toggle),
+//!             progress_bar(0.0..=1.0, self.expanded.value()),
+//!         ]
+//!         .into()
+//!     }
+//!
+//!     fn subscription(&self) -> Subscription<Message> {
+//!         if self.expanded.is_animating() {
+//!             window::frames().map(Message::Frame)
+//!         } else {
+//!             Subscription::none()
+//!         }
+//!     }
+//! }
+//!
+//! fn main() -> iced::Result {
+//!     iced::application("Animation", App::update, App::view)
+//!         .subscription(App::subscription)
+//!         .run_with(|| {
+//!             let expanded = Timeline::new(false, Duration::from_millis(300))
+//!                 .easing(Easing::EaseInOut);
+//!             (App { expanded }, iced::Task::none())
+//!         })
+//! }
+//! ```
+
+use std::time::Duration;
+
+use iced::time::Instant;
+
+/// The longest time step of a [`Spring`], to keep its motion stable when frames are late.
+const MAX_SPRING_STEP: f32 = 0.05;
+
+/// A curve applied to the progress of a [`Timeline`].
+///
+/// ```
+/// use more_iced_aw::animation::Easing;
+///
+/// assert_eq!(Easing::Linear.apply(0.25), 0.25);
+/// assert_eq!(Easing::EaseOut.apply(0.5), 0.875);
+/// assert_eq!(Easing::EaseInOut.apply(1.), 1.);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Easing {
+    /// A constant speed.
+    Linear,
+    /// Starts slowly and ends fast (cubic).
+    EaseIn,
+    /// Starts fast and ends slowly (cubic).
+    #[default]
+    EaseOut,
+    /// Starts and ends slowly (cubic).
+    EaseInOut,
+}
+
+impl Easing {
+    /// Applies the [`Easing`] to a progress between 0 and 1.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0., 1.);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t.powi(3),
+            Easing::EaseOut => 1. - (1. - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4. * t.powi(3)
+                } else {
+                    1. - (2. - 2. * t).powi(3) / 2.
+                }
+            }
+        }
+    }
+}
+
+/// Measures the time between the frames of an animation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Clock {
+    last_frame: Option<Instant>,
+}
+
+impl Clock {
+    /// Creates a new stopped [`Clock`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the seconds elapsed since the last tick, or 0 if the [`Clock`] was stopped.
+    pub fn tick(&mut self, now: Instant) -> f32 {
+        let elapsed = self
+            .last_frame
+            .map_or(0., |last| now.saturating_duration_since(last).as_secs_f32());
+        self.last_frame = Some(now);
+        elapsed
+    }
+
+    /// Stops the [`Clock`], so that its next tick starts the time again.
+    pub fn stop(&mut self) {
+        self.last_frame = None;
+    }
+
+    /// Indicates if the [`Clock`] was ticked since it was last stopped.
+    pub fn is_running(&self) -> bool {
+        self.last_frame.is_some()
+    }
+}
+
+/// A transition between a backward and a forward state, lasting a fixed duration.
+///
+/// Its progress goes from 0 (backward) to 1 (forward), and its [value](Timeline::value) is the
+/// progress with the [`Easing`] applied.
+///
+/// ```
+/// use std::time::{Duration, Instant};
+/// use more_iced_aw::animation::{Easing, Timeline};
+///
+/// let mut timeline = Timeline::new(false, Duration::from_secs(1)).easing(Easing::Linear);
+/// timeline.go(true);
+///
+/// let start = Instant::now();
+/// assert!(timeline.tick(start));
+/// assert!(timeline.tick(start + Duration::from_millis(500)));
+/// assert_eq!(timeline.value(), 0.5);
+///
+/// assert!(!timeline.tick(start + Duration::from_secs(2)));
+/// assert_eq!(timeline.value(), 1.);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timeline {
+    progress: f32,
+    forward: bool,
+    duration: Duration,
+    easing: Easing,
+    clock: Clock,
+}
+
+impl Timeline {
+    /// Creates a new [`Timeline`] resting in the forward or backward state.
+    pub fn new(forward: bool, duration: Duration) -> Self {
+        Self {
+            progress: if forward { 1. } else { 0. },
+            forward,
+            duration,
+            easing: Easing::default(),
+            clock: Clock::new(),
+        }
+    }
+
+    /// Sets the [`Easing`] of the [`Timeline`].
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets the duration of a full transition.
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.duration = duration;
+    }
+
+    /// Starts the transition towards the forward or backward state, from the current progress.
+    pub fn go(&mut self, forward: bool) {
+        self.forward = forward;
+    }
+
+    /// Indicates if the [`Timeline`] goes, or rests, in the forward state.
+    pub fn is_forward(&self) -> bool {
+        self.forward
+    }
+
+    /// Returns the progress, from 0 (backward) to 1 (forward).
+    pub fn progress(&self) -> f32 {
+        self.progress
+    }
+
+    /// Returns the progress with the [`Easing`] applied.
+    pub fn value(&self) -> f32 {
+        self.easing.apply(self.progress)
+    }
+
+    /// Returns the value interpolated between `from` (backward) and `to` (forward).
+    pub fn interpolate(&self, from: f32, to: f32) -> f32 {
+        from + (to - from) * self.value()
+    }
+
+    /// Indicates if the [`Timeline`] has not reached its state yet.
+    pub fn is_animating(&self) -> bool {
+        self.progress != self.target()
+    }
+
+    /// Advances the [`Timeline`] to the time of a frame.
+    ///
+    /// Returns whether it is still animating, and so needs another frame.
+    pub fn tick(&mut self, now: Instant) -> bool {
+        if !self.is_animating() {
+            self.clock.stop();
+            return false;
+        }
+
+        let delta = self.clock.tick(now) / self.duration.as_secs_f32().max(f32::EPSILON);
+
+        self.progress = if self.forward {
+            (self.progress + delta).min(1.)
+        } else {
+            (self.progress - delta).max(0.)
+        };
+
+        if self.is_animating() {
+            true
+        } else {
+            self.clock.stop();
+            false
+        }
+    }
+
+    /// The progress of the state the [`Timeline`] goes to.
+    fn target(&self) -> f32 {
+        if self.forward { 1. } else { 0. }
+    }
+}
+
+/// A value following a target like a damped spring.
+///
+/// It settles when it is closer to its target than its precision, with a velocity lower than
+/// ten times its precision per second.
+///
+/// ```
+/// use std::time::{Duration, Instant};
+/// use more_iced_aw::animation::Spring;
+///
+/// let mut spring = Spring::new(0.);
+/// spring.set_target(100.);
+///
+/// let mut now = Instant::now();
+/// while spring.tick(now) {
+///     now += Duration::from_millis(16);
+/// }
+/// assert_eq!(spring.value(), 100.);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    value: f32,
+    velocity: f32,
+    target: f32,
+    stiffness: f32,
+    damping: f32,
+    precision: f32,
+    clock: Clock,
+}
+
+impl Spring {
+    /// Creates a new [`Spring`] resting at the given value.
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            velocity: 0.,
+            target: value,
+            stiffness: 170.,
+            damping: 26.,
+            precision: 0.01,
+            clock: Clock::new(),
+        }
+    }
+
+    /// Sets the stiffness of the [`Spring`]: the higher, the faster it moves.
+    pub fn stiffness(mut self, stiffness: f32) -> Self {
+        self.stiffness = stiffness;
+        self
+    }
+
+    /// Sets the damping of the [`Spring`]: the lower, the more it oscillates.
+    pub fn damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Sets the distance to its target under which the [`Spring`] settles.
+    pub fn precision(mut self, precision: f32) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Returns the current value.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Sets the current value, keeping the velocity, for example when it is dragged.
+    pub fn set_value(&mut self, value: f32) {
+        self.value = value;
+    }
+
+    /// Returns the current velocity, in units per second.
+    pub fn velocity(&self) -> f32 {
+        self.velocity
+    }
+
+    /// Sets the current velocity, in units per second, for example when it is thrown.
+    pub fn set_velocity(&mut self, velocity: f32) {
+        self.velocity = velocity;
+    }
+
+    /// Returns the target of the [`Spring`].
+    pub fn target(&self) -> f32 {
+        self.target
+    }
+
+    /// Sets the target of the [`Spring`].
+    pub fn set_target(&mut self, target: f32) {
+        self.target = target;
+    }
+
+    /// Stops the motion of the [`Spring`] where it is, until its next tick.
+    pub fn stop(&mut self) {
+        self.velocity = 0.;
+        self.clock.stop();
+    }
+
+    /// Indicates if the [`Spring`] has not settled on its target yet.
+    pub fn is_animating(&self) -> bool {
+        self.value != self.target || self.velocity != 0.
+    }
+
+    /// Advances the [`Spring`] to the time of a frame.
+    ///
+    /// Returns whether it is still animating, and so needs another frame.
+    pub fn tick(&mut self, now: Instant) -> bool {
+        if !self.is_animating() {
+            self.clock.stop();
+            return false;
+        }
+
+        let dt = self.clock.tick(now).min(MAX_SPRING_STEP);
+        let acceleration =
+            self.stiffness * (self.target - self.value) - self.damping * self.velocity;
+        self.velocity += acceleration * dt;
+        self.value += self.velocity * dt;
+
+        if (self.target - self.value).abs() < self.precision
+            && self.velocity.abs() < self.precision * 10.
+        {
+            self.value = self.target;
+            self.velocity = 0.;
+            self.clock.stop();
+            false
+        } else {
+            true
+        }
+    }
+}
+
+/// Moves `current` towards `target` during `elapsed` seconds, covering the fraction
+/// `1 - e^(-rate × elapsed)` of the distance.
+///
+/// Applied at each frame, the value smoothly follows the target, even when it moves.
+///
+/// ```
+/// use more_iced_aw::animation::approach;
+///
+/// assert_eq!(approach(0., 10., 5., 0.), 0.);
+/// assert!((approach(0., 10., 5., 1.) - 10.).abs() < 0.1);
+/// ```
+pub fn approach(current: f32, target: f32, rate: f32, elapsed: f32) -> f32 {
+    current + (target - current) * (1. - (-elapsed * rate).exp())
+}
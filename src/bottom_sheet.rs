@@ -0,0 +1,723 @@
+//! A sheet that rises from the bottom of some content, and can be resized by dragging.
+
+use iced::{
+    Background, Border, Color, Element, Length, Pixels, Point, Rectangle, Shadow, Size, Theme,
+    Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{Operation, Tree, tree},
+    },
+    border, event,
+    time::Instant,
+    touch, window,
+};
+
+use crate::animation::Spring;
+
+/// The height of the area at the top of the sheet that can be dragged.
+const HANDLE_HEIGHT: f32 = 24.;
+/// The size of the grab bar drawn in the handle area.
+const GRAB_BAR: Size = Size::new(36., 4.);
+/// The time used to project the position of the sheet from its velocity when it is released.
+const PROJECTION: f32 = 0.2;
+/// The stiffness of the spring moving the sheet to its snap point.
+const STIFFNESS: f32 = 170.;
+/// The damping of the spring moving the sheet to its snap point.
+const DAMPING: f32 = 26.;
+
+/// A height at which a [`BottomSheet`] rests.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Snap {
+    /// Only the top of the sheet is visible (see [`BottomSheet::peek`]).
+    Peek,
+    /// The sheet covers half of the content.
+    #[default]
+    Half,
+    /// The sheet covers most of the content (see [`BottomSheet::full`]).
+    Full,
+}
+
+/// The appearance of a [`BottomSheet`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the sheet.
+    pub background: Background,
+    /// The radius of the top corners of the sheet.
+    pub radius: f32,
+    /// The shadow of the sheet.
+    pub shadow: Shadow,
+    /// The color of the grab bar at the top of the sheet.
+    pub handle: Color,
+    /// The color of the backdrop covering the content when the sheet is at half height.
+    pub backdrop: Color,
+}
+
+/// The theme catalog of a [`BottomSheet`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`BottomSheet`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`BottomSheet`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        radius: 12.,
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.3),
+            offset: Vector::ZERO,
+            blur_radius: 12.,
+        },
+        handle: palette.background.strong.color,
+        backdrop: Color::BLACK.scale_alpha(0.4),
+    }
+}
+
+/// A drag of the sheet in progress.
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    /// The vertical position of the cursor when the drag started.
+    origin_y: f32,
+    /// The height of the sheet when the drag started.
+    origin_height: f32,
+    /// The last vertical position of the cursor.
+    last_y: f32,
+    /// The time of the last move of the cursor.
+    last_time: Instant,
+}
+
+/// The state of a [`BottomSheet`].
+#[derive(Debug, Clone, Copy)]
+struct State {
+    /// The spring moving the visible height of the sheet, once it was laid out.
+    ///
+    /// Its velocity is in pixels per second, positive upwards.
+    spring: Option<Spring>,
+    /// The snap point the sheet rests at when it is open.
+    snap: Snap,
+    /// The height available to the sheet, from the last layout.
+    available: f32,
+    /// Whether the sheet was dismissed and is waiting to be closed.
+    dismissed: bool,
+    drag: Option<Drag>,
+}
+
+impl State {
+    /// Returns the visible height of the sheet.
+    fn height(&self) -> f32 {
+        self.spring.as_ref().map_or(0., Spring::value)
+    }
+}
+
+/// A sheet that rises from the bottom of some content, created with [`bottom_sheet`].
+///
+/// The sheet rests at one of three [`Snap`] points, and can be dragged by its handle between them.
+/// When released, it settles with the momentum of the drag to the closest snap point.
+/// Dragging it below the peek height, clicking on the backdrop or pressing Escape dismisses it
+/// (see [`BottomSheet::on_dismiss`]).
+pub struct BottomSheet<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    sheet: Element<'a, Message, Theme, Renderer>,
+    open: bool,
+    initial: Snap,
+    peek: f32,
+    full: f32,
+    on_dismiss: Option<Message>,
+    on_snap: Option<Box<dyn Fn(Snap) -> Message + 'a>>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> BottomSheet<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`BottomSheet`] with the given content and sheet.
+    pub fn new(
+        open: bool,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        sheet: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            sheet: sheet.into(),
+            open,
+            initial: Snap::default(),
+            peek: 80.,
+            full: 0.9,
+            on_dismiss: None,
+            on_snap: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the [`Snap`] point of the sheet when it first appears.
+    pub fn initial(mut self, snap: Snap) -> Self {
+        self.initial = snap;
+        self
+    }
+
+    /// Sets the visible height of the sheet at the [`Snap::Peek`] point.
+    pub fn peek(mut self, height: impl Into<Pixels>) -> Self {
+        self.peek = height.into().0;
+        self
+    }
+
+    /// Sets the part of the content covered by the sheet at the [`Snap::Full`] point, between 0 and 1.
+    pub fn full(mut self, fraction: f32) -> Self {
+        self.full = fraction.clamp(0., 1.);
+        self
+    }
+
+    /// Sets the message produced when the [`BottomSheet`] is dismissed.
+    ///
+    /// If it is not set, the sheet can not be dismissed, and dragging it down settles it at the peek height.
+    pub fn on_dismiss(mut self, on_dismiss: Message) -> Self {
+        self.on_dismiss = Some(on_dismiss);
+        self
+    }
+
+    /// Sets the function called with the new [`Snap`] point when the sheet is released at one.
+    pub fn on_snap(mut self, on_snap: impl Fn(Snap) -> Message + 'a) -> Self {
+        self.on_snap = Some(Box::new(on_snap));
+        self
+    }
+
+    /// Sets the style of the [`BottomSheet`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`BottomSheet`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the height of the sheet at a [`Snap`] point.
+    fn snap_height(&self, snap: Snap, available: f32) -> f32 {
+        let full = available * self.full;
+
+        match snap {
+            Snap::Peek => self.peek.min(full),
+            Snap::Half => (available / 2.).clamp(self.peek.min(full), full),
+            Snap::Full => full,
+        }
+    }
+
+    /// Returns the height the sheet is moving to.
+    fn target(&self, state: &State) -> f32 {
+        if !self.open || state.dismissed {
+            0.
+        } else {
+            self.snap_height(state.snap, state.available)
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for BottomSheet<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            spring: None,
+            snap: self.initial,
+            available: 0.,
+            dismissed: false,
+            drag: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content), Tree::new(&self.sheet)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content, &self.sheet]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let bounds = limits.max();
+
+        let state = tree.state.downcast_mut::<State>();
+        state.available = bounds.height;
+        let target = self.target(state);
+        let height = state
+            .spring
+            .get_or_insert_with(|| {
+                Spring::new(target)
+                    .stiffness(STIFFNESS)
+                    .damping(DAMPING)
+                    .precision(0.5)
+            })
+            .value();
+
+        let content = self.content.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &Limits::new(Size::ZERO, bounds),
+        );
+
+        let sheet_size = Size::new(bounds.width, bounds.height * self.full);
+        let sheet = self
+            .sheet
+            .as_widget()
+            .layout(
+                &mut tree.children[1],
+                renderer,
+                &Limits::new(
+                    Size::ZERO,
+                    Size::new(sheet_size.width, sheet_size.height - HANDLE_HEIGHT),
+                ),
+            )
+            .move_to(Point::new(0., HANDLE_HEIGHT));
+
+        let sheet = Node::with_children(sheet_size, vec![sheet])
+            .move_to(Point::new(0., bounds.height - height));
+
+        Node::with_children(bounds, vec![content, sheet])
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let height = state.height();
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let (content, sheet) = (children.next().unwrap(), children.next().unwrap());
+        let appearance = theme.style(&self.class);
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content,
+            if height > 0. {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            },
+            viewport,
+        );
+
+        if height <= 0. {
+            return;
+        }
+
+        renderer.with_layer(bounds, |renderer| {
+            let half = self.snap_height(Snap::Half, state.available).max(1.);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                appearance.backdrop.scale_alpha((height / half).min(1.)),
+            );
+
+            let sheet_bounds = sheet.bounds();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        height: sheet_bounds.height.max(height),
+                        ..sheet_bounds
+                    },
+                    border: Border {
+                        radius: border::Radius {
+                            top_left: appearance.radius,
+                            top_right: appearance.radius,
+                            ..border::Radius::default()
+                        },
+                        ..Border::default()
+                    },
+                    shadow: appearance.shadow,
+                },
+                appearance.background,
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle::new(
+                        sheet_bounds.position()
+                            + Vector::new(
+                                (sheet_bounds.width - GRAB_BAR.width) / 2.,
+                                (HANDLE_HEIGHT - GRAB_BAR.height) / 2.,
+                            ),
+                        GRAB_BAR,
+                    ),
+                    border: border::rounded(GRAB_BAR.height / 2.),
+                    ..renderer::Quad::default()
+                },
+                appearance.handle,
+            );
+
+            self.sheet.as_widget().draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                style,
+                sheet.children().next().unwrap(),
+                cursor,
+                viewport,
+            );
+        });
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let mut children = layout.children();
+        let (content, sheet) = (children.next().unwrap(), children.next().unwrap());
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.content
+                .as_widget()
+                .operate(&mut tree.children[0], content, renderer, operation);
+            self.sheet.as_widget().operate(
+                &mut tree.children[1],
+                sheet.children().next().unwrap(),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let (content, sheet) = (children.next().unwrap(), children.next().unwrap());
+        let handle = Rectangle {
+            height: HANDLE_HEIGHT,
+            ..sheet.bounds()
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if !self.open {
+            state.dismissed = false;
+            state.drag = None;
+        }
+
+        let height = state.height();
+        let full = self.snap_height(Snap::Full, state.available);
+
+        match event {
+            iced::Event::Window(window::Event::RedrawRequested(now)) if state.drag.is_none() => {
+                let target = self.target(state);
+
+                if let Some(spring) = &mut state.spring {
+                    spring.set_target(target);
+
+                    if spring.is_animating() {
+                        if spring.tick(now) {
+                            shell.request_redraw(window::RedrawRequest::NextFrame);
+                        }
+                        spring.set_value(spring.value().clamp(0., full.max(target)));
+                        shell.invalidate_layout();
+                    }
+                }
+            }
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. })
+                if self.open && height > 0. =>
+            {
+                if let Some(position) = cursor.position_over(handle) {
+                    state.drag = Some(Drag {
+                        origin_y: position.y,
+                        origin_height: height,
+                        last_y: position.y,
+                        last_time: Instant::now(),
+                    });
+                    if let Some(spring) = &mut state.spring {
+                        spring.stop();
+                    }
+                    return event::Status::Captured;
+                }
+
+                if cursor.is_over(bounds)
+                    && cursor
+                        .position()
+                        .is_some_and(|p| p.y < bounds.y + bounds.height - height)
+                {
+                    if let Some(on_dismiss) = self.on_dismiss.clone() {
+                        state.dismissed = true;
+                        shell.publish(on_dismiss);
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                    return event::Status::Captured;
+                }
+            }
+            iced::Event::Mouse(mouse::Event::CursorMoved { position })
+            | iced::Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(drag) = &mut state.drag
+                    && let Some(spring) = &mut state.spring
+                {
+                    let now = Instant::now();
+                    let dt = now.saturating_duration_since(drag.last_time).as_secs_f32();
+
+                    if dt > 0. {
+                        spring.set_velocity((drag.last_y - position.y) / dt);
+                    }
+                    drag.last_y = position.y;
+                    drag.last_time = now;
+
+                    spring.set_value(
+                        (drag.origin_height + drag.origin_y - position.y).clamp(0., full),
+                    );
+                    shell.invalidate_layout();
+                    return event::Status::Captured;
+                }
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerLifted { .. })
+            | iced::Event::Touch(touch::Event::FingerLost { .. })
+                if state.drag.is_some() =>
+            {
+                state.drag = None;
+                let velocity = state.spring.as_ref().map_or(0., Spring::velocity);
+                let projected = height + velocity * PROJECTION;
+
+                if projected < self.snap_height(Snap::Peek, state.available) / 2.
+                    && let Some(on_dismiss) = self.on_dismiss.clone()
+                {
+                    state.dismissed = true;
+                    shell.publish(on_dismiss);
+                } else {
+                    let snap = [Snap::Peek, Snap::Half, Snap::Full]
+                        .into_iter()
+                        .min_by(|a, b| {
+                            let distance =
+                                |snap| (self.snap_height(snap, state.available) - projected).abs();
+                            distance(*a).total_cmp(&distance(*b))
+                        })
+                        .unwrap_or_default();
+
+                    if snap != state.snap
+                        && let Some(on_snap) = &self.on_snap
+                    {
+                        shell.publish(on_snap(snap));
+                    }
+                    state.snap = snap;
+                }
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+                return event::Status::Captured;
+            }
+            iced::Event::Keyboard(iced::keyboard::Event::KeyPressed {
+                key: iced::keyboard::Key::Named(iced::keyboard::key::Named::Escape),
+                ..
+            }) if self.open && !state.dismissed => {
+                if let Some(on_dismiss) = self.on_dismiss.clone() {
+                    state.dismissed = true;
+                    shell.publish(on_dismiss);
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        let height = state.height();
+
+        if height > 0. {
+            let status = self.sheet.as_widget_mut().on_event(
+                &mut tree.children[1],
+                event.clone(),
+                sheet.children().next().unwrap(),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            content,
+            if height > 0. {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            },
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let height = state.height();
+        let mut children = layout.children();
+        let (content, sheet) = (children.next().unwrap(), children.next().unwrap());
+        let handle = Rectangle {
+            height: HANDLE_HEIGHT,
+            ..sheet.bounds()
+        };
+
+        if state.drag.is_some() {
+            mouse::Interaction::Grabbing
+        } else if height <= 0. {
+            self.content.as_widget().mouse_interaction(
+                &tree.children[0],
+                content,
+                cursor,
+                viewport,
+                renderer,
+            )
+        } else if cursor.is_over(handle) {
+            mouse::Interaction::Grab
+        } else if cursor.is_over(sheet.bounds()) {
+            self.sheet.as_widget().mouse_interaction(
+                &tree.children[1],
+                sheet.children().next().unwrap(),
+                cursor,
+                viewport,
+                renderer,
+            )
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let mut children = layout.children();
+        let (content, sheet) = (children.next().unwrap(), children.next().unwrap());
+        let (content_tree, sheet_tree) = tree.children.split_at_mut(1);
+
+        let children = [
+            self.content.as_widget_mut().overlay(
+                &mut content_tree[0],
+                content,
+                renderer,
+                translation,
+            ),
+            self.sheet.as_widget_mut().overlay(
+                &mut sheet_tree[0],
+                sheet.children().next().unwrap(),
+                renderer,
+                translation,
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<BottomSheet<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(value: BottomSheet<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`BottomSheet`], which shows the `sheet` over the `content` when `open` is true.
+pub fn bottom_sheet<'a, Message, Theme, Renderer>(
+    open: bool,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    sheet: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> BottomSheet<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    BottomSheet::new(open, content, sheet)
+}
@@ -0,0 +1,569 @@
+//! A container with a header, a body and a footer.
+
+use iced::{
+    Background, Border, Color, Element, Length, Padding, Pixels, Point, Rectangle, Shadow, Size,
+    Theme, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        text::{self, Text},
+        widget::{Operation, Tree},
+    },
+    alignment, border, event,
+};
+
+/// The character drawn as the close button of a [`Card`].
+const CLOSE: &str = "×";
+
+/// The appearance of a [`Card`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the [`Card`].
+    pub background: Background,
+    /// The border of the [`Card`].
+    pub border: Border,
+    /// The shadow of the [`Card`], which gives it its elevation.
+    pub shadow: Shadow,
+    /// The color of the text of the body.
+    pub text_color: Color,
+    /// The background of the header, if any.
+    pub header_background: Option<Background>,
+    /// The color of the text of the header and of the close button.
+    pub header_text_color: Color,
+    /// The background of the footer, if any.
+    pub footer_background: Option<Background>,
+}
+
+/// The theme catalog of a [`Card`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Card`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Card`]: a rounded box with a colored header.
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.,
+            radius: 8.into(),
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0., 2.),
+            blur_radius: 6.,
+        },
+        text_color: palette.background.base.text,
+        header_background: Some(palette.primary.strong.color.into()),
+        header_text_color: palette.primary.strong.text,
+        footer_background: Some(palette.background.weak.color.into()),
+    }
+}
+
+/// A flat [`Card`], without shadow and with an uncolored header.
+pub fn flat(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        shadow: Shadow::default(),
+        header_background: None,
+        header_text_color: palette.background.base.text,
+        footer_background: None,
+        ..default(theme)
+    }
+}
+
+/// A container with an optional header and footer, created with [`card`].
+///
+/// If the [`Card`] has a header, it can have a close button on its right (see [`Card::on_close`]).
+pub struct Card<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    header: Option<Element<'a, Message, Theme, Renderer>>,
+    body: Element<'a, Message, Theme, Renderer>,
+    footer: Option<Element<'a, Message, Theme, Renderer>>,
+    on_close: Option<Message>,
+    width: Length,
+    max_width: f32,
+    padding: Padding,
+    close_size: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Card<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Card`] with the given body.
+    pub fn new(body: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            header: None,
+            body: body.into(),
+            footer: None,
+            on_close: None,
+            width: Length::Shrink,
+            max_width: f32::INFINITY,
+            padding: Padding::new(10.),
+            close_size: 16.,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the header of the [`Card`].
+    pub fn header(mut self, header: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Sets the footer of the [`Card`].
+    pub fn footer(mut self, footer: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
+    /// Sets the message produced when the close button of the [`Card`] is pressed.
+    ///
+    /// The close button is only displayed if the [`Card`] has a header.
+    pub fn on_close(mut self, on_close: Message) -> Self {
+        self.on_close = Some(on_close);
+        self
+    }
+
+    /// Sets the width of the [`Card`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the maximum width of the [`Card`].
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.max_width = max_width.into().0;
+        self
+    }
+
+    /// Sets the padding of each region (header, body and footer) of the [`Card`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the size of the close button of the [`Card`].
+    pub fn close_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.close_size = size.into().0;
+        self
+    }
+
+    /// Sets the style of the [`Card`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Card`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the elements of the [`Card`], in order: header, body and footer.
+    fn elements(&self) -> impl Iterator<Item = &Element<'a, Message, Theme, Renderer>> {
+        self.header
+            .iter()
+            .chain(std::iter::once(&self.body))
+            .chain(self.footer.iter())
+    }
+
+    /// Returns the mutable elements of the [`Card`], in order: header, body and footer.
+    fn elements_mut(&mut self) -> impl Iterator<Item = &mut Element<'a, Message, Theme, Renderer>> {
+        self.header
+            .iter_mut()
+            .chain(std::iter::once(&mut self.body))
+            .chain(self.footer.iter_mut())
+    }
+
+    /// Returns the width taken by the close button in the header, including its spacing.
+    fn close_width(&self) -> f32 {
+        if self.on_close.is_some() {
+            self.close_size + self.padding.right
+        } else {
+            0.
+        }
+    }
+
+    /// Returns the bounds of a region of the [`Card`] from the layout of its content.
+    fn region(&self, card: Rectangle, content: Rectangle) -> Rectangle {
+        Rectangle {
+            x: card.x,
+            y: content.y - self.padding.top,
+            width: card.width,
+            height: content.height + self.padding.vertical(),
+        }
+    }
+
+    /// Returns the bounds of the close button, if any.
+    fn close_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        let header = layout.children().next()?;
+        let region = self.region(layout.bounds(), header.bounds());
+
+        (self.header.is_some() && self.on_close.is_some()).then(|| Rectangle {
+            x: region.x + region.width - self.padding.right - self.close_size,
+            y: region.y + (region.height - self.close_size) / 2.,
+            width: self.close_size,
+            height: self.close_size,
+        })
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Card<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.elements().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.elements().collect::<Vec<_>>());
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).max_width(self.max_width);
+        let max_width = limits.max().width - self.padding.horizontal();
+        let has_header = self.header.is_some();
+
+        let mut y = 0.;
+        let mut width: f32 = 0.;
+
+        let children: Vec<_> = self
+            .elements()
+            .zip(&mut tree.children)
+            .enumerate()
+            .map(|(i, (element, tree))| {
+                let reserved = if i == 0 && has_header {
+                    self.close_width()
+                } else {
+                    0.
+                };
+
+                let child_limits = Limits::new(
+                    Size::ZERO,
+                    Size::new((max_width - reserved).max(0.), f32::INFINITY),
+                );
+                let node = element
+                    .as_widget()
+                    .layout(tree, renderer, &child_limits)
+                    .move_to(Point::new(self.padding.left, y + self.padding.top));
+
+                width = width.max(node.size().width + reserved);
+                y += node.size().height + self.padding.vertical();
+                node
+            })
+            .collect();
+
+        let size = limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(width + self.padding.horizontal(), y),
+        );
+
+        Node::with_children(size, children)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let card = theme.style(&self.class);
+        let radius = card.border.radius;
+        let children: Vec<_> = layout.children().collect();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    color: Color::TRANSPARENT,
+                    width: 0.,
+                    radius,
+                },
+                shadow: card.shadow,
+            },
+            card.background,
+        );
+
+        let regions = [
+            (
+                self.header.is_some(),
+                card.header_background,
+                children.first(),
+                true,
+            ),
+            (
+                self.footer.is_some(),
+                card.footer_background,
+                children.last(),
+                false,
+            ),
+        ];
+
+        for (present, background, child, top) in regions {
+            if let (true, Some(background), Some(child)) = (present, background, child) {
+                let radius = if top {
+                    border::Radius {
+                        bottom_right: 0.,
+                        bottom_left: 0.,
+                        ..radius
+                    }
+                } else {
+                    border::Radius {
+                        top_left: 0.,
+                        top_right: 0.,
+                        ..radius
+                    }
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: self.region(bounds, child.bounds()),
+                        border: border::rounded(radius),
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: card.border,
+                ..renderer::Quad::default()
+            },
+            Color::TRANSPARENT,
+        );
+
+        for (i, ((element, tree), layout)) in self
+            .elements()
+            .zip(&tree.children)
+            .zip(children)
+            .enumerate()
+        {
+            let text_color = if i == 0 && self.header.is_some() {
+                card.header_text_color
+            } else {
+                card.text_color
+            };
+
+            element.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                &renderer::Style { text_color },
+                layout,
+                cursor,
+                viewport,
+            );
+        }
+
+        if let Some(close) = self.close_bounds(layout) {
+            let color = if cursor.is_over(close) {
+                card.header_text_color
+            } else {
+                card.header_text_color.scale_alpha(0.7)
+            };
+
+            renderer.fill_text(
+                Text {
+                    content: CLOSE.to_string(),
+                    bounds: close.size(),
+                    size: Pixels(self.close_size),
+                    line_height: text::LineHeight::Absolute(Pixels(self.close_size)),
+                    font: renderer.default_font(),
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                },
+                close.center(),
+                color,
+                close,
+            );
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.elements()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((element, tree), layout)| {
+                    element
+                        .as_widget()
+                        .operate(tree, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let close = self.close_bounds(layout);
+
+        if let iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event
+            && let Some(close) = close
+            && cursor.is_over(close)
+            && let Some(on_close) = self.on_close.clone()
+        {
+            shell.publish(on_close);
+            return event::Status::Captured;
+        }
+
+        self.elements_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((element, tree), layout)| {
+                element.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self
+            .close_bounds(layout)
+            .is_some_and(|close| cursor.is_over(close))
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.elements()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((element, tree), layout)| {
+                element
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children = self
+            .elements_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((element, tree), layout)| {
+                element
+                    .as_widget_mut()
+                    .overlay(tree, layout, renderer, translation)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Card<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(value: Card<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`Card`] with the given body.
+///
+/// The header and the footer are added with [`Card::header`] and [`Card::footer`].
+pub fn card<'a, Message, Theme, Renderer>(
+    body: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Card<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    Card::new(body)
+}
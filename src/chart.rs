@@ -0,0 +1,689 @@
+//! Basic charts, drawn on a [`Canvas`].
+//!
+//! A [`Chart`] draws one or more [`Series`] of values over shared categories, as lines or as
+//! bars, with a vertical axis, labels and an optional legend. Hovering the chart shows a crosshair
+//! and the values of the hovered category.
+//!
+//! A [`Pie`] draws the shares of a total as the slices of a pie or a donut.
+
+use iced::{
+    Color, Element, Length, Pixels, Point, Rectangle, Renderer, Size, Theme, alignment,
+    mouse::{self, Cursor},
+    widget::canvas::{self, Canvas, Frame, Geometry, Path, Program, Stroke, Text, event},
+};
+
+mod pie;
+pub use pie::*;
+
+/// The colors given to the series without color, in order.
+// The extended palette has too few distinct colors for charts.
+pub const SERIES_COLORS: [Color; 8] = [
+    iced::color!(0x4E, 0x79, 0xA7),
+    iced::color!(0xF2, 0x8E, 0x2B),
+    iced::color!(0xE1, 0x57, 0x59),
+    iced::color!(0x76, 0xB7, 0xB2),
+    iced::color!(0x59, 0xA1, 0x4F),
+    iced::color!(0xED, 0xC9, 0x48),
+    iced::color!(0xB0, 0x7A, 0xA1),
+    iced::color!(0x9C, 0x75, 0x5F),
+];
+
+/// The approximate width of a character, relative to the text size, used to lay out labels.
+const CHAR_WIDTH: f32 = 0.6;
+/// The number of ticks aimed for on the value axis.
+const TICKS: f32 = 5.;
+
+/// How a [`Chart`] draws its series.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Kind {
+    /// Each series is a line through its values.
+    #[default]
+    Line,
+    /// The values of each category are bars side by side.
+    Bar,
+}
+
+/// A named series of values of a [`Chart`], one for each category.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Series {
+    /// The name of the series, shown in the legend.
+    pub name: String,
+    /// The values of the series.
+    pub values: Vec<f32>,
+    /// The color of the series, or `None` to take the next of the [`SERIES_COLORS`].
+    #[cfg_attr(feature = "serde", serde(with = "crate::helpers::serde_iced"))]
+    pub color: Option<Color>,
+}
+
+impl Series {
+    /// Creates a new [`Series`].
+    pub fn new(name: impl Into<String>, values: impl IntoIterator<Item = f32>) -> Self {
+        Self {
+            name: name.into(),
+            values: values.into_iter().collect(),
+            color: None,
+        }
+    }
+
+    /// Sets the color of the [`Series`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// The appearance of a [`Chart`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color of the axes.
+    pub axis: Color,
+    /// The color of the grid lines.
+    pub grid: Color,
+    /// The color of the labels.
+    pub text: Color,
+    /// The color of the crosshair.
+    pub crosshair: Color,
+    /// The background of the readout of the hovered values.
+    pub readout: Color,
+}
+
+/// The theme catalog of a [`Chart`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Chart`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Chart`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        axis: palette.background.strong.color,
+        grid: palette.background.weak.color,
+        text: palette.background.base.text,
+        crosshair: palette.background.strong.color,
+        readout: palette.background.weak.color,
+    }
+}
+
+/// Returns the color of the series with the given index.
+fn series_color(series: &Series, index: usize) -> Color {
+    series
+        .color
+        .unwrap_or(SERIES_COLORS[index % SERIES_COLORS.len()])
+}
+
+/// Returns the lowest tick, the step between the ticks and the highest tick of an axis from `min`
+/// to `max`, with round steps.
+fn ticks(min: f32, max: f32) -> (f32, f32, f32) {
+    let (min, max) = if min < max {
+        (min, max)
+    } else {
+        (min, min + 1.)
+    };
+
+    let raw = (max - min) / TICKS;
+    let magnitude = 10f32.powf(raw.log10().floor());
+    let step = match raw / magnitude {
+        n if n < 1.5 => 1.,
+        n if n < 3. => 2.,
+        n if n < 7. => 5.,
+        _ => 10.,
+    } * magnitude;
+
+    (
+        (min / step).floor() * step,
+        step,
+        (max / step).ceil() * step,
+    )
+}
+
+/// Formats a value with the number of decimals of the step between the ticks.
+fn format_value(value: f32, step: f32) -> String {
+    let decimals = (-step.log10().floor()).max(0.) as usize;
+    format!("{value:.decimals$}")
+}
+
+/// The state of a [`Chart`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    hovered: Option<usize>,
+}
+
+/// The areas of a [`Chart`].
+#[derive(Debug, Clone, Copy)]
+struct Areas {
+    /// The height of the legend, at the top.
+    legend: f32,
+    /// The area of the plot, in the frame.
+    plot: Rectangle,
+    /// The lowest value, the step between the ticks and the highest value.
+    ticks: (f32, f32, f32),
+}
+
+/// A line or bar chart of one or more series, created with [`line_chart`] or [`bar_chart`].
+pub struct Chart<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    kind: Kind,
+    labels: Vec<String>,
+    series: Vec<Series>,
+    legend: bool,
+    on_hover: Option<Box<dyn Fn(Option<usize>) -> Message + 'a>>,
+    text_size: Pixels,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> Chart<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new empty [`Chart`] of the given [`Kind`].
+    pub fn new(kind: Kind) -> Self {
+        Self {
+            kind,
+            labels: Vec::new(),
+            series: Vec::new(),
+            legend: true,
+            on_hover: None,
+            text_size: Pixels(12.),
+            width: Length::Fill,
+            height: Length::Fixed(240.),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the labels of the categories, along the horizontal axis.
+    pub fn labels(mut self, labels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Adds a [`Series`] to the [`Chart`].
+    pub fn push(mut self, series: Series) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Adds several [`Series`] to the [`Chart`].
+    pub fn extend(mut self, series: impl IntoIterator<Item = Series>) -> Self {
+        self.series.extend(series);
+        self
+    }
+
+    /// Sets whether the legend is shown. Defaults to `true`.
+    pub fn legend(mut self, legend: bool) -> Self {
+        self.legend = legend;
+        self
+    }
+
+    /// Sets the message produced when the hovered category changes, with its index.
+    pub fn on_hover(mut self, on_hover: impl Fn(Option<usize>) -> Message + 'a) -> Self {
+        self.on_hover = Some(Box::new(on_hover));
+        self
+    }
+
+    /// Sets the text size of the labels of the [`Chart`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = text_size.into();
+        self
+    }
+
+    /// Sets the width of the [`Chart`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Chart`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`Chart`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Chart`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the number of categories.
+    fn categories(&self) -> usize {
+        self.series
+            .iter()
+            .map(|series| series.values.len())
+            .chain([self.labels.len()])
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the approximate width of a text.
+    fn text_width(&self, text: &str) -> f32 {
+        text.chars().count() as f32 * self.text_size.0 * CHAR_WIDTH
+    }
+
+    /// Returns the areas of the chart in a frame of the given size.
+    fn areas(&self, size: Size) -> Areas {
+        let values = self.series.iter().flat_map(|series| &series.values);
+        let min = values.clone().copied().fold(0f32, f32::min);
+        let max = values.copied().fold(0f32, f32::max);
+        let ticks = ticks(min, max);
+
+        let line = self.text_size.0 * 1.5;
+        let legend = if self.legend && !self.series.is_empty() {
+            line
+        } else {
+            0.
+        };
+        let label_width = [ticks.0, ticks.2]
+            .iter()
+            .map(|value| self.text_width(&format_value(*value, ticks.1)))
+            .fold(0., f32::max);
+        let left = label_width + 8.;
+
+        Areas {
+            legend,
+            plot: Rectangle {
+                x: left,
+                y: legend + self.text_size.0 / 2.,
+                width: (size.width - left - 8.).max(0.),
+                height: (size.height - legend - self.text_size.0 / 2. - line).max(0.),
+            },
+            ticks,
+        }
+    }
+
+    /// Returns the index of the category under the given position in the frame.
+    fn category_at(&self, areas: &Areas, position: Point) -> Option<usize> {
+        let count = self.categories();
+        if count == 0 || !areas.plot.contains(position) {
+            return None;
+        }
+
+        let index = ((position.x - areas.plot.x) / areas.plot.width * count as f32) as usize;
+        Some(index.min(count - 1))
+    }
+
+    /// Returns the vertical position of a value in the plot.
+    fn y(&self, areas: &Areas, value: f32) -> f32 {
+        let (min, _, max) = areas.ticks;
+        areas.plot.y + areas.plot.height * (max - value) / (max - min)
+    }
+
+    fn draw_axes(&self, frame: &mut Frame, areas: &Areas, style: &Style) {
+        let plot = areas.plot;
+        let (min, step, max) = areas.ticks;
+
+        let mut value = min;
+        while value <= max + step / 2. {
+            let y = self.y(areas, value);
+            frame.stroke(
+                &Path::line(Point::new(plot.x, y), Point::new(plot.x + plot.width, y)),
+                Stroke::default().with_color(style.grid).with_width(1.),
+            );
+            frame.fill_text(Text {
+                content: format_value(value, step),
+                position: Point::new(plot.x - 4., y),
+                color: style.text,
+                size: self.text_size,
+                horizontal_alignment: alignment::Horizontal::Right,
+                vertical_alignment: alignment::Vertical::Center,
+                ..Text::default()
+            });
+            value += step;
+        }
+
+        let zero = self.y(areas, 0.);
+        frame.stroke(
+            &Path::line(
+                Point::new(plot.x, zero),
+                Point::new(plot.x + plot.width, zero),
+            ),
+            Stroke::default().with_color(style.axis).with_width(1.),
+        );
+        frame.stroke(
+            &Path::line(
+                Point::new(plot.x, plot.y),
+                Point::new(plot.x, plot.y + plot.height),
+            ),
+            Stroke::default().with_color(style.axis).with_width(1.),
+        );
+
+        let count = self.categories();
+        if count == 0 {
+            return;
+        }
+        let width = plot.width / count as f32;
+        let widest = self
+            .labels
+            .iter()
+            .map(|label| self.text_width(label) + 8.)
+            .fold(0., f32::max);
+        let every = (widest / width.max(1.)).ceil().max(1.) as usize;
+
+        for (i, label) in self.labels.iter().enumerate().step_by(every) {
+            frame.fill_text(Text {
+                content: label.clone(),
+                position: Point::new(plot.x + width * (i as f32 + 0.5), plot.y + plot.height + 4.),
+                color: style.text,
+                size: self.text_size,
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Top,
+                ..Text::default()
+            });
+        }
+    }
+
+    fn draw_legend(&self, frame: &mut Frame, areas: &Areas, style: &Style) {
+        if areas.legend == 0. {
+            return;
+        }
+
+        let size = self.text_size.0;
+        let mut x = areas.plot.x;
+        for (i, series) in self.series.iter().enumerate() {
+            frame.fill_rectangle(
+                Point::new(x, (areas.legend - size) / 2.),
+                Size::new(size, size),
+                series_color(series, i),
+            );
+            frame.fill_text(Text {
+                content: series.name.clone(),
+                position: Point::new(x + size + 4., areas.legend / 2.),
+                color: style.text,
+                size: self.text_size,
+                vertical_alignment: alignment::Vertical::Center,
+                ..Text::default()
+            });
+            x += size + 4. + self.text_width(&series.name) + 12.;
+        }
+    }
+
+    fn draw_series(&self, frame: &mut Frame, areas: &Areas) {
+        let plot = areas.plot;
+        let count = self.categories();
+        if count == 0 {
+            return;
+        }
+        let width = plot.width / count as f32;
+
+        match self.kind {
+            Kind::Line => {
+                for (s, series) in self.series.iter().enumerate() {
+                    let color = series_color(series, s);
+                    let points: Vec<_> = series
+                        .values
+                        .iter()
+                        .enumerate()
+                        .map(|(i, value)| {
+                            Point::new(plot.x + width * (i as f32 + 0.5), self.y(areas, *value))
+                        })
+                        .collect();
+
+                    let path = Path::new(|builder| {
+                        for (i, point) in points.iter().enumerate() {
+                            if i == 0 {
+                                builder.move_to(*point);
+                            } else {
+                                builder.line_to(*point);
+                            }
+                        }
+                    });
+                    frame.stroke(&path, Stroke::default().with_color(color).with_width(2.));
+
+                    for point in points {
+                        frame.fill(&Path::circle(point, 3.), color);
+                    }
+                }
+            }
+            Kind::Bar => {
+                let bars = self.series.len().max(1) as f32;
+                let bar = width * 0.8 / bars;
+                let zero = self.y(areas, 0.);
+
+                for (s, series) in self.series.iter().enumerate() {
+                    let color = series_color(series, s);
+
+                    for (i, value) in series.values.iter().enumerate() {
+                        let x = plot.x + width * (i as f32 + 0.1) + bar * s as f32;
+                        let y = self.y(areas, *value);
+
+                        frame.fill_rectangle(
+                            Point::new(x, y.min(zero)),
+                            Size::new(bar, (y - zero).abs()),
+                            color,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn draw_hover(&self, frame: &mut Frame, areas: &Areas, style: &Style, index: usize) {
+        let plot = areas.plot;
+        let width = plot.width / self.categories() as f32;
+        let x = plot.x + width * (index as f32 + 0.5);
+
+        match self.kind {
+            Kind::Line => frame.stroke(
+                &Path::line(Point::new(x, plot.y), Point::new(x, plot.y + plot.height)),
+                Stroke::default().with_color(style.crosshair).with_width(1.),
+            ),
+            Kind::Bar => frame.fill_rectangle(
+                Point::new(x - width / 2., plot.y),
+                Size::new(width, plot.height),
+                Color {
+                    a: style.crosshair.a * 0.2,
+                    ..style.crosshair
+                },
+            ),
+        }
+
+        let line = self.text_size.0 * 1.3;
+        let title = self.labels.get(index).cloned();
+        let rows: Vec<_> = self
+            .series
+            .iter()
+            .enumerate()
+            .filter_map(|(s, series)| {
+                let value = series.values.get(index)?;
+                Some((
+                    format!(
+                        "{}: {}",
+                        series.name,
+                        format_value(*value, areas.ticks.1 / 10.)
+                    ),
+                    series_color(series, s),
+                ))
+            })
+            .collect();
+
+        let width = title
+            .iter()
+            .map(|title| self.text_width(title))
+            .chain(rows.iter().map(|(row, _)| self.text_width(row)))
+            .fold(0., f32::max)
+            + 12.;
+        let height = (rows.len() + usize::from(title.is_some())) as f32 * line + 8.;
+
+        let left = if x + 8. + width <= plot.x + plot.width {
+            x + 8.
+        } else {
+            x - 8. - width
+        };
+        let top = plot.y + 4.;
+
+        frame.fill_rectangle(
+            Point::new(left, top),
+            Size::new(width, height),
+            style.readout,
+        );
+
+        let mut y = top + 4.;
+        for (content, color) in title
+            .map(|title| (title, style.text))
+            .into_iter()
+            .chain(rows)
+        {
+            frame.fill_text(Text {
+                content,
+                position: Point::new(left + 6., y),
+                color,
+                size: self.text_size,
+                ..Text::default()
+            });
+            y += line;
+        }
+    }
+}
+
+impl<Message, Theme> Program<Message, Theme> for Chart<'_, Message, Theme>
+where
+    Theme: Catalog,
+{
+    type State = State;
+
+    fn update(
+        &self,
+        state: &mut State,
+        event: canvas::Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> (event::Status, Option<Message>) {
+        let canvas::Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft) =
+            event
+        else {
+            return (event::Status::Ignored, None);
+        };
+
+        let areas = self.areas(bounds.size());
+        let hovered = cursor
+            .position_in(bounds)
+            .and_then(|position| self.category_at(&areas, position));
+
+        if hovered == state.hovered {
+            return (event::Status::Ignored, None);
+        }
+        state.hovered = hovered;
+
+        let message = self.on_hover.as_ref().map(|on_hover| on_hover(hovered));
+        (event::Status::Ignored, message)
+    }
+
+    fn draw(
+        &self,
+        _state: &State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let style = theme.style(&self.class);
+        let mut frame = Frame::new(renderer, bounds.size());
+        let areas = self.areas(bounds.size());
+
+        self.draw_axes(&mut frame, &areas, &style);
+        self.draw_legend(&mut frame, &areas, &style);
+
+        let hovered = cursor
+            .position_in(bounds)
+            .and_then(|position| self.category_at(&areas, position));
+
+        if let (Some(index), Kind::Bar) = (hovered, self.kind) {
+            self.draw_hover(&mut frame, &areas, &style, index);
+            self.draw_series(&mut frame, &areas);
+        } else {
+            self.draw_series(&mut frame, &areas);
+            if let Some(index) = hovered {
+                self.draw_hover(&mut frame, &areas, &style, index);
+            }
+        }
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &State,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        let areas = self.areas(bounds.size());
+
+        match cursor.position_in(bounds) {
+            Some(position) if areas.plot.contains(position) => mouse::Interaction::Crosshair,
+            _ => mouse::Interaction::default(),
+        }
+    }
+}
+
+impl<'a, Message, Theme> From<Chart<'a, Message, Theme>> for Element<'a, Message, Theme>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+{
+    fn from(value: Chart<'a, Message, Theme>) -> Self {
+        let (width, height) = (value.width, value.height);
+        Element::new(Canvas::new(value).width(width).height(height))
+    }
+}
+
+/// Creates a new empty line [`Chart`].
+///
+/// ```
+/// use more_iced_aw::chart::{Chart, Series, line_chart};
+///
+/// let chart: Chart<'_, ()> = line_chart()
+///     .labels(["Mon", "Tue", "Wed"])
+///     .push(Series::new("Visits", [12., 18., 9.]))
+///     .push(Series::new("Sales", [3., 5., 2.]));
+/// ```
+pub fn line_chart<'a, Message, Theme>() -> Chart<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    Chart::new(Kind::Line)
+}
+
+/// Creates a new empty bar [`Chart`].
+pub fn bar_chart<'a, Message, Theme>() -> Chart<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    Chart::new(Kind::Bar)
+}
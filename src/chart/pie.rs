@@ -0,0 +1,502 @@
+use std::f32::consts::PI;
+
+use iced::{
+    Color, Element, Length, Pixels, Point, Radians, Rectangle, Renderer, Size, Vector, alignment,
+    mouse::{self, Cursor},
+    widget::canvas::{
+        self, Canvas, Frame, Geometry, Path, Program, Text, event,
+        path::{Arc, Builder},
+    },
+};
+
+use super::{CHAR_WIDTH, Catalog, SERIES_COLORS, Style, StyleFn};
+
+/// How far the hovered slice of a [`Pie`] moves out of the center.
+const EXPLODE: f32 = 8.;
+/// The smallest fraction of a slice with a percentage label.
+const MIN_LABELED: f32 = 0.04;
+
+/// A slice of a [`Pie`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Slice {
+    /// The label of the slice, shown in the legend.
+    pub label: String,
+    /// The value of the slice. Negative values count as zero.
+    pub value: f32,
+    /// The color of the slice, or `None` to take the next of the [`SERIES_COLORS`].
+    #[cfg_attr(feature = "serde", serde(with = "crate::helpers::serde_iced"))]
+    pub color: Option<Color>,
+}
+
+impl Slice {
+    /// Creates a new [`Slice`].
+    pub fn new(label: impl Into<String>, value: f32) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            color: None,
+        }
+    }
+
+    /// Sets the color of the [`Slice`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// The state of a [`Pie`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PieState {
+    hovered: Option<usize>,
+}
+
+/// The areas of a [`Pie`].
+#[derive(Debug, Clone, Copy)]
+struct Areas {
+    center: Point,
+    radius: f32,
+    inner_radius: f32,
+    /// The position of the first entry of the legend, if it is shown.
+    legend: Option<Point>,
+}
+
+/// A pie or donut chart, created with [`pie_chart`] or [`donut_chart`].
+pub struct Pie<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    slices: Vec<Slice>,
+    hole: f32,
+    legend: bool,
+    labels: bool,
+    on_slice_click: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    text_size: Pixels,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> Pie<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new empty [`Pie`].
+    pub fn new() -> Self {
+        Self {
+            slices: Vec::new(),
+            hole: 0.,
+            legend: true,
+            labels: true,
+            on_slice_click: None,
+            text_size: Pixels(12.),
+            width: Length::Fill,
+            height: Length::Fixed(240.),
+            class: Theme::default(),
+        }
+    }
+
+    /// Adds a [`Slice`] to the [`Pie`].
+    pub fn push(mut self, slice: Slice) -> Self {
+        self.slices.push(slice);
+        self
+    }
+
+    /// Adds several [`Slice`]s to the [`Pie`].
+    pub fn extend(mut self, slices: impl IntoIterator<Item = Slice>) -> Self {
+        self.slices.extend(slices);
+        self
+    }
+
+    /// Sets the radius of the hole in the middle, relatively to the radius of the [`Pie`].
+    ///
+    /// A hole makes a donut chart. Defaults to `0`.
+    pub fn hole(mut self, hole: f32) -> Self {
+        self.hole = hole.clamp(0., 0.95);
+        self
+    }
+
+    /// Sets whether the legend is shown. Defaults to `true`.
+    pub fn legend(mut self, legend: bool) -> Self {
+        self.legend = legend;
+        self
+    }
+
+    /// Sets whether the percentages are shown on the slices. Defaults to `true`.
+    pub fn labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Sets the message produced when a slice, or its entry in the legend, is clicked.
+    pub fn on_slice_click(mut self, on_slice_click: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_slice_click = Some(Box::new(on_slice_click));
+        self
+    }
+
+    /// Sets the text size of the labels of the [`Pie`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = text_size.into();
+        self
+    }
+
+    /// Sets the width of the [`Pie`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Pie`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`Pie`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Pie`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the percentage of the total of each slice.
+    ///
+    /// ```
+    /// use more_iced_aw::chart::{Pie, Slice};
+    ///
+    /// let pie: Pie<'_, ()> = Pie::new()
+    ///     .push(Slice::new("Rust", 3.))
+    ///     .push(Slice::new("Other", 1.));
+    ///
+    /// assert_eq!(pie.percentages(), vec![75., 25.]);
+    /// ```
+    pub fn percentages(&self) -> Vec<f32> {
+        self.fractions()
+            .into_iter()
+            .map(|fraction| fraction * 100.)
+            .collect()
+    }
+
+    /// Returns the fraction of the total of each slice.
+    fn fractions(&self) -> Vec<f32> {
+        let total: f32 = self.slices.iter().map(|slice| slice.value.max(0.)).sum();
+
+        self.slices
+            .iter()
+            .map(|slice| {
+                if total > 0. {
+                    slice.value.max(0.) / total
+                } else {
+                    0.
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the start and end angles of each slice, clockwise from the top.
+    fn angles(&self) -> Vec<(f32, f32)> {
+        let mut start = -PI / 2.;
+
+        self.fractions()
+            .into_iter()
+            .map(|fraction| {
+                let end = start + fraction * 2. * PI;
+                let angles = (start, end);
+                start = end;
+                angles
+            })
+            .collect()
+    }
+
+    fn color(&self, index: usize) -> Color {
+        self.slices[index]
+            .color
+            .unwrap_or(SERIES_COLORS[index % SERIES_COLORS.len()])
+    }
+
+    /// Returns the approximate width of a text.
+    fn text_width(&self, text: &str) -> f32 {
+        text.chars().count() as f32 * self.text_size.0 * CHAR_WIDTH
+    }
+
+    /// Returns the height of an entry of the legend.
+    fn legend_line(&self) -> f32 {
+        self.text_size.0 * 1.5
+    }
+
+    fn areas(&self, size: Size) -> Areas {
+        let legend_width = if self.legend && !self.slices.is_empty() {
+            self.slices
+                .iter()
+                .map(|slice| self.text_width(&slice.label))
+                .fold(0., f32::max)
+                + self.text_size.0
+                + 16.
+        } else {
+            0.
+        };
+
+        let chart = Size::new((size.width - legend_width).max(0.), size.height);
+        let radius = (chart.width.min(chart.height) / 2. - EXPLODE).max(0.);
+        let legend_height = self.slices.len() as f32 * self.legend_line();
+
+        Areas {
+            center: Point::new(chart.width / 2., chart.height / 2.),
+            radius,
+            inner_radius: radius * self.hole,
+            legend: (legend_width > 0.).then(|| {
+                Point::new(
+                    chart.width + 8.,
+                    ((size.height - legend_height) / 2.).max(0.),
+                )
+            }),
+        }
+    }
+
+    /// Returns the index of the slice, or of the legend entry, under the given position.
+    fn slice_at(&self, areas: &Areas, position: Point) -> Option<usize> {
+        if let Some(legend) = areas.legend
+            && position.x >= legend.x
+        {
+            let index = ((position.y - legend.y) / self.legend_line()).floor();
+            return (index >= 0. && (index as usize) < self.slices.len()).then_some(index as usize);
+        }
+
+        let offset = position - areas.center;
+        let distance = offset.x.hypot(offset.y);
+        if distance < areas.inner_radius || distance > areas.radius + EXPLODE {
+            return None;
+        }
+
+        // The angle clockwise from the top, in the same range as the slices.
+        let mut angle = offset.y.atan2(offset.x);
+        if angle < -PI / 2. {
+            angle += 2. * PI;
+        }
+
+        self.angles()
+            .iter()
+            .position(|(start, end)| *start <= angle && angle < *end)
+    }
+
+    fn slice_path(&self, areas: &Areas, center: Point, start: f32, end: f32) -> Path {
+        let mut builder = Builder::new();
+
+        builder.arc(Arc {
+            center,
+            radius: areas.radius,
+            start_angle: Radians(start),
+            end_angle: Radians(end),
+        });
+
+        if areas.inner_radius > 0. {
+            builder.line_to(center + Vector::new(end.cos(), end.sin()) * areas.inner_radius);
+            builder.arc(Arc {
+                center,
+                radius: areas.inner_radius,
+                start_angle: Radians(end),
+                end_angle: Radians(start),
+            });
+        } else {
+            builder.line_to(center);
+        }
+
+        builder.close();
+        builder.build()
+    }
+}
+
+impl<Message, Theme> Default for Pie<'_, Message, Theme>
+where
+    Theme: Catalog,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message, Theme> Program<Message, Theme> for Pie<'_, Message, Theme>
+where
+    Theme: Catalog,
+{
+    type State = PieState;
+
+    fn update(
+        &self,
+        state: &mut PieState,
+        event: canvas::Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> (event::Status, Option<Message>) {
+        let areas = self.areas(bounds.size());
+        let hovered = cursor
+            .position_in(bounds)
+            .and_then(|position| self.slice_at(&areas, position));
+
+        match event {
+            canvas::Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft) => {
+                state.hovered = hovered;
+                (event::Status::Ignored, None)
+            }
+            canvas::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                match (hovered, &self.on_slice_click) {
+                    (Some(index), Some(on_slice_click)) => {
+                        (event::Status::Captured, Some(on_slice_click(index)))
+                    }
+                    _ => (event::Status::Ignored, None),
+                }
+            }
+            _ => (event::Status::Ignored, None),
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &PieState,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let style = theme.style(&self.class);
+        let mut frame = Frame::new(renderer, bounds.size());
+        let areas = self.areas(bounds.size());
+        let fractions = self.fractions();
+
+        for (index, (start, end)) in self.angles().into_iter().enumerate() {
+            if end <= start {
+                continue;
+            }
+
+            let middle = (start + end) / 2.;
+            let direction = Vector::new(middle.cos(), middle.sin());
+            let center = if state.hovered == Some(index) {
+                areas.center + direction * EXPLODE
+            } else {
+                areas.center
+            };
+
+            frame.fill(
+                &self.slice_path(&areas, center, start, end),
+                self.color(index),
+            );
+
+            if self.labels && fractions[index] >= MIN_LABELED {
+                let distance = if areas.inner_radius > 0. {
+                    (areas.radius + areas.inner_radius) / 2.
+                } else {
+                    areas.radius * 0.65
+                };
+
+                frame.fill_text(Text {
+                    content: format!("{:.0}%", fractions[index] * 100.),
+                    position: center + direction * distance,
+                    color: Color::WHITE,
+                    size: self.text_size,
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    ..Text::default()
+                });
+            }
+        }
+
+        if areas.inner_radius > 0.
+            && let Some(index) = state.hovered
+        {
+            frame.fill_text(Text {
+                content: format!(
+                    "{}\n{:.1}%",
+                    self.slices[index].label,
+                    fractions[index] * 100.
+                ),
+                position: areas.center,
+                color: style.text,
+                size: self.text_size,
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                ..Text::default()
+            });
+        }
+
+        if let Some(legend) = areas.legend {
+            let line = self.legend_line();
+            let size = self.text_size.0;
+
+            for (index, slice) in self.slices.iter().enumerate() {
+                let y = legend.y + index as f32 * line;
+
+                if state.hovered == Some(index) {
+                    frame.fill_rectangle(
+                        Point::new(legend.x - 4., y),
+                        Size::new(bounds.width - legend.x + 4., line),
+                        style.readout,
+                    );
+                }
+                frame.fill_rectangle(
+                    Point::new(legend.x, y + (line - size) / 2.),
+                    Size::new(size, size),
+                    self.color(index),
+                );
+                frame.fill_text(Text {
+                    content: slice.label.clone(),
+                    position: Point::new(legend.x + size + 6., y + line / 2.),
+                    color: style.text,
+                    size: self.text_size,
+                    vertical_alignment: alignment::Vertical::Center,
+                    ..Text::default()
+                });
+            }
+        }
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &PieState,
+        _bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> mouse::Interaction {
+        if state.hovered.is_some() && self.on_slice_click.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme> From<Pie<'a, Message, Theme>> for Element<'a, Message, Theme>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+{
+    fn from(value: Pie<'a, Message, Theme>) -> Self {
+        let (width, height) = (value.width, value.height);
+        Element::new(Canvas::new(value).width(width).height(height))
+    }
+}
+
+/// Creates a new empty pie chart.
+pub fn pie_chart<'a, Message, Theme>() -> Pie<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    Pie::new()
+}
+
+/// Creates a new empty donut chart, a [`Pie`] with a hole of half its radius.
+pub fn donut_chart<'a, Message, Theme>() -> Pie<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    Pie::new().hole(0.5)
+}
@@ -0,0 +1,285 @@
+//! A group of toggleable filter chips, typically shown above a table or a gallery.
+//!
+//! The chips wrap to the next line when there is no more room, and a "clear filters" chip
+//! deselects them all when some are selected.
+
+use std::{collections::HashSet, hash::Hash, rc::Rc};
+
+use iced::{
+    Background, Border, Color, Element, Pixels, Theme,
+    widget::{button, text},
+};
+
+use crate::{
+    helpers::{color_states, readable_text_color_or},
+    wrap::Wrap,
+};
+
+/// How many chips of a [`ChipGroup`] can be selected at once.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Selection {
+    /// At most one chip is selected: selecting a chip deselects the others.
+    Single,
+    /// Any number of chips are selected.
+    #[default]
+    Multiple,
+}
+
+/// The status of a chip of a [`ChipGroup`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// The chip can be clicked.
+    Active,
+    /// The chip is hovered.
+    Hovered,
+    /// The chip is pressed.
+    Pressed,
+}
+
+impl From<button::Status> for Status {
+    fn from(value: button::Status) -> Self {
+        match value {
+            button::Status::Hovered => Status::Hovered,
+            button::Status::Pressed => Status::Pressed,
+            button::Status::Active | button::Status::Disabled => Status::Active,
+        }
+    }
+}
+
+/// The appearance of a chip of a [`ChipGroup`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the chip.
+    pub background: Background,
+    /// The color of the label of the chip.
+    pub text_color: Color,
+    /// The border of the chip.
+    pub border: Border,
+}
+
+/// The theme catalog of a [`ChipGroup`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a chip of a class, selected or not.
+    fn style(&self, class: &Self::Class<'_>, status: Status, selected: bool) -> Style;
+}
+
+/// A styling function for a [`ChipGroup`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status, bool) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status, selected: bool) -> Style {
+        class(self, status, selected)
+    }
+}
+
+/// The default style of a chip of a [`ChipGroup`], selected or not.
+pub fn default(theme: &Theme, status: Status, selected: bool) -> Style {
+    let palette = theme.extended_palette();
+    let pair = if selected {
+        palette.primary.base
+    } else {
+        palette.background.weak
+    };
+    let states = color_states(pair.color);
+    let background = match status {
+        Status::Active => states.active,
+        Status::Hovered => states.hovered,
+        Status::Pressed => states.pressed,
+    };
+
+    Style {
+        background: background.into(),
+        text_color: readable_text_color_or(background, pair.text, 4.5),
+        border: Border {
+            color: palette.background.strong.color,
+            width: if selected { 0. } else { 1. },
+            radius: f32::INFINITY.into(),
+        },
+    }
+}
+
+/// A group of toggleable filter chips, created with [`chip_group`].
+///
+/// Each click produces the new set of selected chips with the function given to [`ChipGroup::new`].
+pub struct ChipGroup<'a, Id, Message> {
+    chips: Vec<(Id, String)>,
+    selected: HashSet<Id>,
+    on_change: Box<dyn Fn(HashSet<Id>) -> Message + 'a>,
+    selection: Selection,
+    clear: Option<String>,
+    text_size: Pixels,
+    spacing: f32,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Id, Message> ChipGroup<'a, Id, Message>
+where
+    Id: Clone + Eq + Hash,
+{
+    /// Creates a new [`ChipGroup`].
+    ///
+    /// It expects:
+    ///   * the chips, as pairs of an id and a label
+    ///   * the ids of the selected chips
+    ///   * a function that will be called with the new selected ids when a chip is clicked.
+    pub fn new(
+        chips: impl IntoIterator<Item = (Id, impl Into<String>)>,
+        selected: &HashSet<Id>,
+        on_change: impl Fn(HashSet<Id>) -> Message + 'a,
+    ) -> Self {
+        Self {
+            chips: chips
+                .into_iter()
+                .map(|(id, label)| (id, label.into()))
+                .collect(),
+            selected: selected.clone(),
+            on_change: Box::new(on_change),
+            selection: Selection::default(),
+            clear: Some("Clear filters".to_owned()),
+            text_size: Pixels(14.),
+            spacing: 6.,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets how many chips can be selected at once.
+    pub fn selection(mut self, selection: Selection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Sets the label of the chip clearing the selection, or hides it with `None`.
+    pub fn clear(mut self, label: Option<impl Into<String>>) -> Self {
+        self.clear = label.map(Into::into);
+        self
+    }
+
+    /// Sets the text size of the chips.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into();
+        self
+    }
+
+    /// Sets the spacing between the chips.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the style of the chips.
+    pub fn style(mut self, style: impl Fn(&Theme, Status, bool) -> Style + 'a) -> Self {
+        self.class = Box::new(style);
+        self
+    }
+
+    /// Sets the style class of the chips.
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the selected ids after a click on the chip with the given id.
+    fn toggled(&self, id: &Id) -> HashSet<Id> {
+        let mut selected = match self.selection {
+            Selection::Single => HashSet::new(),
+            Selection::Multiple => self.selected.clone(),
+        };
+
+        if self.selected.contains(id) {
+            selected.remove(id);
+        } else {
+            selected.insert(id.clone());
+        }
+
+        selected
+    }
+}
+
+impl<'a, Id, Message> From<ChipGroup<'a, Id, Message>> for Element<'a, Message>
+where
+    Id: Clone + Eq + Hash + 'a,
+    Message: Clone + 'a,
+{
+    fn from(value: ChipGroup<'a, Id, Message>) -> Self {
+        let toggles: Vec<_> = value
+            .chips
+            .iter()
+            .map(|(id, label)| {
+                (
+                    label.clone(),
+                    value.selected.contains(id),
+                    (value.on_change)(value.toggled(id)),
+                )
+            })
+            .collect();
+        let clear = value
+            .clear
+            .filter(|_| !value.selected.is_empty())
+            .map(|label| (label, (value.on_change)(HashSet::new())));
+
+        let text_size = value.text_size;
+        let class = Rc::new(value.class);
+
+        let mut chips: Vec<Element<'a, Message>> = toggles
+            .into_iter()
+            .map(|(label, selected, on_press)| {
+                let class = class.clone();
+
+                button(text(label).size(text_size))
+                    .padding([4, 12])
+                    .on_press(on_press)
+                    .style(move |theme: &Theme, status| {
+                        let chip = Catalog::style(theme, &class, status.into(), selected);
+
+                        button::Style {
+                            background: Some(chip.background),
+                            text_color: chip.text_color,
+                            border: chip.border,
+                            ..button::Style::default()
+                        }
+                    })
+                    .into()
+            })
+            .collect();
+
+        if let Some((label, on_press)) = clear {
+            chips.push(
+                button(text(label).size(text_size))
+                    .padding([4, 12])
+                    .on_press(on_press)
+                    .style(button::text)
+                    .into(),
+            );
+        }
+
+        Wrap::with_children(chips)
+            .spacing(value.spacing)
+            .line_spacing(value.spacing)
+            .into()
+    }
+}
+
+/// Creates a new [`ChipGroup`].
+pub fn chip_group<'a, Id, Message>(
+    chips: impl IntoIterator<Item = (Id, impl Into<String>)>,
+    selected: &HashSet<Id>,
+    on_change: impl Fn(HashSet<Id>) -> Message + 'a,
+) -> ChipGroup<'a, Id, Message>
+where
+    Id: Clone + Eq + Hash,
+{
+    ChipGroup::new(chips, selected, on_change)
+}
@@ -0,0 +1,875 @@
+//! A code editor with a gutter, bracket matching and syntax highlighting.
+//!
+//! The editor is iced's [`text_editor`](iced::widget::text_editor), and edits the same
+//! [`Content`]. It adds a gutter with the line numbers and markers on the lines that open an
+//! indented block, highlights the bracket matching the one at the cursor, and makes Tab indent.
+//!
+//! With the `highlighter` feature, the code is highlighted with [syntect](https://docs.rs/syntect)
+//! through iced's highlighter. See [`CodeEditor::language`] and [`CodeEditor::highlight_theme`].
+//!
+//! Folding itself is not supported by iced's editor: the fold markers only show the foldable
+//! blocks.
+//!
+//! The editor itself keeps the styling of iced's text editor: the [`Catalog`] of this module only
+//! styles the gutter.
+
+use std::ops::Range;
+
+use iced::{
+    Background, Color, Element, Font, Length, Padding, Pixels, Point, Rectangle, Renderer, Size,
+    Theme, Vector,
+    advanced::{
+        self, Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, renderer,
+        text::{LineHeight, Renderer as _, Shaping, Text, Wrapping, highlighter},
+        widget::{Id, Operation, Tree, operation, tree},
+    },
+    alignment, event, font, keyboard,
+    widget::{
+        row, scrollable,
+        text_editor::{self, Binding, Content, KeyPress, Motion, TextEditor},
+    },
+};
+
+/// What the Tab key does in a [`CodeEditor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TabBehavior {
+    /// Inserts spaces up to the next multiple of the given width.
+    /// Shift+Tab removes up to this many spaces at the start of the line.
+    Spaces(u8),
+    /// Inserts a tab character. Shift+Tab removes a tab at the start of the line.
+    Character,
+    /// Ignores the Tab key, which is left to the application.
+    Ignore,
+}
+
+impl Default for TabBehavior {
+    fn default() -> Self {
+        Self::Spaces(4)
+    }
+}
+
+/// The appearance of the gutter of a [`CodeEditor`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the gutter.
+    pub background: Background,
+    /// The color of the line numbers and of the fold markers.
+    pub text_color: Color,
+    /// The color of the number of the line of the cursor.
+    pub current_line_color: Color,
+}
+
+/// The theme catalog of a [`CodeEditor`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`CodeEditor`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of the gutter of a [`CodeEditor`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weak.color.into(),
+        text_color: palette.background.strong.color,
+        current_line_color: palette.background.base.text,
+    }
+}
+
+/// A code editor, created with [`code_editor`].
+///
+/// Without [`CodeEditor::on_action`], the editor is read-only.
+pub struct CodeEditor<'a, Message> {
+    content: &'a Content,
+    on_action: Option<Box<dyn Fn(text_editor::Action) -> Message + 'a>>,
+    tab: TabBehavior,
+    line_numbers: bool,
+    fold_markers: bool,
+    bracket_matching: bool,
+    #[cfg(feature = "highlighter")]
+    syntax: iced::highlighter::Settings,
+    text_size: Pixels,
+    font: Font,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Message> CodeEditor<'a, Message> {
+    /// Creates a new [`CodeEditor`] displaying the given [`Content`].
+    pub fn new(content: &'a Content) -> Self {
+        Self {
+            content,
+            on_action: None,
+            tab: TabBehavior::default(),
+            line_numbers: true,
+            fold_markers: true,
+            bracket_matching: true,
+            #[cfg(feature = "highlighter")]
+            syntax: iced::highlighter::Settings {
+                theme: iced::highlighter::Theme::SolarizedDark,
+                token: String::new(),
+            },
+            text_size: Pixels(14.),
+            font: Font::MONOSPACE,
+            padding: Padding::new(4.),
+            width: Length::Fill,
+            height: Length::Fill,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets the message produced with the [`Action`](text_editor::Action)s of the user, which
+    /// the application applies to the [`Content`] with [`Content::perform`].
+    ///
+    /// Without it, the [`CodeEditor`] is read-only.
+    pub fn on_action(mut self, on_action: impl Fn(text_editor::Action) -> Message + 'a) -> Self {
+        self.on_action = Some(Box::new(on_action));
+        self
+    }
+
+    /// Sets the [`TabBehavior`] of the [`CodeEditor`]. By default, Tab inserts 4 spaces.
+    pub fn tab(mut self, tab: TabBehavior) -> Self {
+        self.tab = tab;
+        self
+    }
+
+    /// Sets whether the line numbers are displayed in the gutter.
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Sets whether the lines that open an indented block are marked in the gutter.
+    pub fn fold_markers(mut self, fold_markers: bool) -> Self {
+        self.fold_markers = fold_markers;
+        self
+    }
+
+    /// Sets whether the bracket matching the one at the cursor is highlighted.
+    pub fn bracket_matching(mut self, bracket_matching: bool) -> Self {
+        self.bracket_matching = bracket_matching;
+        self
+    }
+
+    /// Sets the language of the code, as a name or a file extension (like `"rs"`).
+    #[cfg(feature = "highlighter")]
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.syntax.token = language.into();
+        self
+    }
+
+    /// Sets the theme of the syntax highlighting.
+    #[cfg(feature = "highlighter")]
+    pub fn highlight_theme(mut self, theme: iced::highlighter::Theme) -> Self {
+        self.syntax.theme = theme;
+        self
+    }
+
+    /// Sets the text size of the [`CodeEditor`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = text_size.into();
+        self
+    }
+
+    /// Sets the font of the [`CodeEditor`]. It should be monospaced.
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the padding around the text.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`CodeEditor`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`CodeEditor`]. The code scrolls when it is taller.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the gutter.
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self {
+        self.class = Box::new(style);
+        self
+    }
+
+    /// Sets the style class of the gutter.
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// Creates a new [`CodeEditor`] displaying the given [`Content`].
+pub fn code_editor<'a, Message>(content: &'a Content) -> CodeEditor<'a, Message> {
+    CodeEditor::new(content)
+}
+
+/// Returns the indentation width of a line, counting tabs as 4 columns.
+fn indentation(line: &str) -> usize {
+    line.chars()
+        .map_while(|c| match c {
+            ' ' => Some(1),
+            '\t' => Some(4),
+            _ => None,
+        })
+        .sum()
+}
+
+/// Returns, for each line, whether it opens an indented block.
+fn foldable(lines: &[String]) -> Vec<bool> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            !line.trim().is_empty()
+                && lines[i + 1..]
+                    .iter()
+                    .find(|next| !next.trim().is_empty())
+                    .is_some_and(|next| indentation(next) > indentation(line))
+        })
+        .collect()
+}
+
+/// Returns the bracket pair of a bracket, opening first.
+fn pair(bracket: char) -> Option<(char, char)> {
+    match bracket {
+        '(' | ')' => Some(('(', ')')),
+        '[' | ']' => Some(('[', ']')),
+        '{' | '}' => Some(('{', '}')),
+        _ => None,
+    }
+}
+
+/// Returns the positions (line and byte) of the bracket at or before the cursor and of the
+/// bracket matching it.
+fn matching_brackets(
+    lines: &[String],
+    (line, column): (usize, usize),
+) -> Option<[(usize, usize); 2]> {
+    let text = lines.get(line)?;
+    if !text.is_char_boundary(column) {
+        return None;
+    }
+
+    let at = text[column..].chars().next().map(|c| (column, c));
+    let before = text[..column]
+        .chars()
+        .next_back()
+        .map(|c| (column - c.len_utf8(), c));
+    let (start, bracket) = at
+        .filter(|(_, c)| pair(*c).is_some())
+        .or(before.filter(|(_, c)| pair(*c).is_some()))?;
+    let (open, close) = pair(bracket)?;
+
+    let mut depth = 0;
+    let mut visit = |l: usize, (i, c): (usize, char)| {
+        if c == bracket {
+            depth += 1;
+        } else if c == open || c == close {
+            if depth == 0 {
+                return Some((l, i));
+            }
+            depth -= 1;
+        }
+        None
+    };
+
+    let found = if bracket == open {
+        let rest = text[start + 1..]
+            .char_indices()
+            .map(|(i, c)| (line, (start + 1 + i, c)));
+        let next = lines
+            .iter()
+            .enumerate()
+            .skip(line + 1)
+            .flat_map(|(l, text)| text.char_indices().map(move |c| (l, c)));
+
+        rest.chain(next).find_map(|(l, c)| visit(l, c))
+    } else {
+        let rest = text[..start].char_indices().rev().map(|c| (line, c));
+        let previous = lines
+            .iter()
+            .enumerate()
+            .take(line)
+            .rev()
+            .flat_map(|(l, text)| text.char_indices().rev().map(move |c| (l, c)));
+
+        rest.chain(previous).find_map(|(l, c)| visit(l, c))
+    }?;
+
+    Some([(line, start), found])
+}
+
+/// Returns the binding of the Tab key, or the default binding of the key press.
+fn tab_binding<Message>(
+    tab: TabBehavior,
+    line: &str,
+    column: usize,
+    key_press: KeyPress,
+) -> Option<Binding<Message>> {
+    let is_tab = key_press.key == keyboard::Key::Named(keyboard::key::Named::Tab);
+
+    if !is_tab || tab == TabBehavior::Ignore || key_press.status != text_editor::Status::Focused {
+        return Binding::from_key_press(key_press);
+    }
+
+    if !key_press.modifiers.shift() {
+        return Some(match tab {
+            TabBehavior::Spaces(width) => {
+                let width = usize::from(width.max(1));
+                Binding::Sequence(
+                    (0..width - column % width)
+                        .map(|_| Binding::Insert(' '))
+                        .collect(),
+                )
+            }
+            _ => Binding::Insert('\t'),
+        });
+    }
+
+    let removed = match tab {
+        TabBehavior::Spaces(width) => line
+            .chars()
+            .take(width.into())
+            .take_while(|c| *c == ' ')
+            .count(),
+        _ => usize::from(line.starts_with('\t')),
+    };
+
+    let mut sequence = vec![Binding::Move(Motion::Home)];
+    sequence.extend((0..removed).map(|_| Binding::Delete));
+    sequence.extend((removed..column).map(|_| Binding::Move(Motion::Right)));
+    Some(Binding::Sequence(sequence))
+}
+
+/// The settings of the [`Highlighter`] of a [`CodeEditor`].
+#[derive(Debug, Clone, PartialEq)]
+struct Settings {
+    /// The positions of the matching brackets, if any.
+    brackets: Option<[(usize, usize); 2]>,
+    #[cfg(feature = "highlighter")]
+    syntax: iced::highlighter::Settings,
+}
+
+/// A highlight of a [`CodeEditor`].
+enum Highlight {
+    /// A matching bracket.
+    Bracket,
+    /// A syntax highlight.
+    #[cfg(feature = "highlighter")]
+    Syntax(iced::highlighter::Highlight),
+}
+
+/// Returns the format of a [`Highlight`].
+fn to_format(highlight: &Highlight, theme: &Theme) -> highlighter::Format<Font> {
+    match highlight {
+        Highlight::Bracket => highlighter::Format {
+            color: Some(theme.extended_palette().primary.strong.color),
+            font: Some(Font {
+                weight: font::Weight::Bold,
+                ..Font::MONOSPACE
+            }),
+        },
+        #[cfg(feature = "highlighter")]
+        Highlight::Syntax(highlight) => highlight.to_format(),
+    }
+}
+
+/// Highlights the matching brackets over the syntax highlighting.
+struct Highlighter {
+    settings: Settings,
+    current_line: usize,
+    #[cfg(feature = "highlighter")]
+    syntax: iced::highlighter::Highlighter,
+}
+
+impl highlighter::Highlighter for Highlighter {
+    type Settings = Settings;
+    type Highlight = Highlight;
+    type Iterator<'a> = std::vec::IntoIter<(Range<usize>, Highlight)>;
+
+    fn new(settings: &Settings) -> Self {
+        Self {
+            settings: settings.clone(),
+            current_line: 0,
+            #[cfg(feature = "highlighter")]
+            syntax: <iced::highlighter::Highlighter as highlighter::Highlighter>::new(
+                &settings.syntax,
+            ),
+        }
+    }
+
+    fn update(&mut self, new_settings: &Settings) {
+        #[cfg(feature = "highlighter")]
+        if new_settings.syntax != self.settings.syntax {
+            highlighter::Highlighter::update(&mut self.syntax, &new_settings.syntax);
+            self.current_line = 0;
+        }
+
+        let changed = self
+            .settings
+            .brackets
+            .into_iter()
+            .chain(new_settings.brackets)
+            .flatten()
+            .map(|(line, _)| line)
+            .min();
+        if let Some(line) = changed {
+            self.change_line(line);
+        }
+
+        self.settings = new_settings.clone();
+    }
+
+    fn change_line(&mut self, line: usize) {
+        #[cfg(feature = "highlighter")]
+        {
+            highlighter::Highlighter::change_line(&mut self.syntax, line);
+            self.current_line = highlighter::Highlighter::current_line(&self.syntax);
+        }
+        #[cfg(not(feature = "highlighter"))]
+        {
+            self.current_line = self.current_line.min(line);
+        }
+    }
+
+    fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
+        #[cfg(feature = "highlighter")]
+        let mut highlights: Vec<_> =
+            highlighter::Highlighter::highlight_line(&mut self.syntax, line)
+                .map(|(range, highlight)| (range, Highlight::Syntax(highlight)))
+                .collect();
+        #[cfg(not(feature = "highlighter"))]
+        let mut highlights = {
+            let _ = line;
+            Vec::new()
+        };
+
+        let index = self.current_line;
+        self.current_line += 1;
+
+        highlights.extend(
+            self.settings
+                .brackets
+                .into_iter()
+                .flatten()
+                .filter(|(line, _)| *line == index)
+                .map(|(_, start)| (start..start + 1, Highlight::Bracket)),
+        );
+
+        highlights.into_iter()
+    }
+
+    fn current_line(&self) -> usize {
+        self.current_line
+    }
+}
+
+impl<'a, Message: Clone + 'a> From<CodeEditor<'a, Message>> for Element<'a, Message> {
+    fn from(value: CodeEditor<'a, Message>) -> Self {
+        let content = value.content;
+        let lines: Vec<String> = content.lines().map(|line| line.to_owned()).collect();
+        let cursor = content.cursor_position();
+        let line_height = LineHeight::default().to_absolute(value.text_size).0;
+
+        let settings = Settings {
+            brackets: value
+                .bracket_matching
+                .then(|| matching_brackets(&lines, cursor))
+                .flatten(),
+            #[cfg(feature = "highlighter")]
+            syntax: value.syntax,
+        };
+
+        let mut editor = TextEditor::new(content)
+            .font(value.font)
+            .size(value.text_size)
+            .padding(value.padding)
+            .wrapping(Wrapping::None)
+            .highlight_with::<Highlighter>(settings, to_format);
+
+        if let Some(on_action) = value.on_action {
+            let tab = value.tab;
+            let line = lines.get(cursor.0).cloned().unwrap_or_default();
+            editor = editor
+                .on_action(on_action)
+                .key_binding(move |key_press| tab_binding(tab, &line, cursor.1, key_press));
+        }
+
+        let gutter = (value.line_numbers || value.fold_markers).then(|| Gutter {
+            lines: lines.len(),
+            current: cursor.0,
+            numbers: value.line_numbers,
+            folds: if value.fold_markers {
+                foldable(&lines)
+            } else {
+                Vec::new()
+            },
+            text_size: value.text_size.0,
+            line_height,
+            font: value.font,
+            padding: value.padding,
+            class: value.class,
+        });
+
+        let code = scrollable(row![].push_maybe(gutter.map(Element::new)).push(editor))
+            .width(value.width)
+            .height(value.height);
+
+        Element::new(Follow {
+            code: code.into(),
+            line: cursor.0,
+            line_top: value.padding.top + cursor.0 as f32 * line_height,
+            line_height,
+        })
+    }
+}
+
+/// The gutter of a [`CodeEditor`], with the line numbers and the fold markers.
+struct Gutter<'a> {
+    lines: usize,
+    current: usize,
+    numbers: bool,
+    folds: Vec<bool>,
+    text_size: f32,
+    line_height: f32,
+    font: Font,
+    padding: Padding,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl Gutter<'_> {
+    /// The horizontal space around the numbers and the markers.
+    const SPACING: f32 = 8.;
+
+    /// Returns the width of the line numbers.
+    fn numbers_width(&self) -> f32 {
+        if self.numbers {
+            let digits = self.lines.max(1).ilog10() + 1;
+            digits as f32 * self.text_size * 0.6
+        } else {
+            0.
+        }
+    }
+
+    /// Returns the width of the fold markers.
+    fn folds_width(&self) -> f32 {
+        if self.folds.is_empty() {
+            0.
+        } else {
+            self.text_size
+        }
+    }
+}
+
+impl<Message> Widget<Message, Theme, Renderer> for Gutter<'_> {
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let width = Self::SPACING * 2. + self.numbers_width() + self.folds_width();
+        let height = self.padding.vertical() + self.lines as f32 * self.line_height;
+
+        Node::new(limits.resolve(Length::Shrink, Length::Shrink, Size::new(width, height)))
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = Catalog::style(theme, &self.class);
+
+        advanced::Renderer::fill_quad(
+            renderer,
+            renderer::Quad {
+                bounds,
+                ..Default::default()
+            },
+            style.background,
+        );
+
+        let Some(visible) = bounds.intersection(viewport) else {
+            return;
+        };
+        let top = bounds.y + self.padding.top;
+        let first = ((visible.y - top) / self.line_height).floor().max(0.) as usize;
+        let last = (((visible.y + visible.height - top) / self.line_height)
+            .ceil()
+            .max(0.) as usize)
+            .min(self.lines);
+
+        let text = |content: String, x: f32, y: f32, alignment, renderer: &mut Renderer, color| {
+            renderer.fill_text(
+                Text {
+                    content,
+                    bounds: Size::new(f32::INFINITY, self.line_height),
+                    size: Pixels(self.text_size),
+                    line_height: LineHeight::Absolute(Pixels(self.line_height)),
+                    font: self.font,
+                    horizontal_alignment: alignment,
+                    vertical_alignment: alignment::Vertical::Top,
+                    shaping: Shaping::Advanced,
+                    wrapping: Wrapping::None,
+                },
+                Point::new(x, y),
+                color,
+                visible,
+            );
+        };
+
+        for line in first..last {
+            let y = top + line as f32 * self.line_height;
+            let color = if line == self.current {
+                style.current_line_color
+            } else {
+                style.text_color
+            };
+
+            if self.numbers {
+                text(
+                    (line + 1).to_string(),
+                    bounds.x + Self::SPACING + self.numbers_width(),
+                    y,
+                    alignment::Horizontal::Right,
+                    renderer,
+                    color,
+                );
+            }
+
+            if self.folds.get(line).copied().unwrap_or_default() {
+                text(
+                    "⌄".to_owned(),
+                    bounds.x + Self::SPACING + self.numbers_width() + self.folds_width() / 2.,
+                    y,
+                    alignment::Horizontal::Center,
+                    renderer,
+                    style.text_color,
+                );
+            }
+        }
+    }
+}
+
+/// The state of the [`Follow`] widget of a [`CodeEditor`].
+#[derive(Default)]
+struct FollowState {
+    /// The line of the cursor when it was last revealed.
+    revealed: Option<usize>,
+}
+
+/// Scrolls the code of a [`CodeEditor`] to keep the line of the cursor visible.
+struct Follow<'a, Message> {
+    code: Element<'a, Message>,
+    line: usize,
+    line_top: f32,
+    line_height: f32,
+}
+
+/// An [`Operation`] scrolling the first scrollable to reveal a range of its content.
+struct Reveal {
+    top: f32,
+    bottom: f32,
+}
+
+impl Operation for Reveal {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        _operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn operation::Scrollable,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        _content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        let offset = if self.top < translation.y {
+            self.top
+        } else if self.bottom > translation.y + bounds.height {
+            self.bottom - bounds.height
+        } else {
+            return;
+        };
+
+        state.scroll_to(scrollable::AbsoluteOffset {
+            x: translation.x,
+            y: offset,
+        });
+    }
+}
+
+impl<Message> Widget<Message, Theme, Renderer> for Follow<'_, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<FollowState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(FollowState::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.code)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.code));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.code.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.code
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.code.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.code
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<FollowState>();
+
+        if state.revealed != Some(self.line) {
+            state.revealed = Some(self.line);
+            self.code.as_widget().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                &mut Reveal {
+                    top: self.line_top,
+                    bottom: self.line_top + self.line_height,
+                },
+            );
+        }
+
+        self.code.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.code.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<advanced::overlay::Element<'b, Message, Theme, Renderer>> {
+        self.code
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
@@ -0,0 +1,679 @@
+//! An image cropper.
+//!
+//! The [`Cropper`] draws an image, fitted in its bounds, with a crop rectangle over it. The
+//! rectangle is moved by dragging it and resized by dragging its corners or edges. It can have a
+//! fixed aspect ratio, in which case it is only resized by its corners.
+//!
+//! The crop is confirmed by double-clicking the rectangle or pressing Enter while the cropper is
+//! hovered, which produces the rectangle in the coordinates of the pixels of the image.
+
+use iced::{
+    Background, Border, Color, Element, Length, Point, Radians, Rectangle, Size, Theme, Vector,
+    advanced::{
+        Layout, Shell, Widget,
+        image::{self, FilterMethod, Image},
+        layout::{Limits, Node},
+        mouse, renderer,
+        widget::{Tree, tree},
+    },
+    event, keyboard, touch,
+};
+
+/// The size of the handles of the crop rectangle, and the distance at which they can be grabbed.
+const HANDLE_SIZE: f32 = 10.;
+/// The minimal size of the crop rectangle on the screen.
+const MIN_SIZE: f32 = 16.;
+
+/// The appearance of a [`Cropper`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color covering the part of the image outside of the crop rectangle.
+    pub overlay: Color,
+    /// The border of the crop rectangle.
+    pub border: Border,
+    /// The color of the rule-of-thirds guides.
+    pub guides: Color,
+    /// The background of the handles.
+    pub handle: Background,
+}
+
+/// The theme catalog of a [`Cropper`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Cropper`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Cropper`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        overlay: Color::BLACK.scale_alpha(0.5),
+        border: Border {
+            color: Color::WHITE,
+            width: 1.,
+            radius: 0.into(),
+        },
+        guides: Color::WHITE.scale_alpha(0.5),
+        handle: palette.primary.base.color.into(),
+    }
+}
+
+/// A side of the crop rectangle along an axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Start,
+    End,
+    None,
+}
+
+/// A drag of the crop rectangle in progress.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Drag {
+    /// The rectangle is moved, grabbed at the given offset from its top left corner.
+    Move(Vector),
+    /// The rectangle is resized by the given sides.
+    Resize(Side, Side),
+}
+
+/// The state of a [`Cropper`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    /// The crop rectangle in image coordinates, and the aspect ratio it was made for.
+    crop: Option<(Rectangle, Option<f32>)>,
+    drag: Option<Drag>,
+    last_click: Option<mouse::Click>,
+}
+
+/// The geometry of the image on the screen.
+#[derive(Debug, Clone, Copy)]
+struct Geometry {
+    /// The size of the image in pixels.
+    image: Size,
+    /// The position of the top left corner of the image on the screen.
+    origin: Point,
+    /// The factor from the image to the screen.
+    zoom: f32,
+}
+
+impl Geometry {
+    fn to_screen(self, rectangle: Rectangle) -> Rectangle {
+        Rectangle {
+            x: self.origin.x + rectangle.x * self.zoom,
+            y: self.origin.y + rectangle.y * self.zoom,
+            width: rectangle.width * self.zoom,
+            height: rectangle.height * self.zoom,
+        }
+    }
+
+    fn to_image(self, point: Point) -> Point {
+        Point::new(
+            ((point.x - self.origin.x) / self.zoom).clamp(0., self.image.width),
+            ((point.y - self.origin.y) / self.zoom).clamp(0., self.image.height),
+        )
+    }
+}
+
+/// An image with a movable and resizable crop rectangle, created with [`cropper`].
+pub struct Cropper<'a, Message, Theme = iced::Theme, Handle = image::Handle>
+where
+    Theme: Catalog,
+{
+    handle: Handle,
+    aspect_ratio: Option<f32>,
+    guides: bool,
+    on_confirm: Option<Box<dyn Fn(Rectangle<u32>) -> Message + 'a>>,
+    on_change: Option<Box<dyn Fn(Rectangle<u32>) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Handle> Cropper<'a, Message, Theme, Handle>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Cropper`] of the image with the given handle.
+    pub fn new(handle: impl Into<Handle>) -> Self {
+        Self {
+            handle: handle.into(),
+            aspect_ratio: None,
+            guides: true,
+            on_confirm: None,
+            on_change: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            class: Theme::default(),
+        }
+    }
+
+    /// Fixes the aspect ratio (width divided by height) of the crop rectangle.
+    ///
+    /// Changing it resets the crop rectangle to the largest one with this ratio.
+    pub fn aspect_ratio(mut self, aspect_ratio: impl Into<Option<f32>>) -> Self {
+        self.aspect_ratio = aspect_ratio.into().filter(|ratio| *ratio > 0.);
+        self
+    }
+
+    /// Sets whether the rule-of-thirds guides are drawn in the crop rectangle. Defaults to `true`.
+    pub fn guides(mut self, guides: bool) -> Self {
+        self.guides = guides;
+        self
+    }
+
+    /// Sets the message produced with the crop rectangle, in image coordinates, when the crop is
+    /// confirmed.
+    pub fn on_confirm(mut self, on_confirm: impl Fn(Rectangle<u32>) -> Message + 'a) -> Self {
+        self.on_confirm = Some(Box::new(on_confirm));
+        self
+    }
+
+    /// Sets the message produced with the crop rectangle, in image coordinates, while it is moved
+    /// or resized.
+    pub fn on_change(mut self, on_change: impl Fn(Rectangle<u32>) -> Message + 'a) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Sets the width of the [`Cropper`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Cropper`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`Cropper`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Cropper`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the geometry of the image fitted in the given bounds.
+    fn geometry<Renderer>(&self, renderer: &Renderer, bounds: Rectangle) -> Geometry
+    where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        let Size { width, height } = renderer.measure_image(&self.handle);
+        let image = Size::new(width as f32, height as f32);
+        let zoom = (bounds.width / image.width.max(1.)).min(bounds.height / image.height.max(1.));
+
+        Geometry {
+            image,
+            origin: Point::new(
+                bounds.center_x() - image.width * zoom / 2.,
+                bounds.center_y() - image.height * zoom / 2.,
+            ),
+            zoom,
+        }
+    }
+
+    /// Returns the current crop rectangle, in image coordinates.
+    fn crop(&self, state: &State, image: Size) -> Rectangle {
+        match state.crop {
+            Some((crop, ratio)) if ratio == self.aspect_ratio => crop,
+            _ => {
+                let size = match self.aspect_ratio {
+                    Some(ratio) if image.width / image.height.max(1.) > ratio => {
+                        Size::new(image.height * ratio, image.height)
+                    }
+                    Some(ratio) => Size::new(image.width, image.width / ratio),
+                    None => image,
+                };
+
+                Rectangle::new(
+                    Point::new(
+                        (image.width - size.width) / 2.,
+                        (image.height - size.height) / 2.,
+                    ),
+                    size,
+                )
+            }
+        }
+    }
+
+    /// Returns the handle of the crop rectangle under the given position.
+    fn drag_at(&self, crop: Rectangle, position: Point) -> Option<Drag> {
+        let area = crop.expand(HANDLE_SIZE / 2.);
+        if !area.contains(position) {
+            return None;
+        }
+
+        let side = |position: f32, start: f32, end: f32| {
+            if (position - start).abs() <= HANDLE_SIZE / 2. {
+                Side::Start
+            } else if (position - end).abs() <= HANDLE_SIZE / 2. {
+                Side::End
+            } else {
+                Side::None
+            }
+        };
+        let horizontal = side(position.x, crop.x, crop.x + crop.width);
+        let vertical = side(position.y, crop.y, crop.y + crop.height);
+
+        match (horizontal, vertical) {
+            (Side::None, Side::None) => Some(Drag::Move(position - crop.position())),
+            // With a fixed ratio, the rectangle is only resized by its corners.
+            (Side::None, _) | (_, Side::None) if self.aspect_ratio.is_some() => {
+                Some(Drag::Move(position - crop.position()))
+            }
+            (horizontal, vertical) => Some(Drag::Resize(horizontal, vertical)),
+        }
+    }
+
+    /// Returns the crop rectangle after dragging it to the given position.
+    fn dragged(
+        &self,
+        drag: Drag,
+        crop: Rectangle,
+        geometry: Geometry,
+        position: Point,
+    ) -> Rectangle {
+        let image = geometry.image;
+        let min = MIN_SIZE / geometry.zoom.max(f32::EPSILON);
+
+        match drag {
+            Drag::Move(grab) => {
+                let x = (position.x - geometry.origin.x - grab.x) / geometry.zoom;
+                let y = (position.y - geometry.origin.y - grab.y) / geometry.zoom;
+
+                Rectangle {
+                    x: x.clamp(0., (image.width - crop.width).max(0.)),
+                    y: y.clamp(0., (image.height - crop.height).max(0.)),
+                    ..crop
+                }
+            }
+            Drag::Resize(horizontal, vertical) => {
+                let cursor = geometry.to_image(position);
+                let (mut x0, mut x1) = (crop.x, crop.x + crop.width);
+                let (mut y0, mut y1) = (crop.y, crop.y + crop.height);
+
+                if let Some(ratio) = self.aspect_ratio {
+                    // The opposite corner stays in place.
+                    let anchor_x = if horizontal == Side::Start { x1 } else { x0 };
+                    let anchor_y = if vertical == Side::Start { y1 } else { y0 };
+                    let max_width = if horizontal == Side::Start {
+                        anchor_x
+                    } else {
+                        image.width - anchor_x
+                    };
+                    let max_height = if vertical == Side::Start {
+                        anchor_y
+                    } else {
+                        image.height - anchor_y
+                    };
+
+                    let width = (cursor.x - anchor_x)
+                        .abs()
+                        .max((cursor.y - anchor_y).abs() * ratio)
+                        .max(min)
+                        .min(max_width)
+                        .min(max_height * ratio);
+                    let height = width / ratio;
+
+                    let x = if horizontal == Side::Start {
+                        anchor_x - width
+                    } else {
+                        anchor_x
+                    };
+                    let y = if vertical == Side::Start {
+                        anchor_y - height
+                    } else {
+                        anchor_y
+                    };
+
+                    return Rectangle::new(Point::new(x, y), Size::new(width, height));
+                }
+
+                match horizontal {
+                    Side::Start => x0 = cursor.x.min(x1 - min).max(0.),
+                    Side::End => x1 = cursor.x.max(x0 + min).min(image.width),
+                    Side::None => {}
+                }
+                match vertical {
+                    Side::Start => y0 = cursor.y.min(y1 - min).max(0.),
+                    Side::End => y1 = cursor.y.max(y0 + min).min(image.height),
+                    Side::None => {}
+                }
+
+                Rectangle::new(Point::new(x0, y0), Size::new(x1 - x0, y1 - y0))
+            }
+        }
+    }
+
+    /// Publishes the given message with the crop rectangle rounded to pixels.
+    fn publish(
+        shell: &mut Shell<'_, Message>,
+        on_crop: Option<&(dyn Fn(Rectangle<u32>) -> Message + 'a)>,
+        crop: Rectangle,
+    ) {
+        if let Some(on_crop) = on_crop {
+            shell.publish(on_crop(Rectangle {
+                x: crop.x.round() as u32,
+                y: crop.y.round() as u32,
+                width: crop.width.round() as u32,
+                height: crop.height.round() as u32,
+            }));
+        }
+    }
+}
+
+impl<Message, Theme, Renderer, Handle> Widget<Message, Theme, Renderer>
+    for Cropper<'_, Message, Theme, Handle>
+where
+    Theme: Catalog,
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, _tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let Size { width, height } = renderer.measure_image(&self.handle);
+
+        Node::new(limits.resolve(
+            self.width,
+            self.height,
+            Size::new(width as f32, height as f32),
+        ))
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+        let style = theme.style(&self.class);
+        let geometry = self.geometry(renderer, bounds);
+        let image = geometry.to_screen(Rectangle::with_size(geometry.image));
+        let crop = geometry.to_screen(self.crop(state, geometry.image));
+
+        renderer.with_layer(clip, |renderer| {
+            renderer.draw_image(
+                Image {
+                    handle: self.handle.clone(),
+                    filter_method: FilterMethod::Linear,
+                    rotation: Radians(0.),
+                    opacity: 1.,
+                    snap: true,
+                },
+                image,
+            );
+
+            let mut quad = |bounds: Rectangle, background: Background, border: Border| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border,
+                        ..Default::default()
+                    },
+                    background,
+                );
+            };
+
+            // The overlay, around the crop rectangle.
+            let overlay = Background::from(style.overlay);
+            let top = crop.y - image.y;
+            let bottom = image.y + image.height - crop.y - crop.height;
+            quad(
+                Rectangle::new(image.position(), Size::new(image.width, top)),
+                overlay,
+                Border::default(),
+            );
+            quad(
+                Rectangle::new(
+                    Point::new(image.x, crop.y + crop.height),
+                    Size::new(image.width, bottom),
+                ),
+                overlay,
+                Border::default(),
+            );
+            quad(
+                Rectangle::new(
+                    Point::new(image.x, crop.y),
+                    Size::new(crop.x - image.x, crop.height),
+                ),
+                overlay,
+                Border::default(),
+            );
+            quad(
+                Rectangle::new(
+                    Point::new(crop.x + crop.width, crop.y),
+                    Size::new(image.x + image.width - crop.x - crop.width, crop.height),
+                ),
+                overlay,
+                Border::default(),
+            );
+
+            if self.guides {
+                let guides = Background::from(style.guides);
+                for third in [1. / 3., 2. / 3.] {
+                    quad(
+                        Rectangle {
+                            x: crop.x + crop.width * third,
+                            y: crop.y,
+                            width: 1.,
+                            height: crop.height,
+                        },
+                        guides,
+                        Border::default(),
+                    );
+                    quad(
+                        Rectangle {
+                            x: crop.x,
+                            y: crop.y + crop.height * third,
+                            width: crop.width,
+                            height: 1.,
+                        },
+                        guides,
+                        Border::default(),
+                    );
+                }
+            }
+
+            quad(crop, Background::Color(Color::TRANSPARENT), style.border);
+
+            let xs = [crop.x, crop.center_x(), crop.x + crop.width];
+            let ys = [crop.y, crop.center_y(), crop.y + crop.height];
+            for (i, &x) in xs.iter().enumerate() {
+                for (j, &y) in ys.iter().enumerate() {
+                    let corner = i != 1 && j != 1;
+                    if (i == 1 && j == 1) || (!corner && self.aspect_ratio.is_some()) {
+                        continue;
+                    }
+
+                    quad(
+                        Rectangle {
+                            x: x - HANDLE_SIZE / 2.,
+                            y: y - HANDLE_SIZE / 2.,
+                            width: HANDLE_SIZE,
+                            height: HANDLE_SIZE,
+                        },
+                        style.handle,
+                        Border::default().rounded(2.),
+                    );
+                }
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn iced::advanced::Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let geometry = self.geometry(renderer, bounds);
+        let crop = self.crop(state, geometry.image);
+
+        match event {
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+                let Some(drag) = self.drag_at(geometry.to_screen(crop), position) else {
+                    return event::Status::Ignored;
+                };
+
+                let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+                state.last_click = Some(click);
+
+                if matches!(click.kind(), mouse::click::Kind::Double) {
+                    Self::publish(shell, self.on_confirm.as_deref(), crop);
+                } else {
+                    state.crop = Some((crop, self.aspect_ratio));
+                    state.drag = Some(drag);
+                }
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::CursorMoved { position })
+            | iced::Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(drag) = state.drag else {
+                    return event::Status::Ignored;
+                };
+
+                let dragged = self.dragged(drag, crop, geometry, position);
+                if dragged != crop {
+                    state.crop = Some((dragged, self.aspect_ratio));
+                    Self::publish(shell, self.on_change.as_deref(), dragged);
+                    shell.request_redraw(iced::window::RedrawRequest::NextFrame);
+                }
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerLifted { .. })
+            | iced::Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.drag.take().is_some() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            iced::Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Enter),
+                ..
+            }) if cursor.is_over(bounds) && self.on_confirm.is_some() => {
+                Self::publish(shell, self.on_confirm.as_deref(), crop);
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let drag = match state.drag {
+            Some(drag) => Some(drag),
+            None => cursor.position_over(bounds).and_then(|position| {
+                let geometry = self.geometry(renderer, bounds);
+                let crop = geometry.to_screen(self.crop(state, geometry.image));
+                self.drag_at(crop, position)
+            }),
+        };
+
+        match drag {
+            Some(Drag::Move(_)) if state.drag.is_some() => mouse::Interaction::Grabbing,
+            Some(Drag::Move(_)) => mouse::Interaction::Grab,
+            Some(Drag::Resize(Side::None, _)) => mouse::Interaction::ResizingVertically,
+            Some(Drag::Resize(_, Side::None)) => mouse::Interaction::ResizingHorizontally,
+            Some(Drag::Resize(horizontal, vertical)) if horizontal == vertical => {
+                mouse::Interaction::ResizingDiagonallyDown
+            }
+            Some(Drag::Resize(..)) => mouse::Interaction::ResizingDiagonallyUp,
+            None => mouse::Interaction::default(),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer, Handle> From<Cropper<'a, Message, Theme, Handle>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: image::Renderer<Handle = Handle> + 'a,
+    Handle: Clone + 'a,
+{
+    fn from(value: Cropper<'a, Message, Theme, Handle>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`Cropper`] of the image with the given handle.
+pub fn cropper<'a, Message, Theme, Handle>(
+    handle: impl Into<Handle>,
+) -> Cropper<'a, Message, Theme, Handle>
+where
+    Theme: Catalog,
+{
+    Cropper::new(handle)
+}
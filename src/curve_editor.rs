@@ -0,0 +1,530 @@
+//! An editor of a function defined by control points, drawn on a [`Canvas`].
+//!
+//! The [`CurveEditor`] displays a [`Curve`] over a grid. Its points are moved by dragging them,
+//! added by double clicking where there is none, and removed with a right click. Double clicking a
+//! point switches the segment starting at it between a line and a bezier curve, whose handles are
+//! dragged too.
+//!
+//! The editor produces a message with the edited curve, which the application stores and samples
+//! with [`Curve::sample`]. This is the usual way to edit easing functions, envelopes or transfer
+//! functions.
+
+use std::ops::RangeInclusive;
+
+use iced::{
+    Color, Element, Length, Point, Rectangle, Renderer, Size, Theme, Vector,
+    advanced::mouse::{Click, click},
+    mouse::{self, Cursor},
+    widget::canvas::{self, Canvas, Frame, Geometry, Path, Program, Stroke, event},
+};
+
+mod curve;
+pub use curve::*;
+
+/// The distance from a point or a handle under which it is grabbed.
+const GRAB_DISTANCE: f32 = 8.;
+
+/// The appearance of a [`CurveEditor`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the editor.
+    pub background: Color,
+    /// The color of the grid.
+    pub grid: Color,
+    /// The color of the curve.
+    pub curve: Color,
+    /// The width of the curve.
+    pub curve_width: f32,
+    /// The color of the points.
+    pub point: Color,
+    /// The color of the hovered or dragged point or handle.
+    pub active: Color,
+    /// The color of the handles and of their lines.
+    pub handle: Color,
+}
+
+/// The theme catalog of a [`CurveEditor`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`CurveEditor`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`CurveEditor`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weak.color,
+        grid: palette.background.strong.color,
+        curve: palette.primary.base.color,
+        curve_width: 2.,
+        point: palette.background.base.text,
+        active: palette.primary.strong.color,
+        handle: palette.secondary.base.color,
+    }
+}
+
+/// A part of a [`Curve`] that can be dragged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    /// A point, by index.
+    Point(usize),
+    /// A handle of a bezier segment, by index of the segment and whether it is the one of its end.
+    Handle(usize, bool),
+}
+
+/// The state of a [`CurveEditor`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    dragging: Option<Target>,
+    last_click: Option<Click>,
+}
+
+/// An editor of a [`Curve`], created with [`curve_editor`].
+pub struct CurveEditor<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    curve: &'a Curve,
+    on_change: Box<dyn Fn(Curve) -> Message + 'a>,
+    x_range: RangeInclusive<f32>,
+    y_range: RangeInclusive<f32>,
+    snap: Option<Vector>,
+    grid: (u16, u16),
+    point_radius: f32,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> CurveEditor<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`CurveEditor`] of the given [`Curve`], producing the message of `on_change`
+    /// with the edited curve.
+    pub fn new(curve: &'a Curve, on_change: impl Fn(Curve) -> Message + 'a) -> Self {
+        Self {
+            curve,
+            on_change: Box::new(on_change),
+            x_range: 0.0..=1.0,
+            y_range: 0.0..=1.0,
+            snap: None,
+            grid: (4, 4),
+            point_radius: 4.,
+            width: Length::Fixed(240.),
+            height: Length::Fixed(160.),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the horizontal range displayed by the [`CurveEditor`]. Defaults to `0.0..=1.0`.
+    pub fn x_range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.x_range = range;
+        self
+    }
+
+    /// Sets the vertical range displayed by the [`CurveEditor`]. Defaults to `0.0..=1.0`.
+    pub fn y_range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.y_range = range;
+        self
+    }
+
+    /// Sets the horizontal and vertical steps to which the dragged and added points snap.
+    pub fn snap(mut self, snap: impl Into<Option<Vector>>) -> Self {
+        self.snap = snap.into();
+        self
+    }
+
+    /// Sets the number of horizontal and vertical divisions of the grid.
+    pub fn grid(mut self, columns: u16, rows: u16) -> Self {
+        self.grid = (columns, rows);
+        self
+    }
+
+    /// Sets the radius of the points.
+    pub fn point_radius(mut self, point_radius: f32) -> Self {
+        self.point_radius = point_radius;
+        self
+    }
+
+    /// Sets the width of the [`CurveEditor`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`CurveEditor`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`CurveEditor`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`CurveEditor`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the area of the plot in a frame of the given size.
+    fn plot(&self, size: Size) -> Rectangle {
+        let margin = self.point_radius + 2.;
+
+        Rectangle {
+            x: margin,
+            y: margin,
+            width: (size.width - margin * 2.).max(1.),
+            height: (size.height - margin * 2.).max(1.),
+        }
+    }
+
+    /// Converts a point of the curve to a position in the plot.
+    fn to_screen(&self, plot: Rectangle, point: Point) -> Point {
+        let (x0, x1) = (*self.x_range.start(), *self.x_range.end());
+        let (y0, y1) = (*self.y_range.start(), *self.y_range.end());
+
+        Point::new(
+            plot.x + (point.x - x0) / (x1 - x0) * plot.width,
+            plot.y + (1. - (point.y - y0) / (y1 - y0)) * plot.height,
+        )
+    }
+
+    /// Converts a position in the plot to a point of the curve, clamped to the ranges and
+    /// snapped.
+    fn to_curve(&self, plot: Rectangle, position: Point) -> Point {
+        let (x0, x1) = (*self.x_range.start(), *self.x_range.end());
+        let (y0, y1) = (*self.y_range.start(), *self.y_range.end());
+
+        let x = x0 + (position.x - plot.x) / plot.width * (x1 - x0);
+        let y = y0 + (1. - (position.y - plot.y) / plot.height) * (y1 - y0);
+
+        let snap = |value: f32, step: f32| {
+            if step > 0. {
+                (value / step).round() * step
+            } else {
+                value
+            }
+        };
+        let (x, y) = match self.snap {
+            Some(step) => (snap(x, step.x), snap(y, step.y)),
+            None => (x, y),
+        };
+
+        Point::new(
+            x.clamp(x0.min(x1), x0.max(x1)),
+            y.clamp(y0.min(y1), y0.max(y1)),
+        )
+    }
+
+    /// Returns the part of the curve under the given position in the plot, handles first.
+    fn target_at(&self, plot: Rectangle, position: Point) -> Option<Target> {
+        let near = |point: Point| self.to_screen(plot, point).distance(position) <= GRAB_DISTANCE;
+
+        let handle = self
+            .curve
+            .segments()
+            .iter()
+            .enumerate()
+            .find_map(|(index, segment)| match segment {
+                Segment::Bezier(c1, _) if near(*c1) => Some(Target::Handle(index, false)),
+                Segment::Bezier(_, c2) if near(*c2) => Some(Target::Handle(index, true)),
+                _ => None,
+            });
+
+        handle.or_else(|| {
+            self.curve
+                .points()
+                .iter()
+                .position(|point| near(*point))
+                .map(Target::Point)
+        })
+    }
+
+    fn draw_grid(&self, frame: &mut Frame, plot: Rectangle, style: &Style) {
+        let stroke = Stroke::default().with_width(1.).with_color(style.grid);
+        let (columns, rows) = (self.grid.0.max(1), self.grid.1.max(1));
+
+        for column in 0..=columns {
+            let x = plot.x + plot.width * f32::from(column) / f32::from(columns);
+            frame.stroke(
+                &Path::line(Point::new(x, plot.y), Point::new(x, plot.y + plot.height)),
+                stroke,
+            );
+        }
+        for row in 0..=rows {
+            let y = plot.y + plot.height * f32::from(row) / f32::from(rows);
+            frame.stroke(
+                &Path::line(Point::new(plot.x, y), Point::new(plot.x + plot.width, y)),
+                stroke,
+            );
+        }
+    }
+
+    fn draw_curve(&self, frame: &mut Frame, plot: Rectangle, style: &Style) {
+        let points = self.curve.points();
+        let screen = |point: Point| self.to_screen(plot, point);
+
+        let path = Path::new(|path| {
+            // The curve is constant outside of its points.
+            let first = screen(points[0]);
+            path.move_to(Point::new(plot.x.min(first.x), first.y));
+            path.line_to(first);
+
+            for (index, segment) in self.curve.segments().iter().enumerate() {
+                let to = screen(points[index + 1]);
+                match segment {
+                    Segment::Linear => path.line_to(to),
+                    Segment::Bezier(c1, c2) => path.bezier_curve_to(screen(*c1), screen(*c2), to),
+                }
+            }
+
+            let last = screen(points[points.len() - 1]);
+            path.line_to(Point::new((plot.x + plot.width).max(last.x), last.y));
+        });
+
+        frame.stroke(
+            &path,
+            Stroke::default()
+                .with_width(style.curve_width)
+                .with_color(style.curve),
+        );
+    }
+
+    fn draw_points(
+        &self,
+        frame: &mut Frame,
+        plot: Rectangle,
+        style: &Style,
+        active: Option<Target>,
+    ) {
+        let points = self.curve.points();
+        let stroke = Stroke::default().with_width(1.).with_color(style.handle);
+
+        for (index, segment) in self.curve.segments().iter().enumerate() {
+            let Segment::Bezier(c1, c2) = segment else {
+                continue;
+            };
+
+            for (end, anchor, handle) in [(false, points[index], c1), (true, points[index + 1], c2)]
+            {
+                let handle = self.to_screen(plot, *handle);
+                frame.stroke(&Path::line(self.to_screen(plot, anchor), handle), stroke);
+
+                let color = if active == Some(Target::Handle(index, end)) {
+                    style.active
+                } else {
+                    style.handle
+                };
+                let size = self.point_radius * 1.5;
+                frame.fill_rectangle(
+                    handle - Vector::new(size, size) * 0.5,
+                    Size::new(size, size),
+                    color,
+                );
+            }
+        }
+
+        for (index, point) in points.iter().enumerate() {
+            let (color, radius) = if active == Some(Target::Point(index)) {
+                (style.active, self.point_radius * 1.5)
+            } else {
+                (style.point, self.point_radius)
+            };
+
+            frame.fill(&Path::circle(self.to_screen(plot, *point), radius), color);
+        }
+    }
+}
+
+impl<Message, Theme> Program<Message, Theme> for CurveEditor<'_, Message, Theme>
+where
+    Theme: Catalog,
+{
+    type State = State;
+
+    fn update(
+        &self,
+        state: &mut State,
+        event: canvas::Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> (event::Status, Option<Message>) {
+        let plot = self.plot(bounds.size());
+
+        match event {
+            canvas::Event::Mouse(mouse::Event::ButtonPressed(button)) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return (event::Status::Ignored, None);
+                };
+                let target = self.target_at(plot, position);
+
+                if button == mouse::Button::Right {
+                    let Some(Target::Point(index)) = target else {
+                        return (event::Status::Ignored, None);
+                    };
+
+                    let mut curve = self.curve.clone();
+                    let message = curve.remove(index).then(|| (self.on_change)(curve));
+                    return (event::Status::Captured, message);
+                }
+                if button != mouse::Button::Left {
+                    return (event::Status::Ignored, None);
+                }
+
+                let click = Click::new(position, mouse::Button::Left, state.last_click);
+                state.last_click = Some(click);
+                let double = matches!(click.kind(), click::Kind::Double);
+
+                match target {
+                    Some(Target::Point(index)) if double => {
+                        // Switch the segment starting at the point.
+                        let Some(segment) = self.curve.segments().get(index) else {
+                            return (event::Status::Captured, None);
+                        };
+                        let points = self.curve.points();
+                        let segment = match segment {
+                            Segment::Linear => Segment::ease(points[index], points[index + 1]),
+                            Segment::Bezier(..) => Segment::Linear,
+                        };
+
+                        let mut curve = self.curve.clone();
+                        curve.set_segment(index, segment);
+                        (event::Status::Captured, Some((self.on_change)(curve)))
+                    }
+                    Some(target) => {
+                        state.dragging = Some(target);
+                        (event::Status::Captured, None)
+                    }
+                    None if double => {
+                        let mut curve = self.curve.clone();
+                        let index = curve.insert(self.to_curve(plot, position));
+                        state.dragging = Some(Target::Point(index));
+                        (event::Status::Captured, Some((self.on_change)(curve)))
+                    }
+                    None => (event::Status::Captured, None),
+                }
+            }
+            canvas::Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let (Some(target), Some(position)) = (state.dragging, cursor.position()) else {
+                    return (event::Status::Ignored, None);
+                };
+                let point = self.to_curve(plot, position - Vector::new(bounds.x, bounds.y));
+
+                let mut curve = self.curve.clone();
+                match target {
+                    Target::Point(index) => curve.move_point(index, point),
+                    Target::Handle(index, end) => curve.move_handle(index, end, point),
+                }
+
+                let message = (curve != *self.curve).then(|| (self.on_change)(curve));
+                (event::Status::Captured, message)
+            }
+            canvas::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if state.dragging.take().is_some() {
+                    (event::Status::Captured, None)
+                } else {
+                    (event::Status::Ignored, None)
+                }
+            }
+            _ => (event::Status::Ignored, None),
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let style = theme.style(&self.class);
+        let mut frame = Frame::new(renderer, bounds.size());
+        let plot = self.plot(bounds.size());
+
+        frame.fill_rectangle(Point::ORIGIN, bounds.size(), style.background);
+
+        let active = state.dragging.or_else(|| {
+            cursor
+                .position_in(bounds)
+                .and_then(|position| self.target_at(plot, position))
+        });
+
+        self.draw_grid(&mut frame, plot, &style);
+        self.draw_curve(&mut frame, plot, &style);
+        self.draw_points(&mut frame, plot, &style, active);
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &State,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        if state.dragging.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let plot = self.plot(bounds.size());
+        match cursor.position_in(bounds) {
+            Some(position) if self.target_at(plot, position).is_some() => mouse::Interaction::Grab,
+            Some(_) => mouse::Interaction::Crosshair,
+            None => mouse::Interaction::default(),
+        }
+    }
+}
+
+impl<'a, Message, Theme> From<CurveEditor<'a, Message, Theme>> for Element<'a, Message, Theme>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+{
+    fn from(value: CurveEditor<'a, Message, Theme>) -> Self {
+        let (width, height) = (value.width, value.height);
+        Element::new(Canvas::new(value).width(width).height(height))
+    }
+}
+
+/// Creates a new [`CurveEditor`] of the given [`Curve`], producing the message of `on_change`
+/// with the edited curve.
+pub fn curve_editor<'a, Message, Theme>(
+    curve: &'a Curve,
+    on_change: impl Fn(Curve) -> Message + 'a,
+) -> CurveEditor<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    CurveEditor::new(curve, on_change)
+}
@@ -0,0 +1,300 @@
+use iced::Point;
+
+/// The number of bisection steps used to find the parameter of a bezier segment at a position.
+const BISECTION_STEPS: usize = 32;
+
+/// A segment of a [`Curve`], between two consecutive points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Segment {
+    /// A straight line.
+    Linear,
+    /// A cubic bezier curve, with the handles of its start and of its end.
+    ///
+    /// The handles are kept horizontally between the points of the segment, so the curve stays a
+    /// function.
+    Bezier(
+        #[cfg_attr(feature = "serde", serde(with = "crate::helpers::serde_iced"))] Point,
+        #[cfg_attr(feature = "serde", serde(with = "crate::helpers::serde_iced"))] Point,
+    ),
+}
+
+impl Segment {
+    /// Returns a bezier segment from `from` to `to` easing in and out, with horizontal handles at
+    /// a third of the segment.
+    pub fn ease(from: Point, to: Point) -> Self {
+        let third = (to.x - from.x) / 3.;
+
+        Self::Bezier(
+            Point::new(from.x + third, from.y),
+            Point::new(to.x - third, to.y),
+        )
+    }
+}
+
+/// A function defined by control points, joined by linear or bezier [`Segment`]s.
+///
+/// The points are sorted by their horizontal position, and there are always at least two.
+///
+/// ```
+/// use iced::Point;
+/// use more_iced_aw::curve_editor::Curve;
+///
+/// let mut curve = Curve::new([Point::new(0., 0.), Point::new(1., 1.)]);
+/// assert_eq!(curve.sample(0.25), 0.25);
+///
+/// curve.insert(Point::new(0.5, 0.));
+/// assert_eq!(curve.sample(0.25), 0.);
+/// assert_eq!(curve.sample(0.75), 0.5);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Curve {
+    #[cfg_attr(feature = "serde", serde(with = "crate::helpers::serde_iced"))]
+    points: Vec<Point>,
+    segments: Vec<Segment>,
+}
+
+impl Curve {
+    /// Creates a new [`Curve`] through the given points, with linear segments.
+    ///
+    /// The points are sorted. If there are less than two, the missing ones are added at `(0, 0)`
+    /// and `(1, 1)`.
+    pub fn new(points: impl IntoIterator<Item = Point>) -> Self {
+        let mut points: Vec<Point> = points.into_iter().collect();
+        points.sort_by(|a, b| a.x.total_cmp(&b.x));
+
+        match points.len() {
+            0 => points = vec![Point::ORIGIN, Point::new(1., 1.)],
+            1 => points.push(Point::new(points[0].x + 1., points[0].y + 1.)),
+            _ => {}
+        }
+
+        Self {
+            segments: vec![Segment::Linear; points.len() - 1],
+            points,
+        }
+    }
+
+    /// Returns the points of the [`Curve`].
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    /// Returns the segments of the [`Curve`], the segment `i` going from the point `i` to the
+    /// point `i + 1`.
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// Sets a segment of the [`Curve`]. The handles of a bezier segment are clamped between its
+    /// points.
+    pub fn set_segment(&mut self, index: usize, segment: Segment) {
+        if index < self.segments.len() {
+            self.segments[index] = self.clamped(index, segment);
+        }
+    }
+
+    /// Adds a point to the [`Curve`], and returns its index.
+    ///
+    /// The segment containing the point is split in two segments of the same kind. A bezier
+    /// segment keeps its shape, the point being moved onto it horizontally.
+    pub fn insert(&mut self, point: Point) -> usize {
+        let index = self.points.partition_point(|other| other.x <= point.x);
+
+        if index == 0 || index == self.points.len() {
+            self.points.insert(index, point);
+            self.segments
+                .insert(index.min(self.segments.len()), Segment::Linear);
+            return index;
+        }
+
+        let segment = index - 1;
+        match self.segments[segment] {
+            Segment::Linear => {
+                self.points.insert(index, point);
+                self.segments.insert(index, Segment::Linear);
+            }
+            Segment::Bezier(c1, c2) => {
+                let (p0, p3) = (self.points[segment], self.points[index]);
+                let t = bezier_parameter(p0.x, c1.x, c2.x, p3.x, point.x);
+
+                // De Casteljau's subdivision.
+                let a = lerp(p0, c1, t);
+                let b = lerp(c1, c2, t);
+                let c = lerp(c2, p3, t);
+                let d = lerp(a, b, t);
+                let e = lerp(b, c, t);
+                let split = lerp(d, e, t);
+
+                self.points.insert(index, split);
+                self.segments[segment] = Segment::Bezier(a, d);
+                self.segments.insert(index, Segment::Bezier(e, c));
+            }
+        }
+
+        index
+    }
+
+    /// Removes a point of the [`Curve`], and returns whether it was removed.
+    ///
+    /// A curve keeps at least two points. The segments around the point are merged in a linear
+    /// segment, or in a bezier segment if they both were.
+    pub fn remove(&mut self, index: usize) -> bool {
+        if self.points.len() <= 2 || index >= self.points.len() {
+            return false;
+        }
+
+        self.points.remove(index);
+
+        if index == 0 {
+            self.segments.remove(0);
+        } else if index == self.segments.len() {
+            self.segments.pop();
+        } else {
+            let merged = match (self.segments[index - 1], self.segments[index]) {
+                (Segment::Bezier(c1, _), Segment::Bezier(_, c2)) => Segment::Bezier(c1, c2),
+                _ => Segment::Linear,
+            };
+            self.segments.remove(index);
+            self.segments[index - 1] = self.clamped(index - 1, merged);
+        }
+
+        true
+    }
+
+    /// Moves a point of the [`Curve`], keeping it between its neighbors. The handles of the point
+    /// move with it.
+    pub fn move_point(&mut self, index: usize, point: Point) {
+        let Some(current) = self.points.get(index).copied() else {
+            return;
+        };
+
+        let min = index
+            .checked_sub(1)
+            .map_or(f32::NEG_INFINITY, |i| self.points[i].x);
+        let max = self
+            .points
+            .get(index + 1)
+            .map_or(f32::INFINITY, |point| point.x);
+        let point = Point::new(point.x.clamp(min, max), point.y);
+        let delta = point - current;
+
+        self.points[index] = point;
+
+        if let Some(Segment::Bezier(_, c2)) =
+            index.checked_sub(1).and_then(|i| self.segments.get_mut(i))
+        {
+            *c2 = *c2 + delta;
+        }
+        if let Some(Segment::Bezier(c1, _)) = self.segments.get_mut(index) {
+            *c1 = *c1 + delta;
+        }
+
+        for segment in index.saturating_sub(1)..(index + 1).min(self.segments.len()) {
+            self.segments[segment] = self.clamped(segment, self.segments[segment]);
+        }
+    }
+
+    /// Moves a handle of a bezier segment of the [`Curve`]: the one of its end if `end` is `true`,
+    /// else the one of its start.
+    pub fn move_handle(&mut self, index: usize, end: bool, handle: Point) {
+        let Some(Segment::Bezier(c1, c2)) = self.segments.get(index).copied() else {
+            return;
+        };
+
+        let segment = if end {
+            Segment::Bezier(c1, handle)
+        } else {
+            Segment::Bezier(handle, c2)
+        };
+        self.segments[index] = self.clamped(index, segment);
+    }
+
+    /// Returns the value of the [`Curve`] at the given horizontal position.
+    ///
+    /// The curve is constant before its first point and after its last point.
+    pub fn sample(&self, x: f32) -> f32 {
+        let first = self.points[0];
+        let last = self.points[self.points.len() - 1];
+
+        if x <= first.x {
+            return first.y;
+        }
+        if x >= last.x {
+            return last.y;
+        }
+
+        let index = self.points.partition_point(|point| point.x <= x) - 1;
+        let (p0, p3) = (self.points[index], self.points[index + 1]);
+
+        match self.segments[index] {
+            Segment::Linear => {
+                let t = (x - p0.x) / (p3.x - p0.x);
+                if t.is_finite() {
+                    p0.y + (p3.y - p0.y) * t
+                } else {
+                    p3.y
+                }
+            }
+            Segment::Bezier(c1, c2) => {
+                let t = bezier_parameter(p0.x, c1.x, c2.x, p3.x, x);
+                cubic(p0.y, c1.y, c2.y, p3.y, t)
+            }
+        }
+    }
+
+    /// Returns `count` values of the [`Curve`], evenly spaced from its first to its last point.
+    pub fn samples(&self, count: usize) -> impl Iterator<Item = f32> + '_ {
+        let start = self.points[0].x;
+        let width = self.points[self.points.len() - 1].x - start;
+        let step = width / count.saturating_sub(1).max(1) as f32;
+
+        (0..count).map(move |i| self.sample(start + step * i as f32))
+    }
+
+    /// Clamps the handles of a segment between its points.
+    fn clamped(&self, index: usize, segment: Segment) -> Segment {
+        let (min, max) = (self.points[index].x, self.points[index + 1].x);
+        let clamp = |point: Point| Point::new(point.x.clamp(min, max), point.y);
+
+        match segment {
+            Segment::Linear => Segment::Linear,
+            Segment::Bezier(c1, c2) => Segment::Bezier(clamp(c1), clamp(c2)),
+        }
+    }
+}
+
+impl Default for Curve {
+    /// The identity from `(0, 0)` to `(1, 1)`.
+    fn default() -> Self {
+        Self::new([])
+    }
+}
+
+/// Returns the point between `a` and `b` at `t`.
+fn lerp(a: Point, b: Point, t: f32) -> Point {
+    a + (b - a) * t
+}
+
+/// Returns the value of a cubic bezier curve at `t`.
+fn cubic(p0: f32, p1: f32, p2: f32, p3: f32, t: f32) -> f32 {
+    let u = 1. - t;
+    u * u * u * p0 + 3. * u * u * t * p1 + 3. * u * t * t * p2 + t * t * t * p3
+}
+
+/// Returns the parameter at which a cubic bezier curve with increasing coordinates reaches `x`.
+fn bezier_parameter(p0: f32, p1: f32, p2: f32, p3: f32, x: f32) -> f32 {
+    let (mut low, mut high) = (0f32, 1f32);
+
+    for _ in 0..BISECTION_STEPS {
+        let middle = (low + high) / 2.;
+        if cubic(p0, p1, p2, p3, middle) < x {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    (low + high) / 2.
+}
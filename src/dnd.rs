@@ -0,0 +1,328 @@
+//! The drag and drop infrastructure shared by the widgets of the crate.
+//!
+//! A single payload can be dragged at a time. It is shared through a channel local to the thread
+//! running the application, so that the widget where a drag starts and the widget where it ends do
+//! not need to know each other. The payload is type-erased in a [`Payload`], and targets downcast
+//! it to the types they accept.
+//!
+//! The lifecycle of a drag is:
+//! 1. The source [starts](start) the drag with a payload when the cursor moved far enough.
+//! 2. Targets [peek](with_payload) at the payload to highlight themselves while it is dragged.
+//! 3. When the button is released, the source [releases](release) the payload, and the target
+//!    under the cursor [takes](take) it.
+//! 4. On the next event, the source [finishes](finish) the drag, learning whether the payload was
+//!    taken. The drag can also be [canceled](cancel), for example with Escape.
+//!
+//! Sources usually draw the dragged element under the cursor with a [`Preview`] overlay.
+//!
+//! The [`DragSource`](crate::drag_drop::DragSource) and [`DropTarget`](crate::drag_drop::DropTarget)
+//! wrappers implement this lifecycle for any element, and a
+//! [`ReorderableList`](crate::reorderable_list::ReorderableList) can drag its items out with a
+//! payload. Custom widgets use the functions of this module to take part in the same drags.
+
+use std::{any::Any, cell::RefCell};
+
+use iced::{
+    Background, Border, Color, Element, Point, Rectangle, Shadow, Size, Vector,
+    advanced::{
+        Layout,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::Tree,
+    },
+};
+
+/// The distance the cursor must travel with the button pressed before a drag starts.
+pub const DRAG_THRESHOLD: f32 = 4.;
+
+/// A type-erased dragged value.
+///
+/// ```
+/// use more_iced_aw::dnd::Payload;
+///
+/// let payload = Payload::new(42u32);
+/// assert!(payload.is::<u32>());
+/// assert_eq!(payload.downcast_ref::<u32>(), Some(&42));
+/// assert_eq!(payload.downcast_ref::<i32>(), None);
+/// ```
+pub struct Payload(Box<dyn Any>);
+
+impl Payload {
+    /// Creates a new [`Payload`] holding the given value.
+    pub fn new<T: 'static>(value: T) -> Self {
+        Self(Box::new(value))
+    }
+
+    /// Indicates if the value of the [`Payload`] is of type `T`.
+    pub fn is<T: 'static>(&self) -> bool {
+        self.0.is::<T>()
+    }
+
+    /// Returns the value of the [`Payload`] if it is of type `T`.
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        self.0.downcast_ref()
+    }
+}
+
+impl std::fmt::Debug for Payload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Payload").finish_non_exhaustive()
+    }
+}
+
+/// A step of the lifecycle of a drag, reported to its source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Event {
+    /// The drag started.
+    Started,
+    /// The payload was taken by a target.
+    Dropped,
+    /// The payload was released out of any target, or the drag was canceled.
+    Canceled,
+}
+
+/// The phase of the current drag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    /// The payload is being dragged.
+    Dragging,
+    /// The payload was released, and waits for a target to take it.
+    Released,
+    /// The payload was taken by a target.
+    Taken,
+}
+
+/// The current drag.
+struct Drag {
+    payload: Payload,
+    phase: Phase,
+}
+
+thread_local! {
+    /// The current drag, shared by the sources and the targets.
+    static DRAG: RefCell<Option<Drag>> = const { RefCell::new(None) };
+}
+
+/// Starts dragging a value, replacing any previous drag.
+pub fn start<T: 'static>(value: T) {
+    start_payload(Payload::new(value));
+}
+
+/// Starts dragging a [`Payload`], replacing any previous drag.
+pub fn start_payload(payload: Payload) {
+    DRAG.with_borrow_mut(|drag| {
+        *drag = Some(Drag {
+            payload,
+            phase: Phase::Dragging,
+        });
+    });
+}
+
+/// Indicates if a payload is being dragged, and was not released yet.
+pub fn is_dragging() -> bool {
+    DRAG.with_borrow(|drag| {
+        drag.as_ref()
+            .is_some_and(|drag| drag.phase == Phase::Dragging)
+    })
+}
+
+/// Returns the result of `f` on the [`Payload`] being dragged, if it was not released yet.
+pub fn with_payload<R>(f: impl FnOnce(&Payload) -> R) -> Option<R> {
+    DRAG.with_borrow(|drag| {
+        drag.as_ref()
+            .filter(|drag| drag.phase == Phase::Dragging)
+            .map(|drag| f(&drag.payload))
+    })
+}
+
+/// Returns a copy of the value being dragged, if there is one of type `T` not released yet.
+pub fn dragged<T: Clone + 'static>() -> Option<T> {
+    with_payload(|payload| payload.downcast_ref::<T>().cloned()).flatten()
+}
+
+/// Releases the payload being dragged, so that a target can [`take`] it until the source
+/// [finishes](finish) the drag.
+pub fn release() {
+    DRAG.with_borrow_mut(|drag| {
+        if let Some(drag) = drag
+            && drag.phase == Phase::Dragging
+        {
+            drag.phase = Phase::Released;
+        }
+    });
+}
+
+/// Takes a copy of the dragged value, if it is of type `T` and accepted by `accept`, when the
+/// button is released over a target.
+///
+/// The source then learns that the payload was dropped when it [finishes](finish) the drag.
+pub fn take<T: Clone + 'static>(accept: impl FnOnce(&T) -> bool) -> Option<T> {
+    DRAG.with_borrow_mut(|drag| {
+        let drag = drag.as_mut().filter(|drag| drag.phase != Phase::Taken)?;
+        let value = drag
+            .payload
+            .downcast_ref::<T>()
+            .filter(|value| accept(value))?;
+        let value = value.clone();
+        drag.phase = Phase::Taken;
+        Some(value)
+    })
+}
+
+/// Ends a released drag, returning the [`Event`] of its end: whether the payload was taken.
+///
+/// Returns [`None`] if no drag was released, for example if another drag already started.
+pub fn finish() -> Option<Event> {
+    DRAG.with_borrow_mut(|drag| {
+        let event = match drag.as_ref()?.phase {
+            Phase::Dragging => return None,
+            Phase::Released => Event::Canceled,
+            Phase::Taken => Event::Dropped,
+        };
+        *drag = None;
+        Some(event)
+    })
+}
+
+/// Cancels the current drag, if any.
+pub fn cancel() {
+    DRAG.with_borrow_mut(|drag| *drag = None);
+}
+
+/// The appearance of a [`Preview`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background drawn behind the dragged element.
+    pub background: Option<Background>,
+    /// The border of the dragged element.
+    pub border: Border,
+    /// The shadow of the dragged element.
+    pub shadow: Shadow,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            background: None,
+            border: Border::default().rounded(4.),
+            shadow: Shadow {
+                color: Color::BLACK.scale_alpha(0.3),
+                offset: Vector::new(0., 4.),
+                blur_radius: 12.,
+            },
+        }
+    }
+}
+
+/// An overlay drawing a dragged element under the cursor.
+///
+/// It ignores the cursor, so that the widgets below it still see it.
+pub struct Preview<'a, 'b, Message, Theme, Renderer> {
+    content: &'b Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    position: Point,
+    size: Size,
+    style: Box<dyn Fn(&Theme) -> Style + 'b>,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Preview<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a new [`Preview`] of an element with its tree, at the given position in the window
+    /// and with the given size.
+    pub fn new(
+        content: &'b Element<'a, Message, Theme, Renderer>,
+        tree: &'b mut Tree,
+        position: Point,
+        size: Size,
+    ) -> Self {
+        Self {
+            content,
+            tree,
+            position,
+            size,
+            style: Box::new(|_| Style::default()),
+        }
+    }
+
+    /// Sets the style of the [`Preview`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'b) -> Self {
+        self.style = Box::new(style);
+        self
+    }
+
+    /// Turns the [`Preview`] into an overlay element.
+    pub fn overlay(self) -> overlay::Element<'b, Message, Theme, Renderer>
+    where
+        'a: 'b,
+        Message: 'b,
+        Theme: 'b,
+        Renderer: 'b,
+    {
+        overlay::Element::new(Box::new(self))
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Preview<'_, '_, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, _bounds: Size) -> Node {
+        let limits = Limits::new(Size::ZERO, self.size);
+        let node = self
+            .content
+            .as_widget()
+            .layout(self.tree, renderer, &limits);
+
+        Node::with_children(self.size, vec![node]).move_to(self.position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = (self.style)(theme);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: appearance.border,
+                shadow: appearance.shadow,
+            },
+            appearance
+                .background
+                .unwrap_or(Background::Color(Color::TRANSPARENT)),
+        );
+
+        self.content.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            mouse::Cursor::Unavailable,
+            &bounds,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        mouse::Interaction::Grabbing
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _renderer: &Renderer, _cursor_position: Point) -> bool {
+        false
+    }
+}
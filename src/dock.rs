@@ -0,0 +1,842 @@
+//! Dockable panels, built on top of iced's [`PaneGrid`](iced::widget::PaneGrid).
+//!
+//! Panels are grouped in tabs, and the groups are arranged by splits. A panel can be dragged by
+//! its tab and dropped:
+//!   * on an edge of a group or of the dock, to dock it there,
+//!   * in the middle of another group, to add it to the tabs of that group.
+//!
+//! The tabs are dragged through the channel of the [`dnd`](crate::dnd) module, like the other
+//! drag and drop widgets of the crate. A [`Dock`] only takes the tabs of its own [`Content`].
+//!
+//! The arrangement is kept in a [`Content`], and can be saved and restored as an [`Arrangement`],
+//! which supports serialization with serde if the feature `serde` is enabled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced::{Element, widget::text};
+//! use more_iced_aw::dock::*;
+//!
+//! struct App {
+//!     dock: Content<&'static str>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Dock(Action),
+//! }
+//!
+//! impl App {
+//!     fn update(&mut self, message: Message) {
+//!         let Message::Dock(action) = message;
+//!         self.dock.update(action);
+//!     }
+//!
+//!     fn view(&self) -> Element<'_, Message> {
+//!         dock(
+//!             &self.dock,
+//!             |id| Panel::new(id.to_string(), text(format!("The {id} panel"))),
+//!             Message::Dock,
+//!         )
+//!         .into()
+//!     }
+//! }
+//!
+//! fn main() -> iced::Result {
+//!     let arrangement = Arrangement::Split {
+//!         axis: Axis::Vertical,
+//!         ratio: 0.3,
+//!         a: Box::new(Arrangement::tabs(["Files", "Search"])),
+//!         b: Box::new(Arrangement::tabs(["Editor"])),
+//!     };
+//!
+//!     iced::application("Dock", App::update, App::view)
+//!         .run_with(move || (App { dock: Content::new(arrangement.clone()) }, iced::Task::none()))
+//! }
+//! ```
+
+use std::{
+    rc::Rc,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use iced::{
+    Alignment, Background, Border, Color, Element, Length, Pixels, Point, Rectangle, Renderer,
+    Size, Theme, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{Operation, Tree},
+    },
+    event, touch,
+    widget::{
+        Space, button, container,
+        pane_grid::{self, Edge, Pane, PaneGrid, Region, ResizeEvent, Target},
+        row, text,
+    },
+};
+
+use crate::{dnd, drag_drop::drag_source};
+
+/// The direction of a split of an [`Arrangement`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Axis {
+    /// The split is horizontal: the first part is above the second one.
+    Horizontal,
+    /// The split is vertical: the first part is on the left of the second one.
+    Vertical,
+}
+
+impl From<Axis> for pane_grid::Axis {
+    fn from(value: Axis) -> Self {
+        match value {
+            Axis::Horizontal => pane_grid::Axis::Horizontal,
+            Axis::Vertical => pane_grid::Axis::Vertical,
+        }
+    }
+}
+
+impl From<pane_grid::Axis> for Axis {
+    fn from(value: pane_grid::Axis) -> Self {
+        match value {
+            pane_grid::Axis::Horizontal => Axis::Horizontal,
+            pane_grid::Axis::Vertical => Axis::Vertical,
+        }
+    }
+}
+
+/// The arrangement of the panels of a [`Content`], to save and restore it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Arrangement<Id> {
+    /// A split of the available space.
+    Split {
+        /// The direction of the split.
+        axis: Axis,
+        /// The ratio of the space given to the first part, in [0, 1].
+        ratio: f32,
+        /// The first part, on the left or on top.
+        a: Box<Arrangement<Id>>,
+        /// The second part, on the right or at the bottom.
+        b: Box<Arrangement<Id>>,
+    },
+    /// A group of panels shown as tabs.
+    Tabs {
+        /// The panels of the group.
+        panels: Vec<Id>,
+        /// The index of the visible panel.
+        active: usize,
+    },
+}
+
+impl<Id> Arrangement<Id> {
+    /// Creates a group of panels, the first one being visible.
+    pub fn tabs(panels: impl IntoIterator<Item = Id>) -> Self {
+        Arrangement::Tabs {
+            panels: panels.into_iter().collect(),
+            active: 0,
+        }
+    }
+}
+
+/// The panels of a pane of a [`Content`].
+#[derive(Debug, Clone)]
+struct Tabs<Id> {
+    panels: Vec<Id>,
+    active: usize,
+}
+
+impl<Id> Tabs<Id> {
+    /// Removes the panel at the given index, keeping a valid active panel.
+    fn remove(&mut self, index: usize) -> Id {
+        let id = self.panels.remove(index);
+        if self.active > index || self.active >= self.panels.len() {
+            self.active = self.active.saturating_sub(1);
+        }
+        id
+    }
+
+    /// Adds a panel and makes it visible.
+    fn push(&mut self, id: Id) {
+        self.panels.push(id);
+        self.active = self.panels.len() - 1;
+    }
+}
+
+/// An interaction with a [`Dock`], to be given to [`Content::update`].
+#[derive(Debug, Clone, Copy)]
+pub enum Action {
+    /// The tab at the given index of a group was dropped on a target.
+    Drop(Pane, usize, Target),
+    /// A split was resized.
+    Resize(ResizeEvent),
+    /// A tab was selected.
+    Select(Pane, usize),
+    /// A tab was closed.
+    Close(Pane, usize),
+}
+
+/// A tab of a [`Dock`] being dragged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DraggedTab {
+    /// The id of the [`Content`] the tab comes from.
+    content: u64,
+    pane: Pane,
+    index: usize,
+}
+
+/// The state of a [`Dock`].
+#[derive(Debug)]
+pub struct Content<Id> {
+    panes: pane_grid::State<Tabs<Id>>,
+    /// Identifies the dragged tabs of the [`Content`] among the ones of the other docks.
+    id: u64,
+}
+
+impl<Id: Clone + PartialEq> Content<Id> {
+    /// Creates a new [`Content`] with the given arrangement.
+    pub fn new(arrangement: Arrangement<Id>) -> Self {
+        fn configuration<Id>(arrangement: Arrangement<Id>) -> pane_grid::Configuration<Tabs<Id>> {
+            match arrangement {
+                Arrangement::Split { axis, ratio, a, b } => pane_grid::Configuration::Split {
+                    axis: axis.into(),
+                    ratio: ratio.clamp(0., 1.),
+                    a: Box::new(configuration(*a)),
+                    b: Box::new(configuration(*b)),
+                },
+                Arrangement::Tabs { panels, active } => pane_grid::Configuration::Pane(Tabs {
+                    active: active.min(panels.len().saturating_sub(1)),
+                    panels,
+                }),
+            }
+        }
+
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        Self {
+            panes: pane_grid::State::with_configuration(configuration(arrangement)),
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+
+    /// Returns the current arrangement of the panels, to save it.
+    pub fn arrangement(&self) -> Arrangement<Id> {
+        self.node_arrangement(self.panes.layout())
+    }
+
+    /// Returns the arrangement of a node of the layout.
+    fn node_arrangement(&self, node: &pane_grid::Node) -> Arrangement<Id> {
+        match node {
+            pane_grid::Node::Split {
+                axis, ratio, a, b, ..
+            } => Arrangement::Split {
+                axis: (*axis).into(),
+                ratio: *ratio,
+                a: Box::new(self.node_arrangement(a)),
+                b: Box::new(self.node_arrangement(b)),
+            },
+            pane_grid::Node::Pane(pane) => {
+                let tabs = self.panes.get(*pane).expect("Pane of the layout");
+                Arrangement::Tabs {
+                    panels: tabs.panels.clone(),
+                    active: tabs.active,
+                }
+            }
+        }
+    }
+
+    /// Returns all the panels, group by group.
+    pub fn panels(&self) -> impl Iterator<Item = &Id> {
+        self.panes.iter().flat_map(|(_, tabs)| &tabs.panels)
+    }
+
+    /// Shows the given panel, adding it to the first group if it is not in the [`Content`].
+    pub fn open(&mut self, id: Id) {
+        if let Some((_, tabs)) = self
+            .panes
+            .iter_mut()
+            .find(|(_, tabs)| tabs.panels.contains(&id))
+        {
+            tabs.active = tabs
+                .panels
+                .iter()
+                .position(|panel| *panel == id)
+                .unwrap_or(0);
+        } else if let Some(first) = self.first_pane()
+            && let Some(tabs) = self.panes.get_mut(first)
+        {
+            tabs.push(id);
+        }
+    }
+
+    /// Removes the given panel from the [`Content`].
+    pub fn close(&mut self, id: &Id) {
+        let found = self.panes.iter().find_map(|(pane, tabs)| {
+            Some((*pane, tabs.panels.iter().position(|panel| panel == id)?))
+        });
+
+        if let Some((pane, index)) = found {
+            self.close_tab(pane, index);
+        }
+    }
+
+    /// Updates the [`Content`] with an [`Action`] of the [`Dock`].
+    pub fn update(&mut self, action: Action) {
+        match action {
+            Action::Drop(pane, index, target) => self.drop(pane, index, target),
+            Action::Resize(ResizeEvent { split, ratio }) => self.panes.resize(split, ratio),
+            Action::Select(pane, index) => {
+                if let Some(tabs) = self.panes.get_mut(pane)
+                    && index < tabs.panels.len()
+                {
+                    tabs.active = index;
+                }
+            }
+            Action::Close(pane, index) => self.close_tab(pane, index),
+        }
+    }
+
+    /// Returns the first pane in the layout order.
+    fn first_pane(&self) -> Option<Pane> {
+        let mut node = self.panes.layout();
+        loop {
+            match node {
+                pane_grid::Node::Split { a, .. } => node = a,
+                pane_grid::Node::Pane(pane) => return Some(*pane),
+            }
+        }
+    }
+
+    /// Closes a tab, and its group if it was the last one.
+    fn close_tab(&mut self, pane: Pane, index: usize) {
+        let Some(tabs) = self.panes.get_mut(pane) else {
+            return;
+        };
+        if index >= tabs.panels.len() {
+            return;
+        }
+
+        tabs.remove(index);
+        if tabs.panels.is_empty() {
+            self.panes.close(pane);
+        }
+    }
+
+    /// Drops a panel of a group, or the whole group if it has a single panel.
+    fn drop(&mut self, pane: Pane, index: usize, target: Target) {
+        let Some(tabs) = self.panes.get_mut(pane) else {
+            return;
+        };
+        if index >= tabs.panels.len() {
+            return;
+        }
+
+        match target {
+            Target::Pane(target, Region::Center) if target == pane => {}
+            Target::Pane(target, _) if target == pane && tabs.panels.len() == 1 => {}
+            Target::Pane(target, Region::Center) => {
+                let id = tabs.remove(index);
+                let emptied = tabs.panels.is_empty();
+
+                if let Some(target) = self.panes.get_mut(target) {
+                    target.push(id);
+                }
+                if emptied {
+                    self.panes.close(pane);
+                }
+            }
+            target if tabs.panels.len() > 1 => {
+                let id = tabs.remove(index);
+                let single = Tabs {
+                    panels: vec![id],
+                    active: 0,
+                };
+
+                if let Some((new, _)) = self.panes.split(pane_grid::Axis::Vertical, pane, single) {
+                    self.panes.drop(new, target);
+                }
+            }
+            target => self.panes.drop(pane, target),
+        }
+    }
+}
+
+/// A panel of a [`Dock`], with its title and content.
+pub struct Panel<'a, Message> {
+    title: String,
+    content: Element<'a, Message>,
+}
+
+impl<'a, Message> Panel<'a, Message> {
+    /// Creates a new [`Panel`] with the given title and content.
+    pub fn new(title: impl Into<String>, content: impl Into<Element<'a, Message>>) -> Self {
+        Self {
+            title: title.into(),
+            content: content.into(),
+        }
+    }
+}
+
+/// The appearance of a group of panels of a [`Dock`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the group.
+    pub background: Background,
+    /// The border of the group.
+    pub border: Border,
+    /// The background of the title bar holding the tabs.
+    pub title_bar_background: Background,
+    /// The color of the text of the title bar.
+    pub title_bar_text_color: Color,
+    /// The background of the area where a dragged tab would be docked.
+    pub drop_region: Background,
+}
+
+/// The theme catalog of a [`Dock`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Dock`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Dock`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.,
+            radius: 4.into(),
+        },
+        title_bar_background: palette.background.weak.color.into(),
+        title_bar_text_color: palette.background.weak.text,
+        drop_region: palette.primary.weak.color.scale_alpha(0.5).into(),
+    }
+}
+
+/// The function giving a [`Panel`] from its id.
+type PanelFn<'a, Id, Message> = Box<dyn Fn(&Id) -> Panel<'a, Message> + 'a>;
+
+/// A view of a [`Content`], created with [`dock`].
+///
+/// Every interaction produces an [`Action`] that should be given back to [`Content::update`].
+pub struct Dock<'a, Id, Message> {
+    content: &'a Content<Id>,
+    panel: PanelFn<'a, Id, Message>,
+    on_action: Rc<dyn Fn(Action) -> Message + 'a>,
+    spacing: f32,
+    closable: bool,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Id, Message> Dock<'a, Id, Message> {
+    /// Creates a new [`Dock`] of the given [`Content`].
+    ///
+    /// `panel` gives the title and content of a panel from its id.
+    pub fn new(
+        content: &'a Content<Id>,
+        panel: impl Fn(&Id) -> Panel<'a, Message> + 'a,
+        on_action: impl Fn(Action) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content,
+            panel: Box::new(panel),
+            on_action: Rc::new(on_action),
+            spacing: 4.,
+            closable: true,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets the spacing between the groups of panels.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets whether the tabs have a close button.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Sets the style of the groups of panels.
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self {
+        self.class = Box::new(style);
+        self
+    }
+
+    /// Sets the style class of the groups of panels.
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Id, Message> From<Dock<'a, Id, Message>> for Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    fn from(value: Dock<'a, Id, Message>) -> Self {
+        let on_action = &value.on_action;
+        let class = Rc::new(value.class);
+
+        let grid = PaneGrid::new(&value.content.panes, |pane, tabs, _maximized| {
+            let mut body = None;
+
+            let tabs_row = row(tabs.panels.iter().enumerate().map(|(index, id)| {
+                let Panel { title, content } = (value.panel)(id);
+                let active = index == tabs.active;
+                if active {
+                    body = Some(content);
+                }
+
+                let title = button(text(title).size(13))
+                    .padding([4, 8])
+                    .style(if active {
+                        button::secondary
+                    } else {
+                        button::text
+                    })
+                    .on_press(on_action(Action::Select(pane, index)));
+                let dragged = DraggedTab {
+                    content: value.content.id,
+                    pane,
+                    index,
+                };
+
+                let mut tab = row![drag_source(dragged, title)].align_y(Alignment::Center);
+
+                if value.closable {
+                    tab = tab.push(
+                        button(text("×").size(13))
+                            .padding([4, 6])
+                            .style(button::text)
+                            .on_press(on_action(Action::Close(pane, index))),
+                    );
+                }
+
+                tab.into()
+            }))
+            .spacing(2);
+
+            let body =
+                container(body.unwrap_or_else(|| Space::new(Length::Fill, Length::Fill).into()))
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .padding(4);
+
+            pane_grid::Content::new(body)
+                .title_bar(pane_grid::TitleBar::new(tabs_row).padding(2).style({
+                    let class = class.clone();
+
+                    move |theme: &Theme| {
+                        let style = Catalog::style(theme, &class);
+
+                        container::Style {
+                            background: Some(style.title_bar_background),
+                            text_color: Some(style.title_bar_text_color),
+                            ..container::Style::default()
+                        }
+                    }
+                }))
+                .style({
+                    let class = class.clone();
+
+                    move |theme: &Theme| {
+                        let style = Catalog::style(theme, &class);
+
+                        container::Style {
+                            background: Some(style.background),
+                            border: style.border,
+                            ..container::Style::default()
+                        }
+                    }
+                })
+        })
+        .spacing(value.spacing)
+        .on_resize(8, {
+            let on_action = value.on_action.clone();
+            move |event| on_action(Action::Resize(event))
+        });
+
+        Element::new(Drops {
+            grid: grid.into(),
+            layout: value.content.panes.layout(),
+            content: value.content.id,
+            spacing: value.spacing,
+            on_action: value.on_action,
+            class,
+        })
+    }
+}
+
+/// The thickness of the areas along the edges of a [`Dock`] docking a tab on that edge, relative
+/// to the size of the dock.
+const EDGE_RATIO: f32 = 1. / 25.;
+
+/// Wraps the pane grid of a [`Dock`], on which its tabs are dropped.
+struct Drops<'a, Message> {
+    grid: Element<'a, Message>,
+    layout: &'a pane_grid::Node,
+    content: u64,
+    spacing: f32,
+    on_action: Rc<dyn Fn(Action) -> Message + 'a>,
+    class: Rc<<Theme as Catalog>::Class<'a>>,
+}
+
+impl<Message> Drops<'_, Message> {
+    /// Indicates if a tab of the [`Content`] is being dragged.
+    fn is_dragged(&self) -> bool {
+        dnd::dragged::<DraggedTab>().is_some_and(|tab| tab.content == self.content)
+    }
+
+    /// Returns the target under the cursor and the area it docks the tab in, like the pane grid
+    /// of iced: an edge of the dock, or an edge or the middle of a group.
+    fn target(&self, bounds: Rectangle, cursor: Point) -> Option<(Target, Rectangle)> {
+        if !bounds.contains(cursor) {
+            return None;
+        }
+
+        let thickness = bounds.width.min(bounds.height) * EDGE_RATIO;
+        let edge = if cursor.x < bounds.x + thickness {
+            Some(Edge::Left)
+        } else if cursor.x > bounds.x + bounds.width - thickness {
+            Some(Edge::Right)
+        } else if cursor.y < bounds.y + thickness {
+            Some(Edge::Top)
+        } else if cursor.y > bounds.y + bounds.height - thickness {
+            Some(Edge::Bottom)
+        } else {
+            None
+        };
+
+        if let Some(edge) = edge {
+            return Some((Target::Edge(edge), strip(bounds, edge, thickness)));
+        }
+
+        let (pane, region) = self
+            .layout
+            .pane_regions(self.spacing, bounds.size())
+            .into_iter()
+            .map(|(pane, region)| (pane, region + Vector::new(bounds.x, bounds.y)))
+            .find(|(_, region)| region.contains(cursor))?;
+
+        let third = |start: f32, length: f32, position: f32| (position - start) / length * 3.;
+        let edge = match (
+            third(region.x, region.width, cursor.x),
+            third(region.y, region.height, cursor.y),
+        ) {
+            (x, _) if x < 1. => Some(Edge::Left),
+            (x, _) if x > 2. => Some(Edge::Right),
+            (_, y) if y < 1. => Some(Edge::Top),
+            (_, y) if y > 2. => Some(Edge::Bottom),
+            _ => None,
+        };
+
+        Some(match edge {
+            Some(edge) => {
+                let half = match edge {
+                    Edge::Left | Edge::Right => region.width / 2.,
+                    Edge::Top | Edge::Bottom => region.height / 2.,
+                };
+                (
+                    Target::Pane(pane, Region::Edge(edge)),
+                    strip(region, edge, half),
+                )
+            }
+            None => (Target::Pane(pane, Region::Center), region),
+        })
+    }
+}
+
+impl<Message> Widget<Message, Theme, Renderer> for Drops<'_, Message> {
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.grid)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.grid]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.grid.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.grid
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.grid.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        if self.is_dragged()
+            && let Some(position) = cursor.position()
+            && let Some((_, region)) = self.target(layout.bounds(), position)
+        {
+            <Renderer as iced::advanced::Renderer>::fill_quad(
+                renderer,
+                renderer::Quad {
+                    bounds: region,
+                    ..renderer::Quad::default()
+                },
+                Catalog::style(theme, &self.class).drop_region,
+            );
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.grid
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        // The tab releases the payload first.
+        let status = self.grid.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if let iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | iced::Event::Touch(touch::Event::FingerLifted { .. }) = event
+            && let Some(position) = cursor.position()
+            && let Some((target, _)) = self.target(layout.bounds(), position)
+            && let Some(tab) = dnd::take(|tab: &DraggedTab| tab.content == self.content)
+        {
+            shell.publish((self.on_action)(Action::Drop(tab.pane, tab.index, target)));
+            return event::Status::Captured;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.grid.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.grid
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+/// Returns the part of `bounds` along one of its edges, with the given thickness.
+fn strip(bounds: Rectangle, edge: Edge, thickness: f32) -> Rectangle {
+    match edge {
+        Edge::Top => Rectangle {
+            height: thickness,
+            ..bounds
+        },
+        Edge::Left => Rectangle {
+            width: thickness,
+            ..bounds
+        },
+        Edge::Right => Rectangle {
+            x: bounds.x + bounds.width - thickness,
+            width: thickness,
+            ..bounds
+        },
+        Edge::Bottom => Rectangle {
+            y: bounds.y + bounds.height - thickness,
+            height: thickness,
+            ..bounds
+        },
+    }
+}
+
+/// Creates a new [`Dock`] of the given [`Content`].
+///
+/// `panel` gives the title and content of a panel from its id.
+pub fn dock<'a, Id, Message>(
+    content: &'a Content<Id>,
+    panel: impl Fn(&Id) -> Panel<'a, Message> + 'a,
+    on_action: impl Fn(Action) -> Message + 'a,
+) -> Dock<'a, Id, Message> {
+    Dock::new(content, panel, on_action)
+}
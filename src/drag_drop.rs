@@ -0,0 +1,666 @@
+//! Drag and drop between any widgets.
+//!
+//! A [`DragSource`] wraps an element that can be dragged, carrying a payload of some type.
+//! A [`DropTarget`] wraps an element on which payloads of a given type can be dropped.
+//! While an element is dragged, a preview of it follows the cursor.
+//!
+//! The payload goes through the channel of the [`dnd`](crate::dnd) module, so sources and targets
+//! do not need to know each other: a target only accepts the payloads of its type.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced::{Element, widget::{row, text}};
+//! use more_iced_aw::drag_drop::*;
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Dropped(u32),
+//! }
+//!
+//! fn view<'a>() -> Element<'a, Message> {
+//!     row![
+//!         drag_source(42u32, text("Drag me")),
+//!         drop_target(Message::Dropped, text("Drop here")),
+//!     ]
+//!     .spacing(20)
+//!     .into()
+//! }
+//! ```
+
+use iced::{
+    Background, Border, Color, Element, Length, Point, Rectangle, Size, Theme, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{Operation, Tree, tree},
+    },
+    event, keyboard, touch,
+};
+
+use crate::dnd::{self, DRAG_THRESHOLD, Preview};
+
+/// The state of a [`DragSource`].
+#[derive(Debug, Clone, Copy, Default)]
+struct SourceState {
+    /// The last known position of the cursor in the window.
+    cursor: Option<Point>,
+    /// Where the button was pressed in the window, until a drag starts.
+    pressed: Option<Point>,
+    /// The position of the cursor relatively to the top left corner of the element when it was grabbed.
+    grab: Vector,
+    /// Whether the element is being dragged.
+    dragging: bool,
+    /// Whether the payload was released and the drag should be finished.
+    released: bool,
+}
+
+/// An element that can be dragged, carrying a payload, created with [`drag_source`].
+///
+/// The payload can be dropped on any [`DropTarget`] accepting its type. Pressing Escape cancels the drag.
+pub struct DragSource<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    payload: T,
+    content: Element<'a, Message, Theme, Renderer>,
+    enabled: bool,
+    on_drag: Option<Box<dyn Fn(dnd::Event) -> Message + 'a>>,
+}
+
+impl<'a, T, Message, Theme, Renderer> DragSource<'a, T, Message, Theme, Renderer> {
+    /// Creates a new [`DragSource`] carrying `payload`.
+    pub fn new(payload: T, content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            payload,
+            content: content.into(),
+            enabled: true,
+            on_drag: None,
+        }
+    }
+
+    /// Sets whether the [`DragSource`] can be dragged.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Sets the message produced when the drag starts and when it ends, with the [`dnd::Event`]
+    /// telling whether the payload was dropped on a target.
+    pub fn on_drag(mut self, on_drag: impl Fn(dnd::Event) -> Message + 'a) -> Self {
+        self.on_drag = Some(Box::new(on_drag));
+        self
+    }
+
+    /// Publishes the message of a step of the drag, if any.
+    fn publish(&self, shell: &mut Shell<'_, Message>, event: dnd::Event) {
+        if let Some(on_drag) = &self.on_drag {
+            shell.publish(on_drag(event));
+        }
+    }
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DragSource<'_, T, Message, Theme, Renderer>
+where
+    T: Clone + 'static,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<SourceState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(SourceState::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<SourceState>();
+
+        if state.released {
+            state.released = false;
+            if let Some(end) = dnd::finish() {
+                self.publish(shell, end);
+            }
+        }
+
+        match event {
+            iced::Event::Mouse(mouse::Event::CursorMoved { position })
+            | iced::Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                state.cursor = Some(position);
+
+                if let Some(pressed) = state.pressed
+                    && pressed.distance(position) > DRAG_THRESHOLD
+                {
+                    state.pressed = None;
+                    state.dragging = true;
+                    dnd::start(self.payload.clone());
+                    self.publish(shell, dnd::Event::Started);
+                }
+
+                if state.dragging {
+                    shell.invalidate_layout();
+                    return event::Status::Captured;
+                }
+            }
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. })
+                if self.enabled =>
+            {
+                if let Some(position) = cursor.position_over(layout.bounds()) {
+                    state.pressed = state.cursor.or(Some(position));
+                    state.grab = position - layout.bounds().position();
+                }
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerLifted { .. })
+            | iced::Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed = None;
+
+                if state.dragging {
+                    state.dragging = false;
+                    state.released = true;
+                    dnd::release();
+                    shell.invalidate_layout();
+                    return event::Status::Captured;
+                }
+            }
+            iced::Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) if state.dragging => {
+                state.dragging = false;
+                dnd::cancel();
+                self.publish(shell, dnd::Event::Canceled);
+                shell.invalidate_layout();
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        if state.dragging {
+            return event::Status::Ignored;
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<SourceState>();
+
+        if state.dragging {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::default()
+            && self.enabled
+            && cursor.is_over(layout.bounds())
+        {
+            mouse::Interaction::Grab
+        } else {
+            interaction
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = *tree.state.downcast_ref::<SourceState>();
+
+        if state.dragging
+            && let Some(cursor) = state.cursor
+        {
+            return Some(
+                Preview::new(
+                    &self.content,
+                    &mut tree.children[0],
+                    cursor - state.grab,
+                    layout.bounds().size(),
+                )
+                .overlay(),
+            );
+        }
+
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<DragSource<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Clone + 'static,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(value: DragSource<'a, T, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`DragSource`] carrying `payload`.
+pub fn drag_source<'a, T, Message, Theme, Renderer>(
+    payload: T,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> DragSource<'a, T, Message, Theme, Renderer> {
+    DragSource::new(payload, content)
+}
+
+/// The status of a [`DropTarget`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Status {
+    /// Nothing that the [`DropTarget`] accepts is being dragged.
+    Idle,
+    /// A payload that the [`DropTarget`] accepts is being dragged elsewhere.
+    Active,
+    /// A payload that the [`DropTarget`] accepts is being dragged over it.
+    Hovered,
+}
+
+/// The appearance of a [`DropTarget`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Style {
+    /// The background drawn behind the content.
+    pub background: Option<Background>,
+    /// The border drawn around the content.
+    pub border: Border,
+}
+
+/// The theme catalog of a [`DropTarget`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`DropTarget`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`DropTarget`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    match status {
+        Status::Idle => Style::default(),
+        Status::Active => Style {
+            background: None,
+            border: Border {
+                color: palette.primary.weak.color,
+                width: 1.,
+                radius: 4.into(),
+            },
+        },
+        Status::Hovered => Style {
+            background: Some(palette.primary.weak.color.scale_alpha(0.3).into()),
+            border: Border {
+                color: palette.primary.strong.color,
+                width: 2.,
+                radius: 4.into(),
+            },
+        },
+    }
+}
+
+/// The filter on the payloads accepted by a [`DropTarget`].
+type Accept<'a, T> = Box<dyn Fn(&T) -> bool + 'a>;
+
+/// An element on which payloads of type `T` can be dropped, created with [`drop_target`].
+pub struct DropTarget<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    on_drop: Box<dyn Fn(T) -> Message + 'a>,
+    accept: Option<Accept<'a, T>>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, T, Message, Theme, Renderer> DropTarget<'a, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`DropTarget`] producing the message given by `on_drop` when a payload is dropped on it.
+    pub fn new(
+        on_drop: impl Fn(T) -> Message + 'a,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_drop: Box::new(on_drop),
+            accept: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets a filter on the payloads accepted by the [`DropTarget`].
+    ///
+    /// By default, all the payloads of type `T` are accepted.
+    pub fn accept(mut self, accept: impl Fn(&T) -> bool + 'a) -> Self {
+        self.accept = Some(Box::new(accept));
+        self
+    }
+
+    /// Sets the style of the [`DropTarget`].
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`DropTarget`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<T, Message, Theme, Renderer> DropTarget<'_, T, Message, Theme, Renderer>
+where
+    T: 'static,
+    Theme: Catalog,
+{
+    /// Returns whether an accepted payload is being dragged.
+    fn is_dragged(&self) -> bool {
+        dnd::with_payload(|payload| {
+            payload
+                .downcast_ref::<T>()
+                .is_some_and(|payload| self.accept.as_ref().is_none_or(|accept| accept(payload)))
+        })
+        .unwrap_or(false)
+    }
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DropTarget<'_, T, Message, Theme, Renderer>
+where
+    T: Clone + 'static,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let status = match self.is_dragged() {
+            true if cursor.is_over(bounds) => Status::Hovered,
+            true => Status::Active,
+            false => Status::Idle,
+        };
+        let appearance = theme.style(&self.class, status);
+
+        if let Some(background) = appearance.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        color: Color::TRANSPARENT,
+                        ..appearance.border
+                    },
+                    ..Default::default()
+                },
+                background,
+            );
+        }
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        if appearance.border.width > 0. {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: appearance.border,
+                    ..Default::default()
+                },
+                Color::TRANSPARENT,
+            );
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | iced::Event::Touch(touch::Event::FingerLifted { .. }) = event
+            && cursor.is_over(layout.bounds())
+            && let Some(payload) =
+                dnd::take(|payload: &T| self.accept.as_ref().is_none_or(|accept| accept(payload)))
+        {
+            shell.publish((self.on_drop)(payload));
+            return event::Status::Captured;
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<DropTarget<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Clone + 'static,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(value: DropTarget<'a, T, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`DropTarget`] producing the message given by `on_drop` when a payload is dropped on it.
+pub fn drop_target<'a, T, Message, Theme, Renderer>(
+    on_drop: impl Fn(T) -> Message + 'a,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> DropTarget<'a, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    DropTarget::new(on_drop, content)
+}
@@ -0,0 +1,379 @@
+//! A pad to draw freehand strokes on, drawn on a [`Canvas`].
+//!
+//! The [`DrawPad`] captures the strokes drawn with the mouse or a finger, and smooths them as they
+//! are drawn. When a stroke is finished, it produces a message with its points, which the
+//! application adds to its [`Drawing`]. The drawing keeps the strokes as polylines, can undo the
+//! last one or clear them all, and renders them to an image with [`Drawing::to_image`].
+//!
+//! This is the usual way to capture signatures or to annotate.
+
+use iced::{
+    Color, Element, Length, Point, Rectangle, Renderer, Theme,
+    mouse::{self, Cursor},
+    touch,
+    widget::canvas::{
+        self, Canvas, Frame, Geometry, LineCap, LineJoin, Path, Program, Stroke, event,
+    },
+};
+
+mod drawing;
+pub use drawing::*;
+
+/// The minimum distance between two consecutive points of a stroke.
+const MIN_DISTANCE: f32 = 1.5;
+
+/// The appearance of a [`DrawPad`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the pad.
+    pub background: Color,
+    /// The color of the strokes.
+    pub ink: Color,
+    /// The color of the border of the pad.
+    pub border: Color,
+    /// The color of the line to sign on, if any.
+    pub baseline: Option<Color>,
+}
+
+/// The theme catalog of a [`DrawPad`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`DrawPad`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`DrawPad`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color,
+        ink: palette.background.base.text,
+        border: palette.background.strong.color,
+        baseline: None,
+    }
+}
+
+/// The style of a [`DrawPad`] to sign on, with a baseline.
+pub fn signature(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        baseline: Some(palette.background.strong.color),
+        ..default(theme)
+    }
+}
+
+/// The state of a [`DrawPad`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    /// The points of the stroke being drawn.
+    stroke: Vec<Point>,
+    /// Whether the stroke is drawn with the mouse.
+    mouse: bool,
+    /// The finger drawing the stroke.
+    finger: Option<touch::Finger>,
+}
+
+/// A pad capturing freehand strokes, created with [`draw_pad`].
+pub struct DrawPad<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    drawing: &'a Drawing,
+    on_stroke: Box<dyn Fn(Vec<Point>) -> Message + 'a>,
+    smoothing: f32,
+    stroke_width: f32,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> DrawPad<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`DrawPad`] showing the given [`Drawing`].
+    ///
+    /// `on_stroke` is called with the points of each stroke when it is finished.
+    pub fn new(drawing: &'a Drawing, on_stroke: impl Fn(Vec<Point>) -> Message + 'a) -> Self {
+        Self {
+            drawing,
+            on_stroke: Box::new(on_stroke),
+            smoothing: 0.5,
+            stroke_width: 2.,
+            width: Length::Fill,
+            height: Length::Fixed(200.),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the smoothing of the strokes, from 0 (none) to 1 (excluded). 0.5 by default.
+    ///
+    /// Each point of a stroke only moves towards the pointer by `1 - smoothing` of the distance,
+    /// which evens out the jitter of the hand.
+    pub fn smoothing(mut self, smoothing: f32) -> Self {
+        self.smoothing = smoothing.clamp(0., 0.95);
+        self
+    }
+
+    /// Sets the width of the strokes.
+    pub fn stroke_width(mut self, stroke_width: f32) -> Self {
+        self.stroke_width = stroke_width;
+        self
+    }
+
+    /// Sets the width of the [`DrawPad`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`DrawPad`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`DrawPad`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`DrawPad`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Adds the position of the pointer to the stroke being drawn.
+    fn extend(&self, state: &mut State, position: Point) {
+        let Some(last) = state.stroke.last().copied() else {
+            state.stroke.push(position);
+            return;
+        };
+
+        let smoothed = last + (position - last) * (1. - self.smoothing);
+        if smoothed.distance(last) >= MIN_DISTANCE {
+            state.stroke.push(smoothed);
+        }
+    }
+
+    /// Finishes the stroke being drawn, ending it at the position of the pointer.
+    fn finish(&self, state: &mut State, position: Option<Point>) -> Option<Message> {
+        if let Some(position) = position
+            && state.stroke.last() != Some(&position)
+        {
+            state.stroke.push(position);
+        }
+
+        let stroke = std::mem::take(&mut state.stroke);
+        (!stroke.is_empty()).then(|| (self.on_stroke)(stroke))
+    }
+
+    /// Draws a stroke.
+    fn draw_stroke(&self, frame: &mut Frame, points: &[Point], color: Color) {
+        match points {
+            [] => {}
+            [point] => frame.fill(&Path::circle(*point, self.stroke_width / 2.), color),
+            [first, rest @ ..] => {
+                let path = Path::new(|path| {
+                    path.move_to(*first);
+                    rest.iter().for_each(|point| path.line_to(*point));
+                });
+
+                frame.stroke(
+                    &path,
+                    Stroke::default()
+                        .with_color(color)
+                        .with_width(self.stroke_width)
+                        .with_line_cap(LineCap::Round)
+                        .with_line_join(LineJoin::Round),
+                );
+            }
+        }
+    }
+}
+
+impl<Message, Theme> Program<Message, Theme> for DrawPad<'_, Message, Theme>
+where
+    Theme: Catalog,
+{
+    type State = State;
+
+    fn update(
+        &self,
+        state: &mut State,
+        event: canvas::Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> (event::Status, Option<Message>) {
+        let local = |position: Point| Point::new(position.x - bounds.x, position.y - bounds.y);
+
+        match event {
+            canvas::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if state.finger.is_none() =>
+            {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return (event::Status::Ignored, None);
+                };
+
+                state.mouse = true;
+                state.stroke = vec![position];
+                (event::Status::Captured, None)
+            }
+            canvas::Event::Mouse(mouse::Event::CursorMoved { position }) if state.mouse => {
+                self.extend(state, local(position));
+                (event::Status::Captured, None)
+            }
+            canvas::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.mouse =>
+            {
+                state.mouse = false;
+                let position = cursor.position().map(local);
+                (event::Status::Captured, self.finish(state, position))
+            }
+            canvas::Event::Touch(touch::Event::FingerPressed { id, position })
+                if !state.mouse && state.finger.is_none() && bounds.contains(position) =>
+            {
+                state.finger = Some(id);
+                state.stroke = vec![local(position)];
+                (event::Status::Captured, None)
+            }
+            canvas::Event::Touch(touch::Event::FingerMoved { id, position })
+                if state.finger == Some(id) =>
+            {
+                self.extend(state, local(position));
+                (event::Status::Captured, None)
+            }
+            canvas::Event::Touch(touch::Event::FingerLifted { id, position })
+                if state.finger == Some(id) =>
+            {
+                state.finger = None;
+                (
+                    event::Status::Captured,
+                    self.finish(state, Some(local(position))),
+                )
+            }
+            canvas::Event::Touch(touch::Event::FingerLost { id, .. })
+                if state.finger == Some(id) =>
+            {
+                state.finger = None;
+                (event::Status::Captured, self.finish(state, None))
+            }
+            _ => (event::Status::Ignored, None),
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let style = theme.style(&self.class);
+        let mut frame = Frame::new(renderer, bounds.size());
+        let size = frame.size();
+
+        frame.fill_rectangle(Point::ORIGIN, size, style.background);
+
+        if let Some(baseline) = style.baseline {
+            let y = size.height * 0.75;
+            let margin = size.width * 0.05;
+            frame.stroke(
+                &Path::line(Point::new(margin, y), Point::new(size.width - margin, y)),
+                Stroke::default().with_color(baseline).with_width(1.),
+            );
+        }
+
+        for stroke in self.drawing.strokes() {
+            self.draw_stroke(&mut frame, stroke, style.ink);
+        }
+        self.draw_stroke(&mut frame, &state.stroke, style.ink);
+
+        frame.stroke(
+            &Path::rectangle(Point::ORIGIN, size),
+            Stroke::default().with_color(style.border).with_width(1.),
+        );
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &State,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        if cursor.is_over(bounds) {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme> From<DrawPad<'a, Message, Theme>> for Element<'a, Message, Theme>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+{
+    fn from(value: DrawPad<'a, Message, Theme>) -> Self {
+        let (width, height) = (value.width, value.height);
+        Element::new(Canvas::new(value).width(width).height(height))
+    }
+}
+
+/// Creates a new [`DrawPad`] showing the given [`Drawing`].
+///
+/// ```
+/// use iced::Point;
+/// use more_iced_aw::draw_pad::{DrawPad, Drawing, draw_pad, signature};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Stroke(Vec<Point>),
+///     Undo,
+///     Clear,
+/// }
+///
+/// let drawing = Drawing::new();
+/// let pad: DrawPad<'_, Message> = draw_pad(&drawing, Message::Stroke)
+///     .stroke_width(3.)
+///     .style(signature);
+/// ```
+pub fn draw_pad<'a, Message, Theme>(
+    drawing: &'a Drawing,
+    on_stroke: impl Fn(Vec<Point>) -> Message + 'a,
+) -> DrawPad<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    DrawPad::new(drawing, on_stroke)
+}
@@ -0,0 +1,145 @@
+use iced::{Color, Point, Rectangle, Size, advanced::image};
+
+/// The strokes drawn on a [`DrawPad`](super::DrawPad), each one a polyline.
+///
+/// The points are in the coordinates of the pad, in logical pixels from its top left corner.
+///
+/// ```
+/// use iced::Point;
+/// use more_iced_aw::draw_pad::Drawing;
+///
+/// let mut drawing = Drawing::new();
+/// drawing.push(vec![Point::new(0., 0.), Point::new(10., 5.)]);
+/// drawing.push(vec![Point::new(2., 8.)]);
+/// assert_eq!(drawing.strokes().len(), 2);
+///
+/// drawing.undo();
+/// assert_eq!(drawing.strokes().len(), 1);
+///
+/// drawing.clear();
+/// assert!(drawing.is_empty());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Drawing {
+    #[cfg_attr(feature = "serde", serde(with = "crate::helpers::serde_iced"))]
+    strokes: Vec<Vec<Point>>,
+}
+
+impl Drawing {
+    /// Creates a new empty [`Drawing`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the strokes of the [`Drawing`], in the order they were drawn.
+    pub fn strokes(&self) -> &[Vec<Point>] {
+        &self.strokes
+    }
+
+    /// Returns whether the [`Drawing`] has no stroke.
+    pub fn is_empty(&self) -> bool {
+        self.strokes.is_empty()
+    }
+
+    /// Adds a stroke to the [`Drawing`]. Empty strokes are ignored.
+    pub fn push(&mut self, stroke: Vec<Point>) {
+        if !stroke.is_empty() {
+            self.strokes.push(stroke);
+        }
+    }
+
+    /// Removes the last stroke of the [`Drawing`], and returns it.
+    pub fn undo(&mut self) -> Option<Vec<Point>> {
+        self.strokes.pop()
+    }
+
+    /// Removes all the strokes of the [`Drawing`].
+    pub fn clear(&mut self) {
+        self.strokes.clear();
+    }
+
+    /// Returns the smallest rectangle containing all the points of the [`Drawing`], if it is not
+    /// empty.
+    pub fn bounds(&self) -> Option<Rectangle> {
+        let mut points = self.strokes.iter().flatten();
+        let first = *points.next()?;
+
+        let (min, max) = points.fold((first, first), |(min, max), point| {
+            (
+                Point::new(min.x.min(point.x), min.y.min(point.y)),
+                Point::new(max.x.max(point.x), max.y.max(point.y)),
+            )
+        });
+
+        Some(Rectangle::new(min, Size::new(max.x - min.x, max.y - min.y)))
+    }
+
+    /// Renders the [`Drawing`] to RGBA pixels, row by row, on a transparent background.
+    ///
+    /// The image has the given size, one pixel per logical pixel of the pad, and the strokes have
+    /// the given width and color. Their edges are antialiased.
+    pub fn to_rgba(&self, size: Size<u32>, stroke_width: f32, color: Color) -> Vec<u8> {
+        let (width, height) = (size.width as usize, size.height as usize);
+        let mut coverage = vec![0f32; width * height];
+        let radius = stroke_width / 2.;
+
+        let mut paint = |a: Point, b: Point| {
+            let margin = radius + 1.;
+            let (left, right) = (a.x.min(b.x) - margin, a.x.max(b.x) + margin);
+            let (top, bottom) = (a.y.min(b.y) - margin, a.y.max(b.y) + margin);
+
+            let columns = left.max(0.) as usize..(right.max(0.) as usize + 1).min(width);
+            let rows = top.max(0.) as usize..(bottom.max(0.) as usize + 1).min(height);
+
+            for y in rows {
+                for x in columns.clone() {
+                    let center = Point::new(x as f32 + 0.5, y as f32 + 0.5);
+                    let alpha = (radius + 0.5 - segment_distance(center, a, b)).clamp(0., 1.);
+
+                    let pixel = &mut coverage[y * width + x];
+                    *pixel = pixel.max(alpha);
+                }
+            }
+        };
+
+        for stroke in &self.strokes {
+            match stroke.as_slice() {
+                [point] => paint(*point, *point),
+                points => points
+                    .windows(2)
+                    .for_each(|segment| paint(segment[0], segment[1])),
+            }
+        }
+
+        let [r, g, b, a] = color.into_rgba8();
+
+        coverage
+            .into_iter()
+            .flat_map(|alpha| [r, g, b, (f32::from(a) * alpha).round() as u8])
+            .collect()
+    }
+
+    /// Renders the [`Drawing`] to an image, see [`to_rgba`](Self::to_rgba).
+    pub fn to_image(&self, size: Size<u32>, stroke_width: f32, color: Color) -> image::Handle {
+        image::Handle::from_rgba(
+            size.width,
+            size.height,
+            self.to_rgba(size, stroke_width, color),
+        )
+    }
+}
+
+/// Returns the distance from a point to the segment from `a` to `b`.
+fn segment_distance(point: Point, a: Point, b: Point) -> f32 {
+    let segment = b - a;
+    let length = segment.x * segment.x + segment.y * segment.y;
+
+    let t = if length > 0. {
+        (((point.x - a.x) * segment.x + (point.y - a.y) * segment.y) / length).clamp(0., 1.)
+    } else {
+        0.
+    };
+
+    point.distance(a + segment * t)
+}
@@ -0,0 +1,554 @@
+//! A panel that slides in from an edge, over or beside some content.
+//!
+//! The panel is a layer following the rules of [`layers`](crate::layers): in [`Mode::Overlay`],
+//! its backdrop is a scrim blocking the input of the content, and it is dismissed according to a
+//! [`Dismiss`] policy.
+
+use std::time::Duration;
+
+use iced::{
+    Background, Color, Element, Length, Pixels, Point, Rectangle, Shadow, Size, Theme, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{Operation, Tree, tree},
+    },
+    event, window,
+};
+
+use crate::{
+    animation::Timeline,
+    layers::{self, Dismiss},
+};
+
+/// The edge of the window from which a [`Drawer`] slides in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Edge {
+    /// The left edge.
+    #[default]
+    Left,
+    /// The right edge.
+    Right,
+    /// The top edge.
+    Top,
+    /// The bottom edge.
+    Bottom,
+}
+
+impl Edge {
+    /// Returns whether the [`Edge`] is horizontal (top or bottom).
+    fn is_horizontal(self) -> bool {
+        matches!(self, Edge::Top | Edge::Bottom)
+    }
+}
+
+/// How the panel of a [`Drawer`] is placed relatively to the content.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Mode {
+    /// The panel is drawn over the content, which is covered by a backdrop.
+    #[default]
+    Overlay,
+    /// The panel pushes the content, which shrinks to leave it room.
+    Beside,
+}
+
+/// The appearance of a [`Drawer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the panel.
+    pub background: Background,
+    /// The shadow of the panel.
+    pub shadow: Shadow,
+    /// The color of the backdrop covering the content when the panel is fully open,
+    /// in [`Mode::Overlay`].
+    pub backdrop: Color,
+}
+
+/// The theme catalog of a [`Drawer`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Drawer`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Drawer`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.3),
+            offset: Vector::ZERO,
+            blur_radius: 12.,
+        },
+        backdrop: Color::BLACK.scale_alpha(0.4),
+    }
+}
+
+/// A panel that slides in from an edge of some content, created with [`drawer`].
+///
+/// The panel is opened and closed with an animation when the `open` flag changes.
+/// By default, clicking on the backdrop in [`Mode::Overlay`] dismisses the [`Drawer`], as does
+/// pressing Escape in both modes (see [`Drawer::on_dismiss`] and [`Drawer::dismiss`]).
+pub struct Drawer<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    panel: Element<'a, Message, Theme, Renderer>,
+    open: bool,
+    edge: Edge,
+    mode: Mode,
+    size: f32,
+    duration: Duration,
+    on_dismiss: Option<Message>,
+    dismiss: Dismiss,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Drawer<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Drawer`] with the given content and panel.
+    pub fn new(
+        open: bool,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        panel: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            panel: panel.into(),
+            open,
+            edge: Edge::default(),
+            mode: Mode::default(),
+            size: 250.,
+            duration: Duration::from_millis(200),
+            on_dismiss: None,
+            dismiss: Dismiss::default(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the edge from which the panel slides in.
+    pub fn edge(mut self, edge: Edge) -> Self {
+        self.edge = edge;
+        self
+    }
+
+    /// Sets whether the panel is drawn over the content or beside it.
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the size of the panel: its width for the left and right edges, and its height otherwise.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the duration of the open and close animation.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the message produced when the [`Drawer`] is dismissed, by clicking on the backdrop
+    /// or pressing Escape.
+    pub fn on_dismiss(mut self, on_dismiss: Message) -> Self {
+        self.on_dismiss = Some(on_dismiss);
+        self
+    }
+
+    /// Sets how the [`Drawer`] is dismissed, [`Dismiss::ANY`] by default.
+    ///
+    /// In [`Mode::Beside`], there is no backdrop, so clicks outside never dismiss it.
+    pub fn dismiss(mut self, dismiss: Dismiss) -> Self {
+        self.dismiss = dismiss;
+        self
+    }
+
+    /// Sets the style of the [`Drawer`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Drawer`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Drawer<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Timeline>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Timeline::new(self.open, self.duration))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content), Tree::new(&self.panel)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content, &self.panel]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let bounds = limits.max();
+        let visible = self.size * tree.state.downcast_ref::<Timeline>().value();
+
+        let (content_size, content_position) = match (self.mode, self.edge) {
+            (Mode::Overlay, _) => (bounds, Point::ORIGIN),
+            (Mode::Beside, Edge::Left) => (
+                Size::new((bounds.width - visible).max(0.), bounds.height),
+                Point::new(visible, 0.),
+            ),
+            (Mode::Beside, Edge::Right) => (
+                Size::new((bounds.width - visible).max(0.), bounds.height),
+                Point::ORIGIN,
+            ),
+            (Mode::Beside, Edge::Top) => (
+                Size::new(bounds.width, (bounds.height - visible).max(0.)),
+                Point::new(0., visible),
+            ),
+            (Mode::Beside, Edge::Bottom) => (
+                Size::new(bounds.width, (bounds.height - visible).max(0.)),
+                Point::ORIGIN,
+            ),
+        };
+
+        let content = self
+            .content
+            .as_widget()
+            .layout(
+                &mut tree.children[0],
+                renderer,
+                &Limits::new(Size::ZERO, content_size),
+            )
+            .move_to(content_position);
+
+        let panel_size = if self.edge.is_horizontal() {
+            Size::new(bounds.width, self.size)
+        } else {
+            Size::new(self.size, bounds.height)
+        };
+
+        let panel_position = match self.edge {
+            Edge::Left => Point::new(visible - self.size, 0.),
+            Edge::Right => Point::new(bounds.width - visible, 0.),
+            Edge::Top => Point::new(0., visible - self.size),
+            Edge::Bottom => Point::new(0., bounds.height - visible),
+        };
+
+        let panel = self
+            .panel
+            .as_widget()
+            .layout(
+                &mut tree.children[1],
+                renderer,
+                &Limits::new(panel_size, panel_size),
+            )
+            .move_to(panel_position);
+
+        Node::with_children(bounds, vec![content, panel])
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let timeline = tree.state.downcast_ref::<Timeline>();
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let (content, panel) = (children.next().unwrap(), children.next().unwrap());
+        let drawer = theme.style(&self.class);
+
+        let covered = self.mode == Mode::Overlay && timeline.progress() > 0.;
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content,
+            if covered {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            },
+            viewport,
+        );
+
+        if timeline.progress() <= 0. {
+            return;
+        }
+
+        renderer.with_layer(bounds, |renderer| {
+            if covered {
+                layers::draw_scrim(
+                    renderer,
+                    bounds,
+                    drawer.backdrop.scale_alpha(timeline.value()),
+                );
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: panel.bounds(),
+                    shadow: drawer.shadow,
+                    ..renderer::Quad::default()
+                },
+                drawer.background,
+            );
+
+            self.panel.as_widget().draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                style,
+                panel,
+                cursor,
+                viewport,
+            );
+        });
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            [&self.content, &self.panel]
+                .into_iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((element, tree), layout)| {
+                    element
+                        .as_widget()
+                        .operate(tree, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let timeline = tree.state.downcast_mut::<Timeline>();
+        timeline.go(self.open);
+        timeline.set_duration(self.duration);
+
+        if let iced::Event::Window(window::Event::RedrawRequested(now)) = event
+            && timeline.is_animating()
+        {
+            if timeline.tick(now) {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+            shell.invalidate_layout();
+        }
+
+        let progress = timeline.progress();
+        let mut children = layout.children();
+        let (content, panel) = (children.next().unwrap(), children.next().unwrap());
+
+        if progress > 0. {
+            let status = self.panel.as_widget_mut().on_event(
+                &mut tree.children[1],
+                event.clone(),
+                panel,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        let dismiss = match self.mode {
+            Mode::Overlay => self.dismiss,
+            Mode::Beside => Dismiss {
+                outside_click: false,
+                ..self.dismiss
+            },
+        };
+
+        if self.open
+            && let Some(on_dismiss) = self.on_dismiss.clone()
+            && dismiss.applies(&event, cursor, &[panel.bounds()])
+        {
+            shell.publish(on_dismiss);
+
+            if Dismiss::status(&event) == event::Status::Captured {
+                return event::Status::Captured;
+            }
+        }
+
+        let covered = self.mode == Mode::Overlay && progress > 0.;
+
+        if covered && layers::is_input(&event) {
+            return if cursor.is_over(layout.bounds()) {
+                event::Status::Captured
+            } else {
+                event::Status::Ignored
+            };
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            content,
+            if covered {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            },
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let progress = tree.state.downcast_ref::<Timeline>().progress();
+        let mut children = layout.children();
+        let (content, panel) = (children.next().unwrap(), children.next().unwrap());
+
+        if progress > 0. && cursor.is_over(panel.bounds()) {
+            self.panel.as_widget().mouse_interaction(
+                &tree.children[1],
+                panel,
+                cursor,
+                viewport,
+                renderer,
+            )
+        } else if self.mode == Mode::Overlay && progress > 0. {
+            mouse::Interaction::default()
+        } else {
+            self.content.as_widget().mouse_interaction(
+                &tree.children[0],
+                content,
+                cursor,
+                viewport,
+                renderer,
+            )
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        layers::group(
+            [&mut self.content, &mut self.panel]
+                .into_iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .map(|((element, tree), layout)| {
+                    element
+                        .as_widget_mut()
+                        .overlay(tree, layout, renderer, translation)
+                }),
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Drawer<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(value: Drawer<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`Drawer`], which shows the `panel` over or beside the `content` when `open` is true.
+pub fn drawer<'a, Message, Theme, Renderer>(
+    open: bool,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    panel: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Drawer<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    Drawer::new(open, content, panel)
+}
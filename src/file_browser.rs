@@ -0,0 +1,762 @@
+//! A ready-made file browser, listing the directories and files under a root directory.
+//!
+//! The listings are kept in a [`Content`], which reads the file system when a directory is expanded
+//! or when [`Content::refresh`] is called. The directories are read on a background thread:
+//! [`Content::update`] and [`Content::refresh`] return a [`Task`] that the application runs,
+//! and which gives the listings back as [`Action::Loaded`]. The [`FileBrowser`] widget only
+//! displays the [`Content`], showing a loading row for the directories being read.
+//!
+//! There is no tree widget in this crate yet, so the [`FileBrowser`] draws its own
+//! indented rows, with a toggle in front of each directory.
+//!
+//! The icons of the entries are drawn with the colors of the [`Style`], and can be replaced
+//! with [`FileBrowser::icon`] (for example to use an icon font).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced::{Element, Task};
+//! use more_iced_aw::file_browser::*;
+//!
+//! struct App {
+//!     content: Content,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Browser(Action),
+//! }
+//!
+//! impl App {
+//!     fn update(&mut self, message: Message) -> Task<Message> {
+//!         let Message::Browser(action) = message;
+//!         let (event, task) = self.content.update(action);
+//!         if let Some(Event::Opened(path)) = event {
+//!             println!("Opened {}", path.display());
+//!         }
+//!         task.map(Message::Browser)
+//!     }
+//!
+//!     fn view(&self) -> Element<'_, Message> {
+//!         file_browser(&self.content, Message::Browser).into()
+//!     }
+//! }
+//!
+//! fn main() -> iced::Result {
+//!     iced::application("File browser", App::update, App::view)
+//!         .run_with(|| {
+//!             let (content, task) = Content::new(".");
+//!             (App { content }, task.map(Message::Browser))
+//!         })
+//! }
+//! ```
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+    time::{Duration, Instant, SystemTime},
+};
+
+use iced::{
+    Alignment, Background, Border, Color, Element, Length, Pixels, Task, Theme,
+    futures::channel::oneshot,
+    widget::{Column, Space, button, checkbox, column, container, row, scrollable, text},
+};
+
+use crate::helpers::background_states;
+
+/// The key used to sort the entries of a directory.
+///
+/// Directories are always listed before files.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SortBy {
+    /// Sort by name, ignoring case.
+    #[default]
+    Name,
+    /// Sort by size.
+    Size,
+    /// Sort by last modification time.
+    Modified,
+}
+
+impl SortBy {
+    /// All the sort keys, in the order they are displayed.
+    pub const ALL: [SortBy; 3] = [SortBy::Name, SortBy::Size, SortBy::Modified];
+
+    /// Returns the name of the sort key.
+    fn label(self) -> &'static str {
+        match self {
+            SortBy::Name => "Name",
+            SortBy::Size => "Size",
+            SortBy::Modified => "Modified",
+        }
+    }
+}
+
+/// A directory or file listed by a [`Content`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileEntry {
+    /// The full path of the entry.
+    pub path: PathBuf,
+    /// The file name of the entry.
+    pub name: String,
+    /// Whether the entry is a directory.
+    pub is_dir: bool,
+    /// The size of the entry in bytes, zero for directories.
+    pub size: u64,
+    /// The last modification time of the entry, if available.
+    pub modified: Option<SystemTime>,
+}
+
+impl FileEntry {
+    /// Returns whether the entry is hidden, that is, if its name starts with a dot.
+    pub fn is_hidden(&self) -> bool {
+        self.name.starts_with('.')
+    }
+}
+
+/// An interaction with a [`FileBrowser`], to be given to [`Content::update`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// A row was clicked.
+    Click(PathBuf),
+    /// The toggle of a directory was clicked.
+    Toggle(PathBuf),
+    /// The hidden files checkbox was toggled.
+    ShowHidden(bool),
+    /// A sort key was clicked. Clicking the current key reverses the order.
+    Sort(SortBy),
+    /// A directory was read by the [`Task`] of [`Content::update`] or [`Content::refresh`].
+    Loaded(PathBuf, Result<Vec<FileEntry>, String>),
+}
+
+/// What happened after a [`Content::update`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// An entry was selected by a single click.
+    Selected(PathBuf),
+    /// An entry was opened by a double click.
+    Opened(PathBuf),
+}
+
+/// The time between two clicks on the same entry for them to open it.
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+/// The state of a [`FileBrowser`].
+///
+/// When serialized with serde, the listings of the directories are left out: run the [`Task`] of
+/// [`Content::refresh`] after deserializing to read them again.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Content {
+    root: PathBuf,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    listings: HashMap<PathBuf, Result<Vec<FileEntry>, String>>,
+    expanded: HashSet<PathBuf>,
+    selected: Option<PathBuf>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_click: Option<(PathBuf, Instant)>,
+    show_hidden: bool,
+    sort: SortBy,
+    reversed: bool,
+}
+
+impl Content {
+    /// Creates a new [`Content`] listing the given root directory.
+    ///
+    /// The returned [`Task`] reads the root directory.
+    pub fn new(root: impl Into<PathBuf>) -> (Self, Task<Action>) {
+        let root = root.into();
+        let content = Self {
+            listings: HashMap::new(),
+            expanded: HashSet::from([root.clone()]),
+            root,
+            selected: None,
+            last_click: None,
+            show_hidden: false,
+            sort: SortBy::default(),
+            reversed: false,
+        };
+        let task = content.refresh();
+        (content, task)
+    }
+
+    /// Returns the root directory.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the selected entry, if any.
+    pub fn selected(&self) -> Option<&Path> {
+        self.selected.as_deref()
+    }
+
+    /// Returns whether hidden files are shown.
+    pub fn show_hidden(&self) -> bool {
+        self.show_hidden
+    }
+
+    /// Returns the sort key and whether the order is reversed.
+    pub fn sort(&self) -> (SortBy, bool) {
+        (self.sort, self.reversed)
+    }
+
+    /// Returns whether the given directory is expanded.
+    pub fn is_expanded(&self, path: &Path) -> bool {
+        self.expanded.contains(path)
+    }
+
+    /// Returns whether the given expanded directory is being read.
+    pub fn is_loading(&self, path: &Path) -> bool {
+        self.expanded.contains(path) && !self.listings.contains_key(path)
+    }
+
+    /// Reads again all the expanded directories from the file system.
+    ///
+    /// The current listings are kept until the returned [`Task`] gives the new ones.
+    pub fn refresh(&self) -> Task<Action> {
+        Task::batch(self.expanded.iter().cloned().map(load))
+    }
+
+    /// Updates the [`Content`] with an [`Action`] of the [`FileBrowser`].
+    ///
+    /// Returns an [`Event`] when an entry was selected or opened, and a [`Task`] reading
+    /// the directories that were expanded. Opening a directory also expands or collapses it.
+    pub fn update(&mut self, action: Action) -> (Option<Event>, Task<Action>) {
+        match action {
+            Action::Click(path) => {
+                let now = Instant::now();
+                let double = self.last_click.as_ref().is_some_and(|(last, at)| {
+                    *last == path && now.saturating_duration_since(*at) <= DOUBLE_CLICK
+                });
+
+                self.selected = Some(path.clone());
+
+                if double {
+                    self.last_click = None;
+                    let task = if self.is_dir(&path) {
+                        self.toggle(path.clone())
+                    } else {
+                        Task::none()
+                    };
+                    (Some(Event::Opened(path)), task)
+                } else {
+                    self.last_click = Some((path.clone(), now));
+                    (Some(Event::Selected(path)), Task::none())
+                }
+            }
+            Action::Toggle(path) => (None, self.toggle(path)),
+            Action::ShowHidden(show_hidden) => {
+                self.show_hidden = show_hidden;
+                (None, Task::none())
+            }
+            Action::Sort(sort) => {
+                if self.sort == sort {
+                    self.reversed = !self.reversed;
+                } else {
+                    self.sort = sort;
+                    self.reversed = false;
+                }
+                self.sort_listings();
+                (None, Task::none())
+            }
+            Action::Loaded(path, mut listing) => {
+                // The directory may have been collapsed while it was read.
+                if self.expanded.contains(&path) {
+                    if let Ok(entries) = &mut listing {
+                        sort_entries(entries, self.sort, self.reversed);
+                    }
+                    self.listings.insert(path, listing);
+                }
+                (None, Task::none())
+            }
+        }
+    }
+
+    /// Returns whether the given path is a listed directory, without reading the file system.
+    fn is_dir(&self, path: &Path) -> bool {
+        self.listings
+            .values()
+            .flatten()
+            .flatten()
+            .any(|entry| entry.is_dir && entry.path == path)
+    }
+
+    /// Expands or collapses a directory, returning the [`Task`] reading it when expanded.
+    fn toggle(&mut self, path: PathBuf) -> Task<Action> {
+        if self.expanded.remove(&path) {
+            self.listings.remove(&path);
+            Task::none()
+        } else {
+            self.expanded.insert(path.clone());
+            load(path)
+        }
+    }
+
+    /// Sorts all the listings with the current sort key.
+    fn sort_listings(&mut self) {
+        for entries in self.listings.values_mut().flatten() {
+            sort_entries(entries, self.sort, self.reversed);
+        }
+    }
+
+    /// Returns the visible rows, with their depth, in display order.
+    fn rows(&self) -> Vec<(usize, Row<'_>)> {
+        let mut rows = Vec::new();
+        self.push_rows(&self.root, 0, &mut rows);
+        rows
+    }
+
+    /// Pushes the visible rows of the given directory and its expanded children.
+    fn push_rows<'a>(&'a self, path: &Path, depth: usize, rows: &mut Vec<(usize, Row<'a>)>) {
+        match self.listings.get(path) {
+            Some(Ok(entries)) => {
+                for entry in entries {
+                    if entry.is_hidden() && !self.show_hidden {
+                        continue;
+                    }
+                    rows.push((depth, Row::Entry(entry)));
+                    if entry.is_dir && self.expanded.contains(&entry.path) {
+                        self.push_rows(&entry.path, depth + 1, rows);
+                    }
+                }
+            }
+            Some(Err(error)) => rows.push((depth, Row::Error(error))),
+            None if self.expanded.contains(path) => rows.push((depth, Row::Loading)),
+            None => {}
+        }
+    }
+}
+
+/// A visible row of a [`FileBrowser`].
+enum Row<'a> {
+    /// An entry of a directory.
+    Entry(&'a FileEntry),
+    /// The error that occured when reading a directory.
+    Error(&'a str),
+    /// A directory being read.
+    Loading,
+}
+
+/// Returns a [`Task`] reading the entries of a directory on a background thread,
+/// so that big or remote directories do not block the user interface.
+fn load(path: PathBuf) -> Task<Action> {
+    let read = path.clone();
+
+    let listing = async move {
+        let (sender, receiver) = oneshot::channel();
+        std::thread::spawn(move || {
+            let _ = sender.send(read_dir(&read));
+        });
+
+        receiver
+            .await
+            .unwrap_or_else(|_| Err("the directory could not be read".to_string()))
+    };
+
+    Task::perform(listing, move |listing| {
+        Action::Loaded(path.clone(), listing)
+    })
+}
+
+/// Reads the entries of a directory, without sorting them.
+fn read_dir(path: &Path) -> Result<Vec<FileEntry>, String> {
+    fs::read_dir(path)
+        .map_err(|error| error.to_string())?
+        .map(|entry| {
+            let entry = entry.map_err(|error| error.to_string())?;
+            let metadata = entry.metadata().map_err(|error| error.to_string())?;
+            let path = entry.path();
+            let is_dir = path.is_dir();
+
+            Ok(FileEntry {
+                name: entry.file_name().to_string_lossy().into_owned(),
+                path,
+                is_dir,
+                size: if is_dir { 0 } else { metadata.len() },
+                modified: metadata.modified().ok(),
+            })
+        })
+        .collect()
+}
+
+/// Sorts entries with directories first, then by the given key.
+fn sort_entries(entries: &mut [FileEntry], sort: SortBy, reversed: bool) {
+    entries.sort_by(|a, b| {
+        let order = match sort {
+            SortBy::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            SortBy::Size => a.size.cmp(&b.size),
+            SortBy::Modified => a.modified.cmp(&b.modified),
+        };
+        let order = if reversed { order.reverse() } else { order };
+
+        b.is_dir.cmp(&a.is_dir).then(order)
+    });
+}
+
+/// Formats a size in bytes with a binary unit.
+fn format_size(size: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+    let mut value = size as f64;
+    let mut unit = 0;
+    while value >= 1024. && unit < UNITS.len() - 1 {
+        value /= 1024.;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{size} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+/// The appearance of a [`FileBrowser`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the browser.
+    pub background: Background,
+    /// The border of the browser.
+    pub border: Border,
+    /// The color of the names of the entries.
+    pub text_color: Color,
+    /// The background of a hovered entry.
+    pub hovered_background: Background,
+    /// The background of the selected entry.
+    pub selected_background: Background,
+    /// The color of the name of the selected entry.
+    pub selected_text_color: Color,
+    /// The color of the drawn icons of the directories.
+    pub directory_icon: Color,
+    /// The color of the drawn icons of the files.
+    pub file_icon: Color,
+}
+
+/// The theme catalog of a [`FileBrowser`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`FileBrowser`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`FileBrowser`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weak.color.into(),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.,
+            radius: 5.into(),
+        },
+        text_color: palette.background.base.text,
+        hovered_background: background_states(palette.background.weak.color.into()).hovered,
+        selected_background: palette.primary.base.color.into(),
+        selected_text_color: palette.primary.base.text,
+        directory_icon: palette.primary.strong.color,
+        file_icon: palette.background.strong.text,
+    }
+}
+
+/// A function producing the icon of an entry.
+type IconFn<'a, Message> = Box<dyn Fn(&FileEntry) -> Element<'a, Message> + 'a>;
+
+/// A view of a [`Content`], created with [`file_browser`].
+///
+/// It shows a toolbar with the sort keys and a hidden files toggle, then the entries.
+/// Every interaction produces an [`Action`] that should be given back to [`Content::update`].
+pub struct FileBrowser<'a, Message> {
+    content: &'a Content,
+    on_action: Box<dyn Fn(Action) -> Message + 'a>,
+    icon: Option<IconFn<'a, Message>>,
+    indent: f32,
+    text_size: Pixels,
+    width: Length,
+    height: Length,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Message> FileBrowser<'a, Message> {
+    /// Creates a new [`FileBrowser`] of the given [`Content`].
+    pub fn new(content: &'a Content, on_action: impl Fn(Action) -> Message + 'a) -> Self {
+        Self {
+            content,
+            on_action: Box::new(on_action),
+            icon: None,
+            indent: 16.,
+            text_size: Pixels(14.),
+            width: Length::Fill,
+            height: Length::Fill,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets the indentation of each level of the tree.
+    pub fn indent(mut self, indent: impl Into<Pixels>) -> Self {
+        self.indent = indent.into().0;
+        self
+    }
+
+    /// Sets the icon displayed in front of each entry, replacing the drawn ones.
+    pub fn icon(mut self, icon: impl Fn(&FileEntry) -> Element<'a, Message> + 'a) -> Self {
+        self.icon = Some(Box::new(icon));
+        self
+    }
+
+    /// Sets the text size of the entries.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = text_size.into();
+        self
+    }
+
+    /// Sets the width of the [`FileBrowser`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`FileBrowser`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`FileBrowser`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self {
+        self.class = Box::new(style);
+        self
+    }
+
+    /// Sets the style class of the [`FileBrowser`].
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message: Clone + 'a> From<FileBrowser<'a, Message>> for Element<'a, Message> {
+    fn from(value: FileBrowser<'a, Message>) -> Self {
+        let content = value.content;
+        let on_action = &value.on_action;
+        let (current, reversed) = content.sort();
+        let class = Rc::new(value.class);
+
+        let sorts = SortBy::ALL.into_iter().map(|sort| {
+            let label = match (sort == current, reversed) {
+                (true, false) => format!("{} ▲", sort.label()),
+                (true, true) => format!("{} ▼", sort.label()),
+                (false, _) => sort.label().to_string(),
+            };
+
+            button(text(label).size(13))
+                .style(if sort == current {
+                    button::secondary
+                } else {
+                    button::text
+                })
+                .on_press(on_action(Action::Sort(sort)))
+                .into()
+        });
+
+        let show_hidden = on_action(Action::ShowHidden(!content.show_hidden()));
+        let toolbar = row(sorts)
+            .push(Space::with_width(Length::Fill))
+            .push(
+                checkbox("Hidden files", content.show_hidden())
+                    .on_toggle(move |_| show_hidden.clone())
+                    .size(14)
+                    .text_size(13),
+            )
+            .spacing(4)
+            .align_y(Alignment::Center);
+
+        let rows = content.rows().into_iter().map(|(depth, entry)| {
+            let indent = Space::with_width(depth as f32 * value.indent);
+
+            let entry = match entry {
+                Row::Entry(entry) => entry,
+                Row::Error(error) => {
+                    return row![
+                        indent,
+                        text(error.to_string())
+                            .size(value.text_size)
+                            .style(text::danger)
+                    ]
+                    .padding([2, 4])
+                    .into();
+                }
+                Row::Loading => {
+                    return row![
+                        indent,
+                        text("Loading…")
+                            .size(value.text_size)
+                            .style(text::secondary)
+                    ]
+                    .padding([2, 4])
+                    .into();
+                }
+            };
+
+            let toggle: Element<'a, Message> = if entry.is_dir {
+                let symbol = if content.is_expanded(&entry.path) {
+                    "▾"
+                } else {
+                    "▸"
+                };
+                button(text(symbol).size(value.text_size).width(12))
+                    .padding(0)
+                    .style(button::text)
+                    .on_press(on_action(Action::Toggle(entry.path.clone())))
+                    .into()
+            } else {
+                Space::with_width(12).into()
+            };
+
+            let icon = match &value.icon {
+                Some(icon) => icon(entry),
+                None => drawn_icon(entry.is_dir, value.text_size, class.clone()),
+            };
+            let size = if entry.is_dir {
+                String::new()
+            } else {
+                format_size(entry.size)
+            };
+
+            let label = row![
+                icon,
+                text(entry.name.clone())
+                    .size(value.text_size)
+                    .width(Length::Fill),
+                text(size).size(value.text_size).style(text::secondary),
+            ]
+            .spacing(6)
+            .align_y(Alignment::Center);
+
+            let selected = content.selected() == Some(entry.path.as_path());
+            let class = class.clone();
+
+            row![
+                indent,
+                toggle,
+                button(label)
+                    .width(Length::Fill)
+                    .padding([2, 4])
+                    .style(move |theme: &Theme, status| {
+                        let style = Catalog::style(theme, &class);
+
+                        let (background, text_color) = match status {
+                            _ if selected => {
+                                (Some(style.selected_background), style.selected_text_color)
+                            }
+                            button::Status::Hovered | button::Status::Pressed => {
+                                (Some(style.hovered_background), style.text_color)
+                            }
+                            button::Status::Active | button::Status::Disabled => {
+                                (None, style.text_color)
+                            }
+                        };
+
+                        button::Style {
+                            background,
+                            text_color,
+                            border: Border::default().rounded(style.border.radius),
+                            ..button::Style::default()
+                        }
+                    })
+                    .on_press(on_action(Action::Click(entry.path.clone())))
+            ]
+            .spacing(2)
+            .align_y(Alignment::Center)
+            .into()
+        });
+
+        let entries: Column<'a, Message> = Column::with_children(rows);
+
+        container(column![toolbar, scrollable(entries).height(Length::Fill)].spacing(4))
+            .width(value.width)
+            .height(value.height)
+            .style(move |theme: &Theme| {
+                let style = Catalog::style(theme, &class);
+
+                container::Style {
+                    background: Some(style.background),
+                    border: style.border,
+                    ..container::Style::default()
+                }
+            })
+            .padding(4)
+            .into()
+    }
+}
+
+/// Draws the icon of a directory or a file, sized after the text of the entries.
+fn drawn_icon<'a, Message: 'a>(
+    is_dir: bool,
+    text_size: Pixels,
+    class: Rc<<Theme as Catalog>::Class<'a>>,
+) -> Element<'a, Message> {
+    let size = text_size.0;
+
+    // A directory is a filled folder, a file an outlined sheet.
+    let (width, height) = if is_dir {
+        (size, size * 0.75)
+    } else {
+        (size * 0.7, size * 0.9)
+    };
+
+    let shape = container(Space::new(width, height)).style(move |theme: &Theme| {
+        let style = Catalog::style(theme, &class);
+
+        if is_dir {
+            container::Style {
+                background: Some(style.directory_icon.into()),
+                border: Border::default().rounded(size * 0.12),
+                ..container::Style::default()
+            }
+        } else {
+            container::Style {
+                border: Border {
+                    color: style.file_icon,
+                    width: 1.,
+                    radius: (size * 0.08).into(),
+                },
+                ..container::Style::default()
+            }
+        }
+    });
+
+    container(shape).center(size).into()
+}
+
+/// Creates a new [`FileBrowser`] of the given [`Content`].
+pub fn file_browser<'a, Message>(
+    content: &'a Content,
+    on_action: impl Fn(Action) -> Message + 'a,
+) -> FileBrowser<'a, Message> {
+    FileBrowser::new(content, on_action)
+}
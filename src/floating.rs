@@ -0,0 +1,350 @@
+//! An element floating above a corner or an edge of another one.
+//!
+//! The floating element is drawn through an overlay: it stays on top of the underlay even if the
+//! underlay is a scrollable, like a floating action button above a list. Like the layers of
+//! [`layers`](crate::layers), it is above the overlays of the underlay.
+
+use iced::{
+    Element, Length, Point, Rectangle, Size, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{Operation, Tree},
+    },
+    event,
+};
+
+use crate::layers;
+
+/// Where the element of a [`Floating`] is anchored on its underlay.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Anchor {
+    /// The top left corner.
+    TopLeft,
+    /// The middle of the top edge.
+    Top,
+    /// The top right corner.
+    TopRight,
+    /// The middle of the left edge.
+    Left,
+    /// The middle of the right edge.
+    Right,
+    /// The bottom left corner.
+    BottomLeft,
+    /// The middle of the bottom edge.
+    Bottom,
+    /// The bottom right corner.
+    #[default]
+    BottomRight,
+}
+
+impl Anchor {
+    /// Returns the position of an element of the given size anchored in the bounds.
+    ///
+    /// The offset moves the element towards the inside of the bounds.
+    fn position(self, bounds: Rectangle, size: Size, offset: Vector) -> Point {
+        use Anchor::*;
+
+        let x = match self {
+            TopLeft | Left | BottomLeft => bounds.x + offset.x,
+            Top | Bottom => bounds.center_x() - size.width / 2. + offset.x,
+            TopRight | Right | BottomRight => bounds.x + bounds.width - size.width - offset.x,
+        };
+
+        let y = match self {
+            TopLeft | Top | TopRight => bounds.y + offset.y,
+            Left | Right => bounds.center_y() - size.height / 2. + offset.y,
+            BottomLeft | Bottom | BottomRight => bounds.y + bounds.height - size.height - offset.y,
+        };
+
+        Point::new(x, y)
+    }
+}
+
+/// A widget displaying an element above an anchor of its underlay, created with [`floating`].
+pub struct Floating<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    underlay: Element<'a, Message, Theme, Renderer>,
+    element: Element<'a, Message, Theme, Renderer>,
+    anchor: Anchor,
+    offset: Vector,
+    hidden: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Floating<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Floating`] displaying `element` above `underlay`.
+    pub fn new(
+        underlay: impl Into<Element<'a, Message, Theme, Renderer>>,
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            underlay: underlay.into(),
+            element: element.into(),
+            anchor: Anchor::default(),
+            offset: Vector::new(16., 16.),
+            hidden: false,
+        }
+    }
+
+    /// Sets the [`Anchor`] of the floating element.
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Sets the offset of the floating element from its anchor, towards the inside of the underlay.
+    ///
+    /// On a centered edge, the offset along the edge moves the element right or down.
+    pub fn offset(mut self, offset: impl Into<Vector>) -> Self {
+        self.offset = offset.into();
+        self
+    }
+
+    /// Sets whether the floating element is hidden.
+    pub fn hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Floating<'_, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.underlay), Tree::new(&self.element)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.underlay, &self.element]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.underlay.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.underlay.as_widget().size_hint()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.underlay
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.underlay
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.underlay.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.underlay.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (underlay_tree, element_tree) = tree.children.split_at_mut(1);
+
+        let underlay = self.underlay.as_widget_mut().overlay(
+            &mut underlay_tree[0],
+            layout,
+            renderer,
+            translation,
+        );
+
+        let element = (!self.hidden).then(|| {
+            overlay::Element::new(Box::new(Overlay {
+                element: &mut self.element,
+                tree: &mut element_tree[0],
+                underlay: layout.bounds() + translation,
+                anchor: self.anchor,
+                offset: self.offset,
+            }))
+        });
+
+        layers::group([underlay, element])
+    }
+}
+
+/// The overlay displaying the element of a [`Floating`].
+struct Overlay<'a, 'b, Message, Theme, Renderer> {
+    element: &'b mut Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    underlay: Rectangle,
+    anchor: Anchor,
+    offset: Vector,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        let limits = Limits::new(Size::ZERO, bounds);
+        let node = self
+            .element
+            .as_widget()
+            .layout(self.tree, renderer, &limits);
+        let position = self
+            .anchor
+            .position(self.underlay, node.size(), self.offset);
+
+        node.move_to(position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+
+        self.element
+            .as_widget()
+            .draw(self.tree, renderer, theme, style, layout, cursor, &bounds);
+    }
+
+    fn operate(&mut self, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        self.element
+            .as_widget()
+            .operate(self.tree, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        self.element.as_widget_mut().on_event(
+            self.tree, event, layout, cursor, renderer, clipboard, shell, &bounds,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.element
+            .as_widget()
+            .mouse_interaction(self.tree, layout, cursor, viewport, renderer)
+    }
+
+    fn is_over(&self, layout: Layout<'_>, _renderer: &Renderer, cursor_position: Point) -> bool {
+        layout.bounds().contains(cursor_position)
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        self.element
+            .as_widget_mut()
+            .overlay(self.tree, layout, renderer, Vector::ZERO)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Floating<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(value: Floating<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`Floating`] displaying `element` above `underlay`.
+pub fn floating<'a, Message, Theme, Renderer>(
+    underlay: impl Into<Element<'a, Message, Theme, Renderer>>,
+    element: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Floating<'a, Message, Theme, Renderer> {
+    Floating::new(underlay, element)
+}
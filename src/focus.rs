@@ -0,0 +1,554 @@
+//! Keyboard focus management across several fields.
+//!
+//! A [`Chain`] lists focusable widgets by [`Id`], in the order the focus goes through them, and
+//! produces the [`Task`]s moving the focus along it: to the next or previous field, to the first
+//! one, or to the first [`ParsedInput`](crate::parsed_input::ParsedInput) holding an invalid text.
+//!
+//! A [`FocusScope`] makes Tab and Shift+Tab move the focus within its content, for example inside
+//! a modal or a form, wrapping around at the ends. It only handles the keys while one of its
+//! widgets is focused, so that several scopes can live side by side.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::num::ParseIntError;
+//!
+//! use iced::{Element, Task, widget::{button, column, text_input}};
+//! use more_iced_aw::{
+//!     focus::{Chain, focus_scope},
+//!     parsed_input::{Content, Parsed, ParsedInput},
+//! };
+//!
+//! struct App {
+//!     width: Content<u32, ParseIntError>,
+//!     height: Content<u32, ParseIntError>,
+//!     chain: Chain,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Width(Parsed<u32, ParseIntError>),
+//!     Height(Parsed<u32, ParseIntError>),
+//!     Submit,
+//! }
+//!
+//! impl App {
+//!     fn update(&mut self, message: Message) -> Task<Message> {
+//!         match message {
+//!             Message::Width(parsed) => self.width.update(parsed),
+//!             Message::Height(parsed) => self.height.update(parsed),
+//!             Message::Submit => return self.chain.focus_first_invalid(),
+//!         }
+//!         Task::none()
+//!     }
+//!
+//!     fn view(&self) -> Element<'_, Message> {
+//!         focus_scope(column![
+//!             ParsedInput::new("Width", &self.width).id("width").on_input(Message::Width),
+//!             ParsedInput::new("Height", &self.height).id("height").on_input(Message::Height),
+//!             button("Submit").on_press(Message::Submit),
+//!         ])
+//!         .chain(self.chain.clone())
+//!         .into()
+//!     }
+//! }
+//!
+//! fn main() -> iced::Result {
+//!     iced::application("Focus", App::update, App::view).run_with(|| {
+//!         let chain = Chain::with_ids([text_input::Id::new("width"), text_input::Id::new("height")]);
+//!         let focus = chain.focus_first();
+//!         let app = App {
+//!             width: Content::new(800),
+//!             height: Content::new(600),
+//!             chain,
+//!         };
+//!         (app, focus)
+//!     })
+//! }
+//! ```
+
+use std::any::Any;
+
+use iced::{
+    Element, Length, Rectangle, Size, Task, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{
+            self, Id, Operation, Tree,
+            operation::{Outcome, focusable},
+        },
+    },
+    event, keyboard, window,
+};
+
+use crate::parsed_input::ParseStatus;
+
+/// Where a [`Chain`] moves the focus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    /// The field after the focused one, or the first field if none is focused.
+    Next,
+    /// The field before the focused one, or the last field if none is focused.
+    Previous,
+    /// The first field.
+    First,
+    /// The first field holding an invalid text.
+    FirstInvalid,
+}
+
+/// An ordered list of focusable widgets, identified by their [`Id`].
+///
+/// Fields of the chain that are not in the interface are skipped, and the focus wraps around at
+/// the ends of the chain.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Chain {
+    ids: Vec<Id>,
+}
+
+impl Chain {
+    /// Creates a new empty [`Chain`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`Chain`] going through the given ids in order.
+    pub fn with_ids(ids: impl IntoIterator<Item = impl Into<Id>>) -> Self {
+        Self {
+            ids: ids.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Registers a focusable widget at the end of the [`Chain`].
+    pub fn push(&mut self, id: impl Into<Id>) {
+        self.ids.push(id.into());
+    }
+
+    /// Removes a widget from the [`Chain`].
+    pub fn remove(&mut self, id: impl Into<Id>) {
+        let id = id.into();
+        self.ids.retain(|other| *other != id);
+    }
+
+    /// Returns the ids of the [`Chain`], in order.
+    pub fn ids(&self) -> &[Id] {
+        &self.ids
+    }
+
+    /// Produces a [`Task`] that focuses the field after the focused one.
+    ///
+    /// If no field of the [`Chain`] is focused, the first one is.
+    pub fn focus_next<T: Send + 'static>(&self) -> Task<T> {
+        self.focus(Target::Next)
+    }
+
+    /// Produces a [`Task`] that focuses the field before the focused one.
+    ///
+    /// If no field of the [`Chain`] is focused, the last one is.
+    pub fn focus_previous<T: Send + 'static>(&self) -> Task<T> {
+        self.focus(Target::Previous)
+    }
+
+    /// Produces a [`Task`] that focuses the first field of the [`Chain`].
+    pub fn focus_first<T: Send + 'static>(&self) -> Task<T> {
+        self.focus(Target::First)
+    }
+
+    /// Produces a [`Task`] that focuses the first [`ParsedInput`](crate::parsed_input::ParsedInput)
+    /// of the [`Chain`] whose text is invalid, typically when a form is submitted.
+    ///
+    /// The focus does not change if all the inputs are valid.
+    pub fn focus_first_invalid<T: Send + 'static>(&self) -> Task<T> {
+        self.focus(Target::FirstInvalid)
+    }
+
+    /// Produces the [`Task`] moving the focus to the target.
+    fn focus<T: Send + 'static>(&self, target: Target) -> Task<T> {
+        widget::operate(Find::new(self.ids.clone(), target))
+    }
+}
+
+/// An [`Operation`] looking for the fields of a [`Chain`], then focusing its target.
+struct Find {
+    ids: Vec<Id>,
+    target: Target,
+    present: Vec<bool>,
+    invalid: Vec<bool>,
+    focused: Option<usize>,
+}
+
+impl Find {
+    /// Creates a new [`Find`] operation over the given ids.
+    fn new(ids: Vec<Id>, target: Target) -> Self {
+        Self {
+            present: vec![false; ids.len()],
+            invalid: vec![false; ids.len()],
+            ids,
+            target,
+            focused: None,
+        }
+    }
+
+    /// Returns the index in the chain of the widget with the given id.
+    fn index(&self, id: Option<&Id>) -> Option<usize> {
+        id.and_then(|id| self.ids.iter().position(|other| other == id))
+    }
+
+    /// Returns the index of the field to focus, if any.
+    fn target(&self) -> Option<usize> {
+        let count = self.ids.len();
+        let present = |&index: &usize| self.present[index];
+
+        match self.target {
+            Target::Next => {
+                let start = self.focused.map_or(0, |focused| focused + 1);
+                (0..count).map(|i| (start + i) % count).find(present)
+            }
+            Target::Previous => {
+                let start = self.focused.unwrap_or(0) + count;
+                (1..=count).map(|i| (start - i) % count).find(present)
+            }
+            Target::First => (0..count).find(present),
+            Target::FirstInvalid => {
+                (0..count).find(|&index| present(&index) && self.invalid[index])
+            }
+        }
+    }
+}
+
+impl<T: Send + 'static> Operation<T> for Find {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn focusable(&mut self, state: &mut dyn focusable::Focusable, id: Option<&Id>) {
+        if let Some(index) = self.index(id) {
+            self.present[index] = true;
+            if state.is_focused() {
+                self.focused = Some(index);
+            }
+        }
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        if let Some(index) = self.index(id)
+            && let Some(status) = state.downcast_ref::<ParseStatus>()
+        {
+            self.invalid[index] = !status.valid;
+        }
+    }
+
+    fn finish(&self) -> Outcome<T> {
+        match self.target() {
+            Some(index) => Outcome::Chain(Box::new(focusable::focus(self.ids[index].clone()))),
+            None => Outcome::None,
+        }
+    }
+}
+
+/// An [`Operation`] counting the focusable widgets, then focusing the one after or before the
+/// focused one, wrapping around at the ends.
+struct Cycle {
+    backward: bool,
+    total: usize,
+    focused: Option<usize>,
+    target: Option<usize>,
+    current: usize,
+}
+
+impl Cycle {
+    /// Creates a new [`Cycle`] operation, going backward or forward.
+    fn new(backward: bool) -> Self {
+        Self {
+            backward,
+            total: 0,
+            focused: None,
+            target: None,
+            current: 0,
+        }
+    }
+}
+
+impl Operation for Cycle {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn focusable(&mut self, state: &mut dyn focusable::Focusable, _id: Option<&Id>) {
+        match self.target {
+            None => {
+                if state.is_focused() {
+                    self.focused = Some(self.total);
+                }
+                self.total += 1;
+            }
+            Some(target) => {
+                if self.current == target {
+                    state.focus();
+                } else {
+                    state.unfocus();
+                }
+                self.current += 1;
+            }
+        }
+    }
+
+    fn finish(&self) -> Outcome<()> {
+        match (self.target, self.focused) {
+            (None, Some(focused)) => {
+                let target = if self.backward {
+                    (focused + self.total - 1) % self.total
+                } else {
+                    (focused + 1) % self.total
+                };
+
+                Outcome::Chain(Box::new(Cycle {
+                    target: Some(target),
+                    ..Cycle::new(self.backward)
+                }))
+            }
+            _ => Outcome::None,
+        }
+    }
+}
+
+/// Runs an [`Operation`] and the ones it chains to on the content of a [`FocusScope`].
+fn run<Message, Theme, Renderer>(
+    content: &Element<'_, Message, Theme, Renderer>,
+    tree: &mut Tree,
+    layout: Layout<'_>,
+    renderer: &Renderer,
+    mut operation: Box<dyn Operation<()>>,
+) where
+    Renderer: renderer::Renderer,
+{
+    loop {
+        content
+            .as_widget()
+            .operate(tree, layout, renderer, operation.as_mut());
+
+        match operation.finish() {
+            Outcome::Chain(next) => operation = next,
+            Outcome::None | Outcome::Some(()) => return,
+        }
+    }
+}
+
+/// A wrapper moving the focus within its content with Tab and Shift+Tab, created with
+/// [`focus_scope`].
+///
+/// Without a [`Chain`], the focus goes through all the focusable widgets of the content, in the
+/// order of the interface.
+pub struct FocusScope<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    chain: Option<Chain>,
+}
+
+impl<'a, Message, Theme, Renderer> FocusScope<'a, Message, Theme, Renderer> {
+    /// Creates a new [`FocusScope`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            chain: None,
+        }
+    }
+
+    /// Sets the [`Chain`] the focus goes through, instead of the order of the interface.
+    pub fn chain(mut self, chain: Chain) -> Self {
+        self.chain = Some(chain);
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for FocusScope<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let iced::Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(keyboard::key::Named::Tab),
+            modifiers,
+            ..
+        }) = event
+        else {
+            return status;
+        };
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        let tree = &mut tree.children[0];
+        let target = if modifiers.shift() {
+            Target::Previous
+        } else {
+            Target::Next
+        };
+
+        let outcome = match &self.chain {
+            Some(chain) => {
+                let mut find = Find::new(chain.ids.clone(), target);
+                self.content
+                    .as_widget()
+                    .operate(tree, layout, renderer, &mut find);
+                if find.focused.is_none() {
+                    return event::Status::Ignored;
+                }
+
+                Operation::<()>::finish(&find)
+            }
+            None => {
+                let mut cycle = Cycle::new(target == Target::Previous);
+                self.content
+                    .as_widget()
+                    .operate(tree, layout, renderer, &mut cycle);
+
+                cycle.finish()
+            }
+        };
+
+        let Outcome::Chain(operation) = outcome else {
+            return event::Status::Ignored;
+        };
+
+        run(&self.content, tree, layout, renderer, operation);
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+
+        event::Status::Captured
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<FocusScope<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(value: FocusScope<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`FocusScope`] with the given content.
+pub fn focus_scope<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> FocusScope<'a, Message, Theme, Renderer> {
+    FocusScope::new(content)
+}
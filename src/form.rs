@@ -0,0 +1,302 @@
+//! A form of [`ParsedInput`]s, keeping track of their validity.
+//!
+//! A [`Form`] owns the [`Content`]s of its fields, whatever their types, under their names. It
+//! builds their inputs with [`Form::input`], whose messages all carry an [`Edit`] given back to
+//! [`Form::update`]. It tells whether all the fields are valid with [`Form::is_all_valid`], lists
+//! their errors in a summary with [`Form::summary`], and only enables the submit button given to
+//! [`Form::submit`] when all the fields are valid.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced::{Element, widget::{button, column}};
+//! use more_iced_aw::{form::{Edit, Form}, parsed_input::Content};
+//! use std::num::{ParseFloatError, ParseIntError};
+//!
+//! struct App {
+//!     form: Form,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Edit(Edit),
+//!     Submit,
+//! }
+//!
+//! impl App {
+//!     fn update(&mut self, message: Message) {
+//!         match message {
+//!             Message::Edit(edit) => self.form.update(edit),
+//!             Message::Submit => {
+//!                 let age = self.form.get::<u8, ParseIntError>("Age").unwrap();
+//!                 println!("Submitted age {}", **age);
+//!             }
+//!         }
+//!     }
+//!
+//!     fn view(&self) -> Element<'_, Message> {
+//!         column![
+//!             self.form.input::<u8, ParseIntError, _, _, _>("Age", "Your age", Message::Edit),
+//!             self.form.input::<f32, ParseFloatError, _, _, _>("Height", "Your height", Message::Edit),
+//!             self.form.summary(),
+//!             self.form.submit(button("Submit"), Message::Submit),
+//!         ]
+//!         .spacing(10)
+//!         .into()
+//!     }
+//! }
+//!
+//! fn main() -> iced::Result {
+//!     iced::application("Form", App::update, App::view).run_with(|| {
+//!         let form = Form::new()
+//!             .field("Age", Content::<u8, ParseIntError>::new(30))
+//!             .field("Height", Content::<f32, ParseFloatError>::new(1.75));
+//!         (App { form }, iced::Task::none())
+//!     })
+//! }
+//! ```
+
+use std::{any::Any, fmt::Display, sync::Arc};
+
+use iced::{
+    Element,
+    advanced::text,
+    widget::{Button, Column, Text, button, text_input},
+};
+
+use crate::parsed_input::{Content, Parsed, ParsedInput};
+
+/// A field of a [`Form`], implemented by all the [`Content`]s it can own.
+pub trait Field {
+    /// Indicates if the text of the field corresponds to its value.
+    fn is_valid(&self) -> bool;
+
+    /// Returns the description of the error of the field, if any.
+    fn error(&self) -> Option<String>;
+
+    /// Updates the field with a [`Parsed`] of its type, ignoring it if it has another type.
+    fn update(&mut self, parsed: &dyn Any);
+
+    /// Returns the field as [`Any`], to get back its [`Content`].
+    fn as_any(&self) -> &dyn Any;
+
+    /// Returns the field as mutable [`Any`], to get back its [`Content`].
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T, E> Field for Content<T, E>
+where
+    T: Clone + 'static,
+    E: Clone + Display + 'static,
+{
+    fn is_valid(&self) -> bool {
+        Content::is_valid(self)
+    }
+
+    fn error(&self) -> Option<String> {
+        self.get_error().as_ref().map(ToString::to_string)
+    }
+
+    fn update(&mut self, parsed: &dyn Any) {
+        if let Some(parsed) = parsed.downcast_ref::<Parsed<T, E>>() {
+            Content::update(self, parsed.clone());
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// An edit of a field of a [`Form`], produced by the inputs of [`Form::input`].
+#[derive(Clone)]
+pub struct Edit {
+    name: String,
+    parsed: Arc<dyn Any + Send + Sync>,
+}
+
+impl Edit {
+    /// Creates a new [`Edit`] of the field with the given name.
+    pub fn new<T, E>(name: impl Into<String>, parsed: Parsed<T, E>) -> Self
+    where
+        T: Send + Sync + 'static,
+        E: Send + Sync + 'static,
+    {
+        Self {
+            name: name.into(),
+            parsed: Arc::new(parsed),
+        }
+    }
+
+    /// Returns the name of the edited field.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl std::fmt::Debug for Edit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Edit").field("name", &self.name).finish()
+    }
+}
+
+/// A collection of named fields, each one the [`Content`] of a [`ParsedInput`].
+///
+/// ```
+/// use more_iced_aw::{form::Form, parsed_input::{Content, Parsed}};
+/// use std::num::ParseIntError;
+///
+/// let mut form = Form::new()
+///     .field("Age", Content::<u8, ParseIntError>::new(30))
+///     .field("Count", Content::<i32, ParseIntError>::new(2));
+/// assert!(form.is_all_valid());
+///
+/// let count = form.get_mut::<i32, ParseIntError>("Count").unwrap();
+/// count.update(Parsed::from_string("two"));
+///
+/// assert!(!form.is_all_valid());
+/// assert_eq!(form.errors().len(), 1);
+/// assert_eq!(form.errors()[0].0, "Count");
+/// ```
+#[derive(Default)]
+pub struct Form {
+    fields: Vec<(String, Box<dyn Field>)>,
+}
+
+impl Form {
+    /// Creates a new empty [`Form`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a field to the [`Form`], replacing the one with the same name if any.
+    pub fn field(mut self, name: impl Into<String>, content: impl Field + 'static) -> Self {
+        self.insert(name, content);
+        self
+    }
+
+    /// Inserts a field in the [`Form`], replacing the one with the same name if any.
+    pub fn insert(&mut self, name: impl Into<String>, content: impl Field + 'static) {
+        let name = name.into();
+        let content = Box::new(content);
+
+        match self.fields.iter_mut().find(|(field, _)| *field == name) {
+            Some((_, field)) => *field = content,
+            None => self.fields.push((name, content)),
+        }
+    }
+
+    /// Removes the field with the given name.
+    pub fn remove(&mut self, name: &str) {
+        self.fields.retain(|(field, _)| field != name);
+    }
+
+    /// Returns the names of the fields, in the order they were added.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.fields.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Returns the [`Content`] of the field with the given name, if it has this type.
+    pub fn get<T: 'static, E: 'static>(&self, name: &str) -> Option<&Content<T, E>> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .and_then(|(_, content)| content.as_any().downcast_ref())
+    }
+
+    /// Returns the mutable [`Content`] of the field with the given name, if it has this type.
+    pub fn get_mut<T: 'static, E: 'static>(&mut self, name: &str) -> Option<&mut Content<T, E>> {
+        self.fields
+            .iter_mut()
+            .find(|(field, _)| field == name)
+            .and_then(|(_, content)| content.as_any_mut().downcast_mut())
+    }
+
+    /// Updates the field of an [`Edit`].
+    pub fn update(&mut self, edit: Edit) {
+        if let Some((_, content)) = self
+            .fields
+            .iter_mut()
+            .find(|(field, _)| *field == edit.name)
+        {
+            content.update(edit.parsed.as_ref());
+        }
+    }
+
+    /// Indicates if all the fields are valid.
+    pub fn is_all_valid(&self) -> bool {
+        self.fields.iter().all(|(_, content)| content.is_valid())
+    }
+
+    /// Returns the names and errors of the invalid fields, in the order they were added.
+    pub fn errors(&self) -> Vec<(&str, String)> {
+        self.fields
+            .iter()
+            .filter_map(|(name, content)| content.error().map(|error| (name.as_str(), error)))
+            .collect()
+    }
+
+    /// Creates the [`ParsedInput`] of the field with the given name.
+    ///
+    /// Its typed and pasted texts produce the [`Edit`]s of the field, given to `on_edit`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`Form`] has no field with this name and type.
+    pub fn input<'a, T, E, Message, Theme, Renderer>(
+        &'a self,
+        name: &str,
+        placeholder: &str,
+        on_edit: impl Fn(Edit) -> Message + Clone + 'a,
+    ) -> ParsedInput<'a, T, E, Message, Theme, Renderer>
+    where
+        T: Clone + Send + Sync + 'static,
+        E: Clone + Send + Sync + 'static,
+        Theme: text_input::Catalog + 'a,
+        Renderer: text::Renderer,
+    {
+        let content = self
+            .get::<T, E>(name)
+            .unwrap_or_else(|| panic!("The form has no field named {name:?} of this type"));
+        let (input_name, paste_name) = (name.to_owned(), name.to_owned());
+        let on_paste = on_edit.clone();
+
+        ParsedInput::new(placeholder, content)
+            .on_input(move |parsed| on_edit(Edit::new(input_name.clone(), parsed)))
+            .on_paste(move |parsed| on_paste(Edit::new(paste_name.clone(), parsed)))
+    }
+
+    /// Creates a summary of the errors of the [`Form`], one line per invalid field.
+    pub fn summary<'a, Message, Theme, Renderer>(&self) -> Element<'a, Message, Theme, Renderer>
+    where
+        Message: 'a,
+        Theme: iced::widget::text::Catalog + 'a,
+        Renderer: text::Renderer + 'a,
+    {
+        Column::with_children(
+            self.errors()
+                .into_iter()
+                .map(|(name, error)| Text::new(format!("{name}: {error}")).into()),
+        )
+        .into()
+    }
+
+    /// Enables the given button only if all the fields are valid, producing `on_submit` when
+    /// pressed.
+    pub fn submit<'a, Message, Theme, Renderer>(
+        &self,
+        button: Button<'a, Message, Theme, Renderer>,
+        on_submit: Message,
+    ) -> Button<'a, Message, Theme, Renderer>
+    where
+        Message: Clone,
+        Theme: button::Catalog,
+        Renderer: iced::advanced::Renderer,
+    {
+        button.on_press_maybe(self.is_all_valid().then_some(on_submit))
+    }
+}
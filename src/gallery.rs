@@ -0,0 +1,446 @@
+//! A grid of thumbnails, loaded lazily.
+//!
+//! The [`Gallery`] lays out its thumbnails in as many columns as fit in its width. The thumbnails
+//! are given by the application as a slice of optional image handles: a missing thumbnail is drawn
+//! as a placeholder, and is requested with the [`on_request`](Gallery::on_request) message once
+//! its cell becomes visible. Put the gallery in a [`Scrollable`](iced::widget::Scrollable) so only
+//! the thumbnails scrolled into view are requested.
+//!
+//! A thumbnail is selected by clicking it, and activated by double-clicking it.
+
+use std::collections::HashSet;
+
+use iced::{
+    Background, Border, Color, Element, Length, Pixels, Point, Radians, Rectangle, Size, Theme,
+    advanced::{
+        Layout, Shell, Widget,
+        image::{self, FilterMethod, Image},
+        layout::{Limits, Node},
+        mouse, renderer,
+        widget::{Tree, tree},
+    },
+    border::Radius,
+    event, touch,
+};
+
+/// The appearance of a [`Gallery`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the thumbnails that are not loaded.
+    pub placeholder: Background,
+    /// The radius of the cells.
+    pub radius: Radius,
+    /// The width of the borders of the hovered and selected cells.
+    pub border_width: f32,
+    /// The color of the border of the hovered cell.
+    pub hovered: Color,
+    /// The color of the border of the selected cell.
+    pub selected: Color,
+}
+
+/// The theme catalog of a [`Gallery`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Gallery`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Gallery`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        placeholder: palette.background.weak.color.into(),
+        radius: 4.into(),
+        border_width: 2.,
+        hovered: palette.background.strong.color,
+        selected: palette.primary.base.color,
+    }
+}
+
+/// The state of a [`Gallery`].
+#[derive(Debug, Clone, Default)]
+struct State {
+    /// The thumbnails already requested.
+    requested: HashSet<usize>,
+    last_click: Option<mouse::Click>,
+}
+
+/// A responsive grid of lazily loaded thumbnails, created with [`gallery`].
+pub struct Gallery<'a, Message, Theme = iced::Theme, Handle = image::Handle>
+where
+    Theme: Catalog,
+{
+    thumbnails: &'a [Option<Handle>],
+    selected: Option<usize>,
+    on_request: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_activate: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    thumbnail_size: f32,
+    spacing: f32,
+    padding: f32,
+    width: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Handle> Gallery<'a, Message, Theme, Handle>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Gallery`] of the given thumbnails. `None` thumbnails are not loaded yet.
+    pub fn new(thumbnails: &'a [Option<Handle>]) -> Self {
+        Self {
+            thumbnails,
+            selected: None,
+            on_request: None,
+            on_select: None,
+            on_activate: None,
+            thumbnail_size: 128.,
+            spacing: 8.,
+            padding: 4.,
+            width: Length::Fill,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the index of the selected thumbnail.
+    pub fn selected(mut self, selected: impl Into<Option<usize>>) -> Self {
+        self.selected = selected.into();
+        self
+    }
+
+    /// Sets the message produced, once, when a thumbnail that is not loaded becomes visible.
+    pub fn on_request(mut self, on_request: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_request = Some(Box::new(on_request));
+        self
+    }
+
+    /// Sets the message produced when a thumbnail is clicked.
+    pub fn on_select(mut self, on_select: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the message produced when a thumbnail is double-clicked.
+    pub fn on_activate(mut self, on_activate: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_activate = Some(Box::new(on_activate));
+        self
+    }
+
+    /// Sets the minimal size of the thumbnails. The cells grow to fill the width of the
+    /// [`Gallery`].
+    pub fn thumbnail_size(mut self, thumbnail_size: impl Into<Pixels>) -> Self {
+        self.thumbnail_size = thumbnail_size.into().0;
+        self
+    }
+
+    /// Sets the spacing between the cells.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the padding between the cells and their thumbnail.
+    pub fn padding(mut self, padding: impl Into<Pixels>) -> Self {
+        self.padding = padding.into().0;
+        self
+    }
+
+    /// Sets the width of the [`Gallery`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the style of the [`Gallery`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Gallery`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the number of columns and the size of the cells for the given width.
+    fn grid(&self, width: f32) -> (usize, f32) {
+        let columns = ((width + self.spacing) / (self.thumbnail_size + self.spacing)).floor();
+        let columns = (columns as usize).max(1);
+        let cell = (width - self.spacing * (columns - 1) as f32) / columns as f32;
+
+        (columns, cell.max(0.))
+    }
+
+    /// Returns the bounds of the cell with the given index.
+    fn cell_bounds(&self, bounds: Rectangle, index: usize) -> Rectangle {
+        let (columns, cell) = self.grid(bounds.width);
+        let (row, column) = (index / columns, index % columns);
+
+        Rectangle {
+            x: bounds.x + column as f32 * (cell + self.spacing),
+            y: bounds.y + row as f32 * (cell + self.spacing),
+            width: cell,
+            height: cell,
+        }
+    }
+
+    /// Returns the indices of the cells intersecting the given area.
+    fn cells_in(&self, bounds: Rectangle, area: Rectangle) -> std::ops::Range<usize> {
+        let (columns, cell) = self.grid(bounds.width);
+        let row_height = cell + self.spacing;
+        if row_height <= 0. {
+            return 0..0;
+        }
+
+        let first = ((area.y - bounds.y) / row_height).floor().max(0.) as usize;
+        let last = ((area.y + area.height - bounds.y) / row_height)
+            .ceil()
+            .max(0.) as usize;
+
+        (first * columns).min(self.thumbnails.len())..(last * columns).min(self.thumbnails.len())
+    }
+
+    /// Returns the index of the cell under the given position.
+    fn cell_at(&self, bounds: Rectangle, position: Point) -> Option<usize> {
+        self.cells_in(bounds, Rectangle::new(position, Size::ZERO).expand(1.))
+            .find(|&index| self.cell_bounds(bounds, index).contains(position))
+    }
+}
+
+impl<Message, Theme, Renderer, Handle> Widget<Message, Theme, Renderer>
+    for Gallery<'_, Message, Theme, Handle>
+where
+    Theme: Catalog,
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let width = limits.resolve(self.width, Length::Shrink, Size::ZERO).width;
+        let (columns, cell) = self.grid(width);
+        let rows = self.thumbnails.len().div_ceil(columns);
+        let height = (rows as f32 * (cell + self.spacing) - self.spacing).max(0.);
+
+        Node::new(limits.resolve(self.width, Length::Shrink, Size::new(width, height)))
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let Some(visible) = bounds.intersection(viewport) else {
+            return;
+        };
+        let style = theme.style(&self.class);
+        let hovered = cursor
+            .position_over(visible)
+            .and_then(|position| self.cell_at(bounds, position));
+
+        for index in self.cells_in(bounds, visible) {
+            let cell = self.cell_bounds(bounds, index);
+
+            let border_color = if self.selected == Some(index) {
+                Some(style.selected)
+            } else if hovered == Some(index) {
+                Some(style.hovered)
+            } else {
+                None
+            };
+
+            let Some(handle) = &self.thumbnails[index] else {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: cell,
+                        border: Border {
+                            color: border_color.unwrap_or(Color::TRANSPARENT),
+                            width: style.border_width,
+                            radius: style.radius,
+                        },
+                        ..Default::default()
+                    },
+                    style.placeholder,
+                );
+                continue;
+            };
+
+            let area = cell.shrink(self.padding);
+            let size = renderer.measure_image(handle);
+            let size = Size::new(size.width as f32, size.height as f32);
+            let zoom = (area.width / size.width.max(1.)).min(area.height / size.height.max(1.));
+            let size = size * zoom;
+
+            renderer.draw_image(
+                Image {
+                    handle: handle.clone(),
+                    filter_method: FilterMethod::Linear,
+                    rotation: Radians(0.),
+                    opacity: 1.,
+                    snap: true,
+                },
+                Rectangle::new(
+                    Point::new(
+                        area.center_x() - size.width / 2.,
+                        area.center_y() - size.height / 2.,
+                    ),
+                    size,
+                ),
+            );
+
+            if let Some(color) = border_color {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: cell,
+                        border: Border {
+                            color,
+                            width: style.border_width,
+                            radius: style.radius,
+                        },
+                        ..Default::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn iced::advanced::Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        if let Some(on_request) = &self.on_request
+            && let Some(visible) = bounds.intersection(viewport)
+        {
+            for index in self.cells_in(bounds, visible) {
+                if self.thumbnails[index].is_none() && state.requested.insert(index) {
+                    shell.publish(on_request(index));
+                }
+            }
+        }
+
+        match event {
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) =
+                    cursor.position_over(bounds.intersection(viewport).unwrap_or_default())
+                else {
+                    return event::Status::Ignored;
+                };
+                let Some(index) = self.cell_at(bounds, position) else {
+                    return event::Status::Ignored;
+                };
+
+                let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+                state.last_click = Some(click);
+
+                if let Some(on_select) = &self.on_select {
+                    shell.publish(on_select(index));
+                }
+                if matches!(click.kind(), mouse::click::Kind::Double)
+                    && let Some(on_activate) = &self.on_activate
+                {
+                    shell.publish(on_activate(index));
+                }
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::CursorMoved { .. }) if cursor.is_over(bounds) => {
+                shell.request_redraw(iced::window::RedrawRequest::NextFrame);
+                event::Status::Ignored
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        match cursor.position_over(bounds.intersection(viewport).unwrap_or_default()) {
+            Some(position) if self.cell_at(bounds, position).is_some() => {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer, Handle> From<Gallery<'a, Message, Theme, Handle>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: image::Renderer<Handle = Handle> + 'a,
+    Handle: Clone + 'a,
+{
+    fn from(value: Gallery<'a, Message, Theme, Handle>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`Gallery`] of the given thumbnails. `None` thumbnails are not loaded yet.
+pub fn gallery<'a, Message, Theme, Handle>(
+    thumbnails: &'a [Option<Handle>],
+) -> Gallery<'a, Message, Theme, Handle>
+where
+    Theme: Catalog,
+{
+    Gallery::new(thumbnails)
+}
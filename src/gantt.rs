@@ -0,0 +1,754 @@
+//! A Gantt chart, drawn on a [`Canvas`].
+//!
+//! A [`Gantt`] draws each [`Task`] as a bar on its own row, against a horizontal time axis. The
+//! dependencies of a task are drawn as arrows from the end of the tasks it depends on to its
+//! start.
+//!
+//! Tasks are rescheduled by dragging their bar, and resized by dragging its right edge. As with
+//! the other widgets of this crate, the chart does not change the tasks itself: it produces a
+//! message with the changed task, which the application applies to its data.
+//!
+//! Times are whole units, whose meaning (days, hours...) is up to the application, see
+//! [`Gantt::axis_label`]. The mouse wheel scrolls the rows, with `Shift` it scrolls the time axis,
+//! and with `Ctrl` it zooms the time axis around the cursor. Only the visible rows are drawn, so
+//! large plans stay cheap.
+
+use iced::{
+    Color, Element, Length, Pixels, Point, Rectangle, Renderer, Size, Theme, Vector, alignment,
+    keyboard,
+    mouse::{self, Cursor},
+    widget::canvas::{self, Canvas, Frame, Geometry, Path, Program, Stroke, Text, event},
+};
+
+/// The approximate width of a character, relative to the text size, used to fit the names.
+const CHAR_WIDTH: f32 = 0.6;
+/// The width of the area at the right of a bar that resizes it.
+const RESIZE_HANDLE: f32 = 6.;
+/// The minimal distance between two ticks of the time axis.
+const TICK_SPACING: f32 = 60.;
+
+/// A task of a [`Gantt`] chart.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Task {
+    /// The name of the task.
+    pub name: String,
+    /// The start of the task.
+    pub start: u32,
+    /// The duration of the task.
+    pub duration: u32,
+    /// The indices of the tasks this task depends on.
+    pub dependencies: Vec<usize>,
+    /// The color of the task, or `None` for the one of the [`Style`].
+    #[cfg_attr(feature = "serde", serde(with = "crate::helpers::serde_iced"))]
+    pub color: Option<Color>,
+}
+
+impl Task {
+    /// Creates a new [`Task`] without dependencies.
+    pub fn new(name: impl Into<String>, start: u32, duration: u32) -> Self {
+        Self {
+            name: name.into(),
+            start,
+            duration,
+            dependencies: Vec::new(),
+            color: None,
+        }
+    }
+
+    /// Adds dependencies to the [`Task`], by index.
+    pub fn depends_on(mut self, dependencies: impl IntoIterator<Item = usize>) -> Self {
+        self.dependencies.extend(dependencies);
+        self
+    }
+
+    /// Sets the color of the [`Task`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Returns the end of the task.
+    pub fn end(&self) -> u32 {
+        self.start + self.duration
+    }
+}
+
+/// The appearance of a [`Gantt`] chart.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the chart.
+    pub background: Color,
+    /// The color of the grid lines and of the row stripes.
+    pub grid: Color,
+    /// The color of the names and of the time axis.
+    pub text: Color,
+    /// The default color of the bars.
+    pub bar: Color,
+    /// The color of the names drawn on the bars.
+    pub bar_text: Color,
+    /// The color of the dependency arrows.
+    pub dependency: Color,
+}
+
+/// The theme catalog of a [`Gantt`] chart.
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Gantt`] chart.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Gantt`] chart.
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color,
+        grid: palette.background.weak.color,
+        text: palette.background.base.text,
+        bar: palette.primary.base.color,
+        bar_text: palette.primary.base.text,
+        dependency: palette.background.strong.color,
+    }
+}
+
+/// A drag of a task in progress.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Drag {
+    /// The index of the dragged task.
+    index: usize,
+    /// Whether the task is resized instead of moved.
+    resize: bool,
+    /// The horizontal position where the drag started.
+    origin: f32,
+    /// The start and duration of the task being dragged.
+    preview: (u32, u32),
+}
+
+/// The state of a [`Gantt`] chart.
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    offset: Vector,
+    zoom: Option<f32>,
+    modifiers: keyboard::Modifiers,
+    drag: Option<Drag>,
+}
+
+/// The areas of a [`Gantt`] chart.
+struct Areas {
+    /// The column of the names.
+    names: Rectangle,
+    /// The time axis.
+    axis: Rectangle,
+    /// The bars.
+    bars: Rectangle,
+}
+
+/// A Gantt chart of tasks, created with [`gantt`].
+pub struct Gantt<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    tasks: &'a [Task],
+    unit_width: f32,
+    zoom: (f32, f32),
+    row_height: f32,
+    names_width: f32,
+    axis_label: Box<dyn Fn(u32) -> String + 'a>,
+    on_change: Option<Box<dyn Fn(usize, Task) -> Message + 'a>>,
+    text_size: Pixels,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> Gantt<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Gantt`] chart of the given tasks.
+    pub fn new(tasks: &'a [Task]) -> Self {
+        Self {
+            tasks,
+            unit_width: 24.,
+            zoom: (2., 200.),
+            row_height: 28.,
+            names_width: 160.,
+            axis_label: Box::new(|unit| unit.to_string()),
+            on_change: None,
+            text_size: Pixels(12.),
+            width: Length::Fill,
+            height: Length::Fixed(320.),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the initial width of a time unit.
+    pub fn unit_width(mut self, unit_width: impl Into<Pixels>) -> Self {
+        self.unit_width = unit_width.into().0;
+        self
+    }
+
+    /// Sets the minimal and maximal width of a time unit when zooming.
+    pub fn zoom(mut self, min: impl Into<Pixels>, max: impl Into<Pixels>) -> Self {
+        let min = min.into().0.max(0.1);
+        self.zoom = (min, max.into().0.max(min));
+        self
+    }
+
+    /// Sets the height of a row.
+    pub fn row_height(mut self, row_height: impl Into<Pixels>) -> Self {
+        self.row_height = row_height.into().0;
+        self
+    }
+
+    /// Sets the width of the column of the names.
+    pub fn names_width(mut self, names_width: impl Into<Pixels>) -> Self {
+        self.names_width = names_width.into().0;
+        self
+    }
+
+    /// Sets the function giving the label of a tick of the time axis. Defaults to the unit.
+    pub fn axis_label(mut self, axis_label: impl Fn(u32) -> String + 'a) -> Self {
+        self.axis_label = Box::new(axis_label);
+        self
+    }
+
+    /// Sets the message produced when a task is rescheduled or resized, with its index and the
+    /// task with its new start and duration.
+    pub fn on_change(mut self, on_change: impl Fn(usize, Task) -> Message + 'a) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Sets the text size of the [`Gantt`] chart.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = text_size.into();
+        self
+    }
+
+    /// Sets the width of the [`Gantt`] chart.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Gantt`] chart.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`Gantt`] chart.
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Gantt`] chart.
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the areas of the chart in a frame of the given size.
+    fn areas(&self, size: Size) -> Areas {
+        let header = self.text_size.0 * 2.;
+        let names = self.names_width.min(size.width / 2.);
+
+        Areas {
+            names: Rectangle::new(
+                Point::new(0., header),
+                Size::new(names, (size.height - header).max(0.)),
+            ),
+            axis: Rectangle::new(
+                Point::new(names, 0.),
+                Size::new((size.width - names).max(0.), header),
+            ),
+            bars: Rectangle::new(
+                Point::new(names, header),
+                Size::new((size.width - names).max(0.), (size.height - header).max(0.)),
+            ),
+        }
+    }
+
+    /// Returns the width of a time unit.
+    fn unit(&self, state: &State) -> f32 {
+        state
+            .zoom
+            .unwrap_or(self.unit_width)
+            .clamp(self.zoom.0, self.zoom.1)
+    }
+
+    /// Returns the start and duration of a task, taking the drag into account.
+    fn span(&self, state: &State, index: usize) -> (u32, u32) {
+        match state.drag {
+            Some(drag) if drag.index == index => drag.preview,
+            _ => (self.tasks[index].start, self.tasks[index].duration),
+        }
+    }
+
+    /// Returns the bounds of the bar of a task, relative to the bars area.
+    fn bar(&self, state: &State, index: usize) -> Rectangle {
+        let unit = self.unit(state);
+        let (start, duration) = self.span(state, index);
+        let margin = self.row_height / 5.;
+
+        Rectangle {
+            x: start as f32 * unit - state.offset.x,
+            y: index as f32 * self.row_height - state.offset.y + margin,
+            width: (duration as f32 * unit).max(2.),
+            height: self.row_height - margin * 2.,
+        }
+    }
+
+    /// Returns the index of the task whose bar is under the given position in the frame.
+    fn task_at(&self, state: &State, areas: &Areas, position: Point) -> Option<usize> {
+        if !areas.bars.contains(position) {
+            return None;
+        }
+
+        let position = position - Vector::new(areas.bars.x, areas.bars.y);
+        let index = ((position.y + state.offset.y) / self.row_height) as usize;
+
+        (index < self.tasks.len() && self.bar(state, index).contains(position)).then_some(index)
+    }
+
+    /// Returns the rows visible in the bars area.
+    fn visible(&self, state: &State, areas: &Areas) -> (usize, usize) {
+        let first = (state.offset.y / self.row_height) as usize;
+        let last = ((state.offset.y + areas.bars.height) / self.row_height).ceil() as usize;
+
+        (first.min(self.tasks.len()), last.min(self.tasks.len()))
+    }
+
+    /// Clamps the scroll offset so the content stays in view.
+    fn clamp(&self, state: &mut State, areas: &Areas) {
+        let end = self.tasks.iter().map(Task::end).max().unwrap_or(0) + 1;
+        let width = end as f32 * self.unit(state) - areas.bars.width;
+        let height = self.tasks.len() as f32 * self.row_height - areas.bars.height;
+
+        state.offset.x = state.offset.x.clamp(0., width.max(0.));
+        state.offset.y = state.offset.y.clamp(0., height.max(0.));
+    }
+
+    fn draw_axis(&self, frame: &mut Frame, state: &State, areas: &Areas, style: &Style) {
+        let unit = self.unit(state);
+        let step = [1, 2, 5]
+            .into_iter()
+            .cycle()
+            .scan(1, |scale, step| {
+                let value = step * *scale;
+                if step == 5 {
+                    *scale *= 10;
+                }
+                Some(value)
+            })
+            .find(|step| *step as f32 * unit >= TICK_SPACING)
+            .unwrap_or(1);
+
+        let first = (state.offset.x / unit) as u32 / step * step;
+        let last = ((state.offset.x + areas.bars.width) / unit) as u32 + 1;
+
+        frame.with_clip(
+            Rectangle::new(
+                areas.axis.position(),
+                Size::new(areas.axis.width, areas.axis.height + areas.bars.height),
+            ),
+            |frame| {
+                for tick in (first..=last).step_by(step as usize) {
+                    let x = tick as f32 * unit - state.offset.x;
+                    frame.fill_rectangle(
+                        Point::new(x, 0.),
+                        Size::new(1., frame.height()),
+                        style.grid,
+                    );
+                    frame.fill_text(Text {
+                        content: (self.axis_label)(tick),
+                        position: Point::new(x + 4., areas.axis.height / 2.),
+                        color: style.text,
+                        size: self.text_size,
+                        vertical_alignment: alignment::Vertical::Center,
+                        ..Text::default()
+                    });
+                }
+            },
+        );
+    }
+
+    fn draw_rows(&self, frame: &mut Frame, state: &State, areas: &Areas, style: &Style) {
+        let (first, last) = self.visible(state, areas);
+
+        frame.with_clip(
+            Rectangle::new(
+                Point::new(0., areas.names.y),
+                Size::new(areas.names.width + areas.bars.width, areas.names.height),
+            ),
+            |frame| {
+                for index in (first..last).filter(|index| index % 2 == 1) {
+                    frame.fill_rectangle(
+                        Point::new(0., index as f32 * self.row_height - state.offset.y),
+                        Size::new(frame.width(), self.row_height),
+                        style.grid.scale_alpha(0.3),
+                    );
+                }
+            },
+        );
+
+        frame.with_clip(areas.names, |frame| {
+            for index in first..last {
+                frame.fill_text(Text {
+                    content: self.tasks[index].name.clone(),
+                    position: Point::new(
+                        8.,
+                        (index as f32 + 0.5) * self.row_height - state.offset.y,
+                    ),
+                    color: style.text,
+                    size: self.text_size,
+                    vertical_alignment: alignment::Vertical::Center,
+                    ..Text::default()
+                });
+            }
+        });
+
+        frame.fill_rectangle(
+            Point::new(areas.names.width, 0.),
+            Size::new(1., frame.height()),
+            style.grid,
+        );
+        frame.fill_rectangle(
+            Point::new(0., areas.axis.height),
+            Size::new(frame.width(), 1.),
+            style.grid,
+        );
+    }
+
+    fn draw_dependencies(&self, frame: &mut Frame, state: &State, areas: &Areas, style: &Style) {
+        let (first, last) = self.visible(state, areas);
+        let stroke = Stroke::default()
+            .with_width(1.5)
+            .with_color(style.dependency);
+        let gap = 8.;
+
+        for (index, task) in self.tasks.iter().enumerate() {
+            let to = self.bar(state, index);
+
+            for &dependency in &task.dependencies {
+                if dependency >= self.tasks.len()
+                    || index.max(dependency) < first
+                    || index.min(dependency) >= last
+                {
+                    continue;
+                }
+
+                let from = self.bar(state, dependency);
+                let start = Point::new(from.x + from.width, from.center_y());
+                let end = Point::new(to.x, to.center_y());
+
+                let path = Path::new(|path| {
+                    path.move_to(start);
+                    if end.x - start.x >= gap * 2. {
+                        let middle = start.x + gap;
+                        path.line_to(Point::new(middle, start.y));
+                        path.line_to(Point::new(middle, end.y));
+                    } else {
+                        // Go around, between the rows.
+                        let between = if end.y > start.y {
+                            start.y + self.row_height / 2.
+                        } else {
+                            start.y - self.row_height / 2.
+                        };
+                        path.line_to(Point::new(start.x + gap, start.y));
+                        path.line_to(Point::new(start.x + gap, between));
+                        path.line_to(Point::new(end.x - gap, between));
+                        path.line_to(Point::new(end.x - gap, end.y));
+                    }
+                    path.line_to(end);
+                });
+                frame.stroke(&path, stroke);
+
+                let head = Path::new(|path| {
+                    path.move_to(end);
+                    path.line_to(end - Vector::new(6., 4.));
+                    path.line_to(end - Vector::new(6., -4.));
+                    path.close();
+                });
+                frame.fill(&head, style.dependency);
+            }
+        }
+    }
+
+    fn draw_bars(&self, frame: &mut Frame, state: &State, areas: &Areas, style: &Style) {
+        let (first, last) = self.visible(state, areas);
+
+        for index in first..last {
+            let task = &self.tasks[index];
+            let bar = self.bar(state, index);
+            let color = task.color.unwrap_or(style.bar);
+            let dragged = state.drag.is_some_and(|drag| drag.index == index);
+
+            frame.fill(
+                &Path::rounded_rectangle(bar.position(), bar.size(), 4.into()),
+                if dragged {
+                    color.scale_alpha(0.8)
+                } else {
+                    color
+                },
+            );
+
+            let fits =
+                task.name.chars().count() as f32 * self.text_size.0 * CHAR_WIDTH <= bar.width - 8.;
+            frame.fill_text(Text {
+                content: task.name.clone(),
+                position: Point::new(
+                    if fits {
+                        bar.x + 4.
+                    } else {
+                        bar.x + bar.width + 4.
+                    },
+                    bar.center_y(),
+                ),
+                color: if fits { style.bar_text } else { style.text },
+                size: self.text_size,
+                vertical_alignment: alignment::Vertical::Center,
+                ..Text::default()
+            });
+        }
+    }
+}
+
+impl<Message, Theme> Program<Message, Theme> for Gantt<'_, Message, Theme>
+where
+    Theme: Catalog,
+{
+    type State = State;
+
+    fn update(
+        &self,
+        state: &mut State,
+        event: canvas::Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> (event::Status, Option<Message>) {
+        let areas = self.areas(bounds.size());
+
+        match event {
+            canvas::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+                (event::Status::Ignored, None)
+            }
+            canvas::Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return (event::Status::Ignored, None);
+                };
+
+                let (x, y) = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        (x * self.row_height, y * self.row_height)
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => (x, y),
+                };
+
+                if state.modifiers.command() {
+                    // Zoom around the unit under the cursor.
+                    let unit = self.unit(state);
+                    let anchor = (position.x - areas.bars.x).max(0.);
+                    let at = (anchor + state.offset.x) / unit;
+                    let zoomed =
+                        (unit * 1.1f32.powf(y / self.row_height)).clamp(self.zoom.0, self.zoom.1);
+
+                    state.zoom = Some(zoomed);
+                    state.offset.x = at * zoomed - anchor;
+                } else if state.modifiers.shift() {
+                    state.offset.x -= if x == 0. { y } else { x };
+                } else {
+                    state.offset.x -= x;
+                    state.offset.y -= y;
+                }
+                self.clamp(state, &areas);
+
+                (event::Status::Captured, None)
+            }
+            canvas::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return (event::Status::Ignored, None);
+                };
+                let Some(index) = self
+                    .on_change
+                    .as_ref()
+                    .and_then(|_| self.task_at(state, &areas, position))
+                else {
+                    return (event::Status::Ignored, None);
+                };
+
+                let bar = self.bar(state, index);
+                let task = &self.tasks[index];
+                state.drag = Some(Drag {
+                    index,
+                    resize: position.x - areas.bars.x >= bar.x + bar.width - RESIZE_HANDLE,
+                    origin: position.x,
+                    preview: (task.start, task.duration),
+                });
+
+                (event::Status::Captured, None)
+            }
+            canvas::Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let unit = self.unit(state);
+                let Some(drag) = &mut state.drag else {
+                    return (event::Status::Ignored, None);
+                };
+                let Some(task) = self.tasks.get(drag.index) else {
+                    return (event::Status::Ignored, None);
+                };
+
+                let units = ((position.x - bounds.x - drag.origin) / unit).round() as i64;
+                drag.preview = if drag.resize {
+                    (task.start, (task.duration as i64 + units).max(1) as u32)
+                } else {
+                    ((task.start as i64 + units).max(0) as u32, task.duration)
+                };
+
+                (event::Status::Captured, None)
+            }
+            canvas::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let Some(drag) = state.drag.take() else {
+                    return (event::Status::Ignored, None);
+                };
+                let Some(task) = self.tasks.get(drag.index) else {
+                    return (event::Status::Captured, None);
+                };
+
+                let (start, duration) = drag.preview;
+                let message = ((start, duration) != (task.start, task.duration))
+                    .then(|| {
+                        self.on_change.as_ref().map(|on_change| {
+                            on_change(
+                                drag.index,
+                                Task {
+                                    start,
+                                    duration,
+                                    ..task.clone()
+                                },
+                            )
+                        })
+                    })
+                    .flatten();
+
+                (event::Status::Captured, message)
+            }
+            _ => (event::Status::Ignored, None),
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let style = theme.style(&self.class);
+        let mut frame = Frame::new(renderer, bounds.size());
+        let areas = self.areas(bounds.size());
+
+        frame.fill_rectangle(Point::ORIGIN, bounds.size(), style.background);
+
+        self.draw_rows(&mut frame, state, &areas, &style);
+        self.draw_axis(&mut frame, state, &areas, &style);
+
+        frame.with_clip(areas.bars, |frame| {
+            self.draw_dependencies(frame, state, &areas, &style);
+            self.draw_bars(frame, state, &areas, &style);
+        });
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &State,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        if let Some(drag) = state.drag {
+            return if drag.resize {
+                mouse::Interaction::ResizingHorizontally
+            } else {
+                mouse::Interaction::Grabbing
+            };
+        }
+
+        let areas = self.areas(bounds.size());
+        let Some(position) = cursor.position_in(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        match self.task_at(state, &areas, position) {
+            Some(_) if self.on_change.is_none() => mouse::Interaction::default(),
+            Some(index) => {
+                let bar = self.bar(state, index);
+                if position.x - areas.bars.x >= bar.x + bar.width - RESIZE_HANDLE {
+                    mouse::Interaction::ResizingHorizontally
+                } else {
+                    mouse::Interaction::Grab
+                }
+            }
+            None => mouse::Interaction::default(),
+        }
+    }
+}
+
+impl<'a, Message, Theme> From<Gantt<'a, Message, Theme>> for Element<'a, Message, Theme>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+{
+    fn from(value: Gantt<'a, Message, Theme>) -> Self {
+        let (width, height) = (value.width, value.height);
+        Element::new(Canvas::new(value).width(width).height(height))
+    }
+}
+
+/// Creates a new [`Gantt`] chart of the given tasks.
+///
+/// ```
+/// use more_iced_aw::gantt::{Gantt, Task, gantt};
+///
+/// let tasks = [
+///     Task::new("Design", 0, 5),
+///     Task::new("Build", 5, 10).depends_on([0]),
+///     Task::new("Test", 12, 4).depends_on([1]),
+/// ];
+///
+/// let chart: Gantt<'_, ()> = gantt(&tasks).axis_label(|day| format!("Day {day}"));
+/// ```
+pub fn gantt<'a, Message, Theme>(tasks: &'a [Task]) -> Gantt<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    Gantt::new(tasks)
+}
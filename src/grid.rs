@@ -7,34 +7,110 @@
 //!
 //! See the `grid` example for an example.
 
-use std::{collections::HashSet, fmt::Display};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+};
 
 use iced::{
+    Background, Border, Color,
     Length::{self, Shrink},
-    Padding, Pixels, Point, Size,
+    Padding, Pixels, Point, Size, Theme,
     advanced::{
         self, Widget,
         graphics::core::Element,
         layout::{self, Limits, Node},
-        widget::Tree,
+        renderer,
+        widget::{Tree, tree},
     },
     alignment::{Horizontal, Vertical},
     event,
 };
 
+use crate::{
+    helpers::{Keyed, diff_keyed_children},
+    i18n::{self, Direction},
+};
+
+#[cfg(feature = "iced_014")]
+pub mod iced_014;
+
+/// The appearance of a [`Grid`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Style {
+    /// The background of the grid, if any.
+    pub background: Option<Background>,
+    /// The border of the grid.
+    pub border: Border,
+    /// The color of the text of the cells, or `None` to keep the one of the parent.
+    pub text_color: Option<Color>,
+}
+
+/// The theme catalog of a [`Grid`].
+///
+/// Unlike the other widgets, a [`Grid`] does not require its theme to implement [`Catalog`]:
+/// it is transparent unless [`Grid::style`] or [`Grid::class`] is called, so it can be used
+/// with any theme. The [`Catalog`] is only needed by [`Grid::class`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Grid`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Grid`]: transparent, like a [`Row`](iced::widget::Row).
+///
+/// It is the style of a [`Grid`] that is not styled.
+pub fn default(_theme: &Theme) -> Style {
+    Style::default()
+}
+
+/// A grid with a background and a border, separating it from the rest of the interface.
+pub fn bordered(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: Some(palette.background.weak.color.into()),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.,
+            radius: 4.into(),
+        },
+        text_color: Some(palette.background.weak.text),
+    }
+}
+
 /// The [Grid] widget.
 pub struct Grid<'a, Message, Theme, Renderer> {
     rows: Vec<Vec<Element<'a, Message, Theme, Renderer>>>,
+    keys: Vec<Vec<Option<u64>>>,
     width: Length,
     height: Length,
     padding: Padding,
 
     horizontal_align: Horizontal,
     vertical_align: Vertical,
+    column_align: HashMap<usize, Horizontal>,
 
     column_spacing: f32,
     row_spacing: f32,
     axis: Axis,
+    uniform: bool,
+    auto_fit: Option<f32>,
+    direction: Option<Direction>,
+    style: Option<StyleFn<'a, Theme>>,
 }
 
 impl<'a, Message, Theme, Renderer> Grid<'a, Message, Theme, Renderer> {
@@ -42,14 +118,20 @@ impl<'a, Message, Theme, Renderer> Grid<'a, Message, Theme, Renderer> {
     pub fn new() -> Self {
         Self {
             rows: Vec::new(),
+            keys: Vec::new(),
             width: Shrink,
             height: Shrink,
             padding: Padding::ZERO,
             horizontal_align: Horizontal::Left,
             vertical_align: Vertical::Center,
+            column_align: HashMap::new(),
             column_spacing: 0.,
             row_spacing: 0.,
             axis: Axis::Horizontal,
+            uniform: false,
+            auto_fit: None,
+            direction: None,
+            style: None,
         }
     }
 
@@ -63,7 +145,8 @@ impl<'a, Message, Theme, Renderer> Grid<'a, Message, Theme, Renderer> {
         I: IntoIterator<Item = E>,
     {
         let mut grid = Self::new();
-        grid.rows.extend(rows.into_iter().map(|row| row.into_iter().map(Into::into).collect()));
+        grid.rows.extend(rows.into_iter().map(|row| row.into_iter().map(Into::into).collect::<Vec<_>>()));
+        grid.keys = grid.rows.iter().map(|row| vec![None; row.len()]).collect();
         grid
     }
 
@@ -103,6 +186,12 @@ impl<'a, Message, Theme, Renderer> Grid<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// Sets the horizontal alignment of a single column, overriding the one set by [`align_x`](Self::align_x).
+    pub fn column_align_x(mut self, column: usize, horizontal: impl Into<Horizontal>) -> Self {
+        self.column_align.insert(column, horizontal.into());
+        self
+    }
+
     /// Sets the vertical alignment of the rows.
     pub fn align_y(mut self, vertical: impl Into<Vertical>) -> Self {
         self.vertical_align = vertical.into();
@@ -119,6 +208,58 @@ impl<'a, Message, Theme, Renderer> Grid<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// Sets whether all the cells of the grid have the same size.
+    ///
+    /// In uniform mode, every cell takes the size of the largest one, and the elements are laid out
+    /// with the size of the cell as their minimum size, so that they fill it.
+    /// If some cells fill the grid, the cells share its space evenly, without getting smaller than
+    /// the largest element.
+    pub fn uniform(mut self, uniform: bool) -> Self {
+        self.uniform = uniform;
+        self
+    }
+
+    /// Reflows the cells in as many columns of at least `min_column_width` as fit in the width
+    /// allotted to the grid, in reading order.
+    ///
+    /// The columns share the width evenly, and each row is as tall as its tallest cell. The rows
+    /// given to the grid only define the order of the cells, and the main axis is ignored.
+    pub fn auto_fit(mut self, min_column_width: impl Into<Pixels>) -> Self {
+        self.auto_fit = Some(min_column_width.into().0);
+        self
+    }
+
+    /// Sets the direction of the grid.
+    ///
+    /// From right to left, the layout is mirrored: the first column is on the right, and the
+    /// alignments and the padding are swapped.
+    ///
+    /// If this method is not called, the [`i18n::direction`] of the current thread is read
+    /// each time the grid is laid out.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the style of the grid.
+    ///
+    /// If neither this method nor [`class`](Self::class) is called, the grid is transparent.
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self {
+        self.style = Some(Box::new(style));
+        self
+    }
+
+    /// Sets the style class of the grid, from the [`Catalog`] of the theme.
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self
+    where
+        Theme: Catalog,
+        Theme::Class<'a>: 'a,
+    {
+        let class = class.into();
+        self.style = Some(Box::new(move |theme: &Theme| theme.style(&class)));
+        self
+    }
+
     /// Adds a row to the grid.
     pub fn push_row<E>(mut self, row: impl IntoIterator<Item = E>) -> Self
     where
@@ -144,9 +285,33 @@ impl<'a, Message, Theme, Renderer> Grid<'a, Message, Theme, Renderer> {
             self.height.enclose(size.height);
         }
 
+        self.keys.push(vec![None; row.len()]);
         self.rows.push(row);
     }
 
+    /// Adds a row of [`Keyed`] elements to the grid.
+    ///
+    /// The state of the keyed elements follows their key when the elements are moved
+    /// in the grid, see [`keyed`](crate::helpers::keyed).
+    pub fn push_keyed_row(
+        mut self,
+        row: impl IntoIterator<Item = Keyed<'a, Message, Theme, Renderer>>,
+    ) -> Self
+    where
+        Renderer: advanced::Renderer,
+    {
+        let (keys, row): (Vec<_>, Vec<_>) = row
+            .into_iter()
+            .map(|keyed| (Some(keyed.key), keyed.element))
+            .unzip();
+
+        self.push_row_mut(row);
+        if let Some(last) = self.keys.last_mut() {
+            *last = keys;
+        }
+        self
+    }
+
     /// Adds multiple rows to the grid.
     pub fn extend<E, I>(mut self, rows: impl IntoIterator<Item = I>) -> Self
     where
@@ -169,14 +334,33 @@ impl<'a, Message, Theme, Renderer> Grid<'a, Message, Theme, Renderer> {
     }
 }
 
+impl<'a, Message, Theme, Renderer> Default for Grid<'a, Message, Theme, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Grid<'a, Message, Theme, Renderer>
 where
     Renderer: advanced::Renderer,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            keys: self.keys.iter().flatten().copied().collect(),
+            scratch: Scratch::default(),
+        })
+    }
+
     fn diff(&self, tree: &mut iced::advanced::widget::Tree) {
         let children: Vec<_> = self.get_elements().collect();
-        tree.diff_children(&children);
+        let keys: Vec<_> = self.keys.iter().flatten().copied().collect();
+        let state = tree.state.downcast_mut::<State>();
+        diff_keyed_children(&mut tree.children, &mut state.keys, &children, &keys);
     }
 
     fn children(&self) -> Vec<advanced::widget::Tree> {
@@ -196,25 +380,225 @@ where
         renderer: &Renderer,
         limits: &advanced::layout::Limits,
     ) -> advanced::layout::Node {
+        // Reuse the buffers of the previous layouts.
+        let mut scratch = std::mem::take(&mut tree.state.downcast_mut::<State>().scratch);
+
+        let mut cells = Children {
+            rows: &self.rows,
+            trees: &mut tree.children,
+            renderer,
+        };
+        let node = self.layout_cells(&mut cells, &mut scratch, limits, self.size());
+
+        tree.state.downcast_mut::<State>().scratch = scratch;
+        node
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &advanced::renderer::Style,
+        layout: advanced::Layout<'_>,
+        cursor: advanced::mouse::Cursor,
+        viewport: &iced::Rectangle,
+    ) {
+        let appearance = self
+            .style
+            .as_ref()
+            .map_or_else(Style::default, |style| style(theme));
+        let bounds = layout.bounds();
+
+        if appearance.background.is_some() || appearance.border.width > 0. {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: appearance.border,
+                    ..renderer::Quad::default()
+                },
+                appearance
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        let style = &renderer::Style {
+            text_color: appearance.text_color.unwrap_or(style.text_color),
+        };
+
+        if let Some(clipped_viewport) = bounds.intersection(viewport) {
+            for ((child, state), layout) in self
+                .get_elements()
+                .zip(&tree.children)
+                .zip(layout.children())
+            {
+                child.as_widget().draw(
+                    state,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    cursor,
+                    &clipped_viewport,
+                );
+            }
+        }
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: layout::Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn advanced::widget::Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.get_elements()
+                .zip(&mut state.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: iced::Event,
+        layout: layout::Layout<'_>,
+        cursor: advanced::mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn advanced::Clipboard,
+        shell: &mut advanced::Shell<'_, Message>,
+        viewport: &iced::Rectangle,
+    ) -> advanced::graphics::core::event::Status {
+        self.get_mut_elements()
+            .zip(&mut state.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: layout::Layout<'_>,
+        cursor: advanced::mouse::Cursor,
+        viewport: &iced::Rectangle,
+        renderer: &Renderer,
+    ) -> advanced::mouse::Interaction {
+        self.get_elements()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: layout::Layout<'_>,
+        renderer: &Renderer,
+        translation: iced::Vector,
+    ) -> Option<advanced::overlay::Element<'b, Message, Theme, Renderer>> {
+        let children = self
+            .get_mut_elements()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((child, state), layout)| {
+                child
+                    .as_widget_mut()
+                    .overlay(state, layout, renderer, translation)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| advanced::overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message: 'a, Theme: 'a, Renderer: 'a> From<Grid<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: advanced::Renderer,
+{
+    fn from(value: Grid<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Grid<'a, Message, Theme, Renderer> {
+    /// Returns the direction set with [`direction`](Self::direction), or the one of the
+    /// [`i18n`] format of the current thread.
+    fn resolved_direction(&self) -> Direction {
+        self.direction.unwrap_or_else(i18n::direction)
+    }
+
+    fn get_elements(&self) -> impl Iterator<Item = &Element<'a, Message, Theme, Renderer>> {
+        self.rows.iter().flatten()
+    }
+
+    /// Lays out the cells of the grid, in the given `size`.
+    #[allow(clippy::needless_range_loop)]
+    fn layout_cells(
+        &self,
+        cells: &mut impl Cells,
+        scratch: &mut Scratch,
+        limits: &Limits,
+        size: Size<Length>,
+    ) -> Node {
         // Nomenclature (given for axis == Horizontal):
         // width / height -> main / cross
         // row / column -> prim / sec
 
+        if let Some(min_column_width) = self.auto_fit {
+            return self.layout_auto_fit(cells, limits, size, min_column_width);
+        }
+
         let axis = self.axis;
 
         let (max_main, max_cross) = {
             let limits = limits
-                .height(self.height)
-                .width(self.width)
+                .height(size.height)
+                .width(size.width)
                 .shrink(self.padding);
 
             axis.size_pack(limits.max())
         };
 
-        let (main_length, cross_length) = axis.pack(self.width, self.height);
+        let (main_length, cross_length) = axis.pack(size.width, size.height);
+
+        let nb_rows = cells.rows();
+        let nb_columns = (0..nb_rows).map(|a| cells.row_len(a)).max().unwrap_or(0);
 
-        let nb_columns = self.rows.iter().fold(0, |len, vec| len.max(vec.len()));
-        let nb_rows = self.rows.len();
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "grid_layout",
+            rows = nb_rows,
+            columns = nb_columns,
+            %axis,
+            uniform = self.uniform,
+        )
+        .entered();
 
         let (nb_prim, nb_sec) = axis.pack(nb_rows, nb_columns);
         let (main_spacing, cross_spacing) = axis.pack(self.column_spacing, self.row_spacing);
@@ -227,20 +611,16 @@ where
 
         let mut main = main_max;
 
-        let mut sec_main_factor = vec![0; nb_sec];
-        let mut prim_cross_factor = vec![0; nb_prim];
-
-        let mut sec_main = vec![0f32; nb_sec];
-
-        // Map trees to elements.
-        let mut elts_trees: Vec<Vec<_>> = {
-            let mut iter = tree.children.iter_mut();
-
-            self.rows
-                .iter()
-                .map(|vec| vec.iter().zip(&mut iter).collect())
-                .collect()
-        };
+        scratch.reset((0..nb_rows).map(|a| cells.row_len(a)), nb_prim, nb_sec);
+        let Scratch {
+            offsets,
+            sec_main_factor,
+            prim_cross_factor,
+            sec_main,
+            prim_cross,
+            clamped,
+        } = scratch;
+        let count = (0..nb_rows).map(|a| cells.row_len(a)).sum();
 
         // ==== Build prims with as much cross as they want. (It will be restricted later) ====
 
@@ -249,18 +629,13 @@ where
             for i in 0..nb_prim {
                 // Get element and tree
                 let (a, b) = axis.pack(i, j);
-                let (elt, tree) = {
-                    match elts_trees.get_mut(a).and_then(|vec| vec.get_mut(b)) {
-                        Some(v) => v,
-                        None => continue,
-                    }
-                };
+                if a >= nb_rows || b >= cells.row_len(a) {
+                    continue;
+                }
+                let index = offsets[a] + b;
 
                 // Check size and add fills
-                let (main_len, cross_len) = {
-                    let size = elt.as_widget().size();
-                    axis.size_pack(size)
-                };
+                let (main_len, cross_len) = axis.size_pack(cells.size(a, b));
 
                 let main_fill_factor = main_len.fill_factor();
                 let cross_fill_factor = cross_len.fill_factor();
@@ -273,7 +648,7 @@ where
                     let (max_width, max_height) = axis.pack(main, cross_max);
 
                     let child_limits = Limits::new(Size::ZERO, Size::new(max_width, max_height));
-                    let layout = elt.as_widget().layout(tree, renderer, &child_limits);
+                    let layout = cells.layout(a, b, index, &child_limits);
 
                     let main = axis.main(layout.size());
 
@@ -284,9 +659,17 @@ where
             main -= sec_main[j];
         }
 
+        #[cfg(feature = "tracing")]
+        tracing::trace!(tracks = ?sec_main, factors = ?sec_main_factor, "main tracks measured");
+
+        // The largest main of the contents, before the fill secs grow.
+        let content_main = sec_main.iter().copied().fold(0f32, f32::max);
+        let main_fills = main_length != Shrink && sec_main_factor.iter().any(|factor| *factor > 0);
+
         // Get the final main of the secs.
         if main_length != Shrink {
-            let mut not_clamped: HashSet<_> = (0..nb_sec).collect();
+            clamped.clear();
+            clamped.resize(nb_sec, false);
             main = max_main - main_total_spacing;
 
             let mut fill_sum = sec_main_factor.iter().sum::<u16>();
@@ -294,15 +677,20 @@ where
 
             while !finished && fill_sum > 0 {
                 finished = true;
-                let indexes: Vec<_> = not_clamped.iter().cloned().collect();
-                for j in indexes {
+                for j in 0..nb_sec {
+                    if clamped[j] {
+                        continue;
+                    }
                     let factor = sec_main_factor[j];
                     let size = factor as f32 / fill_sum as f32 * main;
                     let sec_size = sec_main[j];
                     if size < sec_size {
+                        #[cfg(feature = "tracing")]
+                        tracing::trace!(track = j, share = size, content = sec_size, "main track clamped to its content");
+
                         finished = false;
                         fill_sum -= factor;
-                        not_clamped.remove(&j);
+                        clamped[j] = true;
                         sec_main_factor[j] = 0;
                         main -= sec_size
                     }
@@ -316,32 +704,28 @@ where
                     0.
                 })
             }
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(tracks = ?sec_main, fill_sum, remaining = main, "main tracks resolved");
         }
 
         // ==== Resolve cross ====
 
         let mut cross = max_cross;
 
-        let mut nodes: Vec<Vec<_>> = self
-            .rows
-            .iter()
-            .map(|vec| vec.iter().map(|_| Node::default()).collect())
-            .collect();
+        let mut nodes: Vec<Node> = (0..count).map(|_| Node::default()).collect();
 
         // Compute min cross
-        let mut prim_cross = vec![0f32; nb_prim];
 
         for i in 0..nb_prim {
             for j in 0..nb_sec {
                 let (a, b) = axis.pack(i, j);
-                let (elt, tree) = {
-                    match elts_trees.get_mut(a).and_then(|vec| vec.get_mut(b)) {
-                        Some(v) => v,
-                        None => continue,
-                    }
-                };
+                if a >= nb_rows || b >= cells.row_len(a) {
+                    continue;
+                }
+                let index = offsets[a] + b;
 
-                let cross_factor = axis.cross(elt.as_widget().size()).fill_factor();
+                let cross_factor = axis.cross(cells.size(a, b)).fill_factor();
 
                 if cross_factor == 0 {
                     let (max_width, max_height) = axis.pack(sec_main[j], cross);
@@ -354,22 +738,30 @@ where
                         },
                     );
 
-                    let layout = elt.as_widget().layout(tree, renderer, &limits);
+                    let layout = cells.layout(a, b, index, &limits);
 
                     let size_cross = axis.cross(layout.size());
 
                     prim_cross[i] = prim_cross[i].max(size_cross);
-                    nodes[a][b] = layout;
+                    nodes[index] = layout;
                 }
             }
 
             cross -= prim_cross[i];
         }
 
+        #[cfg(feature = "tracing")]
+        tracing::trace!(tracks = ?prim_cross, factors = ?prim_cross_factor, "cross tracks measured");
+
+        // The largest cross of the contents, before the fill prims grow.
+        let content_cross = prim_cross.iter().copied().fold(0f32, f32::max);
+        let cross_fills = cross_length != Shrink && prim_cross_factor.iter().any(|factor| *factor > 0);
+
         // Compute main cross
 
         if cross_length != Shrink {
-            let mut not_clamped: HashSet<_> = (0..nb_prim).collect();
+            clamped.clear();
+            clamped.resize(nb_prim, false);
 
             cross = max_cross - cross_total_spacing;
 
@@ -378,15 +770,20 @@ where
 
             while !finished && fill_sum > 0 {
                 finished = true;
-                let indexes: Vec<_> = not_clamped.iter().cloned().collect();
-                for i in indexes {
+                for i in 0..nb_prim {
+                    if clamped[i] {
+                        continue;
+                    }
                     let factor = prim_cross_factor[i];
                     let size = factor as f32 / fill_sum as f32 * cross;
                     let prim_size = prim_cross[i];
                     if size < prim_size {
+                        #[cfg(feature = "tracing")]
+                        tracing::trace!(track = i, share = size, content = prim_size, "cross track clamped to its content");
+
                         finished = false;
                         fill_sum -= factor;
-                        not_clamped.remove(&i);
+                        clamped[i] = true;
                         prim_cross_factor[i] = 0;
                         cross -= prim_size
                     }
@@ -400,20 +797,21 @@ where
                     0.
                 })
             }
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(tracks = ?prim_cross, fill_sum, remaining = cross, "cross tracks resolved");
         }
 
         // Compute all nodes
         for i in 0..nb_prim {
             for j in 0..nb_sec {
                 let (a, b) = axis.pack(i, j);
-                let (elt, tree) = {
-                    match elts_trees.get_mut(a).and_then(|vec| vec.get_mut(b)) {
-                        Some(v) => v,
-                        None => continue,
-                    }
-                };
+                if a >= nb_rows || b >= cells.row_len(a) {
+                    continue;
+                }
+                let index = offsets[a] + b;
 
-                let cross_factor = axis.cross(elt.as_widget().size()).fill_factor();
+                let cross_factor = axis.cross(cells.size(a, b)).fill_factor();
 
                 if cross_factor != 0 {
                     let max_main = sec_main[j];
@@ -429,7 +827,38 @@ where
                         },
                     );
 
-                    nodes[a][b] = elt.as_widget().layout(tree, renderer, &limits);
+                    nodes[index] = cells.layout(a, b, index, &limits);
+                }
+            }
+        }
+
+        // Make all the cells the size of the largest one.
+        // When some cells fill, they share the available space evenly instead, so that the grid does not overflow.
+        if self.uniform {
+            let cell_main = if main_fills {
+                content_main.max(main_max / nb_sec as f32)
+            } else {
+                sec_main.iter().copied().fold(0f32, f32::max)
+            };
+            let cell_cross = if cross_fills {
+                content_cross.max(cross_max / nb_prim as f32)
+            } else {
+                prim_cross.iter().copied().fold(0f32, f32::max)
+            };
+
+            sec_main.iter_mut().for_each(|main| *main = cell_main);
+            prim_cross.iter_mut().for_each(|cross| *cross = cell_cross);
+
+            let (cell_width, cell_height) = axis.pack(cell_main, cell_cross);
+            let cell = Size::new(cell_width, cell_height);
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(?cell, main_fills, cross_fills, "uniform cell size");
+
+            for a in 0..nb_rows {
+                for b in 0..cells.row_len(a) {
+                    let index = offsets[a] + b;
+                    nodes[index] = cells.layout(a, b, index, &Limits::new(cell, cell));
                 }
             }
         }
@@ -439,11 +868,10 @@ where
         let mut x = start_x;
         let mut y = start_y;
 
-        let mut a = 0;
         let mut b = 0;
 
-        for vec_nodes in nodes.iter_mut() {
-            for node in vec_nodes.iter_mut() {
+        for a in 0..nb_rows {
+            for node in &mut nodes[offsets[a]..offsets[a] + cells.row_len(a)] {
                 let (i, j) = axis.pack(a, b);
 
                 node.move_to_mut(Point::new(x, y));
@@ -451,7 +879,11 @@ where
                 let (width, height) = axis.pack(sec_main[j], prim_cross[i]);
 
                 node.align_mut(
-                    self.horizontal_align.into(),
+                    self.column_align
+                        .get(&b)
+                        .copied()
+                        .unwrap_or(self.horizontal_align)
+                        .into(),
                     self.vertical_align.into(),
                     Size::new(width, height),
                 );
@@ -465,17 +897,30 @@ where
                 Axis::Horizontal => prim_cross[a],
                 Axis::Vertical => sec_main[a],
             } + self.row_spacing;
-            a += 1;
         }
 
+        debug_assert!(
+            sec_main.iter().chain(prim_cross.iter()).all(|size| *size >= 0.),
+            "grid tracks must have non-negative sizes, got {sec_main:?} and {prim_cross:?}"
+        );
+        debug_assert!(
+            nodes.iter().all(|node| {
+                let bounds = node.bounds();
+                [bounds.x, bounds.y, bounds.width, bounds.height].iter().all(|value| !value.is_nan())
+                    && bounds.width >= 0.
+                    && bounds.height >= 0.
+            }),
+            "grid cells must have valid bounds"
+        );
+
         let (intrinsic_width, intrinsic_height) = axis.pack(
             sec_main.iter().sum::<f32>() + main_total_spacing,
             prim_cross.iter().sum::<f32>() + cross_total_spacing,
         );
 
         let size = limits.resolve(
-            self.width,
-            self.height,
+            size.width,
+            size.height,
             Size {
                 width: intrinsic_width,
                 height: intrinsic_height,
@@ -483,150 +928,207 @@ where
             .expand(self.padding),
         );
 
+        #[cfg(feature = "tracing")]
+        {
+            let (column_widths, row_heights) = axis.pack(&sec_main[..], &prim_cross[..]);
+            tracing::debug!(?column_widths, ?row_heights, ?size, "grid laid out");
+        }
+
+        let direction = self.resolved_direction();
+        if direction.is_rtl() {
+            for node in &mut nodes {
+                let bounds = node.bounds();
+                let x = direction.mirror(bounds.x, bounds.width, size.width);
+                node.move_to_mut(Point::new(x, bounds.y));
+            }
+        }
+
         Node::with_children(
             size, // size.expand(self.padding),
-            nodes.into_iter().flatten().collect(),
+            nodes,
         )
     }
 
-    fn draw(
+    /// Lays out the cells in as many columns as fit, see [`Grid::auto_fit`].
+    fn layout_auto_fit(
         &self,
-        tree: &Tree,
-        renderer: &mut Renderer,
-        theme: &Theme,
-        style: &advanced::renderer::Style,
-        layout: advanced::Layout<'_>,
-        cursor: advanced::mouse::Cursor,
-        viewport: &iced::Rectangle,
-    ) {
-        if let Some(clipped_viewport) = layout.bounds().intersection(viewport) {
-            for ((child, state), layout) in self
-                .get_elements()
-                .zip(&tree.children)
-                .zip(layout.children())
-            {
-                child.as_widget().draw(
-                    state,
-                    renderer,
-                    theme,
-                    style,
-                    layout,
-                    cursor,
-                    &clipped_viewport,
+        cells: &mut impl Cells,
+        limits: &Limits,
+        size: Size<Length>,
+        min_column_width: f32,
+    ) -> Node {
+        let max = limits
+            .width(size.width)
+            .height(size.height)
+            .shrink(self.padding)
+            .max();
+        let spacing = self.column_spacing;
+        let count = (0..cells.rows()).map(|a| cells.row_len(a)).sum::<usize>();
+
+        let columns = if max.width.is_finite() {
+            ((max.width + spacing) / (min_column_width + spacing)).floor() as usize
+        } else {
+            count
+        }
+        .clamp(1, count.max(1));
+        let column_width = if max.width.is_finite() {
+            ((max.width - spacing * (columns - 1) as f32) / columns as f32).max(0.)
+        } else {
+            min_column_width
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(columns, column_width, available = max.width, "grid auto fit");
+
+        let cell_limits = Limits::new(Size::ZERO, Size::new(column_width, max.height));
+        let mut nodes = Vec::with_capacity(count);
+        for a in 0..cells.rows() {
+            for b in 0..cells.row_len(a) {
+                let index = nodes.len();
+                nodes.push(cells.layout(a, b, index, &cell_limits));
+            }
+        }
+
+        let mut y = self.padding.top;
+        for row in nodes.chunks_mut(columns) {
+            let height = row
+                .iter()
+                .map(|node| node.size().height)
+                .fold(0f32, f32::max);
+
+            for (column, node) in row.iter_mut().enumerate() {
+                let x = self.padding.left + column as f32 * (column_width + spacing);
+                node.move_to_mut(Point::new(x, y));
+                node.align_mut(
+                    self.column_align
+                        .get(&column)
+                        .copied()
+                        .unwrap_or(self.horizontal_align)
+                        .into(),
+                    self.vertical_align.into(),
+                    Size::new(column_width, height),
                 );
             }
+
+            y += height + self.row_spacing;
         }
-    }
 
-    fn operate(
-        &self,
-        state: &mut Tree,
-        layout: layout::Layout<'_>,
-        renderer: &Renderer,
-        operation: &mut dyn advanced::widget::Operation,
-    ) {
-        operation.container(None, layout.bounds(), &mut |operation| {
-            self.get_elements()
-                .zip(&mut state.children)
-                .zip(layout.children())
-                .for_each(|((child, state), layout)| {
-                    child
-                        .as_widget()
-                        .operate(state, layout, renderer, operation);
-                });
-        });
+        let rows = count.div_ceil(columns);
+        let intrinsic = Size::new(
+            column_width * columns as f32 + spacing * columns.saturating_sub(1) as f32,
+            y - self.padding.top - if rows > 0 { self.row_spacing } else { 0. },
+        );
+        let size = limits.resolve(size.width, size.height, intrinsic.expand(self.padding));
+
+        let direction = self.resolved_direction();
+        if direction.is_rtl() {
+            for node in &mut nodes {
+                let bounds = node.bounds();
+                let x = direction.mirror(bounds.x, bounds.width, size.width);
+                node.move_to_mut(Point::new(x, bounds.y));
+            }
+        }
+
+        Node::with_children(size, nodes)
     }
 
-    fn on_event(
+    fn get_mut_elements(
         &mut self,
-        state: &mut Tree,
-        event: iced::Event,
-        layout: layout::Layout<'_>,
-        cursor: advanced::mouse::Cursor,
-        renderer: &Renderer,
-        clipboard: &mut dyn advanced::Clipboard,
-        shell: &mut advanced::Shell<'_, Message>,
-        viewport: &iced::Rectangle,
-    ) -> advanced::graphics::core::event::Status {
-        self.get_mut_elements()
-            .zip(&mut state.children)
-            .zip(layout.children())
-            .map(|((child, state), layout)| {
-                child.as_widget_mut().on_event(
-                    state,
-                    event.clone(),
-                    layout,
-                    cursor,
-                    renderer,
-                    clipboard,
-                    shell,
-                    viewport,
-                )
-            })
-            .fold(event::Status::Ignored, event::Status::merge)
+    ) -> impl Iterator<Item = &mut Element<'a, Message, Theme, Renderer>> {
+        self.rows.iter_mut().flatten()
     }
+}
 
-    fn mouse_interaction(
-        &self,
-        tree: &Tree,
-        layout: layout::Layout<'_>,
-        cursor: advanced::mouse::Cursor,
-        viewport: &iced::Rectangle,
-        renderer: &Renderer,
-    ) -> advanced::mouse::Interaction {
-        self.get_elements()
-            .zip(&tree.children)
-            .zip(layout.children())
-            .map(|((child, state), layout)| {
-                child
-                    .as_widget()
-                    .mouse_interaction(state, layout, cursor, viewport, renderer)
-            })
-            .max()
-            .unwrap_or_default()
-    }
+/// The state of a [`Grid`].
+struct State {
+    /// The keys of the cells during the last diff.
+    keys: Vec<Option<u64>>,
+    /// The buffers of the layout, kept between layouts to avoid reallocating them.
+    scratch: Scratch,
+}
 
-    fn overlay<'b>(
-        &'b mut self,
-        tree: &'b mut Tree,
-        layout: layout::Layout<'_>,
-        renderer: &Renderer,
-        translation: iced::Vector,
-    ) -> Option<advanced::overlay::Element<'b, Message, Theme, Renderer>> {
-        let children = self
-            .get_mut_elements()
-            .zip(&mut tree.children)
-            .zip(layout.children())
-            .filter_map(|((child, state), layout)| {
-                child
-                    .as_widget_mut()
-                    .overlay(state, layout, renderer, translation)
-            })
-            .collect::<Vec<_>>();
+/// The buffers used during the layout of a [`Grid`].
+#[derive(Default)]
+struct Scratch {
+    /// The index of the first cell of each row in the flattened cells.
+    offsets: Vec<usize>,
+    sec_main_factor: Vec<u16>,
+    prim_cross_factor: Vec<u16>,
+    sec_main: Vec<f32>,
+    prim_cross: Vec<f32>,
+    /// Whether a track was clamped to its content while distributing the fill.
+    clamped: Vec<bool>,
+}
 
-        (!children.is_empty()).then(|| advanced::overlay::Group::with_children(children).overlay())
+impl Scratch {
+    /// Clears the buffers and sizes them for the given rows and tracks.
+    fn reset(&mut self, row_lens: impl Iterator<Item = usize>, nb_prim: usize, nb_sec: usize) {
+        self.offsets.clear();
+        self.offsets.extend(row_lens.scan(0, |offset, len| {
+            let start = *offset;
+            *offset += len;
+            Some(start)
+        }));
+
+        for (factors, len) in [
+            (&mut self.sec_main_factor, nb_sec),
+            (&mut self.prim_cross_factor, nb_prim),
+        ] {
+            factors.clear();
+            factors.resize(len, 0);
+        }
+        for (sizes, len) in [(&mut self.sec_main, nb_sec), (&mut self.prim_cross, nb_prim)] {
+            sizes.clear();
+            sizes.resize(len, 0.);
+        }
     }
 }
 
-impl<'a, Message: 'a, Theme: 'a, Renderer: 'a> From<Grid<'a, Message, Theme, Renderer>>
-    for Element<'a, Message, Theme, Renderer>
+/// The cells of a [`Grid`], as seen by its layout.
+///
+/// The cell at column `b` of row `a` is also the cell `index` of the flattened cells.
+/// It allows to share the layout with the [`Grid`] of iced 0.14.
+trait Cells {
+    /// The number of rows.
+    fn rows(&self) -> usize;
+
+    /// The number of cells of row `a`.
+    fn row_len(&self, a: usize) -> usize;
+
+    /// The size of the cell at column `b` of row `a`.
+    fn size(&self, a: usize, b: usize) -> Size<Length>;
+
+    /// Lays out the cell at column `b` of row `a`.
+    fn layout(&mut self, a: usize, b: usize, index: usize, limits: &Limits) -> Node;
+}
+
+/// The elements of a [`Grid`] and their trees.
+struct Children<'g, 'a, 't, 'r, Message, Theme, Renderer> {
+    rows: &'g [Vec<Element<'a, Message, Theme, Renderer>>],
+    trees: &'t mut [Tree],
+    renderer: &'r Renderer,
+}
+
+impl<Message, Theme, Renderer> Cells for Children<'_, '_, '_, '_, Message, Theme, Renderer>
 where
     Renderer: advanced::Renderer,
 {
-    fn from(value: Grid<'a, Message, Theme, Renderer>) -> Self {
-        Self::new(value)
+    fn rows(&self) -> usize {
+        self.rows.len()
     }
-}
 
-impl<'a, Message, Theme, Renderer> Grid<'a, Message, Theme, Renderer> {
-    fn get_elements(&self) -> impl Iterator<Item = &Element<'a, Message, Theme, Renderer>> {
-        self.rows.iter().flatten()
+    fn row_len(&self, a: usize) -> usize {
+        self.rows[a].len()
     }
 
-    fn get_mut_elements(
-        &mut self,
-    ) -> impl Iterator<Item = &mut Element<'a, Message, Theme, Renderer>> {
-        self.rows.iter_mut().flatten()
+    fn size(&self, a: usize, b: usize) -> Size<Length> {
+        self.rows[a][b].as_widget().size()
+    }
+
+    fn layout(&mut self, a: usize, b: usize, index: usize, limits: &Limits) -> Node {
+        self.rows[a][b]
+            .as_widget()
+            .layout(&mut self.trees[index], self.renderer, limits)
     }
 }
 
@@ -634,6 +1136,7 @@ impl<'a, Message, Theme, Renderer> Grid<'a, Message, Theme, Renderer> {
 ///
 /// See the [Grid::main_axis] method for more info.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Axis {
     /// The horizontal axis
     Horizontal,
@@ -0,0 +1,428 @@
+//! The [`Grid`] for iced 0.14.
+//!
+//! It lays out its cells like the [`Grid`](super::Grid) of iced 0.13, with the same settings.
+//! It does not draw a background nor a border, wrap it in a container to style it, and it does
+//! not support keyed rows.
+
+use iced_widget_014::core::{
+    self, Clipboard, Element, Event, Layout, Length, Padding, Pixels, Rectangle, Shell, Size,
+    Vector, Widget,
+    alignment::{Horizontal, Vertical},
+    layout::{Limits, Node},
+    mouse, overlay, renderer,
+    widget::{Operation, Tree, tree},
+};
+
+use super::{Axis, Scratch};
+use crate::{helpers::iced_014 as convert, i18n::Direction};
+
+/// The [`Grid`](super::Grid) widget, for iced 0.14.
+pub struct Grid<'a, Message, Theme, Renderer> {
+    rows: Vec<Vec<Element<'a, Message, Theme, Renderer>>>,
+    /// The settings of the grid, with no cells.
+    settings: super::Grid<'a, (), (), ()>,
+}
+
+impl<'a, Message, Theme, Renderer> Grid<'a, Message, Theme, Renderer> {
+    /// Creates a new empty grid.
+    pub fn new() -> Self {
+        Self {
+            rows: Vec::new(),
+            settings: super::Grid::new(),
+        }
+    }
+
+    /// Creates a [`Grid`] with the given rows.
+    pub fn with_rows<E, I>(rows: impl IntoIterator<Item = I>) -> Self
+    where
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+        I: IntoIterator<Item = E>,
+        Renderer: core::Renderer,
+    {
+        Self::new().extend(rows)
+    }
+
+    /// Sets the spacing between the columns.
+    pub fn column_spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.settings = self.settings.column_spacing(spacing.into().0);
+        self
+    }
+
+    /// Sets the spacing between the rows.
+    pub fn row_spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.settings = self.settings.row_spacing(spacing.into().0);
+        self
+    }
+
+    /// Sets the padding of the grid.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.settings = self.settings.padding(convert::padding(padding.into()));
+        self
+    }
+
+    /// Sets the width of the grid.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.settings = self.settings.width(convert::length(width.into()));
+        self
+    }
+
+    /// Sets the height of the grid.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.settings = self.settings.height(convert::length(height.into()));
+        self
+    }
+
+    /// Sets the horizontal alignment of the columns.
+    pub fn align_x(mut self, horizontal: impl Into<Horizontal>) -> Self {
+        self.settings = self
+            .settings
+            .align_x(convert::horizontal(horizontal.into()));
+        self
+    }
+
+    /// Sets the horizontal alignment of a single column, overriding the one set by [`align_x`](Self::align_x).
+    pub fn column_align_x(mut self, column: usize, horizontal: impl Into<Horizontal>) -> Self {
+        self.settings = self
+            .settings
+            .column_align_x(column, convert::horizontal(horizontal.into()));
+        self
+    }
+
+    /// Sets the vertical alignment of the rows.
+    pub fn align_y(mut self, vertical: impl Into<Vertical>) -> Self {
+        self.settings = self.settings.align_y(convert::vertical(vertical.into()));
+        self
+    }
+
+    /// Sets the main axis of the grid, see [`Grid::main_axis`](super::Grid::main_axis).
+    pub fn main_axis(mut self, axis: impl Into<Axis>) -> Self {
+        self.settings = self.settings.main_axis(axis);
+        self
+    }
+
+    /// Sets whether all the cells of the grid have the same size, see [`Grid::uniform`](super::Grid::uniform).
+    pub fn uniform(mut self, uniform: bool) -> Self {
+        self.settings = self.settings.uniform(uniform);
+        self
+    }
+
+    /// Reflows the cells in as many columns of at least `min_column_width` as fit, see
+    /// [`Grid::auto_fit`](super::Grid::auto_fit).
+    pub fn auto_fit(mut self, min_column_width: impl Into<Pixels>) -> Self {
+        self.settings = self.settings.auto_fit(min_column_width.into().0);
+        self
+    }
+
+    /// Sets the direction of the grid, see [`Grid::direction`](super::Grid::direction).
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.settings = self.settings.direction(direction);
+        self
+    }
+
+    /// Adds a row to the grid.
+    pub fn push_row<E>(mut self, row: impl IntoIterator<Item = E>) -> Self
+    where
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+        Renderer: core::Renderer,
+    {
+        self.push_row_mut(row);
+        self
+    }
+
+    /// Same as [`push_row`](Self::push_row) but takes a reference to `self`.
+    pub fn push_row_mut<E>(&mut self, row: impl IntoIterator<Item = E>)
+    where
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+        Renderer: core::Renderer,
+    {
+        let row = row.into_iter().map(Into::into).collect::<Vec<_>>();
+
+        for e in row.iter() {
+            let size = convert::size_length(e.as_widget().size_hint());
+
+            self.settings.width.enclose(size.width);
+            self.settings.height.enclose(size.height);
+        }
+
+        self.rows.push(row);
+    }
+
+    /// Adds multiple rows to the grid.
+    pub fn extend<E, I>(mut self, rows: impl IntoIterator<Item = I>) -> Self
+    where
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+        I: IntoIterator<Item = E>,
+        Renderer: core::Renderer,
+    {
+        self.extend_mut(rows);
+        self
+    }
+
+    /// Same as [`extend`](Self::extend) but takes a reference to `self`.
+    pub fn extend_mut<E, I>(&mut self, rows: impl IntoIterator<Item = I>)
+    where
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+        I: IntoIterator<Item = E>,
+        Renderer: core::Renderer,
+    {
+        rows.into_iter().for_each(|row| self.push_row_mut(row));
+    }
+
+    fn get_elements(&self) -> impl Iterator<Item = &Element<'a, Message, Theme, Renderer>> {
+        self.rows.iter().flatten()
+    }
+
+    fn get_mut_elements(
+        &mut self,
+    ) -> impl Iterator<Item = &mut Element<'a, Message, Theme, Renderer>> {
+        self.rows.iter_mut().flatten()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Default for Grid<'a, Message, Theme, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Scratch>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Scratch::default())
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let children: Vec<_> = self.get_elements().collect();
+        tree.diff_children(&children);
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.get_elements().map(Tree::new).collect()
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(
+            convert::length_14(self.settings.width),
+            convert::length_14(self.settings.height),
+        )
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        // Compressed fill lengths shrink to the content in iced 0.14.
+        let compression = limits.compression();
+        let mut size = iced::Size::new(self.settings.width, self.settings.height);
+        if compression.width && size.width.is_fill() {
+            size.width = iced::Length::Shrink;
+        }
+        if compression.height && size.height.is_fill() {
+            size.height = iced::Length::Shrink;
+        }
+
+        let mut scratch = std::mem::take(tree.state.downcast_mut::<Scratch>());
+        let mut cells = Children {
+            rows: &mut self.rows,
+            trees: &mut tree.children,
+            renderer,
+            nodes: Vec::new(),
+        };
+        let node =
+            self.settings
+                .layout_cells(&mut cells, &mut scratch, &convert::limits_13(limits), size);
+        *tree.state.downcast_mut::<Scratch>() = scratch;
+
+        // The shared layout placed stand-ins of the cells, move the actual nodes to them.
+        let mut nodes = cells.nodes;
+        nodes.resize_with(node.children().len(), Option::default);
+        let children = node
+            .children()
+            .iter()
+            .zip(nodes)
+            .map(|(placed, node)| {
+                node.unwrap_or_default()
+                    .move_to(convert::point(placed.bounds().position()))
+            })
+            .collect();
+
+        Node::with_children(convert::size(node.size()), children)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if let Some(clipped_viewport) = layout.bounds().intersection(viewport) {
+            for ((child, state), layout) in self
+                .get_elements()
+                .zip(&tree.children)
+                .zip(layout.children())
+            {
+                child.as_widget().draw(
+                    state,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    cursor,
+                    &clipped_viewport,
+                );
+            }
+        }
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.get_mut_elements()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget_mut()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        for ((child, state), layout) in self
+            .get_mut_elements()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            child.as_widget_mut().update(
+                state, event, layout, cursor, renderer, clipboard, shell, viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.get_elements()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children = self
+            .get_mut_elements()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((child, state), layout)| {
+                child
+                    .as_widget_mut()
+                    .overlay(state, layout, renderer, viewport, translation)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message: 'a, Theme: 'a, Renderer: 'a> From<Grid<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn from(value: Grid<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(value)
+    }
+}
+
+/// The elements of a [`Grid`] and their trees, laid out with iced 0.14.
+///
+/// The shared layout gets stand-ins with the size of the cells, and the actual nodes are kept
+/// until it has placed them.
+struct Children<'g, 'a, 't, 'r, Message, Theme, Renderer> {
+    rows: &'g mut [Vec<Element<'a, Message, Theme, Renderer>>],
+    trees: &'t mut [Tree],
+    renderer: &'r Renderer,
+    /// The last node of each cell, by index.
+    nodes: Vec<Option<Node>>,
+}
+
+impl<Message, Theme, Renderer> super::Cells for Children<'_, '_, '_, '_, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn row_len(&self, a: usize) -> usize {
+        self.rows[a].len()
+    }
+
+    fn size(&self, a: usize, b: usize) -> iced::Size<iced::Length> {
+        convert::size_length(self.rows[a][b].as_widget().size())
+    }
+
+    fn layout(
+        &mut self,
+        a: usize,
+        b: usize,
+        index: usize,
+        limits: &iced::advanced::layout::Limits,
+    ) -> iced::advanced::layout::Node {
+        let node = self.rows[a][b].as_widget_mut().layout(
+            &mut self.trees[index],
+            self.renderer,
+            &convert::limits(limits),
+        );
+        let stand_in = iced::advanced::layout::Node::new(convert::size_13(node.size()));
+
+        if self.nodes.len() <= index {
+            self.nodes.resize_with(index + 1, Option::default);
+        }
+        self.nodes[index] = Some(node);
+        stand_in
+    }
+}
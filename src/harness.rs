@@ -0,0 +1,475 @@
+//! Headless testing of widgets, available with the `test-utils` feature.
+//!
+//! A [`Harness`] keeps the state of a widget tree between interactions, like an application does.
+//! A view is [mounted](Harness::mount) on it, then fed synthetic mouse and keyboard events. The
+//! produced messages and the geometry of the layout can then be checked. Mounting a view again,
+//! for example after updating the state it borrows, keeps the widget states, so that a focused
+//! text input stays focused.
+//!
+//! The widgets are laid out and drawn with the [`Headless`] renderer: text has no size and nothing
+//! is drawn, which makes the results independent of the platform and of the fonts. Only the
+//! widgets generic over their renderer can be mounted.
+//!
+//! ```
+//! use iced::{Point, Size, widget::{button, column, Space}};
+//! use more_iced_aw::harness::Harness;
+//!
+//! let mut harness: Harness<&str> = Harness::new().size(Size::new(200., 200.));
+//!
+//! let mut ui = harness.mount(column![
+//!     Space::new(100, 50),
+//!     button(Space::new(100, 30)).padding(0).on_press("pressed"),
+//! ]);
+//!
+//! let bounds = ui.bounds(&[1]).unwrap();
+//! assert_eq!(bounds.y, 50.);
+//!
+//! ui.click(bounds.center());
+//! assert_eq!(ui.take_messages(), vec!["pressed"]);
+//! ```
+
+use std::time::Instant;
+
+use iced::{
+    Color, Element, Point, Rectangle, Size, Vector,
+    advanced::{
+        Clipboard, Layout, Shell,
+        clipboard::Kind,
+        layout::{Limits, Node},
+        mouse, renderer,
+        widget::{Id, Operation, Tree, operation::focusable},
+    },
+    event, keyboard, window,
+};
+
+mod headless;
+pub use headless::*;
+
+/// The state of a widget tree between interactions, and everything it produced.
+///
+/// See the [module](self) documentation.
+pub struct Harness<Message, Theme = iced::Theme, Renderer = Headless> {
+    tree: Tree,
+    node: Node,
+    size: Size,
+    cursor: mouse::Cursor,
+    modifiers: keyboard::Modifiers,
+    renderer: Renderer,
+    theme: Theme,
+    clipboard: MockClipboard,
+    messages: Vec<Message>,
+}
+
+impl<Message, Theme, Renderer> Harness<Message, Theme, Renderer>
+where
+    Theme: Default,
+    Renderer: Default,
+{
+    /// Creates a new [`Harness`] with a window of 1024x768, and the default theme.
+    pub fn new() -> Self {
+        Self {
+            tree: Tree::empty(),
+            node: Node::default(),
+            size: Size::new(1024., 768.),
+            cursor: mouse::Cursor::Unavailable,
+            modifiers: keyboard::Modifiers::empty(),
+            renderer: Renderer::default(),
+            theme: Theme::default(),
+            clipboard: MockClipboard::default(),
+            messages: Vec::new(),
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Default for Harness<Message, Theme, Renderer>
+where
+    Theme: Default,
+    Renderer: Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message, Theme, Renderer> Harness<Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    /// Sets the size of the window the widgets are laid out in.
+    pub fn size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the theme the widgets are drawn with.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Mounts a view on the [`Harness`], reusing the states of the previous one where it matches.
+    pub fn mount<'h, 'a>(
+        &'h mut self,
+        root: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Mounted<'h, 'a, Message, Theme, Renderer> {
+        let root = root.into();
+        self.tree.diff(&root);
+
+        let mut mounted = Mounted {
+            harness: self,
+            root,
+        };
+        mounted.relayout();
+        mounted
+    }
+
+    /// Returns the messages produced since they were last taken.
+    pub fn messages(&self) -> &[Message] {
+        &self.messages
+    }
+
+    /// Takes the messages produced since they were last taken.
+    pub fn take_messages(&mut self) -> Vec<Message> {
+        std::mem::take(&mut self.messages)
+    }
+
+    /// Returns the text in the clipboard, if any.
+    pub fn clipboard(&self) -> Option<&str> {
+        self.clipboard.standard.as_deref()
+    }
+
+    /// Sets the text in the clipboard.
+    pub fn set_clipboard(&mut self, contents: impl Into<String>) {
+        self.clipboard.standard = Some(contents.into());
+    }
+}
+
+/// A view mounted on a [`Harness`], created with [`Harness::mount`].
+///
+/// The view is laid out again after every event.
+pub struct Mounted<'h, 'a, Message, Theme, Renderer> {
+    harness: &'h mut Harness<Message, Theme, Renderer>,
+    root: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<Message, Theme, Renderer> Mounted<'_, '_, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    /// Returns the layout of the view.
+    pub fn layout(&self) -> Layout<'_> {
+        Layout::new(&self.harness.node)
+    }
+
+    /// Returns the bounds of a node of the layout, found by following the indices of `path` among
+    /// the children from the root.
+    ///
+    /// The bounds of the root are returned for an empty path.
+    pub fn bounds(&self, path: &[usize]) -> Option<Rectangle> {
+        path.iter()
+            .try_fold(self.layout(), |layout, &index| layout.children().nth(index))
+            .map(|layout| layout.bounds())
+    }
+
+    /// Feeds an event to the view and its overlay, and returns whether it was captured.
+    pub fn event(&mut self, event: iced::Event) -> event::Status {
+        match event {
+            iced::Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                self.harness.cursor = mouse::Cursor::Available(position);
+            }
+            iced::Event::Mouse(mouse::Event::CursorLeft) => {
+                self.harness.cursor = mouse::Cursor::Unavailable;
+            }
+            iced::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                self.harness.modifiers = modifiers;
+            }
+            _ => {}
+        }
+
+        let Harness {
+            tree,
+            node,
+            size,
+            cursor,
+            renderer,
+            clipboard,
+            messages,
+            ..
+        } = &mut *self.harness;
+
+        let mut shell = Shell::new(messages);
+        let layout = Layout::new(node);
+
+        let (overlay_status, over_overlay) =
+            match self
+                .root
+                .as_widget_mut()
+                .overlay(tree, layout, renderer, Vector::ZERO)
+            {
+                Some(mut overlay) => {
+                    let node = overlay.layout(renderer, *size);
+                    let layout = Layout::new(&node);
+                    let over = cursor
+                        .position()
+                        .is_some_and(|position| overlay.is_over(layout, renderer, position));
+                    let status = overlay.on_event(
+                        event.clone(),
+                        layout,
+                        *cursor,
+                        renderer,
+                        clipboard,
+                        &mut shell,
+                    );
+                    (status, over)
+                }
+                None => (event::Status::Ignored, false),
+            };
+
+        let status = if overlay_status == event::Status::Captured {
+            overlay_status
+        } else {
+            self.root.as_widget_mut().on_event(
+                tree,
+                event,
+                layout,
+                if over_overlay {
+                    mouse::Cursor::Unavailable
+                } else {
+                    *cursor
+                },
+                renderer,
+                clipboard,
+                &mut shell,
+                &Rectangle::with_size(*size),
+            )
+        };
+
+        self.relayout();
+        status
+    }
+
+    /// Moves the cursor to a position.
+    pub fn move_cursor(&mut self, position: Point) -> event::Status {
+        self.event(iced::Event::Mouse(mouse::Event::CursorMoved { position }))
+    }
+
+    /// Presses a mouse button at the current position of the cursor.
+    pub fn press(&mut self, button: mouse::Button) -> event::Status {
+        self.event(iced::Event::Mouse(mouse::Event::ButtonPressed(button)))
+    }
+
+    /// Releases a mouse button at the current position of the cursor.
+    pub fn release(&mut self, button: mouse::Button) -> event::Status {
+        self.event(iced::Event::Mouse(mouse::Event::ButtonReleased(button)))
+    }
+
+    /// Moves the cursor to a position, then presses and releases the left button.
+    pub fn click(&mut self, position: Point) -> event::Status {
+        self.move_cursor(position);
+        self.press(mouse::Button::Left)
+            .merge(self.release(mouse::Button::Left))
+    }
+
+    /// Moves the cursor to `from` with the left button pressed, then to `to` where the button is
+    /// released.
+    pub fn drag(&mut self, from: Point, to: Point) -> event::Status {
+        self.move_cursor(from);
+        self.press(mouse::Button::Left)
+            .merge(self.move_cursor(to))
+            .merge(self.release(mouse::Button::Left))
+    }
+
+    /// Scrolls by a number of lines at the current position of the cursor.
+    pub fn scroll(&mut self, delta: Vector) -> event::Status {
+        self.event(iced::Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Lines {
+                x: delta.x,
+                y: delta.y,
+            },
+        }))
+    }
+
+    /// Sets the modifiers held while the following keys are pressed.
+    pub fn modifiers(&mut self, modifiers: keyboard::Modifiers) -> event::Status {
+        self.event(iced::Event::Keyboard(keyboard::Event::ModifiersChanged(
+            modifiers,
+        )))
+    }
+
+    /// Presses and releases a key with the current modifiers.
+    ///
+    /// The key produces text if it is a character and no modifier but Shift is held.
+    pub fn key(&mut self, key: keyboard::Key) -> event::Status {
+        let modifiers = self.harness.modifiers;
+        let text = match &key {
+            keyboard::Key::Character(character)
+                if !modifiers.control() && !modifiers.alt() && !modifiers.logo() =>
+            {
+                Some(character.clone())
+            }
+            _ => None,
+        };
+
+        let pressed = self.event(iced::Event::Keyboard(keyboard::Event::KeyPressed {
+            key: key.clone(),
+            modified_key: key.clone(),
+            physical_key: keyboard::key::Physical::Unidentified(
+                keyboard::key::NativeCode::Unidentified,
+            ),
+            location: keyboard::Location::Standard,
+            modifiers,
+            text,
+        }));
+
+        let released = self.event(iced::Event::Keyboard(keyboard::Event::KeyReleased {
+            key: key.clone(),
+            location: keyboard::Location::Standard,
+            modifiers,
+        }));
+
+        pressed.merge(released)
+    }
+
+    /// Presses and releases a named key, like Enter or Tab, with the current modifiers.
+    pub fn named_key(&mut self, named: keyboard::key::Named) -> event::Status {
+        self.key(keyboard::Key::Named(named))
+    }
+
+    /// Types a text, one character at a time.
+    pub fn type_text(&mut self, text: &str) -> event::Status {
+        text.chars().fold(event::Status::Ignored, |status, c| {
+            status.merge(self.key(keyboard::Key::Character(c.to_string().into())))
+        })
+    }
+
+    /// Sends a redraw request at the given instant, which animated widgets use to move forward.
+    pub fn redraw(&mut self, now: Instant) -> event::Status {
+        self.event(iced::Event::Window(window::Event::RedrawRequested(now)))
+    }
+
+    /// Runs an operation on the view and its overlay.
+    pub fn operate(&mut self, operation: &mut dyn Operation) {
+        let Harness {
+            tree,
+            node,
+            size,
+            renderer,
+            ..
+        } = &mut *self.harness;
+        let layout = Layout::new(node);
+
+        self.root
+            .as_widget()
+            .operate(tree, layout, renderer, operation);
+
+        if let Some(mut overlay) =
+            self.root
+                .as_widget_mut()
+                .overlay(tree, layout, renderer, Vector::ZERO)
+        {
+            let node = overlay.layout(renderer, *size);
+            overlay.operate(Layout::new(&node), renderer, operation);
+        }
+
+        self.relayout();
+    }
+
+    /// Focuses the focusable widget with the given [`Id`].
+    pub fn focus(&mut self, id: impl Into<Id>) {
+        self.operate(&mut focusable::focus::<()>(id.into()));
+    }
+
+    /// Draws the view and its overlay, returning the mouse interaction at the cursor.
+    ///
+    /// Nothing is actually drawn, but the drawing code of the widgets runs.
+    pub fn draw(&mut self) -> mouse::Interaction {
+        let Harness {
+            tree,
+            node,
+            size,
+            cursor,
+            renderer,
+            theme,
+            ..
+        } = &mut *self.harness;
+        let layout = Layout::new(node);
+        let viewport = Rectangle::with_size(*size);
+        let style = renderer::Style {
+            text_color: Color::BLACK,
+        };
+
+        let widget = self.root.as_widget();
+        widget.draw(tree, renderer, theme, &style, layout, *cursor, &viewport);
+        let interaction = widget.mouse_interaction(tree, layout, *cursor, &viewport, renderer);
+
+        match self
+            .root
+            .as_widget_mut()
+            .overlay(tree, layout, renderer, Vector::ZERO)
+        {
+            Some(mut overlay) => {
+                let node = overlay.layout(renderer, *size);
+                let layout = Layout::new(&node);
+
+                renderer.with_layer(viewport, |renderer| {
+                    overlay.draw(renderer, theme, &style, layout, *cursor);
+                });
+
+                match overlay.mouse_interaction(layout, *cursor, &viewport, renderer) {
+                    mouse::Interaction::None => interaction,
+                    overlay => overlay,
+                }
+            }
+            None => interaction,
+        }
+    }
+
+    /// Returns the messages produced since they were last taken.
+    pub fn messages(&self) -> &[Message] {
+        &self.harness.messages
+    }
+
+    /// Takes the messages produced since they were last taken.
+    pub fn take_messages(&mut self) -> Vec<Message> {
+        std::mem::take(&mut self.harness.messages)
+    }
+
+    /// Lays out the view in the window.
+    fn relayout(&mut self) {
+        let Harness {
+            tree,
+            node,
+            size,
+            renderer,
+            ..
+        } = &mut *self.harness;
+
+        *node = self
+            .root
+            .as_widget()
+            .layout(tree, renderer, &Limits::new(Size::ZERO, *size));
+    }
+}
+
+/// A clipboard kept in memory.
+#[derive(Debug, Default)]
+struct MockClipboard {
+    standard: Option<String>,
+    primary: Option<String>,
+}
+
+impl Clipboard for MockClipboard {
+    fn read(&self, kind: Kind) -> Option<String> {
+        match kind {
+            Kind::Standard => self.standard.clone(),
+            Kind::Primary => self.primary.clone(),
+        }
+    }
+
+    fn write(&mut self, kind: Kind, contents: String) {
+        match kind {
+            Kind::Standard => self.standard = Some(contents),
+            Kind::Primary => self.primary = Some(contents),
+        }
+    }
+}
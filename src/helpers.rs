@@ -20,26 +20,145 @@ pub fn filter_color(color: Color, filter: Color) -> Color {
 }
 
 /// Adds a [`Color`] on top of a [`Background`].
+///
+/// This is the same as [`BackgroundExt::filter`].
 pub fn filter_background(background: Background, filter: Color) -> Background {
-    match background {
-        iced::Background::Color(color) => Background::Color(filter_color(color, filter)),
-        iced::Background::Gradient(gradient) => match gradient {
-            iced::Gradient::Linear(linear) => {
-                let new_stops = linear.stops.map(|x| {
-                    x.map(|stop| ColorStop {
-                        color: filter_color(stop.color, filter),
+    background.filter(filter)
+}
+
+/// Operations on the colors of a [`Background`], for use in style functions.
+pub trait BackgroundExt {
+    /// Applies `f` to all the colors of the [`Background`], including the stops of gradients.
+    fn map_colors(self, f: impl Fn(Color) -> Color) -> Self;
+
+    /// Adds a [`Color`] on top of the [`Background`] (see [`filter_color`]).
+    fn filter(self, filter: Color) -> Self
+    where
+        Self: Sized,
+    {
+        self.map_colors(|color| filter_color(color, filter))
+    }
+
+    /// Multiplies each channel of the colors of the [`Background`] by the ones of `factor`.
+    fn multiply(self, factor: Color) -> Self
+    where
+        Self: Sized,
+    {
+        self.map_colors(|color| {
+            Color::from_rgba(
+                color.r * factor.r,
+                color.g * factor.g,
+                color.b * factor.b,
+                color.a * factor.a,
+            )
+        })
+    }
+
+    /// Sets the alpha channel of all the colors of the [`Background`].
+    fn with_alpha(self, alpha: f32) -> Self
+    where
+        Self: Sized,
+    {
+        self.map_colors(|color| Color { a: alpha, ..color })
+    }
+}
+
+impl BackgroundExt for Background {
+    fn map_colors(self, f: impl Fn(Color) -> Color) -> Self {
+        match self {
+            Background::Color(color) => Background::Color(f(color)),
+            Background::Gradient(gradient) => Background::Gradient(gradient.map_colors(f)),
+        }
+    }
+}
+
+impl BackgroundExt for Gradient {
+    fn map_colors(self, f: impl Fn(Color) -> Color) -> Self {
+        match self {
+            Gradient::Linear(linear) => Gradient::Linear(Linear {
+                stops: linear.stops.map(|stop| {
+                    stop.map(|stop| ColorStop {
+                        color: f(stop.color),
                         ..stop
                     })
-                });
+                }),
+                ..linear
+            }),
+        }
+    }
+}
+
+/// Linearly interpolates between two [`Color`]s.
+///
+/// `t` is the proportion of `b` in the result: 0 gives `a` and 1 gives `b`.
+/// See [`mix`] to interpolate in a perceptual space instead.
+pub fn lerp_color(a: Color, b: Color, t: f32) -> Color {
+    let lerp = |x: f32, y: f32| x + (y - x) * t;
+
+    Color::from_rgba(lerp(a.r, b.r), lerp(a.g, b.g), lerp(a.b, b.b), lerp(a.a, b.a))
+}
 
-                Background::Gradient(Gradient::Linear(Linear {
-                    stops: new_stops,
-                    ..linear
-                }))
+/// Linearly interpolates between two [`Background`]s.
+///
+/// Gradients are interpolated stop by stop. A [`Color`] interpolated with a gradient
+/// behaves like a gradient of the same shape with that color on all the stops.
+pub fn lerp_background(a: Background, b: Background, t: f32) -> Background {
+    let as_linear = |background: Background, other: &Background| match background {
+        Background::Gradient(Gradient::Linear(linear)) => linear,
+        Background::Color(color) => {
+            let mut linear = match other {
+                Background::Gradient(Gradient::Linear(linear)) => *linear,
+                Background::Color(_) => Linear::new(0.),
+            };
+            for stop in linear.stops.iter_mut().flatten() {
+                stop.color = color;
             }
-        },
+            linear
+        }
+    };
+
+    if let (Background::Color(a), Background::Color(b)) = (a, b) {
+        return Background::Color(lerp_color(a, b, t));
     }
+
+    let (a, b) = (as_linear(a, &b), as_linear(b, &a));
+    let mut stops = a.stops;
+
+    for (stop, other) in stops.iter_mut().zip(b.stops) {
+        *stop = match (*stop, other) {
+            (Some(stop), Some(other)) => Some(ColorStop {
+                offset: stop.offset + (other.offset - stop.offset) * t,
+                color: lerp_color(stop.color, other.color, t),
+            }),
+            (stop, other) => stop.or(other),
+        };
+    }
+
+    Background::Gradient(Gradient::Linear(Linear {
+        angle: (a.angle.0 + (b.angle.0 - a.angle.0) * t).into(),
+        stops,
+    }))
 }
 
+mod color;
+mod conditional;
+mod debug_outline;
+mod display;
 mod element_vec;
-pub use element_vec::*;
\ No newline at end of file
+mod form;
+#[cfg(feature = "iced_014")]
+pub(crate) mod iced_014;
+mod keyed;
+mod length;
+mod states;
+#[cfg(feature = "serde")]
+pub(crate) mod serde_iced;
+pub use color::*;
+pub use conditional::*;
+pub use debug_outline::*;
+pub use display::*;
+pub use element_vec::*;
+pub use form::*;
+pub use keyed::*;
+pub use length::*;
+pub use states::*;
\ No newline at end of file
@@ -0,0 +1,335 @@
+use iced::Color;
+
+/// A color in the [Oklab](https://bottosson.github.io/posts/oklab/) perceptual space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Oklab {
+    l: f32,
+    a: f32,
+    b: f32,
+    alpha: f32,
+}
+
+impl From<Color> for Oklab {
+    fn from(color: Color) -> Self {
+        let [r, g, b, alpha] = color.into_linear();
+
+        let l = 0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b;
+        let m = 0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b;
+        let s = 0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b;
+
+        let (l, m, s) = (l.cbrt(), m.cbrt(), s.cbrt());
+
+        Self {
+            l: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+            alpha,
+        }
+    }
+}
+
+impl From<Oklab> for Color {
+    fn from(color: Oklab) -> Self {
+        let l = color.l + 0.396_337_78 * color.a + 0.215_803_76 * color.b;
+        let m = color.l - 0.105_561_346 * color.a - 0.063_854_17 * color.b;
+        let s = color.l - 0.089_484_18 * color.a - 1.291_485_5 * color.b;
+
+        let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+
+        let r = 4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s;
+        let g = -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s;
+        let b = -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s;
+
+        Color::from_linear_rgba(
+            r.clamp(0., 1.),
+            g.clamp(0., 1.),
+            b.clamp(0., 1.),
+            color.alpha,
+        )
+    }
+}
+
+/// Makes a [`Color`] lighter by `amount` (between 0 and 1), in a perceptual space.
+///
+/// An `amount` of 1 always gives white.
+pub fn lighten(color: Color, amount: f32) -> Color {
+    let mut lab = Oklab::from(color);
+    lab.l = (lab.l + amount).clamp(0., 1.);
+    lab.into()
+}
+
+/// Makes a [`Color`] darker by `amount` (between 0 and 1), in a perceptual space.
+///
+/// An `amount` of 1 always gives black.
+pub fn darken(color: Color, amount: f32) -> Color {
+    lighten(color, -amount)
+}
+
+/// Mixes two [`Color`]s in a perceptual space.
+///
+/// `t` is the proportion of `b` in the result: 0 gives `a` and 1 gives `b`.
+pub fn mix(a: Color, b: Color, t: f32) -> Color {
+    let (a, b) = (Oklab::from(a), Oklab::from(b));
+    let lerp = |x: f32, y: f32| x + (y - x) * t;
+
+    Oklab {
+        l: lerp(a.l, b.l),
+        a: lerp(a.a, b.a),
+        b: lerp(a.b, b.b),
+        alpha: lerp(a.alpha, b.alpha),
+    }
+    .into()
+}
+
+/// Changes the saturation of a [`Color`] by `amount`, in a perceptual space.
+///
+/// The chroma is multiplied by `1 + amount`, so a positive `amount` saturates the color,
+/// a negative one desaturates it, and -1 gives a gray of the same lightness.
+pub fn saturate(color: Color, amount: f32) -> Color {
+    let mut lab = Oklab::from(color);
+    let factor = (1. + amount).max(0.);
+    lab.a *= factor;
+    lab.b *= factor;
+    lab.into()
+}
+
+/// Returns the relative luminance of a [`Color`], as defined by the
+/// [WCAG](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance).
+///
+/// The alpha channel is ignored.
+pub fn luminance(color: Color) -> f32 {
+    let [r, g, b, _] = color.into_linear();
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// Returns the [WCAG](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio) contrast ratio
+/// between two [`Color`]s, from 1 (no contrast) to 21 (black on white).
+///
+/// The alpha channels are ignored.
+pub fn contrast_ratio(a: Color, b: Color) -> f32 {
+    let (a, b) = (luminance(a), luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// Returns black or white, whichever has the best contrast with the `background`.
+pub fn readable_text_color(background: Color) -> Color {
+    if contrast_ratio(background, Color::BLACK) >= contrast_ratio(background, Color::WHITE) {
+        Color::BLACK
+    } else {
+        Color::WHITE
+    }
+}
+
+/// Returns `preferred` if its contrast ratio with the `background` is at least `min_ratio`,
+/// and [`readable_text_color`] otherwise.
+///
+/// The WCAG recommends a ratio of at least 4.5 for normal text and 3 for large text.
+pub fn readable_text_color_or(background: Color, preferred: Color, min_ratio: f32) -> Color {
+    if contrast_ratio(background, preferred) >= min_ratio {
+        preferred
+    } else {
+        readable_text_color(background)
+    }
+}
+
+/// A color in the HSL (hue, saturation, lightness) space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hsl {
+    /// The hue, in degrees (between 0 and 360).
+    pub h: f32,
+    /// The saturation (between 0 and 1).
+    pub s: f32,
+    /// The lightness (between 0 and 1).
+    pub l: f32,
+    /// The alpha channel (between 0 and 1).
+    pub a: f32,
+}
+
+/// A color in the HSV (hue, saturation, value) space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hsv {
+    /// The hue, in degrees (between 0 and 360).
+    pub h: f32,
+    /// The saturation (between 0 and 1).
+    pub s: f32,
+    /// The value (between 0 and 1).
+    pub v: f32,
+    /// The alpha channel (between 0 and 1).
+    pub a: f32,
+}
+
+/// A color in the [OKLCH](https://bottosson.github.io/posts/oklab/) perceptual space.
+///
+/// Colors out of the sRGB gamut are clamped when converted into a [`Color`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Oklch {
+    /// The perceived lightness (between 0 and 1).
+    pub l: f32,
+    /// The chroma (between 0 and about 0.37 for colors in the sRGB gamut).
+    pub c: f32,
+    /// The hue, in degrees (between 0 and 360).
+    pub h: f32,
+    /// The alpha channel (between 0 and 1).
+    pub a: f32,
+}
+
+/// Returns the hue (in degrees), the maximum and the minimum of the channels of a [`Color`].
+fn hue_max_min(color: Color) -> (f32, f32, f32) {
+    let Color { r, g, b, .. } = color;
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let hue = if delta == 0. {
+        0.
+    } else if max == r {
+        // A tiny negative ratio wraps around to exactly 6, which is a hue of 0.
+        (60. * ((g - b) / delta).rem_euclid(6.)) % 360.
+    } else if max == g {
+        60. * ((b - r) / delta + 2.)
+    } else {
+        60. * ((r - g) / delta + 4.)
+    };
+
+    (hue, max, min)
+}
+
+/// Builds a [`Color`] from a hue (in degrees), a chroma and a value to add to all the channels.
+fn from_hue_chroma(h: f32, chroma: f32, m: f32, a: f32) -> Color {
+    let h = h.rem_euclid(360.) / 60.;
+    let x = chroma * (1. - (h.rem_euclid(2.) - 1.).abs());
+
+    let (r, g, b) = match h as u8 {
+        0 => (chroma, x, 0.),
+        1 => (x, chroma, 0.),
+        2 => (0., chroma, x),
+        3 => (0., x, chroma),
+        4 => (x, 0., chroma),
+        _ => (chroma, 0., x),
+    };
+
+    Color::from_rgba(r + m, g + m, b + m, a)
+}
+
+impl From<Color> for Hsl {
+    fn from(color: Color) -> Self {
+        let (h, max, min) = hue_max_min(color);
+        let l = (max + min) / 2.;
+        let s = if max == min {
+            0.
+        } else {
+            (max - min) / (1. - (2. * l - 1.).abs())
+        };
+
+        Self { h, s, l, a: color.a }
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(hsl: Hsl) -> Self {
+        let chroma = (1. - (2. * hsl.l - 1.).abs()) * hsl.s;
+        from_hue_chroma(hsl.h, chroma, hsl.l - chroma / 2., hsl.a)
+    }
+}
+
+impl From<Color> for Hsv {
+    fn from(color: Color) -> Self {
+        let (h, max, min) = hue_max_min(color);
+        let s = if max == 0. { 0. } else { (max - min) / max };
+
+        Self {
+            h,
+            s,
+            v: max,
+            a: color.a,
+        }
+    }
+}
+
+impl From<Hsv> for Color {
+    fn from(hsv: Hsv) -> Self {
+        let chroma = hsv.v * hsv.s;
+        from_hue_chroma(hsv.h, chroma, hsv.v - chroma, hsv.a)
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(lab: Oklab) -> Self {
+        Self {
+            l: lab.l,
+            c: lab.a.hypot(lab.b),
+            h: lab.b.atan2(lab.a).to_degrees().rem_euclid(360.),
+            a: lab.alpha,
+        }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(lch: Oklch) -> Self {
+        let (sin, cos) = lch.h.to_radians().sin_cos();
+        Self {
+            l: lch.l,
+            a: lch.c * cos,
+            b: lch.c * sin,
+            alpha: lch.a,
+        }
+    }
+}
+
+impl From<Color> for Oklch {
+    fn from(color: Color) -> Self {
+        Oklab::from(color).into()
+    }
+}
+
+impl From<Oklch> for Color {
+    fn from(lch: Oklch) -> Self {
+        Oklab::from(lch).into()
+    }
+}
+
+/// Adds constructors and conversions from and to other color spaces to [`Color`].
+pub trait ColorExt {
+    /// Creates a [`Color`] from its hue (in degrees), saturation and lightness.
+    fn from_hsl(h: f32, s: f32, l: f32) -> Self;
+    /// Creates a [`Color`] from its hue (in degrees), saturation and value.
+    fn from_hsv(h: f32, s: f32, v: f32) -> Self;
+    /// Creates a [`Color`] from its perceived lightness, chroma and hue (in degrees).
+    fn from_oklch(l: f32, c: f32, h: f32) -> Self;
+
+    /// Converts the [`Color`] into the HSL space.
+    fn to_hsl(self) -> Hsl;
+    /// Converts the [`Color`] into the HSV space.
+    fn to_hsv(self) -> Hsv;
+    /// Converts the [`Color`] into the OKLCH space.
+    fn to_oklch(self) -> Oklch;
+}
+
+impl ColorExt for Color {
+    fn from_hsl(h: f32, s: f32, l: f32) -> Self {
+        Hsl { h, s, l, a: 1. }.into()
+    }
+
+    fn from_hsv(h: f32, s: f32, v: f32) -> Self {
+        Hsv { h, s, v, a: 1. }.into()
+    }
+
+    fn from_oklch(l: f32, c: f32, h: f32) -> Self {
+        Oklch { l, c, h, a: 1. }.into()
+    }
+
+    fn to_hsl(self) -> Hsl {
+        self.into()
+    }
+
+    fn to_hsv(self) -> Hsv {
+        self.into()
+    }
+
+    fn to_oklch(self) -> Oklch {
+        self.into()
+    }
+}
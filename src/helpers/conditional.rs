@@ -0,0 +1,24 @@
+use iced::advanced::graphics::core::Element;
+
+/// Returns `a` if `condition` is true, and `b` otherwise, converted into an [`Element`].
+pub fn either<'a, Message, Theme, Renderer>(
+    condition: bool,
+    a: impl Into<Element<'a, Message, Theme, Renderer>>,
+    b: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Element<'a, Message, Theme, Renderer> {
+    if condition { a.into() } else { b.into() }
+}
+
+/// Builds an [`Element`] with `f` only if `condition` is true.
+///
+/// The result can be given to the `push_maybe` methods of [`Row`](iced::widget::Row),
+/// [`Column`](iced::widget::Column) or [`ElementVec`](super::ElementVec).
+pub fn when<'a, Message, Theme, Renderer, E>(
+    condition: bool,
+    f: impl FnOnce() -> E,
+) -> Option<Element<'a, Message, Theme, Renderer>>
+where
+    E: Into<Element<'a, Message, Theme, Renderer>>,
+{
+    condition.then(|| f().into())
+}
@@ -0,0 +1,197 @@
+use iced::{
+    Border, Color, Length, Pixels, Point, Rectangle, Size, Vector,
+    advanced::{
+        Clipboard, Shell, Widget,
+        graphics::core::Element,
+        layout, mouse, overlay, renderer,
+        text::{self, Text},
+        widget::{Operation, Tree, tree},
+    },
+    alignment, event,
+};
+
+/// The size of the text of the size label of a [`DebugOutline`].
+const LABEL_SIZE: f32 = 10.;
+
+/// An element with its bounds outlined, created with [`debug_outline`].
+///
+/// It does not change the layout of the element, which allows to apply it
+/// to a single element, contrary to [`Element::explain`].
+pub struct DebugOutline<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    color: Color,
+    size_label: bool,
+}
+
+impl<'a, Message, Theme, Renderer> DebugOutline<'a, Message, Theme, Renderer> {
+    /// Sets whether the size of the element is displayed in its top left corner.
+    pub fn size_label(mut self, size_label: bool) -> Self {
+        self.size_label = size_label;
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DebugOutline<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn state(&self) -> tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn tag(&self) -> tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.content.as_widget().diff(tree);
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(tree, renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .as_widget()
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    color: self.color,
+                    width: 1.,
+                    radius: 0.into(),
+                },
+                ..Default::default()
+            },
+            Color::TRANSPARENT,
+        );
+
+        if self.size_label {
+            renderer.fill_text(
+                Text {
+                    content: format!("{}x{}", bounds.width, bounds.height),
+                    bounds: bounds.size(),
+                    size: Pixels(LABEL_SIZE),
+                    line_height: text::LineHeight::default(),
+                    font: renderer.default_font(),
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Top,
+                    shaping: text::Shaping::Basic,
+                    wrapping: text::Wrapping::None,
+                },
+                Point::new(bounds.x + 2., bounds.y + 1.),
+                self.color,
+                bounds,
+            );
+        }
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: layout::Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(state, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: iced::Event,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            state, event, layout, cursor, renderer, clipboard, shell, viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(state, layout, cursor, viewport, renderer)
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: layout::Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(state, layout, renderer, translation)
+    }
+}
+
+impl<'a, Message: 'a, Theme: 'a, Renderer: 'a> From<DebugOutline<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn from(value: DebugOutline<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Outlines the bounds of an element with a thin border of the given [`Color`].
+///
+/// See [`DebugOutline::size_label`] to also display the size of the element.
+pub fn debug_outline<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    color: impl Into<Color>,
+) -> DebugOutline<'a, Message, Theme, Renderer> {
+    DebugOutline {
+        content: content.into(),
+        color: color.into(),
+        size_label: false,
+    }
+}
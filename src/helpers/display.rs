@@ -0,0 +1,254 @@
+use std::{fmt::Display, ops::Deref, str::FromStr};
+
+use iced::{
+    Length,
+    alignment::{Horizontal, Vertical},
+};
+
+/// The error produced when parsing a [`DispLength`], a [`DispHorizontal`] or a [`DispVertical`]
+/// from an unknown string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParseVariantError {
+    /// The string that could not be parsed.
+    pub string: String,
+}
+
+impl Display for ParseVariantError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown variant \"{}\"", self.string)
+    }
+}
+
+impl std::error::Error for ParseVariantError {}
+
+/// A [`Length`] that can be displayed, for example in a [`ComboBox`](iced::widget::ComboBox).
+///
+/// It is displayed as the name of its variant (`Fill`, `Fill Portion`, `Shrink` or `Fixed`), without its value.
+/// When parsing, the name can be followed by a value (e.g. `Fixed 20` or `Fill Portion 2`),
+/// which defaults to 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DispLength {
+    /// The inner length.
+    pub length: Length,
+}
+
+impl DispLength {
+    /// All the variants of [`Length`], with a value of 1.
+    pub const ALL: [DispLength; 4] = [
+        DispLength {
+            length: Length::Shrink,
+        },
+        DispLength {
+            length: Length::Fixed(1.),
+        },
+        DispLength {
+            length: Length::FillPortion(1),
+        },
+        DispLength {
+            length: Length::Fill,
+        },
+    ];
+}
+
+impl Display for DispLength {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self.length {
+                Length::Fill => "Fill",
+                Length::FillPortion(_) => "Fill Portion",
+                Length::Shrink => "Shrink",
+                Length::Fixed(_) => "Fixed",
+            }
+        )
+    }
+}
+
+impl FromStr for DispLength {
+    type Err = ParseVariantError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseVariantError {
+            string: s.to_string(),
+        };
+
+        let lower = s.trim().to_lowercase();
+        let name_end = lower
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(lower.len());
+        let (name, value) = lower.split_at(name_end);
+        let name: String = name.split_whitespace().collect();
+
+        let length = match (name.as_str(), value.is_empty()) {
+            ("fill", true) => Length::Fill,
+            ("shrink", true) => Length::Shrink,
+            ("fillportion", true) => Length::FillPortion(1),
+            ("fillportion", false) => Length::FillPortion(value.parse().map_err(|_| err())?),
+            ("fixed", true) => Length::Fixed(1.),
+            ("fixed", false) => Length::Fixed(value.parse().map_err(|_| err())?),
+            _ => return Err(err()),
+        };
+
+        Ok(length.into())
+    }
+}
+
+impl From<Length> for DispLength {
+    fn from(value: Length) -> Self {
+        Self { length: value }
+    }
+}
+
+impl From<DispLength> for Length {
+    fn from(value: DispLength) -> Self {
+        value.length
+    }
+}
+
+impl Deref for DispLength {
+    type Target = Length;
+
+    fn deref(&self) -> &Self::Target {
+        &self.length
+    }
+}
+
+/// A [`Horizontal`] alignment that can be displayed, for example in a [`ComboBox`](iced::widget::ComboBox).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DispHorizontal {
+    /// The inner alignment.
+    pub alignment: Horizontal,
+}
+
+impl DispHorizontal {
+    /// All the variants of [`Horizontal`].
+    pub const ALL: [DispHorizontal; 3] = [
+        DispHorizontal {
+            alignment: Horizontal::Left,
+        },
+        DispHorizontal {
+            alignment: Horizontal::Center,
+        },
+        DispHorizontal {
+            alignment: Horizontal::Right,
+        },
+    ];
+}
+
+impl Display for DispHorizontal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self.alignment {
+                Horizontal::Left => "Left",
+                Horizontal::Center => "Center",
+                Horizontal::Right => "Right",
+            }
+        )
+    }
+}
+
+impl FromStr for DispHorizontal {
+    type Err = ParseVariantError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|v| v.to_string().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| ParseVariantError {
+                string: s.to_string(),
+            })
+    }
+}
+
+impl From<Horizontal> for DispHorizontal {
+    fn from(value: Horizontal) -> Self {
+        Self { alignment: value }
+    }
+}
+
+impl From<DispHorizontal> for Horizontal {
+    fn from(value: DispHorizontal) -> Self {
+        value.alignment
+    }
+}
+
+impl Deref for DispHorizontal {
+    type Target = Horizontal;
+
+    fn deref(&self) -> &Self::Target {
+        &self.alignment
+    }
+}
+
+/// A [`Vertical`] alignment that can be displayed, for example in a [`ComboBox`](iced::widget::ComboBox).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DispVertical {
+    /// The inner alignment.
+    pub alignment: Vertical,
+}
+
+impl DispVertical {
+    /// All the variants of [`Vertical`].
+    pub const ALL: [DispVertical; 3] = [
+        DispVertical {
+            alignment: Vertical::Top,
+        },
+        DispVertical {
+            alignment: Vertical::Center,
+        },
+        DispVertical {
+            alignment: Vertical::Bottom,
+        },
+    ];
+}
+
+impl Display for DispVertical {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self.alignment {
+                Vertical::Top => "Top",
+                Vertical::Center => "Center",
+                Vertical::Bottom => "Bottom",
+            }
+        )
+    }
+}
+
+impl FromStr for DispVertical {
+    type Err = ParseVariantError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|v| v.to_string().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| ParseVariantError {
+                string: s.to_string(),
+            })
+    }
+}
+
+impl From<Vertical> for DispVertical {
+    fn from(value: Vertical) -> Self {
+        Self { alignment: value }
+    }
+}
+
+impl From<DispVertical> for Vertical {
+    fn from(value: DispVertical) -> Self {
+        value.alignment
+    }
+}
+
+impl Deref for DispVertical {
+    type Target = Vertical;
+
+    fn deref(&self) -> &Self::Target {
+        &self.alignment
+    }
+}
@@ -74,6 +74,39 @@ impl<'a, Message, Theme, Renderer> ElementVec<'a, Message, Theme, Renderer> {
         self.vec.insert(index, element.into());
     }
 
+    /// Push an element that can be converted into an [`Element`], if it is [`Some`].
+    pub fn push_maybe<E>(&mut self, element: Option<E>)
+    where
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        if let Some(element) = element {
+            self.push(element);
+        }
+    }
+
+    /// Insert an element that can be converted into an [`Element`], if it is [`Some`].
+    pub fn insert_maybe<E>(&mut self, index: usize, element: Option<E>)
+    where
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        if let Some(element) = element {
+            self.insert(index, element);
+        }
+    }
+
+    /// Remove the last element and return it, or [`None`] if the vector is empty.
+    pub fn pop(&mut self) -> Option<Element<'a, Message, Theme, Renderer>> {
+        self.vec.pop()
+    }
+
+    /// Retain only the elements for which `f` returns true.
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&Element<'a, Message, Theme, Renderer>) -> bool,
+    {
+        self.vec.retain(f);
+    }
+
     /// Extend the vector with elements convertible into [`Element`].
     pub fn extend<E, I>(&mut self, iter: I)
     where
@@ -0,0 +1,116 @@
+use iced::{
+    Length, Pixels,
+    advanced::{self, graphics::core::Element},
+    alignment::{Horizontal, Vertical},
+    widget::{Column, Container, Row, container},
+};
+
+use crate::grid::Grid;
+
+/// The spacing between the labels and the fields of a [`form`] or a [`Labeled`] element.
+const FORM_SPACING: f32 = 10.;
+
+/// Builds a two columns [`Grid`] of labels and fields.
+///
+/// The labels are right aligned, and each label is vertically centered with its field.
+/// The returned [`Grid`] can be further customized, for example with [`Grid::column_spacing`].
+pub fn form<'a, Message, Theme, Renderer, L, F>(
+    pairs: impl IntoIterator<Item = (L, F)>,
+) -> Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: advanced::Renderer,
+    L: Into<Element<'a, Message, Theme, Renderer>>,
+    F: Into<Element<'a, Message, Theme, Renderer>>,
+{
+    pairs
+        .into_iter()
+        .fold(Grid::new(), |grid, (label, field)| {
+            grid.push_row([label.into(), field.into()])
+        })
+        .column_spacing(FORM_SPACING)
+        .row_spacing(FORM_SPACING)
+        .align_y(Vertical::Center)
+        .column_align_x(0, Horizontal::Right)
+}
+
+/// The position of the label of a [`Labeled`] element.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LabelPosition {
+    /// The label is on the left of the element, and vertically centered with it.
+    #[default]
+    Left,
+    /// The label is above the element.
+    Top,
+}
+
+/// An element with a label, created with [`labeled`].
+pub struct Labeled<'a, Message, Theme, Renderer> {
+    label: Element<'a, Message, Theme, Renderer>,
+    content: Element<'a, Message, Theme, Renderer>,
+    label_width: Length,
+    position: LabelPosition,
+    spacing: f32,
+}
+
+impl<'a, Message, Theme, Renderer> Labeled<'a, Message, Theme, Renderer> {
+    /// Sets the width of the label, which allows to align several [`Labeled`] elements.
+    pub fn label_width(mut self, width: impl Into<Length>) -> Self {
+        self.label_width = width.into();
+        self
+    }
+
+    /// Sets the [`LabelPosition`] of the label.
+    pub fn position(mut self, position: LabelPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the spacing between the label and the element.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+}
+
+impl<'a, Message: 'a, Theme: 'a, Renderer: 'a> From<Labeled<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: advanced::Renderer,
+    Theme: container::Catalog,
+{
+    fn from(value: Labeled<'a, Message, Theme, Renderer>) -> Self {
+        let label = Container::new(value.label).width(value.label_width);
+
+        match value.position {
+            LabelPosition::Left => Row::new()
+                .push(label)
+                .push(value.content)
+                .spacing(value.spacing)
+                .align_y(Vertical::Center)
+                .into(),
+            LabelPosition::Top => Column::new()
+                .push(label)
+                .push(value.content)
+                .spacing(value.spacing)
+                .into(),
+        }
+    }
+}
+
+/// Adds a label to an element.
+///
+/// By default, the label is on the left, vertically centered with the element.
+/// See [`Labeled`] for the other options.
+pub fn labeled<'a, Message, Theme, Renderer>(
+    label: impl Into<Element<'a, Message, Theme, Renderer>>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Labeled<'a, Message, Theme, Renderer> {
+    Labeled {
+        label: label.into(),
+        content: content.into(),
+        label_width: Length::Shrink,
+        position: LabelPosition::default(),
+        spacing: FORM_SPACING,
+    }
+}
@@ -0,0 +1,86 @@
+//! Conversions between the types of iced 0.13 and the ones of iced 0.14.
+//!
+//! The widgets of the `iced_014` modules share their logic with the ones of iced 0.13,
+//! so they convert their inputs and outputs at the boundary.
+
+use iced_widget_014::core;
+
+/// Converts a [`Length`](core::Length) of iced 0.14.
+pub(crate) fn length(length: core::Length) -> iced::Length {
+    match length {
+        core::Length::Fill => iced::Length::Fill,
+        core::Length::FillPortion(portion) => iced::Length::FillPortion(portion),
+        core::Length::Shrink => iced::Length::Shrink,
+        core::Length::Fixed(amount) => iced::Length::Fixed(amount),
+    }
+}
+
+/// Converts a [`Length`](iced::Length) of iced 0.13.
+pub(crate) fn length_14(length: iced::Length) -> core::Length {
+    match length {
+        iced::Length::Fill => core::Length::Fill,
+        iced::Length::FillPortion(portion) => core::Length::FillPortion(portion),
+        iced::Length::Shrink => core::Length::Shrink,
+        iced::Length::Fixed(amount) => core::Length::Fixed(amount),
+    }
+}
+
+/// Converts a [`Size`](core::Size) of lengths of iced 0.14.
+pub(crate) fn size_length(size: core::Size<core::Length>) -> iced::Size<iced::Length> {
+    iced::Size::new(length(size.width), length(size.height))
+}
+
+/// Converts a [`Size`](iced::Size) of iced 0.13.
+pub(crate) fn size(size: iced::Size) -> core::Size {
+    core::Size::new(size.width, size.height)
+}
+
+/// Converts a [`Size`](core::Size) of iced 0.14.
+pub(crate) fn size_13(size: core::Size) -> iced::Size {
+    iced::Size::new(size.width, size.height)
+}
+
+/// Converts a [`Point`](iced::Point) of iced 0.13.
+pub(crate) fn point(point: iced::Point) -> core::Point {
+    core::Point::new(point.x, point.y)
+}
+
+/// Converts the [`Limits`](core::layout::Limits) of iced 0.14.
+///
+/// The compression is dropped, the caller must take it into account.
+pub(crate) fn limits_13(limits: &core::layout::Limits) -> iced::advanced::layout::Limits {
+    iced::advanced::layout::Limits::new(size_13(limits.min()), size_13(limits.max()))
+}
+
+/// Converts the [`Limits`](iced::advanced::layout::Limits) of iced 0.13.
+pub(crate) fn limits(limits: &iced::advanced::layout::Limits) -> core::layout::Limits {
+    core::layout::Limits::new(size(limits.min()), size(limits.max()))
+}
+
+/// Converts a [`Padding`](core::Padding) of iced 0.14.
+pub(crate) fn padding(padding: core::Padding) -> iced::Padding {
+    iced::Padding {
+        top: padding.top,
+        right: padding.right,
+        bottom: padding.bottom,
+        left: padding.left,
+    }
+}
+
+/// Converts an horizontal alignment of iced 0.14.
+pub(crate) fn horizontal(horizontal: core::alignment::Horizontal) -> iced::alignment::Horizontal {
+    match horizontal {
+        core::alignment::Horizontal::Left => iced::alignment::Horizontal::Left,
+        core::alignment::Horizontal::Center => iced::alignment::Horizontal::Center,
+        core::alignment::Horizontal::Right => iced::alignment::Horizontal::Right,
+    }
+}
+
+/// Converts a vertical alignment of iced 0.14.
+pub(crate) fn vertical(vertical: core::alignment::Vertical) -> iced::alignment::Vertical {
+    match vertical {
+        core::alignment::Vertical::Top => iced::alignment::Vertical::Top,
+        core::alignment::Vertical::Center => iced::alignment::Vertical::Center,
+        core::alignment::Vertical::Bottom => iced::alignment::Vertical::Bottom,
+    }
+}
@@ -0,0 +1,91 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::{DefaultHasher, Hash, Hasher},
+};
+
+use iced::advanced::{self, graphics::core::Element, widget::Tree};
+
+use crate::grid::Grid;
+
+/// An element with a key, created with [`keyed`].
+///
+/// When given to a container of this crate (see [`Grid::push_keyed_row`], [`keyed_column`] and
+/// [`reorderable_list`](crate::reorderable_list::reorderable_list)),
+/// the state of the element (scroll offset, text cursor, ...) follows its key when the
+/// children are reordered, instead of staying at the same position.
+pub struct Keyed<'a, Message, Theme, Renderer> {
+    pub(crate) key: u64,
+    pub(crate) element: Element<'a, Message, Theme, Renderer>,
+}
+
+/// Associates a key to an element.
+///
+/// The keys of the children of a container should be unique.
+pub fn keyed<'a, Message, Theme, Renderer>(
+    key: impl Hash,
+    element: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Keyed<'a, Message, Theme, Renderer> {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+
+    Keyed {
+        key: hasher.finish(),
+        element: element.into(),
+    }
+}
+
+/// Builds a single column [`Grid`] of keyed elements.
+pub fn keyed_column<'a, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = Keyed<'a, Message, Theme, Renderer>>,
+) -> Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: advanced::Renderer,
+{
+    children
+        .into_iter()
+        .fold(Grid::new(), |grid, child| grid.push_keyed_row([child]))
+}
+
+/// Diffs the children of a container, matching the trees with the keys of the children.
+///
+/// Children without keys are matched in order with the previous children without keys.
+/// `previous` holds the keys of the children during the last diff, kept in the state of the
+/// container, and is updated.
+pub(crate) fn diff_keyed_children<Message, Theme, Renderer>(
+    trees: &mut Vec<Tree>,
+    previous: &mut Vec<Option<u64>>,
+    children: &[&Element<'_, Message, Theme, Renderer>],
+    keys: &[Option<u64>],
+) where
+    Renderer: advanced::Renderer,
+{
+    let mut keyed = HashMap::new();
+    let mut unkeyed = VecDeque::new();
+
+    for (old, key) in trees.drain(..).zip(previous.drain(..)) {
+        match key {
+            Some(key) => {
+                keyed.insert(key, old);
+            }
+            None => unkeyed.push_back(old),
+        }
+    }
+
+    for (child, &key) in children.iter().zip(keys) {
+        let old = match key {
+            Some(key) => keyed.remove(&key),
+            None => unkeyed.pop_front(),
+        };
+
+        let tree_child = match old {
+            Some(mut old) => {
+                old.diff(child.as_widget());
+                old
+            }
+            None => Tree::new(child.as_widget()),
+        };
+
+        trees.push(tree_child);
+        previous.push(key);
+    }
+}
@@ -0,0 +1,104 @@
+use std::{fmt::Display, ops::Deref, str::FromStr};
+
+use iced::Length;
+
+/// A [`Length`] that parses from and displays as text, which allows to use it in a
+/// [`ParsedInput`](crate::parsed_input::ParsedInput).
+///
+/// The text representations are `fill`, `fill:<portion>`, `shrink` and `<pixels>px`
+/// (e.g. `fill:3` or `120px`). When parsing, the case is ignored, the `px` suffix is optional
+/// and the portion can also be written `fill(<portion>)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LengthSpec {
+    /// The inner length.
+    pub length: Length,
+}
+
+/// The error produced when parsing an invalid [`LengthSpec`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParseLengthError {
+    /// The string that could not be parsed.
+    pub string: String,
+}
+
+impl FromStr for LengthSpec {
+    type Err = ParseLengthError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseLengthError {
+            string: s.to_string(),
+        };
+
+        let lower = s.trim().to_lowercase();
+        let portion = lower
+            .strip_prefix("fill:")
+            .or_else(|| lower.strip_prefix("fill(")?.strip_suffix(')'));
+
+        let length = match lower.as_str() {
+            "fill" => Length::Fill,
+            "shrink" => Length::Shrink,
+            _ => match portion {
+                Some(portion) => Length::FillPortion(portion.trim().parse().map_err(|_| err())?),
+                None => {
+                    let pixels = lower.strip_suffix("px").unwrap_or(&lower).trim();
+                    match pixels.parse::<f32>() {
+                        Ok(pixels) if pixels.is_finite() && pixels >= 0. => Length::Fixed(pixels),
+                        _ => return Err(err()),
+                    }
+                }
+            },
+        };
+
+        Ok(length.into())
+    }
+}
+
+impl Display for LengthSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.length {
+            Length::Fill => write!(f, "fill"),
+            Length::FillPortion(portion) => write!(f, "fill:{portion}"),
+            Length::Shrink => write!(f, "shrink"),
+            Length::Fixed(pixels) => write!(f, "{pixels}px"),
+        }
+    }
+}
+
+impl Display for ParseLengthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid length \"{}\", expected fill, fill:<portion>, shrink or <pixels>px",
+            self.string
+        )
+    }
+}
+
+impl std::error::Error for ParseLengthError {}
+
+impl Default for LengthSpec {
+    fn default() -> Self {
+        Length::Shrink.into()
+    }
+}
+
+impl From<Length> for LengthSpec {
+    fn from(value: Length) -> Self {
+        Self { length: value }
+    }
+}
+
+impl From<LengthSpec> for Length {
+    fn from(value: LengthSpec) -> Self {
+        value.length
+    }
+}
+
+impl Deref for LengthSpec {
+    type Target = Length;
+
+    fn deref(&self) -> &Self::Target {
+        &self.length
+    }
+}
@@ -0,0 +1,94 @@
+//! Serialization of the iced types held by the serializable types of the crate.
+//!
+//! iced does not implement serde, so the fields holding its types use this module with
+//! `#[serde(with = "crate::helpers::serde_iced")]`, which goes through a plain representation.
+
+use iced::{Color, Point, Vector};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::DeserializeOwned};
+
+/// A type serialized through a plain representation.
+pub(crate) trait Plain: Sized {
+    /// The plain representation.
+    type Repr: Serialize + DeserializeOwned;
+
+    /// Converts the value into its plain representation.
+    fn to_plain(&self) -> Self::Repr;
+
+    /// Converts back a plain representation.
+    fn from_plain(repr: Self::Repr) -> Self;
+}
+
+impl Plain for Point {
+    type Repr = [f32; 2];
+
+    fn to_plain(&self) -> Self::Repr {
+        [self.x, self.y]
+    }
+
+    fn from_plain([x, y]: Self::Repr) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl Plain for Vector {
+    type Repr = [f32; 2];
+
+    fn to_plain(&self) -> Self::Repr {
+        [self.x, self.y]
+    }
+
+    fn from_plain([x, y]: Self::Repr) -> Self {
+        Vector::new(x, y)
+    }
+}
+
+impl Plain for Color {
+    type Repr = [f32; 4];
+
+    fn to_plain(&self) -> Self::Repr {
+        [self.r, self.g, self.b, self.a]
+    }
+
+    fn from_plain([r, g, b, a]: Self::Repr) -> Self {
+        Color::from_rgba(r, g, b, a)
+    }
+}
+
+impl<T: Plain> Plain for Option<T> {
+    type Repr = Option<T::Repr>;
+
+    fn to_plain(&self) -> Self::Repr {
+        self.as_ref().map(T::to_plain)
+    }
+
+    fn from_plain(repr: Self::Repr) -> Self {
+        repr.map(T::from_plain)
+    }
+}
+
+impl<T: Plain> Plain for Vec<T> {
+    type Repr = Vec<T::Repr>;
+
+    fn to_plain(&self) -> Self::Repr {
+        self.iter().map(T::to_plain).collect()
+    }
+
+    fn from_plain(repr: Self::Repr) -> Self {
+        repr.into_iter().map(T::from_plain).collect()
+    }
+}
+
+/// Serializes a value through its plain representation.
+pub(crate) fn serialize<T: Plain, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    value.to_plain().serialize(serializer)
+}
+
+/// Deserializes a value from its plain representation.
+pub(crate) fn deserialize<'de, T: Plain, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    T::Repr::deserialize(deserializer).map(T::from_plain)
+}
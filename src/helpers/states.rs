@@ -0,0 +1,69 @@
+use iced::{Background, Color};
+
+use super::{BackgroundExt, darken, lighten, luminance};
+
+/// A value for each interaction state of a widget.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateVariants<T> {
+    /// The value when the widget is idle.
+    pub active: T,
+    /// The value when the widget is hovered.
+    pub hovered: T,
+    /// The value when the widget is pressed.
+    pub pressed: T,
+    /// The value when the widget is disabled.
+    pub disabled: T,
+}
+
+impl<T> StateVariants<T> {
+    /// Applies a function to all the variants.
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> StateVariants<U> {
+        StateVariants {
+            active: f(self.active),
+            hovered: f(self.hovered),
+            pressed: f(self.pressed),
+            disabled: f(self.disabled),
+        }
+    }
+}
+
+/// The change of lightness of a hovered widget.
+const HOVER: f32 = 0.05;
+/// The change of lightness of a pressed widget.
+const PRESS: f32 = 0.1;
+/// The factor applied to the alpha channel of a disabled widget.
+const DISABLED_ALPHA: f32 = 0.5;
+
+/// Derives the interaction state variants of a [`Color`].
+///
+/// Dark colors get lighter when hovered and pressed, and light colors get darker.
+/// Disabled colors are half transparent.
+pub fn color_states(base: Color) -> StateVariants<Color> {
+    let shift = if luminance(base) < 0.18 { lighten } else { darken };
+
+    StateVariants {
+        active: base,
+        hovered: shift(base, HOVER),
+        pressed: shift(base, PRESS),
+        disabled: Color {
+            a: base.a * DISABLED_ALPHA,
+            ..base
+        },
+    }
+}
+
+/// Derives the interaction state variants of a [`Background`].
+///
+/// Each color of a gradient is derived as in [`color_states`].
+pub fn background_states(base: Background) -> StateVariants<Background> {
+    match base {
+        Background::Color(color) => color_states(color).map(Background::Color),
+        Background::Gradient(_) => StateVariants {
+            active: base,
+            hovered: base.map_colors(|color| color_states(color).hovered),
+            pressed: base.map_colors(|color| color_states(color).pressed),
+            disabled: base.map_colors(|color| color_states(color).disabled),
+        },
+    }
+}
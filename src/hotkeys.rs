@@ -0,0 +1,571 @@
+//! Keyboard shortcuts declared next to the view, instead of in a subscription.
+//!
+//! A [`Keymap`] binds key [`Combo`]s like `"Mod+S"` to messages, and a [`Hotkeys`] wrapping the
+//! content of a window produces them when the combos are pressed. The combos are written with the
+//! names of [`Key::parse`], so that `Mod` is Command on macOS and Control elsewhere, and the same
+//! combos can be displayed with a [`KeyHint`].
+//!
+//! While a text field is focused, combos typing text are left to it: see [`InTextFields`].
+//! Two bindings of a [`Keymap`] with the same combo [conflict](Keymap::conflicts), and only the
+//! first one is used.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced::{Element, widget::{column, text, text_input}};
+//! use more_iced_aw::hotkeys::{Hotkeys, Keymap};
+//!
+//! #[derive(Default)]
+//! struct App {
+//!     name: String,
+//!     saved: usize,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Name(String),
+//!     Save,
+//!     Clear,
+//! }
+//!
+//! impl App {
+//!     fn update(&mut self, message: Message) {
+//!         match message {
+//!             Message::Name(name) => self.name = name,
+//!             Message::Save => self.saved += 1,
+//!             Message::Clear => self.name.clear(),
+//!         }
+//!     }
+//!
+//!     fn view(&self) -> Element<'_, Message> {
+//!         let keymap = Keymap::new()
+//!             .bind("Mod+S", Message::Save)
+//!             .bind("Mod+Shift+Backspace", Message::Clear);
+//!
+//!         Hotkeys::new(
+//!             column![
+//!                 text_input("Name", &self.name).on_input(Message::Name),
+//!                 text(format!("Saved {} times", self.saved)),
+//!             ],
+//!             keymap,
+//!         )
+//!         .into()
+//!     }
+//! }
+//!
+//! fn main() -> iced::Result {
+//!     iced::run("Hotkeys", App::update, App::view)
+//! }
+//! ```
+
+use iced::{
+    Element, Length, Rectangle, Size, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{
+            Id, Operation, Tree,
+            operation::{Focusable, TextInput},
+        },
+    },
+    event, keyboard,
+};
+
+use crate::key_hint::{Key, KeyHint, Platform};
+
+/// A key combination triggering a binding of a [`Keymap`].
+///
+/// It is made of modifiers and a single other key, like `"Mod+Shift+P"`. A combo with no other
+/// key, or with several, never matches.
+///
+/// ```
+/// use iced::keyboard::{Key, Modifiers};
+/// use more_iced_aw::{hotkeys::Combo, key_hint::Platform};
+///
+/// let combo = Combo::parse("Mod+Shift+P");
+/// let p = Key::Character("p".into());
+///
+/// assert!(combo.matches(Platform::Other, &p, Modifiers::CTRL | Modifiers::SHIFT));
+/// assert!(combo.matches(Platform::MacOs, &p, Modifiers::LOGO | Modifiers::SHIFT));
+/// assert!(!combo.matches(Platform::Other, &p, Modifiers::CTRL));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Combo {
+    keys: Vec<Key>,
+}
+
+impl Combo {
+    /// Creates a new [`Combo`] from its keys.
+    pub fn new(keys: impl IntoIterator<Item = Key>) -> Self {
+        Self {
+            keys: keys.into_iter().collect(),
+        }
+    }
+
+    /// Parses a [`Combo`] like `"Mod+Shift+P"`.
+    ///
+    /// See [`Key::parse`] for the names of the keys.
+    pub fn parse(combo: &str) -> Self {
+        Self::new(combo.split('+').map(Key::parse))
+    }
+
+    /// Returns the keys of the [`Combo`], as written.
+    pub fn keys(&self) -> &[Key] {
+        &self.keys
+    }
+
+    /// Returns the modifiers of the [`Combo`] on the given platform.
+    pub fn modifiers(&self, platform: Platform) -> keyboard::Modifiers {
+        self.keys
+            .iter()
+            .fold(keyboard::Modifiers::empty(), |modifiers, key| {
+                modifiers
+                    | match key {
+                        Key::Primary if platform == Platform::MacOs => keyboard::Modifiers::LOGO,
+                        Key::Primary | Key::Ctrl => keyboard::Modifiers::CTRL,
+                        Key::Shift => keyboard::Modifiers::SHIFT,
+                        Key::Alt => keyboard::Modifiers::ALT,
+                        Key::Super => keyboard::Modifiers::LOGO,
+                        _ => keyboard::Modifiers::empty(),
+                    }
+            })
+    }
+
+    /// Returns the key of the [`Combo`] which is not a modifier, if there is exactly one.
+    pub fn key(&self) -> Option<&Key> {
+        let mut keys = self.keys.iter().filter(|key| !is_modifier(key));
+
+        match (keys.next(), keys.next()) {
+            (Some(key), None) => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Indicates if a key pressed with some modifiers matches the [`Combo`] on the given platform.
+    ///
+    /// The modifiers must be exactly the ones of the combo.
+    pub fn matches(
+        &self,
+        platform: Platform,
+        key: &keyboard::Key,
+        modifiers: keyboard::Modifiers,
+    ) -> bool {
+        self.key().is_some_and(|own| is_same_key(own, key)) && self.modifiers(platform) == modifiers
+    }
+
+    /// Creates a [`KeyHint`] displaying the [`Combo`].
+    pub fn hint<'a>(&self) -> KeyHint<'a> {
+        KeyHint::new(self.keys.clone())
+    }
+
+    /// Indicates if two combos are pressed with the same keys on the given platform.
+    fn overlaps(&self, other: &Self, platform: Platform) -> bool {
+        self.modifiers(platform) == other.modifiers(platform)
+            && self
+                .key()
+                .zip(other.key())
+                .is_some_and(|(key, other)| match (key, other) {
+                    (Key::Other(key), Key::Other(other)) => key.eq_ignore_ascii_case(other),
+                    (key, other) => key == other,
+                })
+    }
+}
+
+impl From<&str> for Combo {
+    fn from(value: &str) -> Self {
+        Self::parse(value)
+    }
+}
+
+/// Indicates if a [`Key`] is a modifier.
+fn is_modifier(key: &Key) -> bool {
+    matches!(
+        key,
+        Key::Primary | Key::Ctrl | Key::Shift | Key::Alt | Key::Super
+    )
+}
+
+/// Indicates if a [`Key`] of a [`Combo`] is the pressed key.
+fn is_same_key(own: &Key, key: &keyboard::Key) -> bool {
+    use keyboard::key::Named;
+
+    match (own, key) {
+        (Key::Enter, keyboard::Key::Named(Named::Enter))
+        | (Key::Escape, keyboard::Key::Named(Named::Escape))
+        | (Key::Tab, keyboard::Key::Named(Named::Tab))
+        | (Key::Backspace, keyboard::Key::Named(Named::Backspace))
+        | (Key::Delete, keyboard::Key::Named(Named::Delete))
+        | (Key::Space, keyboard::Key::Named(Named::Space))
+        | (Key::Up, keyboard::Key::Named(Named::ArrowUp))
+        | (Key::Down, keyboard::Key::Named(Named::ArrowDown))
+        | (Key::Left, keyboard::Key::Named(Named::ArrowLeft))
+        | (Key::Right, keyboard::Key::Named(Named::ArrowRight)) => true,
+        (Key::Other(label), keyboard::Key::Character(character)) => {
+            label.to_lowercase() == character.to_lowercase()
+        }
+        (Key::Other(label), keyboard::Key::Named(named)) => {
+            label.eq_ignore_ascii_case(&format!("{named:?}"))
+        }
+        _ => false,
+    }
+}
+
+/// A [`Combo`] bound to a message in a [`Keymap`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Binding<Message> {
+    /// The combo triggering the binding.
+    pub combo: Combo,
+    /// The message produced by the binding.
+    pub message: Message,
+}
+
+/// Several bindings of a [`Keymap`] triggered by the same keys, found with
+/// [`Keymap::conflicts`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict {
+    /// The combo of the first of the bindings.
+    pub combo: Combo,
+    /// The indices of the bindings in the [`Keymap`], in order. Only the first one is used.
+    pub bindings: Vec<usize>,
+}
+
+/// Key [`Combo`]s bound to messages.
+///
+/// ```
+/// use more_iced_aw::{hotkeys::Keymap, key_hint::Platform};
+///
+/// let keymap = Keymap::new()
+///     .bind("Mod+S", "save")
+///     .bind("Ctrl+Shift+S", "save as")
+///     .bind("Ctrl+s", "sort");
+///
+/// let conflicts = keymap.conflicts(Platform::Other);
+/// assert_eq!(conflicts.len(), 1);
+/// assert_eq!(conflicts[0].bindings, vec![0, 2]);
+///
+/// assert!(keymap.conflicts(Platform::MacOs).is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keymap<Message> {
+    bindings: Vec<Binding<Message>>,
+}
+
+impl<Message> Keymap<Message> {
+    /// Creates a new empty [`Keymap`].
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Binds a [`Combo`] to a message.
+    pub fn bind(mut self, combo: impl Into<Combo>, message: Message) -> Self {
+        self.push(combo, message);
+        self
+    }
+
+    /// Binds a [`Combo`] to a message in place.
+    pub fn push(&mut self, combo: impl Into<Combo>, message: Message) {
+        self.bindings.push(Binding {
+            combo: combo.into(),
+            message,
+        });
+    }
+
+    /// Returns the bindings of the [`Keymap`], in order.
+    pub fn bindings(&self) -> &[Binding<Message>] {
+        &self.bindings
+    }
+
+    /// Returns the first binding matching a key pressed with some modifiers on the given platform.
+    pub fn find(
+        &self,
+        platform: Platform,
+        key: &keyboard::Key,
+        modifiers: keyboard::Modifiers,
+    ) -> Option<&Binding<Message>> {
+        self.bindings
+            .iter()
+            .find(|binding| binding.combo.matches(platform, key, modifiers))
+    }
+
+    /// Returns the groups of bindings triggered by the same keys on the given platform.
+    ///
+    /// `Mod+S` and `Ctrl+S` conflict everywhere but on macOS.
+    pub fn conflicts(&self, platform: Platform) -> Vec<Conflict> {
+        let mut conflicts: Vec<Conflict> = Vec::new();
+
+        for (i, binding) in self.bindings.iter().enumerate() {
+            if conflicts
+                .iter()
+                .any(|conflict| conflict.bindings.contains(&i))
+            {
+                continue;
+            }
+
+            let bindings: Vec<_> = (i..self.bindings.len())
+                .filter(|&j| self.bindings[j].combo.overlaps(&binding.combo, platform))
+                .collect();
+
+            if bindings.len() > 1 {
+                conflicts.push(Conflict {
+                    combo: binding.combo.clone(),
+                    bindings,
+                });
+            }
+        }
+
+        conflicts
+    }
+}
+
+impl<Message> Default for Keymap<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Which combos a [`Hotkeys`] handles while a text field of its content is focused.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InTextFields {
+    /// All the combos.
+    Always,
+    /// The combos with Control, Alt or Super, which do not type text.
+    #[default]
+    WithModifiers,
+    /// None: the keys all go to the text field.
+    Never,
+}
+
+/// A wrapper producing the messages of a [`Keymap`] when their combos are pressed.
+///
+/// Bound combos are handled before the content sees them.
+pub struct Hotkeys<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    keymap: Keymap<Message>,
+    platform: Platform,
+    in_text_fields: InTextFields,
+}
+
+impl<'a, Message, Theme, Renderer> Hotkeys<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Hotkeys`] handling the combos of the [`Keymap`] over the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        keymap: Keymap<Message>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            keymap,
+            platform: Platform::default(),
+            in_text_fields: InTextFields::default(),
+        }
+    }
+
+    /// Sets the [`Platform`] whose modifiers are used. By default, it is the current one.
+    pub fn platform(mut self, platform: Platform) -> Self {
+        self.platform = platform;
+        self
+    }
+
+    /// Sets which combos are handled while a text field is focused.
+    pub fn in_text_fields(mut self, in_text_fields: InTextFields) -> Self {
+        self.in_text_fields = in_text_fields;
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Hotkeys<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: iced::advanced::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let iced::Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) = &event
+            && let Some(binding) = self.keymap.find(self.platform, key, *modifiers)
+        {
+            let typing = match self.in_text_fields {
+                InTextFields::Always => false,
+                InTextFields::WithModifiers
+                    if modifiers.control() || modifiers.alt() || modifiers.logo() =>
+                {
+                    false
+                }
+                InTextFields::WithModifiers | InTextFields::Never => {
+                    let mut find = FocusedTextField::default();
+                    self.content.as_widget().operate(
+                        &mut tree.children[0],
+                        layout,
+                        renderer,
+                        &mut find,
+                    );
+                    find.found
+                }
+            };
+
+            if !typing {
+                shell.publish(binding.message.clone());
+                return event::Status::Captured;
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Hotkeys<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(value: Hotkeys<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`Hotkeys`] handling the combos of the [`Keymap`] over the given content.
+pub fn hotkeys<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    keymap: Keymap<Message>,
+) -> Hotkeys<'a, Message, Theme, Renderer> {
+    Hotkeys::new(content, keymap)
+}
+
+/// An operation looking for a focused text field.
+///
+/// Text fields report their state as focusable, then as text input.
+#[derive(Default)]
+struct FocusedTextField {
+    /// Whether the last focusable widget is focused.
+    focused: bool,
+    /// Whether a focused text field was found.
+    found: bool,
+}
+
+impl Operation for FocusedTextField {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        self.focused = state.is_focused();
+    }
+
+    fn text_input(&mut self, _state: &mut dyn TextInput, _id: Option<&Id>) {
+        self.found |= self.focused;
+        self.focused = false;
+    }
+}
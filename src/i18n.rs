@@ -0,0 +1,312 @@
+//! Internationalization: the direction of the layout and the formatting of numbers, times and dates.
+//!
+//! The [`Format`] of the current thread is set once with [`set_format`], usually at the start of
+//! the application. The widgets read it when they need it, not when it is set:
+//! - the [`direction`] is the default one of the [`Grid`](crate::grid::Grid), the
+//!   [`RangeSlider`](crate::range_slider::RangeSlider) and the
+//!   [`RichEditor`](crate::rich_editor::RichEditor), which mirror their layout from right to left.
+//!   Each of them takes a direction with its `direction` builder, and reads the one of the thread
+//!   when it is laid out or drawn otherwise.
+//! - the numbers of the [`Fixed`](crate::parsed_input::Fixed),
+//!   [`Scientific`](crate::parsed_input::Scientific) and [`Money`](crate::parsed_input::Money)
+//!   presets of the parsed inputs are displayed and parsed with [`Format::format_number`] and
+//!   [`Format::parse_number`].
+//! - the [`decimal`](crate::parsed_input::decimal) filter and the
+//!   [`currency_input`](crate::parsed_input::currency_input) read the separators when they are
+//!   created, in `view`. [`decimal_for`](crate::parsed_input::decimal_for) and
+//!   [`currency_input_for`](crate::parsed_input::currency_input_for) take a format instead.
+//! - the times of the [`Scheduler`](crate::scheduler::Scheduler) are displayed with
+//!   [`Format::format_time`].
+//!
+//! [`Locale`] implements [`Format`] from a few conventions, and a custom [`Format`] can
+//! override any of its methods. The default format is [`Locale::DEFAULT`], which keeps the
+//! conventions of Rust: left to right, `.` as decimal separator, no grouping, a 24-hour clock and
+//! ISO 8601 dates.
+//!
+//! ```
+//! use more_iced_aw::i18n::{self, Direction, Locale};
+//! use more_iced_aw::parsed_input::Fixed;
+//!
+//! i18n::set_format(Locale::FR_FR);
+//!
+//! let value: Fixed<f64, 2> = "1 234,5".parse().unwrap();
+//! assert_eq!(value.value, 1234.5);
+//! assert_eq!(value.to_string(), "1\u{202F}234,50");
+//!
+//! i18n::set_format(Locale {
+//!     direction: Direction::RightToLeft,
+//!     ..Locale::DEFAULT
+//! });
+//! assert!(i18n::direction().is_rtl());
+//! ```
+
+use std::{borrow::Cow, cell::RefCell, rc::Rc};
+
+/// The direction of a layout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    /// From left to right, like English.
+    #[default]
+    LeftToRight,
+    /// From right to left, like Arabic or Hebrew. Layouts are mirrored.
+    RightToLeft,
+}
+
+impl Direction {
+    /// Returns whether the direction is [`RightToLeft`](Direction::RightToLeft).
+    pub fn is_rtl(self) -> bool {
+        self == Direction::RightToLeft
+    }
+
+    /// Mirrors the position `x` of something of the given `width`, relative to a container of
+    /// width `container`, if the direction is right to left.
+    pub(crate) fn mirror(self, x: f32, width: f32, container: f32) -> f32 {
+        match self {
+            Direction::LeftToRight => x,
+            Direction::RightToLeft => container - x - width,
+        }
+    }
+}
+
+/// The clock used to display times.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Clock {
+    /// From `00:00` to `23:59`.
+    #[default]
+    H24,
+    /// From `12:00 AM` to `11:59 PM`.
+    H12,
+}
+
+/// The order of the parts of a date.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DateOrder {
+    /// Year, month, day (e.g. `2024-03-15`).
+    #[default]
+    YearMonthDay,
+    /// Day, month, year (e.g. `15/03/2024`).
+    DayMonthYear,
+    /// Month, day, year (e.g. `03/15/2024`).
+    MonthDayYear,
+}
+
+/// The localized formatting used by the widgets.
+///
+/// All the methods have a default implementation derived from [`direction`](Format::direction),
+/// [`decimal_separator`](Format::decimal_separator) and
+/// [`group_separator`](Format::group_separator), so a custom format only overrides what differs.
+pub trait Format {
+    /// The direction of the layouts.
+    fn direction(&self) -> Direction {
+        Direction::LeftToRight
+    }
+
+    /// The character separating the integer part of a number from its fraction.
+    fn decimal_separator(&self) -> char {
+        '.'
+    }
+
+    /// The character separating the groups of three digits of the integer part of a number, if any.
+    fn group_separator(&self) -> Option<char> {
+        None
+    }
+
+    /// Localizes a number formatted by Rust (e.g. `-1234.5` or `1.5e-3`).
+    ///
+    /// The first run of digits is the integer part, so prefixes like signs or currency symbols
+    /// are kept.
+    fn format_number(&self, number: &str) -> String {
+        let start = number
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(number.len());
+        let (prefix, rest) = number.split_at(start);
+        let end = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let (integer, suffix) = rest.split_at(end);
+
+        let mut formatted = String::from(prefix);
+        for (i, digit) in integer.chars().enumerate() {
+            if let Some(separator) = self.group_separator()
+                && i != 0
+                && (integer.len() - i) % 3 == 0
+            {
+                formatted.push(separator);
+            }
+            formatted.push(digit);
+        }
+
+        match suffix.strip_prefix('.') {
+            Some(fraction) => {
+                formatted.push(self.decimal_separator());
+                formatted.push_str(fraction);
+            }
+            None => formatted.push_str(suffix),
+        }
+
+        formatted
+    }
+
+    /// Converts a localized number back to the notation of Rust, to be parsed.
+    ///
+    /// The group separators are removed, as well as spaces when grouping, since they are often
+    /// typed instead of the narrow spaces used by some locales.
+    fn parse_number<'s>(&self, text: &'s str) -> Cow<'s, str> {
+        let decimal = self.decimal_separator();
+        let group = self.group_separator();
+
+        if decimal == '.' && group.is_none() {
+            return Cow::Borrowed(text);
+        }
+
+        Cow::Owned(
+            text.chars()
+                .filter(|c| {
+                    group.is_none_or(|group| {
+                        *c != group && !(group.is_whitespace() && c.is_whitespace())
+                    })
+                })
+                .map(|c| if c == decimal { '.' } else { c })
+                .collect(),
+        )
+    }
+
+    /// Formats a time of the day, given in minutes from midnight.
+    fn format_time(&self, minutes: u32) -> String {
+        format!("{:02}:{:02}", minutes / 60 % 24, minutes % 60)
+    }
+
+    /// Formats a date, with the `month` and the `day` starting at `1`.
+    fn format_date(&self, year: i32, month: u32, day: u32) -> String {
+        format!("{year:04}-{month:02}-{day:02}")
+    }
+}
+
+/// A [`Format`] made of common conventions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Locale {
+    /// The direction of the layouts.
+    pub direction: Direction,
+    /// The character separating the integer part of a number from its fraction.
+    pub decimal_separator: char,
+    /// The character separating the groups of three digits of a number, if any.
+    pub group_separator: Option<char>,
+    /// The clock of the times.
+    pub clock: Clock,
+    /// The order of the parts of the dates.
+    pub date_order: DateOrder,
+    /// The character separating the parts of the dates.
+    pub date_separator: char,
+}
+
+impl Locale {
+    /// The conventions of Rust, used by default.
+    pub const DEFAULT: Self = Self {
+        direction: Direction::LeftToRight,
+        decimal_separator: '.',
+        group_separator: None,
+        clock: Clock::H24,
+        date_order: DateOrder::YearMonthDay,
+        date_separator: '-',
+    };
+
+    /// American English: `1,234.5`, `1:30 PM` and `03/15/2024`.
+    pub const EN_US: Self = Self {
+        direction: Direction::LeftToRight,
+        decimal_separator: '.',
+        group_separator: Some(','),
+        clock: Clock::H12,
+        date_order: DateOrder::MonthDayYear,
+        date_separator: '/',
+    };
+
+    /// French: `1 234,5`, `13:30` and `15/03/2024`.
+    pub const FR_FR: Self = Self {
+        direction: Direction::LeftToRight,
+        decimal_separator: ',',
+        group_separator: Some('\u{202F}'),
+        clock: Clock::H24,
+        date_order: DateOrder::DayMonthYear,
+        date_separator: '/',
+    };
+
+    /// German: `1.234,5`, `13:30` and `15.03.2024`.
+    pub const DE_DE: Self = Self {
+        direction: Direction::LeftToRight,
+        decimal_separator: ',',
+        group_separator: Some('.'),
+        clock: Clock::H24,
+        date_order: DateOrder::DayMonthYear,
+        date_separator: '.',
+    };
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Format for Locale {
+    fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    fn decimal_separator(&self) -> char {
+        self.decimal_separator
+    }
+
+    fn group_separator(&self) -> Option<char> {
+        self.group_separator
+    }
+
+    fn format_time(&self, minutes: u32) -> String {
+        let (hours, minutes) = (minutes / 60 % 24, minutes % 60);
+
+        match self.clock {
+            Clock::H24 => format!("{hours:02}:{minutes:02}"),
+            Clock::H12 => {
+                let suffix = if hours < 12 { "AM" } else { "PM" };
+                let hours = if hours % 12 == 0 { 12 } else { hours % 12 };
+                format!("{hours}:{minutes:02} {suffix}")
+            }
+        }
+    }
+
+    fn format_date(&self, year: i32, month: u32, day: u32) -> String {
+        let separator = self.date_separator;
+
+        match self.date_order {
+            DateOrder::YearMonthDay => format!("{year:04}{separator}{month:02}{separator}{day:02}"),
+            DateOrder::DayMonthYear => format!("{day:02}{separator}{month:02}{separator}{year:04}"),
+            DateOrder::MonthDayYear => format!("{month:02}{separator}{day:02}{separator}{year:04}"),
+        }
+    }
+}
+
+thread_local! {
+    /// The format of the current thread.
+    static FORMAT: RefCell<Rc<dyn Format>> = RefCell::new(Rc::new(Locale::DEFAULT));
+}
+
+/// Sets the [`Format`] of the current thread.
+///
+/// The widgets read it when they are laid out, drawn or parse a value, so it also applies to the
+/// widgets already created.
+pub fn set_format(format: impl Format + 'static) {
+    FORMAT.set(Rc::new(format));
+}
+
+/// Returns the [`Format`] of the current thread.
+pub fn format() -> Rc<dyn Format> {
+    FORMAT.with_borrow(Rc::clone)
+}
+
+/// Returns the [`Direction`] of the [`Format`] of the current thread.
+pub fn direction() -> Direction {
+    FORMAT.with_borrow(|format| format.direction())
+}
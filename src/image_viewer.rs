@@ -0,0 +1,367 @@
+//! An image viewer with pan and zoom.
+//!
+//! The image is zoomed by scrolling, around the cursor, and panned by dragging it. It starts
+//! with the zoom given by its [`Mode`], and always stays in view: an image smaller than the viewer
+//! is centered, and the edges of a larger one can't be dragged inside of the viewer.
+//!
+//! The renderer must support images, which needs the `image` feature.
+
+use iced::{
+    Element, Length, Point, Radians, Rectangle, Size, Vector,
+    advanced::{
+        Layout, Shell, Widget,
+        image::{self, FilterMethod, Image},
+        layout::{Limits, Node},
+        mouse, renderer,
+        widget::{Tree, tree},
+    },
+    event, touch,
+};
+
+/// The initial zoom of an [`ImageViewer`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Mode {
+    /// The whole image is visible.
+    #[default]
+    Fit,
+    /// The image covers the whole viewer.
+    Fill,
+    /// One pixel of the image is one pixel of the screen.
+    Original,
+}
+
+impl Mode {
+    /// Returns the zoom of an image of the given size in the given bounds.
+    pub fn zoom(self, image: Size, bounds: Size) -> f32 {
+        let horizontal = bounds.width / image.width.max(1.);
+        let vertical = bounds.height / image.height.max(1.);
+
+        match self {
+            Mode::Fit => horizontal.min(vertical),
+            Mode::Fill => horizontal.max(vertical),
+            Mode::Original => 1.,
+        }
+    }
+}
+
+/// The state of an [`ImageViewer`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    /// The zoom chosen by the user, or `None` to use the one of the mode.
+    zoom: Option<f32>,
+    /// The offset of the center of the image from the center of the viewer.
+    offset: Vector,
+    /// The cursor position and the offset when the drag started.
+    drag: Option<(Point, Vector)>,
+    /// The mode when the zoom was last computed.
+    mode: Mode,
+}
+
+/// An image that can be zoomed and panned, created with [`image_viewer`].
+pub struct ImageViewer<'a, Message, Handle = image::Handle> {
+    handle: Handle,
+    mode: Mode,
+    min_zoom: f32,
+    max_zoom: f32,
+    zoom_step: f32,
+    on_zoom: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    filter_method: FilterMethod,
+    width: Length,
+    height: Length,
+}
+
+impl<'a, Message, Handle> ImageViewer<'a, Message, Handle> {
+    /// Creates a new [`ImageViewer`] displaying the image with the given handle.
+    pub fn new(handle: impl Into<Handle>) -> Self {
+        Self {
+            handle: handle.into(),
+            mode: Mode::default(),
+            min_zoom: 0.1,
+            max_zoom: 10.,
+            zoom_step: 0.1,
+            on_zoom: None,
+            filter_method: FilterMethod::default(),
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+
+    /// Sets the [`Mode`] of the [`ImageViewer`].
+    ///
+    /// Changing the mode resets the zoom and the position of the image.
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the minimal zoom of the [`ImageViewer`]. Defaults to `0.1`.
+    pub fn min_zoom(mut self, min_zoom: f32) -> Self {
+        self.min_zoom = min_zoom;
+        self
+    }
+
+    /// Sets the maximal zoom of the [`ImageViewer`]. Defaults to `10`.
+    pub fn max_zoom(mut self, max_zoom: f32) -> Self {
+        self.max_zoom = max_zoom;
+        self
+    }
+
+    /// Sets the relative change of zoom for each scrolled line. Defaults to `0.1`.
+    pub fn zoom_step(mut self, zoom_step: f32) -> Self {
+        self.zoom_step = zoom_step;
+        self
+    }
+
+    /// Sets the message produced when the user zooms, with the new zoom.
+    ///
+    /// A zoom of `1` displays one pixel of the image on one pixel of the screen.
+    pub fn on_zoom(mut self, on_zoom: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_zoom = Some(Box::new(on_zoom));
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the [`ImageViewer`].
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets the width of the [`ImageViewer`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`ImageViewer`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Returns the size of the image.
+    fn image_size<Renderer>(&self, renderer: &Renderer) -> Size
+    where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        let Size { width, height } = renderer.measure_image(&self.handle);
+        Size::new(width as f32, height as f32)
+    }
+
+    /// Returns the current zoom.
+    fn zoom(&self, state: &State, image: Size, bounds: Rectangle) -> f32 {
+        let zoom = match state.zoom {
+            Some(zoom) if state.mode == self.mode => zoom,
+            _ => self.mode.zoom(image, bounds.size()),
+        };
+
+        zoom.clamp(self.min_zoom, self.max_zoom.max(self.min_zoom))
+    }
+
+    /// Returns the offset clamped so that the image stays in view.
+    fn clamp(offset: Vector, image: Size, bounds: Rectangle) -> Vector {
+        let x = ((image.width - bounds.width) / 2.).max(0.);
+        let y = ((image.height - bounds.height) / 2.).max(0.);
+
+        Vector::new(offset.x.clamp(-x, x), offset.y.clamp(-y, y))
+    }
+
+    /// Returns the bounds of the drawn image.
+    fn image_bounds(&self, state: &State, image: Size, bounds: Rectangle) -> Rectangle {
+        let size = image * self.zoom(state, image, bounds);
+        let offset = if state.mode == self.mode {
+            Self::clamp(state.offset, size, bounds)
+        } else {
+            Vector::ZERO
+        };
+        let center = bounds.center() + offset;
+
+        Rectangle::new(
+            Point::new(center.x - size.width / 2., center.y - size.height / 2.),
+            size,
+        )
+    }
+}
+
+impl<Message, Theme, Renderer, Handle> Widget<Message, Theme, Renderer>
+    for ImageViewer<'_, Message, Handle>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            mode: self.mode,
+            ..State::default()
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, _tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.resolve(self.width, self.height, self.image_size(renderer)))
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let image = self.image_bounds(state, self.image_size(renderer), bounds);
+
+        renderer.with_layer(clip, |renderer| {
+            renderer.draw_image(
+                Image {
+                    handle: self.handle.clone(),
+                    filter_method: self.filter_method,
+                    rotation: Radians(0.),
+                    opacity: 1.,
+                    snap: true,
+                },
+                image,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn iced::advanced::Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let image = self.image_size(renderer);
+
+        if state.mode != self.mode {
+            *state = State {
+                mode: self.mode,
+                ..State::default()
+            };
+        }
+
+        match event {
+            iced::Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / 60.,
+                };
+
+                let zoom = self.zoom(state, image, bounds);
+                let new_zoom = (zoom * (1. + self.zoom_step).powf(lines))
+                    .clamp(self.min_zoom, self.max_zoom.max(self.min_zoom));
+                if new_zoom == zoom {
+                    return event::Status::Captured;
+                }
+
+                // Keeps the point of the image under the cursor in place.
+                let offset = Self::clamp(state.offset, image * zoom, bounds);
+                let cursor = position - bounds.center();
+                let offset = cursor - (cursor - offset) * (new_zoom / zoom);
+
+                state.zoom = Some(new_zoom);
+                state.offset = Self::clamp(offset, image * new_zoom, bounds);
+
+                if let Some(on_zoom) = &self.on_zoom {
+                    shell.publish(on_zoom(new_zoom));
+                }
+                shell.request_redraw(iced::window::RedrawRequest::NextFrame);
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+                let size = image * self.zoom(state, image, bounds);
+
+                state.drag = Some((position, Self::clamp(state.offset, size, bounds)));
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::CursorMoved { position })
+            | iced::Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some((origin, offset)) = state.drag else {
+                    return event::Status::Ignored;
+                };
+                let size = image * self.zoom(state, image, bounds);
+
+                state.offset = Self::clamp(offset + (position - origin), size, bounds);
+                shell.request_redraw(iced::window::RedrawRequest::NextFrame);
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerLifted { .. })
+            | iced::Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.drag.take().is_some() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer, Handle> From<ImageViewer<'a, Message, Handle>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: image::Renderer<Handle = Handle> + 'a,
+    Handle: Clone + 'a,
+{
+    fn from(value: ImageViewer<'a, Message, Handle>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`ImageViewer`] displaying the image with the given handle.
+pub fn image_viewer<'a, Message, Handle>(
+    handle: impl Into<Handle>,
+) -> ImageViewer<'a, Message, Handle> {
+    ImageViewer::new(handle)
+}
@@ -0,0 +1,345 @@
+//! A scrollable list that asks for more items when scrolled near its end.
+//!
+//! This is meant for paginated feeds: the application appends the next page to the content when
+//! it receives the message set with [`InfiniteScroll::on_reach_end`], while a loading footer is shown.
+
+use iced::{
+    Element, Length, Pixels, Rectangle, Size, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{Id, Operation, Tree, operation, tree},
+    },
+    event,
+    widget::{Column, container, scrollable, text},
+};
+
+/// A scrollable list emitting a message when scrolled within a threshold of its end,
+/// created with [`infinite_scroll`].
+///
+/// The message is emitted once each time the end is reached, and again only after the
+/// content grew or was scrolled away from the end. No message is emitted while loading.
+pub struct InfiniteScroll<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    footer: Option<Element<'a, Message, Theme, Renderer>>,
+    on_reach_end: Option<Message>,
+    loading: bool,
+    threshold: f32,
+    width: Length,
+    height: Length,
+    spacing: f32,
+}
+
+impl<'a, Message, Theme, Renderer> InfiniteScroll<'a, Message, Theme, Renderer> {
+    /// Creates a new [`InfiniteScroll`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            footer: None,
+            on_reach_end: None,
+            loading: false,
+            threshold: 200.,
+            width: Length::Fill,
+            height: Length::Fill,
+            spacing: 0.,
+        }
+    }
+
+    /// Sets the message produced when the list is scrolled near its end.
+    pub fn on_reach_end(mut self, on_reach_end: Message) -> Self {
+        self.on_reach_end = Some(on_reach_end);
+        self
+    }
+
+    /// Sets whether more items are being loaded, which shows the loading footer.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Sets the footer shown at the end of the list while loading.
+    ///
+    /// By default, a centered "Loading…" text is shown.
+    pub fn loading_footer(
+        mut self,
+        footer: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
+    /// Sets the distance from the end of the list under which more items are requested.
+    pub fn threshold(mut self, threshold: impl Into<Pixels>) -> Self {
+        self.threshold = threshold.into().0;
+        self
+    }
+
+    /// Sets the width of the [`InfiniteScroll`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`InfiniteScroll`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the spacing between the content and the loading footer.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+}
+
+/// The state of the [`Watcher`] of an [`InfiniteScroll`].
+#[derive(Debug, Clone, Copy)]
+struct State {
+    /// Whether the message can be emitted the next time the end is reached.
+    armed: bool,
+    /// The height of the content when the end was last reached.
+    content_height: f32,
+}
+
+/// Wraps the scrollable of an [`InfiniteScroll`] and watches its offset.
+struct Watcher<'a, Message, Theme, Renderer> {
+    scrollable: Element<'a, Message, Theme, Renderer>,
+    on_reach_end: Option<Message>,
+    loading: bool,
+    threshold: f32,
+}
+
+/// An [`Operation`] reading the bounds and translation of the outermost scrollable.
+#[derive(Default)]
+struct Measure {
+    measure: Option<(Rectangle, Rectangle, Vector)>,
+}
+
+impl Operation for Measure {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        _operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn operation::Scrollable,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.measure
+            .get_or_insert((bounds, content_bounds, translation));
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Watcher<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            armed: true,
+            content_height: 0.,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.scrollable)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.scrollable]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.scrollable.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.scrollable.as_widget().size_hint()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let node = self
+            .scrollable
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        Node::with_children(node.size(), vec![node])
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.scrollable.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.scrollable.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let layout = layout.children().next().unwrap();
+
+        let status = self.scrollable.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let mut measure = Measure::default();
+        self.scrollable
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, &mut measure);
+
+        if let Some((bounds, content_bounds, translation)) = measure.measure {
+            let state = tree.state.downcast_mut::<State>();
+            let remaining = content_bounds.height - translation.y - bounds.height;
+
+            if content_bounds.height != state.content_height {
+                state.armed = true;
+            }
+
+            if remaining > self.threshold {
+                state.armed = true;
+            } else if state.armed
+                && !self.loading
+                && let Some(on_reach_end) = self.on_reach_end.clone()
+            {
+                state.armed = false;
+                state.content_height = content_bounds.height;
+                shell.publish(on_reach_end);
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.scrollable.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.scrollable.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<InfiniteScroll<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: scrollable::Catalog + container::Catalog + text::Catalog + 'a,
+    Renderer: iced::advanced::text::Renderer + 'a,
+{
+    fn from(value: InfiniteScroll<'a, Message, Theme, Renderer>) -> Self {
+        let mut column = Column::new()
+            .push(value.content)
+            .spacing(value.spacing)
+            .width(Length::Fill);
+
+        if value.loading {
+            column = column.push(value.footer.unwrap_or_else(|| {
+                container(text("Loading…"))
+                    .center_x(Length::Fill)
+                    .padding(8)
+                    .into()
+            }));
+        }
+
+        let scrollable = scrollable::Scrollable::new(column)
+            .width(value.width)
+            .height(value.height);
+
+        Element::new(Watcher {
+            scrollable: scrollable.into(),
+            on_reach_end: value.on_reach_end,
+            loading: value.loading,
+            threshold: value.threshold,
+        })
+    }
+}
+
+/// Creates a new [`InfiniteScroll`] with the given content.
+pub fn infinite_scroll<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> InfiniteScroll<'a, Message, Theme, Renderer> {
+    InfiniteScroll::new(content)
+}
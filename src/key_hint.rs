@@ -0,0 +1,302 @@
+//! A key combination displayed as key caps, for menus, tooltips and onboarding hints.
+//!
+//! The keys are displayed with the symbols of the platform: `Ctrl` `Shift` `P` on Windows and
+//! Linux becomes `⌘` `⇧` `P` on macOS when written with [`Key::Primary`].
+
+use std::rc::Rc;
+
+use iced::{
+    Background, Border, Color, Element, Font, Pixels, Shadow, Theme, Vector,
+    widget::{Row, container, text},
+};
+
+/// The platform whose conventions are used to display the keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Platform {
+    /// macOS, where modifiers are shown as symbols.
+    MacOs,
+    /// Windows, Linux and the others.
+    Other,
+}
+
+impl Platform {
+    /// Returns the platform the application is compiled for.
+    pub fn current() -> Self {
+        if cfg!(target_os = "macos") {
+            Self::MacOs
+        } else {
+            Self::Other
+        }
+    }
+}
+
+impl Default for Platform {
+    fn default() -> Self {
+        Self::current()
+    }
+}
+
+/// A key of a [`KeyHint`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Key {
+    /// The main modifier of the platform: Command on macOS, Control elsewhere.
+    Primary,
+    /// The Control key.
+    Ctrl,
+    /// The Shift key.
+    Shift,
+    /// The Alt key, Option on macOS.
+    Alt,
+    /// The Super key: Command on macOS, Windows on Windows.
+    Super,
+    /// The Enter key.
+    Enter,
+    /// The Escape key.
+    Escape,
+    /// The Tab key.
+    Tab,
+    /// The Backspace key.
+    Backspace,
+    /// The Delete key.
+    Delete,
+    /// The Space bar.
+    Space,
+    /// The up arrow.
+    Up,
+    /// The down arrow.
+    Down,
+    /// The left arrow.
+    Left,
+    /// The right arrow.
+    Right,
+    /// Any other key, displayed with the given label.
+    Other(String),
+}
+
+impl Key {
+    /// Parses a key from its name, ignoring case: `ctrl`, `cmd`, `shift`, `enter`, `up`...
+    ///
+    /// `mod` and `primary` give [`Key::Primary`]. Unknown names give [`Key::Other`], with single
+    /// letters in uppercase.
+    pub fn parse(name: &str) -> Self {
+        match name.trim().to_lowercase().as_str() {
+            "mod" | "primary" => Self::Primary,
+            "ctrl" | "control" => Self::Ctrl,
+            "shift" => Self::Shift,
+            "alt" | "option" | "opt" => Self::Alt,
+            "super" | "cmd" | "command" | "meta" | "win" | "logo" => Self::Super,
+            "enter" | "return" => Self::Enter,
+            "esc" | "escape" => Self::Escape,
+            "tab" => Self::Tab,
+            "backspace" => Self::Backspace,
+            "del" | "delete" => Self::Delete,
+            "space" => Self::Space,
+            "up" => Self::Up,
+            "down" => Self::Down,
+            "left" => Self::Left,
+            "right" => Self::Right,
+            _ if name.trim().chars().count() == 1 => Self::Other(name.trim().to_uppercase()),
+            _ => Self::Other(name.trim().to_owned()),
+        }
+    }
+
+    /// Returns the label of the key on the given platform.
+    pub fn label(&self, platform: Platform) -> String {
+        let mac = platform == Platform::MacOs;
+
+        let label = match (self, mac) {
+            (Self::Primary | Self::Super, true) => "⌘",
+            (Self::Primary | Self::Ctrl, false) => "Ctrl",
+            (Self::Ctrl, true) => "⌃",
+            (Self::Shift, true) => "⇧",
+            (Self::Shift, false) => "Shift",
+            (Self::Alt, true) => "⌥",
+            (Self::Alt, false) => "Alt",
+            (Self::Super, false) => "Super",
+            (Self::Enter, true) => "↩",
+            (Self::Enter, false) => "Enter",
+            (Self::Escape, true) => "⎋",
+            (Self::Escape, false) => "Esc",
+            (Self::Tab, true) => "⇥",
+            (Self::Tab, false) => "Tab",
+            (Self::Backspace, true) => "⌫",
+            (Self::Backspace, false) => "Backspace",
+            (Self::Delete, true) => "⌦",
+            (Self::Delete, false) => "Del",
+            (Self::Space, _) => "Space",
+            (Self::Up, _) => "↑",
+            (Self::Down, _) => "↓",
+            (Self::Left, _) => "←",
+            (Self::Right, _) => "→",
+            (Self::Other(label), _) => label,
+        };
+
+        label.to_owned()
+    }
+}
+
+/// The appearance of the key caps of a [`KeyHint`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of a key cap.
+    pub background: Background,
+    /// The color of the label of a key cap.
+    pub text_color: Color,
+    /// The border of a key cap.
+    pub border: Border,
+    /// The shadow of a key cap, giving it some depth.
+    pub shadow: Shadow,
+}
+
+/// The theme catalog of a [`KeyHint`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`KeyHint`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of the key caps of a [`KeyHint`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weak.color.into(),
+        text_color: palette.background.weak.text,
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.,
+            radius: 4.into(),
+        },
+        shadow: Shadow {
+            color: palette.background.strong.color,
+            offset: Vector::new(0., 1.),
+            blur_radius: 0.,
+        },
+    }
+}
+
+/// A key combination displayed as key caps, created with [`key_hint`].
+pub struct KeyHint<'a> {
+    keys: Vec<Key>,
+    platform: Platform,
+    text_size: Pixels,
+    font: Option<Font>,
+    spacing: f32,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a> KeyHint<'a> {
+    /// Creates a new [`KeyHint`] displaying the given keys.
+    pub fn new(keys: impl IntoIterator<Item = Key>) -> Self {
+        Self {
+            keys: keys.into_iter().collect(),
+            platform: Platform::default(),
+            text_size: Pixels(12.),
+            font: None,
+            spacing: 4.,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Creates a new [`KeyHint`] from a combination like `"Ctrl+Shift+P"`.
+    ///
+    /// See [`Key::parse`] for the names of the keys.
+    pub fn parse(combination: &str) -> Self {
+        Self::new(combination.split('+').map(Key::parse))
+    }
+
+    /// Sets the [`Platform`] whose conventions are used. By default, it is the current one.
+    pub fn platform(mut self, platform: Platform) -> Self {
+        self.platform = platform;
+        self
+    }
+
+    /// Sets the text size of the key caps.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = text_size.into();
+        self
+    }
+
+    /// Sets the font of the key caps.
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the spacing between the key caps.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the style of the key caps.
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self {
+        self.class = Box::new(style);
+        self
+    }
+
+    /// Sets the style class of the key caps.
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message: 'a> From<KeyHint<'a>> for Element<'a, Message> {
+    fn from(value: KeyHint<'a>) -> Self {
+        let padding = [value.text_size.0 * 0.15, value.text_size.0 * 0.5];
+        let class = Rc::new(value.class);
+
+        Row::with_children(value.keys.iter().map(|key| {
+            let mut label = text(key.label(value.platform)).size(value.text_size);
+            if let Some(font) = value.font {
+                label = label.font(font);
+            }
+
+            let class = class.clone();
+
+            container(label)
+                .padding(padding)
+                .style(move |theme: &Theme| {
+                    let cap = Catalog::style(theme, &class);
+
+                    container::Style {
+                        text_color: Some(cap.text_color),
+                        background: Some(cap.background),
+                        border: cap.border,
+                        shadow: cap.shadow,
+                    }
+                })
+                .into()
+        }))
+        .spacing(value.spacing)
+        .into()
+    }
+}
+
+/// Creates a new [`KeyHint`] from a combination like `"Ctrl+Shift+P"`.
+pub fn key_hint<'a>(combination: &str) -> KeyHint<'a> {
+    KeyHint::parse(combination)
+}
@@ -0,0 +1,609 @@
+//! A keypad, such as a phone dial pad or the keys of a calculator.
+//!
+//! The keys of a [`Keypad`] are laid out in a uniform [`Grid`]. Clicking or touching a key produces
+//! the message of [`Keypad::on_key`] with its label. Holding a key with an
+//! [alternate](Key::alternate) for a while produces the message with the alternate instead, like
+//! holding `0` to dial `+`.
+
+use std::time::{Duration, Instant};
+
+use iced::{
+    Background, Border, Color, Element, Length, Padding, Pixels, Rectangle, Size, Theme,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer, text as advanced_text,
+        widget::{Operation, Tree, tree},
+    },
+    alignment, event, touch,
+    widget::{column, container, text},
+    window,
+};
+
+use crate::{grid::Grid, helpers::background_states};
+
+/// A key of a [`Keypad`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Key {
+    /// The label of the key, given to [`Keypad::on_key`] when the key is pressed.
+    pub label: String,
+    /// The label given to [`Keypad::on_key`] when the key is held.
+    pub alternate: Option<String>,
+    /// Whether the key is an action, such as an operator, rather than a digit.
+    pub action: bool,
+}
+
+impl Key {
+    /// Creates a new [`Key`] with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            alternate: None,
+            action: false,
+        }
+    }
+
+    /// Creates a new action [`Key`] with the given label.
+    pub fn action(label: impl Into<String>) -> Self {
+        Self {
+            action: true,
+            ..Self::new(label)
+        }
+    }
+
+    /// Sets the alternate label of the [`Key`], produced when the key is held.
+    pub fn alternate(mut self, alternate: impl Into<String>) -> Self {
+        self.alternate = Some(alternate.into());
+        self
+    }
+}
+
+impl From<&str> for Key {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for Key {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+/// The possible status of a key of a [`Keypad`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The key can be pressed.
+    Active,
+    /// The key is being hovered.
+    Hovered,
+    /// The key is being pressed.
+    Pressed,
+}
+
+/// The appearance of a key of a [`Keypad`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the digit keys.
+    pub key: Background,
+    /// The background of the action keys.
+    pub action: Background,
+    /// The border of the keys.
+    pub border: Border,
+    /// The color of the labels of the keys.
+    pub text: Color,
+}
+
+/// The theme catalog of a [`Keypad`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Keypad`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Keypad`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let key = background_states(palette.background.weak.color.into());
+    let action = background_states(palette.background.strong.color.into());
+    let (key, action) = match status {
+        Status::Active => (key.active, action.active),
+        Status::Hovered => (key.hovered, action.hovered),
+        Status::Pressed => (key.pressed, action.pressed),
+    };
+
+    Style {
+        key,
+        action,
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.,
+            radius: 6.into(),
+        },
+        text: palette.background.base.text,
+    }
+}
+
+/// A key being pressed.
+#[derive(Debug, Clone, Copy)]
+struct Press {
+    /// The index of the key.
+    key: usize,
+    /// When the key was pressed, set on the first redraw after the press.
+    since: Option<Instant>,
+    /// Whether the alternate of the key was produced.
+    held: bool,
+}
+
+/// The state of a [`Keypad`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    press: Option<Press>,
+}
+
+/// A grid of keys, created with [`keypad`].
+pub struct Keypad<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    keys: Vec<Key>,
+    grid: Element<'a, Message, Theme, Renderer>,
+    on_key: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    long_press: Duration,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Keypad<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + container::Catalog + text::Catalog + 'a,
+    Renderer: advanced_text::Renderer + 'a,
+{
+    /// Creates a new [`Keypad`] with the given rows of keys.
+    pub fn new<K, R>(rows: impl IntoIterator<Item = R>) -> Self
+    where
+        K: Into<Key>,
+        R: IntoIterator<Item = K>,
+    {
+        Self::with_options(rows, Pixels(20.), 8., Padding::new(12.))
+    }
+
+    /// Creates the [`Keypad`] of a phone: the digits, `*` and `#`, holding `0` producing `+`.
+    pub fn phone() -> Self {
+        Self::new([
+            vec![Key::new("1"), Key::new("2"), Key::new("3")],
+            vec![Key::new("4"), Key::new("5"), Key::new("6")],
+            vec![Key::new("7"), Key::new("8"), Key::new("9")],
+            vec![
+                Key::action("*"),
+                Key::new("0").alternate("+"),
+                Key::action("#"),
+            ],
+        ])
+    }
+
+    /// Creates the [`Keypad`] of a calculator: the digits, the four operations, `.`, `=`, `C` and
+    /// `⌫`, holding `⌫` producing `C`.
+    pub fn calculator() -> Self {
+        Self::new([
+            vec![
+                Key::action("C"),
+                Key::action("⌫").alternate("C"),
+                Key::action("%"),
+                Key::action("÷"),
+            ],
+            vec![
+                Key::new("7"),
+                Key::new("8"),
+                Key::new("9"),
+                Key::action("×"),
+            ],
+            vec![
+                Key::new("4"),
+                Key::new("5"),
+                Key::new("6"),
+                Key::action("−"),
+            ],
+            vec![
+                Key::new("1"),
+                Key::new("2"),
+                Key::new("3"),
+                Key::action("+"),
+            ],
+            vec![
+                Key::new("±"),
+                Key::new("0"),
+                Key::new("."),
+                Key::action("="),
+            ],
+        ])
+    }
+
+    /// Creates a new [`Keypad`] with the given rows of keys, text size, spacing between the keys,
+    /// and padding of the labels in the keys.
+    pub fn with_options<K, R>(
+        rows: impl IntoIterator<Item = R>,
+        text_size: impl Into<Pixels>,
+        spacing: f32,
+        padding: impl Into<Padding>,
+    ) -> Self
+    where
+        K: Into<Key>,
+        R: IntoIterator<Item = K>,
+    {
+        let rows: Vec<Vec<Key>> = rows
+            .into_iter()
+            .map(|row| row.into_iter().map(Into::into).collect())
+            .collect();
+        let text_size = text_size.into();
+        let padding = padding.into();
+
+        let cell = |key: &Key| -> Element<'a, Message, Theme, Renderer> {
+            let label = text(key.label.clone()).size(text_size);
+            let alternate = key
+                .alternate
+                .clone()
+                .map(|alternate| text(alternate).size(text_size * 0.5));
+
+            container(
+                column![label]
+                    .push_maybe(alternate)
+                    .align_x(alignment::Horizontal::Center),
+            )
+            .padding(padding)
+            .align_x(alignment::Horizontal::Center)
+            .align_y(alignment::Vertical::Center)
+            .into()
+        };
+
+        let grid = Grid::new()
+            .uniform(true)
+            .column_spacing(spacing)
+            .row_spacing(spacing)
+            .extend(rows.iter().map(|row| row.iter().map(cell)));
+
+        Self {
+            keys: rows.into_iter().flatten().collect(),
+            grid: grid.into(),
+            on_key: None,
+            long_press: Duration::from_millis(500),
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets the message produced when a key is pressed, with its label or its alternate.
+    pub fn on_key(mut self, on_key: impl Fn(String) -> Message + 'a) -> Self {
+        self.on_key = Some(Box::new(on_key));
+        self
+    }
+
+    /// Sets how long a key has to be held to produce its alternate. Half a second by default.
+    pub fn long_press(mut self, long_press: Duration) -> Self {
+        self.long_press = long_press;
+        self
+    }
+
+    /// Sets the style of the [`Keypad`].
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Keypad`].
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Keypad<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Returns the index of the key under the cursor.
+    fn key_at(layout: Layout<'_>, cursor: mouse::Cursor) -> Option<usize> {
+        layout
+            .children()
+            .next()?
+            .children()
+            .position(|key| cursor.is_over(key.bounds()))
+    }
+
+    /// Starts pressing a key.
+    fn press(&self, state: &mut State, key: usize, shell: &mut Shell<'_, Message>) {
+        state.press = Some(Press {
+            key,
+            since: None,
+            held: false,
+        });
+
+        if self.keys[key].alternate.is_some() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+    }
+
+    /// Releases the pressed key, producing its label if it was not held.
+    fn release(&self, state: &mut State, over: Option<usize>, shell: &mut Shell<'_, Message>) {
+        let Some(press) = state.press.take() else {
+            return;
+        };
+
+        if !press.held
+            && over == Some(press.key)
+            && let Some(on_key) = &self.on_key
+        {
+            shell.publish(on_key(self.keys[press.key].label.clone()));
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Keypad<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.grid)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.grid]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.grid.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let grid = self
+            .grid
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        Node::with_children(grid.size(), vec![grid])
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(key) = Self::key_at(layout, cursor) else {
+                    return event::Status::Ignored;
+                };
+
+                self.press(state, key, shell);
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if state.press.is_none() {
+                    return event::Status::Ignored;
+                }
+
+                self.release(state, Self::key_at(layout, cursor), shell);
+                event::Status::Captured
+            }
+            iced::Event::Touch(touch::Event::FingerLost { .. }) => {
+                self.release(state, None, shell);
+                event::Status::Ignored
+            }
+            iced::Event::Window(window::Event::RedrawRequested(now)) => {
+                let Some(press) = &mut state.press else {
+                    return event::Status::Ignored;
+                };
+                let Some(alternate) = &self.keys[press.key].alternate else {
+                    return event::Status::Ignored;
+                };
+                if press.held {
+                    return event::Status::Ignored;
+                }
+
+                let since = *press.since.get_or_insert(now);
+                if now.duration_since(since) >= self.long_press {
+                    press.held = true;
+                    if let Some(on_key) = &self.on_key {
+                        shell.publish(on_key(alternate.clone()));
+                    }
+                } else {
+                    shell.request_redraw(window::RedrawRequest::At(since + self.long_press));
+                }
+                event::Status::Ignored
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let Some(grid) = layout.children().next() else {
+            return;
+        };
+
+        for (index, (key, bounds)) in self
+            .keys
+            .iter()
+            .zip(grid.children().map(|cell| cell.bounds()))
+            .enumerate()
+        {
+            let status = if state.press.is_some_and(|press| press.key == index) {
+                Status::Pressed
+            } else if cursor.is_over(bounds) && state.press.is_none() {
+                Status::Hovered
+            } else {
+                Status::Active
+            };
+            let appearance = theme.style(&self.class, status);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: appearance.border,
+                    ..Default::default()
+                },
+                if key.action {
+                    appearance.action
+                } else {
+                    appearance.key
+                },
+            );
+        }
+
+        // The labels are drawn together by the grid, with the color of the active keys.
+        let text_color = theme.style(&self.class, Status::Active).text;
+
+        self.grid.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            &renderer::Style { text_color },
+            grid,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(grid) = layout.children().next() {
+            self.grid
+                .as_widget()
+                .operate(&mut tree.children[0], grid, renderer, operation);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if Self::key_at(layout, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: iced::Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.grid.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next()?,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Keypad<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(value: Keypad<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`Keypad`] with the given rows of keys.
+///
+/// ```
+/// use more_iced_aw::keypad::{Key, Keypad, keypad};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Key(String),
+/// }
+///
+/// let dial: Keypad<'_, Message> = Keypad::phone().on_key(Message::Key);
+///
+/// let hex: Keypad<'_, Message> = keypad([
+///     ["C", "D", "E", "F"],
+///     ["8", "9", "A", "B"],
+///     ["4", "5", "6", "7"],
+///     ["0", "1", "2", "3"],
+/// ])
+/// .on_key(Message::Key);
+///
+/// let custom: Keypad<'_, Message> = keypad([[Key::new("OK").alternate("Cancel")]]);
+/// ```
+pub fn keypad<'a, K, R, Message, Theme, Renderer>(
+    rows: impl IntoIterator<Item = R>,
+) -> Keypad<'a, Message, Theme, Renderer>
+where
+    K: Into<Key>,
+    R: IntoIterator<Item = K>,
+    Message: 'a,
+    Theme: Catalog + container::Catalog + text::Catalog + 'a,
+    Renderer: advanced_text::Renderer + 'a,
+{
+    Keypad::new(rows)
+}
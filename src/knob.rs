@@ -0,0 +1,571 @@
+//! A rotary knob, drawn on a [`Canvas`].
+//!
+//! The value is changed by dragging the mouse vertically (or around the knob, see [`Interaction`]),
+//! by scrolling, or with the arrow keys when the knob is hovered.
+
+use std::{f32::consts::PI, ops::RangeInclusive};
+
+use iced::{
+    Color, Element, Length, Pixels, Point, Radians, Rectangle, Renderer, Theme, Vector, alignment,
+    keyboard,
+    mouse::{self, Cursor},
+    widget::canvas::{
+        self, Canvas, Frame, Geometry, LineCap, Path, Program, Stroke, Text, event,
+        path::{Arc, Builder},
+    },
+};
+use num_traits::FromPrimitive;
+
+use crate::helpers::color_states;
+
+/// The distance the mouse has to be dragged to go through the whole range of a [`Knob`].
+const DRAG_DISTANCE: f32 = 200.;
+/// The factor applied to drags and scrolls when Shift is held.
+const FINE_FACTOR: f64 = 0.1;
+/// The part of the range moved by one scroll line or one key press, when the [`Knob`] has no step.
+const SCROLL_FRACTION: f64 = 0.01;
+/// The distance (as a part of the range) under which a value snaps to a detent.
+const DETENT_SNAP: f64 = 0.02;
+/// The length of the ticks of the detents, outside the track.
+const TICK_LENGTH: f32 = 4.;
+
+/// How the mouse changes the value of a [`Knob`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Interaction {
+    /// Dragging up increases the value, dragging down decreases it.
+    #[default]
+    Vertical,
+    /// The value follows the angle of the cursor around the center of the knob.
+    Circular,
+}
+
+/// The possible status of a [`Knob`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Knob`] can be interacted with.
+    Active,
+    /// The [`Knob`] is being hovered.
+    Hovered,
+    /// The [`Knob`] is being dragged.
+    Dragged,
+}
+
+/// The appearance of a [`Knob`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color of the track, along the whole arc.
+    pub track: Color,
+    /// The color of the part of the track up to the value.
+    pub value: Color,
+    /// The width of the track.
+    pub track_width: f32,
+    /// The color of the body of the knob.
+    pub body: Color,
+    /// The color of the line indicating the value on the body.
+    pub indicator: Color,
+    /// The color of the ticks of the detents.
+    pub detent: Color,
+    /// The color of the value label.
+    pub text: Color,
+}
+
+/// The theme catalog of a [`Knob`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Knob`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Knob`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let states = color_states(palette.primary.base.color);
+    let value = match status {
+        Status::Active => states.active,
+        Status::Hovered => states.hovered,
+        Status::Dragged => states.pressed,
+    };
+
+    Style {
+        track: palette.background.strong.color,
+        value,
+        track_width: 4.,
+        body: palette.background.weak.color,
+        indicator: palette.background.weak.text,
+        detent: palette.background.strong.color,
+        text: palette.background.base.text,
+    }
+}
+
+/// A drag in progress.
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    /// The vertical position of the cursor when the drag started.
+    origin_y: f32,
+    /// The position of the value in the range when the drag started.
+    origin: f64,
+}
+
+/// The state of a [`Knob`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    dragging: Option<Drag>,
+    keyboard_modifiers: keyboard::Modifiers,
+}
+
+/// A rotary knob, that selects a value in a range.
+///
+/// The value is shown by an arc (270° by default, see [`Knob::arc`]), and can optionally
+/// snap to [detents](Knob::detents) and display a [label](Knob::label).
+pub struct Knob<'a, T, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    range: RangeInclusive<T>,
+    value: T,
+    step: Option<T>,
+    detents: Vec<T>,
+    on_change: Box<dyn Fn(T) -> Message + 'a>,
+    on_release: Option<Message>,
+    label: Option<Box<dyn Fn(T) -> String + 'a>>,
+    interaction: Interaction,
+    start_angle: Radians,
+    sweep: Radians,
+    size: f32,
+    text_size: Pixels,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, T, Message, Theme> Knob<'a, T, Message, Theme>
+where
+    T: Copy + Into<f64> + FromPrimitive + PartialOrd,
+    Theme: Catalog,
+{
+    /// The default size of a [`Knob`].
+    pub const DEFAULT_SIZE: f32 = 48.;
+
+    /// Creates a new [`Knob`].
+    ///
+    /// It expects:
+    ///   * the range of the possible values
+    ///   * the current value
+    ///   * a function that will be called with the new value when the knob is turned.
+    pub fn new(range: RangeInclusive<T>, value: T, on_change: impl Fn(T) -> Message + 'a) -> Self {
+        Self {
+            range,
+            value,
+            step: None,
+            detents: Vec::new(),
+            on_change: Box::new(on_change),
+            on_release: None,
+            label: None,
+            interaction: Interaction::default(),
+            start_angle: Radians(0.75 * PI),
+            sweep: Radians(1.5 * PI),
+            size: Self::DEFAULT_SIZE,
+            text_size: Pixels(12.),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the release message of the [`Knob`].
+    ///
+    /// It is produced when the knob is released after being dragged.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the step of the [`Knob`]. The value is always rounded to a multiple of the step.
+    pub fn step(mut self, step: impl Into<T>) -> Self {
+        self.step = Some(step.into());
+        self
+    }
+
+    /// Sets the detents of the [`Knob`].
+    ///
+    /// They are marked with a tick, and the value snaps to them when it gets close.
+    pub fn detents(mut self, detents: impl IntoIterator<Item = T>) -> Self {
+        self.detents = detents.into_iter().collect();
+        self
+    }
+
+    /// Sets the function used to display the value in the center of the [`Knob`].
+    pub fn label(mut self, label: impl Fn(T) -> String + 'a) -> Self {
+        self.label = Some(Box::new(label));
+        self
+    }
+
+    /// Sets how the mouse changes the value of the [`Knob`].
+    pub fn interaction(mut self, interaction: Interaction) -> Self {
+        self.interaction = interaction;
+        self
+    }
+
+    /// Sets the arc of the [`Knob`]: the angle of the minimum value, and the angle between the minimum
+    /// and the maximum values.
+    ///
+    /// Angles are clockwise from the right. The default arc starts at the bottom left and sweeps 270°.
+    pub fn arc(mut self, start: impl Into<Radians>, sweep: impl Into<Radians>) -> Self {
+        self.start_angle = start.into();
+        self.sweep = Radians(sweep.into().0.clamp(0., 2. * PI));
+        self
+    }
+
+    /// Sets the size (width and height) of the [`Knob`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the text size of the label of the [`Knob`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = text_size.into();
+        self
+    }
+
+    /// Sets the style of the [`Knob`].
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Knob`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the position of a value in the range, from 0 to 1.
+    fn fraction(&self, value: T) -> f64 {
+        let (start, end) = ((*self.range.start()).into(), (*self.range.end()).into());
+
+        if start >= end {
+            0.
+        } else {
+            ((value.into() - start) / (end - start)).clamp(0., 1.)
+        }
+    }
+
+    /// Returns the value at a position in the range, snapped to the detents and rounded to the step.
+    fn value_at(&self, fraction: f64) -> Option<T> {
+        let fraction = fraction.clamp(0., 1.);
+
+        if let Some(detent) = self
+            .detents
+            .iter()
+            .find(|detent| (self.fraction(**detent) - fraction).abs() < DETENT_SNAP)
+        {
+            return Some(*detent);
+        }
+
+        let (start, end) = ((*self.range.start()).into(), (*self.range.end()).into());
+        let value = start + fraction * (end - start);
+
+        let value = match self.step {
+            Some(step) if step.into() > 0. => {
+                let step = step.into();
+                (start + ((value - start) / step).round() * step).min(end)
+            }
+            _ => value,
+        };
+
+        T::from_f64(value)
+    }
+
+    /// Returns the angle of a position in the range.
+    fn angle(&self, fraction: f64) -> f32 {
+        self.start_angle.0 + self.sweep.0 * fraction as f32
+    }
+
+    /// Returns the position in the range that corresponds to the angle of the cursor.
+    fn fraction_at(&self, center: Point, cursor: Point) -> f64 {
+        let angle = (cursor.y - center.y).atan2(cursor.x - center.x);
+        let from_start = (angle - self.start_angle.0).rem_euclid(2. * PI);
+
+        if self.sweep.0 <= 0. {
+            0.
+        } else if from_start <= self.sweep.0 {
+            f64::from(from_start / self.sweep.0)
+        } else if from_start - self.sweep.0 < (2. * PI - self.sweep.0) / 2. {
+            1.
+        } else {
+            0.
+        }
+    }
+
+    /// Returns how much one scroll line or key press moves the value, as a part of the range.
+    fn increment(&self, state: &State) -> f64 {
+        let (start, end) = ((*self.range.start()).into(), (*self.range.end()).into());
+
+        let increment = match self.step {
+            Some(step) if end > start && step.into() > 0. => step.into() / (end - start),
+            _ => SCROLL_FRACTION,
+        };
+
+        if state.keyboard_modifiers.shift() && self.step.is_none() {
+            increment * FINE_FACTOR
+        } else {
+            increment
+        }
+    }
+
+    /// Produces the change message if `fraction` changes the value.
+    fn change(&self, fraction: f64) -> Option<Message> {
+        self.value_at(fraction)
+            .filter(|value| value.partial_cmp(&self.value) != Some(std::cmp::Ordering::Equal))
+            .map(&self.on_change)
+    }
+}
+
+impl<'a, T, Message, Theme> Program<Message, Theme> for Knob<'a, T, Message, Theme>
+where
+    T: Copy + Into<f64> + FromPrimitive + PartialOrd,
+    Message: Clone,
+    Theme: Catalog,
+{
+    type State = State;
+
+    fn update(
+        &self,
+        state: &mut State,
+        event: canvas::Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> (event::Status, Option<Message>) {
+        let center = bounds.center();
+
+        match event {
+            canvas::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return (event::Status::Ignored, None);
+                };
+
+                state.dragging = Some(Drag {
+                    origin_y: position.y,
+                    origin: self.fraction(self.value),
+                });
+
+                let message = match self.interaction {
+                    Interaction::Vertical => None,
+                    Interaction::Circular => self.change(self.fraction_at(center, position)),
+                };
+
+                (event::Status::Captured, message)
+            }
+            canvas::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if state.dragging.take().is_none() {
+                    return (event::Status::Ignored, None);
+                }
+
+                (event::Status::Captured, self.on_release.clone())
+            }
+            canvas::Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let Some(drag) = state.dragging else {
+                    return (event::Status::Ignored, None);
+                };
+
+                let fraction = match self.interaction {
+                    Interaction::Vertical => {
+                        let factor = if state.keyboard_modifiers.shift() {
+                            FINE_FACTOR
+                        } else {
+                            1.
+                        };
+
+                        drag.origin
+                            + f64::from((drag.origin_y - position.y) / DRAG_DISTANCE) * factor
+                    }
+                    Interaction::Circular => self.fraction_at(center, position),
+                };
+
+                (event::Status::Captured, self.change(fraction))
+            }
+            canvas::Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if !cursor.is_over(bounds) {
+                    return (event::Status::Ignored, None);
+                }
+
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y.signum(),
+                    mouse::ScrollDelta::Pixels { y, .. } => y.signum(),
+                };
+
+                let fraction = self.fraction(self.value) + f64::from(lines) * self.increment(state);
+                (event::Status::Captured, self.change(fraction))
+            }
+            canvas::Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) => {
+                if !cursor.is_over(bounds) {
+                    return (event::Status::Ignored, None);
+                }
+
+                let steps = match key {
+                    keyboard::key::Named::ArrowUp | keyboard::key::Named::ArrowRight => 1.,
+                    keyboard::key::Named::ArrowDown | keyboard::key::Named::ArrowLeft => -1.,
+                    _ => return (event::Status::Ignored, None),
+                };
+
+                let fraction = self.fraction(self.value) + steps * self.increment(state);
+                (event::Status::Captured, self.change(fraction))
+            }
+            canvas::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = modifiers;
+                (event::Status::Ignored, None)
+            }
+            _ => (event::Status::Ignored, None),
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let style = theme.style(
+            &self.class,
+            if state.dragging.is_some() {
+                Status::Dragged
+            } else if cursor.is_over(bounds) {
+                Status::Hovered
+            } else {
+                Status::Active
+            },
+        );
+
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        let center = frame.center();
+        let outer = bounds.width.min(bounds.height) / 2.;
+        let radius = (outer - TICK_LENGTH - style.track_width / 2.).max(0.);
+        let body = (radius - style.track_width * 1.5).max(0.);
+
+        let at = |angle: f32, distance: f32| {
+            center + Vector::new(angle.cos() * distance, angle.sin() * distance)
+        };
+
+        let arc = |end: f32| {
+            let mut builder = Builder::new();
+            builder.arc(Arc {
+                center,
+                radius,
+                start_angle: self.start_angle,
+                end_angle: Radians(end),
+            });
+            builder.build()
+        };
+
+        let stroke = |color: Color| {
+            Stroke::default()
+                .with_color(color)
+                .with_width(style.track_width)
+                .with_line_cap(LineCap::Round)
+        };
+
+        for detent in &self.detents {
+            let angle = self.angle(self.fraction(*detent));
+            frame.stroke(
+                &Path::line(at(angle, radius + style.track_width / 2.), at(angle, outer)),
+                Stroke::default().with_color(style.detent).with_width(1.),
+            );
+        }
+
+        let value = self.angle(self.fraction(self.value));
+
+        frame.stroke(&arc(self.start_angle.0 + self.sweep.0), stroke(style.track));
+        frame.stroke(&arc(value), stroke(style.value));
+
+        frame.fill(&Path::circle(center, body), style.body);
+        frame.stroke(
+            &Path::line(at(value, body * 0.5), at(value, body * 0.9)),
+            Stroke::default()
+                .with_color(style.indicator)
+                .with_width(2.)
+                .with_line_cap(LineCap::Round),
+        );
+
+        if let Some(label) = &self.label {
+            frame.fill_text(Text {
+                content: label(self.value),
+                position: center,
+                color: style.text,
+                size: self.text_size,
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                ..Text::default()
+            });
+        }
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &State,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        if state.dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(bounds) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, T, Message, Theme> From<Knob<'a, T, Message, Theme>> for Element<'a, Message, Theme>
+where
+    T: Copy + Into<f64> + FromPrimitive + PartialOrd + 'a,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+{
+    fn from(value: Knob<'a, T, Message, Theme>) -> Self {
+        let size = Length::Fixed(value.size);
+        Element::new(Canvas::new(value).width(size).height(size))
+    }
+}
+
+/// Creates a new [`Knob`].
+pub fn knob<'a, T, Message, Theme>(
+    range: RangeInclusive<T>,
+    value: T,
+    on_change: impl Fn(T) -> Message + 'a,
+) -> Knob<'a, T, Message, Theme>
+where
+    T: Copy + Into<f64> + FromPrimitive + PartialOrd,
+    Theme: Catalog,
+{
+    Knob::new(range, value, on_change)
+}
@@ -0,0 +1,802 @@
+//! A stack of overlays drawn above some content, with consistent ordering and dismissal.
+//!
+//! The application keeps track of which modals, popovers or context menus are open, and gives
+//! them as [`Layer`]s to a [`Layers`] wrapping its content. The last layer is drawn on top.
+//!
+//! All the layers follow the same rules:
+//! * Events go to the layers from the top one down, then to the content.
+//! * A layer with a scrim covers everything below it, which stops receiving pointer and keyboard
+//!   events.
+//! * Escape and clicks outside of the top layer dismiss it, according to its [`Dismiss`] policy.
+//!   Only the top layer is dismissed, so that closing a stack of layers takes as many steps.
+//!
+//! The other overlay widgets of the crate (popovers, tooltips, menus of the combo boxes and search
+//! bars, toasts, drawers, tours, ...) follow the same rules: the overlays of their content are
+//! below their own, their scrims block the input of what they cover, and they are dismissed
+//! according to a [`Dismiss`] policy.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced::{Element, widget::{button, column, container, text}};
+//! use more_iced_aw::layers::{Layer, Layers};
+//!
+//! #[derive(Default)]
+//! struct App {
+//!     confirm: bool,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Delete,
+//!     Confirm,
+//!     Cancel,
+//! }
+//!
+//! impl App {
+//!     fn update(&mut self, message: Message) {
+//!         match message {
+//!             Message::Delete => self.confirm = true,
+//!             Message::Confirm | Message::Cancel => self.confirm = false,
+//!         }
+//!     }
+//!
+//!     fn view(&self) -> Element<'_, Message> {
+//!         let content = button("Delete").on_press(Message::Delete);
+//!
+//!         let dialog = container(column![
+//!             text("Delete the file?"),
+//!             button("Confirm").on_press(Message::Confirm),
+//!         ])
+//!         .padding(20)
+//!         .style(container::bordered_box);
+//!
+//!         Layers::new(content)
+//!             .push_maybe(
+//!                 self.confirm
+//!                     .then(|| Layer::modal(dialog).on_dismiss(Message::Cancel)),
+//!             )
+//!             .into()
+//!     }
+//! }
+//!
+//! fn main() -> iced::Result {
+//!     iced::run("Layers", App::update, App::view)
+//! }
+//! ```
+
+use iced::{
+    Background, Color, Element, Length, Point, Rectangle, Size, Theme, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{Operation, Tree},
+    },
+    alignment, event, keyboard, touch,
+};
+
+/// The appearance of [`Layers`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background covering everything below a layer with a scrim.
+    pub scrim: Background,
+}
+
+/// The theme catalog of [`Layers`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for [`Layers`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of [`Layers`].
+pub fn default(_theme: &Theme) -> Style {
+    Style {
+        scrim: Color::BLACK.scale_alpha(0.5).into(),
+    }
+}
+
+/// When a layer is dismissed.
+///
+/// ```
+/// use iced::{Event, Point, Rectangle, Size, keyboard, mouse};
+/// use more_iced_aw::layers::Dismiss;
+///
+/// let layer = Rectangle::new(Point::ORIGIN, Size::new(100., 100.));
+/// let click = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+/// let outside = mouse::Cursor::Available(Point::new(150., 50.));
+///
+/// assert!(Dismiss::default().applies(&click, outside, &[layer]));
+/// assert!(!Dismiss::ESCAPE.applies(&click, outside, &[layer]));
+/// assert!(!Dismiss::default().applies(&click, mouse::Cursor::Available(Point::new(50., 50.)), &[layer]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dismiss {
+    /// Whether a click outside of the layer dismisses it.
+    pub outside_click: bool,
+    /// Whether the Escape key dismisses the layer.
+    pub escape: bool,
+}
+
+impl Dismiss {
+    /// Dismissed by a click outside of it and by Escape.
+    pub const ANY: Self = Self {
+        outside_click: true,
+        escape: true,
+    };
+
+    /// Only dismissed by Escape.
+    pub const ESCAPE: Self = Self {
+        outside_click: false,
+        escape: true,
+    };
+
+    /// Never dismissed: the layer is only closed by the application.
+    pub const NEVER: Self = Self {
+        outside_click: false,
+        escape: false,
+    };
+
+    /// Returns the status of an event which dismissed a layer.
+    ///
+    /// Escape is captured, so that it only dismisses the top layer, while a click outside
+    /// goes on to what is below.
+    pub(crate) fn status(event: &iced::Event) -> event::Status {
+        match event {
+            iced::Event::Keyboard(_) => event::Status::Captured,
+            _ => event::Status::Ignored,
+        }
+    }
+
+    /// Indicates if an event dismisses a layer covering the given areas.
+    pub fn applies(self, event: &iced::Event, cursor: mouse::Cursor, areas: &[Rectangle]) -> bool {
+        match event {
+            iced::Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) => self.escape,
+            iced::Event::Mouse(mouse::Event::ButtonPressed(_))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. }) => {
+                self.outside_click && !areas.iter().any(|area| cursor.is_over(*area))
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Default for Dismiss {
+    fn default() -> Self {
+        Self::ANY
+    }
+}
+
+/// Indicates if an event comes from the user, the events blocked by a scrim.
+pub(crate) fn is_input(event: &iced::Event) -> bool {
+    matches!(
+        event,
+        iced::Event::Mouse(_) | iced::Event::Keyboard(_) | iced::Event::Touch(_)
+    )
+}
+
+/// Draws a scrim covering `bounds`.
+pub(crate) fn draw_scrim<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    scrim: impl Into<Background>,
+) where
+    Renderer: iced::advanced::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            ..renderer::Quad::default()
+        },
+        scrim,
+    );
+}
+
+/// Groups the overlays of a widget, given from the bottom one to the top one.
+///
+/// The overlays of the content of a widget go below its own, like the content of [`Layers`]
+/// is below its layers.
+pub(crate) fn group<'a, Message, Theme, Renderer>(
+    overlays: impl IntoIterator<Item = Option<overlay::Element<'a, Message, Theme, Renderer>>>,
+) -> Option<overlay::Element<'a, Message, Theme, Renderer>>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    let overlays: Vec<_> = overlays.into_iter().flatten().collect();
+
+    (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+}
+
+/// Where a [`Layer`] is placed in the window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Position {
+    /// Aligned in the window.
+    Aligned(alignment::Horizontal, alignment::Vertical),
+    /// With its top left corner at a point of the window, like a context menu.
+    ///
+    /// The layer is moved to stay inside the window.
+    At(Point),
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        Self::Aligned(alignment::Horizontal::Center, alignment::Vertical::Center)
+    }
+}
+
+/// An overlay of [`Layers`], created with [`layer`].
+pub struct Layer<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    position: Position,
+    dismiss: Dismiss,
+    on_dismiss: Option<Message>,
+    scrim: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Layer<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Layer`] centered in the window, without scrim.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            position: Position::default(),
+            dismiss: Dismiss::default(),
+            on_dismiss: None,
+            scrim: false,
+        }
+    }
+
+    /// Creates a new modal [`Layer`]: centered in the window, with a scrim blocking everything below.
+    pub fn modal(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self::new(content).scrim(true)
+    }
+
+    /// Creates a new [`Layer`] with its top left corner at a point, without scrim, like a context
+    /// menu.
+    pub fn at(position: Point, content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self::new(content).position(Position::At(position))
+    }
+
+    /// Sets the [`Position`] of the [`Layer`].
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets when the [`Layer`] is dismissed.
+    pub fn dismiss(mut self, dismiss: Dismiss) -> Self {
+        self.dismiss = dismiss;
+        self
+    }
+
+    /// Sets the message produced when the [`Layer`] is dismissed.
+    ///
+    /// Without it, the [`Layer`] cannot be dismissed.
+    pub fn on_dismiss(mut self, on_dismiss: Message) -> Self {
+        self.on_dismiss = Some(on_dismiss);
+        self
+    }
+
+    /// Sets whether a scrim covers and blocks everything below the [`Layer`].
+    pub fn scrim(mut self, scrim: bool) -> Self {
+        self.scrim = scrim;
+        self
+    }
+}
+
+/// Creates a new [`Layer`] centered in the window, without scrim.
+pub fn layer<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Layer<'a, Message, Theme, Renderer> {
+    Layer::new(content)
+}
+
+/// Some content with a stack of [`Layer`]s drawn above it, created with [`layers`].
+pub struct Layers<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    layers: Vec<Layer<'a, Message, Theme, Renderer>>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Layers<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates new [`Layers`] above the given content, without any layer.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            layers: Vec::new(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Adds a [`Layer`] on top of the others.
+    pub fn push(mut self, layer: Layer<'a, Message, Theme, Renderer>) -> Self {
+        self.layers.push(layer);
+        self
+    }
+
+    /// Adds a [`Layer`] on top of the others, if `Some`.
+    pub fn push_maybe(self, layer: Option<Layer<'a, Message, Theme, Renderer>>) -> Self {
+        match layer {
+            Some(layer) => self.push(layer),
+            None => self,
+        }
+    }
+
+    /// Sets the style of the [`Layers`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Layers`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Indicates if a layer has a scrim, blocking the content.
+    fn is_blocked(&self) -> bool {
+        self.layers.iter().any(|layer| layer.scrim)
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Layers<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        std::iter::once(Tree::new(&self.content))
+            .chain(self.layers.iter().map(|layer| Tree::new(&layer.content)))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let children: Vec<_> = std::iter::once(&self.content)
+            .chain(self.layers.iter().map(|layer| &layer.content))
+            .collect();
+        tree.diff_children(&children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            if self.is_blocked() {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            },
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let blocked = self.is_blocked();
+
+        if blocked && is_input(&event) {
+            return event::Status::Ignored;
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            if blocked {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            },
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.is_blocked() {
+            return mouse::Interaction::default();
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (content_tree, layer_trees) = tree.children.split_at_mut(1);
+
+        let content = self.content.as_widget_mut().overlay(
+            &mut content_tree[0],
+            layout,
+            renderer,
+            translation,
+        );
+
+        let stack = (!self.layers.is_empty()).then(|| {
+            overlay::Element::new(Box::new(Stack {
+                layers: &mut self.layers,
+                trees: layer_trees,
+                class: &self.class,
+            }))
+        });
+
+        group([content, stack])
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Layers<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(value: Layers<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates new [`Layers`] above the given content, without any layer.
+pub fn layers<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Layers<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    Layers::new(content)
+}
+
+/// The overlay drawing the layers of [`Layers`].
+struct Stack<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    layers: &'b mut [Layer<'a, Message, Theme, Renderer>],
+    trees: &'b mut [Tree],
+    class: &'b Theme::Class<'a>,
+}
+
+impl<Message, Theme, Renderer> Stack<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Returns the index of the lowest layer still receiving events: the top layer with a scrim.
+    fn lowest_active(&self) -> usize {
+        self.layers
+            .iter()
+            .rposition(|layer| layer.scrim)
+            .unwrap_or(0)
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Stack<'_, '_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        let limits = Limits::new(Size::ZERO, bounds);
+
+        let children = self
+            .layers
+            .iter()
+            .zip(self.trees.iter_mut())
+            .map(|(layer, tree)| {
+                let node = layer.content.as_widget().layout(tree, renderer, &limits);
+                let size = node.size();
+
+                let position = match layer.position {
+                    Position::Aligned(horizontal, vertical) => Point::new(
+                        match horizontal {
+                            alignment::Horizontal::Left => 0.,
+                            alignment::Horizontal::Center => (bounds.width - size.width) / 2.,
+                            alignment::Horizontal::Right => bounds.width - size.width,
+                        },
+                        match vertical {
+                            alignment::Vertical::Top => 0.,
+                            alignment::Vertical::Center => (bounds.height - size.height) / 2.,
+                            alignment::Vertical::Bottom => bounds.height - size.height,
+                        },
+                    ),
+                    Position::At(point) => Point::new(
+                        point.x.min(bounds.width - size.width).max(0.),
+                        point.y.min(bounds.height - size.height).max(0.),
+                    ),
+                };
+
+                node.move_to(position)
+            })
+            .collect();
+
+        Node::with_children(bounds, children)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.style(self.class);
+        let lowest_active = self.lowest_active();
+
+        for (i, ((layer, tree), layout)) in self
+            .layers
+            .iter()
+            .zip(self.trees.iter())
+            .zip(layout.children())
+            .enumerate()
+        {
+            renderer.with_layer(bounds, |renderer| {
+                if layer.scrim {
+                    draw_scrim(renderer, bounds, appearance.scrim);
+                }
+
+                layer.content.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    if i < lowest_active {
+                        mouse::Cursor::Unavailable
+                    } else {
+                        cursor
+                    },
+                    &bounds,
+                );
+            });
+        }
+    }
+
+    fn operate(&mut self, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for ((layer, tree), layout) in self
+                .layers
+                .iter()
+                .zip(self.trees.iter_mut())
+                .zip(layout.children())
+            {
+                layer
+                    .content
+                    .as_widget()
+                    .operate(tree, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let lowest_active = self.lowest_active();
+        let is_input = is_input(&event);
+        let layouts: Vec<_> = layout.children().collect();
+        let areas: Vec<_> = layouts.iter().map(Layout::bounds).collect();
+
+        let mut status = event::Status::Ignored;
+        for (i, ((layer, tree), layout)) in self
+            .layers
+            .iter_mut()
+            .zip(self.trees.iter_mut())
+            .zip(layouts)
+            .enumerate()
+            .rev()
+        {
+            let active = i >= lowest_active;
+            if !active && is_input {
+                continue;
+            }
+
+            status = status.merge(layer.content.as_widget_mut().on_event(
+                tree,
+                event.clone(),
+                layout,
+                if active && status == event::Status::Ignored {
+                    cursor
+                } else {
+                    mouse::Cursor::Unavailable
+                },
+                renderer,
+                clipboard,
+                shell,
+                &bounds,
+            ));
+        }
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        if let Some((top, area)) = self.layers.last().zip(areas.last())
+            && let Some(on_dismiss) = &top.on_dismiss
+            && top
+                .dismiss
+                .applies(&event, cursor, std::slice::from_ref(area))
+        {
+            shell.publish(on_dismiss.clone());
+
+            if Dismiss::status(&event) == event::Status::Captured {
+                return event::Status::Captured;
+            }
+        }
+
+        let over_layer = areas.iter().any(|area| cursor.is_over(*area));
+        if is_input && (self.layers.iter().any(|layer| layer.scrim) || over_layer) {
+            if let iced::Event::Mouse(mouse::Event::CursorMoved { .. }) = event {
+                return event::Status::Ignored;
+            }
+            return event::Status::Captured;
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let lowest_active = self.lowest_active();
+        let layouts: Vec<_> = layout.children().collect();
+
+        self.layers
+            .iter()
+            .zip(self.trees.iter())
+            .zip(layouts)
+            .enumerate()
+            .rev()
+            .filter(|(i, _)| *i >= lowest_active)
+            .find(|(_, (_, layout))| cursor.is_over(layout.bounds()))
+            .map(|(_, ((layer, tree), layout))| {
+                layer
+                    .content
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .unwrap_or_default()
+    }
+
+    fn is_over(&self, layout: Layout<'_>, _renderer: &Renderer, cursor_position: Point) -> bool {
+        self.layers.iter().any(|layer| layer.scrim)
+            || layout
+                .children()
+                .any(|layout| layout.bounds().contains(cursor_position))
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        group(
+            self.layers
+                .iter_mut()
+                .zip(self.trees.iter_mut())
+                .zip(layout.children())
+                .map(|((layer, tree), layout)| {
+                    layer
+                        .content
+                        .as_widget_mut()
+                        .overlay(tree, layout, renderer, Vector::ZERO)
+                }),
+        )
+    }
+}
@@ -0,0 +1,508 @@
+//! A level meter, for audio or telemetry levels.
+//!
+//! The [`LevelMeter`] displays a level in decibels as a bar colored by zones, from the normal
+//! levels to the dangerous ones. The bar rises instantly and decays smoothly, and the highest
+//! recent level is held for a while by a peak indicator.
+//!
+//! The meter is fed by the application, which gives it the current level each time it changes:
+//!
+//! ```
+//! use more_iced_aw::level_meter::{LevelMeter, decibels, level_meter};
+//!
+//! let samples = [0.1f32, -0.4, 0.25];
+//! let peak = samples.iter().fold(0f32, |peak, sample| peak.max(sample.abs()));
+//!
+//! let meter: LevelMeter<'_> = level_meter(decibels(peak));
+//! ```
+
+use std::{f32::consts::FRAC_PI_2, ops::RangeInclusive, time::Duration};
+
+use iced::{
+    Background, Color, Element, Gradient, Length, Pixels, Point, Radians, Rectangle, Size, Theme,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, renderer,
+        text::{self, LineHeight, Shaping, Text, Wrapping},
+        widget::{Tree, tree},
+    },
+    alignment, event,
+    gradient::Linear,
+    time::Instant,
+    window,
+};
+
+use crate::animation::Clock;
+
+/// The direction of a [`LevelMeter`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Axis {
+    /// The level rises from left to right.
+    Horizontal,
+    /// The level rises from bottom to top.
+    #[default]
+    Vertical,
+}
+
+/// Converts a linear amplitude to decibels.
+///
+/// ```
+/// use more_iced_aw::level_meter::decibels;
+///
+/// assert_eq!(decibels(1.), 0.);
+/// assert_eq!(decibels(0.1), -20.);
+/// assert_eq!(decibels(0.), f32::NEG_INFINITY);
+/// ```
+pub fn decibels(amplitude: f32) -> f32 {
+    20. * amplitude.abs().log10()
+}
+
+/// The appearance of a [`LevelMeter`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the bar.
+    pub background: Background,
+    /// The color of the normal levels.
+    pub normal: Color,
+    /// The color of the levels above the warning level.
+    pub warning: Color,
+    /// The color of the levels above the danger level.
+    pub danger: Color,
+    /// The color of the peak indicator.
+    pub peak: Color,
+    /// The color of the scale.
+    pub scale: Color,
+}
+
+/// The theme catalog of a [`LevelMeter`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`LevelMeter`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`LevelMeter`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weak.color.into(),
+        normal: palette.success.base.color,
+        // The extended palette has no warning color.
+        warning: iced::color!(0xF2, 0xC0, 0x37),
+        danger: palette.danger.base.color,
+        peak: palette.background.base.text,
+        scale: palette.background.strong.color,
+    }
+}
+
+/// The state of a [`LevelMeter`].
+#[derive(Debug, Clone, Copy)]
+struct State {
+    /// The displayed level, decaying towards the level.
+    displayed: f32,
+    /// The held peak.
+    peak: f32,
+    /// When the peak was reached.
+    peak_time: Option<Instant>,
+    clock: Clock,
+}
+
+/// A level meter, created with [`level_meter`].
+pub struct LevelMeter<'a, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    level: f32,
+    range: RangeInclusive<f32>,
+    zones: (f32, f32),
+    axis: Axis,
+    decay: f32,
+    peak_hold: Option<Duration>,
+    scale: Option<f32>,
+    thickness: f32,
+    length: Length,
+    text_size: Pixels,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme> LevelMeter<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`LevelMeter`] with the given level, in decibels.
+    pub fn new(level: f32) -> Self {
+        Self {
+            level,
+            range: -60.0..=0.0,
+            zones: (-12., -3.),
+            axis: Axis::default(),
+            decay: 20.,
+            peak_hold: Some(Duration::from_millis(1500)),
+            scale: Some(6.),
+            thickness: 12.,
+            length: Length::Fill,
+            text_size: Pixels(10.),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the range of levels displayed, in decibels. Defaults to `-60.0..=0.0`.
+    pub fn range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Sets the levels, in decibels, above which the bar has the warning and the danger colors.
+    /// Defaults to -12 dB and -3 dB.
+    pub fn zones(mut self, warning: f32, danger: f32) -> Self {
+        self.zones = (warning, danger.max(warning));
+        self
+    }
+
+    /// Sets the [`Axis`] of the [`LevelMeter`].
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Sets the speed, in decibels per second, at which the bar and the peak decay. Defaults to
+    /// 20 dB/s.
+    pub fn decay(mut self, decay: f32) -> Self {
+        self.decay = decay.max(0.);
+        self
+    }
+
+    /// Sets how long the peak is held before decaying, or `None` to hide it.
+    pub fn peak_hold(mut self, peak_hold: impl Into<Option<Duration>>) -> Self {
+        self.peak_hold = peak_hold.into();
+        self
+    }
+
+    /// Sets the step, in decibels, between the markings of the scale, or `None` to hide it.
+    pub fn scale(mut self, scale: impl Into<Option<f32>>) -> Self {
+        self.scale = scale.into().filter(|step| *step > 0.);
+        self
+    }
+
+    /// Sets the thickness of the bar.
+    pub fn thickness(mut self, thickness: impl Into<Pixels>) -> Self {
+        self.thickness = thickness.into().0;
+        self
+    }
+
+    /// Sets the length of the [`LevelMeter`].
+    pub fn length(mut self, length: impl Into<Length>) -> Self {
+        self.length = length.into();
+        self
+    }
+
+    /// Sets the text size of the scale.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = text_size.into();
+        self
+    }
+
+    /// Sets the style of the [`LevelMeter`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`LevelMeter`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the position of a level in the range, from `0` to `1`.
+    fn fraction(&self, level: f32) -> f32 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let fraction = (level - start) / (end - start);
+
+        if fraction.is_nan() {
+            0.
+        } else {
+            fraction.clamp(0., 1.)
+        }
+    }
+
+    /// Returns the width of the scale.
+    fn scale_width(&self) -> f32 {
+        if self.scale.is_some() {
+            self.text_size.0 * 2.5
+        } else {
+            0.
+        }
+    }
+
+    /// Returns the part of the bar from the bottom of the range to the given fraction.
+    fn filled(&self, bar: Rectangle, fraction: f32) -> Rectangle {
+        match self.axis {
+            Axis::Vertical => {
+                let height = bar.height * fraction;
+                Rectangle {
+                    y: bar.y + bar.height - height,
+                    height,
+                    ..bar
+                }
+            }
+            Axis::Horizontal => Rectangle {
+                width: bar.width * fraction,
+                ..bar
+            },
+        }
+    }
+
+    /// Returns the level decayed from `from` towards `to` during `elapsed` seconds.
+    fn decayed(&self, from: f32, to: f32, elapsed: f32) -> f32 {
+        if to >= from {
+            to
+        } else {
+            (from - self.decay * elapsed).max(to)
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for LevelMeter<'_, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            displayed: self.level,
+            peak: self.level,
+            peak_time: None,
+            clock: Clock::new(),
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        let thickness = Length::Fixed(self.thickness + self.scale_width());
+
+        match self.axis {
+            Axis::Vertical => Size::new(thickness, self.length),
+            Axis::Horizontal => Size::new(self.length, thickness),
+        }
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let size = <Self as Widget<Message, Theme, Renderer>>::size(self);
+        Node::new(limits.resolve(size.width, size.height, Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if let iced::Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+            let elapsed = state.clock.tick(now);
+
+            state.displayed = self.decayed(state.displayed, self.level, elapsed);
+
+            if state.displayed >= state.peak || state.peak_time.is_none() {
+                state.peak = state.displayed;
+                state.peak_time = Some(now);
+            } else if let Some(hold) = self.peak_hold
+                && state
+                    .peak_time
+                    .is_some_and(|time| now.saturating_duration_since(time) > hold)
+            {
+                state.peak = self.decayed(state.peak, state.displayed, elapsed);
+            }
+
+            if state.displayed > self.level || state.peak > state.displayed {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let bounds = layout.bounds();
+
+        let bar = match self.axis {
+            Axis::Vertical => Rectangle {
+                width: self.thickness.min(bounds.width),
+                ..bounds
+            },
+            Axis::Horizontal => Rectangle {
+                height: self.thickness.min(bounds.height),
+                ..bounds
+            },
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: bar,
+                ..Default::default()
+            },
+            style.background,
+        );
+
+        let angle = match self.axis {
+            Axis::Vertical => Radians(0.),
+            Axis::Horizontal => Radians(FRAC_PI_2),
+        };
+        let gradient = Linear::new(angle)
+            .add_stop(0., style.normal)
+            .add_stop(self.fraction(self.zones.0), style.warning)
+            .add_stop(self.fraction(self.zones.1), style.danger)
+            .add_stop(1., style.danger);
+
+        // The whole gradient is drawn in the filled part, so each level keeps its color.
+        let filled = self.filled(bar, self.fraction(state.displayed));
+        renderer.with_layer(filled, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bar,
+                    ..Default::default()
+                },
+                Gradient::from(gradient),
+            );
+        });
+
+        if self.peak_hold.is_some() && state.peak > *self.range.start() {
+            let peak = self.filled(bar, self.fraction(state.peak));
+            let indicator = match self.axis {
+                Axis::Vertical => Rectangle { height: 2., ..peak },
+                Axis::Horizontal => Rectangle {
+                    x: peak.x + peak.width - 2.,
+                    width: 2.,
+                    ..peak
+                },
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: indicator,
+                    ..Default::default()
+                },
+                style.peak,
+            );
+        }
+
+        let Some(step) = self.scale else {
+            return;
+        };
+
+        let (start, end) = (
+            self.range.start().min(*self.range.end()),
+            self.range.start().max(*self.range.end()),
+        );
+        let mut level = (end / step).floor() * step;
+
+        while level >= start {
+            let position = self.filled(bar, self.fraction(level));
+            let (tick, anchor, alignment) = match self.axis {
+                Axis::Vertical => (
+                    Rectangle::new(Point::new(bar.x + bar.width, position.y), Size::new(3., 1.)),
+                    Point::new(bar.x + bar.width + 5., position.y),
+                    (alignment::Horizontal::Left, alignment::Vertical::Center),
+                ),
+                Axis::Horizontal => (
+                    Rectangle::new(
+                        Point::new(position.x + position.width, bar.y + bar.height),
+                        Size::new(1., 3.),
+                    ),
+                    Point::new(position.x + position.width, bar.y + bar.height + 3.),
+                    (alignment::Horizontal::Center, alignment::Vertical::Top),
+                ),
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: tick,
+                    ..Default::default()
+                },
+                style.scale,
+            );
+            renderer.fill_text(
+                Text {
+                    content: format!("{level:.0}"),
+                    bounds: Size::INFINITY,
+                    size: self.text_size,
+                    line_height: LineHeight::default(),
+                    font: renderer.default_font(),
+                    horizontal_alignment: alignment.0,
+                    vertical_alignment: alignment.1,
+                    shaping: Shaping::Basic,
+                    wrapping: Wrapping::None,
+                },
+                anchor,
+                style.scale,
+                *viewport,
+            );
+
+            level -= step;
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<LevelMeter<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer,
+{
+    fn from(value: LevelMeter<'a, Theme>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`LevelMeter`] with the given level, in decibels.
+pub fn level_meter<'a, Theme>(level: f32) -> LevelMeter<'a, Theme>
+where
+    Theme: Catalog,
+{
+    LevelMeter::new(level)
+}
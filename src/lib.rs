@@ -3,7 +3,118 @@
 //! Adds additionnal iced widgets. Some are inspired by iced_aw.
 //! 
 //! All widgets that have a state support serialization and deserialization with serde if the feature `serde` is enabled.
+//! This covers the states kept by the application, like the contents of the widgets and the data they show,
+//! but not the interaction states kept by the widgets themselves, like a hovered item or an ongoing drag.
+//!
+//! The feature `test-utils` adds the `harness` module, which mounts widgets without a window to
+//! feed them events and check the messages they produce and their layout.
+//!
+//! The feature `tracing` emits [`tracing`](https://docs.rs/tracing) spans and events from the
+//! layout of the [`Grid`](grid::Grid), with the sizes of its tracks and how the fill was shared, and
+//! from the event handling of the [`ParsedInput`](parsed_input::ParsedInput), with the reasons edits
+//! are dropped. Summaries are emitted at the `debug` level and details at the `trace` level.
+//!
+//! The feature `iced_014` adds the `grid::iced_014` and `parsed_input::iced_014` modules,
+//! with versions of the [`Grid`](grid::Grid) and of the [`ParsedInput`](parsed_input::ParsedInput)
+//! implementing the widget trait of iced 0.14, built on `iced_widget` 0.14. They share their
+//! layout and parsing with the widgets of iced 0.13, and the rest of the crate stays on iced 0.13.
+//! The feature enables the `tiny-skia` renderer of iced 0.14, which `iced_widget` needs in
+//! release builds.
+//!
+//! # Styling
+//!
+//! Widgets are styled like the widgets of iced: each module has a `Style` struct describing the
+//! appearance of its widget, a `Catalog` trait producing it from a class, and a `StyleFn` class
+//! implemented for [`iced::Theme`]. The `default` function of the module derives the style from
+//! the extended palette of the theme, so it follows light and dark themes.
+//!
+//! The class of a widget is overridden with its `style` builder, taking a closure, or with its
+//! `class` builder. A custom theme implements the `Catalog` of a module to provide its own classes.
+//!
+//! The [`Grid`](grid::Grid) is the exception: it is transparent unless styled, so it can be
+//! used with any theme, and its `Catalog` is only required by its `class` builder.
+//!
+//! ```no_run
+//! use iced::{Border, Element, Theme};
+//! use more_iced_aw::key_hint::{self, key_hint};
+//!
+//! fn view<'a, Message: 'a>() -> Element<'a, Message> {
+//!     key_hint("Ctrl+S")
+//!         .style(|theme: &Theme| key_hint::Style {
+//!             border: Border::default().rounded(8),
+//!             ..key_hint::default(theme)
+//!         })
+//!         .into()
+//! }
+//! ```
+//!
+//! Some widgets reuse the catalog of the iced widget they build upon, like
+//! [`ParsedInput`](parsed_input::ParsedInput) with the one of the text input and
+//! [`RangeSlider`](range_slider::RangeSlider) with the one of the slider, and the notifications of
+//! [`toast`] are styled per severity with [`Manager::with_style`](toast::Manager::with_style).
+//! The layout widgets, like [`wrap`], [`floating`], [`stack_aligned`], [`viewport`] and
+//! [`infinite_scroll`], draw nothing themselves.
 
 pub mod parsed_input;
 pub mod grid;
-pub mod helpers;
\ No newline at end of file
+pub mod animation;
+pub mod bottom_sheet;
+pub mod card;
+pub mod chart;
+pub mod chip_group;
+pub mod code_editor;
+pub mod cropper;
+pub mod curve_editor;
+pub mod dnd;
+pub mod dock;
+pub mod drag_drop;
+pub mod draw_pad;
+pub mod drawer;
+pub mod file_browser;
+pub mod floating;
+pub mod focus;
+pub mod form;
+pub mod gallery;
+pub mod gantt;
+#[cfg(feature = "test-utils")]
+pub mod harness;
+pub mod helpers;
+pub mod hotkeys;
+pub mod i18n;
+pub mod image_viewer;
+pub mod infinite_scroll;
+pub mod key_hint;
+pub mod keypad;
+pub mod layers;
+pub mod level_meter;
+pub mod loading_overlay;
+pub mod log_view;
+pub mod mdi;
+pub mod minimap;
+pub mod knob;
+pub mod otp_input;
+pub mod piano;
+pub mod popover;
+pub mod range_slider;
+pub mod rating;
+pub mod reorderable_list;
+pub mod responsive;
+pub mod rich_combo_box;
+pub mod rich_editor;
+pub mod stack_aligned;
+pub mod sticky_scroll;
+pub mod rich_tooltip;
+pub mod scheduler;
+pub mod search_bar;
+pub mod spinner;
+pub mod swatch_picker;
+pub mod table;
+pub mod tab_rail;
+pub mod terminal_view;
+pub mod timeline;
+pub mod titlebar;
+pub mod toast;
+pub mod tour;
+pub mod viewport;
+pub mod wrap;
+pub mod xy_pad;
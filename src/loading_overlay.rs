@@ -0,0 +1,472 @@
+//! A wrapper blocking its content while something is loading.
+//!
+//! While loading, a [`LoadingOverlay`] dims its content, keeps all the pointer and keyboard events
+//! from reaching it, and shows a [`Spinner`] in its center, with an optional status text below.
+//! Its backdrop is a scrim like the ones of [`layers`](crate::layers), which are never dismissed:
+//! it is removed when the loading ends.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced::{Element, Task, widget::button};
+//! use more_iced_aw::loading_overlay::loading_overlay;
+//!
+//! #[derive(Default)]
+//! struct App {
+//!     saving: bool,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Save,
+//!     Saved,
+//! }
+//!
+//! impl App {
+//!     fn update(&mut self, message: Message) -> Task<Message> {
+//!         match message {
+//!             Message::Save => {
+//!                 self.saving = true;
+//!                 Task::perform(async {}, |()| Message::Saved)
+//!             }
+//!             Message::Saved => {
+//!                 self.saving = false;
+//!                 Task::none()
+//!             }
+//!         }
+//!     }
+//!
+//!     fn view(&self) -> Element<'_, Message> {
+//!         loading_overlay(button("Save").on_press(Message::Save), self.saving)
+//!             .status("Saving...")
+//!             .into()
+//!     }
+//! }
+//!
+//! fn main() -> iced::Result {
+//!     iced::run("Loading", App::update, App::view)
+//! }
+//! ```
+
+use iced::{
+    Color, Element, Length, Pixels, Point, Rectangle, Size, Theme, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        text::{self, LineHeight, Shaping, Text, Wrapping},
+        widget::{Operation, Tree},
+    },
+    alignment, event,
+};
+
+use crate::{
+    layers,
+    spinner::{self, Spinner},
+};
+
+/// The appearance of a [`LoadingOverlay`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color covering the content while loading.
+    pub backdrop: Color,
+    /// The color of the status text.
+    pub text_color: Color,
+}
+
+/// The theme catalog of a [`LoadingOverlay`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`LoadingOverlay`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`LoadingOverlay`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        backdrop: palette.background.base.color.scale_alpha(0.7),
+        text_color: palette.background.base.text,
+    }
+}
+
+/// A wrapper dimming and blocking its content while loading, created with [`loading_overlay`].
+///
+/// Events that are not related to the pointer or the keyboard, like redraw requests, still reach
+/// the content while it is blocked.
+pub struct LoadingOverlay<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog + spinner::Catalog,
+    Renderer: text::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    is_loading: bool,
+    spinner: Spinner<'a, Theme>,
+    status: Option<String>,
+    spacing: f32,
+    text_size: Pixels,
+    font: Option<Renderer::Font>,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> LoadingOverlay<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + spinner::Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`LoadingOverlay`] of the given content, blocked if `is_loading` is true.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        is_loading: bool,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            is_loading,
+            spinner: Spinner::new().size(32),
+            status: None,
+            spacing: 10.,
+            text_size: Pixels(16.),
+            font: None,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets the [`Spinner`] shown while loading.
+    pub fn spinner(mut self, spinner: Spinner<'a, Theme>) -> Self {
+        self.spinner = spinner;
+        self
+    }
+
+    /// Sets the status text shown below the spinner.
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.status = Some(status.into());
+        self
+    }
+
+    /// Sets the spacing between the spinner and the status text.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the status.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into();
+        self
+    }
+
+    /// Sets the font of the status.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`LoadingOverlay`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`LoadingOverlay`].
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the height of a line of the status text.
+    fn line_height(&self) -> f32 {
+        LineHeight::default().to_absolute(self.text_size).0
+    }
+
+    /// Returns the spinner as a widget, to handle it as a child.
+    fn spinner_widget(&self) -> &dyn Widget<Message, Theme, Renderer> {
+        &self.spinner
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for LoadingOverlay<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + spinner::Catalog,
+    Renderer: text::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content), Tree::new(self.spinner_widget())]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.children[0].diff(&self.content);
+        tree.children[1].diff(self.spinner_widget());
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let content = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+        let size = content.size();
+
+        let spinner = self.spinner_widget().layout(
+            &mut tree.children[1],
+            renderer,
+            &Limits::new(Size::ZERO, size),
+        );
+        let below = self
+            .status
+            .as_ref()
+            .map_or(0., |_| self.spacing + self.line_height());
+        let spinner_size = spinner.size();
+        let spinner = spinner.move_to(Point::new(
+            (size.width - spinner_size.width) / 2.,
+            (size.height - spinner_size.height - below) / 2.,
+        ));
+
+        Node::with_children(size, vec![content, spinner])
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let (content, spinner) = (children.next().unwrap(), children.next().unwrap());
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content,
+            if self.is_loading {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            },
+            viewport,
+        );
+
+        if !self.is_loading {
+            return;
+        }
+
+        let overlay = Catalog::style(theme, &self.class);
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        renderer.with_layer(bounds, |renderer| {
+            layers::draw_scrim(renderer, bounds, overlay.backdrop);
+
+            self.spinner_widget().draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                style,
+                spinner,
+                cursor,
+                viewport,
+            );
+
+            if let Some(status) = &self.status {
+                let line = Rectangle {
+                    x: bounds.x,
+                    y: spinner.bounds().y + spinner.bounds().height + self.spacing,
+                    width: bounds.width,
+                    height: self.line_height(),
+                };
+
+                renderer.fill_text(
+                    Text {
+                        content: status.clone(),
+                        bounds: line.size(),
+                        size: self.text_size,
+                        line_height: LineHeight::default(),
+                        font,
+                        horizontal_alignment: alignment::Horizontal::Center,
+                        vertical_alignment: alignment::Vertical::Center,
+                        shaping: Shaping::Advanced,
+                        wrapping: Wrapping::None,
+                    },
+                    line.center(),
+                    overlay.text_color,
+                    bounds,
+                );
+            }
+        });
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let (content, spinner) = (children.next().unwrap(), children.next().unwrap());
+
+        if !self.is_loading {
+            return self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event,
+                content,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        let _ = Widget::<Message, Theme, Renderer>::on_event(
+            &mut self.spinner,
+            &mut tree.children[1],
+            event.clone(),
+            spinner,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if layers::is_input(&event) {
+            return event::Status::Captured;
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            content,
+            mouse::Cursor::Unavailable,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if !self.is_loading {
+            self.content.as_widget().mouse_interaction(
+                &tree.children[0],
+                layout.children().next().unwrap(),
+                cursor,
+                viewport,
+                renderer,
+            )
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Working
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        if self.is_loading {
+            return None;
+        }
+
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<LoadingOverlay<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + spinner::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(value: LoadingOverlay<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`LoadingOverlay`], which blocks the `content` while `is_loading` is true.
+pub fn loading_overlay<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    is_loading: bool,
+) -> LoadingOverlay<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + spinner::Catalog,
+    Renderer: text::Renderer,
+{
+    LoadingOverlay::new(content, is_loading)
+}
@@ -0,0 +1,779 @@
+//! A viewer for append-only logs, with follow mode, level colors and filtering.
+//!
+//! The lines are kept by the application in a [`LogBuffer`], a ring buffer that drops the oldest
+//! lines when it is full. The [`LogView`] only lays out and draws the visible lines, so it stays
+//! fast with large buffers.
+//!
+//! The view follows the end of the log while new lines are pushed. Scrolling up stops following,
+//! and scrolling back to the bottom follows again. Lines can be selected with the mouse and copied
+//! with Ctrl+C (Cmd+C on macOS).
+
+use std::{cell::OnceCell, collections::VecDeque};
+
+use iced::{
+    Background, Border, Color, Element, Length, Padding, Pixels, Point, Rectangle, Size, Theme,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        clipboard::Kind,
+        layout::{Limits, Node},
+        mouse, renderer,
+        text::{self, LineHeight, Shaping, Text, Wrapping},
+        widget::{Tree, tree},
+    },
+    alignment, event, keyboard, touch,
+};
+
+/// The level of a log line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Level {
+    /// Very detailed information.
+    Trace,
+    /// Information useful when debugging.
+    Debug,
+    /// General information.
+    Info,
+    /// Something unexpected happened.
+    Warn,
+    /// Something failed.
+    Error,
+}
+
+impl Level {
+    /// Detects the level of a line from a level name (like `ERROR`, `warn` or `[INFO]`) among its
+    /// first words.
+    pub fn detect(line: &str) -> Option<Self> {
+        line.split(|c: char| !c.is_ascii_alphabetic())
+            .filter(|word| !word.is_empty())
+            .take(6)
+            .find_map(|word| match word.to_ascii_uppercase().as_str() {
+                "TRACE" => Some(Self::Trace),
+                "DEBUG" => Some(Self::Debug),
+                "INFO" => Some(Self::Info),
+                "WARN" | "WARNING" => Some(Self::Warn),
+                "ERROR" | "ERR" | "FATAL" => Some(Self::Error),
+                _ => None,
+            })
+    }
+}
+
+/// A line of a [`LogBuffer`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LogLine {
+    /// The level of the line, if it has one.
+    pub level: Option<Level>,
+    /// The text of the line.
+    pub text: String,
+}
+
+/// The lines displayed by a [`LogView`], in a ring buffer.
+///
+/// When the buffer is full, pushing a line drops the oldest one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LogBuffer {
+    lines: VecDeque<LogLine>,
+    capacity: usize,
+    dropped: usize,
+}
+
+impl LogBuffer {
+    /// Creates a new empty [`LogBuffer`] keeping at most `capacity` lines.
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+
+        Self {
+            lines: VecDeque::with_capacity(capacity.min(4096)),
+            capacity,
+            dropped: 0,
+        }
+    }
+
+    /// Pushes a line with the given level.
+    pub fn push(&mut self, level: Option<Level>, text: impl Into<String>) {
+        if self.lines.len() == self.capacity {
+            self.lines.pop_front();
+            self.dropped += 1;
+        }
+
+        self.lines.push_back(LogLine {
+            level,
+            text: text.into(),
+        });
+    }
+
+    /// Pushes a line, with the level [detected](Level::detect) from its text.
+    pub fn push_line(&mut self, text: impl Into<String>) {
+        let text = text.into();
+        self.push(Level::detect(&text), text);
+    }
+
+    /// Pushes each line of some text, with the level detected from their text.
+    pub fn push_text(&mut self, text: &str) {
+        for line in text.lines() {
+            self.push_line(line);
+        }
+    }
+
+    /// Removes all the lines.
+    pub fn clear(&mut self) {
+        self.dropped += self.lines.len();
+        self.lines.clear();
+    }
+
+    /// Returns the lines of the buffer, from the oldest.
+    pub fn lines(&self) -> impl DoubleEndedIterator<Item = &LogLine> {
+        self.lines.iter()
+    }
+
+    /// Returns the number of lines in the buffer.
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns whether the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Returns the maximum number of lines of the buffer.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of lines pushed since the creation of the buffer.
+    ///
+    /// It keeps increasing when old lines are dropped, which makes it a stable index for lines.
+    pub fn total(&self) -> usize {
+        self.dropped + self.lines.len()
+    }
+
+    /// Returns the line with the given stable index, counted from the creation of the buffer,
+    /// if it is still in the buffer.
+    pub fn get(&self, index: usize) -> Option<&LogLine> {
+        self.lines.get(index.checked_sub(self.dropped)?)
+    }
+}
+
+/// The appearance of a [`LogView`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the view.
+    pub background: Background,
+    /// The border of the view.
+    pub border: Border,
+    /// The color of the lines without level.
+    pub text_color: Color,
+    /// The color of the trace lines.
+    pub trace: Color,
+    /// The color of the debug lines.
+    pub debug: Color,
+    /// The color of the info lines.
+    pub info: Color,
+    /// The color of the warning lines.
+    pub warn: Color,
+    /// The color of the error lines.
+    pub error: Color,
+    /// The background of the selected lines.
+    pub selection: Color,
+    /// The color of the scrollbar.
+    pub scrollbar: Color,
+}
+
+impl Style {
+    /// Returns the color of the lines with the given level.
+    pub fn color(&self, level: Option<Level>) -> Color {
+        match level {
+            None => self.text_color,
+            Some(Level::Trace) => self.trace,
+            Some(Level::Debug) => self.debug,
+            Some(Level::Info) => self.info,
+            Some(Level::Warn) => self.warn,
+            Some(Level::Error) => self.error,
+        }
+    }
+}
+
+/// The theme catalog of a [`LogView`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`LogView`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`LogView`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.,
+            radius: 2.into(),
+        },
+        text_color: palette.background.base.text,
+        trace: palette.background.strong.color,
+        debug: palette.secondary.base.color,
+        info: palette.success.base.color,
+        // The extended palette has no warning color.
+        warn: Color::from_rgb8(0xE0, 0x9A, 0x00),
+        error: palette.danger.base.color,
+        selection: palette.primary.weak.color,
+        scrollbar: palette.background.strong.color,
+    }
+}
+
+/// How the lines of a [`LogView`] are filtered.
+enum Filter {
+    /// Lines containing the text.
+    Substring(String),
+    /// Lines matching the regex.
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+}
+
+/// The state of a [`LogView`].
+#[derive(Debug, Clone)]
+struct State {
+    /// The vertical scroll offset, when not following.
+    offset: f32,
+    /// Whether the view follows the end of the log.
+    follow: bool,
+    /// The stable indices of the first and last selected lines.
+    selection: Option<(usize, usize)>,
+    /// Whether lines are being selected with the mouse.
+    selecting: bool,
+    /// Whether the view was clicked last, which makes it receive the shortcuts.
+    focused: bool,
+    /// Where the scrollbar was grabbed, relatively to the top of its thumb, while it is dragged.
+    scrollbar_grab: Option<f32>,
+    modifiers: keyboard::Modifiers,
+}
+
+/// A virtualized viewer for the lines of a [`LogBuffer`], created with [`log_view`].
+pub struct LogView<'a, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    buffer: &'a LogBuffer,
+    filter: Option<Filter>,
+    min_level: Option<Level>,
+    visible: OnceCell<Vec<usize>>,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme, Renderer> LogView<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The width of the scrollbar.
+    const SCROLLBAR_WIDTH: f32 = 8.;
+
+    /// Creates a new [`LogView`] displaying the given [`LogBuffer`].
+    pub fn new(buffer: &'a LogBuffer) -> Self {
+        Self {
+            buffer,
+            filter: None,
+            min_level: None,
+            visible: OnceCell::new(),
+            text_size: None,
+            font: None,
+            padding: Padding::new(4.),
+            width: Length::Fill,
+            height: Length::Fill,
+            class: Theme::default(),
+        }
+    }
+
+    /// Only displays the lines containing the given text. An empty text displays all the lines.
+    pub fn filter(mut self, text: impl Into<String>) -> Self {
+        let text = text.into();
+        self.filter = (!text.is_empty()).then_some(Filter::Substring(text));
+        self
+    }
+
+    /// Only displays the lines matching the given regex.
+    #[cfg(feature = "regex")]
+    pub fn filter_regex(mut self, regex: regex::Regex) -> Self {
+        self.filter = Some(Filter::Regex(regex));
+        self
+    }
+
+    /// Hides the lines with a level below the given one. Lines without level are always shown.
+    pub fn min_level(mut self, level: Level) -> Self {
+        self.min_level = Some(level);
+        self
+    }
+
+    /// Sets the text size of the [`LogView`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the [`LogView`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the padding of the [`LogView`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`LogView`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`LogView`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`LogView`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`LogView`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the stable indices of the lines passing the filters.
+    fn visible(&self) -> &[usize] {
+        self.visible.get_or_init(|| {
+            let first = self.buffer.total() - self.buffer.len();
+
+            self.buffer
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| {
+                    line.level
+                        .zip(self.min_level)
+                        .is_none_or(|(level, min)| level >= min)
+                })
+                .filter(|(_, line)| match &self.filter {
+                    None => true,
+                    Some(Filter::Substring(text)) => line.text.contains(text.as_str()),
+                    #[cfg(feature = "regex")]
+                    Some(Filter::Regex(regex)) => regex.is_match(&line.text),
+                })
+                .map(|(i, _)| first + i)
+                .collect()
+        })
+    }
+
+    /// Returns the height of a line.
+    fn line_height(&self, renderer: &Renderer) -> f32 {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        LineHeight::default().to_absolute(size).0
+    }
+
+    /// Returns the height of all the visible lines.
+    fn content_height(&self, renderer: &Renderer) -> f32 {
+        self.visible().len() as f32 * self.line_height(renderer) + self.padding.vertical()
+    }
+
+    /// Returns the current scroll offset.
+    fn offset(&self, state: &State, bounds: Rectangle, renderer: &Renderer) -> f32 {
+        let max_offset = (self.content_height(renderer) - bounds.height).max(0.);
+
+        if state.follow {
+            max_offset
+        } else {
+            state.offset.min(max_offset)
+        }
+    }
+
+    /// Scrolls to the given offset, following the log if it is the bottom.
+    fn scroll_to(&self, state: &mut State, bounds: Rectangle, renderer: &Renderer, offset: f32) {
+        let max_offset = (self.content_height(renderer) - bounds.height).max(0.);
+
+        state.offset = offset.clamp(0., max_offset);
+        state.follow = state.offset >= max_offset - 0.5;
+    }
+
+    /// Returns the bounds of the scrollbar thumb, if the lines overflow.
+    fn scrollbar(
+        &self,
+        state: &State,
+        bounds: Rectangle,
+        renderer: &Renderer,
+    ) -> Option<Rectangle> {
+        let content_height = self.content_height(renderer);
+        if content_height <= bounds.height {
+            return None;
+        }
+
+        let max_offset = content_height - bounds.height;
+        let height = (bounds.height * bounds.height / content_height).max(20.);
+        let y =
+            bounds.y + (bounds.height - height) * self.offset(state, bounds, renderer) / max_offset;
+
+        Some(Rectangle {
+            x: bounds.x + bounds.width - Self::SCROLLBAR_WIDTH,
+            y,
+            width: Self::SCROLLBAR_WIDTH,
+            height,
+        })
+    }
+
+    /// Returns the stable index of the visible line at the given height.
+    fn line_at(
+        &self,
+        state: &State,
+        bounds: Rectangle,
+        renderer: &Renderer,
+        y: f32,
+    ) -> Option<usize> {
+        let visible = self.visible();
+        let row = (y - bounds.y - self.padding.top + self.offset(state, bounds, renderer))
+            / self.line_height(renderer);
+
+        let row = (row.max(0.) as usize).min(visible.len().checked_sub(1)?);
+        Some(visible[row])
+    }
+
+    /// Returns the text of the selected visible lines.
+    fn selected_text(&self, state: &State) -> Option<String> {
+        let (anchor, end) = state.selection?;
+        let (first, last) = (anchor.min(end), anchor.max(end));
+
+        let text = self
+            .visible()
+            .iter()
+            .filter(|&&i| first <= i && i <= last)
+            .filter_map(|&i| self.buffer.get(i))
+            .map(|line| line.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Some(text)
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for LogView<'_, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            offset: 0.,
+            follow: true,
+            selection: None,
+            selecting: false,
+            focused: false,
+            scrollbar_grab: None,
+            modifiers: keyboard::Modifiers::default(),
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, _tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let height = self.content_height(renderer);
+
+        Node::new(limits.resolve(self.width, self.height, Size::new(0., height)))
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..Default::default()
+            },
+            style.background,
+        );
+
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let line_height = self.line_height(renderer);
+        let offset = self.offset(state, bounds, renderer);
+        let visible = self.visible();
+        let selection = state
+            .selection
+            .map(|(anchor, end)| anchor.min(end)..=anchor.max(end));
+
+        let top = bounds.y + self.padding.top - offset;
+        let first = ((clip.y - top) / line_height).floor().max(0.) as usize;
+        let last = (((clip.y + clip.height - top) / line_height).ceil().max(0.) as usize)
+            .min(visible.len());
+
+        renderer.with_layer(clip, |renderer| {
+            for (row, &index) in visible.iter().enumerate().take(last).skip(first) {
+                let Some(line) = self.buffer.get(index) else {
+                    continue;
+                };
+                let y = top + row as f32 * line_height;
+
+                if selection
+                    .as_ref()
+                    .is_some_and(|selection| selection.contains(&index))
+                {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle::new(
+                                Point::new(bounds.x, y),
+                                Size::new(bounds.width, line_height),
+                            ),
+                            ..Default::default()
+                        },
+                        style.selection,
+                    );
+                }
+
+                renderer.fill_text(
+                    Text {
+                        content: line.text.clone(),
+                        bounds: Size::new(f32::INFINITY, line_height),
+                        size,
+                        line_height: LineHeight::Absolute(Pixels(line_height)),
+                        font,
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Top,
+                        shaping: Shaping::Basic,
+                        wrapping: Wrapping::None,
+                    },
+                    Point::new(bounds.x + self.padding.left, y),
+                    style.color(line.level),
+                    clip,
+                );
+            }
+
+            if let Some(thumb) = self.scrollbar(state, bounds, renderer) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: thumb.shrink(1.),
+                        border: Border::default().rounded(Self::SCROLLBAR_WIDTH / 2.),
+                        ..Default::default()
+                    },
+                    style.scrollbar,
+                );
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            iced::Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let dy = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * self.line_height(renderer) * 3.,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+                let offset = self.offset(state, bounds, renderer);
+                self.scroll_to(state, bounds, renderer, offset - dy);
+                shell.request_redraw(iced::window::RedrawRequest::NextFrame);
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    state.focused = false;
+                    return event::Status::Ignored;
+                };
+                state.focused = true;
+
+                if let Some(thumb) = self.scrollbar(state, bounds, renderer)
+                    && position.x >= thumb.x
+                {
+                    let grab = if thumb.contains(position) {
+                        position.y - thumb.y
+                    } else {
+                        thumb.height / 2.
+                    };
+                    state.scrollbar_grab = Some(grab);
+                } else if let Some(line) = self.line_at(state, bounds, renderer, position.y) {
+                    let anchor = state
+                        .selection
+                        .filter(|_| state.modifiers.shift())
+                        .map_or(line, |(anchor, _)| anchor);
+                    state.selection = Some((anchor, line));
+                    state.selecting = true;
+                }
+
+                shell.request_redraw(iced::window::RedrawRequest::NextFrame);
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(grab) = state.scrollbar_grab {
+                    let Some(thumb) = self.scrollbar(state, bounds, renderer) else {
+                        return event::Status::Ignored;
+                    };
+                    let track = (bounds.height - thumb.height).max(f32::EPSILON);
+                    let ratio = ((position.y - grab - bounds.y) / track).clamp(0., 1.);
+                    let max_offset = self.content_height(renderer) - bounds.height;
+
+                    self.scroll_to(state, bounds, renderer, ratio * max_offset);
+                } else if state.selecting
+                    && let Some(line) = self.line_at(state, bounds, renderer, position.y)
+                    && let Some((anchor, _)) = state.selection
+                {
+                    state.selection = Some((anchor, line));
+                } else {
+                    return event::Status::Ignored;
+                }
+
+                shell.request_redraw(iced::window::RedrawRequest::NextFrame);
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerLifted { .. })
+            | iced::Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.selecting = false;
+                state.scrollbar_grab = None;
+                event::Status::Ignored
+            }
+            iced::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+                event::Status::Ignored
+            }
+            iced::Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) if state.focused => {
+                match key.as_ref() {
+                    keyboard::Key::Character("c") if state.modifiers.command() => {
+                        let Some(text) = self.selected_text(state) else {
+                            return event::Status::Ignored;
+                        };
+                        clipboard.write(Kind::Standard, text);
+                    }
+                    keyboard::Key::Character("a") if state.modifiers.command() => {
+                        let visible = self.visible();
+                        state.selection = visible.first().copied().zip(visible.last().copied());
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                        state.selection = None;
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::End) => {
+                        state.follow = true;
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Home) => {
+                        self.scroll_to(state, bounds, renderer, 0.);
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                shell.request_redraw(iced::window::RedrawRequest::NextFrame);
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let Some(position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        match self.scrollbar(state, bounds, renderer) {
+            Some(thumb) if position.x >= thumb.x => mouse::Interaction::default(),
+            _ => mouse::Interaction::Text,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<LogView<'a, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(value: LogView<'a, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`LogView`] displaying the given [`LogBuffer`].
+pub fn log_view<'a, Theme, Renderer>(buffer: &'a LogBuffer) -> LogView<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    LogView::new(buffer)
+}
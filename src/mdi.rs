@@ -0,0 +1,1120 @@
+//! Internal windows, for multiple documents in a single OS window.
+//!
+//! The windows of an [`Mdi`] area are moved by dragging their title bars, resized by dragging their
+//! edges, and brought to the front by clicking them. The buttons of their title bars minimize,
+//! maximize and close them; a minimized window is shown as a title bar at the bottom of the area,
+//! restored by clicking it.
+//!
+//! The windows are kept in a [`Content`], updated with the [`Action`]s of the area. Their
+//! arrangement can be saved and restored as an [`Arrangement`], which supports serialization with
+//! serde if the feature `serde` is enabled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced::{Element, widget::text};
+//! use more_iced_aw::mdi::*;
+//!
+//! struct App {
+//!     windows: Content<usize>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Mdi(Action<usize>),
+//! }
+//!
+//! impl App {
+//!     fn update(&mut self, message: Message) {
+//!         let Message::Mdi(action) = message;
+//!         self.windows.update(action);
+//!     }
+//!
+//!     fn view(&self) -> Element<'_, Message> {
+//!         mdi(
+//!             &self.windows,
+//!             |id| Window::new(format!("Document {id}"), text(format!("The content of {id}"))),
+//!             Message::Mdi,
+//!         )
+//!         .into()
+//!     }
+//! }
+//!
+//! fn main() -> iced::Result {
+//!     let mut windows = Content::default();
+//!     windows.open(1, Placement::new(1, 20., 20., 300., 200.));
+//!     windows.open(2, Placement::new(2, 80., 60., 300., 200.));
+//!
+//!     iced::application("Documents", App::update, App::view)
+//!         .run_with(move || (App { windows: windows.clone() }, iced::Task::none()))
+//! }
+//! ```
+
+use iced::{
+    Background, Border, Color, Element, Length, Pixels, Point, Rectangle, Shadow, Size, Theme,
+    Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse::{self, Click, click},
+        overlay, renderer,
+        text::{self, LineHeight, Shaping, Text, Wrapping},
+        widget::{Operation, Tree, tree},
+    },
+    alignment, event, touch,
+};
+
+/// The height of the title bars.
+const TITLE_HEIGHT: f32 = 28.;
+/// The width of the buttons of the title bars.
+const BUTTON_WIDTH: f32 = 28.;
+/// The distance from an edge of a window under which it is resized.
+const EDGE: f32 = 5.;
+/// The minimum size of a window.
+const MIN_SIZE: Size = Size::new(120., 60.);
+/// The width of a minimized window.
+const MINIMIZED_WIDTH: f32 = 180.;
+
+/// The state of a window of a [`Content`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindowState {
+    /// The window has its own position and size.
+    #[default]
+    Normal,
+    /// The window is only shown as a title bar at the bottom of the area.
+    Minimized,
+    /// The window fills the area.
+    Maximized,
+}
+
+/// The placement of a window of a [`Content`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Placement<Id> {
+    /// The id of the window.
+    pub id: Id,
+    /// The horizontal position of the window in the area.
+    pub x: f32,
+    /// The vertical position of the window in the area.
+    pub y: f32,
+    /// The width of the window, when it is not maximized.
+    pub width: f32,
+    /// The height of the window, title bar included, when it is not maximized.
+    pub height: f32,
+    /// The state of the window.
+    pub state: WindowState,
+}
+
+impl<Id> Placement<Id> {
+    /// Creates a new [`Placement`] of a normal window.
+    pub fn new(id: Id, x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            id,
+            x,
+            y,
+            width,
+            height,
+            state: WindowState::Normal,
+        }
+    }
+
+    /// Returns the bounds of the window when it is not maximized nor minimized.
+    fn bounds(&self) -> Rectangle {
+        Rectangle::new(
+            Point::new(self.x, self.y),
+            Size::new(self.width, self.height),
+        )
+    }
+
+    /// Sets the bounds of the window, keeping its minimum size.
+    fn set_bounds(&mut self, bounds: Rectangle) {
+        self.x = bounds.x;
+        self.y = bounds.y;
+        self.width = bounds.width.max(MIN_SIZE.width);
+        self.height = bounds.height.max(MIN_SIZE.height);
+    }
+}
+
+/// The arrangement of the windows of a [`Content`], to save and restore it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Arrangement<Id> {
+    /// The windows, from the back to the front.
+    pub windows: Vec<Placement<Id>>,
+}
+
+impl<Id> Default for Arrangement<Id> {
+    fn default() -> Self {
+        Self {
+            windows: Vec::new(),
+        }
+    }
+}
+
+/// An interaction with an [`Mdi`] area, to be given to [`Content::update`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action<Id> {
+    /// A window was brought to the front.
+    Focus(Id),
+    /// A window was moved to the given position.
+    Move(Id, Point),
+    /// A window was resized to the given bounds.
+    Resize(Id, Rectangle),
+    /// A window was minimized.
+    Minimize(Id),
+    /// A window was maximized, or restored if it was.
+    ToggleMaximize(Id),
+    /// A minimized window was restored.
+    Restore(Id),
+    /// A window was closed.
+    Close(Id),
+}
+
+/// The windows of an [`Mdi`] area.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Content<Id> {
+    /// The windows, from the back to the front.
+    windows: Vec<Placement<Id>>,
+}
+
+impl<Id> Default for Content<Id> {
+    fn default() -> Self {
+        Self {
+            windows: Vec::new(),
+        }
+    }
+}
+
+impl<Id: Clone + PartialEq> Content<Id> {
+    /// Creates a new [`Content`] with the given arrangement.
+    pub fn new(arrangement: Arrangement<Id>) -> Self {
+        Self {
+            windows: arrangement.windows,
+        }
+    }
+
+    /// Returns the current arrangement of the windows, to save it.
+    pub fn arrangement(&self) -> Arrangement<Id> {
+        Arrangement {
+            windows: self.windows.clone(),
+        }
+    }
+
+    /// Returns the windows, from the back to the front.
+    pub fn windows(&self) -> &[Placement<Id>] {
+        &self.windows
+    }
+
+    /// Returns the window in front, which has the focus.
+    pub fn focused(&self) -> Option<&Id> {
+        self.windows.last().map(|window| &window.id)
+    }
+
+    /// Opens a window with the given placement, or brings it to the front if it is already open.
+    pub fn open(&mut self, id: Id, placement: Placement<Id>) {
+        if self.index(&id).is_some() {
+            self.focus(&id);
+        } else {
+            self.windows.push(Placement { id, ..placement });
+        }
+    }
+
+    /// Closes the given window.
+    pub fn close(&mut self, id: &Id) {
+        self.windows.retain(|window| window.id != *id);
+    }
+
+    /// Brings the given window to the front.
+    pub fn focus(&mut self, id: &Id) {
+        if let Some(index) = self.index(id) {
+            let window = self.windows.remove(index);
+            self.windows.push(window);
+        }
+    }
+
+    /// Updates the [`Content`] with an [`Action`] of the [`Mdi`] area.
+    pub fn update(&mut self, action: Action<Id>) {
+        match action {
+            Action::Focus(id) => self.focus(&id),
+            Action::Move(id, position) => {
+                if let Some(window) = self.get_mut(&id) {
+                    window.x = position.x;
+                    window.y = position.y;
+                }
+            }
+            Action::Resize(id, bounds) => {
+                if let Some(window) = self.get_mut(&id) {
+                    window.set_bounds(bounds);
+                }
+            }
+            Action::Minimize(id) => {
+                if let Some(window) = self.get_mut(&id) {
+                    window.state = WindowState::Minimized;
+                }
+            }
+            Action::ToggleMaximize(id) => {
+                if let Some(window) = self.get_mut(&id) {
+                    window.state = match window.state {
+                        WindowState::Maximized => WindowState::Normal,
+                        _ => WindowState::Maximized,
+                    };
+                }
+                self.focus(&id);
+            }
+            Action::Restore(id) => {
+                if let Some(window) = self.get_mut(&id) {
+                    window.state = WindowState::Normal;
+                }
+                self.focus(&id);
+            }
+            Action::Close(id) => self.close(&id),
+        }
+    }
+
+    fn index(&self, id: &Id) -> Option<usize> {
+        self.windows.iter().position(|window| window.id == *id)
+    }
+
+    fn get_mut(&mut self, id: &Id) -> Option<&mut Placement<Id>> {
+        self.windows.iter_mut().find(|window| window.id == *id)
+    }
+}
+
+/// A window of an [`Mdi`] area, with its title and content.
+pub struct Window<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    title: String,
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> Window<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Window`] with the given title and content.
+    pub fn new(
+        title: impl Into<String>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            content: content.into(),
+        }
+    }
+}
+
+/// The possible status of a window of an [`Mdi`] area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The window is in front, and has the focus.
+    Focused,
+    /// The window is behind another one.
+    Unfocused,
+}
+
+/// The appearance of the windows of an [`Mdi`] area.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the area, behind the windows.
+    pub background: Option<Background>,
+    /// The background of a window.
+    pub window: Background,
+    /// The border of a window.
+    pub border: Border,
+    /// The shadow of a window.
+    pub shadow: Shadow,
+    /// The background of the title bar of a window.
+    pub title_bar: Background,
+    /// The color of the title and of the buttons of a window.
+    pub title_color: Color,
+    /// The background of the hovered button of a title bar.
+    pub button_hovered: Background,
+    /// The color of the text of a window.
+    pub text_color: Color,
+}
+
+/// The theme catalog of an [`Mdi`] area.
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for an [`Mdi`] area.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of an [`Mdi`] area.
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let (title_bar, title_color) = match status {
+        Status::Focused => (palette.primary.strong.color, palette.primary.strong.text),
+        Status::Unfocused => (
+            palette.background.strong.color,
+            palette.background.strong.text,
+        ),
+    };
+
+    Style {
+        background: Some(palette.background.weak.color.into()),
+        window: palette.background.base.color.into(),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.,
+            radius: 4.into(),
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.25),
+            offset: Vector::new(0., 2.),
+            blur_radius: 8.,
+        },
+        title_bar: title_bar.into(),
+        title_color,
+        button_hovered: Color::WHITE.scale_alpha(0.2).into(),
+        text_color: palette.background.base.text,
+    }
+}
+
+/// The edges of a window being resized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Edges {
+    left: bool,
+    right: bool,
+    top: bool,
+    bottom: bool,
+}
+
+impl Edges {
+    /// Returns the edges of the given bounds near a position.
+    fn at(bounds: Rectangle, position: Point) -> Self {
+        Self {
+            left: (position.x - bounds.x).abs() <= EDGE,
+            right: (bounds.x + bounds.width - position.x).abs() <= EDGE,
+            top: (position.y - bounds.y).abs() <= EDGE,
+            bottom: (bounds.y + bounds.height - position.y).abs() <= EDGE,
+        }
+    }
+
+    fn any(self) -> bool {
+        self.left || self.right || self.top || self.bottom
+    }
+
+    fn interaction(self) -> mouse::Interaction {
+        match (self.left || self.right, self.top || self.bottom) {
+            (true, true) if self.left == self.top => mouse::Interaction::ResizingDiagonallyDown,
+            (true, true) => mouse::Interaction::ResizingDiagonallyUp,
+            (true, false) => mouse::Interaction::ResizingHorizontally,
+            (false, true) => mouse::Interaction::ResizingVertically,
+            (false, false) => mouse::Interaction::default(),
+        }
+    }
+
+    /// Returns the bounds resized by moving these edges by the given offset.
+    fn resize(self, bounds: Rectangle, offset: Vector) -> Rectangle {
+        let mut left = bounds.x;
+        let mut top = bounds.y;
+        let mut right = bounds.x + bounds.width;
+        let mut bottom = bounds.y + bounds.height;
+
+        if self.left {
+            left = (left + offset.x).min(right - MIN_SIZE.width);
+        }
+        if self.right {
+            right = (right + offset.x).max(left + MIN_SIZE.width);
+        }
+        if self.top {
+            top = (top + offset.y).min(bottom - MIN_SIZE.height);
+        }
+        if self.bottom {
+            bottom = (bottom + offset.y).max(top + MIN_SIZE.height);
+        }
+
+        Rectangle::new(Point::new(left, top), Size::new(right - left, bottom - top))
+    }
+}
+
+/// A drag of a window.
+#[derive(Debug, Clone, Copy)]
+enum Drag {
+    /// The window is moved, grabbed at the given offset from its top left corner.
+    Move { window: usize, grab: Vector },
+    /// The window is resized by some edges, from its bounds at the start of the drag.
+    Resize {
+        window: usize,
+        edges: Edges,
+        origin: Rectangle,
+        start: Point,
+    },
+}
+
+/// The state of an [`Mdi`] area.
+#[derive(Debug, Clone, Default)]
+struct State {
+    drag: Option<Drag>,
+    last_click: Option<Click>,
+}
+
+/// A button of a title bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Button {
+    Minimize,
+    Maximize,
+    Close,
+}
+
+impl Button {
+    const ALL: [Self; 3] = [Self::Minimize, Self::Maximize, Self::Close];
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Button::Minimize => "–",
+            Button::Maximize => "□",
+            Button::Close => "×",
+        }
+    }
+
+    /// Returns the bounds of the button in the title bar of a window.
+    fn bounds(self, frame: Rectangle) -> Rectangle {
+        let index = match self {
+            Button::Minimize => 3.,
+            Button::Maximize => 2.,
+            Button::Close => 1.,
+        };
+
+        Rectangle::new(
+            Point::new(frame.x + frame.width - BUTTON_WIDTH * index, frame.y),
+            Size::new(BUTTON_WIDTH, TITLE_HEIGHT),
+        )
+    }
+}
+
+/// An area of internal windows showing a [`Content`], created with [`mdi`].
+///
+/// Every interaction produces an [`Action`] that should be given back to [`Content::update`].
+pub struct Mdi<'a, Id, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    placements: &'a [Placement<Id>],
+    titles: Vec<String>,
+    contents: Vec<Element<'a, Message, Theme, Renderer>>,
+    on_action: Box<dyn Fn(Action<Id>) -> Message + 'a>,
+    width: Length,
+    height: Length,
+    text_size: Pixels,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Id, Message, Theme, Renderer> Mdi<'a, Id, Message, Theme, Renderer>
+where
+    Id: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`Mdi`] area showing the given [`Content`].
+    ///
+    /// `window` gives the title and content of a window from its id.
+    pub fn new(
+        content: &'a Content<Id>,
+        window: impl Fn(&Id) -> Window<'a, Message, Theme, Renderer>,
+        on_action: impl Fn(Action<Id>) -> Message + 'a,
+    ) -> Self {
+        let (titles, contents) = content
+            .windows
+            .iter()
+            .map(|placement| {
+                let Window { title, content } = window(&placement.id);
+                (title, content)
+            })
+            .unzip();
+
+        Self {
+            placements: &content.windows,
+            titles,
+            contents,
+            on_action: Box::new(on_action),
+            width: Length::Fill,
+            height: Length::Fill,
+            text_size: Pixels(14.),
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the width of the [`Mdi`] area.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Mdi`] area.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the text size of the titles.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = text_size.into();
+        self
+    }
+
+    /// Sets the font of the titles.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Mdi`] area.
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Mdi`] area.
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the frames of the windows, title bars included, relative to the area.
+    fn frames(&self, size: Size) -> Vec<Rectangle> {
+        let mut minimized = 0.;
+
+        self.placements
+            .iter()
+            .map(|placement| match placement.state {
+                WindowState::Normal => placement.bounds(),
+                WindowState::Maximized => Rectangle::with_size(size),
+                WindowState::Minimized => {
+                    let frame = Rectangle::new(
+                        Point::new(
+                            minimized * (MINIMIZED_WIDTH + 4.),
+                            size.height - TITLE_HEIGHT,
+                        ),
+                        Size::new(MINIMIZED_WIDTH, TITLE_HEIGHT),
+                    );
+                    minimized += 1.;
+                    frame
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the index of the window in front at the given position, relative to the area.
+    fn window_at(frames: &[Rectangle], position: Point) -> Option<usize> {
+        frames
+            .iter()
+            .rposition(|frame| frame.expand(EDGE).contains(position))
+    }
+
+    /// Produces an action of the window at the given index.
+    fn publish(
+        &self,
+        shell: &mut Shell<'_, Message>,
+        window: usize,
+        action: impl FnOnce(Id) -> Action<Id>,
+    ) {
+        shell.publish((self.on_action)(action(self.placements[window].id.clone())));
+    }
+}
+
+impl<Id, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Mdi<'_, Id, Message, Theme, Renderer>
+where
+    Id: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.contents.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.contents);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let size = limits.resolve(self.width, self.height, Size::ZERO);
+
+        let children = self
+            .frames(size)
+            .into_iter()
+            .zip(self.placements)
+            .zip(self.contents.iter().zip(&mut tree.children))
+            .map(|((frame, placement), (content, tree))| {
+                let body = if placement.state == WindowState::Minimized {
+                    Size::ZERO
+                } else {
+                    Size::new(frame.width, (frame.height - TITLE_HEIGHT).max(0.))
+                };
+
+                content
+                    .as_widget()
+                    .layout(tree, renderer, &Limits::new(Size::ZERO, body))
+                    .move_to(Point::new(frame.x, frame.y + TITLE_HEIGHT))
+            })
+            .collect();
+
+        Node::with_children(size, children)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let offset = Vector::new(bounds.x, bounds.y);
+        let frames = self.frames(bounds.size());
+        let hovered = cursor
+            .position_in(bounds)
+            .and_then(|position| Self::window_at(&frames, position));
+        let focused = self.placements.len().checked_sub(1);
+
+        if let Some(background) = theme.style(&self.class, Status::Unfocused).background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..Default::default()
+                },
+                background,
+            );
+        }
+
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        renderer.with_layer(clip, |renderer| {
+            for (index, ((frame, placement), (content, body))) in frames
+                .iter()
+                .zip(self.placements)
+                .zip(self.contents.iter().zip(layout.children()))
+                .enumerate()
+            {
+                let status = if Some(index) == focused {
+                    Status::Focused
+                } else {
+                    Status::Unfocused
+                };
+                let appearance = theme.style(&self.class, status);
+                let frame = *frame + offset;
+                let title_bar = Rectangle {
+                    height: TITLE_HEIGHT,
+                    ..frame
+                };
+
+                // Each window is drawn in its own layer, over the previous ones.
+                renderer.with_layer(clip, |renderer| {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: frame,
+                            border: appearance.border,
+                            shadow: appearance.shadow,
+                        },
+                        appearance.window,
+                    );
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: title_bar,
+                            border: Border {
+                                radius: appearance.border.radius,
+                                ..Border::default()
+                            },
+                            ..Default::default()
+                        },
+                        appearance.title_bar,
+                    );
+
+                    let font = self.font.unwrap_or_else(|| renderer.default_font());
+                    let text = |content: &str, size: Pixels| Text {
+                        content: content.to_owned(),
+                        bounds: Size::new(f32::INFINITY, TITLE_HEIGHT),
+                        size,
+                        line_height: LineHeight::default(),
+                        font,
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Center,
+                        shaping: Shaping::Advanced,
+                        wrapping: Wrapping::None,
+                    };
+
+                    let buttons = if placement.state == WindowState::Minimized {
+                        &Button::ALL[2..]
+                    } else {
+                        &Button::ALL[..]
+                    };
+                    let title_width =
+                        (frame.width - BUTTON_WIDTH * buttons.len() as f32 - 8.).max(0.);
+
+                    renderer.fill_text(
+                        text(&self.titles[index], self.text_size),
+                        Point::new(frame.x + 8., title_bar.center_y()),
+                        appearance.title_color,
+                        Rectangle {
+                            width: title_width,
+                            ..title_bar
+                        },
+                    );
+
+                    for button in buttons {
+                        let button_bounds = button.bounds(frame);
+                        if hovered == Some(index) && cursor.is_over(button_bounds) {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: button_bounds,
+                                    ..Default::default()
+                                },
+                                appearance.button_hovered,
+                            );
+                        }
+
+                        renderer.fill_text(
+                            Text {
+                                horizontal_alignment: alignment::Horizontal::Center,
+                                ..text(button.symbol(), self.text_size)
+                            },
+                            button_bounds.center(),
+                            appearance.title_color,
+                            button_bounds,
+                        );
+                    }
+
+                    if placement.state != WindowState::Minimized
+                        && let Some(body_clip) = body.bounds().intersection(&clip)
+                    {
+                        renderer.with_layer(body_clip, |renderer| {
+                            content.as_widget().draw(
+                                &tree.children[index],
+                                renderer,
+                                theme,
+                                &renderer::Style {
+                                    text_color: appearance.text_color,
+                                },
+                                body,
+                                if hovered == Some(index) {
+                                    cursor
+                                } else {
+                                    mouse::Cursor::Unavailable
+                                },
+                                &body_clip,
+                            );
+                        });
+                    }
+                });
+            }
+        });
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.contents
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((content, tree), layout)| {
+                    content
+                        .as_widget()
+                        .operate(tree, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let frames = self.frames(bounds.size());
+        let local = cursor.position_in(bounds);
+        let hovered = local.and_then(|position| Self::window_at(&frames, position));
+        let state = tree.state.downcast_mut::<State>();
+
+        // A drag in progress takes all the pointer events.
+        if let Some(drag) = state.drag {
+            match event {
+                iced::Event::Mouse(mouse::Event::CursorMoved { position })
+                | iced::Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                    let position = position - Vector::new(bounds.x, bounds.y);
+
+                    match drag {
+                        Drag::Move { window, grab } => {
+                            self.publish(shell, window, |id| Action::Move(id, position - grab));
+                        }
+                        Drag::Resize {
+                            window,
+                            edges,
+                            origin,
+                            start,
+                        } => {
+                            let resized = edges.resize(origin, position - start);
+                            self.publish(shell, window, |id| Action::Resize(id, resized));
+                        }
+                    }
+                    return event::Status::Captured;
+                }
+                iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | iced::Event::Touch(
+                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+                ) => {
+                    state.drag = None;
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        if let iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | iced::Event::Touch(touch::Event::FingerPressed { .. }) = event
+            && let (Some(window), Some(position)) = (hovered, local)
+        {
+            let frame = frames[window];
+            let placement = &self.placements[window];
+
+            if window + 1 != self.placements.len() {
+                self.publish(shell, window, Action::Focus);
+            }
+
+            let title_bar = Rectangle {
+                height: TITLE_HEIGHT,
+                ..frame
+            };
+            let edges = Edges::at(frame, position);
+
+            if placement.state == WindowState::Normal && edges.any() {
+                state.drag = Some(Drag::Resize {
+                    window,
+                    edges,
+                    origin: frame,
+                    start: position,
+                });
+                return event::Status::Captured;
+            }
+
+            if title_bar.contains(position) {
+                let button = Button::ALL
+                    .into_iter()
+                    .filter(|button| {
+                        placement.state != WindowState::Minimized || *button == Button::Close
+                    })
+                    .find(|button| button.bounds(frame).contains(position));
+
+                match (button, placement.state) {
+                    (Some(Button::Close), _) => self.publish(shell, window, Action::Close),
+                    (Some(Button::Minimize), _) => self.publish(shell, window, Action::Minimize),
+                    (Some(Button::Maximize), _) => {
+                        self.publish(shell, window, Action::ToggleMaximize);
+                    }
+                    (None, WindowState::Minimized) => self.publish(shell, window, Action::Restore),
+                    (None, window_state) => {
+                        let click = Click::new(position, mouse::Button::Left, state.last_click);
+                        state.last_click = Some(click);
+
+                        if matches!(click.kind(), click::Kind::Double) {
+                            self.publish(shell, window, Action::ToggleMaximize);
+                        } else if window_state == WindowState::Normal {
+                            state.drag = Some(Drag::Move {
+                                window,
+                                grab: position - frame.position(),
+                            });
+                        }
+                    }
+                }
+                return event::Status::Captured;
+            }
+        }
+
+        // Only the window under the cursor sees it, so the windows behind it are not hovered.
+        self.contents
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children().collect::<Vec<_>>())
+            .zip(self.placements)
+            .enumerate()
+            .rev()
+            .filter(|(_, (_, placement))| placement.state != WindowState::Minimized)
+            .map(|(index, (((content, tree), layout), _))| {
+                content.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    if hovered == Some(index) {
+                        cursor
+                    } else {
+                        mouse::Cursor::Unavailable
+                    },
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(
+                if hovered.is_some()
+                    && matches!(
+                        event,
+                        iced::Event::Mouse(mouse::Event::ButtonPressed(_))
+                            | iced::Event::Touch(touch::Event::FingerPressed { .. })
+                    )
+                {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                },
+                event::Status::merge,
+            )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let frames = self.frames(bounds.size());
+
+        match state.drag {
+            Some(Drag::Move { .. }) => return mouse::Interaction::Grabbing,
+            Some(Drag::Resize { edges, .. }) => return edges.interaction(),
+            None => {}
+        }
+
+        let Some(position) = cursor.position_in(bounds) else {
+            return mouse::Interaction::default();
+        };
+        let Some(window) = Self::window_at(&frames, position) else {
+            return mouse::Interaction::default();
+        };
+
+        let frame = frames[window];
+        let edges = Edges::at(frame, position);
+        if self.placements[window].state == WindowState::Normal && edges.any() {
+            return edges.interaction();
+        }
+
+        if position.y < frame.y + TITLE_HEIGHT {
+            return mouse::Interaction::default();
+        }
+
+        let (content, body) = self
+            .contents
+            .iter()
+            .zip(layout.children())
+            .nth(window)
+            .expect("Layout of the window");
+
+        content.as_widget().mouse_interaction(
+            &tree.children[window],
+            body,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children = self
+            .contents
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((content, tree), layout)| {
+                content
+                    .as_widget_mut()
+                    .overlay(tree, layout, renderer, translation)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Id, Message, Theme, Renderer> From<Mdi<'a, Id, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Id: Clone + 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(value: Mdi<'a, Id, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`Mdi`] area showing the given [`Content`].
+///
+/// `window` gives the title and content of a window from its id.
+pub fn mdi<'a, Id, Message, Theme, Renderer>(
+    content: &'a Content<Id>,
+    window: impl Fn(&Id) -> Window<'a, Message, Theme, Renderer>,
+    on_action: impl Fn(Action<Id>) -> Message + 'a,
+) -> Mdi<'a, Id, Message, Theme, Renderer>
+where
+    Id: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    Mdi::new(content, window, on_action)
+}
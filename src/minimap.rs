@@ -0,0 +1,518 @@
+//! A minimap of the content of a [`Scrollable`](iced::widget::Scrollable).
+//!
+//! The [`Minimap`] draws a scaled down overview of the content, usually a second view of the same
+//! content, with an indicator of the part shown by the scrollable. Dragging or clicking the
+//! indicator scrolls the content.
+//!
+//! The minimap and the scrollable are kept in sync by the application: the
+//! [`Viewport`] given by [`on_scroll`](iced::widget::Scrollable::on_scroll) is given to the
+//! minimap, and the offsets produced by the minimap are applied with
+//! [`scroll_to`](iced::widget::scrollable::scroll_to).
+//!
+//! ```
+//! use iced::widget::{column, row, scrollable, text};
+//! use iced::widget::scrollable::{AbsoluteOffset, Viewport};
+//! use more_iced_aw::minimap::minimap;
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Scrolled(Viewport),
+//!     Minimap(AbsoluteOffset),
+//! }
+//!
+//! struct App {
+//!     lines: Vec<String>,
+//!     viewport: Option<Viewport>,
+//! }
+//!
+//! impl App {
+//!     fn update(&mut self, message: Message) -> iced::Task<Message> {
+//!         match message {
+//!             Message::Scrolled(viewport) => {
+//!                 self.viewport = Some(viewport);
+//!                 iced::Task::none()
+//!             }
+//!             Message::Minimap(offset) => {
+//!                 scrollable::scroll_to(scrollable::Id::new("content"), offset)
+//!             }
+//!         }
+//!     }
+//!
+//!     fn content(&self) -> iced::Element<'_, Message> {
+//!         column(self.lines.iter().map(|line| text(line).into())).into()
+//!     }
+//!
+//!     fn view(&self) -> iced::Element<'_, Message> {
+//!         row![
+//!             scrollable(self.content())
+//!                 .id(scrollable::Id::new("content"))
+//!                 .on_scroll(Message::Scrolled),
+//!             minimap(self.content(), self.viewport, Message::Minimap).width(100),
+//!         ]
+//!         .into()
+//!     }
+//! }
+//! ```
+
+use iced::{
+    Background, Border, Color, Element, Length, Point, Rectangle, Size, Theme, Transformation,
+    advanced::{
+        Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, renderer,
+        widget::{Tree, tree},
+    },
+    event, touch,
+    widget::scrollable::{AbsoluteOffset, Viewport},
+};
+
+/// The possible status of a [`Minimap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Minimap`] can be interacted with.
+    Active,
+    /// The viewport indicator is being hovered.
+    Hovered,
+    /// The viewport indicator is being dragged.
+    Dragged,
+}
+
+/// The appearance of a [`Minimap`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the minimap.
+    pub background: Option<Background>,
+    /// The background of the viewport indicator.
+    pub viewport: Background,
+    /// The border of the viewport indicator.
+    pub viewport_border: Border,
+}
+
+/// The theme catalog of a [`Minimap`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Minimap`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Minimap`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let alpha = match status {
+        Status::Active => 0.15,
+        Status::Hovered => 0.25,
+        Status::Dragged => 0.35,
+    };
+
+    Style {
+        background: Some(palette.background.weak.color.into()),
+        viewport: Color {
+            a: alpha,
+            ..palette.primary.base.color
+        }
+        .into(),
+        viewport_border: Border {
+            color: palette.primary.base.color,
+            width: 1.,
+            radius: 0.into(),
+        },
+    }
+}
+
+/// The state of a [`Minimap`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    /// Where the indicator was grabbed, relatively to its top, while it is dragged.
+    grab: Option<f32>,
+}
+
+/// The geometry of a [`Minimap`] for a given [`Viewport`].
+#[derive(Debug, Clone, Copy)]
+struct Geometry {
+    /// The factor from the content to the minimap.
+    scale: f32,
+    /// How much the scaled content is scrolled up in the minimap.
+    shift: f32,
+    /// How much the indicator moves for each pixel of offset.
+    speed: f32,
+    /// The maximal vertical offset of the scrollable.
+    max_offset: f32,
+    /// The bounds of the viewport indicator.
+    indicator: Rectangle,
+}
+
+/// A scaled down overview of the content of a scrollable, created with [`minimap`].
+pub struct Minimap<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    overview: Element<'a, Message, Theme, Renderer>,
+    viewport: Option<Viewport>,
+    on_scroll: Box<dyn Fn(AbsoluteOffset) -> Message + 'a>,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Minimap<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    /// Creates a new [`Minimap`] drawing the given overview of the content of a scrollable with
+    /// the given [`Viewport`].
+    ///
+    /// The overview is laid out with the width of the content of the scrollable and scaled down
+    /// to the width of the minimap. `on_scroll` is called with the offset the scrollable should
+    /// scroll to when the indicator is dragged.
+    pub fn new(
+        overview: impl Into<Element<'a, Message, Theme, Renderer>>,
+        viewport: Option<Viewport>,
+        on_scroll: impl Fn(AbsoluteOffset) -> Message + 'a,
+    ) -> Self {
+        Self {
+            overview: overview.into(),
+            viewport,
+            on_scroll: Box::new(on_scroll),
+            width: Length::Fixed(120.),
+            height: Length::Fill,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the width of the [`Minimap`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Minimap`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`Minimap`].
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Minimap`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the geometry of the minimap with the given bounds.
+    fn geometry(&self, bounds: Rectangle, overview: Size) -> Option<Geometry> {
+        let viewport = self.viewport?;
+        let content = viewport.content_bounds();
+        let visible = viewport.bounds();
+
+        let scale = bounds.width / content.width.max(1.);
+        let scaled_height = overview.height.max(content.height) * scale;
+        let max_offset = (content.height - visible.height).max(0.);
+        let offset = viewport.absolute_offset().y.min(max_offset);
+
+        // When the scaled content overflows, it is scrolled proportionally to the offset.
+        let overflow = if max_offset > 0. {
+            (scaled_height - bounds.height).max(0.) / max_offset
+        } else {
+            0.
+        };
+
+        let shift = overflow * offset;
+
+        Some(Geometry {
+            scale,
+            shift,
+            speed: scale - overflow,
+            max_offset,
+            indicator: Rectangle {
+                x: bounds.x,
+                y: bounds.y + offset * scale - shift,
+                width: bounds.width,
+                height: (visible.height * scale).min(bounds.height),
+            },
+        })
+    }
+
+    /// Returns the offset moving the top of the indicator to the given height.
+    fn offset_at(&self, bounds: Rectangle, geometry: Geometry, y: f32) -> AbsoluteOffset {
+        AbsoluteOffset {
+            x: self
+                .viewport
+                .map_or(0., |viewport| viewport.absolute_offset().x),
+            y: ((y - bounds.y) / geometry.speed.max(f32::EPSILON)).clamp(0., geometry.max_offset),
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Minimap<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.overview)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.overview));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let size = limits.resolve(self.width, self.height, Size::ZERO);
+        let width = self
+            .viewport
+            .map_or(size.width, |viewport| viewport.content_bounds().width);
+
+        let overview = self.overview.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &Limits::new(Size::new(width, 0.), Size::new(width, f32::INFINITY)),
+        );
+
+        Node::with_children(size, vec![overview])
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let Some(overview) = layout.children().next() else {
+            return;
+        };
+        let geometry = self.geometry(bounds, overview.bounds().size());
+
+        let status = match geometry {
+            _ if state.grab.is_some() => Status::Dragged,
+            Some(geometry) if cursor.is_over(geometry.indicator) => Status::Hovered,
+            _ => Status::Active,
+        };
+        let appearance = theme.style(&self.class, status);
+
+        if let Some(background) = appearance.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..Default::default()
+                },
+                background,
+            );
+        }
+
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let (scale, shift) = geometry.map_or_else(
+            || (bounds.width / overview.bounds().width.max(1.), 0.),
+            |geometry| (geometry.scale, geometry.shift),
+        );
+        let transformation = Transformation::translate(bounds.x, bounds.y - shift)
+            * Transformation::scale(scale)
+            * Transformation::translate(-bounds.x, -bounds.y);
+
+        renderer.with_layer(clip, |renderer| {
+            renderer.with_transformation(transformation, |renderer| {
+                self.overview.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    overview,
+                    mouse::Cursor::Unavailable,
+                    &Rectangle::new(
+                        Point::new(bounds.x, bounds.y + shift / scale),
+                        bounds.size() * (1. / scale),
+                    ),
+                );
+            });
+
+            if let Some(geometry) = geometry {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: geometry.indicator,
+                        border: appearance.viewport_border,
+                        ..Default::default()
+                    },
+                    appearance.viewport,
+                );
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn iced::advanced::Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let Some(overview) = layout.children().next() else {
+            return event::Status::Ignored;
+        };
+        let Some(geometry) = self.geometry(bounds, overview.bounds().size()) else {
+            return event::Status::Ignored;
+        };
+
+        match event {
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                if geometry.indicator.contains(position) {
+                    state.grab = Some(position.y - geometry.indicator.y);
+                } else {
+                    let grab = geometry.indicator.height / 2.;
+                    state.grab = Some(grab);
+                    shell.publish((self.on_scroll)(self.offset_at(
+                        bounds,
+                        geometry,
+                        position.y - grab,
+                    )));
+                }
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::CursorMoved { position })
+            | iced::Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(grab) = state.grab else {
+                    return event::Status::Ignored;
+                };
+
+                shell.publish((self.on_scroll)(self.offset_at(
+                    bounds,
+                    geometry,
+                    position.y - grab,
+                )));
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerLifted { .. })
+            | iced::Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.grab.take().is_some() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            iced::Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let Some(viewport) = self.viewport else {
+                    return event::Status::Ignored;
+                };
+                let dy = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * 60.,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+                let offset = viewport.absolute_offset();
+
+                shell.publish((self.on_scroll)(AbsoluteOffset {
+                    x: offset.x,
+                    y: (offset.y - dy).clamp(0., geometry.max_offset),
+                }));
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.grab.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) && self.viewport.is_some() {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Minimap<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(value: Minimap<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`Minimap`] drawing the given overview of the content of a scrollable with the
+/// given [`Viewport`].
+pub fn minimap<'a, Message, Theme, Renderer>(
+    overview: impl Into<Element<'a, Message, Theme, Renderer>>,
+    viewport: Option<Viewport>,
+    on_scroll: impl Fn(AbsoluteOffset) -> Message + 'a,
+) -> Minimap<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    Minimap::new(overview, viewport, on_scroll)
+}
@@ -0,0 +1,512 @@
+//! An input for one-time codes and PINs, with one box per character.
+//!
+//! Typing a character moves to the next box, Backspace goes back to the previous one, and pasting
+//! a code fills the boxes from the focused one.
+
+use iced::{
+    Background, Border, Color, Element, Length, Pixels, Rectangle, Size, Theme,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, renderer,
+        text::{self, Text},
+        widget::{Id, Operation, Tree, operation, tree},
+    },
+    alignment, event, keyboard, touch,
+};
+
+/// The possible status of a box of an [`OtpInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The box can be interacted with.
+    Active,
+    /// The box is being hovered.
+    Hovered,
+    /// The box is focused: the next typed character goes in it.
+    Focused,
+}
+
+/// The appearance of a box of an [`OtpInput`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the box.
+    pub background: Background,
+    /// The border of the box.
+    pub border: Border,
+    /// The color of the character in the box.
+    pub text_color: Color,
+}
+
+/// The theme catalog of an [`OtpInput`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for an [`OtpInput`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of an [`OtpInput`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let (color, width) = match status {
+        Status::Active => (palette.background.strong.color, 1.),
+        Status::Hovered => (palette.background.base.text, 1.),
+        Status::Focused => (palette.primary.strong.color, 2.),
+    };
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            color,
+            width,
+            radius: 4.into(),
+        },
+        text_color: palette.background.base.text,
+    }
+}
+
+/// The state of an [`OtpInput`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    /// Whether the input is focused.
+    focused: bool,
+    /// The index of the focused box.
+    cursor: usize,
+    /// The keyboard modifiers currently pressed.
+    modifiers: keyboard::Modifiers,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn focus(&mut self) {
+        self.focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.focused = false;
+    }
+}
+
+/// An input with one box per character, created with [`otp_input`].
+///
+/// The value is given by the application, which updates it with the message of
+/// [`OtpInput::on_input`]. When all the boxes are filled, the message of
+/// [`OtpInput::on_complete`] is produced too.
+pub struct OtpInput<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    length: usize,
+    value: String,
+    on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    on_complete: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    numeric: bool,
+    masked: bool,
+    id: Option<Id>,
+    box_size: Size,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> OtpInput<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`OtpInput`] with the given number of boxes and value.
+    ///
+    /// Without [`OtpInput::on_input`], it is disabled.
+    pub fn new(length: usize, value: &str) -> Self {
+        Self {
+            length: length.max(1),
+            value: value.chars().take(length).collect(),
+            on_input: None,
+            on_complete: None,
+            numeric: true,
+            masked: false,
+            id: None,
+            box_size: Size::new(40., 48.),
+            spacing: 8.,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced with the new value when it changes.
+    pub fn on_input(mut self, on_input: impl Fn(String) -> Message + 'a) -> Self {
+        self.on_input = Some(Box::new(on_input));
+        self
+    }
+
+    /// Sets the message produced with the value when all the boxes are filled.
+    pub fn on_complete(mut self, on_complete: impl Fn(String) -> Message + 'a) -> Self {
+        self.on_complete = Some(Box::new(on_complete));
+        self
+    }
+
+    /// Sets whether only digits are accepted. It is the case by default.
+    pub fn numeric(mut self, numeric: bool) -> Self {
+        self.numeric = numeric;
+        self
+    }
+
+    /// Sets whether the characters are hidden behind dots.
+    pub fn masked(mut self, masked: bool) -> Self {
+        self.masked = masked;
+        self
+    }
+
+    /// Sets the [`Id`] of the [`OtpInput`], to focus it with an operation.
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the size of each box.
+    pub fn box_size(mut self, box_size: impl Into<Size>) -> Self {
+        self.box_size = box_size.into();
+        self
+    }
+
+    /// Sets the spacing between the boxes.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the characters.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the characters.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`OtpInput`].
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`OtpInput`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the bounds of the box at the given index.
+    fn box_bounds(&self, bounds: Rectangle, index: usize) -> Rectangle {
+        Rectangle {
+            x: bounds.x + index as f32 * (self.box_size.width + self.spacing),
+            y: bounds.y,
+            width: self.box_size.width,
+            height: self.box_size.height,
+        }
+    }
+
+    /// Returns whether a character can be typed in the boxes.
+    fn accepts(&self, c: char) -> bool {
+        if self.numeric {
+            c.is_ascii_digit()
+        } else {
+            !c.is_control() && !c.is_whitespace()
+        }
+    }
+
+    /// Writes the characters from the box at the cursor, and returns the new value and cursor.
+    fn write(&self, cursor: usize, input: impl Iterator<Item = char>) -> (String, usize) {
+        let mut chars: Vec<char> = self.value.chars().collect();
+        let mut cursor = cursor.min(chars.len());
+
+        for c in input.filter(|c| self.accepts(*c)) {
+            if cursor >= self.length {
+                break;
+            }
+            if cursor < chars.len() {
+                chars[cursor] = c;
+            } else {
+                chars.push(c);
+            }
+            cursor += 1;
+        }
+
+        (chars.into_iter().collect(), cursor.min(self.length - 1))
+    }
+
+    /// Produces the messages of a new value.
+    fn publish(&self, value: String, shell: &mut Shell<'_, Message>) {
+        if value == self.value {
+            return;
+        }
+
+        let complete = value.chars().count() == self.length;
+
+        if let Some(on_input) = &self.on_input {
+            shell.publish(on_input(value.clone()));
+        }
+        if complete && let Some(on_complete) = &self.on_complete {
+            shell.publish(on_complete(value));
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for OtpInput<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let count = self.length as f32;
+        let width = count * self.box_size.width + (count - 1.) * self.spacing;
+
+        Node::new(limits.resolve(
+            Length::Shrink,
+            Length::Shrink,
+            Size::new(width, self.box_size.height),
+        ))
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        operation.focusable(state, self.id.as_ref());
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let filled = self.value.chars().count();
+        state.cursor = state.cursor.min(filled).min(self.length - 1);
+
+        match event {
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if self.on_input.is_none() || !cursor.is_over(bounds) {
+                    state.focused = false;
+                    return event::Status::Ignored;
+                }
+
+                let clicked = (0..self.length)
+                    .find(|&i| cursor.is_over(self.box_bounds(bounds, i)))
+                    .unwrap_or(filled);
+
+                state.focused = true;
+                state.cursor = clicked.min(filled).min(self.length - 1);
+                shell.request_redraw(iced::window::RedrawRequest::NextFrame);
+                event::Status::Captured
+            }
+            iced::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+                event::Status::Ignored
+            }
+            iced::Event::Keyboard(keyboard::Event::KeyPressed { key, text, .. })
+                if state.focused =>
+            {
+                let mut chars: Vec<char> = self.value.chars().collect();
+
+                match key.as_ref() {
+                    keyboard::Key::Character("v") if state.modifiers.command() => {
+                        if let Some(pasted) =
+                            clipboard.read(iced::advanced::clipboard::Kind::Standard)
+                        {
+                            let (value, next) = self.write(state.cursor, pasted.chars());
+                            state.cursor = next;
+                            self.publish(value, shell);
+                        }
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Backspace) => {
+                        if state.cursor < chars.len() {
+                            chars.remove(state.cursor);
+                        } else if state.cursor > 0 {
+                            state.cursor -= 1;
+                            chars.remove(state.cursor);
+                        }
+                        self.publish(chars.into_iter().collect(), shell);
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowLeft) => {
+                        state.cursor = state.cursor.saturating_sub(1);
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowRight) => {
+                        state.cursor = (state.cursor + 1).min(filled).min(self.length - 1);
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                        state.focused = false;
+                    }
+                    _ => {
+                        let Some(text) = text.filter(|_| !state.modifiers.command()) else {
+                            return event::Status::Ignored;
+                        };
+
+                        let (value, next) = self.write(state.cursor, text.chars());
+                        state.cursor = next;
+                        self.publish(value, shell);
+                    }
+                }
+
+                shell.request_redraw(iced::window::RedrawRequest::NextFrame);
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let mut chars = self.value.chars();
+
+        for i in 0..self.length {
+            let box_bounds = self.box_bounds(bounds, i);
+            let status = if state.focused && state.cursor == i {
+                Status::Focused
+            } else if self.on_input.is_some() && cursor.is_over(box_bounds) {
+                Status::Hovered
+            } else {
+                Status::Active
+            };
+            let style = theme.style(&self.class, status);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: box_bounds,
+                    border: style.border,
+                    ..Default::default()
+                },
+                style.background,
+            );
+
+            let Some(c) = chars.next() else {
+                continue;
+            };
+
+            renderer.fill_text(
+                Text {
+                    content: if self.masked { '•' } else { c }.to_string(),
+                    bounds: box_bounds.size(),
+                    size,
+                    line_height: text::LineHeight::default(),
+                    font,
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                },
+                box_bounds.center(),
+                style.text_color,
+                *viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_input.is_some() && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<OtpInput<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(value: OtpInput<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`OtpInput`] with the given number of boxes and value.
+pub fn otp_input<'a, Message, Theme, Renderer>(
+    length: usize,
+    value: &str,
+) -> OtpInput<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    OtpInput::new(length, value)
+}
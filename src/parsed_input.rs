@@ -7,8 +7,8 @@
 //!
 //! # Example
 //!
-//! ```
-//! use iced::{self, Element, widget::{text_input, row, text, column}, color, alignment::Vertical};
+//! ```no_run
+//! use iced::{self, Element, widget::{text_input, text, column}, color};
 //! use more_iced_aw::parsed_input::*;
 //!
 //! #[derive(Default)]
@@ -40,22 +40,13 @@
 //!     fn view(&self) -> Element<'_, Message> {
 //!         let input = ParsedInput::new("Type an integer", &self.content)
 //!         .style(color_on_err(text_input::default, color!(0xff0000, 0.2)))
+//!         .allowed_chars(numeric())
 //!         .on_input(Message::Input)
 //!         .on_paste(Message::Paste)
-//!         .on_submit(Message::Submit);
-//!         
-//!         let row = row![input]
-//!         .push_maybe(
-//!             self
-//!             .content
-//!             .get_error()
-//!             .as_ref()
-//!             .map(|err| text(err.to_string()))
-//!          )
-//!         .align_y(Vertical::Center)
-//!         .spacing(10);
+//!         .on_submit(Message::Submit)
+//!         .with_error();
 //!         
-//!         column![row, text(&self.msg)].spacing(20).into()
+//!         column![input, text(&self.msg)].spacing(20).into()
 //!     }
 //! }
 //!
@@ -65,23 +56,56 @@
 //! ```
 
 use std::{
-    borrow::Borrow,
+    borrow::{Borrow, Cow},
+    cell::Cell,
     ops::{Deref, DerefMut},
+    rc::Rc,
     str::FromStr,
 };
 
 use iced::{
     Color, Length, Padding, Pixels,
-    advanced::{Shell, Widget, graphics::core::Element, text},
-    alignment,
+    advanced::{Shell, Widget, graphics::core::Element, text, widget},
+    alignment, event, keyboard,
     widget::{
         TextInput,
-        text_input::{self, Icon, Id, Status, Style, StyleFn},
+        text_input::{self, Icon, Id, Status, Style, StyleFn, cursor},
     },
 };
 
 use crate::helpers::filter_background;
 
+mod color;
+mod address;
+mod currency;
+mod duration;
+mod float;
+#[cfg(feature = "iced_014")]
+pub mod iced_014;
+mod path;
+mod pattern;
+#[cfg(feature = "test-utils")]
+mod simulate;
+mod status;
+mod step;
+mod task;
+mod vector;
+mod with_error;
+pub use address::*;
+pub use color::*;
+pub use currency::*;
+pub use duration::*;
+pub use float::*;
+pub use path::*;
+pub use pattern::*;
+#[cfg(feature = "test-utils")]
+pub use simulate::*;
+pub use status::*;
+pub use step::*;
+pub use task::*;
+pub use vector::*;
+pub use with_error::*;
+
 /// The content of the [`ParsedInput`] for a value of type `T` and parsing errors of type `E`.
 ///
 /// It implements [`Deref`] into `T`, which allows you to access the inner value.
@@ -93,12 +117,14 @@ use crate::helpers::filter_background;
 /// For a [`ParsedInput`] build on this [`Content`] to work as intendeed, 
 /// it is mendatory that for all `value: T`,
 /// `value.to_string().parse() == Ok(value)`.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Content<T, E> {
     value: T,
     string: String,
     error: Option<E>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    version: u64,
 }
 
 impl<T, E> Content<T, E> {
@@ -112,9 +138,19 @@ impl<T, E> Content<T, E> {
             value,
             string,
             error: None,
+            version: 0,
         }
     }
 
+    /// Returns the version of the content.
+    ///
+    /// It is incremented every time the content is modified, which allows to cheaply
+    /// detect changes without comparing values.
+    /// It is not taken into account when comparing contents, and is not serialized.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
     /// Mutably borrows the inner value (`T`), to then be able to modify it.
     ///
     /// The returned [`BorrowMut`] implements [`DerefMut<Target: T>`]. 
@@ -141,6 +177,7 @@ impl<T, E> Content<T, E> {
     /// 
     /// See this [example](crate::parsed_input) for recommended usage.
     pub fn update(&mut self, parsed: Parsed<T, E>) {
+        self.version = self.version.wrapping_add(1);
         self.string = parsed.string;
         match parsed.parsed {
             Ok(val) => {
@@ -151,6 +188,20 @@ impl<T, E> Content<T, E> {
         }
     }
 
+    /// Re-renders the string from the value, if the string is valid.
+    ///
+    /// This is usefull to normalize what the user typed once it is committed
+    /// (for example on submit), see [`Scientific`] and [`Fixed`] to control how floats are rendered.
+    pub fn commit(&mut self)
+    where
+        T: ToString,
+    {
+        if self.is_valid() {
+            self.version = self.version.wrapping_add(1);
+            self.string = self.value.to_string();
+        }
+    }
+
     /// Consumes the content and returns the value, 
     /// even if the text is not representative of that value.
     pub fn into_value(self) -> T {
@@ -227,14 +278,48 @@ where
     Renderer: iced::advanced::text::Renderer,
     Theme: text_input::Catalog,
 {
-    content: &'a Content<T, E>,
+    logic: Logic<'a, T, E, Message>,
     text_input: TextInput<'a, InnerMessage, Theme, Renderer>,
+    id: Option<widget::Id>,
+}
 
-    on_input: Option<Box<dyn Fn(Parsed<T, E>) -> Message + 'a>>,
-    on_paste: Option<Box<dyn Fn(Parsed<T, E>) -> Message + 'a>>,
+/// The parsing, filtering, grouping and stepping of a [`ParsedInput`], independent of
+/// the renderer and of the version of iced.
+struct Logic<'a, T, E, Message> {
+    content: &'a Content<T, E>,
+
+    on_input: Option<OnParsed<'a, T, E, Message>>,
+    on_paste: Option<OnParsed<'a, T, E, Message>>,
     on_submit: Option<Message>,
+    on_submit_with: Option<OnParsed<'a, T, E, Message>>,
+
+    allowed_chars: Option<Box<dyn Fn(char) -> bool + 'a>>,
+    accept: Option<Predicate<'a>>,
+    jump_on: Option<char>,
+    group_separator: Option<char>,
+    max_length: Option<usize>,
+    valid: Rc<Cell<bool>>,
+    stepper: Option<Stepper<'a, T, E>>,
+    step_multipliers: StepMultipliers,
+    validator: Option<Validator<'a, T, E>>,
+    describe_error: Option<Describer<'a, E>>,
 }
 
+/// A boxed function producing a message from a [`Parsed`].
+type OnParsed<'a, T, E, Message> = Box<dyn Fn(Parsed<T, E>) -> Message + 'a>;
+
+/// A boxed predicate on a text.
+type Predicate<'a> = Box<dyn Fn(&str) -> bool + 'a>;
+
+/// A boxed function stepping a value by an amount.
+type Stepper<'a, T, E> = Box<dyn Fn(&T, f64) -> Parsed<T, E> + 'a>;
+
+/// A boxed function validating a parsed value.
+type Validator<'a, T, E> = Box<dyn Fn(&T) -> Result<(), E> + 'a>;
+
+/// A boxed function describing an error.
+type Describer<'a, E> = Box<dyn Fn(&E) -> String + 'a>;
+
 impl<'a, T, E, Message, Theme, Renderer> ParsedInput<'a, T, E, Message, Theme, Renderer>
 where
     T: Clone,
@@ -245,17 +330,19 @@ where
     /// Creates a new [`ParsedInput`] from a [`Content`].
     pub fn new(placeholder: &str, content: &'a Content<T, E>) -> Self {
         Self {
-            content,
+            logic: Logic::new(content),
             text_input: TextInput::new(placeholder, &content.string),
-            on_input: None,
-            on_paste: None,
-            on_submit: None,
+            id: None,
         }
     }
 
     /// Sets the [`Id`] of the underlying [`TextInput`].
+    ///
+    /// It is also the [`Id`] to give to [`parse_status`].
     pub fn id(self, id: impl Into<Id>) -> Self {
+        let id = id.into();
         Self {
+            id: Some(id.clone().into()),
             text_input: self.text_input.id(id),
             ..self
         }
@@ -272,12 +359,10 @@ where
     /// Sets the message that should be produced when some text is typed into the [`ParsedInput`].
     ///
     /// If this method is not called, the [`ParsedInput`] will be disabled.
-    pub fn on_input(self, on_input: impl Fn(Parsed<T, E>) -> Message + 'a) -> Self {
-        Self {
-            text_input: self.text_input.on_input(InnerMessage::Input),
-            on_input: Some(Box::new(on_input)),
-            ..self
-        }
+    pub fn on_input(mut self, on_input: impl Fn(Parsed<T, E>) -> Message + 'a) -> Self {
+        self.text_input = self.text_input.on_input(InnerMessage::Input);
+        self.logic.on_input = Some(Box::new(on_input));
+        self
     }
 
     /// Sets the message that should be produced when some text is typed into the [`ParsedInput`], if [`Some`].
@@ -294,7 +379,7 @@ where
     /// focused and the enter key is pressed.
     pub fn on_submit(mut self, on_submit: Message) -> Self {
         self.text_input = self.text_input.on_submit(InnerMessage::Submit);
-        self.on_submit = Some(on_submit);
+        self.logic.on_submit = Some(on_submit);
         self
     }
 
@@ -311,7 +396,7 @@ where
     /// the [`ParsedInput`].
     pub fn on_paste(mut self, on_paste: impl Fn(Parsed<T, E>) -> Message + 'a) -> Self {
         self.text_input = self.text_input.on_paste(InnerMessage::Paste);
-        self.on_paste = Some(Box::new(on_paste));
+        self.logic.on_paste = Some(Box::new(on_paste));
         self
     }
 
@@ -324,6 +409,137 @@ where
         }
     }
 
+    /// Same as [`on_input`](Self::on_input), but the message also receives the value
+    /// held by the [`Content`] before the edit, that is the last valid value.
+    ///
+    /// This allows to implement undo or delta based updates without storing a copy of the value.
+    pub fn on_input_with_previous(self, on_input: impl Fn(T, Parsed<T, E>) -> Message + 'a) -> Self
+    where
+        T: 'a,
+    {
+        let previous = self.logic.content.value.clone();
+        self.on_input(move |parsed| on_input(previous.clone(), parsed))
+    }
+
+    /// Same as [`on_paste`](Self::on_paste), but the message also receives the value
+    /// held by the [`Content`] before the edit, that is the last valid value.
+    pub fn on_paste_with_previous(self, on_paste: impl Fn(T, Parsed<T, E>) -> Message + 'a) -> Self
+    where
+        T: 'a,
+    {
+        let previous = self.logic.content.value.clone();
+        self.on_paste(move |parsed| on_paste(previous.clone(), parsed))
+    }
+
+    /// Sets the message that should be produced when the [`ParsedInput`] is
+    /// focused and the enter key is pressed, built from the value currently held by the [`Content`]
+    /// (the last valid value) and the [`Parsed`] of the submitted text.
+    ///
+    /// It replaces the message set by [`on_submit`](Self::on_submit).
+    pub fn on_submit_with_previous(
+        mut self,
+        on_submit: impl Fn(T, Parsed<T, E>) -> Message + 'a,
+    ) -> Self
+    where
+        T: 'a,
+    {
+        let previous = self.logic.content.value.clone();
+        self.text_input = self.text_input.on_submit(InnerMessage::Submit);
+        self.logic.on_submit_with = Some(Box::new(move |parsed| on_submit(previous.clone(), parsed)));
+        self
+    }
+
+    /// Sets a filter on the characters that can be typed or pasted into the [`ParsedInput`].
+    ///
+    /// Characters for which `filter` returns `false` are removed from the text before it is parsed,
+    /// so they never reach the [`Content`]. If nothing is left to change, no message is produced.
+    ///
+    /// See [`numeric`], [`decimal`] and [`hex`] for common filters.
+    pub fn allowed_chars(mut self, filter: impl Fn(char) -> bool + 'a) -> Self {
+        self.logic.allowed_chars = Some(Box::new(filter));
+        self
+    }
+
+    /// Sets a predicate on the whole text resulting from an edit.
+    ///
+    /// If `accept` returns `false`, the edit is rejected and no message is produced.
+    /// It is checked after the filter of [`allowed_chars`](Self::allowed_chars).
+    pub fn accept(mut self, accept: impl Fn(&str) -> bool + 'a) -> Self {
+        self.logic.accept = Some(Box::new(accept));
+        self
+    }
+
+    /// Sets a separator used to navigate between the parts of the text.
+    ///
+    /// When `separator` is typed and there is a `separator` after the cursor, the cursor
+    /// jumps after it instead of inserting a new one
+    /// (e.g. typing `.` in an IPv4 address moves to the next octet).
+    pub fn jump_on(mut self, separator: char) -> Self {
+        self.logic.jump_on = Some(separator);
+        self
+    }
+
+    /// Displays the digits of the integer part in groups of three, separated by `separator`,
+    /// while the user types (e.g. `1,234,567.89`).
+    ///
+    /// The separators are removed before parsing, but are kept in the string of the [`Content`].
+    /// The cursor stays next to the digit it was next to when the text is regrouped.
+    pub fn group_digits(mut self, separator: char) -> Self {
+        self.logic.group_separator = Some(separator);
+        self
+    }
+
+    /// Sets the maximum number of characters of the [`ParsedInput`].
+    ///
+    /// Typing or pasting is blocked once the limit is reached. If the string of the
+    /// [`Content`] is longer (for example after being set programmatically), the
+    /// [`ParsedInput`] is styled as invalid until it is shortened.
+    /// See [`ParsedInputWithError::counter`] to display the number of characters.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.logic.max_length = Some(max_length);
+        self
+    }
+
+    /// Allows to change the value with the arrow keys when the [`ParsedInput`] is focused.
+    ///
+    /// Pressing up adds `step` to the value and pressing down substracts it.
+    /// The step is scaled when modifier keys are held, see [`step_multipliers`](Self::step_multipliers).
+    /// The new value is sent through the [`on_input`](Self::on_input) message.
+    pub fn step(mut self, step: f64) -> Self
+    where
+        T: Step + ToString + 'a,
+    {
+        self.logic.stepper = Some(Box::new(move |value: &T, factor| {
+            Parsed::from_value(value.step(step * factor))
+        }));
+        self
+    }
+
+    /// Sets the multipliers applied to the [`step`](Self::step) when
+    /// Shift (`large`) or Ctrl/Alt (`fine`) is held.
+    pub fn step_multipliers(mut self, large: f64, fine: f64) -> Self {
+        self.logic.step_multipliers = StepMultipliers { large, fine };
+        self
+    }
+
+    /// Adds a validation step after the text is successfully parsed.
+    ///
+    /// If `validator` returns an error, the produced [`Parsed`] holds that error
+    /// instead of the parsed value.
+    pub fn validate(mut self, validator: impl Fn(&T) -> Result<(), E> + 'a) -> Self {
+        self.logic.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Sets how the error of the [`Content`] is described in the [`ParseStatus`]
+    /// exposed to accessibility tools.
+    ///
+    /// If this method is not called, the [`ParseStatus`] only tells whether the input is valid.
+    pub fn describe_error(mut self, describe_error: impl Fn(&E) -> String + 'a) -> Self {
+        self.logic.describe_error = Some(Box::new(describe_error));
+        self
+    }
+
     /// Sets the [`Font`] of the [`ParsedInput`].
     ///
     /// [`Font`]: text::Renderer::Font
@@ -377,11 +593,8 @@ where
     where
         Theme::Class<'a>: From<StyleFn<'a, Theme>>,
     {
-        self.text_input = if self.content.is_valid() {
-            self.text_input.style(move |t, s| style(t, s, true))
-        } else {
-            self.text_input.style(move |t, s| style(t, s, false))
-        };
+        let valid = self.logic.valid.clone();
+        self.text_input = self.text_input.style(move |t, s| style(t, s, valid.get()));
         self
     }
 
@@ -442,6 +655,8 @@ where
         cursor: iced::advanced::mouse::Cursor,
         viewport: &iced::Rectangle,
     ) {
+        self.logic.valid.set(self.logic.is_valid());
+
         <TextInput<'_, _, _, _> as Widget<_, _, _>>::draw(
             &self.text_input,
             tree,
@@ -462,6 +677,18 @@ where
         operation: &mut dyn iced::advanced::widget::Operation,
     ) {
         self.text_input.operate(state, layout, renderer, operation);
+
+        let mut status = ParseStatus {
+            valid: self.logic.content.is_valid(),
+            error: self
+                .logic
+                .content
+                .get_error()
+                .as_ref()
+                .zip(self.logic.describe_error.as_ref())
+                .map(|(error, describe)| describe(error)),
+        };
+        operation.custom(&mut status, self.id.as_ref());
     }
 
     fn on_event(
@@ -475,6 +702,17 @@ where
         shell: &mut iced::advanced::Shell<'_, Message>,
         viewport: &iced::Rectangle,
     ) -> iced::advanced::graphics::core::event::Status {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!("parsed_input_event", ?event, id = ?self.id).entered();
+
+        if self.jump(state, &event) || self.step_value(state, &event, shell) {
+            #[cfg(feature = "tracing")]
+            tracing::debug!("event captured by a jump or a step");
+
+            return event::Status::Captured;
+        }
+
+        // An empty vector does not allocate, only the events producing messages do.
         let mut messages = Vec::new();
         let mut sub_shell = Shell::new(&mut messages);
         let status = self.text_input.on_event(
@@ -488,23 +726,42 @@ where
             viewport,
         );
 
-        shell.merge(sub_shell, |inner| match inner {
-            InnerMessage::Input(str) => self
-                .on_input
-                .as_ref()
-                .map(|f| f(Parsed::from_string(&str)))
-                .expect("Should have on_input msg"),
-            InnerMessage::Paste(str) => self
-                .on_paste
-                .as_ref()
-                .map(|f| f(Parsed::from_string(&str)))
-                .expect("Should have on_paste msg"),
-            InnerMessage::Submit => self
-                .on_submit
-                .as_ref()
-                .cloned()
-                .expect("Should have submit msg"),
-        });
+        let redraw_request = sub_shell.redraw_request();
+        let is_layout_invalid = sub_shell.is_layout_invalid();
+        let are_widgets_invalid = sub_shell.are_widgets_invalid();
+
+        for inner in messages {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(?inner, "inner message");
+
+            let edited = match &inner {
+                InnerMessage::Input(str) | InnerMessage::Paste(str) => {
+                    self.logic.group_separator.and(Some(str.clone()))
+                }
+                InnerMessage::Submit => None,
+            };
+
+            if let Some(message) = self.logic.convert(inner) {
+                shell.publish(message);
+
+                if let Some(edited) = edited {
+                    self.regroup_cursor(state, &edited);
+                }
+            } else {
+                #[cfg(feature = "tracing")]
+                tracing::debug!("inner message dropped");
+            }
+        }
+
+        if let Some(redraw_request) = redraw_request {
+            shell.request_redraw(redraw_request);
+        }
+        if is_layout_invalid {
+            shell.invalidate_layout();
+        }
+        if are_widgets_invalid {
+            shell.invalidate_widgets();
+        }
 
         status
     }
@@ -526,6 +783,312 @@ where
     }
 }
 
+impl<'a, T: FromStr<Err = E>, E, Message: Clone, Theme, Renderer>
+    ParsedInput<'a, T, E, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::text::Renderer,
+    Theme: text_input::Catalog,
+{
+    /// Moves the cursor after the next separator if the event is the typing of the separator.
+    ///
+    /// Returns true if the cursor jumped.
+    fn jump(&self, tree: &mut iced::advanced::widget::Tree, event: &iced::Event) -> bool {
+        let Some(separator) = self.logic.jump_on else {
+            return false;
+        };
+
+        let iced::Event::Keyboard(keyboard::Event::KeyPressed {
+            text: Some(text), ..
+        }) = event
+        else {
+            return false;
+        };
+
+        if text.chars().ne([separator]) {
+            return false;
+        }
+
+        let state = tree
+            .state
+            .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+
+        if !state.is_focused() {
+            return false;
+        }
+
+        let value = text_input::Value::new(&self.logic.content.string);
+        let cursor::State::Index(index) = state.cursor().state(&value) else {
+            return false;
+        };
+
+        match self.logic.jump_target(separator, index) {
+            Some(target) => {
+                state.move_cursor_to(target);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Steps the value if the event is the press of an arrow key.
+    ///
+    /// Returns true if the value was stepped.
+    fn step_value(
+        &self,
+        tree: &mut iced::advanced::widget::Tree,
+        event: &iced::Event,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        if self.logic.stepper.is_none() || self.logic.on_input.is_none() {
+            return false;
+        }
+
+        let iced::Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key),
+            modifiers,
+            ..
+        }) = event
+        else {
+            return false;
+        };
+
+        let direction = match key {
+            keyboard::key::Named::ArrowUp => 1.,
+            keyboard::key::Named::ArrowDown => -1.,
+            _ => return false,
+        };
+
+        let state = tree
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>();
+
+        if !state.is_focused() {
+            return false;
+        }
+
+        let fine = modifiers.control() || modifiers.alt();
+        match self.logic.stepped(direction, modifiers.shift(), fine) {
+            Some(message) => {
+                shell.publish(message);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves the cursor after the text `edited` is regrouped, so that it stays after the same digit.
+    fn regroup_cursor(&self, tree: &mut iced::advanced::widget::Tree, edited: &str) {
+        let Some(separator) = self.logic.group_separator else {
+            return;
+        };
+
+        let state = tree
+            .state
+            .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+
+        let cursor::State::Index(index) = state.cursor().state(&text_input::Value::new(edited))
+        else {
+            return;
+        };
+
+        state.move_cursor_to(self.logic.regrouped_index(separator, edited, index));
+    }
+}
+
+impl<'a, T, E, Message> Logic<'a, T, E, Message> {
+    /// Creates the logic of a [`ParsedInput`] of the given [`Content`], doing nothing more than parsing.
+    fn new(content: &'a Content<T, E>) -> Self {
+        Self {
+            content,
+            on_input: None,
+            on_paste: None,
+            on_submit: None,
+            on_submit_with: None,
+            allowed_chars: None,
+            accept: None,
+            jump_on: None,
+            group_separator: None,
+            max_length: None,
+            valid: Rc::new(Cell::new(content.is_valid())),
+            stepper: None,
+            step_multipliers: StepMultipliers::default(),
+            validator: None,
+            describe_error: None,
+        }
+    }
+}
+
+impl<'a, T: FromStr<Err = E>, E, Message: Clone> Logic<'a, T, E, Message> {
+    /// Converts a message of the inner [`TextInput`] into a message of the [`ParsedInput`].
+    ///
+    /// Returns [`None`] if the message has to be dropped.
+    fn convert(&self, inner: InnerMessage) -> Option<Message> {
+        match inner {
+            InnerMessage::Input(str) => {
+                let str = self.filter(self.ungroup_owned(str))?;
+                self.on_input.as_ref().map(|f| f(self.parse(&str)))
+            }
+            InnerMessage::Paste(str) => {
+                let str = self.filter(self.ungroup_owned(str))?;
+                self.on_paste.as_ref().map(|f| f(self.parse(&str)))
+            }
+            InnerMessage::Submit => match &self.on_submit_with {
+                Some(f) => Some(f(self.parse(&self.content.string))),
+                None => self.on_submit.clone(),
+            },
+        }
+    }
+
+    /// Returns where the cursor at `index` jumps when `separator` is typed, if there is a
+    /// `separator` after it.
+    fn jump_target(&self, separator: char, index: usize) -> Option<usize> {
+        self.content
+            .string
+            .chars()
+            .skip(index)
+            .position(|c| c == separator)
+            .map(|offset| index + offset + 1)
+    }
+
+    /// Returns the message of a step of the value in the given `direction` (`1.` or `-1.`),
+    /// scaled by the `large` or `fine` multiplier.
+    fn stepped(&self, direction: f64, large: bool, fine: bool) -> Option<Message> {
+        let (Some(stepper), Some(on_input)) = (&self.stepper, &self.on_input) else {
+            return None;
+        };
+
+        let factor = if large {
+            self.step_multipliers.large
+        } else if fine {
+            self.step_multipliers.fine
+        } else {
+            1.
+        };
+
+        let mut parsed = stepper(&self.content.value, direction * factor);
+        if let Some(separator) = self.group_separator {
+            parsed.string = group_digits(&parsed.string, separator);
+        }
+
+        Some(on_input(parsed))
+    }
+
+    /// Returns the index in the regrouped text `edited` of the cursor at `index`,
+    /// so that it stays after the same digit.
+    fn regrouped_index(&self, separator: char, edited: &str, index: usize) -> usize {
+        let mut remaining = edited
+            .chars()
+            .take(index)
+            .filter(|c| *c != separator)
+            .count();
+
+        group_digits(&self.ungroup(edited), separator)
+            .chars()
+            .take_while(|c| {
+                if remaining == 0 {
+                    return false;
+                }
+                if *c != separator {
+                    remaining -= 1;
+                }
+                true
+            })
+            .count()
+    }
+
+    /// Removes the group separators from a text, only allocating if there are any.
+    fn ungroup<'s>(&self, str: &'s str) -> Cow<'s, str> {
+        match self.group_separator {
+            Some(separator) if str.contains(separator) => {
+                Cow::Owned(str.chars().filter(|c| *c != separator).collect())
+            }
+            _ => Cow::Borrowed(str),
+        }
+    }
+
+    /// Same as [`ungroup`](Self::ungroup), reusing the given text if it has no group separators.
+    fn ungroup_owned(&self, str: String) -> String {
+        match self.ungroup(&str) {
+            Cow::Owned(ungrouped) => ungrouped,
+            Cow::Borrowed(_) => str,
+        }
+    }
+
+    /// Parses a text and applies the validator.
+    ///
+    /// The group separators are removed before parsing, and the digits of the
+    /// returned string are regrouped.
+    fn parse(&self, str: &str) -> Parsed<T, E> {
+        let ungrouped = self.ungroup(str);
+        let mut parsed = Parsed::from_string(&ungrouped);
+
+        if let Some(separator) = self.group_separator {
+            parsed.string = group_digits(&ungrouped, separator);
+        }
+
+        if let (Some(validator), Ok(value)) = (&self.validator, &parsed.parsed)
+            && let Err(err) = validator(value)
+        {
+            parsed.parsed = Err(err);
+        }
+
+        parsed
+    }
+
+    /// Applies the character filter and the predicate to a new text.
+    ///
+    /// Returns [`None`] if the edit is rejected or if the filtered text is the same as the current one.
+    fn filter(&self, str: String) -> Option<String> {
+        let str = match &self.allowed_chars {
+            Some(allowed) => {
+                let filtered: String = str.chars().filter(|c| allowed(*c)).collect();
+                if filtered == self.ungroup(&self.content.string) {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(text = str, "edit only added disallowed characters");
+
+                    return None;
+                }
+                filtered
+            }
+            None => str,
+        };
+
+        if let Some(max_length) = self.max_length {
+            let length = str.chars().count();
+            let current = self.length();
+            if length > max_length && length > current {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(text = str, length, max_length, "edit exceeds the maximum length");
+
+                return None;
+            }
+        }
+
+        match &self.accept {
+            Some(accept) if !accept(&str) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(text = str, "edit rejected by the predicate");
+
+                None
+            }
+            _ => Some(str),
+        }
+    }
+
+    /// Returns the number of characters of the [`Content`], without the group separators.
+    fn length(&self) -> usize {
+        self.ungroup(&self.content.string).chars().count()
+    }
+
+    /// Indicates if the [`Content`] is valid and not longer than the maximum length.
+    fn is_valid(&self) -> bool {
+        self.content.is_valid()
+            && self
+                .max_length
+                .is_none_or(|max_length| self.length() <= max_length)
+    }
+}
+
 impl<'a, T: FromStr<Err = E>, E, Message: Clone + 'a, Theme: 'a, Renderer: 'a>
     From<ParsedInput<'a, T, E, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
 where
@@ -545,6 +1108,64 @@ pub struct BorrowMut<'a, T: ToString, E> {
     content: &'a mut Content<T, E>,
 }
 
+/// Groups the digits of the integer part of a number by three (e.g. `-$1234.5` into `-$1,234.5`).
+///
+/// The integer part is the first run of digits, so prefixes like signs or currency symbols are kept.
+fn group_digits(str: &str, separator: char) -> String {
+    let start = str.find(|c: char| c.is_ascii_digit()).unwrap_or(str.len());
+    let (prefix, rest) = str.split_at(start);
+    let end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+    let (integer, suffix) = rest.split_at(end);
+
+    let mut grouped = String::from(prefix);
+    for (i, digit) in integer.chars().enumerate() {
+        if i != 0 && (integer.len() - i) % 3 == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped.push_str(suffix);
+
+    grouped
+}
+
+/// A character filter that accepts the characters of an integer.
+///
+/// See [`ParsedInput::allowed_chars`].
+pub fn numeric() -> impl Fn(char) -> bool {
+    |c| c.is_ascii_digit() || c == '-' || c == '+'
+}
+
+/// A character filter that accepts the characters of a decimal number, with `.` or the decimal
+/// separator of the [`i18n`](crate::i18n) format of the current thread.
+///
+/// The format is read when the filter is created, usually in `view`.
+/// See [`decimal_for`] to give the format explicitly, and [`ParsedInput::allowed_chars`].
+pub fn decimal() -> impl Fn(char) -> bool {
+    decimal_for(&*crate::i18n::format())
+}
+
+/// Same as [`decimal`], with the decimal separator of the given `format`.
+pub fn decimal_for(format: &dyn crate::i18n::Format) -> impl Fn(char) -> bool + use<> {
+    let separator = format.decimal_separator();
+    move |c| c.is_ascii_digit() || c == '-' || c == '+' || c == '.' || c == separator
+}
+
+/// A character filter that accepts the characters of a decimal number
+/// in fixed or scientific notation (such as `1.5e-3`).
+///
+/// See [`ParsedInput::allowed_chars`].
+pub fn scientific() -> impl Fn(char) -> bool {
+    |c| c.is_ascii_digit() || c == '-' || c == '+' || c == '.' || c == 'e' || c == 'E'
+}
+
+/// A character filter that accepts hexadecimal digits.
+///
+/// See [`ParsedInput::allowed_chars`].
+pub fn hex() -> impl Fn(char) -> bool {
+    |c| c.is_ascii_hexdigit()
+}
+
 /// Returns a [`text_input::Style`] and applies a color to it's background when the [`ParsedInput`] has an invalid [`String`].
 pub fn color_on_err<Theme>(
     style: impl Fn(&Theme, Status) -> Style,
@@ -585,21 +1206,65 @@ pub fn danger_on_err(
     }
 }
 
+/// Returns a [`text_input::Style`] and sets the color of it's border when the [`ParsedInput`] has an invalid [`String`].
+pub fn border_on_err<Theme>(
+    style: impl Fn(&Theme, Status) -> Style,
+    color: Color,
+) -> impl Fn(&Theme, Status, bool) -> Style {
+    move |theme, status, valid| {
+        let style = style(theme, status);
+        if valid {
+            style
+        } else {
+            text_input::Style {
+                border: style.border.color(color),
+                ..style
+            }
+        }
+    }
+}
+
+/// Returns a [`text_input::Style`] and sets the color of it's text when the [`ParsedInput`] has an invalid [`String`].
+pub fn text_color_on_err<Theme>(
+    style: impl Fn(&Theme, Status) -> Style,
+    color: Color,
+) -> impl Fn(&Theme, Status, bool) -> Style {
+    move |theme, status, valid| {
+        let style = style(theme, status);
+        if valid {
+            style
+        } else {
+            text_input::Style {
+                value: color,
+                ..style
+            }
+        }
+    }
+}
+
 impl<T: Default + ToString, E> Default for Content<T, E> {
     fn default() -> Self {
         Self::new(T::default())
     }
 }
 
+impl<T: PartialEq, E: PartialEq> PartialEq for Content<T, E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.string == other.string && self.error == other.error
+    }
+}
+
+impl<T: Eq, E: Eq> Eq for Content<T, E> {}
+
 impl<T, E> AsRef<T> for Content<T, E> {
     fn as_ref(&self) -> &T {
-        &**self
+        self
     }
 }
 
 impl<T, E> Borrow<T> for Content<T, E> {
     fn borrow(&self) -> &T {
-        &**self
+        self
     }
 }
 
@@ -613,22 +1278,22 @@ impl<T, E> Deref for Content<T, E> {
 
 impl<'a, T: ToString, E> AsRef<T> for BorrowMut<'a, T, E> {
     fn as_ref(&self) -> &T {
-        &**self
+        self
     }
 }
 impl<'a, T: ToString, E> AsMut<T> for BorrowMut<'a, T, E> {
     fn as_mut(&mut self) -> &mut T {
-        &mut **self
+        self
     }
 }
 impl<'a, T: ToString, E> Borrow<T> for BorrowMut<'a, T, E> {
     fn borrow(&self) -> &T {
-        &**self
+        self
     }
 }
 impl<'a, T: ToString, E> std::borrow::BorrowMut<T> for BorrowMut<'a, T, E> {
     fn borrow_mut(&mut self) -> &mut T {
-        &mut **self
+        self
     }
 }
 
@@ -648,6 +1313,7 @@ impl<'a, T: ToString, E> DerefMut for BorrowMut<'a, T, E> {
 
 impl<'a, T: ToString, E> Drop for BorrowMut<'a, T, E> {
     fn drop(&mut self) {
+        self.content.version = self.content.version.wrapping_add(1);
         self.content.string = self.content.value.to_string();
         self.content.error = None;
     }
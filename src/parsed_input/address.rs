@@ -0,0 +1,134 @@
+use std::{
+    fmt::Display,
+    net::{AddrParseError, IpAddr, SocketAddr},
+    ops::{Deref, RangeInclusive},
+    str::FromStr,
+};
+
+use iced::{advanced::text, widget::text_input};
+
+use super::{Content, ParsedInput};
+
+/// A [`SocketAddr`] that can be used in a [`ParsedInput`] with port validation.
+///
+/// It parses and displays like a [`SocketAddr`] (`127.0.0.1:8080` or `[::1]:8080`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SocketAddress {
+    /// The inner address.
+    pub addr: SocketAddr,
+}
+
+/// The error that can occur when parsing or validating a [`SocketAddress`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AddressError {
+    /// The string is not a valid socket address.
+    Invalid,
+    /// The port is not in the allowed range.
+    PortOutOfRange(RangeInclusive<u16>),
+}
+
+impl FromStr for SocketAddress {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim()
+            .parse::<SocketAddr>()
+            .map(Into::into)
+            .map_err(|_: AddrParseError| AddressError::Invalid)
+    }
+}
+
+impl Display for SocketAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.addr)
+    }
+}
+
+impl Display for AddressError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AddressError::Invalid => write!(f, "invalid socket address"),
+            AddressError::PortOutOfRange(range) => write!(
+                f,
+                "the port must be between {} and {}",
+                range.start(),
+                range.end()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AddressError {}
+
+impl From<SocketAddr> for SocketAddress {
+    fn from(value: SocketAddr) -> Self {
+        Self { addr: value }
+    }
+}
+
+impl From<SocketAddress> for SocketAddr {
+    fn from(value: SocketAddress) -> Self {
+        value.addr
+    }
+}
+
+impl Deref for SocketAddress {
+    type Target = SocketAddr;
+
+    fn deref(&self) -> &Self::Target {
+        &self.addr
+    }
+}
+
+impl Default for SocketAddress {
+    fn default() -> Self {
+        SocketAddr::from(([0, 0, 0, 0], 0)).into()
+    }
+}
+
+/// Accepts the characters of an IPv4 or IPv6 address.
+fn address_chars(c: char) -> bool {
+    c.is_ascii_hexdigit() || c == '.' || c == ':'
+}
+
+/// Creates a [`ParsedInput`] for IPv4 and IPv6 addresses.
+///
+/// Typing `.` moves to the next octet if there is one.
+pub fn ip_input<'a, Message, Theme, Renderer>(
+    placeholder: &str,
+    content: &'a Content<IpAddr, AddrParseError>,
+) -> ParsedInput<'a, IpAddr, AddrParseError, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog + 'a,
+{
+    ParsedInput::new(placeholder, content)
+        .allowed_chars(address_chars)
+        .jump_on('.')
+}
+
+/// Creates a [`ParsedInput`] for socket addresses, the port of which must be in `ports`.
+///
+/// Typing `.` moves to the next octet if there is one.
+pub fn socket_input<'a, Message, Theme, Renderer>(
+    placeholder: &str,
+    content: &'a Content<SocketAddress, AddressError>,
+    ports: RangeInclusive<u16>,
+) -> ParsedInput<'a, SocketAddress, AddressError, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog + 'a,
+{
+    ParsedInput::new(placeholder, content)
+        .allowed_chars(|c| address_chars(c) || c == '[' || c == ']')
+        .jump_on('.')
+        .validate(move |address| {
+            if ports.contains(&address.port()) {
+                Ok(())
+            } else {
+                Err(AddressError::PortOutOfRange(ports.clone()))
+            }
+        })
+}
@@ -0,0 +1,422 @@
+use std::{fmt::Display, ops::Deref, str::FromStr};
+
+use iced::{
+    Border, Color, Length, Padding, Pixels, Rectangle, Size,
+    advanced::{
+        Clipboard, Shell, Widget,
+        graphics::core::Element,
+        layout, mouse, renderer, text,
+        widget::{Operation, Tree, tree},
+    },
+    event,
+    widget::text_input::{self, Id, Status, Style, StyleFn},
+};
+
+use super::{Content, Parsed, ParsedInput};
+
+/// A [`Color`] that parses from and displays as a hexadecimal string.
+///
+/// It parses `#RRGGBB` and `#RRGGBBAA` strings, as well as their `#RGB` and `#RGBA` shorthands
+/// (the `#` is optional and the case is ignored), and is displayed as `#rrggbb`, or `#rrggbbaa`
+/// when the color is not opaque.
+///
+/// Each channel is stored with a precision of 8 bits when parsed.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct HexColor {
+    /// The inner color.
+    pub color: Color,
+}
+
+/// The error that can occur when parsing a [`HexColor`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ParseColorError {
+    /// The string does not have 3, 4, 6 or 8 hexadecimal digits.
+    InvalidLength(usize),
+    /// The string contains a character that is not an hexadecimal digit.
+    InvalidDigit(char),
+}
+
+impl FromStr for HexColor {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let digits = s.strip_prefix('#').unwrap_or(s);
+
+        if let Some(c) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+            return Err(ParseColorError::InvalidDigit(c));
+        }
+
+        // The number of digits of each channel.
+        let width = match digits.len() {
+            3 | 4 => 1,
+            6 | 8 => 2,
+            len => return Err(ParseColorError::InvalidLength(len)),
+        };
+
+        let channel = |i: usize| {
+            let value = u8::from_str_radix(&digits[width * i..width * (i + 1)], 16)
+                .expect("Digits were checked");
+            // A shorthand digit is repeated: `f` means `ff`.
+            if width == 1 { value * 0x11 } else { value }
+        };
+
+        let alpha = if digits.len() == 4 * width {
+            channel(3)
+        } else {
+            255
+        };
+
+        Ok(Color::from_rgba8(channel(0), channel(1), channel(2), alpha as f32 / 255.).into())
+    }
+}
+
+impl Display for HexColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [r, g, b, a] = self.color.into_rgba8();
+
+        if a == 255 {
+            write!(f, "#{r:02x}{g:02x}{b:02x}")
+        } else {
+            write!(f, "#{r:02x}{g:02x}{b:02x}{a:02x}")
+        }
+    }
+}
+
+impl Display for ParseColorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseColorError::InvalidLength(len) => {
+                write!(f, "expected 3, 4, 6 or 8 hexadecimal digits, got {len}")
+            }
+            ParseColorError::InvalidDigit(c) => write!(f, "invalid hexadecimal digit '{c}'"),
+        }
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for HexColor {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HexColor {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        string.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<Color> for HexColor {
+    fn from(value: Color) -> Self {
+        Self { color: value }
+    }
+}
+
+impl From<HexColor> for Color {
+    fn from(value: HexColor) -> Self {
+        value.color
+    }
+}
+
+impl Deref for HexColor {
+    type Target = Color;
+
+    fn deref(&self) -> &Self::Target {
+        &self.color
+    }
+}
+
+/// The default size of the swatch of a [`ColorInput`].
+const DEFAULT_SWATCH_SIZE: f32 = 16.0;
+
+/// A [`ParsedInput`] for [`HexColor`]s, with a swatch of the current color
+/// drawn at the end of the field.
+///
+/// The swatch shows the value of the [`Content`], so it keeps the last valid color
+/// while the text is malformed.
+pub struct ColorInput<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog,
+{
+    input: ParsedInput<'a, HexColor, ParseColorError, Message, Theme, Renderer>,
+    color: Color,
+    padding: Padding,
+    swatch_size: f32,
+}
+
+impl<'a, Message, Theme, Renderer> ColorInput<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog + 'a,
+{
+    /// Creates a new [`ColorInput`] from a [`Content`].
+    pub fn new(placeholder: &str, content: &'a Content<HexColor, ParseColorError>) -> Self {
+        let input = ParsedInput::new(placeholder, content)
+            .allowed_chars(|c| c == '#' || c.is_ascii_hexdigit());
+
+        Self {
+            input,
+            color: content.value.color,
+            padding: text_input::DEFAULT_PADDING,
+            swatch_size: DEFAULT_SWATCH_SIZE,
+        }
+        .update_padding()
+    }
+
+    /// Sets the [`Id`] of the underlying [`TextInput`](iced::widget::TextInput).
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.input = self.input.id(id);
+        self
+    }
+
+    /// Sets the message that should be produced when some text is typed into the [`ColorInput`].
+    ///
+    /// If this method is not called, the [`ColorInput`] will be disabled.
+    pub fn on_input(
+        mut self,
+        on_input: impl Fn(Parsed<HexColor, ParseColorError>) -> Message + 'a,
+    ) -> Self {
+        self.input = self.input.on_input(on_input);
+        self
+    }
+
+    /// Sets the message that should be produced when the [`ColorInput`] is
+    /// focused and the enter key is pressed.
+    pub fn on_submit(mut self, on_submit: Message) -> Self {
+        self.input = self.input.on_submit(on_submit);
+        self
+    }
+
+    /// Sets the message that should be produced when some text is pasted into
+    /// the [`ColorInput`].
+    pub fn on_paste(
+        mut self,
+        on_paste: impl Fn(Parsed<HexColor, ParseColorError>) -> Message + 'a,
+    ) -> Self {
+        self.input = self.input.on_paste(on_paste);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`ColorInput`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.input = self.input.font(font);
+        self
+    }
+
+    /// Sets the width of the [`ColorInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.input = self.input.width(width);
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`ColorInput`].
+    ///
+    /// The space taken by the swatch is added to the right padding.
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self.update_padding()
+    }
+
+    /// Sets the text size of the [`ColorInput`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.input = self.input.size(size);
+        self
+    }
+
+    /// Sets the size of the side of the swatch.
+    pub fn swatch_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.swatch_size = size.into().0;
+        self.update_padding()
+    }
+
+    /// Sets the style of the [`ColorInput`].
+    ///
+    /// See [`ParsedInput::style`].
+    pub fn style(mut self, style: impl Fn(&Theme, Status, bool) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.input = self.input.style(style);
+        self
+    }
+
+    /// Sets the style class of the [`ColorInput`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.input = self.input.class(class);
+        self
+    }
+
+    /// Reserves the space of the swatch in the padding of the inner input.
+    fn update_padding(mut self) -> Self {
+        let padding = Padding {
+            right: self.padding.right + self.swatch_size + self.padding.right,
+            ..self.padding
+        };
+        self.input = self.input.padding(padding);
+        self
+    }
+}
+
+impl<'a, Message: Clone, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ColorInput<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog,
+{
+    fn state(&self) -> tree::State {
+        Widget::state(&self.input)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        Widget::tag(&self.input)
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        Widget::diff(&self.input, tree);
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        Widget::children(&self.input)
+    }
+
+    fn size(&self) -> Size<Length> {
+        Widget::size(&self.input)
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        Widget::size_hint(&self.input)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        Widget::layout(&self.input, tree, renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        Widget::draw(
+            &self.input,
+            tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        let bounds = layout.bounds();
+        let size = self.swatch_size.min(bounds.height);
+
+        let swatch = Rectangle {
+            x: bounds.x + bounds.width - self.padding.right - size,
+            y: bounds.center_y() - size / 2.,
+            width: size,
+            height: size,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: swatch,
+                border: Border {
+                    color: Color::from_rgba(0., 0., 0., 0.5),
+                    width: 1.,
+                    radius: 2.into(),
+                },
+                ..Default::default()
+            },
+            self.color,
+        );
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: layout::Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        Widget::operate(&self.input, state, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: iced::Event,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        Widget::on_event(
+            &mut self.input,
+            state,
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        Widget::mouse_interaction(&self.input, state, layout, cursor, viewport, renderer)
+    }
+}
+
+impl<'a, Message: Clone + 'a, Theme: 'a, Renderer: 'a>
+    From<ColorInput<'a, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog,
+{
+    fn from(value: ColorInput<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a [`ColorInput`], a [`ParsedInput`] for hexadecimal colors with a live swatch.
+///
+/// See [`HexColor`] for the accepted format.
+pub fn color_input<'a, Message, Theme, Renderer>(
+    placeholder: &str,
+    content: &'a Content<HexColor, ParseColorError>,
+) -> ColorInput<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog + 'a,
+{
+    ColorInput::new(placeholder, content)
+}
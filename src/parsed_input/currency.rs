@@ -0,0 +1,189 @@
+use std::{fmt::Display, str::FromStr};
+
+use iced::{advanced::text, widget::text_input};
+
+use super::{Content, ParsedInput};
+use crate::i18n;
+
+/// An amount of money stored as an integer number of minor units (e.g. cents).
+///
+/// It is displayed with the `SYMBOL` of the currency and exactly `DECIMALS` decimals
+/// (e.g. `$12.50` for `Money { minor: 1250 }`).
+///
+/// When parsing, the symbol is optional and at most `DECIMALS` decimals are accepted,
+/// so `"12.5"`, `"$12.50"` and `"-$3"` are valid but `"12.505"` is not.
+/// The decimal and group separators are the ones of the [`i18n`] format of the current thread,
+/// read each time an amount is parsed or displayed.
+///
+/// `DECIMALS` is at most 18, so that one major unit fits in an `i64`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Money<const SYMBOL: char = '$', const DECIMALS: u32 = 2> {
+    /// The amount, in minor units.
+    pub minor: i64,
+}
+
+/// The error that can occur when parsing [`Money`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ParseMoneyError {
+    /// The string does not contain any digit.
+    Empty,
+    /// The string contains an unexpected character.
+    InvalidChar(char),
+    /// The string has more decimals than allowed.
+    TooPrecise(u32),
+    /// The amount is too big.
+    Overflow,
+}
+
+impl<const SYMBOL: char, const DECIMALS: u32> Money<SYMBOL, DECIMALS> {
+    /// The factor between major and minor units, checked at compile time to fit in an `i64`.
+    const FACTOR: i64 = {
+        assert!(DECIMALS <= 18, "Money supports at most 18 decimals");
+        10i64.pow(DECIMALS)
+    };
+
+    /// Creates an amount from a number of minor units.
+    pub fn new(minor: i64) -> Self {
+        // Fails to compile if `DECIMALS` is too big.
+        let _ = Self::FACTOR;
+        Self { minor }
+    }
+}
+
+impl<const SYMBOL: char, const DECIMALS: u32> FromStr for Money<SYMBOL, DECIMALS> {
+    type Err = ParseMoneyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = i18n::format().parse_number(s.trim());
+        let s = s.as_ref();
+
+        // The sign can be placed before or after the symbol.
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest.strip_prefix(SYMBOL).unwrap_or(rest)),
+            None => {
+                let s = s.strip_prefix(SYMBOL).unwrap_or(s);
+                match s.strip_prefix('-') {
+                    Some(rest) => (true, rest),
+                    None => (false, s),
+                }
+            }
+        };
+
+        let (integer, fraction) = s.split_once('.').unwrap_or((s, ""));
+
+        if let Some(c) = integer
+            .chars()
+            .chain(fraction.chars())
+            .find(|c| !c.is_ascii_digit())
+        {
+            return Err(ParseMoneyError::InvalidChar(c));
+        }
+
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(ParseMoneyError::Empty);
+        }
+
+        let parse = |digits: &str| -> Result<i64, ParseMoneyError> {
+            digits.chars().try_fold(0i64, |acc, c| {
+                acc.checked_mul(10)
+                    .and_then(|acc| acc.checked_add(c.to_digit(10).unwrap_or_default() as i64))
+                    .ok_or(ParseMoneyError::Overflow)
+            })
+        };
+
+        let fraction_factor = u32::try_from(fraction.len())
+            .ok()
+            .and_then(|len| DECIMALS.checked_sub(len))
+            .and_then(|missing| 10i64.checked_pow(missing))
+            .ok_or(ParseMoneyError::TooPrecise(DECIMALS))?;
+
+        let minor = parse(integer)?
+            .checked_mul(Self::FACTOR)
+            .and_then(|minor| minor.checked_add(parse(fraction).ok()? * fraction_factor))
+            .ok_or(ParseMoneyError::Overflow)?;
+
+        Ok(Self::new(if negative { -minor } else { minor }))
+    }
+}
+
+impl<const SYMBOL: char, const DECIMALS: u32> Display for Money<SYMBOL, DECIMALS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.minor < 0 { "-" } else { "" };
+        let amount = self.minor.unsigned_abs();
+        let factor = Self::FACTOR.unsigned_abs();
+
+        let mut number = format!("{sign}{SYMBOL}{}", amount / factor);
+
+        if DECIMALS > 0 {
+            number.push_str(&format!(
+                ".{:0width$}",
+                amount % factor,
+                width = DECIMALS as usize
+            ));
+        }
+
+        f.write_str(&i18n::format().format_number(&number))
+    }
+}
+
+impl Display for ParseMoneyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseMoneyError::Empty => write!(f, "empty amount"),
+            ParseMoneyError::InvalidChar(c) => write!(f, "invalid character '{c}'"),
+            ParseMoneyError::TooPrecise(decimals) => {
+                write!(f, "at most {decimals} decimals are allowed")
+            }
+            ParseMoneyError::Overflow => write!(f, "amount too big"),
+        }
+    }
+}
+
+impl std::error::Error for ParseMoneyError {}
+
+/// Creates a [`ParsedInput`] for amounts of [`Money`].
+///
+/// Typing characters that are not part of an amount, or more decimals than allowed, is prevented.
+/// Call [`Content::commit`] (for example on submit) to display the amount
+/// with the currency symbol and all the decimals.
+///
+/// The separators allowed while typing are the ones of the [`i18n`] format of the current thread,
+/// read when the input is created, usually in `view`. See [`currency_input_for`] to give the
+/// format explicitly.
+pub fn currency_input<'a, const SYMBOL: char, const DECIMALS: u32, Message, Theme, Renderer>(
+    placeholder: &str,
+    content: &'a Content<Money<SYMBOL, DECIMALS>, ParseMoneyError>,
+) -> ParsedInput<'a, Money<SYMBOL, DECIMALS>, ParseMoneyError, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog + 'a,
+{
+    currency_input_for(placeholder, content, &*i18n::format())
+}
+
+/// Same as [`currency_input`], with the separators of the given `format`.
+///
+/// The amount itself is still parsed and displayed with the [`i18n`] format of the current thread,
+/// see [`Money`].
+pub fn currency_input_for<'a, const SYMBOL: char, const DECIMALS: u32, Message, Theme, Renderer>(
+    placeholder: &str,
+    content: &'a Content<Money<SYMBOL, DECIMALS>, ParseMoneyError>,
+    format: &dyn i18n::Format,
+) -> ParsedInput<'a, Money<SYMBOL, DECIMALS>, ParseMoneyError, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog + 'a,
+{
+    let (decimal, group) = (format.decimal_separator(), format.group_separator());
+
+    ParsedInput::new(placeholder, content)
+        .allowed_chars(move |c| {
+            c.is_ascii_digit() || c == decimal || Some(c) == group || c == '-' || c == SYMBOL
+        })
+        .accept(move |str| {
+            str.split_once(decimal)
+                .is_none_or(|(_, fraction)| fraction.len() <= DECIMALS as usize)
+        })
+}
@@ -0,0 +1,177 @@
+use std::{fmt::Display, ops::Deref, str::FromStr, time::Duration};
+
+use iced::{advanced::text, widget::text_input};
+
+use super::{Content, ParsedInput};
+
+/// A [`Duration`] that parses and displays in a human friendly way.
+///
+/// It parses strings such as `"1h 30m"`, `"90s"`, `"1.5h"` or `"2d 250ms"`,
+/// and is displayed canonically (`"90s"` becomes `"1m 30s"`).
+///
+/// The supported units are `d`, `h`, `m`, `s` and `ms`.
+/// The precision is the millisecond: anything below is dropped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HumanDuration {
+    /// The inner duration.
+    pub duration: Duration,
+}
+
+/// The error that can occur when parsing a [`HumanDuration`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ParseDurationError {
+    /// The string is empty.
+    Empty,
+    /// A number could not be parsed.
+    InvalidNumber(String),
+    /// A number is not followed by a unit.
+    MissingUnit(String),
+    /// The unit is not one of `d`, `h`, `m`, `s` or `ms`.
+    UnknownUnit(String),
+    /// The duration is too big.
+    Overflow,
+}
+
+const UNITS: [(&str, u128); 5] = [
+    ("d", 86_400_000),
+    ("h", 3_600_000),
+    ("m", 60_000),
+    ("s", 1_000),
+    ("ms", 1),
+];
+
+impl FromStr for HumanDuration {
+    type Err = ParseDurationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.trim().chars().peekable();
+
+        if chars.peek().is_none() {
+            return Err(ParseDurationError::Empty);
+        }
+
+        let mut millis = 0f64;
+
+        while chars.peek().is_some() {
+            let number: String =
+                std::iter::from_fn(|| chars.next_if(|c| c.is_ascii_digit() || *c == '.')).collect();
+
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+
+            let unit: String =
+                std::iter::from_fn(|| chars.next_if(|c| c.is_alphabetic())).collect();
+
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+
+            if number.is_empty() {
+                let invalid = if unit.is_empty() {
+                    chars.next().map(String::from).unwrap_or_default()
+                } else {
+                    unit
+                };
+                return Err(ParseDurationError::InvalidNumber(invalid));
+            }
+
+            let value: f64 = number
+                .parse()
+                .map_err(|_| ParseDurationError::InvalidNumber(number.clone()))?;
+
+            if unit.is_empty() {
+                return Err(ParseDurationError::MissingUnit(number));
+            }
+
+            let factor = UNITS
+                .iter()
+                .find(|(name, _)| *name == unit)
+                .map(|(_, factor)| *factor)
+                .ok_or(ParseDurationError::UnknownUnit(unit))?;
+
+            millis += value * factor as f64;
+        }
+
+        if millis > u64::MAX as f64 {
+            return Err(ParseDurationError::Overflow);
+        }
+
+        Ok(Duration::from_millis(millis.round() as u64).into())
+    }
+}
+
+impl Display for HumanDuration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut millis = self.duration.as_millis();
+
+        if millis == 0 {
+            return write!(f, "0s");
+        }
+
+        let mut first = true;
+        for (name, factor) in UNITS {
+            let amount = millis / factor;
+            millis %= factor;
+
+            if amount != 0 {
+                if !first {
+                    write!(f, " ")?;
+                }
+                write!(f, "{amount}{name}")?;
+                first = false;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for ParseDurationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseDurationError::Empty => write!(f, "empty duration"),
+            ParseDurationError::InvalidNumber(number) => write!(f, "invalid number: \"{number}\""),
+            ParseDurationError::MissingUnit(number) => write!(f, "missing unit after \"{number}\""),
+            ParseDurationError::UnknownUnit(unit) => {
+                write!(f, "unknown unit \"{unit}\" (expected d, h, m, s or ms)")
+            }
+            ParseDurationError::Overflow => write!(f, "duration too big"),
+        }
+    }
+}
+
+impl std::error::Error for ParseDurationError {}
+
+impl From<Duration> for HumanDuration {
+    fn from(value: Duration) -> Self {
+        Self { duration: value }
+    }
+}
+
+impl From<HumanDuration> for Duration {
+    fn from(value: HumanDuration) -> Self {
+        value.duration
+    }
+}
+
+impl Deref for HumanDuration {
+    type Target = Duration;
+
+    fn deref(&self) -> &Self::Target {
+        &self.duration
+    }
+}
+
+/// Creates a [`ParsedInput`] for human friendly durations.
+///
+/// See [`HumanDuration`] for the accepted format.
+pub fn duration_input<'a, Message, Theme, Renderer>(
+    placeholder: &str,
+    content: &'a Content<HumanDuration, ParseDurationError>,
+) -> ParsedInput<'a, HumanDuration, ParseDurationError, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog + 'a,
+{
+    ParsedInput::new(placeholder, content)
+        .allowed_chars(|c| c.is_ascii_digit() || c == '.' || c == ' ' || "dhms".contains(c))
+}
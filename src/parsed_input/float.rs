@@ -0,0 +1,113 @@
+use std::{
+    fmt::{Display, LowerExp},
+    ops::Deref,
+    str::FromStr,
+};
+
+use crate::i18n;
+
+/// A float that is displayed in scientific notation with `PRECISION` decimals (e.g. `1.50e-3`).
+///
+/// Any float notation is accepted when parsing, so `"0.0015"` and `"1.5e-3"` are both valid.
+/// The value is rounded to `PRECISION` decimals when parsed or created with [`new`](Scientific::new),
+/// so that it always matches its displayed text.
+/// The decimal and group separators are the ones of the [`i18n`] format of the current thread,
+/// read each time a value is parsed or displayed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scientific<T, const PRECISION: usize> {
+    /// The inner value.
+    pub value: T,
+}
+
+/// A float that is displayed in fixed notation with `PRECISION` decimals (e.g. `0.0015`).
+///
+/// Any float notation is accepted when parsing, so `"0.0015"` and `"1.5e-3"` are both valid.
+/// The value is rounded to `PRECISION` decimals when parsed or created with [`new`](Fixed::new),
+/// so that it always matches its displayed text.
+/// The decimal and group separators are the ones of the [`i18n`] format of the current thread,
+/// read each time a value is parsed or displayed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fixed<T, const PRECISION: usize> {
+    /// The inner value.
+    pub value: T,
+}
+
+impl<T: LowerExp, const PRECISION: usize> Display for Scientific<T, PRECISION> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let number = format!("{:.*e}", PRECISION, self.value);
+        f.write_str(&i18n::format().format_number(&number))
+    }
+}
+
+impl<T: Display, const PRECISION: usize> Display for Fixed<T, PRECISION> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let number = format!("{:.*}", PRECISION, self.value);
+        f.write_str(&i18n::format().format_number(&number))
+    }
+}
+
+impl<T: FromStr + LowerExp, const PRECISION: usize> Scientific<T, PRECISION> {
+    /// Creates a float from a value, rounded to `PRECISION` decimals of its mantissa.
+    pub fn new(value: T) -> Self {
+        let rounded = format!("{:.*e}", PRECISION, value).parse().ok();
+        Self {
+            value: rounded.unwrap_or(value),
+        }
+    }
+}
+
+impl<T: FromStr + Display, const PRECISION: usize> Fixed<T, PRECISION> {
+    /// Creates a float from a value, rounded to `PRECISION` decimals.
+    pub fn new(value: T) -> Self {
+        let rounded = format!("{:.*}", PRECISION, value).parse().ok();
+        Self {
+            value: rounded.unwrap_or(value),
+        }
+    }
+}
+
+impl<T: FromStr + LowerExp, const PRECISION: usize> FromStr for Scientific<T, PRECISION> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        i18n::format().parse_number(s.trim()).parse().map(Self::new)
+    }
+}
+
+impl<T: FromStr + Display, const PRECISION: usize> FromStr for Fixed<T, PRECISION> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        i18n::format().parse_number(s.trim()).parse().map(Self::new)
+    }
+}
+
+impl<T: FromStr + LowerExp, const PRECISION: usize> From<T> for Scientific<T, PRECISION> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: FromStr + Display, const PRECISION: usize> From<T> for Fixed<T, PRECISION> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T, const PRECISION: usize> Deref for Scientific<T, PRECISION> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T, const PRECISION: usize> Deref for Fixed<T, PRECISION> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
@@ -0,0 +1,521 @@
+//! The [`ParsedInput`] for iced 0.14.
+//!
+//! It parses, filters, groups and steps its text like the [`ParsedInput`](super::ParsedInput)
+//! of iced 0.13, and is styled with the catalog of the text input of iced 0.14.
+//! It does not expose a [`ParseStatus`](super::ParseStatus) to operations, and it has no
+//! counterpart of [`with_error`](super::ParsedInput::with_error) nor of the style helpers like
+//! [`color_on_err`](super::color_on_err), which produce styles of iced 0.13.
+
+use std::str::FromStr;
+
+use iced_widget_014::{
+    TextInput,
+    core::{
+        Clipboard, Element, Event, Layout, Length, Padding, Pixels, Rectangle, Shell, Size, Widget,
+        alignment, keyboard, layout, mouse, renderer, text,
+        widget::{self, Operation, Tree, tree},
+    },
+    text_input::{self, Icon, Status, Style, StyleFn, cursor},
+};
+
+use super::{Content, InnerMessage, Logic, Parsed, Step, StepMultipliers};
+
+/// The [`ParsedInput`](super::ParsedInput) widget, for iced 0.14.
+pub struct ParsedInput<
+    'a,
+    T,
+    E,
+    Message,
+    Theme = iced_widget_014::Theme,
+    Renderer = iced_widget_014::Renderer,
+> where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog,
+{
+    logic: Logic<'a, T, E, Message>,
+    text_input: TextInput<'a, InnerMessage, Theme, Renderer>,
+}
+
+impl<'a, T, E, Message, Theme, Renderer> ParsedInput<'a, T, E, Message, Theme, Renderer>
+where
+    T: Clone,
+    E: Clone,
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog + 'a,
+{
+    /// Creates a new [`ParsedInput`] from a [`Content`].
+    pub fn new(placeholder: &str, content: &'a Content<T, E>) -> Self {
+        Self {
+            logic: Logic::new(content),
+            text_input: TextInput::new(placeholder, &content.string),
+        }
+    }
+
+    /// Sets the [`Id`](widget::Id) of the underlying [`TextInput`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.text_input = self.text_input.id(id);
+        self
+    }
+
+    /// Converts the underlying [`TextInput`] into a secure password input.
+    pub fn secure(mut self, is_secure: bool) -> Self {
+        self.text_input = self.text_input.secure(is_secure);
+        self
+    }
+
+    /// Sets the message that should be produced when some text is typed into the [`ParsedInput`].
+    ///
+    /// If this method is not called, the [`ParsedInput`] will be disabled.
+    pub fn on_input(mut self, on_input: impl Fn(Parsed<T, E>) -> Message + 'a) -> Self {
+        self.logic.on_input = Some(Box::new(on_input));
+        self.text_input = self.text_input.on_input(InnerMessage::Input);
+        self
+    }
+
+    /// Sets the message that should be produced when some text is typed into the [`ParsedInput`], if [`Some`].
+    pub fn on_input_maybe(self, on_input: Option<impl Fn(Parsed<T, E>) -> Message + 'a>) -> Self {
+        match on_input {
+            Some(on_input) => self.on_input(on_input),
+            None => self,
+        }
+    }
+
+    /// Sets the message that should be produced when the [`ParsedInput`] is
+    /// focused and the enter key is pressed.
+    pub fn on_submit(mut self, on_submit: Message) -> Self {
+        self.logic.on_submit = Some(on_submit);
+        self.text_input = self.text_input.on_submit(InnerMessage::Submit);
+        self
+    }
+
+    /// Sets the message that should be produced when some text is pasted into
+    /// the [`ParsedInput`].
+    pub fn on_paste(mut self, on_paste: impl Fn(Parsed<T, E>) -> Message + 'a) -> Self {
+        self.logic.on_paste = Some(Box::new(on_paste));
+        self.text_input = self.text_input.on_paste(InnerMessage::Paste);
+        self
+    }
+
+    /// Same as [`on_input`](Self::on_input), see
+    /// [`ParsedInput::on_input_with_previous`](super::ParsedInput::on_input_with_previous).
+    pub fn on_input_with_previous(self, on_input: impl Fn(T, Parsed<T, E>) -> Message + 'a) -> Self
+    where
+        T: 'a,
+    {
+        let previous = self.logic.content.value.clone();
+        self.on_input(move |parsed| on_input(previous.clone(), parsed))
+    }
+
+    /// Same as [`on_paste`](Self::on_paste), see
+    /// [`ParsedInput::on_paste_with_previous`](super::ParsedInput::on_paste_with_previous).
+    pub fn on_paste_with_previous(self, on_paste: impl Fn(T, Parsed<T, E>) -> Message + 'a) -> Self
+    where
+        T: 'a,
+    {
+        let previous = self.logic.content.value.clone();
+        self.on_paste(move |parsed| on_paste(previous.clone(), parsed))
+    }
+
+    /// Same as [`on_submit`](Self::on_submit), see
+    /// [`ParsedInput::on_submit_with_previous`](super::ParsedInput::on_submit_with_previous).
+    pub fn on_submit_with_previous(
+        mut self,
+        on_submit: impl Fn(T, Parsed<T, E>) -> Message + 'a,
+    ) -> Self
+    where
+        T: 'a,
+    {
+        let previous = self.logic.content.value.clone();
+        self.logic.on_submit_with =
+            Some(Box::new(move |parsed| on_submit(previous.clone(), parsed)));
+        self.text_input = self.text_input.on_submit(InnerMessage::Submit);
+        self
+    }
+
+    /// Sets a filter on the characters that can be typed or pasted, see
+    /// [`ParsedInput::allowed_chars`](super::ParsedInput::allowed_chars).
+    pub fn allowed_chars(mut self, filter: impl Fn(char) -> bool + 'a) -> Self {
+        self.logic.allowed_chars = Some(Box::new(filter));
+        self
+    }
+
+    /// Sets a predicate on the whole text resulting from an edit, see
+    /// [`ParsedInput::accept`](super::ParsedInput::accept).
+    pub fn accept(mut self, accept: impl Fn(&str) -> bool + 'a) -> Self {
+        self.logic.accept = Some(Box::new(accept));
+        self
+    }
+
+    /// Sets a separator used to navigate between the parts of the text, see
+    /// [`ParsedInput::jump_on`](super::ParsedInput::jump_on).
+    pub fn jump_on(mut self, separator: char) -> Self {
+        self.logic.jump_on = Some(separator);
+        self
+    }
+
+    /// Displays the digits of the integer part in groups of three, see
+    /// [`ParsedInput::group_digits`](super::ParsedInput::group_digits).
+    pub fn group_digits(mut self, separator: char) -> Self {
+        self.logic.group_separator = Some(separator);
+        self
+    }
+
+    /// Sets the maximum number of characters, see
+    /// [`ParsedInput::max_length`](super::ParsedInput::max_length).
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.logic.max_length = Some(max_length);
+        self
+    }
+
+    /// Allows to change the value with the arrow keys, see
+    /// [`ParsedInput::step`](super::ParsedInput::step).
+    pub fn step(mut self, step: f64) -> Self
+    where
+        T: Step + ToString + 'a,
+    {
+        self.logic.stepper = Some(Box::new(move |value: &T, factor| {
+            Parsed::from_value(value.step(step * factor))
+        }));
+        self
+    }
+
+    /// Sets the multipliers applied to the [`step`](Self::step) when
+    /// Shift (`large`) or Ctrl/Alt (`fine`) is held.
+    pub fn step_multipliers(mut self, large: f64, fine: f64) -> Self {
+        self.logic.step_multipliers = StepMultipliers { large, fine };
+        self
+    }
+
+    /// Adds a validation step after the text is successfully parsed.
+    pub fn validate(mut self, validator: impl Fn(&T) -> Result<(), E> + 'a) -> Self {
+        self.logic.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Sets the [`Font`] of the [`ParsedInput`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.text_input = self.text_input.font(font);
+        self
+    }
+
+    /// Sets the [`Icon`] of the [`ParsedInput`].
+    pub fn icon(mut self, icon: Icon<Renderer::Font>) -> Self {
+        self.text_input = self.text_input.icon(icon);
+        self
+    }
+
+    /// Sets the width of the [`ParsedInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.text_input = self.text_input.width(width);
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`ParsedInput`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.text_input = self.text_input.padding(padding);
+        self
+    }
+
+    /// Sets the text size of the [`ParsedInput`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_input = self.text_input.size(size);
+        self
+    }
+
+    /// Sets the [`text::LineHeight`] of the [`ParsedInput`].
+    pub fn line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
+        self.text_input = self.text_input.line_height(line_height);
+        self
+    }
+
+    /// Sets the horizontal alignment of the [`ParsedInput`].
+    pub fn align_x(mut self, alignment: impl Into<alignment::Horizontal>) -> Self {
+        self.text_input = self.text_input.align_x(alignment);
+        self
+    }
+
+    /// Sets the style of the [`ParsedInput`].
+    ///
+    /// Like [`ParsedInput::style`](super::ParsedInput::style), the function also takes
+    /// whether the string matched the value (true) or not (false).
+    pub fn style(mut self, style: impl Fn(&Theme, Status, bool) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        let valid = self.logic.valid.clone();
+        self.text_input = self.text_input.style(move |t, s| style(t, s, valid.get()));
+        self
+    }
+
+    /// Sets the style class of the [`ParsedInput`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.text_input = self.text_input.class(class);
+        self
+    }
+}
+
+impl<'a, T: FromStr<Err = E>, E, Message: Clone, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ParsedInput<'a, T, E, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog,
+{
+    fn state(&self) -> tree::State {
+        self.text_input.state()
+    }
+
+    fn tag(&self) -> tree::Tag {
+        self.text_input.tag()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.text_input.diff(tree);
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.text_input.children()
+    }
+
+    fn size(&self) -> Size<Length> {
+        <TextInput<'_, _, _, _> as Widget<_, _, _>>::size(&self.text_input)
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.text_input.size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        <TextInput<'_, _, _, _> as Widget<_, _, _>>::layout(
+            &mut self.text_input,
+            tree,
+            renderer,
+            limits,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.logic.valid.set(self.logic.is_valid());
+
+        <TextInput<'_, _, _, _> as Widget<_, _, _>>::draw(
+            &self.text_input,
+            tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.text_input.operate(tree, layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if self.jump(tree, event) || self.step_value(tree, event, shell) {
+            shell.capture_event();
+            return;
+        }
+
+        let mut messages = Vec::new();
+        let mut sub_shell = Shell::new(&mut messages);
+        self.text_input.update(
+            tree,
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut sub_shell,
+            viewport,
+        );
+
+        if sub_shell.is_event_captured() {
+            shell.capture_event();
+        }
+        shell.request_redraw_at(sub_shell.redraw_request());
+        shell.request_input_method(sub_shell.input_method());
+        if sub_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+        if sub_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+
+        for inner in messages {
+            let edited = match &inner {
+                InnerMessage::Input(str) | InnerMessage::Paste(str) => {
+                    self.logic.group_separator.and(Some(str.clone()))
+                }
+                InnerMessage::Submit => None,
+            };
+
+            if let Some(message) = self.logic.convert(inner) {
+                shell.publish(message);
+
+                if let Some(edited) = edited {
+                    self.regroup_cursor(tree, &edited);
+                }
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.text_input
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+}
+
+impl<'a, T: FromStr<Err = E>, E, Message: Clone, Theme, Renderer>
+    ParsedInput<'a, T, E, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog,
+{
+    /// Moves the cursor after the next separator if the event is the typing of the separator.
+    ///
+    /// Returns true if the cursor jumped.
+    fn jump(&self, tree: &mut Tree, event: &Event) -> bool {
+        let Some(separator) = self.logic.jump_on else {
+            return false;
+        };
+
+        let Event::Keyboard(keyboard::Event::KeyPressed {
+            text: Some(text), ..
+        }) = event
+        else {
+            return false;
+        };
+
+        if text.chars().ne([separator]) {
+            return false;
+        }
+
+        let state = tree
+            .state
+            .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+
+        if !state.is_focused() {
+            return false;
+        }
+
+        let value = text_input::Value::new(&self.logic.content.string);
+        let cursor::State::Index(index) = state.cursor().state(&value) else {
+            return false;
+        };
+
+        match self.logic.jump_target(separator, index) {
+            Some(target) => {
+                state.move_cursor_to(target);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Steps the value if the event is the press of an arrow key.
+    ///
+    /// Returns true if the value was stepped.
+    fn step_value(&self, tree: &mut Tree, event: &Event, shell: &mut Shell<'_, Message>) -> bool {
+        let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key),
+            modifiers,
+            ..
+        }) = event
+        else {
+            return false;
+        };
+
+        let direction = match key {
+            keyboard::key::Named::ArrowUp => 1.,
+            keyboard::key::Named::ArrowDown => -1.,
+            _ => return false,
+        };
+
+        let state = tree
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>();
+
+        if !state.is_focused() {
+            return false;
+        }
+
+        let fine = modifiers.control() || modifiers.alt();
+        match self.logic.stepped(direction, modifiers.shift(), fine) {
+            Some(message) => {
+                shell.publish(message);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves the cursor after the text `edited` is regrouped, so that it stays after the same digit.
+    fn regroup_cursor(&self, tree: &mut Tree, edited: &str) {
+        let Some(separator) = self.logic.group_separator else {
+            return;
+        };
+
+        let state = tree
+            .state
+            .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+
+        let cursor::State::Index(index) = state.cursor().state(&text_input::Value::new(edited))
+        else {
+            return;
+        };
+
+        state.move_cursor_to(self.logic.regrouped_index(separator, edited, index));
+    }
+}
+
+impl<'a, T: FromStr<Err = E>, E, Message: Clone + 'a, Theme: 'a, Renderer: 'a>
+    From<ParsedInput<'a, T, E, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog,
+{
+    fn from(value: ParsedInput<'a, T, E, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
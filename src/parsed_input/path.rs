@@ -0,0 +1,289 @@
+use std::{
+    fmt::Display,
+    ops::Deref,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use iced::{
+    Length, Pixels,
+    advanced::{graphics::core::Element, text},
+    alignment::Vertical,
+    widget::{
+        Row, button,
+        text_input::{self, Id, Status, Style, StyleFn},
+    },
+};
+
+use super::{Content, Parsed, ParsedInput};
+
+/// A [`PathBuf`] that can be used in a [`ParsedInput`].
+///
+/// It is displayed with [`Path::display`], so non UTF-8 paths are displayed lossily.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FilePath {
+    /// The inner path.
+    pub path: PathBuf,
+}
+
+/// The error that can occur when parsing or validating a [`FilePath`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PathError {
+    /// The path is empty.
+    Empty,
+    /// The path does not exist.
+    NotFound,
+    /// The extension of the path is not one of the allowed ones.
+    InvalidExtension(Vec<String>),
+}
+
+impl FromStr for FilePath {
+    type Err = PathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            Err(PathError::Empty)
+        } else {
+            Ok(PathBuf::from(s).into())
+        }
+    }
+}
+
+impl Display for FilePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.path.display())
+    }
+}
+
+impl Display for PathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathError::Empty => write!(f, "empty path"),
+            PathError::NotFound => write!(f, "path not found"),
+            PathError::InvalidExtension(extensions) => {
+                write!(
+                    f,
+                    "expected one of the extensions: {}",
+                    extensions.join(", ")
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for PathError {}
+
+impl From<PathBuf> for FilePath {
+    fn from(value: PathBuf) -> Self {
+        Self { path: value }
+    }
+}
+
+impl From<FilePath> for PathBuf {
+    fn from(value: FilePath) -> Self {
+        value.path
+    }
+}
+
+impl Deref for FilePath {
+    type Target = Path;
+
+    fn deref(&self) -> &Self::Target {
+        &self.path
+    }
+}
+
+/// The validation rules of a [`PathInput`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PathRules {
+    /// If true, the path must exist.
+    pub must_exist: bool,
+    /// The allowed extensions (without the leading dot). If empty, all extensions are allowed.
+    pub extensions: Vec<String>,
+}
+
+impl PathRules {
+    /// Checks that a path follows the rules.
+    ///
+    /// This is usefull to validate paths that did not come from the [`PathInput`],
+    /// for example before writing the result of a file dialog into the [`Content`].
+    pub fn check(&self, path: &Path) -> Result<(), PathError> {
+        if !self.extensions.is_empty() {
+            let valid = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| self.extensions.iter().any(|e| e.eq_ignore_ascii_case(ext)));
+
+            if !valid {
+                return Err(PathError::InvalidExtension(self.extensions.clone()));
+            }
+        }
+
+        if self.must_exist && !path.exists() {
+            return Err(PathError::NotFound);
+        }
+
+        Ok(())
+    }
+}
+
+/// A [`ParsedInput`] for [`FilePath`]s, with optional validation and a slot for a "browse" button.
+///
+/// The browse button is meant to open a file dialog. The selected path can then be
+/// written back in the [`Content`], for example with
+/// `*content.borrow_mut() = path.into()`.
+pub struct PathInput<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog,
+{
+    input: ParsedInput<'a, FilePath, PathError, Message, Theme, Renderer>,
+    rules: PathRules,
+    browse: Option<Element<'a, Message, Theme, Renderer>>,
+    spacing: f32,
+}
+
+impl<'a, Message, Theme, Renderer> PathInput<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog + 'a,
+{
+    /// Creates a new [`PathInput`] from a [`Content`].
+    pub fn new(placeholder: &str, content: &'a Content<FilePath, PathError>) -> Self {
+        Self {
+            input: ParsedInput::new(placeholder, content),
+            rules: PathRules::default(),
+            browse: None,
+            spacing: 5.,
+        }
+    }
+
+    /// Sets whether the path must exist to be valid.
+    pub fn must_exist(mut self, must_exist: bool) -> Self {
+        self.rules.must_exist = must_exist;
+        self
+    }
+
+    /// Sets the extensions (without the leading dot) allowed for the path.
+    pub fn extensions(mut self, extensions: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.rules.extensions = extensions.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the element placed after the field, usually a button opening a file dialog.
+    pub fn browse(mut self, browse: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.browse = Some(browse.into());
+        self
+    }
+
+    /// Places a default "Browse…" button after the field, that produces the given message when pressed.
+    pub fn on_browse(self, on_browse: Message) -> Self
+    where
+        Message: Clone + 'a,
+        Theme: button::Catalog + iced::widget::text::Catalog,
+        Renderer: 'a,
+    {
+        self.browse(button("Browse…").on_press(on_browse))
+    }
+
+    /// Sets the spacing between the field and the browse button.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the [`Id`] of the underlying [`TextInput`](iced::widget::TextInput).
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.input = self.input.id(id);
+        self
+    }
+
+    /// Sets the message that should be produced when some text is typed into the [`PathInput`].
+    ///
+    /// If this method is not called, the field will be disabled.
+    pub fn on_input(
+        mut self,
+        on_input: impl Fn(Parsed<FilePath, PathError>) -> Message + 'a,
+    ) -> Self {
+        self.input = self.input.on_input(on_input);
+        self
+    }
+
+    /// Sets the message that should be produced when the field is
+    /// focused and the enter key is pressed.
+    pub fn on_submit(mut self, on_submit: Message) -> Self {
+        self.input = self.input.on_submit(on_submit);
+        self
+    }
+
+    /// Sets the message that should be produced when some text is pasted into the field.
+    pub fn on_paste(
+        mut self,
+        on_paste: impl Fn(Parsed<FilePath, PathError>) -> Message + 'a,
+    ) -> Self {
+        self.input = self.input.on_paste(on_paste);
+        self
+    }
+
+    /// Sets the width of the field.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.input = self.input.width(width);
+        self
+    }
+
+    /// Sets the text size of the field.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.input = self.input.size(size);
+        self
+    }
+
+    /// Sets the style of the field.
+    ///
+    /// See [`ParsedInput::style`].
+    pub fn style(mut self, style: impl Fn(&Theme, Status, bool) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.input = self.input.style(style);
+        self
+    }
+
+    /// Sets the style class of the field.
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.input = self.input.class(class);
+        self
+    }
+}
+
+impl<'a, Message: Clone + 'a, Theme: 'a, Renderer: 'a> From<PathInput<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog,
+{
+    fn from(value: PathInput<'a, Message, Theme, Renderer>) -> Self {
+        let rules = value.rules;
+        let input = value.input.validate(move |path| rules.check(path));
+
+        Row::new()
+            .push(input)
+            .push_maybe(value.browse)
+            .spacing(value.spacing)
+            .align_y(Vertical::Center)
+            .into()
+    }
+}
+
+/// Creates a [`PathInput`], a [`ParsedInput`] for paths with a browse button slot.
+pub fn path_input<'a, Message, Theme, Renderer>(
+    placeholder: &str,
+    content: &'a Content<FilePath, PathError>,
+) -> PathInput<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog + 'a,
+{
+    PathInput::new(placeholder, content)
+}
@@ -0,0 +1,144 @@
+use std::{fmt::Display, ops::Deref, str::FromStr};
+
+use iced::{advanced::text, widget::text_input};
+
+use super::{Content, ParsedInput};
+
+/// A [`String`] that is valid if it matches a pattern.
+///
+/// Parsing never fails by itself, the pattern is checked by the [`ParsedInput`]
+/// created with [`pattern_input`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PatternString {
+    /// The inner string.
+    pub string: String,
+}
+
+/// The error produced when a [`PatternString`] does not match its pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PatternError {
+    /// A description of what is expected, displayed as the error message.
+    pub description: String,
+}
+
+impl FromStr for PatternString {
+    type Err = PatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.to_string().into())
+    }
+}
+
+impl Display for PatternString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.string)
+    }
+}
+
+impl Display for PatternError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.description)
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+impl From<String> for PatternString {
+    fn from(value: String) -> Self {
+        Self { string: value }
+    }
+}
+
+impl From<PatternString> for String {
+    fn from(value: PatternString) -> Self {
+        value.string
+    }
+}
+
+impl Deref for PatternString {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.string
+    }
+}
+
+/// Creates a [`ParsedInput`] for strings that are valid if `matches` returns true.
+///
+/// When the string does not match, the error of the [`Content`] is a
+/// [`PatternError`] holding the `description`.
+///
+/// ```
+/// # use more_iced_aw::parsed_input::*;
+/// # fn view(content: &Content<PatternString, PatternError>) -> iced::Element<'_, ()> {
+/// pattern_input("Username", content, |s| {
+///     !s.is_empty() && s.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+/// }, "only lowercase letters and digits")
+/// .into()
+/// # }
+/// ```
+pub fn pattern_input<'a, Message, Theme, Renderer>(
+    placeholder: &str,
+    content: &'a Content<PatternString, PatternError>,
+    matches: impl Fn(&str) -> bool + 'a,
+    description: impl Into<String>,
+) -> ParsedInput<'a, PatternString, PatternError, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog + 'a,
+{
+    let description = description.into();
+
+    ParsedInput::new(placeholder, content).validate(move |string| {
+        if matches(string) {
+            Ok(())
+        } else {
+            Err(PatternError {
+                description: description.clone(),
+            })
+        }
+    })
+}
+
+/// Creates a [`ParsedInput`] for strings that are valid if they entirely match `regex`.
+///
+/// When the string does not match, the error of the [`Content`] is a
+/// [`PatternError`] holding the `description`.
+#[cfg(feature = "regex")]
+pub fn regex_input<'a, Message, Theme, Renderer>(
+    placeholder: &str,
+    content: &'a Content<PatternString, PatternError>,
+    regex: regex::Regex,
+    description: impl Into<String>,
+) -> ParsedInput<'a, PatternString, PatternError, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog + 'a,
+{
+    let regex = anchored(regex);
+
+    pattern_input(
+        placeholder,
+        content,
+        move |string| {
+            regex
+                .find(string)
+                .is_some_and(|m| m.start() == 0 && m.end() == string.len())
+        },
+        description,
+    )
+}
+
+/// Anchors `regex` at both ends of the text, so that it only matches whole strings.
+///
+/// A verbose regex ending in a comment would swallow the closing group, a newline
+/// ends that comment. If anchoring fails anyway, `regex` is returned as is and the
+/// caller must check that its matches cover the whole text.
+#[cfg(feature = "regex")]
+fn anchored(regex: regex::Regex) -> regex::Regex {
+    regex::Regex::new(&format!("^(?:{})$", regex.as_str()))
+        .or_else(|_| regex::Regex::new(&format!("^(?:{}\n)$", regex.as_str())))
+        .unwrap_or(regex)
+}
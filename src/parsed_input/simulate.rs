@@ -0,0 +1,90 @@
+use std::str::FromStr;
+
+use super::{Content, InnerMessage, Parsed, ParsedInput};
+
+/// Simulates a user editing a [`ParsedInput`], without any renderer.
+///
+/// The edits go through the same filtering, parsing and validation as
+/// in the widget, and the produced [`Parsed`] are used to update the [`Content`].
+/// The cursor is always assumed to be at the end of the text.
+///
+/// ```
+/// # use more_iced_aw::parsed_input::*;
+/// let mut simulator = Simulator::new(Content::<u8, _>::new(0), |content| {
+///     ParsedInput::new("", content).allowed_chars(numeric())
+/// });
+///
+/// simulator.backspace().type_str("4a2");
+/// assert_eq!(**simulator.content(), 42);
+///
+/// simulator.paste("0");
+/// assert!(!simulator.content().is_valid());
+/// ```
+pub struct Simulator<T, E, F> {
+    content: Content<T, E>,
+    build: F,
+}
+
+impl<T, E, F> Simulator<T, E, F>
+where
+    T: FromStr<Err = E> + Clone,
+    E: Clone,
+    F: for<'a> Fn(&'a Content<T, E>) -> ParsedInput<'a, T, E, Parsed<T, E>>,
+{
+    /// Creates a new [`Simulator`] from a [`Content`] and a function building the
+    /// [`ParsedInput`] to simulate.
+    ///
+    /// The messages of the built [`ParsedInput`] are overridden by the [`Simulator`].
+    pub fn new(content: Content<T, E>, build: F) -> Self {
+        Self { content, build }
+    }
+
+    /// Types the characters of `str` one by one.
+    pub fn type_str(&mut self, str: &str) -> &mut Self {
+        for c in str.chars() {
+            let mut string = self.content.string.clone();
+            string.push(c);
+            self.edit(InnerMessage::Input(string));
+        }
+        self
+    }
+
+    /// Removes the last character.
+    pub fn backspace(&mut self) -> &mut Self {
+        let mut string = self.content.string.clone();
+        if string.pop().is_some() {
+            self.edit(InnerMessage::Input(string));
+        }
+        self
+    }
+
+    /// Pastes `str` at once.
+    pub fn paste(&mut self, str: &str) -> &mut Self {
+        let string = format!("{}{str}", self.content.string);
+        self.edit(InnerMessage::Paste(string));
+        self
+    }
+
+    /// Returns the current [`Content`].
+    pub fn content(&self) -> &Content<T, E> {
+        &self.content
+    }
+
+    /// Consumes the [`Simulator`] and returns the resulting [`Content`].
+    pub fn into_content(self) -> Content<T, E> {
+        self.content
+    }
+
+    /// Sends an edit through the [`ParsedInput`] and updates the [`Content`] with the result.
+    fn edit(&mut self, edit: InnerMessage) {
+        let parsed = (self.build)(&self.content)
+            .on_input(|parsed| parsed)
+            .on_paste(|parsed| parsed)
+            .logic
+            .convert(edit);
+
+        if let Some(parsed) = parsed {
+            self.content.update(parsed);
+        }
+    }
+}
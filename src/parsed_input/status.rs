@@ -0,0 +1,60 @@
+use std::any::Any;
+
+use iced::{
+    Rectangle, Task,
+    advanced::widget::{self, Operation, operation::Outcome},
+    widget::text_input::Id,
+};
+
+/// The validity of a [`ParsedInput`](super::ParsedInput), as exposed to accessibility tools.
+///
+/// Every [`ParsedInput`](super::ParsedInput) reports it through
+/// [`Operation::custom`], so it can be retrieved by any [`Operation`],
+/// for example with [`parse_status`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseStatus {
+    /// Whether the text of the input matches its value.
+    pub valid: bool,
+    /// The description of the error, if the input is invalid and
+    /// [`describe_error`](super::ParsedInput::describe_error) was called.
+    pub error: Option<String>,
+}
+
+/// An [`Operation`] looking for the [`ParseStatus`] of the [`ParsedInput`](super::ParsedInput)
+/// with the given [`Id`].
+struct FindParseStatus {
+    target: widget::Id,
+    status: Option<ParseStatus>,
+}
+
+impl Operation<Option<ParseStatus>> for FindParseStatus {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<Option<ParseStatus>>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
+        if id == Some(&self.target)
+            && let Some(status) = state.downcast_ref::<ParseStatus>()
+        {
+            self.status = Some(status.clone());
+        }
+    }
+
+    fn finish(&self) -> Outcome<Option<ParseStatus>> {
+        Outcome::Some(self.status.clone())
+    }
+}
+
+/// Produces a [`Task`] that returns the [`ParseStatus`] of the [`ParsedInput`](super::ParsedInput)
+/// with the given [`Id`], or [`None`] if there is no such input.
+pub fn parse_status(id: impl Into<Id>) -> Task<Option<ParseStatus>> {
+    widget::operate(FindParseStatus {
+        target: id.into().into(),
+        status: None,
+    })
+}
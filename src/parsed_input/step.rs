@@ -0,0 +1,97 @@
+use std::{
+    fmt::{Display, LowerExp},
+    str::FromStr,
+};
+
+use super::{Fixed, Money, Scientific};
+
+/// A value that can be incremented or decremented by an amount.
+///
+/// It is used by [`ParsedInput::step`](super::ParsedInput::step) to change the value with the arrow keys.
+pub trait Step {
+    /// Returns the value moved by `amount`.
+    ///
+    /// Integers round the amount, move by at least one for any non-zero amount
+    /// and saturate at their bounds.
+    fn step(&self, amount: f64) -> Self;
+}
+
+macro_rules! impl_step_int {
+    ($($t:ty),*) => {
+        $(
+            impl Step for $t {
+                fn step(&self, amount: f64) -> Self {
+                    if amount == 0. || amount.is_nan() {
+                        return *self;
+                    }
+
+                    let amount = if amount.abs() < 1. {
+                        amount.signum()
+                    } else {
+                        amount.round()
+                    };
+
+                    // The cast saturates, and the arithmetic stays exact for big integers.
+                    if amount > 0. {
+                        self.saturating_add(amount as $t)
+                    } else {
+                        self.saturating_sub(amount.abs() as $t)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_step_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl Step for f32 {
+    fn step(&self, amount: f64) -> Self {
+        self + amount as f32
+    }
+}
+
+impl Step for f64 {
+    fn step(&self, amount: f64) -> Self {
+        self + amount
+    }
+}
+
+impl<T: Step + FromStr + LowerExp, const PRECISION: usize> Step for Scientific<T, PRECISION> {
+    fn step(&self, amount: f64) -> Self {
+        self.value.step(amount).into()
+    }
+}
+
+impl<T: Step + FromStr + Display, const PRECISION: usize> Step for Fixed<T, PRECISION> {
+    fn step(&self, amount: f64) -> Self {
+        self.value.step(amount).into()
+    }
+}
+
+/// The amount is in major units (e.g. `1.0` is one dollar, not one cent).
+impl<const SYMBOL: char, const DECIMALS: u32> Step for Money<SYMBOL, DECIMALS> {
+    fn step(&self, amount: f64) -> Self {
+        Self::new(self.minor.step(amount * 10f64.powi(DECIMALS as i32)))
+    }
+}
+
+/// The multipliers applied to the step of a [`ParsedInput`](super::ParsedInput)
+/// when modifier keys are held.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StepMultipliers {
+    /// The multiplier applied when Shift is held.
+    pub large: f64,
+    /// The multiplier applied when Ctrl or Alt is held.
+    pub fine: f64,
+}
+
+impl Default for StepMultipliers {
+    fn default() -> Self {
+        Self {
+            large: 10.,
+            fine: 0.1,
+        }
+    }
+}
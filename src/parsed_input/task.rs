@@ -0,0 +1,35 @@
+use iced::{
+    Task,
+    widget::text_input::{self, Id},
+};
+
+/// Produces a [`Task`] that focuses the [`ParsedInput`](super::ParsedInput) with the given [`Id`].
+pub fn focus<T>(id: impl Into<Id>) -> Task<T> {
+    text_input::focus(id)
+}
+
+/// Produces a [`Task`] that selects all the text of the [`ParsedInput`](super::ParsedInput)
+/// with the given [`Id`].
+///
+/// Combined with [`focus`], it allows the user to directly retype a rejected value.
+pub fn select_all<T>(id: impl Into<Id>) -> Task<T> {
+    text_input::select_all(id)
+}
+
+/// Produces a [`Task`] that moves the cursor of the [`ParsedInput`](super::ParsedInput)
+/// with the given [`Id`] to the end.
+pub fn move_cursor_to_end<T>(id: impl Into<Id>) -> Task<T> {
+    text_input::move_cursor_to_end(id)
+}
+
+/// Produces a [`Task`] that moves the cursor of the [`ParsedInput`](super::ParsedInput)
+/// with the given [`Id`] to the front.
+pub fn move_cursor_to_front<T>(id: impl Into<Id>) -> Task<T> {
+    text_input::move_cursor_to_front(id)
+}
+
+/// Produces a [`Task`] that moves the cursor of the [`ParsedInput`](super::ParsedInput)
+/// with the given [`Id`] to the provided position.
+pub fn move_cursor_to<T>(id: impl Into<Id>, position: usize) -> Task<T> {
+    text_input::move_cursor_to(id, position)
+}
@@ -0,0 +1,572 @@
+use std::{cell::Cell, fmt::Display, ops::Deref, rc::Rc, str::FromStr};
+
+use iced::{
+    Length, Pixels, Point, Rectangle, Size,
+    advanced::{
+        Clipboard, Shell, Widget,
+        graphics::core::Element,
+        layout::{self, Limits, Node},
+        mouse, renderer, text,
+        widget::{Operation, Tree},
+    },
+    event, keyboard,
+    widget::{
+        TextInput,
+        text_input::{self, Status, Style, StyleFn},
+    },
+};
+
+use super::{Content, OnParsed, Parsed};
+
+/// The separator between the components of a [`Vector`] in its text representation.
+const SEPARATOR: char = ',';
+
+/// A vector of `N` components that can be used in a [`VectorInput`].
+///
+/// It is displayed as its components separated by commas (e.g. `1, 2.5, -3`),
+/// so the components must not contain commas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vector<T, const N: usize> {
+    /// The components of the vector.
+    pub components: [T; N],
+}
+
+/// The error that can occur when parsing a [`Vector`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VectorError<E> {
+    /// The number of components is wrong.
+    Count {
+        /// The expected number of components.
+        expected: usize,
+        /// The number of components found.
+        found: usize,
+    },
+    /// A component could not be parsed.
+    Component {
+        /// The index of the component.
+        index: usize,
+        /// The parsing error of the component.
+        error: E,
+    },
+}
+
+impl<T: FromStr, const N: usize> FromStr for Vector<T, N> {
+    type Err = VectorError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<_> = s.split(SEPARATOR).map(str::trim).collect();
+
+        if parts.len() != N {
+            return Err(VectorError::Count {
+                expected: N,
+                found: parts.len(),
+            });
+        }
+
+        let components = parts
+            .into_iter()
+            .enumerate()
+            .map(|(index, part)| {
+                part.parse()
+                    .map_err(|error| VectorError::Component { index, error })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            components: components
+                .try_into()
+                .unwrap_or_else(|_| unreachable!("The number of components was checked")),
+        })
+    }
+}
+
+impl<T: Display, const N: usize> Display for Vector<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, component) in self.components.iter().enumerate() {
+            if i != 0 {
+                write!(f, "{SEPARATOR} ")?;
+            }
+            write!(f, "{component}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: Display> Display for VectorError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VectorError::Count { expected, found } => {
+                write!(f, "expected {expected} components, found {found}")
+            }
+            VectorError::Component { index, error } => write!(f, "component {index}: {error}"),
+        }
+    }
+}
+
+impl<E: std::fmt::Debug + Display> std::error::Error for VectorError<E> {}
+
+impl<T: Default, const N: usize> Default for Vector<T, N> {
+    fn default() -> Self {
+        Self {
+            components: std::array::from_fn(|_| T::default()),
+        }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Vector<T, N> {
+    fn from(value: [T; N]) -> Self {
+        Self { components: value }
+    }
+}
+
+impl<T, const N: usize> From<Vector<T, N>> for [T; N] {
+    fn from(value: Vector<T, N>) -> Self {
+        value.components
+    }
+}
+
+impl<T, const N: usize> Deref for Vector<T, N> {
+    type Target = [T; N];
+
+    fn deref(&self) -> &Self::Target {
+        &self.components
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, const N: usize> serde::Serialize for Vector<T, N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(&self.components)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::Deserialize<'de> for Vector<T, N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let components = Vec::<T>::deserialize(deserializer)?;
+        let found = components.len();
+
+        components
+            .try_into()
+            .map(|components| Self { components })
+            .map_err(|_| {
+                serde::de::Error::invalid_length(found, &format!("{N} components").as_str())
+            })
+    }
+}
+
+/// A message produced by one of the fields of a [`VectorInput`].
+#[derive(Debug, Clone)]
+enum InnerMessage {
+    /// The text of a component changed.
+    Input(usize, String),
+    /// The user submited.
+    Submit,
+}
+
+/// An input for a [`Vector`], made of one field per component.
+///
+/// All the fields edit the same [`Content`], so the validity is shared:
+/// if one of the components is invalid, the whole vector is.
+/// Tab and Shift+Tab move the focus between the fields.
+/// Commas cannot be typed in the fields, since they separate the components.
+pub struct VectorInput<'a, T, E, const N: usize, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog,
+{
+    content: &'a Content<Vector<T, N>, VectorError<E>>,
+    inputs: Vec<TextInput<'a, InnerMessage, Theme, Renderer>>,
+    width: Length,
+    spacing: f32,
+    valid: Rc<Cell<bool>>,
+
+    on_input: Option<OnParsed<'a, Vector<T, N>, VectorError<E>, Message>>,
+    on_submit: Option<Message>,
+}
+
+impl<'a, T, E, const N: usize, Message, Theme, Renderer>
+    VectorInput<'a, T, E, N, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog + 'a,
+{
+    /// Creates a new [`VectorInput`] from a [`Content`].
+    ///
+    /// The `placeholders` are the placeholders of the fields (e.g. `["X", "Y", "Z"]`).
+    pub fn new(placeholders: [&str; N], content: &'a Content<Vector<T, N>, VectorError<E>>) -> Self {
+        let mut parts = content.string.splitn(N, SEPARATOR).map(str::trim);
+
+        let inputs = placeholders
+            .into_iter()
+            .map(|placeholder| TextInput::new(placeholder, parts.next().unwrap_or_default()))
+            .collect();
+
+        Self {
+            content,
+            inputs,
+            width: Length::Fill,
+            spacing: 5.,
+            valid: Rc::new(Cell::new(content.is_valid())),
+            on_input: None,
+            on_submit: None,
+        }
+    }
+
+    /// Sets the message that should be produced when one of the components is edited.
+    ///
+    /// If this method is not called, the [`VectorInput`] will be disabled.
+    pub fn on_input(
+        mut self,
+        on_input: impl Fn(Parsed<Vector<T, N>, VectorError<E>>) -> Message + 'a,
+    ) -> Self {
+        self.inputs = self
+            .inputs
+            .into_iter()
+            .enumerate()
+            .map(|(i, input)| input.on_input(move |str| InnerMessage::Input(i, str)))
+            .collect();
+        self.on_input = Some(Box::new(on_input));
+        self
+    }
+
+    /// Sets the message that should be produced when one of the fields is
+    /// focused and the enter key is pressed.
+    pub fn on_submit(mut self, on_submit: Message) -> Self {
+        self.inputs = self
+            .inputs
+            .into_iter()
+            .map(|input| input.on_submit(InnerMessage::Submit))
+            .collect();
+        self.on_submit = Some(on_submit);
+        self
+    }
+
+    /// Sets the width of the [`VectorInput`].
+    ///
+    /// The fields share the width equally.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the spacing between the fields.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the fields.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        let size = size.into();
+        self.inputs = self.inputs.into_iter().map(|input| input.size(size)).collect();
+        self
+    }
+
+    /// Sets the style of the fields.
+    ///
+    /// See [`ParsedInput::style`](super::ParsedInput::style).
+    pub fn style(mut self, style: impl Fn(&Theme, Status, bool) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        let style = Rc::new(style);
+
+        self.inputs = self
+            .inputs
+            .into_iter()
+            .map(|input| {
+                let style = style.clone();
+                let valid = self.valid.clone();
+                input.style(move |t, s| style(t, s, valid.get()))
+            })
+            .collect();
+        self
+    }
+}
+
+impl<'a, T, E, const N: usize, Message, Theme, Renderer>
+    VectorInput<'a, T, E, N, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog,
+{
+    /// Moves the focus to the next or previous field if the event is a press of Tab.
+    ///
+    /// Returns true if the focus moved.
+    fn tab(&self, tree: &mut Tree, event: &iced::Event) -> bool {
+        let iced::Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(keyboard::key::Named::Tab),
+            modifiers,
+            ..
+        }) = event
+        else {
+            return false;
+        };
+
+        let states = || {
+            tree.children
+                .iter()
+                .map(|child| child.state.downcast_ref::<text_input::State<Renderer::Paragraph>>())
+        };
+
+        let Some(focused) = states().position(|state| state.is_focused()) else {
+            return false;
+        };
+
+        let target = if modifiers.shift() {
+            focused.checked_sub(1)
+        } else {
+            Some(focused + 1).filter(|i| *i < tree.children.len())
+        };
+
+        let Some(target) = target else {
+            return false;
+        };
+
+        tree.children[focused]
+            .state
+            .downcast_mut::<text_input::State<Renderer::Paragraph>>()
+            .unfocus();
+
+        let target = tree.children[target]
+            .state
+            .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+        target.focus();
+        target.select_all();
+
+        true
+    }
+
+    /// Converts a message of the fields.
+    fn convert(&self, inner: InnerMessage) -> Option<Message>
+    where
+        T: FromStr<Err = E>,
+        Message: Clone,
+    {
+        match inner {
+            InnerMessage::Input(index, str) => {
+                let mut parts: Vec<_> = self.content.string.splitn(N, SEPARATOR).map(str::trim).collect();
+                parts.resize(N, "");
+
+                // A separator would move the rest of the text to the next components.
+                let str: String = str.chars().filter(|c| *c != SEPARATOR).collect();
+                if parts[index] == str {
+                    return None;
+                }
+                parts[index] = &str;
+
+                let joined = parts.join(&format!("{SEPARATOR} "));
+                self.on_input.as_ref().map(|f| f(Parsed::from_string(&joined)))
+            }
+            InnerMessage::Submit => self.on_submit.clone(),
+        }
+    }
+}
+
+impl<'a, T, E, const N: usize, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for VectorInput<'a, T, E, N, Message, Theme, Renderer>
+where
+    T: FromStr<Err = E>,
+    Message: Clone,
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.inputs
+            .iter()
+            .map(|input| Tree::new(input as &dyn Widget<_, _, _>))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let children: Vec<_> = self
+            .inputs
+            .iter()
+            .map(|input| input as &dyn Widget<_, _, _>)
+            .collect();
+        tree.diff_children(&children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let max = limits.max();
+
+        let total_spacing = self.spacing * N.saturating_sub(1) as f32;
+        let width = ((max.width - total_spacing) / N.max(1) as f32).max(0.);
+
+        let mut x = 0.;
+        let mut height = 0f32;
+
+        let children = self
+            .inputs
+            .iter()
+            .zip(&mut tree.children)
+            .map(|(input, tree)| {
+                let limits = Limits::new(Size::ZERO, Size::new(width, max.height));
+                let node = Widget::layout(input, tree, renderer, &limits).move_to(Point::new(x, 0.));
+
+                x += width + self.spacing;
+                height = height.max(node.size().height);
+
+                node
+            })
+            .collect();
+
+        let size = limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(width * N as f32 + total_spacing, height),
+        );
+
+        Node::with_children(size, children)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.valid.set(self.content.is_valid());
+
+        for ((input, tree), layout) in self.inputs.iter().zip(&tree.children).zip(layout.children()) {
+            Widget::draw(input, tree, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: layout::Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for ((input, tree), layout) in self
+                .inputs
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                Widget::operate(input, tree, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if self.tab(tree, &event) {
+            shell.request_redraw(iced::window::RedrawRequest::NextFrame);
+            return event::Status::Captured;
+        }
+
+        let mut messages = Vec::new();
+        let mut sub_shell = Shell::new(&mut messages);
+
+        let status = self
+            .inputs
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((input, tree), layout)| {
+                Widget::on_event(
+                    input,
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    &mut sub_shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        let redraw_request = sub_shell.redraw_request();
+        let is_layout_invalid = sub_shell.is_layout_invalid();
+        let are_widgets_invalid = sub_shell.are_widgets_invalid();
+
+        for inner in messages {
+            if let Some(message) = self.convert(inner) {
+                shell.publish(message);
+            }
+        }
+
+        if let Some(redraw_request) = redraw_request {
+            shell.request_redraw(redraw_request);
+        }
+        if is_layout_invalid {
+            shell.invalidate_layout();
+        }
+        if are_widgets_invalid {
+            shell.invalidate_widgets();
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.inputs
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((input, tree), layout)| {
+                Widget::mouse_interaction(input, tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+impl<'a, T, E, const N: usize, Message, Theme, Renderer>
+    From<VectorInput<'a, T, E, N, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    T: FromStr<Err = E> + 'a,
+    E: 'a,
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Theme: text_input::Catalog + 'a,
+{
+    fn from(value: VectorInput<'a, T, E, N, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a [`VectorInput`], an input for a [`Vector`] with one field per component.
+pub fn vector_input<'a, T, E, const N: usize, Message, Theme, Renderer>(
+    placeholders: [&str; N],
+    content: &'a Content<Vector<T, N>, VectorError<E>>,
+) -> VectorInput<'a, T, E, N, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog + 'a,
+{
+    VectorInput::new(placeholders, content)
+}
@@ -0,0 +1,128 @@
+use std::{fmt::Display, str::FromStr};
+
+use iced::{
+    Color, Length, Pixels,
+    advanced::{Widget, graphics::core::Element, text},
+    widget::{Column, Row, Text, text_input},
+};
+
+use super::ParsedInput;
+
+/// A [`ParsedInput`] with the error of its [`Content`](super::Content) displayed below it.
+///
+/// The space of the error text is always reserved, so the layout does not
+/// move when the input becomes invalid.
+/// It is created with [`ParsedInput::with_error`].
+pub struct ParsedInputWithError<'a, T, E, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog,
+{
+    input: ParsedInput<'a, T, E, Message, Theme, Renderer>,
+    text_size: Pixels,
+    text_color: Option<Color>,
+    spacing: f32,
+    counter: bool,
+}
+
+impl<'a, T, E, Message, Theme, Renderer> ParsedInput<'a, T, E, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog,
+{
+    /// Displays the error of the [`Content`](super::Content) below the [`ParsedInput`].
+    pub fn with_error(self) -> ParsedInputWithError<'a, T, E, Message, Theme, Renderer> {
+        ParsedInputWithError {
+            input: self,
+            text_size: Pixels(16.),
+            text_color: None,
+            spacing: 2.,
+            counter: false,
+        }
+    }
+}
+
+impl<'a, T, E, Message, Theme, Renderer> ParsedInputWithError<'a, T, E, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog,
+{
+    /// Sets the size of the error text.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into();
+        self
+    }
+
+    /// Sets the color of the error text.
+    ///
+    /// If this method is not called, the default text color of the theme is used.
+    pub fn text_color(mut self, color: impl Into<Color>) -> Self {
+        self.text_color = Some(color.into());
+        self
+    }
+
+    /// Sets the spacing between the [`ParsedInput`] and the error text.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets whether the number of characters is displayed after the error text.
+    ///
+    /// It is displayed as `length/max` if the [`ParsedInput`] has a
+    /// [`max_length`](ParsedInput::max_length), and as `length` otherwise.
+    pub fn counter(mut self, counter: bool) -> Self {
+        self.counter = counter;
+        self
+    }
+}
+
+impl<'a, T: FromStr<Err = E>, E: Display, Message: Clone + 'a, Theme: 'a, Renderer: 'a>
+    From<ParsedInputWithError<'a, T, E, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: text_input::Catalog + iced::widget::text::Catalog,
+    <Theme as iced::widget::text::Catalog>::Class<'a>: From<iced::widget::text::StyleFn<'a, Theme>>,
+{
+    fn from(value: ParsedInputWithError<'a, T, E, Message, Theme, Renderer>) -> Self {
+        let error = value
+            .input
+            .logic
+            .content
+            .get_error()
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_default();
+
+        let size = value.text_size;
+        let height = text::LineHeight::default().to_absolute(size);
+
+        let text = |content: String| {
+            let text = Text::new(content).size(size).height(Length::Fixed(height.0));
+            match value.text_color {
+                Some(color) => text.color(color),
+                None => text,
+            }
+        };
+
+        let counter = value.counter.then(|| {
+            let length = value.input.logic.length();
+            text(match value.input.logic.max_length {
+                Some(max_length) => format!("{length}/{max_length}"),
+                None => length.to_string(),
+            })
+        });
+
+        let footer = Row::new()
+            .push(text(error).width(Length::Fill))
+            .push_maybe(counter)
+            .width(Widget::size_hint(&value.input).width);
+
+        Column::new()
+            .push(value.input)
+            .push(footer)
+            .spacing(value.spacing)
+            .into()
+    }
+}
@@ -0,0 +1,505 @@
+//! A piano keyboard.
+//!
+//! The [`Piano`] draws the keys of a range of MIDI notes, from a single octave to the 88 keys of
+//! a full piano ([`Piano::FULL`]). Pressing a key with the mouse or a finger produces the message
+//! of [`Piano::on_note_on`], and releasing it the one of [`Piano::on_note_off`]. Sliding over the
+//! keys while pressing plays a glissando: each key is released when the next one is pressed.
+//!
+//! The keys played by other sources, such as a MIDI input or a sequencer, are highlighted with
+//! [`Piano::pressed`].
+
+use std::ops::RangeInclusive;
+
+use iced::{
+    Border, Color, Element, Length, Pixels, Point, Rectangle, Size, Theme,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, renderer,
+        text::{self, LineHeight, Shaping, Text, Wrapping},
+        widget::{Tree, tree},
+    },
+    alignment, event, touch,
+};
+
+/// The width of a black key, relative to a white key.
+const BLACK_WIDTH: f32 = 0.6;
+/// The height of a black key, relative to a white key.
+const BLACK_HEIGHT: f32 = 0.62;
+
+/// Returns whether a MIDI note is played by a black key.
+pub fn is_black(note: u8) -> bool {
+    matches!(note % 12, 1 | 3 | 6 | 8 | 10)
+}
+
+/// Returns the name of a MIDI note, with its octave.
+///
+/// ```
+/// use more_iced_aw::piano::note_name;
+///
+/// assert_eq!(note_name(60), "C4");
+/// assert_eq!(note_name(69), "A4");
+/// assert_eq!(note_name(22), "A#0");
+/// ```
+pub fn note_name(note: u8) -> String {
+    const NAMES: [&str; 12] = [
+        "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+    ];
+
+    format!(
+        "{}{}",
+        NAMES[usize::from(note % 12)],
+        i16::from(note / 12) - 1
+    )
+}
+
+/// The appearance of a [`Piano`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color of the white keys.
+    pub white: Color,
+    /// The color of the black keys.
+    pub black: Color,
+    /// The color of the pressed keys.
+    pub pressed: Color,
+    /// The color of the borders of the keys.
+    pub border: Color,
+    /// The color of the labels of the octaves.
+    pub label: Color,
+}
+
+/// The theme catalog of a [`Piano`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Piano`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Piano`].
+///
+/// The keys keep their usual colors whatever the theme.
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        white: Color::WHITE,
+        black: iced::color!(0x22, 0x22, 0x22),
+        pressed: palette.primary.base.color,
+        border: iced::color!(0x88, 0x88, 0x88),
+        label: iced::color!(0x88, 0x88, 0x88),
+    }
+}
+
+/// The state of a [`Piano`].
+#[derive(Debug, Clone, Default)]
+struct State {
+    /// The note held by the mouse.
+    mouse: Option<u8>,
+    /// The notes held by the fingers.
+    fingers: Vec<(touch::Finger, u8)>,
+}
+
+impl State {
+    fn is_held(&self, note: u8) -> bool {
+        self.mouse == Some(note) || self.fingers.iter().any(|(_, held)| *held == note)
+    }
+}
+
+/// A piano keyboard, created with [`piano`].
+pub struct Piano<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    range: RangeInclusive<u8>,
+    pressed: Vec<u8>,
+    on_note_on: Option<Box<dyn Fn(u8) -> Message + 'a>>,
+    on_note_off: Option<Box<dyn Fn(u8) -> Message + 'a>>,
+    labels: bool,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> Piano<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// The range of the 88 keys of a piano, from A0 to C8.
+    pub const FULL: RangeInclusive<u8> = 21..=108;
+
+    /// Creates a new [`Piano`] with the keys of the given range of MIDI notes.
+    ///
+    /// The range is extended to start and end with white keys.
+    pub fn new(range: RangeInclusive<u8>) -> Self {
+        let mut start = (*range.start()).min(127);
+        let mut end = (*range.end()).clamp(start, 127);
+        if is_black(start) {
+            start -= 1;
+        }
+        if is_black(end) {
+            end += 1;
+        }
+
+        Self {
+            range: start..=end,
+            pressed: Vec::new(),
+            on_note_on: None,
+            on_note_off: None,
+            labels: true,
+            width: Length::Fill,
+            height: Length::Fixed(120.),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the notes to display as pressed, usually played by another source.
+    pub fn pressed(mut self, notes: impl IntoIterator<Item = u8>) -> Self {
+        self.pressed = notes.into_iter().collect();
+        self
+    }
+
+    /// Sets the message produced when a key is pressed, with its MIDI note.
+    pub fn on_note_on(mut self, on_note_on: impl Fn(u8) -> Message + 'a) -> Self {
+        self.on_note_on = Some(Box::new(on_note_on));
+        self
+    }
+
+    /// Sets the message produced when a key is released, with its MIDI note.
+    pub fn on_note_off(mut self, on_note_off: impl Fn(u8) -> Message + 'a) -> Self {
+        self.on_note_off = Some(Box::new(on_note_off));
+        self
+    }
+
+    /// Sets whether the C keys are labeled with their octave, when they are wide enough. Enabled
+    /// by default.
+    pub fn labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Sets the width of the [`Piano`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Piano`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`Piano`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Piano`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the notes of the keys with their bounds, the white keys first.
+    fn keys(&self, bounds: Rectangle) -> impl Iterator<Item = (u8, Rectangle)> + '_ {
+        let whites = self.range.clone().filter(|note| !is_black(*note)).count();
+        let width = bounds.width / whites.max(1) as f32;
+
+        let white = self
+            .range
+            .clone()
+            .filter(|note| !is_black(*note))
+            .enumerate()
+            .map(move |(index, note)| {
+                let bounds = Rectangle {
+                    x: bounds.x + width * index as f32,
+                    width,
+                    ..bounds
+                };
+                (note, bounds)
+            });
+
+        let black = self
+            .range
+            .clone()
+            .filter(|note| is_black(*note))
+            .map(move |note| {
+                // A black key is centered on the boundary after the white key below it.
+                let below = self
+                    .range
+                    .clone()
+                    .take_while(|other| *other < note)
+                    .filter(|other| !is_black(*other))
+                    .count();
+                let boundary = bounds.x + width * below as f32;
+
+                let bounds = Rectangle {
+                    x: boundary - width * BLACK_WIDTH / 2.,
+                    width: width * BLACK_WIDTH,
+                    height: bounds.height * BLACK_HEIGHT,
+                    ..bounds
+                };
+                (note, bounds)
+            });
+
+        white.chain(black)
+    }
+
+    /// Returns the note under the given position.
+    fn note_at(&self, bounds: Rectangle, position: Point) -> Option<u8> {
+        let keys: Vec<_> = self.keys(bounds).collect();
+
+        keys.iter()
+            .rev()
+            .find(|(_, key)| key.contains(position))
+            .map(|(note, _)| *note)
+    }
+
+    /// Releases a note and presses another one, if they differ.
+    fn change(&self, from: Option<u8>, to: Option<u8>, shell: &mut Shell<'_, Message>) {
+        if from == to {
+            return;
+        }
+
+        if let (Some(note), Some(on_note_off)) = (from, &self.on_note_off) {
+            shell.publish(on_note_off(note));
+        }
+        if let (Some(note), Some(on_note_on)) = (to, &self.on_note_on) {
+            shell.publish(on_note_on(note));
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Piano<'_, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(note) = cursor
+                    .position_over(bounds)
+                    .and_then(|position| self.note_at(bounds, position))
+                else {
+                    return event::Status::Ignored;
+                };
+
+                self.change(state.mouse, Some(note), shell);
+                state.mouse = Some(note);
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let Some(held) = state.mouse else {
+                    return event::Status::Ignored;
+                };
+
+                // Keep the last note when the cursor leaves the keyboard.
+                let note = self.note_at(bounds, position).unwrap_or(held);
+                self.change(Some(held), Some(note), shell);
+                state.mouse = Some(note);
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let Some(held) = state.mouse.take() else {
+                    return event::Status::Ignored;
+                };
+
+                self.change(Some(held), None, shell);
+                event::Status::Captured
+            }
+            iced::Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                let Some(note) = self.note_at(bounds, position) else {
+                    return event::Status::Ignored;
+                };
+
+                self.change(None, Some(note), shell);
+                state.fingers.push((id, note));
+                event::Status::Captured
+            }
+            iced::Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                let Some((_, held)) = state.fingers.iter_mut().find(|(finger, _)| *finger == id)
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let note = self.note_at(bounds, position).unwrap_or(*held);
+                self.change(Some(*held), Some(note), shell);
+                *held = note;
+                event::Status::Captured
+            }
+            iced::Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) => {
+                let Some(index) = state.fingers.iter().position(|(finger, _)| *finger == id) else {
+                    return event::Status::Ignored;
+                };
+
+                let (_, note) = state.fingers.remove(index);
+                self.change(Some(note), None, shell);
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let bounds = layout.bounds();
+
+        for (note, key) in self.keys(bounds) {
+            let pressed = state.is_held(note) || self.pressed.contains(&note);
+            let color = match (pressed, is_black(note)) {
+                (true, _) => style.pressed,
+                (false, true) => style.black,
+                (false, false) => style.white,
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: key,
+                    border: Border {
+                        color: style.border,
+                        width: 1.,
+                        radius: iced::border::bottom(3.),
+                    },
+                    ..Default::default()
+                },
+                color,
+            );
+
+            let size = key.width * 0.45;
+            if self.labels && note % 12 == 0 && size >= 6. {
+                renderer.fill_text(
+                    Text {
+                        content: note_name(note),
+                        bounds: Size::new(key.width, key.height),
+                        size: Pixels(size.min(14.)),
+                        line_height: LineHeight::default(),
+                        font: renderer.default_font(),
+                        horizontal_alignment: alignment::Horizontal::Center,
+                        vertical_alignment: alignment::Vertical::Bottom,
+                        shaping: Shaping::Basic,
+                        wrapping: Wrapping::None,
+                    },
+                    Point::new(key.center_x(), key.y + key.height - 4.),
+                    style.label,
+                    *viewport,
+                );
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) && self.on_note_on.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Piano<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer,
+{
+    fn from(value: Piano<'a, Message, Theme>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`Piano`] with the keys of the given range of MIDI notes.
+///
+/// ```
+/// use more_iced_aw::piano::{Piano, piano};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     NoteOn(u8),
+///     NoteOff(u8),
+/// }
+///
+/// let keyboard: Piano<'_, Message> = piano(48..=72)
+///     .on_note_on(Message::NoteOn)
+///     .on_note_off(Message::NoteOff)
+///     .pressed([60, 64, 67]);
+/// ```
+pub fn piano<'a, Message, Theme>(range: RangeInclusive<u8>) -> Piano<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    Piano::new(range)
+}
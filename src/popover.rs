@@ -0,0 +1,626 @@
+//! A floating panel anchored to a trigger element.
+
+use iced::{
+    Background, Border, Color, Element, Length, Padding, Pixels, Point, Rectangle, Shadow, Size,
+    Theme, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{Operation, Tree, tree},
+    },
+    event, touch,
+};
+
+use crate::layers::{self, Dismiss};
+
+/// Where the panel of a [`Popover`] is placed relatively to its trigger.
+///
+/// The first part is the side of the trigger, and the second one the alignment along that side:
+/// `BottomStart` places the panel below the trigger, aligned on its left edge.
+/// If the panel does not fit in the window on the chosen side, it is placed on the opposite side,
+/// and it is then moved to stay inside the window.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Placement {
+    /// Above the trigger, aligned on its left edge.
+    TopStart,
+    /// Above the trigger, centered.
+    Top,
+    /// Above the trigger, aligned on its right edge.
+    TopEnd,
+    /// Below the trigger, aligned on its left edge.
+    #[default]
+    BottomStart,
+    /// Below the trigger, centered.
+    Bottom,
+    /// Below the trigger, aligned on its right edge.
+    BottomEnd,
+    /// On the left of the trigger, aligned on its top edge.
+    LeftStart,
+    /// On the left of the trigger, centered.
+    Left,
+    /// On the left of the trigger, aligned on its bottom edge.
+    LeftEnd,
+    /// On the right of the trigger, aligned on its top edge.
+    RightStart,
+    /// On the right of the trigger, centered.
+    Right,
+    /// On the right of the trigger, aligned on its bottom edge.
+    RightEnd,
+}
+
+impl Placement {
+    /// Returns the placement on the opposite side of the trigger, with the same alignment.
+    fn flip(self) -> Self {
+        use Placement::*;
+
+        match self {
+            TopStart => BottomStart,
+            Top => Bottom,
+            TopEnd => BottomEnd,
+            BottomStart => TopStart,
+            Bottom => Top,
+            BottomEnd => TopEnd,
+            LeftStart => RightStart,
+            Left => Right,
+            LeftEnd => RightEnd,
+            RightStart => LeftStart,
+            Right => Left,
+            RightEnd => LeftEnd,
+        }
+    }
+
+    /// Returns the position of a panel of the given size around the trigger.
+    fn position(self, trigger: Rectangle, size: Size, gap: f32) -> Point {
+        use Placement::*;
+
+        let x = match self {
+            TopStart | BottomStart => trigger.x,
+            Top | Bottom => trigger.center_x() - size.width / 2.,
+            TopEnd | BottomEnd => trigger.x + trigger.width - size.width,
+            LeftStart | Left | LeftEnd => trigger.x - gap - size.width,
+            RightStart | Right | RightEnd => trigger.x + trigger.width + gap,
+        };
+
+        let y = match self {
+            TopStart | Top | TopEnd => trigger.y - gap - size.height,
+            BottomStart | Bottom | BottomEnd => trigger.y + trigger.height + gap,
+            LeftStart | RightStart => trigger.y,
+            Left | Right => trigger.center_y() - size.height / 2.,
+            LeftEnd | RightEnd => trigger.y + trigger.height - size.height,
+        };
+
+        Point::new(x, y)
+    }
+}
+
+/// The appearance of a [`Popover`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the panel.
+    pub background: Background,
+    /// The color of the text of the panel.
+    pub text_color: Color,
+    /// The border of the panel.
+    pub border: Border,
+    /// The shadow of the panel.
+    pub shadow: Shadow,
+}
+
+/// The theme catalog of a [`Popover`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Popover`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Popover`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        text_color: palette.background.base.text,
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.,
+            radius: 6.into(),
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0., 2.),
+            blur_radius: 8.,
+        },
+    }
+}
+
+/// The state of a [`Popover`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    open: bool,
+}
+
+/// A trigger element that opens a floating panel when clicked, created with [`popover`].
+///
+/// The panel is closed by clicking on the trigger again, clicking outside of it, or pressing Escape.
+/// The last two follow a [`Dismiss`] policy, like the [`layers`](crate::layers).
+pub struct Popover<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    trigger: Element<'a, Message, Theme, Renderer>,
+    panel: Element<'a, Message, Theme, Renderer>,
+    placement: Placement,
+    gap: f32,
+    padding: Padding,
+    on_open: Option<Message>,
+    on_dismiss: Option<Message>,
+    dismiss: Dismiss,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Popover<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Popover`] opening `panel` when `trigger` is clicked.
+    pub fn new(
+        trigger: impl Into<Element<'a, Message, Theme, Renderer>>,
+        panel: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            trigger: trigger.into(),
+            panel: panel.into(),
+            placement: Placement::default(),
+            gap: 4.,
+            padding: Padding::new(8.),
+            on_open: None,
+            on_dismiss: None,
+            dismiss: Dismiss::default(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets where the panel is placed relatively to the trigger.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the distance between the trigger and the panel.
+    pub fn gap(mut self, gap: impl Into<Pixels>) -> Self {
+        self.gap = gap.into().0;
+        self
+    }
+
+    /// Sets the padding of the panel.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the message produced when the panel is opened.
+    pub fn on_open(mut self, on_open: Message) -> Self {
+        self.on_open = Some(on_open);
+        self
+    }
+
+    /// Sets the message produced when the panel is closed.
+    pub fn on_dismiss(mut self, on_dismiss: Message) -> Self {
+        self.on_dismiss = Some(on_dismiss);
+        self
+    }
+
+    /// Sets when the panel is closed by a click outside of it or by Escape.
+    pub fn dismiss(mut self, dismiss: Dismiss) -> Self {
+        self.dismiss = dismiss;
+        self
+    }
+
+    /// Sets the style of the [`Popover`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Popover`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Popover<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.trigger), Tree::new(&self.panel)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.trigger, &self.panel]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.trigger.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.trigger.as_widget().size_hint()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.trigger
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.trigger.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.trigger
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.trigger.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if let iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | iced::Event::Touch(touch::Event::FingerPressed { .. }) = event
+            && cursor.is_over(layout.bounds())
+        {
+            let state = tree.state.downcast_mut::<State>();
+            state.open = !state.open;
+
+            let message = if state.open {
+                self.on_open.clone()
+            } else {
+                self.on_dismiss.clone()
+            };
+            if let Some(message) = message {
+                shell.publish(message);
+            }
+
+            return event::Status::Captured;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.trigger.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (trigger_tree, panel_tree) = tree.children.split_at_mut(1);
+        let state = tree.state.downcast_mut::<State>();
+
+        let trigger = self.trigger.as_widget_mut().overlay(
+            &mut trigger_tree[0],
+            layout,
+            renderer,
+            translation,
+        );
+
+        let panel = state.open.then(|| {
+            overlay::Element::new(Box::new(Overlay {
+                panel: &mut self.panel,
+                tree: &mut panel_tree[0],
+                state,
+                trigger: layout.bounds() + translation,
+                placement: self.placement,
+                gap: self.gap,
+                padding: self.padding,
+                on_dismiss: self.on_dismiss.clone(),
+                dismiss: self.dismiss,
+                class: &self.class,
+            }))
+        });
+
+        layers::group([trigger, panel])
+    }
+}
+
+/// The overlay displaying the panel of a [`Popover`].
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    panel: &'b mut Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    state: &'b mut State,
+    trigger: Rectangle,
+    placement: Placement,
+    gap: f32,
+    padding: Padding,
+    on_dismiss: Option<Message>,
+    dismiss: Dismiss,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<Message, Theme, Renderer> Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Closes the panel and produces the dismiss message.
+    fn dismiss(&mut self, shell: &mut Shell<'_, Message>)
+    where
+        Message: Clone,
+    {
+        self.state.open = false;
+        if let Some(on_dismiss) = self.on_dismiss.clone() {
+            shell.publish(on_dismiss);
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        let limits = Limits::new(Size::ZERO, bounds).shrink(self.padding);
+        let content = self
+            .panel
+            .as_widget()
+            .layout(self.tree, renderer, &limits)
+            .move_to(Point::new(self.padding.left, self.padding.top));
+
+        let size = content.size().expand(self.padding);
+        let window = Rectangle::new(Point::ORIGIN, bounds);
+        let fits = |position: Point| {
+            window.contains(position)
+                && window.contains(position + Vector::new(size.width, size.height))
+        };
+
+        let mut position = self.placement.position(self.trigger, size, self.gap);
+        if !fits(position) {
+            let flipped = self.placement.flip().position(self.trigger, size, self.gap);
+            if fits(flipped) {
+                position = flipped;
+            }
+        }
+
+        let position = Point::new(
+            position.x.min(bounds.width - size.width).max(0.),
+            position.y.min(bounds.height - size.height).max(0.),
+        );
+
+        Node::with_children(size, vec![content]).move_to(position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.style(self.class);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: appearance.border,
+                shadow: appearance.shadow,
+            },
+            appearance.background,
+        );
+
+        self.panel.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: appearance.text_color,
+            },
+            layout.children().next().unwrap(),
+            cursor,
+            &bounds,
+        );
+    }
+
+    fn operate(&mut self, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        self.panel.as_widget().operate(
+            self.tree,
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        let status = self.panel.as_widget_mut().on_event(
+            self.tree,
+            event.clone(),
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &bounds,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        if self
+            .dismiss
+            .applies(&event, cursor, &[bounds, self.trigger])
+        {
+            self.dismiss(shell);
+
+            return Dismiss::status(&event);
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.panel.as_widget().mouse_interaction(
+            self.tree,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        self.panel.as_widget_mut().overlay(
+            self.tree,
+            layout.children().next().unwrap(),
+            renderer,
+            Vector::ZERO,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Popover<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(value: Popover<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`Popover`] opening `panel` when `trigger` is clicked.
+pub fn popover<'a, Message, Theme, Renderer>(
+    trigger: impl Into<Element<'a, Message, Theme, Renderer>>,
+    panel: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Popover<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    Popover::new(trigger, panel)
+}
@@ -0,0 +1,529 @@
+//! A slider with two handles, to select a range of values.
+//!
+//! It is styled with the same [`Catalog`] as iced's [`Slider`](iced::widget::Slider):
+//! the first background of the rail is used between the handles, and the second one outside.
+
+use std::ops::RangeInclusive;
+
+use iced::{
+    Border, Element, Length, Pixels, Point, Rectangle, Size,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{self, Limits, Node},
+        mouse, renderer,
+        widget::{Tree, tree},
+    },
+    event, keyboard, touch,
+    widget::slider::{Catalog, HandleShape, Status, Style, StyleFn},
+};
+use num_traits::FromPrimitive;
+
+use crate::i18n::{self, Direction};
+
+/// One of the handles of a [`RangeSlider`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum Handle {
+    /// The handle of the start of the range.
+    #[default]
+    Start,
+    /// The handle of the end of the range.
+    End,
+}
+
+/// The state of a [`RangeSlider`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    /// The handle being dragged, if any.
+    dragging: Option<Handle>,
+    /// The handle moved by the keyboard, which is the last one that was dragged.
+    active: Handle,
+    keyboard_modifiers: keyboard::Modifiers,
+}
+
+/// An horizontal bar with two handles, that selects a range of values.
+///
+/// The handles can be dragged with the mouse, or moved with the arrow keys when the
+/// [`RangeSlider`] is hovered (the arrow keys move the last dragged handle).
+pub struct RangeSlider<'a, T, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    bounds: RangeInclusive<T>,
+    value: RangeInclusive<T>,
+    step: T,
+    shift_step: Option<T>,
+    on_change: Box<dyn Fn(RangeInclusive<T>) -> Message + 'a>,
+    on_release: Option<Message>,
+    width: Length,
+    height: f32,
+    direction: Option<Direction>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, T, Message, Theme> RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Theme: Catalog,
+{
+    /// The default height of a [`RangeSlider`].
+    pub const DEFAULT_HEIGHT: f32 = 16.0;
+
+    /// Creates a new [`RangeSlider`].
+    ///
+    /// It expects:
+    ///   * the `bounds` of the possible values
+    ///   * the currently selected range
+    ///   * a function that will be called with the new range when a handle is moved.
+    pub fn new(
+        bounds: RangeInclusive<T>,
+        value: RangeInclusive<T>,
+        on_change: impl Fn(RangeInclusive<T>) -> Message + 'a,
+    ) -> Self {
+        let clamp = |v: T| {
+            if v < *bounds.start() {
+                *bounds.start()
+            } else if v > *bounds.end() {
+                *bounds.end()
+            } else {
+                v
+            }
+        };
+
+        let (start, end) = (clamp(*value.start()), clamp(*value.end()));
+        let value = if start <= end { start..=end } else { end..=start };
+
+        Self {
+            bounds,
+            value,
+            step: T::from(1),
+            shift_step: None,
+            on_change: Box::new(on_change),
+            on_release: None,
+            width: Length::Fill,
+            height: Self::DEFAULT_HEIGHT,
+            direction: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the release message of the [`RangeSlider`].
+    ///
+    /// It is produced when a handle is released after being dragged.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the step of the [`RangeSlider`].
+    pub fn step(mut self, step: impl Into<T>) -> Self {
+        self.step = step.into();
+        self
+    }
+
+    /// Sets the step of the [`RangeSlider`] when Shift is held.
+    pub fn shift_step(mut self, shift_step: impl Into<T>) -> Self {
+        self.shift_step = Some(shift_step.into());
+        self
+    }
+
+    /// Sets the width of the [`RangeSlider`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`RangeSlider`].
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = height.into().0;
+        self
+    }
+
+    /// Sets the direction of the [`RangeSlider`].
+    ///
+    /// From right to left, the start of the bounds is on the right, and the left and right arrow
+    /// keys are swapped.
+    ///
+    /// If this method is not called, the [`i18n::direction`] of the current thread is read
+    /// each time the slider is drawn or handles an event.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the style of the [`RangeSlider`].
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`RangeSlider`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, T, Message, Theme> RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + Into<f64> + FromPrimitive + PartialOrd,
+    Theme: Catalog,
+{
+    /// Returns the direction set with [`direction`](Self::direction), or the one of the
+    /// [`i18n`] format of the current thread.
+    fn resolved_direction(&self) -> Direction {
+        self.direction.unwrap_or_else(i18n::direction)
+    }
+
+    /// Returns the position of a value between the bounds, from 0 to 1.
+    fn fraction(&self, value: T) -> f32 {
+        let (start, end) = ((*self.bounds.start()).into(), (*self.bounds.end()).into());
+
+        if start >= end {
+            0.
+        } else {
+            ((value.into() - start) / (end - start)) as f32
+        }
+    }
+
+    /// Returns the current step, depending on the modifiers.
+    fn current_step(&self, state: &State) -> f64 {
+        if state.keyboard_modifiers.shift() {
+            self.shift_step.unwrap_or(self.step)
+        } else {
+            self.step
+        }
+        .into()
+    }
+
+    /// Returns the value under the cursor, rounded to the step.
+    fn locate(&self, state: &State, bounds: Rectangle, cursor: Point) -> Option<T> {
+        let (start, end) = ((*self.bounds.start()).into(), (*self.bounds.end()).into());
+        let x = self.resolved_direction().mirror(cursor.x - bounds.x, 0., bounds.width);
+
+        if x <= 0. {
+            return Some(*self.bounds.start());
+        }
+        if x >= bounds.width {
+            return Some(*self.bounds.end());
+        }
+
+        let step = self.current_step(state);
+        let percent = f64::from(x) / f64::from(bounds.width);
+        let steps = (percent * (end - start) / step).round();
+
+        T::from_f64((steps * step + start).min(end))
+    }
+
+    /// Moves a value by a number of steps, without leaving the bounds.
+    fn offset(&self, state: &State, value: T, steps: f64) -> Option<T> {
+        let step = self.current_step(state);
+        let new_value = step * ((value.into() / step).round() + steps);
+
+        if new_value <= (*self.bounds.start()).into() {
+            Some(*self.bounds.start())
+        } else if new_value >= (*self.bounds.end()).into() {
+            Some(*self.bounds.end())
+        } else {
+            T::from_f64(new_value)
+        }
+    }
+
+    /// Moves a handle to a value, without crossing the other handle, and publishes the new range.
+    fn change(&mut self, handle: Handle, value: T, shell: &mut Shell<'_, Message>) {
+        let (start, end) = (*self.value.start(), *self.value.end());
+
+        let new = match handle {
+            Handle::Start if value > end => end..=end,
+            Handle::Start => value..=end,
+            Handle::End if value < start => start..=start,
+            Handle::End => start..=value,
+        };
+
+        if new != self.value {
+            shell.publish((self.on_change)(new.clone()));
+            self.value = new;
+        }
+    }
+
+    /// Returns the handle closest to a value.
+    fn closest(&self, value: T) -> Handle {
+        let value = value.into();
+        let (start, end) = ((*self.value.start()).into(), (*self.value.end()).into());
+
+        if (value - start).abs() < (value - end).abs() || (value < start) {
+            Handle::Start
+        } else {
+            Handle::End
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + Into<f64> + FromPrimitive + PartialOrd,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = *tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let new_state = match event {
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+                let Some(value) = self.locate(&state, bounds, position) else {
+                    return event::Status::Ignored;
+                };
+
+                let handle = self.closest(value);
+                self.change(handle, value, shell);
+
+                State {
+                    dragging: Some(handle),
+                    active: handle,
+                    ..state
+                }
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerLifted { .. })
+            | iced::Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.dragging.is_none() {
+                    return event::Status::Ignored;
+                }
+
+                if let Some(on_release) = self.on_release.clone() {
+                    shell.publish(on_release);
+                }
+
+                State {
+                    dragging: None,
+                    ..state
+                }
+            }
+            iced::Event::Mouse(mouse::Event::CursorMoved { .. })
+            | iced::Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let Some(handle) = state.dragging else {
+                    return event::Status::Ignored;
+                };
+
+                if let Some(value) = cursor
+                    .position()
+                    .and_then(|position| self.locate(&state, bounds, position))
+                {
+                    self.change(handle, value, shell);
+                }
+
+                state
+            }
+            iced::Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) => {
+                if !cursor.is_over(bounds) {
+                    return event::Status::Ignored;
+                }
+
+                let forward = if self.resolved_direction().is_rtl() { -1. } else { 1. };
+                let steps = match key {
+                    keyboard::key::Named::ArrowUp => 1.,
+                    keyboard::key::Named::ArrowDown => -1.,
+                    keyboard::key::Named::ArrowRight => forward,
+                    keyboard::key::Named::ArrowLeft => -forward,
+                    _ => return event::Status::Ignored,
+                };
+
+                let current = match state.active {
+                    Handle::Start => *self.value.start(),
+                    Handle::End => *self.value.end(),
+                };
+
+                if let Some(value) = self.offset(&state, current, steps) {
+                    self.change(state.active, value, shell);
+                }
+
+                state
+            }
+            iced::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                tree.state.downcast_mut::<State>().keyboard_modifiers = modifiers;
+                return event::Status::Ignored;
+            }
+            _ => return event::Status::Ignored,
+        };
+
+        *tree.state.downcast_mut::<State>() = new_state;
+        event::Status::Captured
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let style = theme.style(
+            &self.class,
+            if state.dragging.is_some() {
+                Status::Dragged
+            } else if cursor.is_over(bounds) {
+                Status::Hovered
+            } else {
+                Status::Active
+            },
+        );
+
+        let (handle_width, handle_height, handle_border_radius) = match style.handle.shape {
+            HandleShape::Circle { radius } => (radius * 2.0, radius * 2.0, radius.into()),
+            HandleShape::Rectangle {
+                width,
+                border_radius,
+            } => (f32::from(width), bounds.height, border_radius),
+        };
+
+        let offset = |value: T| {
+            let offset = (bounds.width - handle_width) * self.fraction(value);
+            self.resolved_direction().mirror(offset, handle_width, bounds.width)
+        };
+        let (start, end) = (offset(*self.value.start()), offset(*self.value.end()));
+
+        let rail_y = bounds.y + bounds.height / 2.0;
+
+        let rail = |x: f32, width: f32| Rectangle {
+            x: bounds.x + x,
+            y: rail_y - style.rail.width / 2.0,
+            width,
+            height: style.rail.width,
+        };
+
+        let middle = (
+            start.min(end) + handle_width / 2.0,
+            start.max(end) + handle_width / 2.0,
+        );
+
+        for (rail_bounds, background) in [
+            (rail(0., middle.0), style.rail.backgrounds.1),
+            (rail(middle.0, middle.1 - middle.0), style.rail.backgrounds.0),
+            (
+                rail(middle.1, bounds.width - middle.1),
+                style.rail.backgrounds.1,
+            ),
+        ] {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: rail_bounds,
+                    border: style.rail.border,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        for offset in [start, end] {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + offset,
+                        y: rail_y - handle_height / 2.0,
+                        width: handle_width,
+                        height: handle_height,
+                    },
+                    border: Border {
+                        radius: handle_border_radius,
+                        width: style.handle.border_width,
+                        color: style.handle.border_color,
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.handle.background,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<RangeSlider<'a, T, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Copy + Into<f64> + FromPrimitive + PartialOrd + 'a,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(value: RangeSlider<'a, T, Message, Theme>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`RangeSlider`].
+pub fn range_slider<'a, T, Message, Theme>(
+    bounds: RangeInclusive<T>,
+    value: RangeInclusive<T>,
+    on_change: impl Fn(RangeInclusive<T>) -> Message + 'a,
+) -> RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Theme: Catalog,
+{
+    RangeSlider::new(bounds, value, on_change)
+}
@@ -0,0 +1,386 @@
+//! A row of stars (or other icons) to display and pick a rating, drawn on a [`Canvas`].
+//!
+//! Hovering the icons previews the rating under the cursor, and clicking picks it.
+//! The rating can also be changed with the arrow keys when the widget is hovered.
+
+use iced::{
+    Color, Element, Length, Pixels, Point, Rectangle, Renderer, Size, Theme, Vector, keyboard,
+    mouse::{self, Cursor},
+    widget::canvas::{self, Canvas, Frame, Geometry, Path, Program, event},
+};
+
+/// The icon of a [`Rating`].
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Icon {
+    /// A five-pointed star.
+    #[default]
+    Star,
+    /// A heart.
+    Heart,
+    /// A circle.
+    Circle,
+    /// A custom icon, built in a square of the given size with its top left corner at the origin.
+    Custom(fn(f32) -> Path),
+}
+
+impl Icon {
+    /// Returns the path of the icon in a square of the given size at the origin.
+    fn path(self, size: f32) -> Path {
+        let center = Point::new(size / 2., size / 2.);
+
+        match self {
+            Icon::Star => Path::new(|builder| {
+                let outer = size / 2.;
+                let inner = outer * 0.4;
+
+                for i in 0..10 {
+                    let radius = if i % 2 == 0 { outer } else { inner };
+                    let angle = -std::f32::consts::FRAC_PI_2 + i as f32 * std::f32::consts::PI / 5.;
+                    let point = center + Vector::new(angle.cos() * radius, angle.sin() * radius);
+
+                    if i == 0 {
+                        builder.move_to(point);
+                    } else {
+                        builder.line_to(point);
+                    }
+                }
+                builder.close();
+            }),
+            Icon::Heart => Path::new(|builder| {
+                let at = |x: f32, y: f32| Point::new(x * size, y * size);
+
+                builder.move_to(at(0.5, 0.95));
+                builder.bezier_curve_to(at(0.1, 0.65), at(-0.05, 0.35), at(0.15, 0.15));
+                builder.bezier_curve_to(at(0.3, 0.), at(0.5, 0.1), at(0.5, 0.28));
+                builder.bezier_curve_to(at(0.5, 0.1), at(0.7, 0.), at(0.85, 0.15));
+                builder.bezier_curve_to(at(1.05, 0.35), at(0.9, 0.65), at(0.5, 0.95));
+                builder.close();
+            }),
+            Icon::Circle => Path::circle(center, size / 2.),
+            Icon::Custom(path) => path(size),
+        }
+    }
+}
+
+/// The possible status of a [`Rating`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Rating`] can be interacted with.
+    Active,
+    /// The [`Rating`] is being hovered.
+    Hovered,
+    /// The [`Rating`] is read-only.
+    ReadOnly,
+}
+
+/// The appearance of a [`Rating`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color of the filled part of the icons.
+    pub filled: Color,
+    /// The color of the empty part of the icons.
+    pub empty: Color,
+    /// The color of the icons filled by the hovered rating.
+    pub preview: Color,
+}
+
+/// The theme catalog of a [`Rating`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Rating`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Rating`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    // The extended palette has no warning color, stars are gold in every theme.
+    let gold = Color::from_rgb8(0xF5, 0xB3, 0x01);
+
+    let filled = match status {
+        Status::Active | Status::Hovered => gold,
+        Status::ReadOnly => gold.scale_alpha(0.8),
+    };
+
+    Style {
+        filled,
+        empty: palette.background.strong.color,
+        preview: gold.scale_alpha(0.5),
+    }
+}
+
+/// A row of icons displaying a rating, created with [`rating`].
+///
+/// The rating goes from 0 to the number of icons. Without [`Rating::on_rate`], the widget is
+/// read-only, and can display any fractional rating (like an average of 3.7).
+pub struct Rating<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    value: f32,
+    on_rate: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    count: u8,
+    half_steps: bool,
+    icon: Icon,
+    size: f32,
+    spacing: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> Rating<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new read-only [`Rating`] with the given value.
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            on_rate: None,
+            count: 5,
+            half_steps: false,
+            icon: Icon::default(),
+            size: 20.,
+            spacing: 4.,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced with the new rating when the user rates.
+    ///
+    /// Without it, the [`Rating`] is read-only.
+    pub fn on_rate(mut self, on_rate: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_rate = Some(Box::new(on_rate));
+        self
+    }
+
+    /// Sets the number of icons, which is also the maximum rating.
+    pub fn count(mut self, count: u8) -> Self {
+        self.count = count;
+        self
+    }
+
+    /// Sets whether the user can pick half ratings.
+    pub fn half_steps(mut self, half_steps: bool) -> Self {
+        self.half_steps = half_steps;
+        self
+    }
+
+    /// Sets the [`Icon`] of the [`Rating`].
+    pub fn icon(mut self, icon: Icon) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    /// Sets the size of the icons.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the spacing between the icons.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the style of the [`Rating`].
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Rating`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the step between two ratings.
+    fn step(&self) -> f32 {
+        if self.half_steps { 0.5 } else { 1. }
+    }
+
+    /// Returns the rating under the cursor, if any.
+    fn hovered(&self, bounds: Rectangle, cursor: Cursor) -> Option<f32> {
+        self.on_rate.as_ref()?;
+        let position = cursor.position_over(bounds)?;
+
+        let x = position.x - bounds.x;
+        let pitch = self.size + self.spacing;
+        let index = (x / pitch).floor();
+        let within = ((x - index * pitch) / self.size).min(1.);
+
+        let rating = index + (within / self.step()).ceil() * self.step();
+        Some(rating.clamp(self.step(), f32::from(self.count)))
+    }
+
+    /// Produces the rate message if `rating` changes the value.
+    fn rate(&self, rating: f32) -> Option<Message> {
+        let rating = rating.clamp(0., f32::from(self.count));
+
+        (rating != self.value)
+            .then_some(self.on_rate.as_ref())
+            .flatten()
+            .map(|on_rate| on_rate(rating))
+    }
+}
+
+impl<Message, Theme> Program<Message, Theme> for Rating<'_, Message, Theme>
+where
+    Theme: Catalog,
+{
+    type State = ();
+
+    fn update(
+        &self,
+        _state: &mut (),
+        event: canvas::Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> (event::Status, Option<Message>) {
+        match event {
+            canvas::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(rating) = self.hovered(bounds, cursor) else {
+                    return (event::Status::Ignored, None);
+                };
+
+                (event::Status::Captured, self.rate(rating))
+            }
+            canvas::Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) => {
+                if self.on_rate.is_none() || !cursor.is_over(bounds) {
+                    return (event::Status::Ignored, None);
+                }
+
+                let rating = match key {
+                    keyboard::key::Named::ArrowUp | keyboard::key::Named::ArrowRight => {
+                        ((self.value / self.step()).floor() + 1.) * self.step()
+                    }
+                    keyboard::key::Named::ArrowDown | keyboard::key::Named::ArrowLeft => {
+                        ((self.value / self.step()).ceil() - 1.) * self.step()
+                    }
+                    keyboard::key::Named::Home => 0.,
+                    keyboard::key::Named::End => f32::from(self.count),
+                    _ => return (event::Status::Ignored, None),
+                };
+
+                (event::Status::Captured, self.rate(rating))
+            }
+            _ => (event::Status::Ignored, None),
+        }
+    }
+
+    fn draw(
+        &self,
+        _state: &(),
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let hovered = self.hovered(bounds, cursor);
+        let style = theme.style(
+            &self.class,
+            if self.on_rate.is_none() {
+                Status::ReadOnly
+            } else if hovered.is_some() {
+                Status::Hovered
+            } else {
+                Status::Active
+            },
+        );
+
+        let (shown, color) = match hovered {
+            Some(rating) => (rating, style.preview),
+            None => (self.value, style.filled),
+        };
+
+        let mut frame = Frame::new(renderer, bounds.size());
+        let path = self.icon.path(self.size);
+
+        for i in 0..self.count {
+            let x = f32::from(i) * (self.size + self.spacing);
+            let fill = (shown - f32::from(i)).clamp(0., 1.);
+
+            if fill < 1. {
+                frame.with_save(|frame| {
+                    frame.translate(Vector::new(x, 0.));
+                    frame.fill(&path, style.empty);
+                });
+            }
+
+            if fill > 0. {
+                let clip =
+                    Rectangle::new(Point::new(x, 0.), Size::new(self.size * fill, self.size));
+                frame.with_clip(clip, |frame| frame.fill(&path, color));
+            }
+        }
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &(),
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        if self.hovered(bounds, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme> From<Rating<'a, Message, Theme>> for Element<'a, Message, Theme>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+{
+    fn from(value: Rating<'a, Message, Theme>) -> Self {
+        let count = f32::from(value.count);
+        let width = (count * value.size + (count - 1.).max(0.) * value.spacing).max(0.);
+        let height = value.size;
+
+        Element::new(
+            Canvas::new(value)
+                .width(Length::Fixed(width))
+                .height(Length::Fixed(height)),
+        )
+    }
+}
+
+/// Creates a new read-only [`Rating`] with the given value.
+pub fn rating<'a, Message, Theme>(value: f32) -> Rating<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    Rating::new(value)
+}
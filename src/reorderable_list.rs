@@ -0,0 +1,718 @@
+//! A vertical list whose items can be dragged by a handle to reorder them.
+//!
+//! With [`ReorderableList::payload`], the items can also be dragged out of the list, onto any
+//! target of the [`dnd`](crate::dnd) module.
+
+use iced::{
+    Background, Border, Color, Element, Length, Pixels, Point, Rectangle, Shadow, Size, Theme,
+    Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{Operation, Tree, tree},
+    },
+    event, touch, window,
+};
+
+use crate::{
+    animation::{Clock, approach},
+    dnd::{self, Payload, Preview},
+    helpers::{Keyed, diff_keyed_children},
+};
+
+/// The appearance of a [`ReorderableList`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color of the grip of the handles.
+    pub handle: Color,
+    /// The color of the grip of the hovered handle.
+    pub handle_hovered: Color,
+    /// The background of the dragged item.
+    pub dragged_background: Background,
+    /// The shadow of the dragged item.
+    pub dragged_shadow: Shadow,
+    /// The background of the gap where the dragged item will be dropped.
+    pub gap: Background,
+    /// The border of the gap where the dragged item will be dropped.
+    pub gap_border: Border,
+}
+
+/// The theme catalog of a [`ReorderableList`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`ReorderableList`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`ReorderableList`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        handle: palette.background.strong.color,
+        handle_hovered: palette.background.base.text,
+        dragged_background: palette.background.weak.color.into(),
+        dragged_shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.3),
+            offset: Vector::new(0., 4.),
+            blur_radius: 12.,
+        },
+        gap: palette.primary.weak.color.scale_alpha(0.2).into(),
+        gap_border: Border {
+            color: palette.primary.base.color,
+            width: 1.,
+            radius: 4.into(),
+        },
+    }
+}
+
+/// How fast the items move to their new position, in 1/s.
+const SPEED: f32 = 20.;
+
+/// An item being dragged.
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    /// The index of the dragged item.
+    from: usize,
+    /// The distance between the cursor and the top left corner of the item.
+    grab: Vector,
+    /// The vertical position of the cursor, relatively to the top of the list.
+    cursor: f32,
+    /// The position of the cursor in the window, while the item is dragged out of the list.
+    outside: Option<Point>,
+}
+
+/// The state of a [`ReorderableList`].
+#[derive(Debug, Clone)]
+struct State {
+    /// The keys of the items during the last diff.
+    keys: Vec<Option<u64>>,
+    /// The item being dragged, if any.
+    drag: Option<Drag>,
+    /// The current vertical offset of each item, animated towards its target.
+    offsets: Vec<f32>,
+    /// The current position of the gap, animated towards its target.
+    gap: Option<f32>,
+    /// The clock of the animation, running while the items are moving.
+    clock: Clock,
+    /// Whether the payload of an item was released and the drag should be finished.
+    released: bool,
+}
+
+/// A vertical list of keyed items that can be reordered by dragging their handle,
+/// created with [`reorderable_list`].
+///
+/// Each item gets a handle on its left. While an item is dragged, the other items move aside
+/// to show the gap where it will be dropped. When it is dropped at a new position, the
+/// [`ReorderableList`] produces the message given by `on_reorder(from, to)`, where `to` is the
+/// index of the item once moved: the application should `remove(from)` then `insert(to, ..)`.
+///
+/// The items are [`Keyed`], so their state follows them when they are reordered.
+///
+/// An item dragged out of the list follows the cursor in a [`Preview`], and is not reordered when
+/// it is dropped.
+pub struct ReorderableList<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    items: Vec<Element<'a, Message, Theme, Renderer>>,
+    keys: Vec<Option<u64>>,
+    on_reorder: Box<dyn Fn(usize, usize) -> Message + 'a>,
+    payload: Option<Box<dyn Fn(usize) -> Payload + 'a>>,
+    width: Length,
+    spacing: f32,
+    handle_width: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> ReorderableList<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`ReorderableList`] with the given items.
+    ///
+    /// `on_reorder` produces the message emitted when an item is moved from an index to another.
+    pub fn new(
+        items: impl IntoIterator<Item = Keyed<'a, Message, Theme, Renderer>>,
+        on_reorder: impl Fn(usize, usize) -> Message + 'a,
+    ) -> Self {
+        let (keys, items) = items
+            .into_iter()
+            .map(|keyed| (Some(keyed.key), keyed.element))
+            .unzip();
+
+        Self {
+            items,
+            keys,
+            on_reorder: Box::new(on_reorder),
+            payload: None,
+            width: Length::Fill,
+            spacing: 4.,
+            handle_width: 20.,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the payload dragged with the item at an index, so that the items can be dropped on any
+    /// target accepting payloads of type `T`, like a [`DropTarget`](crate::drag_drop::DropTarget).
+    pub fn payload<T: 'static>(mut self, payload: impl Fn(usize) -> T + 'a) -> Self {
+        self.payload = Some(Box::new(move |index| Payload::new(payload(index))));
+        self
+    }
+
+    /// Sets the width of the [`ReorderableList`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the spacing between the items.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the width of the handles.
+    pub fn handle_width(mut self, handle_width: impl Into<Pixels>) -> Self {
+        self.handle_width = handle_width.into().0;
+        self
+    }
+
+    /// Sets the style of the [`ReorderableList`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`ReorderableList`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the bounds of each item, including its handle.
+    fn rows(&self, layout: Layout<'_>) -> Vec<Rectangle> {
+        let x = layout.bounds().x;
+        let width = layout.bounds().width;
+
+        layout
+            .children()
+            .map(|child| Rectangle {
+                x,
+                width,
+                ..child.bounds()
+            })
+            .collect()
+    }
+
+    /// Returns the index of the item whose handle is under the cursor, if any.
+    fn hovered_handle(&self, rows: &[Rectangle], cursor: mouse::Cursor) -> Option<usize> {
+        let position = cursor.position()?;
+
+        rows.iter().position(|row| {
+            Rectangle {
+                width: self.handle_width,
+                ..*row
+            }
+            .contains(position)
+        })
+    }
+
+    /// Returns the index where the dragged item would be dropped.
+    fn target(drag: &Drag, rows: &[Rectangle], top: f32) -> usize {
+        let dragged = rows[drag.from];
+        let center = top + drag.cursor - drag.grab.y + dragged.height / 2.;
+
+        rows.iter()
+            .enumerate()
+            .filter(|&(i, row)| i != drag.from && row.center_y() < center)
+            .count()
+    }
+
+    /// Returns the target offset of each item and the target position of the gap.
+    fn targets(
+        &self,
+        drag: Option<&Drag>,
+        rows: &[Rectangle],
+        top: f32,
+    ) -> (Vec<f32>, Option<f32>) {
+        let Some(drag) = drag.filter(|drag| drag.outside.is_none()) else {
+            return (vec![0.; rows.len()], None);
+        };
+
+        let to = Self::target(drag, rows, top);
+        let from = drag.from;
+        let shift = rows[from].height + self.spacing;
+
+        let offsets = (0..rows.len())
+            .map(|i| {
+                if from < to && i > from && i <= to {
+                    -shift
+                } else if to < from && i >= to && i < from {
+                    shift
+                } else {
+                    0.
+                }
+            })
+            .collect();
+
+        let gap = if to < from {
+            rows[to].y
+        } else if to > from {
+            rows[to].y + rows[to].height - rows[from].height
+        } else {
+            rows[from].y
+        };
+
+        (offsets, Some(gap - top))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ReorderableList<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            keys: self.keys.clone(),
+            drag: None,
+            offsets: vec![0.; self.items.len()],
+            gap: None,
+            clock: Clock::new(),
+            released: false,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.items.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        let children: Vec<_> = self.items.iter().collect();
+        diff_keyed_children(&mut tree.children, &mut state.keys, &children, &self.keys);
+
+        state.offsets.resize(self.items.len(), 0.);
+        if state.drag.is_some_and(|drag| drag.from >= self.items.len()) {
+            state.drag = None;
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width);
+        let max_width = limits.max().width;
+        let item_limits = Limits::new(
+            Size::ZERO,
+            Size::new((max_width - self.handle_width).max(0.), f32::INFINITY),
+        );
+
+        let mut y = 0.;
+        let mut width: f32 = 0.;
+
+        let children: Vec<_> = self
+            .items
+            .iter()
+            .zip(&mut tree.children)
+            .map(|(item, tree)| {
+                let node = item
+                    .as_widget()
+                    .layout(tree, renderer, &item_limits)
+                    .move_to(Point::new(self.handle_width, y));
+
+                width = width.max(node.size().width + self.handle_width);
+                y += node.size().height + self.spacing;
+                node
+            })
+            .collect();
+
+        let height = (y - self.spacing).max(0.);
+        let size = limits.resolve(self.width, Length::Shrink, Size::new(width, height));
+
+        Node::with_children(size, children)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.style(&self.class);
+        let rows = self.rows(layout);
+        let hovered = self.hovered_handle(&rows, cursor);
+
+        let draw_grip = |renderer: &mut Renderer, row: Rectangle, color: Color| {
+            let x = row.x + (self.handle_width - 10.) / 2.;
+            for line in 0..3 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x,
+                            y: row.center_y() - 5. + line as f32 * 4.,
+                            width: 10.,
+                            height: 2.,
+                        },
+                        border: Border::default().rounded(1.),
+                        ..Default::default()
+                    },
+                    color,
+                );
+            }
+        };
+
+        if let (Some(drag), Some(gap)) = (state.drag, state.gap) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + gap,
+                        height: rows[drag.from].height,
+                        ..bounds
+                    },
+                    border: appearance.gap_border,
+                    ..Default::default()
+                },
+                appearance.gap,
+            );
+        }
+
+        let item_cursor = if state.drag.is_some() {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        for (i, ((item, tree), layout)) in self
+            .items
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            if state.drag.is_some_and(|drag| drag.from == i) {
+                continue;
+            }
+
+            let offset = Vector::new(0., state.offsets.get(i).copied().unwrap_or(0.));
+
+            renderer.with_translation(offset, |renderer| {
+                let color = if hovered == Some(i) && state.drag.is_none() {
+                    appearance.handle_hovered
+                } else {
+                    appearance.handle
+                };
+                draw_grip(renderer, rows[i], color);
+
+                item.as_widget()
+                    .draw(tree, renderer, theme, style, layout, item_cursor, viewport);
+            });
+        }
+
+        if let Some(drag) = state.drag.filter(|drag| drag.outside.is_none()) {
+            let row = rows[drag.from];
+            let offset = Vector::new(0., bounds.y + drag.cursor - drag.grab.y - row.y);
+
+            renderer.with_layer(*viewport, |renderer| {
+                renderer.with_translation(offset, |renderer| {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: row,
+                            border: Border::default().rounded(4.),
+                            shadow: appearance.dragged_shadow,
+                        },
+                        appearance.dragged_background,
+                    );
+                    draw_grip(renderer, row, appearance.handle_hovered);
+
+                    if let Some(layout) = layout.children().nth(drag.from) {
+                        self.items[drag.from].as_widget().draw(
+                            &tree.children[drag.from],
+                            renderer,
+                            theme,
+                            style,
+                            layout,
+                            mouse::Cursor::Unavailable,
+                            viewport,
+                        );
+                    }
+                });
+            });
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.items
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((item, tree), layout)| {
+                    item.as_widget().operate(tree, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let rows = self.rows(layout);
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.released {
+            state.released = false;
+            let _ = dnd::finish();
+        }
+
+        match event {
+            iced::Event::Window(window::Event::RedrawRequested(now)) => {
+                let (targets, gap) = self.targets(state.drag.as_ref(), &rows, bounds.y);
+                let elapsed = state.clock.tick(now);
+                let mut moving = false;
+
+                state.offsets.resize(rows.len(), 0.);
+                for (offset, target) in state.offsets.iter_mut().zip(targets) {
+                    if (target - *offset).abs() > 0.5 {
+                        *offset = approach(*offset, target, SPEED, elapsed);
+                        moving = true;
+                    } else {
+                        *offset = target;
+                    }
+                }
+
+                state.gap = match (state.gap, gap) {
+                    (Some(current), Some(target)) if (target - current).abs() > 0.5 => {
+                        moving = true;
+                        Some(approach(current, target, SPEED, elapsed))
+                    }
+                    (_, gap) => gap,
+                };
+
+                if moving {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                } else {
+                    state.clock.stop();
+                }
+            }
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. })
+                if state.drag.is_none() =>
+            {
+                if let Some(from) = self.hovered_handle(&rows, cursor)
+                    && let Some(position) = cursor.position()
+                {
+                    state.drag = Some(Drag {
+                        from,
+                        grab: position - rows[from].position(),
+                        cursor: position.y - bounds.y,
+                        outside: None,
+                    });
+                    if let Some(payload) = &self.payload {
+                        dnd::start_payload(payload(from));
+                    }
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                    return event::Status::Captured;
+                }
+            }
+            iced::Event::Mouse(mouse::Event::CursorMoved { position })
+            | iced::Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(drag) = &mut state.drag {
+                    drag.cursor = position.y - bounds.y;
+
+                    if self.payload.is_some() {
+                        drag.outside = (!bounds.contains(position)).then_some(position);
+                        shell.invalidate_layout();
+                    }
+
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                    return event::Status::Captured;
+                }
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerLifted { .. })
+            | iced::Event::Touch(touch::Event::FingerLost { .. }) => {
+                if let Some(drag) = state.drag.take() {
+                    let to = Self::target(&drag, &rows, bounds.y);
+                    if to != drag.from && drag.outside.is_none() {
+                        shell.publish((self.on_reorder)(drag.from, to));
+                    }
+
+                    if self.payload.is_some() {
+                        dnd::release();
+                        state.released = true;
+                    }
+
+                    state.offsets = vec![0.; rows.len()];
+                    state.gap = None;
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        if state.drag.is_some() {
+            return event::Status::Ignored;
+        }
+
+        self.items
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((item, tree), layout)| {
+                item.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if self.hovered_handle(&self.rows(layout), cursor).is_some() {
+            return mouse::Interaction::Grab;
+        }
+
+        self.items
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((item, tree), layout)| {
+                item.as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        _translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let drag = tree.state.downcast_ref::<State>().drag?;
+        let position = drag.outside?;
+        let size = layout.children().nth(drag.from)?.bounds().size();
+        let class = &self.class;
+
+        Some(
+            Preview::new(
+                self.items.get(drag.from)?,
+                tree.children.get_mut(drag.from)?,
+                position - drag.grab + Vector::new(self.handle_width, 0.),
+                size,
+            )
+            .style(move |theme: &Theme| {
+                let appearance = theme.style(class);
+
+                dnd::Style {
+                    background: Some(appearance.dragged_background),
+                    shadow: appearance.dragged_shadow,
+                    ..dnd::Style::default()
+                }
+            })
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ReorderableList<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(value: ReorderableList<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`ReorderableList`] with the given items.
+///
+/// `on_reorder` produces the message emitted when an item is moved from an index to another.
+pub fn reorderable_list<'a, Message, Theme, Renderer>(
+    items: impl IntoIterator<Item = Keyed<'a, Message, Theme, Renderer>>,
+    on_reorder: impl Fn(usize, usize) -> Message + 'a,
+) -> ReorderableList<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    ReorderableList::new(items, on_reorder)
+}
@@ -0,0 +1,372 @@
+//! Widgets reacting to the width allotted to them, rather than to the size of the window.
+//!
+//! [`Breakpoints`] split widths into [`SizeClass`]es, shared by the widgets so that they change
+//! their layout at the same widths:
+//! - an [`Adaptive`] shows one of several alternatives depending on the class of its width.
+//! - a [`TabRail`](crate::tab_rail::TabRail) collapses to its icons below a class, with
+//!   [`collapse_below`](crate::tab_rail::TabRail::collapse_below).
+//!
+//! The [`Grid`](crate::grid::Grid) also reflows its cells in as many columns as fit in its width
+//! with [`auto_fit`](crate::grid::Grid::auto_fit).
+//!
+//! ```
+//! use more_iced_aw::responsive::{Breakpoints, SizeClass};
+//!
+//! let breakpoints = Breakpoints::DEFAULT;
+//!
+//! assert_eq!(breakpoints.class(400.), SizeClass::Compact);
+//! assert_eq!(breakpoints.class(700.), SizeClass::Medium);
+//! assert_eq!(breakpoints.class(1200.), SizeClass::Expanded);
+//! ```
+
+use iced::{
+    Element, Length, Rectangle, Size, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{Operation, Tree, tree},
+    },
+    event,
+};
+
+/// A range of widths, from the narrowest to the widest.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SizeClass {
+    /// Narrower than [`Breakpoints::medium`], like a phone.
+    #[default]
+    Compact,
+    /// From [`Breakpoints::medium`] to [`Breakpoints::expanded`], like a tablet or a side panel.
+    Medium,
+    /// From [`Breakpoints::expanded`], like a desktop window.
+    Expanded,
+}
+
+/// The widths at which a [`SizeClass`] starts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Breakpoints {
+    /// The width from which the class is [`SizeClass::Medium`].
+    pub medium: f32,
+    /// The width from which the class is [`SizeClass::Expanded`].
+    pub expanded: f32,
+}
+
+impl Breakpoints {
+    /// The breakpoints used by default: medium from 600 and expanded from 840.
+    pub const DEFAULT: Self = Self {
+        medium: 600.,
+        expanded: 840.,
+    };
+
+    /// Creates new [`Breakpoints`].
+    pub fn new(medium: f32, expanded: f32) -> Self {
+        Self { medium, expanded }
+    }
+
+    /// Returns the [`SizeClass`] of a width.
+    pub fn class(&self, width: f32) -> SizeClass {
+        if width >= self.expanded {
+            SizeClass::Expanded
+        } else if width >= self.medium {
+            SizeClass::Medium
+        } else {
+            SizeClass::Compact
+        }
+    }
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The state of an [`Adaptive`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    /// The index of the alternative chosen by the last layout.
+    selected: usize,
+}
+
+/// A widget showing one of several alternatives, depending on the [`SizeClass`] of the width
+/// allotted to it, created with [`adaptive`].
+///
+/// The alternative of the widest class not wider than the available width is shown, and the
+/// compact one is shown when no other fits. Each alternative keeps its own state while hidden.
+///
+/// ```no_run
+/// use iced::{Element, widget::{column, row, text}};
+/// use more_iced_aw::responsive::adaptive;
+///
+/// fn view<'a, Message: 'a>() -> Element<'a, Message> {
+///     adaptive(column![text("Menu"), text("Content")])
+///         .expanded(row![text("Menu"), text("Content")])
+///         .into()
+/// }
+/// ```
+pub struct Adaptive<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// The alternatives, sorted by class and starting with the compact one.
+    alternatives: Vec<(SizeClass, Element<'a, Message, Theme, Renderer>)>,
+    breakpoints: Breakpoints,
+    width: Length,
+    height: Length,
+}
+
+impl<'a, Message, Theme, Renderer> Adaptive<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Adaptive`] with the alternative shown in compact widths.
+    pub fn new(compact: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            alternatives: vec![(SizeClass::Compact, compact.into())],
+            breakpoints: Breakpoints::DEFAULT,
+            width: Length::Fill,
+            height: Length::Shrink,
+        }
+    }
+
+    /// Sets the alternative shown from the given [`SizeClass`], replacing the previous one if any.
+    pub fn at(
+        mut self,
+        class: SizeClass,
+        alternative: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let alternative = alternative.into();
+
+        match self
+            .alternatives
+            .binary_search_by_key(&class, |(class, _)| *class)
+        {
+            Ok(index) => self.alternatives[index].1 = alternative,
+            Err(index) => self.alternatives.insert(index, (class, alternative)),
+        }
+
+        self
+    }
+
+    /// Sets the alternative shown from [`SizeClass::Medium`].
+    pub fn medium(self, alternative: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.at(SizeClass::Medium, alternative)
+    }
+
+    /// Sets the alternative shown from [`SizeClass::Expanded`].
+    pub fn expanded(self, alternative: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.at(SizeClass::Expanded, alternative)
+    }
+
+    /// Sets the [`Breakpoints`] of the [`Adaptive`].
+    pub fn breakpoints(mut self, breakpoints: Breakpoints) -> Self {
+        self.breakpoints = breakpoints;
+        self
+    }
+
+    /// Sets the width of the [`Adaptive`]. It fills its parent by default, to measure the width
+    /// available to it.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Adaptive`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Returns the index of the alternative chosen by the last layout.
+    fn selected(&self, tree: &Tree) -> usize {
+        tree.state
+            .downcast_ref::<State>()
+            .selected
+            .min(self.alternatives.len() - 1)
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Adaptive<'_, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.alternatives
+            .iter()
+            .map(|(_, alternative)| Tree::new(alternative))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(
+            &self
+                .alternatives
+                .iter()
+                .map(|(_, alternative)| alternative)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let class = self.breakpoints.class(limits.max().width);
+
+        let selected = self
+            .alternatives
+            .iter()
+            .rposition(|(alternative, _)| *alternative <= class)
+            .unwrap_or(0);
+        tree.state.downcast_mut::<State>().selected = selected;
+
+        let content = self.alternatives[selected].1.as_widget().layout(
+            &mut tree.children[selected],
+            renderer,
+            &limits,
+        );
+        let size = limits.resolve(self.width, self.height, content.size());
+
+        Node::with_children(size, vec![content])
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let selected = self.selected(tree);
+        let Some(content) = layout.children().next() else {
+            return;
+        };
+
+        self.alternatives[selected].1.as_widget().draw(
+            &tree.children[selected],
+            renderer,
+            theme,
+            style,
+            content,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let selected = self.selected(tree);
+        let Some(content) = layout.children().next() else {
+            return;
+        };
+
+        self.alternatives[selected].1.as_widget().operate(
+            &mut tree.children[selected],
+            content,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let selected = self.selected(tree);
+        let Some(content) = layout.children().next() else {
+            return event::Status::Ignored;
+        };
+
+        self.alternatives[selected].1.as_widget_mut().on_event(
+            &mut tree.children[selected],
+            event,
+            content,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let selected = self.selected(tree);
+        let Some(content) = layout.children().next() else {
+            return mouse::Interaction::default();
+        };
+
+        self.alternatives[selected].1.as_widget().mouse_interaction(
+            &tree.children[selected],
+            content,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let selected = self.selected(tree);
+        let content = layout.children().next()?;
+
+        self.alternatives[selected].1.as_widget_mut().overlay(
+            &mut tree.children[selected],
+            content,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Adaptive<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(value: Adaptive<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`Adaptive`] with the alternative shown in compact widths.
+pub fn adaptive<'a, Message, Theme, Renderer>(
+    compact: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Adaptive<'a, Message, Theme, Renderer> {
+    Adaptive::new(compact)
+}
@@ -0,0 +1,795 @@
+//! A searchable combo box whose options are displayed with arbitrary elements.
+//!
+//! iced's `ComboBox` only displays its options as plain strings. A [`RichComboBox`] displays them
+//! with any element (an icon next to a name, a description under a title...), and filters them
+//! with a user provided matcher, which can rank them (for a fuzzy search for example).
+//!
+//! The list of options is navigated with the arrow keys, and an option is selected with a click
+//! or with Enter.
+//!
+//! The list is a layer following the rules of [`layers`](crate::layers): it is closed, by
+//! unfocusing the input, according to a [`Dismiss`] policy (see [`RichComboBox::dismiss`]).
+
+use std::cell::RefCell;
+
+use iced::{
+    Background, Border, Color, Element, Length, Padding, Pixels, Point, Rectangle, Shadow, Size,
+    Theme, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer, text,
+        widget::{Tree, tree},
+    },
+    event, keyboard,
+    widget::text_input::{self, TextInput},
+};
+
+use crate::layers::Dismiss;
+
+/// The options of a [`RichComboBox`] and the text searched in them.
+///
+/// It must be kept in the application state, as the search text is stored in it.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State<T> {
+    options: Vec<T>,
+    query: RefCell<String>,
+}
+
+impl<T> State<T> {
+    /// Creates a new [`State`] with the given options.
+    pub fn new(options: Vec<T>) -> Self {
+        Self {
+            options,
+            query: RefCell::new(String::new()),
+        }
+    }
+
+    /// Returns the options of the [`State`].
+    pub fn options(&self) -> &[T] {
+        &self.options
+    }
+
+    /// Returns the text currently searched.
+    pub fn query(&self) -> String {
+        self.query.borrow().clone()
+    }
+}
+
+/// The appearance of the list of options of a [`RichComboBox`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the list.
+    pub background: Background,
+    /// The color of the text of the options.
+    pub text_color: Color,
+    /// The border of the list.
+    pub border: Border,
+    /// The shadow of the list.
+    pub shadow: Shadow,
+    /// The background of the highlighted option.
+    pub highlighted_background: Background,
+    /// The color of the text of the highlighted option.
+    pub highlighted_text_color: Color,
+}
+
+/// The theme catalog of a [`RichComboBox`].
+pub trait Catalog: text_input::Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`RichComboBox`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> <Self as Catalog>::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`RichComboBox`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        text_color: palette.background.base.text,
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.,
+            radius: 4.into(),
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0., 2.),
+            blur_radius: 8.,
+        },
+        highlighted_background: palette.primary.weak.color.into(),
+        highlighted_text_color: palette.primary.weak.text,
+    }
+}
+
+/// The function displaying an option.
+type Item<'a, T, Message, Theme, Renderer> =
+    Box<dyn Fn(&T) -> Element<'a, Message, Theme, Renderer> + 'a>;
+
+/// The function matching an option with the searched text.
+type Matcher<'a, T> = Box<dyn Fn(&T, &str) -> Option<i64> + 'a>;
+
+/// The internal state of a [`RichComboBox`].
+struct Menu {
+    /// The position of the highlighted option in the filtered options.
+    highlighted: usize,
+    /// Whether the list must be scrolled to show the highlighted option.
+    reveal: bool,
+    /// The vertical scroll offset of the list.
+    offset: f32,
+    /// The trees of the displayed options.
+    items: Tree,
+}
+
+/// A text input searching in a list of options displayed with arbitrary elements,
+/// created with [`rich_combo_box`].
+///
+/// The options are displayed below the input while it is focused. They are only displayed: events
+/// are not forwarded to their elements.
+pub struct RichComboBox<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    state: &'a State<T>,
+    text_input: TextInput<'a, String, Theme, Renderer>,
+    selection: Option<text_input::Value>,
+    item: Item<'a, T, Message, Theme, Renderer>,
+    matcher: Matcher<'a, T>,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    item_padding: Padding,
+    menu_height: f32,
+    dismiss: Dismiss,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, T, Message, Theme, Renderer> RichComboBox<'a, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`RichComboBox`] searching in the options of `state`, displaying them with
+    /// `item`, and producing the message of `on_select` when one is selected.
+    pub fn new(
+        state: &'a State<T>,
+        placeholder: &str,
+        item: impl Fn(&T) -> Element<'a, Message, Theme, Renderer> + 'a,
+        on_select: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        Self {
+            state,
+            text_input: TextInput::new(placeholder, &state.query.borrow())
+                .on_input(std::convert::identity),
+            selection: None,
+            item: Box::new(item),
+            matcher: Box::new(|_, _| Some(0)),
+            on_select: Box::new(on_select),
+            item_padding: Padding::from([4, 8]),
+            menu_height: 240.,
+            dismiss: Dismiss::default(),
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets the function matching an option with the searched text.
+    ///
+    /// It returns the score of the option, or `None` if the option is filtered out.
+    /// The options are listed by decreasing score. By default, all the options are listed.
+    pub fn matcher(mut self, matcher: impl Fn(&T, &str) -> Option<i64> + 'a) -> Self {
+        self.matcher = Box::new(matcher);
+        self
+    }
+
+    /// Sets how the list of options is closed, [`Dismiss::ANY`] by default.
+    ///
+    /// Closing the list unfocuses the input, so the input stays focused when the event is not
+    /// allowed to close it.
+    pub fn dismiss(mut self, dismiss: Dismiss) -> Self {
+        self.dismiss = dismiss;
+        self
+    }
+
+    /// Sets the text displayed in the input while it is not focused, usually the label of the
+    /// selected option.
+    pub fn selection(mut self, selection: impl AsRef<str>) -> Self {
+        self.selection = Some(text_input::Value::new(selection.as_ref()));
+        self
+    }
+
+    /// Sets the width of the [`RichComboBox`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.text_input = self.text_input.width(width);
+        self
+    }
+
+    /// Sets the padding of the input.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.text_input = self.text_input.padding(padding);
+        self
+    }
+
+    /// Sets the text size of the input.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_input = self.text_input.size(size);
+        self
+    }
+
+    /// Sets the font of the input.
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.text_input = self.text_input.font(font);
+        self
+    }
+
+    /// Sets the padding around each option.
+    pub fn item_padding(mut self, item_padding: impl Into<Padding>) -> Self {
+        self.item_padding = item_padding.into();
+        self
+    }
+
+    /// Sets the maximum height of the list of options.
+    pub fn menu_height(mut self, menu_height: impl Into<Pixels>) -> Self {
+        self.menu_height = menu_height.into().0;
+        self
+    }
+
+    /// Sets the style of the list of options.
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the list of options.
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the style of the input.
+    pub fn input_style(
+        mut self,
+        style: impl Fn(&Theme, text_input::Status) -> text_input::Style + 'a,
+    ) -> Self
+    where
+        <Theme as text_input::Catalog>::Class<'a>: From<text_input::StyleFn<'a, Theme>>,
+    {
+        self.text_input = self.text_input.style(style);
+        self
+    }
+
+    /// Returns the indices of the options matching the searched text, by decreasing score.
+    fn filtered(&self) -> Vec<usize> {
+        let query = self.state.query.borrow();
+        let mut scored: Vec<_> = self
+            .state
+            .options
+            .iter()
+            .enumerate()
+            .filter_map(|(i, option)| (self.matcher)(option, &query).map(|score| (i, score)))
+            .collect();
+        scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+
+        scored.into_iter().map(|(i, _)| i).collect()
+    }
+
+    /// Returns whether the input is focused.
+    fn is_focused(tree: &Tree) -> bool {
+        tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused()
+    }
+}
+
+/// Selects an option: produces its message, clears the search and unfocuses the input.
+fn select<T, Message, Paragraph>(
+    state: &State<T>,
+    index: usize,
+    on_select: &dyn Fn(T) -> Message,
+    menu: &mut Menu,
+    input: &mut Tree,
+    shell: &mut Shell<'_, Message>,
+) where
+    T: Clone,
+    Paragraph: text::Paragraph + 'static,
+{
+    shell.publish(on_select(state.options[index].clone()));
+    state.query.borrow_mut().clear();
+    menu.highlighted = 0;
+    menu.offset = 0.;
+    input
+        .state
+        .downcast_mut::<text_input::State<Paragraph>>()
+        .unfocus();
+    shell.invalidate_widgets();
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for RichComboBox<'_, T, Message, Theme, Renderer>
+where
+    T: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Menu>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Menu {
+            highlighted: 0,
+            reveal: false,
+            offset: 0.,
+            items: Tree::empty(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.text_input as &dyn Widget<_, _, _>)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.text_input as &dyn Widget<_, _, _>]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Widget::<String, Theme, Renderer>::size(&self.text_input)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let selection = (!Self::is_focused(tree))
+            .then_some(self.selection.as_ref())
+            .flatten();
+
+        self.text_input
+            .layout(&mut tree.children[0], renderer, limits, selection)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let selection = (!Self::is_focused(tree))
+            .then_some(self.selection.as_ref())
+            .flatten();
+
+        self.text_input.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            layout,
+            cursor,
+            selection,
+            viewport,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let was_focused = Self::is_focused(tree);
+
+        // The clicks on the list are captured by its overlay, so the input is the only area.
+        if was_focused && Dismiss::ANY.applies(&event, cursor, &[layout.bounds()]) {
+            if !self.dismiss.applies(&event, cursor, &[layout.bounds()]) {
+                return event::Status::Ignored;
+            }
+
+            tree.children[0]
+                .state
+                .downcast_mut::<text_input::State<Renderer::Paragraph>>()
+                .unfocus();
+            shell.invalidate_widgets();
+            return Dismiss::status(&event);
+        }
+
+        let mut queries = Vec::new();
+        let mut local_shell = Shell::new(&mut queries);
+        let status = self.text_input.on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            viewport,
+        );
+
+        let menu = tree.state.downcast_mut::<Menu>();
+
+        if let Some(query) = queries.pop() {
+            *self.state.query.borrow_mut() = query;
+            menu.highlighted = 0;
+            menu.offset = 0.;
+            shell.invalidate_layout();
+        }
+
+        let is_focused = Self::is_focused(tree);
+        if was_focused != is_focused {
+            shell.invalidate_widgets();
+        }
+
+        if !is_focused {
+            return status;
+        }
+
+        let iced::Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key),
+            ..
+        }) = event
+        else {
+            return status;
+        };
+
+        let menu = tree.state.downcast_mut::<Menu>();
+        let count = self.filtered().len();
+
+        match key {
+            keyboard::key::Named::ArrowDown if count > 0 => {
+                menu.highlighted = (menu.highlighted + 1) % count;
+                menu.reveal = true;
+                shell.invalidate_layout();
+                event::Status::Captured
+            }
+            keyboard::key::Named::ArrowUp if count > 0 => {
+                menu.highlighted = (menu.highlighted + count - 1) % count;
+                menu.reveal = true;
+                shell.invalidate_layout();
+                event::Status::Captured
+            }
+            keyboard::key::Named::Enter => {
+                if let Some(&index) = self.filtered().get(menu.highlighted) {
+                    select::<_, _, Renderer::Paragraph>(
+                        self.state,
+                        index,
+                        &self.on_select,
+                        menu,
+                        &mut tree.children[0],
+                        shell,
+                    );
+                }
+                event::Status::Captured
+            }
+            _ => status,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        Widget::<String, Theme, Renderer>::mouse_interaction(
+            &self.text_input,
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        if !Self::is_focused(tree) {
+            return None;
+        }
+
+        let filtered = self.filtered();
+        if filtered.is_empty() {
+            return None;
+        }
+
+        let items: Vec<_> = filtered
+            .iter()
+            .map(|&i| (self.item)(&self.state.options[i]))
+            .collect();
+
+        let (input, _) = tree.children.split_at_mut(1);
+        let menu = tree.state.downcast_mut::<Menu>();
+        menu.items.diff_children(&items);
+        menu.highlighted = menu.highlighted.min(filtered.len() - 1);
+
+        Some(overlay::Element::new(Box::new(Overlay {
+            state: self.state,
+            filtered,
+            items,
+            menu,
+            input: &mut input[0],
+            on_select: &self.on_select,
+            input_bounds: layout.bounds() + translation,
+            item_padding: self.item_padding,
+            menu_height: self.menu_height,
+            class: &self.class,
+        })))
+    }
+}
+
+/// The overlay displaying the options of a [`RichComboBox`].
+struct Overlay<'a, 'b, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    state: &'a State<T>,
+    filtered: Vec<usize>,
+    items: Vec<Element<'a, Message, Theme, Renderer>>,
+    menu: &'b mut Menu,
+    input: &'b mut Tree,
+    on_select: &'b dyn Fn(T) -> Message,
+    input_bounds: Rectangle,
+    item_padding: Padding,
+    menu_height: f32,
+    class: &'b <Theme as Catalog>::Class<'a>,
+}
+
+impl<T, Message, Theme, Renderer> Overlay<'_, '_, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Returns the position of the option under the cursor, if any.
+    fn hovered(&self, layout: Layout<'_>, cursor: mouse::Cursor) -> Option<usize> {
+        cursor.position_over(layout.bounds())?;
+        layout
+            .children()
+            .position(|item| cursor.is_over(item.bounds()))
+    }
+}
+
+impl<T, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, T, Message, Theme, Renderer>
+where
+    T: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        let width = self.input_bounds.width;
+        let limits = Limits::new(Size::ZERO, Size::new(width, f32::INFINITY))
+            .width(width)
+            .shrink(self.item_padding);
+
+        let mut y = 0.;
+        let mut nodes: Vec<_> = self
+            .items
+            .iter()
+            .zip(&mut self.menu.items.children)
+            .map(|(item, tree)| {
+                let content = item
+                    .as_widget()
+                    .layout(tree, renderer, &limits)
+                    .move_to(Point::new(self.item_padding.left, self.item_padding.top));
+                let size = Size::new(width, content.size().height + self.item_padding.vertical());
+                let node = Node::with_children(size, vec![content]).move_to(Point::new(0., y));
+                y += size.height;
+                node
+            })
+            .collect();
+
+        let below = bounds.height - self.input_bounds.y - self.input_bounds.height;
+        let above = self.input_bounds.y;
+        let height = y.min(self.menu_height).min(below.max(above));
+
+        if self.menu.reveal {
+            let item = nodes[self.menu.highlighted].bounds();
+            self.menu.offset = self
+                .menu
+                .offset
+                .max(item.y + item.height - height)
+                .min(item.y);
+            self.menu.reveal = false;
+        }
+        self.menu.offset = self.menu.offset.clamp(0., (y - height).max(0.));
+
+        for node in &mut nodes {
+            node.translate_mut(Vector::new(0., -self.menu.offset));
+        }
+
+        let position = if height <= below || below >= above {
+            Point::new(
+                self.input_bounds.x,
+                self.input_bounds.y + self.input_bounds.height,
+            )
+        } else {
+            Point::new(self.input_bounds.x, self.input_bounds.y - height)
+        };
+
+        Node::with_children(Size::new(width, height), nodes).move_to(position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let style = Catalog::style(theme, self.class);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                shadow: style.shadow,
+            },
+            style.background,
+        );
+
+        renderer.with_layer(bounds, |renderer| {
+            for (i, ((item, tree), layout)) in self
+                .items
+                .iter()
+                .zip(&self.menu.items.children)
+                .zip(layout.children())
+                .enumerate()
+            {
+                let item_bounds = layout.bounds();
+                if !item_bounds.intersects(&bounds) {
+                    continue;
+                }
+
+                let text_color = if i == self.menu.highlighted {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: item_bounds,
+                            border: Border::default().rounded(style.border.radius),
+                            ..Default::default()
+                        },
+                        style.highlighted_background,
+                    );
+                    style.highlighted_text_color
+                } else {
+                    style.text_color
+                };
+
+                item.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    &renderer::Style { text_color },
+                    layout.children().next().unwrap(),
+                    cursor,
+                    &bounds,
+                );
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            iced::Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(hovered) = self.hovered(layout, cursor)
+                    && hovered != self.menu.highlighted
+                {
+                    self.menu.highlighted = hovered;
+                    shell.request_redraw(iced::window::RedrawRequest::NextFrame);
+                }
+                event::Status::Ignored
+            }
+            iced::Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let dy = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * 40.,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+                self.menu.offset -= dy;
+                shell.invalidate_layout();
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if cursor.is_over(bounds) =>
+            {
+                if let Some(hovered) = self.hovered(layout, cursor) {
+                    select::<_, _, Renderer::Paragraph>(
+                        self.state,
+                        self.filtered[hovered],
+                        self.on_select,
+                        self.menu,
+                        self.input,
+                        shell,
+                    );
+                }
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.hovered(layout, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<RichComboBox<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Clone + 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(value: RichComboBox<'a, T, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`RichComboBox`] searching in the options of `state`, displaying them with
+/// `item`, and producing the message of `on_select` when one is selected.
+pub fn rich_combo_box<'a, T, Message, Theme, Renderer>(
+    state: &'a State<T>,
+    placeholder: &str,
+    item: impl Fn(&T) -> Element<'a, Message, Theme, Renderer> + 'a,
+    on_select: impl Fn(T) -> Message + 'a,
+) -> RichComboBox<'a, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    RichComboBox::new(state, placeholder, item, on_select)
+}
@@ -0,0 +1,1021 @@
+//! A basic rich text editor with bold, italic and underlined text, headings and lists.
+//!
+//! The document is a [`Content`] owned by the application: the editor produces [`Action`]s,
+//! which the application applies with [`Content::perform`], like iced's
+//! [`text_editor`](iced::widget::text_editor). The document is read back as a list of [`Block`]s
+//! made of formatted [`Span`]s, or exported with [`Content::to_markdown`] and
+//! [`Content::to_html`].
+//!
+//! A toolbar above the text toggles the formatting. The usual shortcuts work too: Ctrl+B, Ctrl+I
+//! and Ctrl+U (Cmd on macOS) toggle bold, italic and underline.
+
+use iced::{
+    Background, Border, Color, Element, Font, Length, Padding, Pixels, Point, Rectangle, Renderer,
+    Size, Theme, Vector,
+    advanced::{
+        self, Clipboard, Layout, Shell, Widget,
+        clipboard::Kind,
+        layout::{Limits, Node},
+        mouse, renderer,
+        text::{self, LineHeight, Paragraph as _, Renderer as _, Shaping, Text, Wrapping},
+        widget::{Id, Operation, Tree, operation, tree},
+    },
+    alignment, event, font, keyboard,
+    widget::{Row, button, text as text_widget},
+    window,
+};
+
+use crate::i18n::{self, Direction};
+
+mod content;
+mod export;
+pub use content::*;
+
+/// The possible status of a [`RichEditor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`RichEditor`] can be interacted with.
+    Active,
+    /// The [`RichEditor`] is being hovered.
+    Hovered,
+    /// The [`RichEditor`] is focused.
+    Focused,
+}
+
+/// The appearance of a [`RichEditor`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the text area.
+    pub background: Background,
+    /// The border of the text area.
+    pub border: Border,
+    /// The color of the text.
+    pub text_color: Color,
+    /// The color of the placeholder.
+    pub placeholder: Color,
+    /// The color of the selection.
+    pub selection: Color,
+    /// The color of the cursor.
+    pub caret: Color,
+}
+
+/// The theme catalog of a [`RichEditor`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`RichEditor`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`RichEditor`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let border_color = match status {
+        Status::Active => palette.background.strong.color,
+        Status::Hovered => palette.background.base.text,
+        Status::Focused => palette.primary.strong.color,
+    };
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            color: border_color,
+            width: 1.,
+            radius: 2.into(),
+        },
+        text_color: palette.background.base.text,
+        placeholder: palette.background.strong.color,
+        selection: palette.primary.weak.color,
+        caret: palette.background.base.text,
+    }
+}
+
+/// A basic rich text editor, created with [`rich_editor`].
+///
+/// Without [`RichEditor::on_action`], the editor is read-only and has no toolbar.
+pub struct RichEditor<'a, Message> {
+    content: &'a Content,
+    on_action: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    toolbar: bool,
+    placeholder: String,
+    id: Option<Id>,
+    text_size: f32,
+    font: Font,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    direction: Option<Direction>,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Message> RichEditor<'a, Message> {
+    /// Creates a new [`RichEditor`] displaying the given [`Content`].
+    pub fn new(content: &'a Content) -> Self {
+        Self {
+            content,
+            on_action: None,
+            toolbar: true,
+            placeholder: String::new(),
+            id: None,
+            text_size: 16.,
+            font: Font::default(),
+            padding: Padding::new(8.),
+            width: Length::Fill,
+            height: Length::Shrink,
+            direction: None,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets the message produced with the [`Action`]s of the user, which the application
+    /// applies to the [`Content`] with [`Content::perform`].
+    ///
+    /// Without it, the [`RichEditor`] is read-only.
+    pub fn on_action(mut self, on_action: impl Fn(Action) -> Message + 'a) -> Self {
+        self.on_action = Some(Box::new(on_action));
+        self
+    }
+
+    /// Sets whether the formatting toolbar is displayed. It is by default.
+    pub fn toolbar(mut self, toolbar: bool) -> Self {
+        self.toolbar = toolbar;
+        self
+    }
+
+    /// Sets the placeholder displayed when the document is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the [`Id`] of the [`RichEditor`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the size of the paragraph text. Headings are scaled from it.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = text_size.into().0;
+        self
+    }
+
+    /// Sets the font of the text. Bold and italic text use its bold and italic variants.
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the padding of the text area.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`RichEditor`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`RichEditor`]. By default, it grows with the document.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the direction of the toolbar of the [`RichEditor`].
+    ///
+    /// From right to left, the tools are in reverse order and aligned to the right.
+    ///
+    /// If this method is not called, the [`i18n::direction`] of the current thread is read
+    /// when the [`RichEditor`] is turned into an [`Element`] and when it is laid out.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the style of the [`RichEditor`].
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self {
+        self.class = Box::new(style);
+        self
+    }
+
+    /// Sets the style class of the [`RichEditor`].
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the direction set with [`direction`](Self::direction), or the one of the
+    /// [`i18n`] format of the current thread.
+    fn resolved_direction(&self) -> Direction {
+        self.direction.unwrap_or_else(i18n::direction)
+    }
+}
+
+/// Creates a new [`RichEditor`] displaying the given [`Content`].
+pub fn rich_editor<'a, Message>(content: &'a Content) -> RichEditor<'a, Message> {
+    RichEditor::new(content)
+}
+
+/// The spacing between the toolbar and the text area.
+const TOOLBAR_SPACING: f32 = 4.;
+
+/// Returns the text size of a block of the given kind.
+fn block_size(kind: BlockKind, size: f32) -> f32 {
+    match kind {
+        BlockKind::Heading(1) => size * 2.,
+        BlockKind::Heading(2) => size * 1.6,
+        BlockKind::Heading(3) => size * 1.3,
+        BlockKind::Heading(_) => size * 1.1,
+        _ => size,
+    }
+}
+
+/// Returns the font of the characters with the given format in a block of the given kind.
+fn block_font(kind: BlockKind, format: Format, base: Font) -> Font {
+    let bold = format.bold || matches!(kind, BlockKind::Heading(_));
+
+    Font {
+        weight: if bold {
+            font::Weight::Bold
+        } else {
+            base.weight
+        },
+        style: if format.italic {
+            font::Style::Italic
+        } else {
+            base.style
+        },
+        ..base
+    }
+}
+
+/// Returns the indentation of a block of the given kind, which leaves room for list markers.
+fn block_indent(kind: BlockKind, size: f32) -> f32 {
+    if kind.is_list() { size * 1.75 } else { 0. }
+}
+
+/// Builds the formatting toolbar.
+fn toolbar<'a, Message: Clone + 'a>(
+    content: &Content,
+    on_action: &dyn Fn(Action) -> Message,
+    font: Font,
+    direction: Direction,
+) -> Element<'a, Message> {
+    let format = content.format();
+    let kind = content.block_kind();
+
+    let tool =
+        |label: text_widget::Text<'a>, active: bool, action: Action| -> Element<'a, Message> {
+            button(label.size(14))
+                .padding([2, 8])
+                .style(if active {
+                    button::primary
+                } else {
+                    button::text
+                })
+                .on_press(on_action(action))
+                .into()
+        };
+    let heading = |level: u8| {
+        tool(
+            text_widget(format!("H{level}")),
+            kind == BlockKind::Heading(level),
+            Action::SetBlockKind(BlockKind::Heading(level)),
+        )
+    };
+
+    let mut tools = vec![
+        tool(
+            text_widget("B").font(block_font(
+                BlockKind::Paragraph,
+                Format {
+                    bold: true,
+                    ..Format::default()
+                },
+                font,
+            )),
+            format.bold,
+            Action::ToggleBold,
+        ),
+        tool(
+            text_widget("I").font(block_font(
+                BlockKind::Paragraph,
+                Format {
+                    italic: true,
+                    ..Format::default()
+                },
+                font,
+            )),
+            format.italic,
+            Action::ToggleItalic,
+        ),
+        tool(text_widget("U̲"), format.underline, Action::ToggleUnderline),
+        heading(1),
+        heading(2),
+        heading(3),
+        tool(
+            text_widget("•"),
+            kind == BlockKind::Bullet,
+            Action::SetBlockKind(BlockKind::Bullet),
+        ),
+        tool(
+            text_widget("1."),
+            kind == BlockKind::Numbered,
+            Action::SetBlockKind(BlockKind::Numbered),
+        ),
+    ];
+
+    if direction.is_rtl() {
+        tools.reverse();
+    }
+
+    Row::with_children(tools).spacing(2).into()
+}
+
+impl<'a, Message: Clone + 'a> From<RichEditor<'a, Message>> for Element<'a, Message> {
+    fn from(value: RichEditor<'a, Message>) -> Self {
+        let toolbar = value
+            .on_action
+            .as_deref()
+            .filter(|_| value.toolbar)
+            .map(|on_action| toolbar(value.content, on_action, value.font, value.resolved_direction()));
+
+        Element::new(Editor {
+            editor: value,
+            toolbar,
+        })
+    }
+}
+
+/// The paragraph of the renderer.
+type Paragraph = <Renderer as text::Renderer>::Paragraph;
+
+/// The state of a [`RichEditor`].
+#[derive(Default)]
+struct State {
+    /// The laid out paragraph of each block.
+    paragraphs: Vec<Paragraph>,
+    focused: bool,
+    dragging: bool,
+    modifiers: keyboard::Modifiers,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn focus(&mut self) {
+        self.focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.focused = false;
+    }
+}
+
+/// The widget of a [`RichEditor`], with its toolbar.
+struct Editor<'a, Message> {
+    editor: RichEditor<'a, Message>,
+    toolbar: Option<Element<'a, Message>>,
+}
+
+impl<Message> Editor<'_, Message> {
+    /// Lays out the paragraph of a block.
+    fn paragraph(&self, block: &Block, width: f32) -> Paragraph {
+        let kind = block.kind();
+        let text: String = block.text();
+
+        let spans: Vec<text::Span<'_>> = text
+            .char_indices()
+            .zip(block.chars())
+            .map(|((i, c), (_, format))| {
+                text::Span::new(&text[i..i + c.len_utf8()]).font(block_font(
+                    kind,
+                    *format,
+                    self.editor.font,
+                ))
+            })
+            .collect();
+
+        Paragraph::with_spans::<()>(Text {
+            content: &spans,
+            bounds: Size::new(width, f32::INFINITY),
+            size: Pixels(block_size(kind, self.editor.text_size)),
+            line_height: LineHeight::default(),
+            font: block_font(kind, Format::default(), self.editor.font),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: Shaping::Advanced,
+            wrapping: Wrapping::WordOrGlyph,
+        })
+    }
+
+    /// Returns the bounds of the cursor at `offset` in a block, relative to the block.
+    fn caret(&self, block: &Block, paragraph: &Paragraph, offset: usize) -> Rectangle {
+        let size = block_size(block.kind(), self.editor.text_size);
+        let line_height = LineHeight::default().to_absolute(Pixels(size)).0;
+
+        let (x, y) = if block.is_empty() {
+            (0., 0.)
+        } else if offset < block.len() {
+            paragraph
+                .span_bounds(offset)
+                .first()
+                .map_or((0., 0.), |bounds| (bounds.x, bounds.y))
+        } else {
+            paragraph
+                .span_bounds(block.len() - 1)
+                .last()
+                .map_or((0., 0.), |bounds| (bounds.x + bounds.width, bounds.y))
+        };
+
+        Rectangle::new(Point::new(x, y), Size::new(1., line_height))
+    }
+
+    /// Returns the position in the block at `index` closest to `point`.
+    fn hit_block(&self, state: &State, area: Layout<'_>, index: usize, point: Point) -> Position {
+        let block = &self.editor.content.blocks()[index];
+        let bounds = area.children().nth(index).unwrap().bounds();
+
+        let offset = state.paragraphs[index]
+            .hit_test(point - Vector::new(bounds.x, bounds.y))
+            .map_or(0, |hit| {
+                let byte = hit.cursor();
+                block
+                    .text()
+                    .char_indices()
+                    .take_while(|(i, _)| *i < byte)
+                    .count()
+            });
+
+        Position {
+            block: index,
+            offset,
+        }
+    }
+
+    /// Returns the position in the document closest to `point`.
+    fn hit(&self, state: &State, area: Layout<'_>, point: Point) -> Position {
+        let spacing = self.editor.text_size * 0.5;
+        let index = area
+            .children()
+            .position(|block| point.y < block.bounds().y + block.bounds().height + spacing / 2.)
+            .unwrap_or(state.paragraphs.len() - 1);
+
+        self.hit_block(state, area, index, point)
+    }
+
+    /// Returns the position of the cursor one line above or below.
+    fn vertical(&self, state: &State, area: Layout<'_>, up: bool) -> Position {
+        let blocks = self.editor.content.blocks();
+        let cursor = self.editor.content.cursor();
+        let bounds = area.children().nth(cursor.block).unwrap().bounds();
+        let caret = self.caret(
+            &blocks[cursor.block],
+            &state.paragraphs[cursor.block],
+            cursor.offset,
+        ) + Vector::new(bounds.x, bounds.y);
+
+        if up {
+            let y = caret.y - 1.;
+            if y >= bounds.y {
+                self.hit_block(state, area, cursor.block, Point::new(caret.x, y))
+            } else if cursor.block > 0 {
+                let previous = area.children().nth(cursor.block - 1).unwrap().bounds();
+                let point = Point::new(caret.x, previous.y + previous.height - 1.);
+                self.hit_block(state, area, cursor.block - 1, point)
+            } else {
+                Position::default()
+            }
+        } else {
+            let y = caret.y + caret.height + 1.;
+            if y < bounds.y + bounds.height {
+                self.hit_block(state, area, cursor.block, Point::new(caret.x, y))
+            } else if cursor.block + 1 < blocks.len() {
+                let next = area.children().nth(cursor.block + 1).unwrap().bounds();
+                self.hit_block(
+                    state,
+                    area,
+                    cursor.block + 1,
+                    Point::new(caret.x, next.y + 1.),
+                )
+            } else {
+                Position {
+                    block: cursor.block,
+                    offset: blocks[cursor.block].len(),
+                }
+            }
+        }
+    }
+
+    /// Returns the action of a key press, if any.
+    fn key_action(
+        &self,
+        state: &mut State,
+        area: Layout<'_>,
+        key: keyboard::Key,
+        text: Option<&str>,
+        clipboard: &mut dyn Clipboard,
+    ) -> Option<Action> {
+        use keyboard::key::Named;
+
+        let content = self.editor.content;
+        let modifiers = state.modifiers;
+        let motion = |motion| {
+            if modifiers.shift() {
+                Action::Select(motion)
+            } else {
+                Action::Move(motion)
+            }
+        };
+        let to = |position| {
+            if modifiers.shift() {
+                Action::SelectTo(position)
+            } else {
+                Action::MoveTo(position)
+            }
+        };
+
+        match key.as_ref() {
+            keyboard::Key::Character("b") if modifiers.command() => Some(Action::ToggleBold),
+            keyboard::Key::Character("i") if modifiers.command() => Some(Action::ToggleItalic),
+            keyboard::Key::Character("u") if modifiers.command() => Some(Action::ToggleUnderline),
+            keyboard::Key::Character("a") if modifiers.command() => Some(Action::SelectAll),
+            keyboard::Key::Character("c") if modifiers.command() => {
+                if let Some(selected) = content.selected_text() {
+                    clipboard.write(Kind::Standard, selected);
+                }
+                None
+            }
+            keyboard::Key::Character("x") if modifiers.command() => {
+                let selected = content.selected_text()?;
+                clipboard.write(Kind::Standard, selected);
+                Some(Action::Backspace)
+            }
+            keyboard::Key::Character("v") if modifiers.command() => {
+                clipboard.read(Kind::Standard).map(Action::Paste)
+            }
+            keyboard::Key::Named(Named::ArrowLeft) if modifiers.jump() => {
+                Some(motion(Motion::WordLeft))
+            }
+            keyboard::Key::Named(Named::ArrowRight) if modifiers.jump() => {
+                Some(motion(Motion::WordRight))
+            }
+            keyboard::Key::Named(Named::ArrowLeft) => Some(motion(Motion::Left)),
+            keyboard::Key::Named(Named::ArrowRight) => Some(motion(Motion::Right)),
+            keyboard::Key::Named(Named::ArrowUp) => Some(to(self.vertical(state, area, true))),
+            keyboard::Key::Named(Named::ArrowDown) => Some(to(self.vertical(state, area, false))),
+            keyboard::Key::Named(Named::Home) if modifiers.command() => {
+                Some(motion(Motion::DocumentStart))
+            }
+            keyboard::Key::Named(Named::End) if modifiers.command() => {
+                Some(motion(Motion::DocumentEnd))
+            }
+            keyboard::Key::Named(Named::Home) => Some(motion(Motion::BlockStart)),
+            keyboard::Key::Named(Named::End) => Some(motion(Motion::BlockEnd)),
+            keyboard::Key::Named(Named::Enter) => Some(Action::Enter),
+            keyboard::Key::Named(Named::Backspace) => Some(Action::Backspace),
+            keyboard::Key::Named(Named::Delete) => Some(Action::Delete),
+            keyboard::Key::Named(Named::Escape) => {
+                state.focused = false;
+                None
+            }
+            _ => {
+                let text: String = text
+                    .filter(|_| !modifiers.command())?
+                    .chars()
+                    .filter(|c| !c.is_control())
+                    .collect();
+
+                match text.chars().count() {
+                    0 => None,
+                    1 => text.chars().next().map(Action::Insert),
+                    _ => Some(Action::Paste(text)),
+                }
+            }
+        }
+    }
+}
+
+impl<Message> Widget<Message, Theme, Renderer> for Editor<'_, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.toolbar.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(self.toolbar.as_slice());
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.editor.width, self.editor.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.editor.width).height(self.editor.height);
+        let max = limits.max();
+        let padding = self.editor.padding;
+        let spacing = self.editor.text_size * 0.5;
+
+        let mut toolbar = self.toolbar.as_ref().map(|toolbar| {
+            toolbar.as_widget().layout(
+                &mut tree.children[0],
+                renderer,
+                &Limits::new(Size::ZERO, max),
+            )
+        });
+        let top = toolbar
+            .as_ref()
+            .map_or(0., |toolbar| toolbar.size().height + TOOLBAR_SPACING);
+
+        let width = (max.width - padding.horizontal()).max(0.);
+        let blocks = self.editor.content.blocks();
+        let paragraphs: Vec<_> = blocks
+            .iter()
+            .map(|block| {
+                let indent = block_indent(block.kind(), self.editor.text_size);
+                self.paragraph(block, (width - indent).max(0.))
+            })
+            .collect();
+
+        let mut y = padding.top;
+        let mut content_width: f32 = 0.;
+        let nodes: Vec<_> = blocks
+            .iter()
+            .zip(&paragraphs)
+            .map(|(block, paragraph)| {
+                let kind = block.kind();
+                let indent = block_indent(kind, self.editor.text_size);
+                let line_height = LineHeight::default()
+                    .to_absolute(Pixels(block_size(kind, self.editor.text_size)))
+                    .0;
+                let bounds = paragraph.min_bounds();
+                let height = bounds.height.max(line_height);
+
+                content_width = content_width.max(indent + bounds.width);
+                let node = Node::new(Size::new((width - indent).max(0.), height))
+                    .move_to(Point::new(padding.left + indent, y));
+                y += height + spacing;
+                node
+            })
+            .collect();
+
+        tree.state.downcast_mut::<State>().paragraphs = paragraphs;
+
+        let intrinsic = Size::new(
+            content_width + padding.horizontal(),
+            top + y - spacing + padding.bottom,
+        );
+        let size = limits.resolve(self.editor.width, self.editor.height, intrinsic);
+
+        let area = Node::with_children(Size::new(size.width, (size.height - top).max(0.)), nodes)
+            .move_to(Point::new(0., top));
+
+        if let Some(toolbar) = &mut toolbar {
+            let x = self
+                .editor
+                .resolved_direction()
+                .mirror(0., toolbar.size().width, size.width);
+            toolbar.move_to_mut(Point::new(x, 0.));
+        }
+
+        Node::with_children(size, std::iter::once(area).chain(toolbar).collect())
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let mut children = layout.children();
+        let area = children.next().unwrap();
+        let bounds = area.bounds();
+
+        if let Some((toolbar, layout)) = self.toolbar.as_ref().zip(children.next()) {
+            toolbar.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                viewport,
+            );
+        }
+
+        let status = if state.focused {
+            Status::Focused
+        } else if self.editor.on_action.is_some() && cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+        let style = Catalog::style(theme, &self.editor.class, status);
+
+        advanced::Renderer::fill_quad(
+            renderer,
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..Default::default()
+            },
+            style.background,
+        );
+
+        let content = self.editor.content;
+        let selection = content.selection();
+        let text_size = self.editor.text_size;
+
+        advanced::Renderer::with_layer(renderer, bounds, |renderer| {
+            let mut number = 0;
+
+            for (i, ((block, paragraph), layout)) in content
+                .blocks()
+                .iter()
+                .zip(&state.paragraphs)
+                .zip(area.children())
+                .enumerate()
+            {
+                let position = layout.position();
+                let translation = position - Point::ORIGIN;
+                let size = block_size(block.kind(), text_size);
+
+                number = match block.kind() {
+                    BlockKind::Numbered => number + 1,
+                    _ => 0,
+                };
+                let marker = match block.kind() {
+                    BlockKind::Bullet => Some("•".to_owned()),
+                    BlockKind::Numbered => Some(format!("{number}.")),
+                    _ => None,
+                };
+                if let Some(marker) = marker {
+                    renderer.fill_text(
+                        Text {
+                            content: marker,
+                            bounds: Size::new(block_indent(block.kind(), text_size), f32::INFINITY),
+                            size: Pixels(size),
+                            line_height: LineHeight::default(),
+                            font: self.editor.font,
+                            horizontal_alignment: alignment::Horizontal::Left,
+                            vertical_alignment: alignment::Vertical::Top,
+                            shaping: Shaping::Advanced,
+                            wrapping: Wrapping::None,
+                        },
+                        Point::new(
+                            position.x - block_indent(block.kind(), text_size) + size * 0.25,
+                            position.y,
+                        ),
+                        style.text_color,
+                        bounds,
+                    );
+                }
+
+                if let Some((start, end)) = selection
+                    && (start.block..=end.block).contains(&i)
+                {
+                    let from = if i == start.block { start.offset } else { 0 };
+                    let to = if i == end.block {
+                        end.offset
+                    } else {
+                        block.len()
+                    };
+
+                    for region in (from..to).flat_map(|offset| paragraph.span_bounds(offset)) {
+                        advanced::Renderer::fill_quad(
+                            renderer,
+                            renderer::Quad {
+                                bounds: region + translation,
+                                ..Default::default()
+                            },
+                            style.selection,
+                        );
+                    }
+                }
+
+                let line_height = LineHeight::default().to_absolute(Pixels(size)).0;
+                let baseline = size + (line_height - size) / 2. - size * 0.08;
+                for (offset, _) in block
+                    .chars()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (_, format))| format.underline)
+                {
+                    for region in paragraph.span_bounds(offset) {
+                        advanced::Renderer::fill_quad(
+                            renderer,
+                            renderer::Quad {
+                                bounds: Rectangle::new(
+                                    Point::new(region.x, region.y + baseline) + translation,
+                                    Size::new(region.width, 1.),
+                                ),
+                                ..Default::default()
+                            },
+                            style.text_color,
+                        );
+                    }
+                }
+
+                renderer.fill_paragraph(paragraph, position, style.text_color, bounds);
+            }
+
+            if content.is_empty()
+                && let Some(first) = area.children().next()
+            {
+                let kind = content.blocks()[0].kind();
+                renderer.fill_text(
+                    Text {
+                        content: self.editor.placeholder.clone(),
+                        bounds: first.bounds().size(),
+                        size: Pixels(block_size(kind, text_size)),
+                        line_height: LineHeight::default(),
+                        font: block_font(kind, Format::default(), self.editor.font),
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Top,
+                        shaping: Shaping::Advanced,
+                        wrapping: Wrapping::WordOrGlyph,
+                    },
+                    first.position(),
+                    style.placeholder,
+                    bounds,
+                );
+            }
+
+            let position = content.cursor();
+            if state.focused
+                && let Some(layout) = area.children().nth(position.block)
+            {
+                let caret = self.caret(
+                    &content.blocks()[position.block],
+                    &state.paragraphs[position.block],
+                    position.offset,
+                );
+                advanced::Renderer::fill_quad(
+                    renderer,
+                    renderer::Quad {
+                        bounds: caret + (layout.position() - Point::ORIGIN),
+                        ..Default::default()
+                    },
+                    style.caret,
+                );
+            }
+        });
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        operation.focusable(state, self.editor.id.as_ref());
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let area = children.next().unwrap();
+
+        if let Some((toolbar, layout)) = self.toolbar.as_mut().zip(children.next())
+            && toolbar.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event.clone(),
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            ) == event::Status::Captured
+        {
+            return event::Status::Captured;
+        }
+
+        let Some(on_action) = &self.editor.on_action else {
+            return event::Status::Ignored;
+        };
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(area.bounds()) else {
+                    if !cursor.is_over(layout.bounds()) {
+                        state.focused = false;
+                    }
+                    return event::Status::Ignored;
+                };
+
+                let position = self.hit(state, area, position);
+                state.focused = true;
+                state.dragging = true;
+                shell.publish(on_action(if state.modifiers.shift() {
+                    Action::SelectTo(position)
+                } else {
+                    Action::MoveTo(position)
+                }));
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::CursorMoved { position }) if state.dragging => {
+                let position = self.hit(state, area, position);
+                shell.publish(on_action(Action::SelectTo(position)));
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                state.dragging = false;
+                event::Status::Ignored
+            }
+            iced::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+                event::Status::Ignored
+            }
+            iced::Event::Keyboard(keyboard::Event::KeyPressed { key, text, .. })
+                if state.focused =>
+            {
+                if let Some(action) = self.key_action(state, area, key, text.as_deref(), clipboard)
+                {
+                    shell.publish(on_action(action));
+                }
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let area = children.next().unwrap();
+
+        if self.editor.on_action.is_some() && cursor.is_over(area.bounds()) {
+            return mouse::Interaction::Text;
+        }
+
+        self.toolbar.as_ref().zip(children.next()).map_or(
+            mouse::Interaction::default(),
+            |(toolbar, layout)| {
+                toolbar.as_widget().mouse_interaction(
+                    &tree.children[0],
+                    layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            },
+        )
+    }
+}
@@ -0,0 +1,646 @@
+/// The formatting of a piece of text in a [`RichEditor`](super::RichEditor).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Format {
+    /// Whether the text is bold.
+    pub bold: bool,
+    /// Whether the text is italic.
+    pub italic: bool,
+    /// Whether the text is underlined.
+    pub underline: bool,
+}
+
+/// A piece of text with a single [`Format`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    /// The text of the span.
+    pub text: String,
+    /// The format of the text.
+    pub format: Format,
+}
+
+impl Span {
+    /// Creates a new [`Span`] with the given text and format.
+    pub fn new(text: impl Into<String>, format: Format) -> Self {
+        Self {
+            text: text.into(),
+            format,
+        }
+    }
+}
+
+/// The kind of a [`Block`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BlockKind {
+    /// A paragraph of text.
+    #[default]
+    Paragraph,
+    /// A heading of the given level, from 1 (the largest) to 6.
+    Heading(u8),
+    /// An item of a bullet list.
+    Bullet,
+    /// An item of a numbered list.
+    Numbered,
+}
+
+impl BlockKind {
+    /// Returns whether the kind is a list item.
+    pub fn is_list(self) -> bool {
+        matches!(self, Self::Bullet | Self::Numbered)
+    }
+}
+
+/// A block of a [`Content`]: a paragraph, a heading or a list item.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Block {
+    kind: BlockKind,
+    chars: Vec<(char, Format)>,
+}
+
+impl Block {
+    /// Creates a new [`Block`] of the given kind with the given spans.
+    ///
+    /// Blocks are single paragraphs: line breaks in the spans are replaced by spaces.
+    pub fn new(kind: BlockKind, spans: impl IntoIterator<Item = Span>) -> Self {
+        Self {
+            kind,
+            chars: spans
+                .into_iter()
+                .flat_map(|span| {
+                    span.text
+                        .chars()
+                        .map(|c| if c == '\n' { ' ' } else { c })
+                        .map(move |c| (c, span.format))
+                        .collect::<Vec<_>>()
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the [`BlockKind`] of the block.
+    pub fn kind(&self) -> BlockKind {
+        self.kind
+    }
+
+    /// Returns the text of the block as spans, where consecutive characters with the same
+    /// [`Format`] are merged.
+    pub fn spans(&self) -> Vec<Span> {
+        let mut spans: Vec<Span> = Vec::new();
+
+        for &(c, format) in &self.chars {
+            match spans.last_mut() {
+                Some(span) if span.format == format => span.text.push(c),
+                _ => spans.push(Span::new(c, format)),
+            }
+        }
+
+        spans
+    }
+
+    /// Returns the text of the block, without formatting.
+    pub fn text(&self) -> String {
+        self.chars.iter().map(|(c, _)| c).collect()
+    }
+
+    /// Returns the number of characters of the block.
+    pub fn len(&self) -> usize {
+        self.chars.len()
+    }
+
+    /// Returns whether the block has no text.
+    pub fn is_empty(&self) -> bool {
+        self.chars.is_empty()
+    }
+
+    /// Returns the characters of the block with their format.
+    pub(super) fn chars(&self) -> &[(char, Format)] {
+        &self.chars
+    }
+}
+
+/// A position in a [`Content`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Position {
+    /// The index of the block.
+    pub block: usize,
+    /// The offset in the block, in characters.
+    pub offset: usize,
+}
+
+/// A movement of the cursor of a [`Content`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Motion {
+    /// The previous character.
+    Left,
+    /// The next character.
+    Right,
+    /// The start of the current or previous word.
+    WordLeft,
+    /// The end of the current or next word.
+    WordRight,
+    /// The start of the block.
+    BlockStart,
+    /// The end of the block.
+    BlockEnd,
+    /// The start of the document.
+    DocumentStart,
+    /// The end of the document.
+    DocumentEnd,
+}
+
+/// An action performed on a [`Content`], produced by a [`RichEditor`](super::RichEditor).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    /// Moves the cursor and clears the selection.
+    Move(Motion),
+    /// Moves the cursor and extends the selection.
+    Select(Motion),
+    /// Moves the cursor to the given position and clears the selection.
+    MoveTo(Position),
+    /// Moves the cursor to the given position and extends the selection.
+    SelectTo(Position),
+    /// Selects the whole document.
+    SelectAll,
+    /// Replaces the selection with a character.
+    Insert(char),
+    /// Replaces the selection with some text, where line breaks start new blocks.
+    Paste(String),
+    /// Splits the block at the cursor.
+    Enter,
+    /// Deletes the selection or the character before the cursor.
+    Backspace,
+    /// Deletes the selection or the character after the cursor.
+    Delete,
+    /// Toggles bold on the selection or for the next typed characters.
+    ToggleBold,
+    /// Toggles italic on the selection or for the next typed characters.
+    ToggleItalic,
+    /// Toggles underline on the selection or for the next typed characters.
+    ToggleUnderline,
+    /// Sets the kind of the selected blocks, or turns them back into paragraphs if they already
+    /// are of this kind.
+    SetBlockKind(BlockKind),
+}
+
+/// The document edited by a [`RichEditor`](super::RichEditor), with its cursor and selection.
+///
+/// The editor produces [`Action`]s that the application applies with [`Content::perform`].
+///
+/// ```
+/// use more_iced_aw::rich_editor::{Action, BlockKind, Content};
+///
+/// let mut content = Content::new();
+/// content.perform(Action::SetBlockKind(BlockKind::Heading(1)));
+/// content.perform(Action::Paste("Title\nSome ".to_owned()));
+/// content.perform(Action::ToggleBold);
+/// content.perform(Action::Paste("bold".to_owned()));
+///
+/// assert_eq!(content.to_markdown(), "# Title\n\nSome **bold**");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Content {
+    blocks: Vec<Block>,
+    cursor: Position,
+    anchor: Option<Position>,
+    pending: Option<Format>,
+}
+
+impl Default for Content {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Content {
+    /// Creates a new empty [`Content`].
+    pub fn new() -> Self {
+        Self::with_blocks([])
+    }
+
+    /// Creates a new [`Content`] with the given blocks.
+    pub fn with_blocks(blocks: impl IntoIterator<Item = Block>) -> Self {
+        let mut blocks: Vec<_> = blocks.into_iter().collect();
+        if blocks.is_empty() {
+            blocks.push(Block::default());
+        }
+
+        Self {
+            blocks,
+            cursor: Position::default(),
+            anchor: None,
+            pending: None,
+        }
+    }
+
+    /// Creates a new [`Content`] with the given plain text, with one paragraph per line.
+    pub fn with_text(text: &str) -> Self {
+        Self::with_blocks(
+            text.lines()
+                .map(|line| Block::new(BlockKind::Paragraph, [Span::new(line, Format::default())])),
+        )
+    }
+
+    /// Returns the blocks of the document.
+    pub fn blocks(&self) -> &[Block] {
+        &self.blocks
+    }
+
+    /// Returns the text of the document, without formatting, with one line per block.
+    pub fn text(&self) -> String {
+        self.blocks
+            .iter()
+            .map(Block::text)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Returns whether the document has no text.
+    pub fn is_empty(&self) -> bool {
+        self.blocks.len() == 1 && self.blocks[0].is_empty()
+    }
+
+    /// Returns the position of the cursor.
+    pub fn cursor(&self) -> Position {
+        self.cursor
+    }
+
+    /// Returns the start and the end of the selection, if any.
+    pub fn selection(&self) -> Option<(Position, Position)> {
+        let anchor = self.anchor.filter(|&anchor| anchor != self.cursor)?;
+
+        Some((anchor.min(self.cursor), anchor.max(self.cursor)))
+    }
+
+    /// Returns the selected text, without formatting, if any.
+    pub fn selected_text(&self) -> Option<String> {
+        let (start, end) = self.selection()?;
+
+        let text = (start.block..=end.block)
+            .map(|i| {
+                let chars = self.blocks[i].chars();
+                let from = if i == start.block { start.offset } else { 0 };
+                let to = if i == end.block {
+                    end.offset
+                } else {
+                    chars.len()
+                };
+
+                chars[from..to].iter().map(|(c, _)| c).collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Some(text)
+    }
+
+    /// Returns the format of the selection, where a style is set if all the selected text has
+    /// it, or the format of the next typed characters.
+    pub fn format(&self) -> Format {
+        let Some((start, end)) = self.selection() else {
+            return self.typing_format();
+        };
+
+        let mut selected = self.selected_chars(start, end).map(|(_, format)| format);
+        let Some(first) = selected.next() else {
+            return Format::default();
+        };
+
+        selected.fold(first, |all, format| Format {
+            bold: all.bold && format.bold,
+            italic: all.italic && format.italic,
+            underline: all.underline && format.underline,
+        })
+    }
+
+    /// Returns the kind of the block of the cursor.
+    pub fn block_kind(&self) -> BlockKind {
+        self.blocks[self.cursor.block].kind
+    }
+
+    /// Performs an [`Action`] on the document.
+    pub fn perform(&mut self, action: Action) {
+        match action {
+            Action::Move(motion) => {
+                self.cursor = match (self.selection(), motion) {
+                    (Some((start, _)), Motion::Left) => start,
+                    (Some((_, end)), Motion::Right) => end,
+                    _ => self.moved(motion),
+                };
+                self.anchor = None;
+                self.pending = None;
+            }
+            Action::Select(motion) => {
+                self.anchor.get_or_insert(self.cursor);
+                self.cursor = self.moved(motion);
+                self.pending = None;
+            }
+            Action::MoveTo(position) => {
+                self.cursor = self.clamp(position);
+                self.anchor = None;
+                self.pending = None;
+            }
+            Action::SelectTo(position) => {
+                self.anchor.get_or_insert(self.cursor);
+                self.cursor = self.clamp(position);
+                self.pending = None;
+            }
+            Action::SelectAll => {
+                self.anchor = Some(Position::default());
+                self.cursor = self.end();
+                self.pending = None;
+            }
+            Action::Insert('\n') => self.perform(Action::Enter),
+            Action::Insert(c) => self.insert([c]),
+            Action::Paste(text) => {
+                for (i, line) in text.split('\n').enumerate() {
+                    if i > 0 {
+                        self.split();
+                    }
+                    self.insert(line.trim_end_matches('\r').chars());
+                }
+            }
+            Action::Enter => {
+                self.delete_selection();
+
+                let block = &mut self.blocks[self.cursor.block];
+                if block.kind.is_list() && block.is_empty() {
+                    block.kind = BlockKind::Paragraph;
+                } else {
+                    self.split();
+                }
+            }
+            Action::Backspace => {
+                if self.delete_selection() {
+                    return;
+                }
+
+                let Position { block, offset } = self.cursor;
+                if offset > 0 {
+                    self.blocks[block].chars.remove(offset - 1);
+                    self.cursor.offset -= 1;
+                } else if self.blocks[block].kind != BlockKind::Paragraph {
+                    self.blocks[block].kind = BlockKind::Paragraph;
+                } else if block > 0 {
+                    self.merge(block - 1);
+                }
+            }
+            Action::Delete => {
+                if self.delete_selection() {
+                    return;
+                }
+
+                let Position { block, offset } = self.cursor;
+                if offset < self.blocks[block].len() {
+                    self.blocks[block].chars.remove(offset);
+                } else if block + 1 < self.blocks.len() {
+                    self.merge(block);
+                }
+            }
+            Action::ToggleBold => self.toggle(|format| &mut format.bold),
+            Action::ToggleItalic => self.toggle(|format| &mut format.italic),
+            Action::ToggleUnderline => self.toggle(|format| &mut format.underline),
+            Action::SetBlockKind(kind) => {
+                let (start, end) = self.selection().unwrap_or((self.cursor, self.cursor));
+                let blocks = &mut self.blocks[start.block..=end.block];
+
+                let kind = if blocks.iter().all(|block| block.kind == kind) {
+                    BlockKind::Paragraph
+                } else {
+                    kind
+                };
+
+                for block in blocks {
+                    block.kind = kind;
+                }
+            }
+        }
+    }
+
+    /// Returns the position at the end of the document.
+    fn end(&self) -> Position {
+        let block = self.blocks.len() - 1;
+
+        Position {
+            block,
+            offset: self.blocks[block].len(),
+        }
+    }
+
+    /// Returns the closest valid position.
+    fn clamp(&self, position: Position) -> Position {
+        let block = position.block.min(self.blocks.len() - 1);
+
+        Position {
+            block,
+            offset: position.offset.min(self.blocks[block].len()),
+        }
+    }
+
+    /// Returns the position of the cursor after the given motion.
+    fn moved(&self, motion: Motion) -> Position {
+        let Position { block, offset } = self.cursor;
+        let chars = self.blocks[block].chars();
+        let is_space = |i: usize| chars[i].0.is_whitespace();
+
+        match motion {
+            Motion::Left | Motion::WordLeft if offset == 0 => match block {
+                0 => self.cursor,
+                _ => Position {
+                    block: block - 1,
+                    offset: self.blocks[block - 1].len(),
+                },
+            },
+            Motion::Right | Motion::WordRight if offset == chars.len() => {
+                if block + 1 < self.blocks.len() {
+                    Position {
+                        block: block + 1,
+                        offset: 0,
+                    }
+                } else {
+                    self.cursor
+                }
+            }
+            Motion::Left => Position {
+                block,
+                offset: offset - 1,
+            },
+            Motion::Right => Position {
+                block,
+                offset: offset + 1,
+            },
+            Motion::WordLeft => {
+                let mut offset = offset;
+                while offset > 0 && is_space(offset - 1) {
+                    offset -= 1;
+                }
+                while offset > 0 && !is_space(offset - 1) {
+                    offset -= 1;
+                }
+
+                Position { block, offset }
+            }
+            Motion::WordRight => {
+                let mut offset = offset;
+                while offset < chars.len() && is_space(offset) {
+                    offset += 1;
+                }
+                while offset < chars.len() && !is_space(offset) {
+                    offset += 1;
+                }
+
+                Position { block, offset }
+            }
+            Motion::BlockStart => Position { block, offset: 0 },
+            Motion::BlockEnd => Position {
+                block,
+                offset: chars.len(),
+            },
+            Motion::DocumentStart => Position::default(),
+            Motion::DocumentEnd => self.end(),
+        }
+    }
+
+    /// Returns the selected characters between `start` and `end`.
+    fn selected_chars(
+        &self,
+        start: Position,
+        end: Position,
+    ) -> impl Iterator<Item = (char, Format)> + '_ {
+        (start.block..=end.block).flat_map(move |i| {
+            let chars = self.blocks[i].chars();
+            let from = if i == start.block { start.offset } else { 0 };
+            let to = if i == end.block {
+                end.offset
+            } else {
+                chars.len()
+            };
+
+            chars[from..to].iter().copied()
+        })
+    }
+
+    /// Returns the format of the next typed characters.
+    fn typing_format(&self) -> Format {
+        let Position { block, offset } = self.cursor;
+        let chars = self.blocks[block].chars();
+
+        self.pending
+            .or_else(|| offset.checked_sub(1).map(|i| chars[i].1))
+            .or_else(|| chars.first().map(|(_, format)| *format))
+            .unwrap_or_default()
+    }
+
+    /// Deletes the selection, returning whether there was one.
+    fn delete_selection(&mut self) -> bool {
+        let Some((start, end)) = self.selection() else {
+            self.anchor = None;
+            return false;
+        };
+
+        if start.block == end.block {
+            self.blocks[start.block]
+                .chars
+                .drain(start.offset..end.offset);
+        } else {
+            let tail = self.blocks[end.block].chars.split_off(end.offset);
+            let first = &mut self.blocks[start.block].chars;
+            first.truncate(start.offset);
+            first.extend(tail);
+            self.blocks.drain(start.block + 1..=end.block);
+        }
+
+        self.cursor = start;
+        self.anchor = None;
+        true
+    }
+
+    /// Replaces the selection with the given characters in the typing format.
+    fn insert(&mut self, chars: impl IntoIterator<Item = char>) {
+        let format = self.typing_format();
+        self.delete_selection();
+
+        let Position { block, offset } = self.cursor;
+        let chars: Vec<_> = chars.into_iter().map(|c| (c, format)).collect();
+        let count = chars.len();
+
+        self.blocks[block].chars.splice(offset..offset, chars);
+        self.cursor.offset += count;
+    }
+
+    /// Splits the block of the cursor at the cursor.
+    fn split(&mut self) {
+        let Position { block, offset } = self.cursor;
+        let kind = self.blocks[block].kind;
+
+        if offset == 0 {
+            let kind = if kind.is_list() {
+                kind
+            } else {
+                BlockKind::Paragraph
+            };
+            self.blocks.insert(
+                block,
+                Block {
+                    kind,
+                    chars: Vec::new(),
+                },
+            );
+        } else {
+            let chars = self.blocks[block].chars.split_off(offset);
+            let kind = match kind {
+                BlockKind::Heading(_) => BlockKind::Paragraph,
+                kind => kind,
+            };
+            self.blocks.insert(block + 1, Block { kind, chars });
+        }
+
+        self.cursor = Position {
+            block: block + 1,
+            offset: 0,
+        };
+    }
+
+    /// Merges the block at `index` with the next one, moving the cursor at the junction.
+    fn merge(&mut self, index: usize) {
+        let next = self.blocks.remove(index + 1);
+        let block = &mut self.blocks[index];
+
+        self.cursor = Position {
+            block: index,
+            offset: block.len(),
+        };
+        block.chars.extend(next.chars);
+    }
+
+    /// Toggles a style on the selection, or for the next typed characters.
+    fn toggle(&mut self, style: fn(&mut Format) -> &mut bool) {
+        let Some((start, end)) = self.selection() else {
+            let mut format = self.typing_format();
+            *style(&mut format) ^= true;
+            self.pending = Some(format);
+            return;
+        };
+
+        let mut current = self.format();
+        let value = !*style(&mut current);
+
+        for i in start.block..=end.block {
+            let chars = &mut self.blocks[i].chars;
+            let from = if i == start.block { start.offset } else { 0 };
+            let to = if i == end.block {
+                end.offset
+            } else {
+                chars.len()
+            };
+
+            for (_, format) in &mut chars[from..to] {
+                *style(format) = value;
+            }
+        }
+    }
+}
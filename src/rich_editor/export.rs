@@ -0,0 +1,165 @@
+use super::{Block, BlockKind, Content, Span};
+
+impl Content {
+    /// Exports the document as Markdown.
+    ///
+    /// Markdown has no underline, so underlined text is wrapped in `<u>` tags, which most
+    /// renderers accept.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        let mut previous: Option<&Block> = None;
+        let mut number = 0;
+
+        for block in self.blocks() {
+            if let Some(previous) = previous {
+                let same_list = block.kind().is_list() && previous.kind() == block.kind();
+                markdown.push_str(if same_list { "\n" } else { "\n\n" });
+            }
+
+            number = match block.kind() {
+                BlockKind::Numbered => number + 1,
+                _ => 0,
+            };
+
+            match block.kind() {
+                BlockKind::Paragraph => {}
+                BlockKind::Heading(level) => {
+                    markdown.push_str(&"#".repeat(level.clamp(1, 6).into()));
+                    markdown.push(' ');
+                }
+                BlockKind::Bullet => markdown.push_str("- "),
+                BlockKind::Numbered => markdown.push_str(&format!("{number}. ")),
+            }
+
+            for span in block.spans() {
+                push_markdown_span(&mut markdown, &span);
+            }
+
+            previous = Some(block);
+        }
+
+        markdown
+    }
+
+    /// Exports the document as an HTML fragment.
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        let mut list: Option<BlockKind> = None;
+
+        for block in self.blocks() {
+            let kind = block.kind();
+
+            if list.is_some_and(|list| list != kind) {
+                html.push_str(list_tags(list).1);
+                list = None;
+            }
+            if kind.is_list() && list.is_none() {
+                html.push_str(list_tags(Some(kind)).0);
+                list = Some(kind);
+            }
+
+            let tag = match kind {
+                BlockKind::Paragraph => "p".to_owned(),
+                BlockKind::Heading(level) => format!("h{}", level.clamp(1, 6)),
+                BlockKind::Bullet | BlockKind::Numbered => "li".to_owned(),
+            };
+
+            html.push_str(&format!("<{tag}>"));
+            for span in block.spans() {
+                push_html_span(&mut html, &span);
+            }
+            html.push_str(&format!("</{tag}>\n"));
+        }
+
+        html.push_str(list_tags(list).1);
+        html
+    }
+}
+
+/// Returns the opening and closing tags of a list of the given kind.
+fn list_tags(list: Option<BlockKind>) -> (&'static str, &'static str) {
+    match list {
+        Some(BlockKind::Bullet) => ("<ul>\n", "</ul>\n"),
+        Some(BlockKind::Numbered) => ("<ol>\n", "</ol>\n"),
+        _ => ("", ""),
+    }
+}
+
+/// Pushes a span as Markdown, keeping the surrounding whitespace outside of the markers.
+fn push_markdown_span(markdown: &mut String, span: &Span) {
+    let trimmed = span.text.trim();
+    let format = span.format;
+
+    if trimmed.is_empty() || !(format.bold || format.italic || format.underline) {
+        push_escaped_markdown(markdown, &span.text);
+        return;
+    }
+
+    let leading = &span.text[..span.text.len() - span.text.trim_start().len()];
+    let trailing = &span.text[span.text.trim_end().len()..];
+
+    markdown.push_str(leading);
+    if format.underline {
+        markdown.push_str("<u>");
+    }
+    if format.bold {
+        markdown.push_str("**");
+    }
+    if format.italic {
+        markdown.push('_');
+    }
+    push_escaped_markdown(markdown, trimmed);
+    if format.italic {
+        markdown.push('_');
+    }
+    if format.bold {
+        markdown.push_str("**");
+    }
+    if format.underline {
+        markdown.push_str("</u>");
+    }
+    markdown.push_str(trailing);
+}
+
+/// Pushes text escaping the characters that Markdown would interpret.
+fn push_escaped_markdown(markdown: &mut String, text: &str) {
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '`' | '#' | '[' | ']' | '<' | '>') {
+            markdown.push('\\');
+        }
+        markdown.push(c);
+    }
+}
+
+/// Pushes a span as HTML.
+fn push_html_span(html: &mut String, span: &Span) {
+    let format = span.format;
+
+    if format.underline {
+        html.push_str("<u>");
+    }
+    if format.bold {
+        html.push_str("<strong>");
+    }
+    if format.italic {
+        html.push_str("<em>");
+    }
+    for c in span.text.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            c => html.push(c),
+        }
+    }
+    if format.italic {
+        html.push_str("</em>");
+    }
+    if format.bold {
+        html.push_str("</strong>");
+    }
+    if format.underline {
+        html.push_str("</u>");
+    }
+}
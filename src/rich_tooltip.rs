@@ -0,0 +1,602 @@
+//! A tooltip with arbitrary content, a show delay and an arrow pointing at its target.
+//!
+//! The tooltip is a layer following the rules of [`layers`](crate::layers): it is above the
+//! overlays of its target, and it is dismissed by any click and by Escape.
+
+use std::time::Duration;
+
+use iced::{
+    Border, Color, Element, Length, Padding, Pixels, Point, Rectangle, Shadow, Size, Theme, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{Operation, Tree, tree},
+    },
+    event,
+    time::Instant,
+    window,
+};
+
+use crate::layers::{self, Dismiss};
+
+/// Where the tooltip of a [`RichTooltip`] is placed.
+///
+/// If there is not enough room on the chosen side, the tooltip is placed on the opposite side.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Placement {
+    /// Above the target.
+    #[default]
+    Top,
+    /// Below the target.
+    Bottom,
+    /// On the left of the target.
+    Left,
+    /// On the right of the target.
+    Right,
+    /// Below the cursor, following it.
+    FollowCursor,
+}
+
+/// A side of the target, where the tooltip is actually placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Side {
+    /// Returns the opposite side.
+    fn flip(self) -> Self {
+        match self {
+            Side::Top => Side::Bottom,
+            Side::Bottom => Side::Top,
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+}
+
+/// The appearance of a [`RichTooltip`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background color of the tooltip and of its arrow.
+    pub background: Color,
+    /// The color of the text of the tooltip.
+    pub text_color: Color,
+    /// The border of the tooltip.
+    pub border: Border,
+    /// The shadow of the tooltip.
+    pub shadow: Shadow,
+}
+
+/// The theme catalog of a [`RichTooltip`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`RichTooltip`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`RichTooltip`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.strong.color,
+        text_color: palette.background.strong.text,
+        border: Border {
+            radius: 4.into(),
+            ..Border::default()
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0., 1.),
+            blur_radius: 4.,
+        },
+    }
+}
+
+/// The state of a [`RichTooltip`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    /// The time at which the cursor entered the target, if it is over it.
+    hovered_since: Option<Instant>,
+    /// Whether the tooltip is shown.
+    visible: bool,
+    /// The last position of the cursor.
+    cursor: Point,
+}
+
+/// An element showing a tooltip when it is hovered for some time, created with [`rich_tooltip`].
+///
+/// Contrary to [`iced::widget::Tooltip`], the tooltip points at the element with an arrow,
+/// and is flipped to the other side of the element when it does not fit in the window.
+pub struct RichTooltip<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    tooltip: Element<'a, Message, Theme, Renderer>,
+    placement: Placement,
+    delay: Duration,
+    gap: f32,
+    padding: Padding,
+    arrow: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> RichTooltip<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`RichTooltip`] showing `tooltip` when `content` is hovered.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        tooltip: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            tooltip: tooltip.into(),
+            placement: Placement::default(),
+            delay: Duration::from_millis(500),
+            gap: 4.,
+            padding: Padding::new(6.),
+            arrow: 6.,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets where the tooltip is placed.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the time the element has to be hovered before the tooltip is shown.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets the distance between the target and the tip of the arrow.
+    pub fn gap(mut self, gap: impl Into<Pixels>) -> Self {
+        self.gap = gap.into().0;
+        self
+    }
+
+    /// Sets the padding of the tooltip.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the size of the arrow. A size of 0 hides it.
+    pub fn arrow(mut self, size: impl Into<Pixels>) -> Self {
+        self.arrow = size.into().0;
+        self
+    }
+
+    /// Sets the style of the [`RichTooltip`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`RichTooltip`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for RichTooltip<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content), Tree::new(&self.tooltip)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content, &self.tooltip]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        // The tooltip does not take clicks, so every click is outside of it.
+        if Dismiss::ANY.applies(&event, cursor, &[]) {
+            let visible = state.visible;
+            state.hovered_since = None;
+            state.visible = false;
+
+            if visible && Dismiss::status(&event) == event::Status::Captured {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+                return event::Status::Captured;
+            }
+        }
+
+        match event {
+            iced::Event::Mouse(mouse::Event::CursorMoved { .. })
+            | iced::Event::Mouse(mouse::Event::CursorLeft) => {
+                if let Some(position) = cursor.position_over(layout.bounds()) {
+                    if state.hovered_since.is_none() {
+                        let now = Instant::now();
+                        state.hovered_since = Some(now);
+                        shell.request_redraw(window::RedrawRequest::At(now + self.delay));
+                    }
+                    if state.visible && self.placement == Placement::FollowCursor {
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                    state.cursor = position;
+                } else {
+                    if state.visible {
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                    state.hovered_since = None;
+                    state.visible = false;
+                }
+            }
+            iced::Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(since) = state.hovered_since
+                    && !state.visible
+                    && now >= since + self.delay
+                {
+                    state.visible = true;
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+            _ => {}
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = *tree.state.downcast_ref::<State>();
+        let (content_tree, tooltip_tree) = tree.children.split_at_mut(1);
+
+        let content = self.content.as_widget_mut().overlay(
+            &mut content_tree[0],
+            layout,
+            renderer,
+            translation,
+        );
+
+        let target = if self.placement == Placement::FollowCursor {
+            Rectangle::new(state.cursor, Size::ZERO) + translation
+        } else {
+            layout.bounds() + translation
+        };
+
+        let tooltip = state.visible.then(|| {
+            overlay::Element::new(Box::new(Overlay {
+                tooltip: &self.tooltip,
+                tree: &mut tooltip_tree[0],
+                target,
+                side: match self.placement {
+                    Placement::Top => Side::Top,
+                    Placement::Bottom | Placement::FollowCursor => Side::Bottom,
+                    Placement::Left => Side::Left,
+                    Placement::Right => Side::Right,
+                },
+                gap: if self.placement == Placement::FollowCursor {
+                    self.gap.max(12.)
+                } else {
+                    self.gap
+                },
+                padding: self.padding,
+                arrow: self.arrow,
+                class: &self.class,
+            }))
+        });
+
+        layers::group([content, tooltip])
+    }
+}
+
+/// The overlay displaying the tooltip of a [`RichTooltip`].
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    tooltip: &'b Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    target: Rectangle,
+    /// The side of the target where the tooltip is placed, updated by the layout when it is flipped.
+    side: Side,
+    gap: f32,
+    padding: Padding,
+    arrow: f32,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        let limits = Limits::new(Size::ZERO, bounds).shrink(self.padding);
+        let content = self
+            .tooltip
+            .as_widget()
+            .layout(self.tree, renderer, &limits)
+            .move_to(Point::new(self.padding.left, self.padding.top));
+
+        let size = content.size().expand(self.padding);
+        let offset = self.gap + self.arrow;
+        let target = self.target;
+
+        let position = |side: Side| match side {
+            Side::Top => Point::new(
+                target.center_x() - size.width / 2.,
+                target.y - offset - size.height,
+            ),
+            Side::Bottom => Point::new(
+                target.center_x() - size.width / 2.,
+                target.y + target.height + offset,
+            ),
+            Side::Left => Point::new(
+                target.x - offset - size.width,
+                target.center_y() - size.height / 2.,
+            ),
+            Side::Right => Point::new(
+                target.x + target.width + offset,
+                target.center_y() - size.height / 2.,
+            ),
+        };
+
+        let fits = |side: Side| {
+            let position = position(side);
+            match side {
+                Side::Top | Side::Bottom => {
+                    position.y >= 0. && position.y + size.height <= bounds.height
+                }
+                Side::Left | Side::Right => {
+                    position.x >= 0. && position.x + size.width <= bounds.width
+                }
+            }
+        };
+
+        if !fits(self.side) && fits(self.side.flip()) {
+            self.side = self.side.flip();
+        }
+
+        let position = position(self.side);
+        let position = Point::new(
+            position.x.min(bounds.width - size.width).max(0.),
+            position.y.min(bounds.height - size.height).max(0.),
+        );
+
+        Node::with_children(size, vec![content]).move_to(position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.style(self.class);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: appearance.border,
+                shadow: appearance.shadow,
+            },
+            appearance.background,
+        );
+
+        // The arrow is drawn as a stack of thin quads, getting narrower towards its tip.
+        let steps = self.arrow.ceil() as usize;
+        for step in 0..steps {
+            let distance = step as f32;
+            let half = self.arrow - distance;
+            let (cx, cy) = (
+                self.target
+                    .center_x()
+                    .clamp(bounds.x + self.arrow, bounds.x + bounds.width - self.arrow),
+                self.target
+                    .center_y()
+                    .clamp(bounds.y + self.arrow, bounds.y + bounds.height - self.arrow),
+            );
+
+            let quad = match self.side {
+                Side::Top => Rectangle::new(
+                    Point::new(cx - half, bounds.y + bounds.height + distance),
+                    Size::new(2. * half, 1.),
+                ),
+                Side::Bottom => Rectangle::new(
+                    Point::new(cx - half, bounds.y - distance - 1.),
+                    Size::new(2. * half, 1.),
+                ),
+                Side::Left => Rectangle::new(
+                    Point::new(bounds.x + bounds.width + distance, cy - half),
+                    Size::new(1., 2. * half),
+                ),
+                Side::Right => Rectangle::new(
+                    Point::new(bounds.x - distance - 1., cy - half),
+                    Size::new(1., 2. * half),
+                ),
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: quad,
+                    ..renderer::Quad::default()
+                },
+                appearance.background,
+            );
+        }
+
+        self.tooltip.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: appearance.text_color,
+            },
+            layout.children().next().unwrap(),
+            cursor,
+            &bounds,
+        );
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _renderer: &Renderer, _cursor_position: Point) -> bool {
+        false
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<RichTooltip<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(value: RichTooltip<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`RichTooltip`] showing `tooltip` when `content` is hovered.
+pub fn rich_tooltip<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    tooltip: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> RichTooltip<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    RichTooltip::new(content, tooltip)
+}
@@ -0,0 +1,766 @@
+//! A week view of events on a time grid.
+//!
+//! The [`Scheduler`] draws a column for each day of the week and a row for each hour. The
+//! [`Event`]s are placed by their day, start and duration, and overlapping events share the width
+//! of their day side by side.
+//!
+//! Events are moved by dragging them, and resized by dragging their bottom edge. The scheduler
+//! does not change them itself: it produces a message with the moved event, which the application
+//! applies to its data. Times are in minutes from midnight, and snap to a configurable step. They
+//! are displayed with the [`i18n`](crate::i18n) format.
+//!
+//! The scheduler is as tall as its hours, so it is meant to be put in a
+//! [`Scrollable`](iced::widget::Scrollable).
+
+use std::ops::Range;
+
+use iced::{
+    Background, Border, Color, Element, Length, Padding, Pixels, Point, Rectangle, Size, Theme,
+    Vector,
+    advanced::{
+        Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, renderer,
+        text::{self, LineHeight, Shaping, Text, Wrapping},
+        widget::{Tree, tree},
+    },
+    alignment, event, touch,
+};
+
+use crate::i18n;
+
+/// The height of the area at the bottom of an event that resizes it.
+const RESIZE_HANDLE: f32 = 6.;
+/// The minimal height of an event.
+const MIN_EVENT_HEIGHT: f32 = 14.;
+
+/// An event of a [`Scheduler`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Event {
+    /// The title of the event.
+    pub title: String,
+    /// The day of the event, from `0` for the first column.
+    pub day: usize,
+    /// The start of the event, in minutes from midnight.
+    pub start: u32,
+    /// The duration of the event, in minutes.
+    pub duration: u32,
+    /// The color of the event, or `None` for the one of the [`Style`].
+    #[cfg_attr(feature = "serde", serde(with = "crate::helpers::serde_iced"))]
+    pub color: Option<Color>,
+}
+
+impl Event {
+    /// Creates a new [`Event`].
+    pub fn new(title: impl Into<String>, day: usize, start: u32, duration: u32) -> Self {
+        Self {
+            title: title.into(),
+            day,
+            start,
+            duration,
+            color: None,
+        }
+    }
+
+    /// Sets the color of the [`Event`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Returns the end of the event, in minutes from midnight.
+    pub fn end(&self) -> u32 {
+        self.start + self.duration
+    }
+}
+
+/// The appearance of a [`Scheduler`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the scheduler.
+    pub background: Background,
+    /// The color of the grid lines.
+    pub grid: Color,
+    /// The color of the day and hour labels.
+    pub text: Color,
+    /// The default color of the events.
+    pub event: Color,
+    /// The color of the titles of the events.
+    pub event_text: Color,
+    /// The radius of the events.
+    pub event_radius: f32,
+    /// The color of the current time indicator.
+    pub now: Color,
+}
+
+/// The theme catalog of a [`Scheduler`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Scheduler`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Scheduler`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        grid: palette.background.weak.color,
+        text: palette.background.base.text,
+        event: palette.primary.base.color,
+        event_text: palette.primary.base.text,
+        event_radius: 4.,
+        now: palette.danger.base.color,
+    }
+}
+
+/// A drag of an event in progress.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Drag {
+    /// The index of the dragged event.
+    index: usize,
+    /// Whether the event is resized instead of moved.
+    resize: bool,
+    /// The offset between the cursor and the top left corner of the event when the drag started.
+    grab: Vector,
+    /// The position where the drag started.
+    origin: Point,
+    /// The day, start and duration of the event being dragged.
+    preview: (usize, u32, u32),
+}
+
+/// The state of a [`Scheduler`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    drag: Option<Drag>,
+}
+
+/// A week time grid with events, created with [`scheduler`].
+pub struct Scheduler<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    events: &'a [Event],
+    days: Vec<String>,
+    hours: Range<u32>,
+    hour_height: f32,
+    snap: u32,
+    now: Option<(usize, u32)>,
+    on_change: Option<Box<dyn Fn(usize, Event) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    text_size: Pixels,
+    font: Option<Renderer::Font>,
+    width: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Scheduler<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The width of the column of the hours.
+    const GUTTER: f32 = 48.;
+
+    /// Creates a new [`Scheduler`] of the given events.
+    pub fn new(events: &'a [Event]) -> Self {
+        Self {
+            events,
+            days: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"]
+                .map(String::from)
+                .into(),
+            hours: 0..24,
+            hour_height: 48.,
+            snap: 15,
+            now: None,
+            on_change: None,
+            on_select: None,
+            text_size: Pixels(12.),
+            font: None,
+            width: Length::Fill,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the labels of the days, one for each column. Defaults to the days of the week, from
+    /// Monday.
+    pub fn days(mut self, days: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.days = days.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the hours shown by the [`Scheduler`]. Defaults to the whole day.
+    pub fn hours(mut self, hours: Range<u32>) -> Self {
+        self.hours = hours.start.min(23)..hours.end.clamp(hours.start.min(23) + 1, 24);
+        self
+    }
+
+    /// Sets the height of an hour.
+    pub fn hour_height(mut self, hour_height: impl Into<Pixels>) -> Self {
+        self.hour_height = hour_height.into().0;
+        self
+    }
+
+    /// Sets the step, in minutes, to which the dragged events snap. Defaults to 15 minutes.
+    pub fn snap(mut self, snap: u32) -> Self {
+        self.snap = snap.max(1);
+        self
+    }
+
+    /// Sets the current time, shown by a line: the day and the minutes from midnight.
+    pub fn now(mut self, now: impl Into<Option<(usize, u32)>>) -> Self {
+        self.now = now.into();
+        self
+    }
+
+    /// Sets the message produced when an event is moved or resized, with its index and the event
+    /// with its new day, start and duration.
+    pub fn on_change(mut self, on_change: impl Fn(usize, Event) -> Message + 'a) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Sets the message produced when an event is clicked, with its index.
+    pub fn on_select(mut self, on_select: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the text size of the [`Scheduler`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = text_size.into();
+        self
+    }
+
+    /// Sets the font of the [`Scheduler`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the width of the [`Scheduler`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the style of the [`Scheduler`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Scheduler`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the height of the header with the days.
+    fn header(&self) -> f32 {
+        self.text_size.0 * 2.
+    }
+
+    /// Returns the width of a day column.
+    fn column_width(&self, bounds: Rectangle) -> f32 {
+        (bounds.width - Self::GUTTER).max(0.) / self.days.len().max(1) as f32
+    }
+
+    /// Returns the vertical position of a time.
+    fn y(&self, bounds: Rectangle, minutes: u32) -> f32 {
+        let minutes = minutes as f32 - (self.hours.start * 60) as f32;
+        bounds.y + self.header() + minutes / 60. * self.hour_height
+    }
+
+    /// Returns the time at a vertical position, snapped.
+    fn minutes_at(&self, bounds: Rectangle, y: f32) -> u32 {
+        let minutes = (y - bounds.y - self.header()) / self.hour_height * 60.
+            + (self.hours.start * 60) as f32;
+        let snapped = (minutes / self.snap as f32).round() * self.snap as f32;
+
+        (snapped.max(0.) as u32).clamp(self.hours.start * 60, self.hours.end * 60)
+    }
+
+    /// Returns the day at a horizontal position.
+    fn day_at(&self, bounds: Rectangle, x: f32) -> usize {
+        let day = (x - bounds.x - Self::GUTTER) / self.column_width(bounds).max(1.);
+        (day.max(0.) as usize).min(self.days.len().saturating_sub(1))
+    }
+
+    /// Returns the bounds of an event with the given day, start and duration, in the given lane
+    /// among the lanes of its group of overlapping events.
+    fn event_bounds(
+        &self,
+        bounds: Rectangle,
+        (day, start, duration): (usize, u32, u32),
+        (lane, lanes): (usize, usize),
+    ) -> Rectangle {
+        let column = self.column_width(bounds);
+        let width = column / lanes.max(1) as f32;
+        let top = self.y(bounds, start);
+
+        Rectangle {
+            x: bounds.x + Self::GUTTER + column * day as f32 + width * lane as f32 + 1.,
+            y: top + 1.,
+            width: (width - 2.).max(0.),
+            height: (self.y(bounds, start + duration) - top - 2.).max(MIN_EVENT_HEIGHT),
+        }
+    }
+
+    /// Returns the lane of each event and the number of lanes of its group of overlapping events.
+    fn lanes(&self) -> Vec<(usize, usize)> {
+        let mut lanes = vec![(0, 1); self.events.len()];
+
+        let mut order: Vec<usize> = (0..self.events.len()).collect();
+        order.sort_by_key(|&i| {
+            (
+                self.events[i].day,
+                self.events[i].start,
+                self.events[i].end(),
+            )
+        });
+
+        // The events of the current group of overlapping events, and the end of each of its lanes.
+        let mut group: Vec<usize> = Vec::new();
+        let mut ends: Vec<u32> = Vec::new();
+        let (mut group_day, mut group_end) = (usize::MAX, 0);
+
+        for i in order.into_iter().chain([usize::MAX]) {
+            let event = self.events.get(i);
+            if event.is_none_or(|event| event.day != group_day || event.start >= group_end) {
+                for &j in &group {
+                    lanes[j].1 = ends.len();
+                }
+                group.clear();
+                ends.clear();
+                group_end = 0;
+            }
+            let Some(event) = event else {
+                break;
+            };
+
+            let lane = match ends.iter().position(|end| *end <= event.start) {
+                Some(lane) => lane,
+                None => {
+                    ends.push(0);
+                    ends.len() - 1
+                }
+            };
+            ends[lane] = event.end();
+
+            lanes[i].0 = lane;
+            group.push(i);
+            group_day = event.day;
+            group_end = group_end.max(event.end());
+        }
+
+        lanes
+    }
+
+    /// Returns the dragged event, with its new position.
+    fn dragged(&self, bounds: Rectangle, drag: &Drag, position: Point) -> (usize, u32, u32) {
+        let event = &self.events[drag.index];
+        let max = self.hours.end * 60;
+
+        if drag.resize {
+            let end = self.minutes_at(bounds, position.y);
+            (
+                event.day,
+                event.start,
+                end.max(event.start + self.snap) - event.start,
+            )
+        } else {
+            let day = self.day_at(
+                bounds,
+                position.x - drag.grab.x + self.column_width(bounds) / 2.,
+            );
+            let start = self
+                .minutes_at(bounds, position.y - drag.grab.y)
+                .min(max.saturating_sub(event.duration));
+            (day, start, event.duration)
+        }
+    }
+
+    fn text(
+        &self,
+        content: String,
+        bounds: Size,
+        renderer: &Renderer,
+    ) -> Text<String, Renderer::Font> {
+        Text {
+            content,
+            bounds,
+            size: self.text_size,
+            line_height: LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: Shaping::Advanced,
+            wrapping: Wrapping::Word,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Scheduler<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let hours = (self.hours.end - self.hours.start) as f32;
+        let height = self.header() + hours * self.hour_height;
+
+        Node::new(limits.resolve(self.width, Length::Shrink, Size::new(0., height)))
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+        let style = theme.style(&self.class);
+        let column = self.column_width(bounds);
+        let header = self.header();
+        let format = i18n::format();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..Default::default()
+            },
+            style.background,
+        );
+
+        let line = |renderer: &mut Renderer, bounds: Rectangle| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..Default::default()
+                },
+                style.grid,
+            );
+        };
+
+        for hour in self.hours.clone() {
+            let y = self.y(bounds, hour * 60);
+            line(
+                renderer,
+                Rectangle::new(
+                    Point::new(bounds.x + Self::GUTTER, y),
+                    Size::new(bounds.width - Self::GUTTER, 1.),
+                ),
+            );
+            renderer.fill_text(
+                Text {
+                    wrapping: Wrapping::None,
+                    ..self.text(
+                        format.format_time(hour * 60),
+                        Size::new(Self::GUTTER, self.hour_height),
+                        renderer,
+                    )
+                },
+                Point::new(bounds.x + 4., y + 2.),
+                style.text,
+                clip,
+            );
+        }
+
+        for (day, label) in self.days.iter().enumerate() {
+            let x = bounds.x + Self::GUTTER + column * day as f32;
+            line(
+                renderer,
+                Rectangle::new(Point::new(x, bounds.y), Size::new(1., bounds.height)),
+            );
+            renderer.fill_text(
+                Text {
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    ..self.text(label.clone(), Size::new(column, header), renderer)
+                },
+                Point::new(x + column / 2., bounds.y + header / 2.),
+                style.text,
+                clip,
+            );
+        }
+
+        let lanes = self.lanes();
+        let padding = Padding::from([2, 4]);
+
+        for (index, event) in self.events.iter().enumerate() {
+            let dragged = state.drag.filter(|drag| drag.index == index);
+            let (position, lane) = match dragged {
+                Some(drag) => (drag.preview, (0, 1)),
+                None => ((event.day, event.start, event.duration), lanes[index]),
+            };
+            if position.0 >= self.days.len() {
+                continue;
+            }
+
+            let bounds = self.event_bounds(bounds, position, lane);
+            let color = event.color.unwrap_or(style.event);
+            let color = if dragged.is_some() {
+                color.scale_alpha(0.8)
+            } else {
+                color
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border::default().rounded(style.event_radius),
+                    ..Default::default()
+                },
+                color,
+            );
+
+            let Some(text_clip) = bounds.intersection(&clip) else {
+                continue;
+            };
+            let start = position.1;
+            let content = format!("{} {}", format.format_time(start), event.title);
+            renderer.fill_text(
+                self.text(content, bounds.shrink(padding).size(), renderer),
+                bounds.shrink(padding).position(),
+                style.event_text,
+                text_clip,
+            );
+        }
+
+        if let Some((day, minutes)) = self.now
+            && day < self.days.len()
+            && (self.hours.start * 60..=self.hours.end * 60).contains(&minutes)
+        {
+            let y = self.y(bounds, minutes);
+            let x = bounds.x + Self::GUTTER + column * day as f32;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle::new(Point::new(x, y - 1.), Size::new(column, 2.)),
+                    ..Default::default()
+                },
+                style.now,
+            );
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle::new(Point::new(x - 4., y - 4.), Size::new(8., 8.)),
+                    border: Border::default().rounded(4.),
+                    ..Default::default()
+                },
+                style.now,
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn iced::advanced::Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                let lanes = self.lanes();
+                let hit = self
+                    .events
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .find_map(|(index, event)| {
+                        let position_of = (event.day, event.start, event.duration);
+                        let bounds = self.event_bounds(bounds, position_of, lanes[index]);
+                        bounds.contains(position).then_some((index, bounds))
+                    });
+                let Some((index, event_bounds)) = hit else {
+                    return event::Status::Ignored;
+                };
+
+                let event = &self.events[index];
+                state.drag = Some(Drag {
+                    index,
+                    resize: position.y >= event_bounds.y + event_bounds.height - RESIZE_HANDLE,
+                    grab: position - event_bounds.position(),
+                    origin: position,
+                    preview: (event.day, event.start, event.duration),
+                });
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::CursorMoved { position })
+            | iced::Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(drag) = &mut state.drag else {
+                    return event::Status::Ignored;
+                };
+                if self.on_change.is_none() || drag.index >= self.events.len() {
+                    return event::Status::Ignored;
+                }
+
+                let preview = self.dragged(bounds, drag, position);
+                if preview != drag.preview {
+                    drag.preview = preview;
+                    shell.request_redraw(iced::window::RedrawRequest::NextFrame);
+                }
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerLifted { .. })
+            | iced::Event::Touch(touch::Event::FingerLost { .. }) => {
+                let Some(drag) = state.drag.take() else {
+                    return event::Status::Ignored;
+                };
+                let Some(event) = self.events.get(drag.index) else {
+                    return event::Status::Ignored;
+                };
+
+                let (day, start, duration) = drag.preview;
+                if (day, start, duration) != (event.day, event.start, event.duration) {
+                    if let Some(on_change) = &self.on_change {
+                        shell.publish(on_change(
+                            drag.index,
+                            Event {
+                                day,
+                                start,
+                                duration,
+                                ..event.clone()
+                            },
+                        ));
+                    }
+                } else if let Some(on_select) = &self.on_select
+                    && cursor
+                        .position()
+                        .is_some_and(|position| position.distance(drag.origin) < 4.)
+                {
+                    shell.publish(on_select(drag.index));
+                }
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        if let Some(drag) = state.drag {
+            return if drag.resize {
+                mouse::Interaction::ResizingVertically
+            } else {
+                mouse::Interaction::Grabbing
+            };
+        }
+
+        let Some(position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        let lanes = self.lanes();
+        let hovered = self.events.iter().enumerate().find_map(|(index, event)| {
+            let bounds = self.event_bounds(
+                bounds,
+                (event.day, event.start, event.duration),
+                lanes[index],
+            );
+            bounds.contains(position).then_some(bounds)
+        });
+
+        match hovered {
+            Some(_) if self.on_change.is_none() => mouse::Interaction::Pointer,
+            Some(event) if position.y >= event.y + event.height - RESIZE_HANDLE => {
+                mouse::Interaction::ResizingVertically
+            }
+            Some(_) => mouse::Interaction::Grab,
+            None => mouse::Interaction::default(),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Scheduler<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(value: Scheduler<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`Scheduler`] of the given events.
+pub fn scheduler<'a, Message, Theme, Renderer>(
+    events: &'a [Event],
+) -> Scheduler<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    Scheduler::new(events)
+}
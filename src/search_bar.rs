@@ -0,0 +1,864 @@
+//! A search field with a debounced search message and a dropdown of results.
+//!
+//! The search bar combines a text input with a leading search icon, a clear button, and a list of
+//! results displayed below it. The results are usually supplied asynchronously: the application
+//! starts a search when it receives the message of [`SearchBar::on_search`], which is only
+//! produced once the user stopped typing for a while, and then gives the results to the bar.
+//!
+//! The results are navigated with the arrow keys, and one is selected with a click or with Enter.
+//!
+//! The results are a layer following the rules of [`layers`](crate::layers): they are above the
+//! overlays of the field, and they are closed according to a [`Dismiss`] policy (see
+//! [`SearchBar::dismiss`]).
+
+use std::{rc::Rc, time::Duration};
+
+use iced::{
+    Background, Border, Color, Element, Length, Padding, Pixels, Point, Rectangle, Renderer,
+    Shadow, Size, Theme, Vector,
+    advanced::{
+        self, Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{Id, Operation, Tree, operation, tree},
+    },
+    alignment, event, keyboard,
+    time::Instant,
+    widget::{
+        button, canvas, container, row, text,
+        text_input::{self, TextInput},
+    },
+    window,
+};
+
+use crate::{
+    layers::{self, Dismiss},
+    spinner::spinner,
+};
+
+/// The appearance of a [`SearchBar`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the field.
+    pub background: Background,
+    /// The border of the field.
+    pub border: Border,
+    /// The color of the search icon.
+    pub icon_color: Color,
+    /// The background of the list of results.
+    pub menu_background: Background,
+    /// The border of the list of results.
+    pub menu_border: Border,
+    /// The shadow of the list of results.
+    pub menu_shadow: Shadow,
+    /// The color of the text of the results.
+    pub text_color: Color,
+    /// The background of the highlighted result.
+    pub highlighted_background: Background,
+    /// The color of the text of the highlighted result.
+    pub highlighted_text_color: Color,
+}
+
+/// The theme catalog of a [`SearchBar`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`SearchBar`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`SearchBar`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+    let border = Border {
+        color: palette.background.strong.color,
+        width: 1.,
+        radius: 4.into(),
+    };
+
+    Style {
+        background: palette.background.base.color.into(),
+        border,
+        icon_color: palette.background.strong.text,
+        menu_background: palette.background.base.color.into(),
+        menu_border: border,
+        menu_shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0., 2.),
+            blur_radius: 8.,
+        },
+        text_color: palette.background.base.text,
+        highlighted_background: palette.primary.weak.color.into(),
+        highlighted_text_color: palette.primary.weak.text,
+    }
+}
+
+/// A search field with a debounced search message and a dropdown of results,
+/// created with [`search_bar`].
+///
+/// The results are only displayed: events are not forwarded to their elements.
+pub struct SearchBar<'a, Message> {
+    query: String,
+    placeholder: String,
+    on_input: Box<dyn Fn(String) -> Message + 'a>,
+    on_search: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    results: Vec<Element<'a, Message>>,
+    debounce: Duration,
+    loading: bool,
+    id: Option<text_input::Id>,
+    width: Length,
+    text_size: Option<Pixels>,
+    menu_height: f32,
+    dismiss: Dismiss,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Message> SearchBar<'a, Message> {
+    /// Creates a new [`SearchBar`] with the given query, producing the message of `on_input` when
+    /// it is edited.
+    pub fn new(placeholder: &str, query: &str, on_input: impl Fn(String) -> Message + 'a) -> Self {
+        Self {
+            query: query.to_owned(),
+            placeholder: placeholder.to_owned(),
+            on_input: Box::new(on_input),
+            on_search: None,
+            on_select: None,
+            results: Vec::new(),
+            debounce: Duration::from_millis(300),
+            loading: false,
+            id: None,
+            width: Length::Fill,
+            text_size: None,
+            menu_height: 300.,
+            dismiss: Dismiss::default(),
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets the message produced with the query once the user stopped typing for the
+    /// [debounce delay](Self::debounce).
+    pub fn on_search(mut self, on_search: impl Fn(String) -> Message + 'a) -> Self {
+        self.on_search = Some(Box::new(on_search));
+        self
+    }
+
+    /// Sets the message produced with the index of a result when it is selected.
+    pub fn on_select(mut self, on_select: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the results displayed below the bar.
+    pub fn results(
+        mut self,
+        results: impl IntoIterator<Item = impl Into<Element<'a, Message>>>,
+    ) -> Self {
+        self.results = results.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the delay without typing after which the search message is produced.
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Sets whether the results are being loaded, which shows a spinner in the bar.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Sets the [`Id`](text_input::Id) of the text input of the [`SearchBar`].
+    pub fn id(mut self, id: impl Into<text_input::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the width of the [`SearchBar`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the text size of the [`SearchBar`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the maximum height of the list of results.
+    pub fn menu_height(mut self, menu_height: impl Into<Pixels>) -> Self {
+        self.menu_height = menu_height.into().0;
+        self
+    }
+
+    /// Sets how the list of results is closed, [`Dismiss::ANY`] by default.
+    ///
+    /// A click outside also unfocuses the field, so the field stays focused when the click is not
+    /// allowed to close the list.
+    pub fn dismiss(mut self, dismiss: Dismiss) -> Self {
+        self.dismiss = dismiss;
+        self
+    }
+
+    /// Sets the style of the [`SearchBar`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self {
+        self.class = Box::new(style);
+        self
+    }
+
+    /// Sets the style class of the [`SearchBar`].
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// The magnifying glass displayed at the start of a [`SearchBar`].
+struct SearchIcon<'a> {
+    class: Rc<<Theme as Catalog>::Class<'a>>,
+}
+
+impl<Message> canvas::Program<Message> for SearchIcon<'_> {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &(),
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        let size = bounds.width.min(bounds.height);
+        let radius = size * 0.3;
+        let center = Point::new(size * 0.42, size * 0.42);
+        let stroke = canvas::Stroke::default()
+            .with_color(Catalog::style(theme, &self.class).icon_color)
+            .with_width((size * 0.12).max(1.))
+            .with_line_cap(canvas::LineCap::Round);
+
+        frame.stroke(&canvas::Path::circle(center, radius), stroke);
+
+        let start = center + Vector::new(radius, radius) * std::f32::consts::FRAC_1_SQRT_2;
+        frame.stroke(
+            &canvas::Path::line(start, Point::new(size * 0.9, size * 0.9)),
+            stroke,
+        );
+
+        vec![frame.into_geometry()]
+    }
+}
+
+impl<'a, Message: Clone + 'a> From<SearchBar<'a, Message>> for Element<'a, Message> {
+    fn from(value: SearchBar<'a, Message>) -> Self {
+        let text_size = value.text_size.unwrap_or(Pixels(16.));
+        let icon_size = Length::Fixed(text_size.0);
+
+        let clear = (value.on_input)(String::new());
+        let class = Rc::new(value.class);
+
+        let mut input = TextInput::new(&value.placeholder, &value.query)
+            .on_input(value.on_input)
+            .size(text_size)
+            .padding(Padding::from([6, 0]))
+            .style(|theme, status| text_input::Style {
+                background: Color::TRANSPARENT.into(),
+                border: Border::default(),
+                ..text_input::default(theme, status)
+            });
+        if let Some(id) = value.id {
+            input = input.id(id);
+        }
+
+        let trailing: Option<Element<'a, Message>> = if value.loading {
+            Some(spinner().size(text_size.0).into())
+        } else if !value.query.is_empty() {
+            Some(
+                button(text("✕").size(text_size.0 * 0.8))
+                    .on_press(clear)
+                    .padding(Padding::from([0, 4]))
+                    .style(button::text)
+                    .into(),
+            )
+        } else {
+            None
+        };
+
+        let field = container(
+            row![
+                canvas(SearchIcon {
+                    class: class.clone()
+                })
+                .width(icon_size)
+                .height(icon_size),
+                input
+            ]
+            .push_maybe(trailing)
+            .spacing(6)
+            .align_y(alignment::Vertical::Center),
+        )
+        .padding(Padding::from([0, 8]))
+        .width(value.width)
+        .style({
+            let class = class.clone();
+
+            move |theme: &Theme| {
+                let style = Catalog::style(theme, &class);
+
+                container::Style {
+                    background: Some(style.background),
+                    border: style.border,
+                    ..container::Style::default()
+                }
+            }
+        });
+
+        Element::new(Watcher {
+            field: field.into(),
+            query: value.query,
+            on_search: value.on_search,
+            on_select: value.on_select,
+            results: value.results,
+            debounce: value.debounce,
+            menu_height: value.menu_height,
+            dismiss: value.dismiss,
+            class,
+        })
+    }
+}
+
+/// Creates a new [`SearchBar`] with the given query, producing the message of `on_input` when it
+/// is edited.
+pub fn search_bar<'a, Message>(
+    placeholder: &str,
+    query: &str,
+    on_input: impl Fn(String) -> Message + 'a,
+) -> SearchBar<'a, Message> {
+    SearchBar::new(placeholder, query, on_input)
+}
+
+/// The state of the [`Watcher`] of a [`SearchBar`].
+struct State {
+    /// The query when it was last seen.
+    query: String,
+    /// When the search message must be produced, if the query changed since the last one.
+    deadline: Option<Instant>,
+    /// Whether the results are displayed.
+    open: bool,
+    /// The highlighted result, if any.
+    highlighted: Option<usize>,
+    /// Whether the list must be scrolled to show the highlighted result.
+    reveal: bool,
+    /// The vertical scroll offset of the list.
+    offset: f32,
+    /// The trees of the results.
+    results: Tree,
+}
+
+/// An [`Operation`] finding whether a focusable widget is focused.
+#[derive(Default)]
+struct Focused(bool);
+
+impl Operation for Focused {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn focusable(&mut self, state: &mut dyn operation::Focusable, _id: Option<&Id>) {
+        self.0 |= state.is_focused();
+    }
+}
+
+/// Wraps the field of a [`SearchBar`], debounces the query and displays the results.
+struct Watcher<'a, Message> {
+    field: Element<'a, Message>,
+    query: String,
+    on_search: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    results: Vec<Element<'a, Message>>,
+    debounce: Duration,
+    menu_height: f32,
+    dismiss: Dismiss,
+    class: Rc<<Theme as Catalog>::Class<'a>>,
+}
+
+impl<Message> Widget<Message, Theme, Renderer> for Watcher<'_, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            query: self.query.clone(),
+            deadline: None,
+            open: false,
+            highlighted: None,
+            reveal: false,
+            offset: 0.,
+            results: Tree::empty(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.field)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.field]);
+
+        let state = tree.state.downcast_mut::<State>();
+        state.results.diff_children(&self.results);
+        if state
+            .highlighted
+            .is_some_and(|highlighted| highlighted >= self.results.len())
+        {
+            state.highlighted = None;
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.field.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let node = self
+            .field
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        Node::with_children(node.size(), vec![node])
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.field.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.field.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let field_layout = layout.children().next().unwrap();
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.query != self.query {
+            state.query = self.query.clone();
+            state.deadline = Some(Instant::now() + self.debounce);
+            state.open = true;
+            state.highlighted = None;
+            state.offset = 0.;
+        }
+
+        // The clicks on the results are captured by their overlay, so the field is the only area.
+        let areas = [field_layout.bounds()];
+        if state.open && !self.results.is_empty() && Dismiss::ANY.applies(&event, cursor, &areas) {
+            if !self.dismiss.applies(&event, cursor, &areas) {
+                return event::Status::Ignored;
+            }
+
+            state.open = false;
+
+            if Dismiss::status(&event) == event::Status::Captured {
+                return event::Status::Captured;
+            }
+        }
+
+        match &event {
+            iced::Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(deadline) = state.deadline {
+                    if *now >= deadline {
+                        state.deadline = None;
+                        if let Some(on_search) = &self.on_search {
+                            shell.publish(on_search(self.query.clone()));
+                        }
+                    } else {
+                        shell.request_redraw(window::RedrawRequest::At(deadline));
+                    }
+                }
+            }
+            iced::Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if state.open && !self.results.is_empty() => {
+                let count = self.results.len();
+
+                match key {
+                    keyboard::key::Named::ArrowDown => {
+                        state.highlighted = Some(
+                            state
+                                .highlighted
+                                .map_or(0, |highlighted| (highlighted + 1) % count),
+                        );
+                        state.reveal = true;
+                        shell.invalidate_layout();
+                        return event::Status::Captured;
+                    }
+                    keyboard::key::Named::ArrowUp => {
+                        state.highlighted =
+                            Some(state.highlighted.map_or(count - 1, |highlighted| {
+                                (highlighted + count - 1) % count
+                            }));
+                        state.reveal = true;
+                        shell.invalidate_layout();
+                        return event::Status::Captured;
+                    }
+                    keyboard::key::Named::Enter => {
+                        if let Some(highlighted) = state.highlighted
+                            && let Some(on_select) = &self.on_select
+                        {
+                            state.open = false;
+                            shell.publish(on_select(highlighted));
+                            return event::Status::Captured;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+
+        let status = self.field.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            field_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let mut focused = Focused::default();
+        self.field
+            .as_widget()
+            .operate(&mut tree.children[0], field_layout, renderer, &mut focused);
+
+        let state = tree.state.downcast_mut::<State>();
+        if !focused.0 {
+            state.open = false;
+        } else if let iced::Event::Mouse(mouse::Event::ButtonPressed(_)) = event
+            && cursor.is_over(field_layout.bounds())
+        {
+            state.open = true;
+        }
+
+        if let Some(deadline) = state.deadline {
+            shell.request_redraw(window::RedrawRequest::At(deadline));
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.field.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let layout = layout.children().next().unwrap();
+        let (field_tree, _) = tree.children.split_at_mut(1);
+
+        let field =
+            self.field
+                .as_widget_mut()
+                .overlay(&mut field_tree[0], layout, renderer, translation);
+
+        let state = tree.state.downcast_mut::<State>();
+        let results = (state.open && !self.results.is_empty()).then(|| {
+            overlay::Element::new(Box::new(Results {
+                results: &self.results,
+                state,
+                on_select: self.on_select.as_deref(),
+                field: layout.bounds() + translation,
+                menu_height: self.menu_height,
+                class: &self.class,
+            }))
+        });
+
+        layers::group([field, results])
+    }
+}
+
+/// The overlay displaying the results of a [`SearchBar`].
+struct Results<'a, 'b, Message> {
+    results: &'b [Element<'a, Message>],
+    state: &'b mut State,
+    on_select: Option<&'b dyn Fn(usize) -> Message>,
+    field: Rectangle,
+    menu_height: f32,
+    class: &'b <Theme as Catalog>::Class<'a>,
+}
+
+impl<Message> Results<'_, '_, Message> {
+    /// The padding around each result.
+    const PADDING: Padding = Padding {
+        top: 6.,
+        right: 10.,
+        bottom: 6.,
+        left: 10.,
+    };
+
+    /// Returns the index of the result under the cursor, if any.
+    fn hovered(&self, layout: Layout<'_>, cursor: mouse::Cursor) -> Option<usize> {
+        cursor.position_over(layout.bounds())?;
+        layout
+            .children()
+            .position(|result| cursor.is_over(result.bounds()))
+    }
+}
+
+impl<Message> overlay::Overlay<Message, Theme, Renderer> for Results<'_, '_, Message> {
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        let width = self.field.width;
+        let limits = Limits::new(Size::ZERO, Size::new(width, f32::INFINITY))
+            .width(width)
+            .shrink(Self::PADDING);
+
+        let mut y = 0.;
+        let mut nodes: Vec<_> = self
+            .results
+            .iter()
+            .zip(&mut self.state.results.children)
+            .map(|(result, tree)| {
+                let content = result
+                    .as_widget()
+                    .layout(tree, renderer, &limits)
+                    .move_to(Point::new(Self::PADDING.left, Self::PADDING.top));
+                let size = Size::new(width, content.size().height + Self::PADDING.vertical());
+                let node = Node::with_children(size, vec![content]).move_to(Point::new(0., y));
+                y += size.height;
+                node
+            })
+            .collect();
+
+        let below = (bounds.height - self.field.y - self.field.height).max(0.);
+        let height = y.min(self.menu_height).min(below);
+
+        if self.state.reveal
+            && let Some(highlighted) = self.state.highlighted
+        {
+            let result = nodes[highlighted].bounds();
+            self.state.offset = self
+                .state
+                .offset
+                .max(result.y + result.height - height)
+                .min(result.y);
+        }
+        self.state.reveal = false;
+        self.state.offset = self.state.offset.clamp(0., (y - height).max(0.));
+
+        for node in &mut nodes {
+            node.translate_mut(Vector::new(0., -self.state.offset));
+        }
+
+        Node::with_children(Size::new(width, height), nodes).move_to(Point::new(
+            self.field.x,
+            self.field.y + self.field.height + 2.,
+        ))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let style = Catalog::style(theme, self.class);
+
+        advanced::Renderer::fill_quad(
+            renderer,
+            renderer::Quad {
+                bounds,
+                border: style.menu_border,
+                shadow: style.menu_shadow,
+            },
+            style.menu_background,
+        );
+
+        advanced::Renderer::with_layer(renderer, bounds, |renderer| {
+            for (i, ((result, tree), layout)) in self
+                .results
+                .iter()
+                .zip(&self.state.results.children)
+                .zip(layout.children())
+                .enumerate()
+            {
+                let result_bounds = layout.bounds();
+                if !result_bounds.intersects(&bounds) {
+                    continue;
+                }
+
+                let text_color = if self.state.highlighted == Some(i) {
+                    advanced::Renderer::fill_quad(
+                        renderer,
+                        renderer::Quad {
+                            bounds: result_bounds,
+                            border: Border::default().rounded(4.),
+                            ..Default::default()
+                        },
+                        style.highlighted_background,
+                    );
+                    style.highlighted_text_color
+                } else {
+                    style.text_color
+                };
+
+                result.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    &renderer::Style { text_color },
+                    layout.children().next().unwrap(),
+                    cursor,
+                    &bounds,
+                );
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            iced::Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(hovered) = self.hovered(layout, cursor)
+                    && self.state.highlighted != Some(hovered)
+                {
+                    self.state.highlighted = Some(hovered);
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+                event::Status::Ignored
+            }
+            iced::Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let dy = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * 40.,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+                self.state.offset -= dy;
+                shell.invalidate_layout();
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if cursor.is_over(bounds) =>
+            {
+                if let Some(hovered) = self.hovered(layout, cursor)
+                    && let Some(on_select) = self.on_select
+                {
+                    self.state.open = false;
+                    shell.publish(on_select(hovered));
+                }
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_select.is_some() && self.hovered(layout, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
@@ -0,0 +1,245 @@
+//! A small animated indicator for loading states without a known progress.
+
+use std::f32::consts::TAU;
+
+use iced::{
+    Border, Color, Element, Length, Pixels, Point, Rectangle, Size, Theme,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, renderer,
+        widget::{Tree, tree},
+    },
+    event, window,
+};
+
+use crate::animation::Clock;
+
+/// The appearance of a [`Spinner`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color of the dots.
+    pub color: Color,
+}
+
+/// The theme catalog of a [`Spinner`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Spinner`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Spinner`].
+pub fn default(theme: &Theme) -> Style {
+    Style {
+        color: theme.extended_palette().primary.base.color,
+    }
+}
+
+/// The state of a [`Spinner`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    /// The angle of the leading dot.
+    angle: f32,
+    /// The clock of the animation.
+    clock: Clock,
+}
+
+/// A ring of dots fading behind a rotating leading dot, created with [`spinner`].
+///
+/// It drives its own animation, so it only has to be displayed while something is loading.
+pub struct Spinner<'a, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    size: f32,
+    speed: f32,
+    dots: u8,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme> Spinner<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Spinner`].
+    pub fn new() -> Self {
+        Self {
+            size: 24.,
+            speed: 1.,
+            dots: 8,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the size (width and height) of the [`Spinner`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the speed of the [`Spinner`], in turns per second.
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Sets the number of dots of the [`Spinner`].
+    pub fn dots(mut self, dots: u8) -> Self {
+        self.dots = dots.max(1);
+        self
+    }
+
+    /// Sets the style of the [`Spinner`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Spinner`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<Theme> Default for Spinner<'_, Theme>
+where
+    Theme: Catalog,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Spinner<'_, Theme>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fixed(self.size), Length::Fixed(self.size))
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.resolve(self.size, self.size, Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if let iced::Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+            let elapsed = state.clock.tick(now);
+
+            state.angle = (state.angle + elapsed * self.speed * TAU).rem_euclid(TAU);
+
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let bounds = layout.bounds();
+        let center = bounds.center();
+
+        let radius = bounds.width.min(bounds.height) / 2.;
+        let dot = radius * 0.2;
+        let orbit = radius - dot;
+        let count = f32::from(self.dots);
+
+        for i in 0..self.dots {
+            let behind = f32::from(i) / count;
+            let angle = state.angle - behind * TAU;
+            let position = Point::new(
+                center.x + angle.cos() * orbit,
+                center.y + angle.sin() * orbit,
+            );
+            let size = dot * (1. - behind * 0.5);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: position.x - size,
+                        y: position.y - size,
+                        width: size * 2.,
+                        height: size * 2.,
+                    },
+                    border: Border::default().rounded(size),
+                    ..Default::default()
+                },
+                style.color.scale_alpha(1. - behind * 0.85),
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Spinner<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(value: Spinner<'a, Theme>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`Spinner`].
+pub fn spinner<'a, Theme>() -> Spinner<'a, Theme>
+where
+    Theme: Catalog,
+{
+    Spinner::new()
+}
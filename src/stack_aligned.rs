@@ -0,0 +1,350 @@
+//! A stack layering its children on top of each other, each with its own alignment and offset.
+//!
+//! Unlike iced's `Stack`, every layer is positioned independently: a badge can be pinned to the
+//! top right corner of a thumbnail while a caption sits at its bottom.
+//!
+//! Layers are hit-tested from the top down: a layer under the cursor hides it from the layers
+//! below, so that clicking on a badge does not also click on the thumbnail.
+
+use iced::{
+    Element, Length, Rectangle, Size, Vector,
+    advanced::{
+        self, Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{Operation, Tree},
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+};
+
+/// A layer of a [`StackAligned`], created with [`layer`].
+pub struct Layer<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    align_x: Horizontal,
+    align_y: Vertical,
+    offset: Vector,
+}
+
+impl<'a, Message, Theme, Renderer> Layer<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Layer`] with the given content, aligned to the top left corner.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            align_x: Horizontal::Left,
+            align_y: Vertical::Top,
+            offset: Vector::ZERO,
+        }
+    }
+
+    /// Sets the horizontal alignment of the [`Layer`] in the stack.
+    pub fn align_x(mut self, align_x: impl Into<Horizontal>) -> Self {
+        self.align_x = align_x.into();
+        self
+    }
+
+    /// Sets the vertical alignment of the [`Layer`] in the stack.
+    pub fn align_y(mut self, align_y: impl Into<Vertical>) -> Self {
+        self.align_y = align_y.into();
+        self
+    }
+
+    /// Sets the offset of the [`Layer`] from its aligned position.
+    pub fn offset(mut self, offset: impl Into<Vector>) -> Self {
+        self.offset = offset.into();
+        self
+    }
+}
+
+/// Creates a new [`Layer`] with the given content.
+pub fn layer<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Layer<'a, Message, Theme, Renderer> {
+    Layer::new(content)
+}
+
+/// A widget layering its children on top of each other, created with [`stack_aligned`].
+///
+/// The first layer is at the bottom. By default, the stack is as large as its largest layer.
+pub struct StackAligned<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    layers: Vec<Layer<'a, Message, Theme, Renderer>>,
+    width: Length,
+    height: Length,
+}
+
+impl<'a, Message, Theme, Renderer> StackAligned<'a, Message, Theme, Renderer> {
+    /// Creates a new empty [`StackAligned`].
+    pub fn new() -> Self {
+        Self::with_layers([])
+    }
+
+    /// Creates a new [`StackAligned`] with the given layers.
+    pub fn with_layers(
+        layers: impl IntoIterator<Item = Layer<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            layers: layers.into_iter().collect(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    /// Adds a child on top of the [`StackAligned`], aligned to the top left corner.
+    pub fn push(self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.push_layer(Layer::new(child))
+    }
+
+    /// Adds a [`Layer`] on top of the [`StackAligned`].
+    pub fn push_layer(mut self, layer: Layer<'a, Message, Theme, Renderer>) -> Self {
+        self.layers.push(layer);
+        self
+    }
+
+    /// Sets the width of the [`StackAligned`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`StackAligned`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Default for StackAligned<'_, Message, Theme, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the index of the topmost layer under the cursor, if any.
+fn topmost(layout: Layout<'_>, cursor: mouse::Cursor) -> Option<usize> {
+    layout
+        .children()
+        .enumerate()
+        .filter(|(_, layout)| cursor.is_over(layout.bounds()))
+        .map(|(i, _)| i)
+        .last()
+}
+
+/// Returns the cursor as seen by the layer at the given index.
+fn cursor_for(index: usize, topmost: Option<usize>, cursor: mouse::Cursor) -> mouse::Cursor {
+    match topmost {
+        Some(top) if index < top => mouse::Cursor::Unavailable,
+        _ => cursor,
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for StackAligned<'_, Message, Theme, Renderer>
+where
+    Renderer: advanced::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.layers
+            .iter()
+            .map(|layer| Tree::new(&layer.content))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let children: Vec<_> = self.layers.iter().map(|layer| &layer.content).collect();
+        tree.diff_children(&children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let mut nodes: Vec<Node> = self
+            .layers
+            .iter()
+            .zip(&mut tree.children)
+            .map(|(layer, tree)| layer.content.as_widget().layout(tree, renderer, &limits))
+            .collect();
+
+        let content = nodes.iter().fold(Size::ZERO, |size, node| {
+            let child = node.size();
+            Size::new(size.width.max(child.width), size.height.max(child.height))
+        });
+        let size = limits.resolve(self.width, self.height, content);
+
+        let bounded = Limits::new(Size::ZERO, size);
+        for ((layer, tree), node) in self.layers.iter().zip(&mut tree.children).zip(&mut nodes) {
+            let child = node.size();
+            if child.width > size.width || child.height > size.height {
+                *node = layer.content.as_widget().layout(tree, renderer, &bounded);
+            }
+
+            node.align_mut(layer.align_x.into(), layer.align_y.into(), size);
+            node.translate_mut(layer.offset);
+        }
+
+        Node::with_children(size, nodes)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let topmost = topmost(layout, cursor);
+
+        for (i, ((layer, tree), layout)) in self
+            .layers
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            let cursor = cursor_for(i, topmost, cursor);
+
+            if i == 0 {
+                layer
+                    .content
+                    .as_widget()
+                    .draw(tree, renderer, theme, style, layout, cursor, viewport);
+            } else {
+                renderer.with_layer(*viewport, |renderer| {
+                    layer
+                        .content
+                        .as_widget()
+                        .draw(tree, renderer, theme, style, layout, cursor, viewport);
+                });
+            }
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.layers
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((layer, tree), layout)| {
+                    layer
+                        .content
+                        .as_widget()
+                        .operate(tree, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let topmost = topmost(layout, cursor);
+        let layouts: Vec<_> = layout.children().collect();
+
+        self.layers
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layouts)
+            .enumerate()
+            .rev()
+            .map(|(i, ((layer, tree), layout))| {
+                layer.content.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor_for(i, topmost, cursor),
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .find(|&status| status == event::Status::Captured)
+            .unwrap_or(event::Status::Ignored)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let Some(top) = topmost(layout, cursor) else {
+            return mouse::Interaction::default();
+        };
+
+        self.layers[top].content.as_widget().mouse_interaction(
+            &tree.children[top],
+            layout.children().nth(top).unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children: Vec<_> = self
+            .layers
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((layer, tree), layout)| {
+                layer
+                    .content
+                    .as_widget_mut()
+                    .overlay(tree, layout, renderer, translation)
+            })
+            .collect();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<StackAligned<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: advanced::Renderer + 'a,
+{
+    fn from(value: StackAligned<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`StackAligned`] with the given layers.
+pub fn stack_aligned<'a, Message, Theme, Renderer>(
+    layers: impl IntoIterator<Item = Layer<'a, Message, Theme, Renderer>>,
+) -> StackAligned<'a, Message, Theme, Renderer> {
+    StackAligned::with_layers(layers)
+}
@@ -0,0 +1,553 @@
+//! A scrollable column where headers stick to the top while the content under them scrolls.
+//!
+//! This is meant for grouped lists, like contacts sorted by initial or a changelog split by
+//! version: the header of the current group stays visible, and is pushed out by the next one.
+
+use iced::{
+    Border, Color, Element, Length, Pixels, Point, Rectangle, Size, Theme, Vector,
+    advanced::{
+        self, Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{Operation, Tree, tree},
+    },
+    event, touch,
+};
+
+/// The appearance of a [`StickyScroll`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color of the scrollbar.
+    pub scrollbar: Color,
+}
+
+/// The theme catalog of a [`StickyScroll`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`StickyScroll`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`StickyScroll`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        scrollbar: palette.background.strong.color,
+    }
+}
+
+/// The state of a [`StickyScroll`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    /// The vertical scroll offset of the content.
+    offset: f32,
+    /// The height of the content.
+    content_height: f32,
+    /// The index of the header pinned to the top, if any.
+    pinned: Option<usize>,
+    /// Where the scrollbar was grabbed, relatively to the top of its thumb, while it is dragged.
+    scrollbar_grab: Option<f32>,
+}
+
+/// A scrollable column whose sticky children pin to its top, created with [`sticky_scroll`].
+///
+/// When scrolled past, the last sticky child stays at the top, above the content, until the next
+/// sticky child pushes it out. Sticky children are drawn above the content, so they should have
+/// an opaque background.
+///
+/// The content is scrolled with the mouse wheel or the scrollbar.
+pub struct StickyScroll<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    sticky: Vec<bool>,
+    spacing: f32,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> StickyScroll<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// The width of the scrollbar.
+    const SCROLLBAR_WIDTH: f32 = 8.;
+
+    /// Creates a new empty [`StickyScroll`].
+    pub fn new() -> Self {
+        Self {
+            children: Vec::new(),
+            sticky: Vec::new(),
+            spacing: 0.,
+            width: Length::Fill,
+            height: Length::Fill,
+            class: Theme::default(),
+        }
+    }
+
+    /// Adds a child scrolling with the content.
+    pub fn push(mut self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.children.push(child.into());
+        self.sticky.push(false);
+        self
+    }
+
+    /// Adds a child sticking to the top when scrolled past.
+    pub fn push_sticky(mut self, header: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.children.push(header.into());
+        self.sticky.push(true);
+        self
+    }
+
+    /// Sets the vertical spacing between the children.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the width of the [`StickyScroll`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`StickyScroll`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`StickyScroll`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`StickyScroll`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the bounds of the scrollbar thumb, if the content overflows.
+    fn scrollbar(bounds: Rectangle, state: &State) -> Option<Rectangle> {
+        if state.content_height <= bounds.height {
+            return None;
+        }
+
+        let max_offset = state.content_height - bounds.height;
+        let height = (bounds.height * bounds.height / state.content_height).max(20.);
+        let y = bounds.y + (bounds.height - height) * state.offset / max_offset;
+
+        Some(Rectangle {
+            x: bounds.x + bounds.width - Self::SCROLLBAR_WIDTH,
+            y,
+            width: Self::SCROLLBAR_WIDTH,
+            height,
+        })
+    }
+
+    /// Sets the scroll offset from the position of the cursor on the scrollbar.
+    fn drag_scrollbar(bounds: Rectangle, state: &mut State, y: f32, grab: f32) {
+        let Some(thumb) = Self::scrollbar(bounds, state) else {
+            return;
+        };
+        let track = (bounds.height - thumb.height).max(f32::EPSILON);
+        let ratio = ((y - grab - bounds.y) / track).clamp(0., 1.);
+
+        state.offset = ratio * (state.content_height - bounds.height);
+    }
+
+    /// Returns the cursor as seen by the child at the given index.
+    ///
+    /// The children are only reachable inside the bounds, and the pinned header hides the
+    /// content under it.
+    fn cursor_for(
+        index: usize,
+        state: &State,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> mouse::Cursor {
+        let bounds = layout.bounds();
+        if !cursor.is_over(bounds) {
+            return mouse::Cursor::Unavailable;
+        }
+
+        match state.pinned {
+            Some(pinned)
+                if pinned != index
+                    && layout
+                        .children()
+                        .nth(pinned)
+                        .is_some_and(|header| cursor.is_over(header.bounds())) =>
+            {
+                mouse::Cursor::Unavailable
+            }
+            _ => cursor,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Default for StickyScroll<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for StickyScroll<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let width = limits.max().width;
+        let child_limits = Limits::new(Size::ZERO, Size::new(width, f32::INFINITY)).width(width);
+
+        let mut nodes: Vec<Node> = self
+            .children
+            .iter()
+            .zip(&mut tree.children)
+            .map(|(child, tree)| child.as_widget().layout(tree, renderer, &child_limits))
+            .collect();
+
+        let mut positions = Vec::with_capacity(nodes.len());
+        let mut y = 0.;
+        for node in &nodes {
+            positions.push(y);
+            y += node.size().height + self.spacing;
+        }
+        let content_height = (y - self.spacing).max(0.);
+
+        let size = limits.resolve(self.width, self.height, Size::new(width, content_height));
+
+        let state = tree.state.downcast_mut::<State>();
+        state.content_height = content_height;
+        state.offset = state
+            .offset
+            .clamp(0., (content_height - size.height).max(0.));
+        let offset = state.offset;
+
+        state.pinned = (0..nodes.len())
+            .rev()
+            .find(|&i| self.sticky[i] && positions[i] < offset);
+
+        for (node, &position) in nodes.iter_mut().zip(&positions) {
+            node.move_to_mut(Point::new(0., position - offset));
+        }
+
+        if let Some(pinned) = state.pinned {
+            let height = nodes[pinned].size().height;
+            let next = (pinned + 1..nodes.len()).find(|&i| self.sticky[i]);
+            let y = next.map_or(0., |next| (positions[next] - offset - height).min(0.));
+
+            nodes[pinned].move_to_mut(Point::new(0., y));
+        }
+
+        Node::with_children(size, nodes)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.style(&self.class);
+
+        renderer.with_layer(clip, |renderer| {
+            for (i, ((child, tree), layout)) in self
+                .children
+                .iter()
+                .zip(&tree.children)
+                .zip(layout.children())
+                .enumerate()
+            {
+                if state.pinned != Some(i) && layout.bounds().intersects(&clip) {
+                    child.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        style,
+                        layout,
+                        Self::cursor_for(i, state, layout, cursor),
+                        &clip,
+                    );
+                }
+            }
+        });
+
+        if let Some(pinned) = state.pinned {
+            renderer.with_layer(clip, |renderer| {
+                self.children[pinned].as_widget().draw(
+                    &tree.children[pinned],
+                    renderer,
+                    theme,
+                    style,
+                    layout.children().nth(pinned).unwrap(),
+                    Self::cursor_for(pinned, state, layout, cursor),
+                    &clip,
+                );
+            });
+        }
+
+        if let Some(thumb) = Self::scrollbar(bounds, state) {
+            renderer.with_layer(clip, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: thumb.shrink(1.),
+                        border: Border::default().rounded(Self::SCROLLBAR_WIDTH / 2.),
+                        ..Default::default()
+                    },
+                    appearance.scrollbar,
+                );
+            });
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, tree), layout)| {
+                    child.as_widget().operate(tree, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let clip = bounds.intersection(viewport).unwrap_or(bounds);
+        let state = *tree.state.downcast_ref::<State>();
+
+        if state.scrollbar_grab.is_none() {
+            let status = self
+                .children
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .enumerate()
+                .map(|(i, ((child, tree), child_layout))| {
+                    child.as_widget_mut().on_event(
+                        tree,
+                        event.clone(),
+                        child_layout,
+                        Self::cursor_for(i, &state, layout, cursor),
+                        renderer,
+                        clipboard,
+                        shell,
+                        &clip,
+                    )
+                })
+                .fold(event::Status::Ignored, event::Status::merge);
+
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            iced::Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let dy = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * 60.,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+                let max_offset = (state.content_height - bounds.height).max(0.);
+                let offset = (state.offset - dy).clamp(0., max_offset);
+
+                if offset != state.offset {
+                    state.offset = offset;
+                    shell.invalidate_layout();
+                    return event::Status::Captured;
+                }
+
+                event::Status::Ignored
+            }
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(bounds)
+                    && let Some(thumb) = Self::scrollbar(bounds, state)
+                    && position.x >= thumb.x
+                {
+                    let grab = if thumb.contains(position) {
+                        position.y - thumb.y
+                    } else {
+                        thumb.height / 2.
+                    };
+                    state.scrollbar_grab = Some(grab);
+                    Self::drag_scrollbar(bounds, state, position.y, grab);
+                    shell.invalidate_layout();
+                    return event::Status::Captured;
+                }
+
+                event::Status::Ignored
+            }
+            iced::Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(grab) = state.scrollbar_grab {
+                    Self::drag_scrollbar(bounds, state, position.y, grab);
+                    shell.invalidate_layout();
+                    return event::Status::Captured;
+                }
+
+                event::Status::Ignored
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerLifted { .. })
+            | iced::Event::Touch(touch::Event::FingerLost { .. })
+                if state.scrollbar_grab.is_some() =>
+            {
+                state.scrollbar_grab = None;
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+
+        if let Some(thumb) = Self::scrollbar(bounds, state)
+            && let Some(position) = cursor.position_over(bounds)
+            && position.x >= thumb.x
+        {
+            return mouse::Interaction::Idle;
+        }
+
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+            .map(|(i, ((child, tree), child_layout))| {
+                child.as_widget().mouse_interaction(
+                    tree,
+                    child_layout,
+                    Self::cursor_for(i, state, layout, cursor),
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<StickyScroll<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: advanced::Renderer + 'a,
+{
+    fn from(value: StickyScroll<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new empty [`StickyScroll`].
+pub fn sticky_scroll<'a, Message, Theme, Renderer>() -> StickyScroll<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    StickyScroll::new()
+}
@@ -0,0 +1,229 @@
+//! A grid of color swatches, to pick a color among a few named ones.
+//!
+//! This is a lightweight alternative to a full color picker, for example to choose a theme.
+
+use std::rc::Rc;
+
+use iced::{
+    Border, Color, Element, Length, Pixels, Theme,
+    widget::{Space, button, container, text, tooltip},
+};
+
+use crate::{
+    grid::Grid,
+    helpers::{readable_text_color, readable_text_color_or},
+};
+
+/// The status of a swatch of a [`SwatchPicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// The swatch can be clicked.
+    Active,
+    /// The swatch is hovered or pressed.
+    Hovered,
+}
+
+impl From<button::Status> for Status {
+    fn from(value: button::Status) -> Self {
+        match value {
+            button::Status::Hovered | button::Status::Pressed => Status::Hovered,
+            button::Status::Active | button::Status::Disabled => Status::Active,
+        }
+    }
+}
+
+/// The appearance of a swatch of a [`SwatchPicker`].
+///
+/// The swatch itself is always filled with its color.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The border of the swatch.
+    pub border: Border,
+}
+
+/// The theme catalog of a [`SwatchPicker`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a swatch of the given color of a class, selected or not.
+    fn style(&self, class: &Self::Class<'_>, status: Status, color: Color, selected: bool)
+    -> Style;
+}
+
+/// A styling function for a [`SwatchPicker`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status, Color, bool) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(
+        &self,
+        class: &Self::Class<'_>,
+        status: Status,
+        color: Color,
+        selected: bool,
+    ) -> Style {
+        class(self, status, color, selected)
+    }
+}
+
+/// The default style of a swatch of a [`SwatchPicker`].
+///
+/// The selected swatch has a thick border contrasting with its color.
+pub fn default(theme: &Theme, status: Status, color: Color, selected: bool) -> Style {
+    let palette = theme.extended_palette();
+
+    let border = if selected {
+        Border {
+            color: readable_text_color(color),
+            width: 3.,
+            radius: 4.into(),
+        }
+    } else {
+        match status {
+            Status::Hovered => Border {
+                color: readable_text_color_or(color, palette.primary.strong.color, 3.),
+                width: 2.,
+                radius: 4.into(),
+            },
+            Status::Active => Border {
+                color: palette.background.strong.color,
+                width: 1.,
+                radius: 4.into(),
+            },
+        }
+    };
+
+    Style { border }
+}
+
+/// A grid of named color swatches, created with [`swatch_picker`].
+///
+/// The name of a swatch is shown in a tooltip when it is hovered, and the selected swatch is highlighted.
+pub struct SwatchPicker<'a, Message> {
+    swatches: Vec<(String, Color)>,
+    selected: Option<Color>,
+    on_pick: Box<dyn Fn(Color) -> Message + 'a>,
+    columns: usize,
+    swatch_size: f32,
+    spacing: f32,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Message> SwatchPicker<'a, Message> {
+    /// The default number of columns of a [`SwatchPicker`].
+    pub const DEFAULT_COLUMNS: usize = 8;
+
+    /// Creates a new [`SwatchPicker`].
+    ///
+    /// It expects:
+    ///   * the swatches, as pairs of a name and a color
+    ///   * the selected color, if any
+    ///   * a function that will be called with the color of a swatch when it is clicked.
+    pub fn new(
+        swatches: impl IntoIterator<Item = (impl Into<String>, Color)>,
+        selected: Option<Color>,
+        on_pick: impl Fn(Color) -> Message + 'a,
+    ) -> Self {
+        Self {
+            swatches: swatches
+                .into_iter()
+                .map(|(name, color)| (name.into(), color))
+                .collect(),
+            selected,
+            on_pick: Box::new(on_pick),
+            columns: Self::DEFAULT_COLUMNS,
+            swatch_size: 24.,
+            spacing: 4.,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets the number of swatches in each row of the [`SwatchPicker`].
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// Sets the size of the swatches.
+    pub fn swatch_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.swatch_size = size.into().0;
+        self
+    }
+
+    /// Sets the spacing between the swatches.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the style of the swatches.
+    pub fn style(mut self, style: impl Fn(&Theme, Status, Color, bool) -> Style + 'a) -> Self {
+        self.class = Box::new(style);
+        self
+    }
+
+    /// Sets the style class of the swatches.
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message: Clone + 'a> From<SwatchPicker<'a, Message>> for Element<'a, Message> {
+    fn from(value: SwatchPicker<'a, Message>) -> Self {
+        let size = Length::Fixed(value.swatch_size);
+        let class = Rc::new(value.class);
+
+        let swatches: Vec<Element<'a, Message>> = value
+            .swatches
+            .into_iter()
+            .map(|(name, color)| {
+                let selected = value.selected == Some(color);
+                let class = class.clone();
+
+                let swatch = button(Space::new(size, size))
+                    .padding(0)
+                    .on_press((value.on_pick)(color))
+                    .style(move |theme: &Theme, status| button::Style {
+                        background: Some(color.into()),
+                        border: Catalog::style(theme, &class, status.into(), color, selected)
+                            .border,
+                        ..button::Style::default()
+                    });
+
+                tooltip(swatch, text(name), tooltip::Position::Bottom)
+                    .style(container::rounded_box)
+                    .into()
+            })
+            .collect();
+
+        let mut swatches = swatches.into_iter().peekable();
+        let mut grid = Grid::new()
+            .column_spacing(value.spacing)
+            .row_spacing(value.spacing);
+
+        while swatches.peek().is_some() {
+            grid = grid.push_row(swatches.by_ref().take(value.columns).collect::<Vec<_>>());
+        }
+
+        grid.into()
+    }
+}
+
+/// Creates a new [`SwatchPicker`].
+pub fn swatch_picker<'a, Message>(
+    swatches: impl IntoIterator<Item = (impl Into<String>, Color)>,
+    selected: Option<Color>,
+    on_pick: impl Fn(Color) -> Message + 'a,
+) -> SwatchPicker<'a, Message> {
+    SwatchPicker::new(swatches, selected, on_pick)
+}
@@ -0,0 +1,663 @@
+//! A vertical navigation rail of tabs, with icons, labels and badges.
+
+use iced::{
+    Background, Border, Color, Element, Length, Pixels, Point, Rectangle, Size, Theme,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, renderer,
+        text::{self, Paragraph, Text},
+        widget::{Operation, Tree, tree},
+    },
+    alignment, event, touch, window,
+};
+
+use crate::{
+    animation::{Clock, approach},
+    responsive::{Breakpoints, SizeClass},
+};
+
+/// The appearance of a [`TabRail`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the rail.
+    pub background: Background,
+    /// The color of the icons and labels.
+    pub text_color: Color,
+    /// The background of the hovered entry.
+    pub hovered_background: Background,
+    /// The background of the selection indicator.
+    pub indicator: Background,
+    /// The color of the icon and label of the selected entry.
+    pub selected_text_color: Color,
+    /// The background of the badges.
+    pub badge_background: Color,
+    /// The color of the text of the badges.
+    pub badge_text_color: Color,
+}
+
+/// The theme catalog of a [`TabRail`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`TabRail`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`TabRail`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weak.color.into(),
+        text_color: palette.background.weak.text,
+        hovered_background: palette.background.strong.color.scale_alpha(0.5).into(),
+        indicator: palette.primary.weak.color.into(),
+        selected_text_color: palette.primary.weak.text,
+        badge_background: palette.danger.base.color,
+        badge_text_color: palette.danger.base.text,
+    }
+}
+
+/// How fast the selection indicator moves towards the selected entry, in 1/s.
+const INDICATOR_SPEED: f32 = 18.;
+
+/// An entry of a [`TabRail`], created with [`entry`].
+pub struct Entry<'a, Key, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    key: Key,
+    icon: Element<'a, Message, Theme, Renderer>,
+    label: String,
+    badge: Option<String>,
+}
+
+impl<'a, Key, Message, Theme, Renderer> Entry<'a, Key, Message, Theme, Renderer> {
+    /// Creates a new [`Entry`] identified by `key`, with the given icon and label.
+    pub fn new(
+        key: Key,
+        icon: impl Into<Element<'a, Message, Theme, Renderer>>,
+        label: impl Into<String>,
+    ) -> Self {
+        Self {
+            key,
+            icon: icon.into(),
+            label: label.into(),
+            badge: None,
+        }
+    }
+
+    /// Sets the badge of the [`Entry`], a short text such as a count of unread items.
+    pub fn badge(mut self, badge: impl Into<String>) -> Self {
+        self.badge = Some(badge.into());
+        self
+    }
+}
+
+/// Creates a new [`Entry`] identified by `key`, with the given icon and label.
+pub fn entry<'a, Key, Message, Theme, Renderer>(
+    key: Key,
+    icon: impl Into<Element<'a, Message, Theme, Renderer>>,
+    label: impl Into<String>,
+) -> Entry<'a, Key, Message, Theme, Renderer> {
+    Entry::new(key, icon, label)
+}
+
+/// The state of a [`TabRail`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    /// The offset of the selection indicator from the top of the rail, once known.
+    indicator: Option<f32>,
+    /// The clock of the animation, running while the indicator is moving.
+    clock: Clock,
+    /// Whether the rail was collapsed by the last layout.
+    collapsed: bool,
+}
+
+/// A vertical navigation rail, created with [`tab_rail`].
+///
+/// Each entry shows an icon and a label, and an optional badge. When collapsed, only the icons
+/// (and their badges) are shown. The selection indicator slides to the selected entry when
+/// the selection changes.
+pub struct TabRail<'a, Key, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    entries: Vec<Entry<'a, Key, Message, Theme, Renderer>>,
+    selected: Key,
+    on_select: Box<dyn Fn(Key) -> Message + 'a>,
+    collapsed: bool,
+    collapse_below: Option<SizeClass>,
+    breakpoints: Breakpoints,
+    width: f32,
+    height: Length,
+    entry_height: f32,
+    icon_size: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Key, Message, Theme, Renderer> TabRail<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new empty [`TabRail`] with the given selected key.
+    ///
+    /// `on_select` produces the message emitted when an entry is clicked.
+    pub fn new(selected: Key, on_select: impl Fn(Key) -> Message + 'a) -> Self {
+        Self {
+            entries: Vec::new(),
+            selected,
+            on_select: Box::new(on_select),
+            collapsed: false,
+            collapse_below: None,
+            breakpoints: Breakpoints::DEFAULT,
+            width: 200.,
+            height: Length::Fill,
+            entry_height: 40.,
+            icon_size: 20.,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Adds an [`Entry`] at the bottom of the [`TabRail`].
+    pub fn push(mut self, entry: Entry<'a, Key, Message, Theme, Renderer>) -> Self {
+        self.entries.push(entry);
+        self
+    }
+
+    /// Adds several [`Entry`] at the bottom of the [`TabRail`].
+    pub fn extend(
+        mut self,
+        entries: impl IntoIterator<Item = Entry<'a, Key, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.entries.extend(entries);
+        self
+    }
+
+    /// Sets whether the [`TabRail`] is collapsed, showing only the icons.
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+
+    /// Collapses the [`TabRail`] when the width allotted to it is of a smaller [`SizeClass`]
+    /// than `class`, like a rail next to the content of a narrow window.
+    pub fn collapse_below(mut self, class: SizeClass) -> Self {
+        self.collapse_below = Some(class);
+        self
+    }
+
+    /// Sets the [`Breakpoints`] used by [`collapse_below`](Self::collapse_below).
+    pub fn breakpoints(mut self, breakpoints: Breakpoints) -> Self {
+        self.breakpoints = breakpoints;
+        self
+    }
+
+    /// Sets the width of the [`TabRail`] when it is not collapsed.
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = width.into().0;
+        self
+    }
+
+    /// Sets the height of the [`TabRail`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the height of each entry, which is also the width of the collapsed [`TabRail`].
+    pub fn entry_height(mut self, entry_height: impl Into<Pixels>) -> Self {
+        self.entry_height = entry_height.into().0;
+        self
+    }
+
+    /// Sets the size of the space given to the icons.
+    pub fn icon_size(mut self, icon_size: impl Into<Pixels>) -> Self {
+        self.icon_size = icon_size.into().0;
+        self
+    }
+
+    /// Sets the text size of the labels.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the labels and badges.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`TabRail`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`TabRail`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the width of the [`TabRail`], collapsed or not.
+    fn current_width(&self, collapsed: bool) -> f32 {
+        if collapsed {
+            self.entry_height
+        } else {
+            self.width
+        }
+    }
+
+    /// Returns the offset of the selected entry from the top of the rail, if any.
+    fn selected_offset(&self) -> Option<f32> {
+        self.entries
+            .iter()
+            .position(|entry| entry.key == self.selected)
+            .map(|index| index as f32 * self.entry_height)
+    }
+
+    /// Returns the bounds of the entry at the given index.
+    fn entry_bounds(&self, bounds: Rectangle, index: usize) -> Rectangle {
+        Rectangle {
+            x: bounds.x,
+            y: bounds.y + index as f32 * self.entry_height,
+            width: bounds.width,
+            height: self.entry_height,
+        }
+    }
+
+    /// Returns the index of the entry under the cursor, if any.
+    fn hovered(&self, bounds: Rectangle, cursor: mouse::Cursor) -> Option<usize> {
+        let position = cursor.position_over(bounds)?;
+        let index = ((position.y - bounds.y) / self.entry_height) as usize;
+        (index < self.entries.len()).then_some(index)
+    }
+
+    /// Draws a badge centered on the given point.
+    fn draw_badge(
+        &self,
+        renderer: &mut Renderer,
+        style: &Style,
+        badge: &str,
+        center: Point,
+        clip: Rectangle,
+    ) {
+        let size = Pixels(11.);
+        let height = 16.;
+        let text = Text {
+            content: badge,
+            bounds: Size::INFINITY,
+            size,
+            line_height: text::LineHeight::Absolute(Pixels(height)),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: text::Shaping::Basic,
+            wrapping: text::Wrapping::None,
+        };
+        let width = (Renderer::Paragraph::with_text(text).min_bounds().width + 8.).max(height);
+        let bounds = Rectangle {
+            x: center.x - width / 2.,
+            y: center.y - height / 2.,
+            width,
+            height,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border::default().rounded(height / 2.),
+                ..Default::default()
+            },
+            style.badge_background,
+        );
+        renderer.fill_text(
+            Text {
+                content: badge.to_string(),
+                bounds: bounds.size(),
+                size: text.size,
+                line_height: text.line_height,
+                font: text.font,
+                horizontal_alignment: text.horizontal_alignment,
+                vertical_alignment: text.vertical_alignment,
+                shaping: text.shaping,
+                wrapping: text.wrapping,
+            },
+            bounds.center(),
+            style.badge_text_color,
+            clip,
+        );
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TabRail<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.entries
+            .iter()
+            .map(|entry| Tree::new(&entry.icon))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(
+            &self
+                .entries
+                .iter()
+                .map(|entry| &entry.icon)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fixed(self.current_width(self.collapsed)),
+            height: self.height,
+        }
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let icon_limits = Limits::new(Size::ZERO, Size::new(self.icon_size, self.icon_size));
+
+        let children = self
+            .entries
+            .iter()
+            .zip(&mut tree.children)
+            .enumerate()
+            .map(|(i, (entry, tree))| {
+                let node = entry.icon.as_widget().layout(tree, renderer, &icon_limits);
+                let size = node.size();
+                node.move_to(Point::new(
+                    (self.entry_height - size.width) / 2.,
+                    i as f32 * self.entry_height + (self.entry_height - size.height) / 2.,
+                ))
+            })
+            .collect();
+
+        let collapsed = self.collapsed
+            || self
+                .collapse_below
+                .is_some_and(|class| self.breakpoints.class(limits.max().width) < class);
+        tree.state.downcast_mut::<State>().collapsed = collapsed;
+
+        let width = self.current_width(collapsed);
+        let size = limits.width(width).resolve(
+            width,
+            self.height,
+            Size::new(width, self.entries.len() as f32 * self.entry_height),
+        );
+
+        Node::with_children(size, children)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+        let state = tree.state.downcast_ref::<State>();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..Default::default()
+            },
+            style.background,
+        );
+
+        let inset = 4.;
+        let radius = 6.;
+
+        if let Some(hovered) = self.hovered(bounds, cursor) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: self.entry_bounds(bounds, hovered).shrink(inset),
+                    border: Border::default().rounded(radius),
+                    ..Default::default()
+                },
+                style.hovered_background,
+            );
+        }
+
+        if let Some(offset) = state.indicator.or_else(|| self.selected_offset()) {
+            let indicator = Rectangle {
+                y: bounds.y + offset,
+                height: self.entry_height,
+                ..bounds
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: indicator.shrink(inset),
+                    border: Border::default().rounded(radius),
+                    ..Default::default()
+                },
+                style.indicator,
+            );
+        }
+
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        for (i, ((entry, tree), icon)) in self
+            .entries
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            let row = self.entry_bounds(bounds, i);
+            let color = if entry.key == self.selected {
+                style.selected_text_color
+            } else {
+                style.text_color
+            };
+
+            entry.icon.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                &renderer::Style { text_color: color },
+                icon,
+                cursor,
+                viewport,
+            );
+
+            if !state.collapsed {
+                let label = Rectangle {
+                    x: row.x + self.entry_height,
+                    width: (row.width - self.entry_height - inset * 2.).max(0.),
+                    ..row
+                };
+
+                renderer.fill_text(
+                    Text {
+                        content: entry.label.clone(),
+                        bounds: label.size(),
+                        size: text_size,
+                        line_height: text::LineHeight::default(),
+                        font,
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Center,
+                        shaping: text::Shaping::Advanced,
+                        wrapping: text::Wrapping::None,
+                    },
+                    Point::new(label.x, label.center_y()),
+                    color,
+                    label,
+                );
+            }
+
+            if let Some(badge) = &entry.badge {
+                let center = if state.collapsed {
+                    let icon = icon.bounds();
+                    Point::new(icon.x + icon.width, icon.y)
+                } else {
+                    Point::new(row.x + row.width - inset * 2. - 10., row.center_y())
+                };
+
+                self.draw_badge(renderer, &style, badge, center, bounds);
+            }
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.entries
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((entry, tree), layout)| {
+                    entry
+                        .icon
+                        .as_widget()
+                        .operate(tree, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            iced::Event::Window(window::Event::RedrawRequested(now)) => {
+                let state = tree.state.downcast_mut::<State>();
+                let target = self.selected_offset();
+
+                state.indicator = match (state.indicator, target) {
+                    (Some(current), Some(target)) if (target - current).abs() > 0.5 => {
+                        let elapsed = state.clock.tick(now);
+
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                        Some(approach(current, target, INDICATOR_SPEED, elapsed))
+                    }
+                    (_, target) => {
+                        state.clock.stop();
+                        target
+                    }
+                };
+
+                event::Status::Ignored
+            }
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. }) => {
+                match self.hovered(bounds, cursor) {
+                    Some(index) => {
+                        shell.publish((self.on_select)(self.entries[index].key.clone()));
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.hovered(layout.bounds(), cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> From<TabRail<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq + 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(value: TabRail<'a, Key, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new empty [`TabRail`] with the given selected key.
+///
+/// `on_select` produces the message emitted when an entry is clicked.
+pub fn tab_rail<'a, Key, Message, Theme, Renderer>(
+    selected: Key,
+    on_select: impl Fn(Key) -> Message + 'a,
+) -> TabRail<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    TabRail::new(selected, on_select)
+}
@@ -0,0 +1,891 @@
+//! A data table with typed columns, sorting, selection and row virtualization.
+//!
+//! Unlike [`Grid`](crate::grid::Grid), a [`Table`] is built from a list of rows of any type and
+//! a list of [`Column`] definitions describing how to display a row. Only the rows that are
+//! visible are turned into elements, so the table stays fast with many rows.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced::{Element, widget::text};
+//! use more_iced_aw::table::*;
+//!
+//! struct Person {
+//!     name: String,
+//!     age: u32,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Select(usize),
+//! }
+//!
+//! fn view(people: &[Person]) -> Element<'_, Message> {
+//!     table(
+//!         vec![
+//!             column("Name", |person: &&Person| text(person.name.clone()).into())
+//!                 .sort_key(|person| person.name.clone()),
+//!             column("Age", |person: &&Person| text(person.age).into())
+//!                 .width(80)
+//!                 .sort_key(|person| person.age),
+//!         ],
+//!         people.iter().collect(),
+//!     )
+//!     .on_select(Message::Select)
+//!     .into()
+//! }
+//! ```
+
+use std::{cell::RefCell, cmp::Reverse};
+
+use iced::{
+    Background, Border, Color, Element, Length, Padding, Pixels, Point, Rectangle, Size, Theme,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, renderer,
+        text::{self, Text},
+        widget::{Operation, Tree, tree},
+    },
+    alignment, event, touch,
+};
+
+/// The appearance of a [`Table`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the header.
+    pub header_background: Background,
+    /// The color of the titles of the header.
+    pub header_text_color: Color,
+    /// The background of the rows.
+    pub row_background: Background,
+    /// The background of every other row, if different.
+    pub alternate_row_background: Option<Background>,
+    /// The background of the hovered row.
+    pub hovered_row_background: Background,
+    /// The background of the selected row.
+    pub selected_row_background: Background,
+    /// The color of the text of the rows.
+    pub text_color: Color,
+    /// The color of the text of the selected row.
+    pub selected_text_color: Color,
+    /// The color of the lines between the header and the rows.
+    pub divider: Color,
+    /// The color of the scrollbar.
+    pub scrollbar: Color,
+}
+
+/// The theme catalog of a [`Table`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Table`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Table`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        header_background: palette.background.weak.color.into(),
+        header_text_color: palette.background.weak.text,
+        row_background: palette.background.base.color.into(),
+        alternate_row_background: Some(palette.background.weak.color.scale_alpha(0.4).into()),
+        hovered_row_background: palette.background.strong.color.scale_alpha(0.5).into(),
+        selected_row_background: palette.primary.weak.color.into(),
+        text_color: palette.background.base.text,
+        selected_text_color: palette.primary.weak.text,
+        divider: palette.background.strong.color,
+        scrollbar: palette.background.strong.color,
+    }
+}
+
+/// The function displaying a row in a [`Column`].
+type Render<'a, Row, Message, Theme, Renderer> =
+    Box<dyn Fn(&Row) -> Element<'a, Message, Theme, Renderer> + 'a>;
+
+/// The function sorting the display order of the rows for a sortable [`Column`], descending if
+/// asked to.
+type Sort<'a, Row> = Box<dyn Fn(&[Row], &mut [usize], bool) + 'a>;
+
+/// A column of a [`Table`], created with [`column`].
+pub struct Column<'a, Row, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    title: String,
+    width: Length,
+    render: Render<'a, Row, Message, Theme, Renderer>,
+    sort: Option<Sort<'a, Row>>,
+}
+
+impl<'a, Row, Message, Theme, Renderer> Column<'a, Row, Message, Theme, Renderer> {
+    /// Creates a new [`Column`] with the given title, displaying a row with `render`.
+    pub fn new(
+        title: impl Into<String>,
+        render: impl Fn(&Row) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            width: Length::Fill,
+            render: Box::new(render),
+            sort: None,
+        }
+    }
+
+    /// Sets the width of the [`Column`].
+    ///
+    /// [`Length::Fill`] and [`Length::FillPortion`] share the space left by the fixed columns,
+    /// and [`Length::Shrink`] is treated as [`Length::Fill`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Makes the [`Column`] sortable by the key returned by `sort_key`.
+    ///
+    /// Clicking on the title of a sortable column sorts the rows, and clicking again reverses the order.
+    /// The key of each row is computed once per sort, and the order is kept until the sorted column,
+    /// the direction or the number of rows changes.
+    pub fn sort_key<K: Ord>(mut self, sort_key: impl Fn(&Row) -> K + 'a) -> Self {
+        self.sort = Some(Box::new(move |rows, order, descending| {
+            if descending {
+                order.sort_by_cached_key(|&row| Reverse(sort_key(&rows[row])));
+            } else {
+                order.sort_by_cached_key(|&row| sort_key(&rows[row]));
+            }
+        }));
+        self
+    }
+}
+
+/// Creates a new [`Column`] with the given title, displaying a row with `render`.
+pub fn column<'a, Row, Message, Theme, Renderer>(
+    title: impl Into<String>,
+    render: impl Fn(&Row) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> Column<'a, Row, Message, Theme, Renderer> {
+    Column::new(title, render)
+}
+
+/// The state of a [`Table`].
+#[derive(Debug, Clone, Default)]
+struct State {
+    /// The vertical scroll offset of the rows.
+    offset: f32,
+    /// The sorted column and whether the order is descending.
+    sort: Option<(usize, bool)>,
+    /// The display order of the rows, as indices in the rows given to the [`Table`].
+    order: Vec<usize>,
+    /// The sort and the number of rows the order was computed for, if it was.
+    ordered: Option<(Option<(usize, bool)>, usize)>,
+    /// The index of the selected row in the rows given to the [`Table`].
+    selected: Option<usize>,
+    /// Where the scrollbar was grabbed, relatively to the top of its thumb, while it is dragged.
+    scrollbar_grab: Option<f32>,
+}
+
+/// The elements of the visible rows of a [`Table`], built during layout.
+struct Visible<'a, Message, Theme, Renderer> {
+    /// The position in the display order of the first visible row.
+    first: usize,
+    /// The cells of the visible rows, row by row.
+    cells: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+/// A table of rows displayed with typed columns, created with [`table`].
+///
+/// Clicking a row selects it, and clicking the title of a sortable column sorts the rows.
+/// The rows are scrolled with the mouse wheel or the scrollbar.
+pub struct Table<'a, Row, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    columns: Vec<Column<'a, Row, Message, Theme, Renderer>>,
+    rows: Vec<Row>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    row_height: f32,
+    header_height: f32,
+    cell_padding: Padding,
+    text_size: Option<Pixels>,
+    class: Theme::Class<'a>,
+    visible: RefCell<Visible<'a, Message, Theme, Renderer>>,
+}
+
+impl<'a, Row, Message, Theme, Renderer> Table<'a, Row, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// The width of the scrollbar.
+    const SCROLLBAR_WIDTH: f32 = 8.;
+
+    /// Creates a new [`Table`] displaying the given rows with the given columns.
+    pub fn new(
+        columns: impl IntoIterator<Item = Column<'a, Row, Message, Theme, Renderer>>,
+        rows: Vec<Row>,
+    ) -> Self {
+        Self {
+            columns: columns.into_iter().collect(),
+            rows,
+            on_select: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            row_height: 32.,
+            header_height: 36.,
+            cell_padding: Padding::from([0, 8]),
+            text_size: None,
+            class: Theme::default(),
+            visible: RefCell::new(Visible {
+                first: 0,
+                cells: Vec::new(),
+            }),
+        }
+    }
+
+    /// Sets the message produced with the index of a row when it is selected.
+    ///
+    /// The index is the position of the row in the rows given to the [`Table`], whatever the sort order.
+    pub fn on_select(mut self, on_select: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the width of the [`Table`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Table`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the height of each row.
+    pub fn row_height(mut self, row_height: impl Into<Pixels>) -> Self {
+        self.row_height = row_height.into().0;
+        self
+    }
+
+    /// Sets the height of the header.
+    pub fn header_height(mut self, header_height: impl Into<Pixels>) -> Self {
+        self.header_height = header_height.into().0;
+        self
+    }
+
+    /// Sets the padding of the cells.
+    pub fn cell_padding(mut self, cell_padding: impl Into<Padding>) -> Self {
+        self.cell_padding = cell_padding.into();
+        self
+    }
+
+    /// Sets the text size of the titles of the header.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Table`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the horizontal position and width of each column, for the given total width.
+    fn column_spans(&self, width: f32) -> Vec<(f32, f32)> {
+        let fixed: f32 = self
+            .columns
+            .iter()
+            .filter_map(|column| match column.width {
+                Length::Fixed(width) => Some(width),
+                _ => None,
+            })
+            .sum();
+        let portions: u16 = self
+            .columns
+            .iter()
+            .filter(|column| !matches!(column.width, Length::Fixed(_)))
+            .map(|column| column.width.fill_factor().max(1))
+            .sum();
+        let share = (width - fixed).max(0.) / f32::from(portions.max(1));
+
+        let mut x = 0.;
+        self.columns
+            .iter()
+            .map(|column| {
+                let width = match column.width {
+                    Length::Fixed(width) => width,
+                    other => share * f32::from(other.fill_factor().max(1)),
+                };
+                let span = (x, width);
+                x += width;
+                span
+            })
+            .collect()
+    }
+
+    /// Updates the display order of the rows, if the sort or the number of rows changed.
+    fn order(&self, state: &mut State) {
+        let ordered = (state.sort, self.rows.len());
+        if state.ordered == Some(ordered) {
+            return;
+        }
+
+        state.order = (0..self.rows.len()).collect();
+
+        if let Some((column, descending)) = state.sort
+            && let Some(sort) = self.columns.get(column).and_then(|c| c.sort.as_ref())
+        {
+            sort(&self.rows, &mut state.order, descending);
+        }
+
+        state.ordered = Some(ordered);
+    }
+
+    /// Returns the bounds of the rows below the header.
+    fn body(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            y: bounds.y + self.header_height,
+            height: (bounds.height - self.header_height).max(0.),
+            ..bounds
+        }
+    }
+
+    /// Returns the largest scroll offset for the given body height.
+    fn max_offset(&self, body_height: f32) -> f32 {
+        (self.rows.len() as f32 * self.row_height - body_height).max(0.)
+    }
+
+    /// Returns the bounds of the scrollbar thumb, if the rows overflow.
+    fn scrollbar(&self, body: Rectangle, offset: f32) -> Option<Rectangle> {
+        let content = self.rows.len() as f32 * self.row_height;
+        if content <= body.height {
+            return None;
+        }
+
+        let height = (body.height * body.height / content).max(20.);
+        let y = body.y + (body.height - height) * offset / self.max_offset(body.height);
+
+        Some(Rectangle {
+            x: body.x + body.width - Self::SCROLLBAR_WIDTH,
+            y,
+            width: Self::SCROLLBAR_WIDTH,
+            height,
+        })
+    }
+
+    /// Returns the position in the display order of the row under the cursor, if any.
+    fn hovered_row(&self, body: Rectangle, offset: f32, cursor: mouse::Cursor) -> Option<usize> {
+        let position = cursor.position_over(body)?;
+        if position.x >= body.x + body.width - Self::SCROLLBAR_WIDTH
+            && self.scrollbar(body, offset).is_some()
+        {
+            return None;
+        }
+
+        let row = ((position.y - body.y + offset) / self.row_height) as usize;
+        (row < self.rows.len()).then_some(row)
+    }
+
+    /// Sets the scroll offset from the position of the cursor on the scrollbar.
+    fn drag_scrollbar(&self, state: &mut State, body: Rectangle, y: f32, grab: f32) {
+        let Some(thumb) = self.scrollbar(body, state.offset) else {
+            return;
+        };
+        let track = (body.height - thumb.height).max(f32::EPSILON);
+        let ratio = ((y - grab - body.y) / track).clamp(0., 1.);
+
+        state.offset = ratio * self.max_offset(body.height);
+    }
+}
+
+impl<'a, Row, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Table<'a, Row, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let content = self.header_height + self.rows.len() as f32 * self.row_height;
+        let size = limits.resolve(self.width, self.height, Size::new(0., content));
+
+        let body_height = (size.height - self.header_height).max(0.);
+        let state = tree.state.downcast_mut::<State>();
+        state.offset = state.offset.clamp(0., self.max_offset(body_height));
+        let offset = state.offset;
+
+        self.order(state);
+        let first = (offset / self.row_height) as usize;
+        let last =
+            (((offset + body_height) / self.row_height).ceil() as usize).min(state.order.len());
+        let first = first.min(last);
+
+        let cells: Vec<_> = state.order[first..last]
+            .iter()
+            .flat_map(|&row| {
+                self.columns
+                    .iter()
+                    .map(move |column| (column.render)(&self.rows[row]))
+            })
+            .collect();
+        tree.diff_children(&cells);
+
+        let spans = self.column_spans(size.width);
+        let nodes = cells
+            .iter()
+            .zip(&mut tree.children)
+            .enumerate()
+            .map(|(i, (cell, tree))| {
+                let (row, column) = (first + i / self.columns.len(), i % self.columns.len());
+                let (x, width) = spans[column];
+
+                let limits = Limits::new(
+                    Size::ZERO,
+                    Size::new(
+                        (width - self.cell_padding.horizontal()).max(0.),
+                        (self.row_height - self.cell_padding.vertical()).max(0.),
+                    ),
+                );
+                let node = cell.as_widget().layout(tree, renderer, &limits);
+                let height = node.size().height;
+
+                node.move_to(Point::new(
+                    x + self.cell_padding.left,
+                    self.header_height + row as f32 * self.row_height - offset
+                        + (self.row_height - height) / 2.,
+                ))
+            })
+            .collect();
+
+        *self.visible.borrow_mut() = Visible { first, cells };
+
+        Node::with_children(size, nodes)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let body = self.body(bounds);
+        let style = theme.style(&self.class);
+        let state = tree.state.downcast_ref::<State>();
+        let visible = self.visible.borrow();
+        let spans = self.column_spans(bounds.width);
+        let hovered = self.hovered_row(body, state.offset, cursor);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..Default::default()
+            },
+            style.row_background,
+        );
+
+        renderer.with_layer(body, |renderer| {
+            let rows = visible.cells.chunks(self.columns.len().max(1));
+
+            for (i, (cells, layouts)) in rows
+                .zip(
+                    layout
+                        .children()
+                        .zip(&tree.children)
+                        .collect::<Vec<_>>()
+                        .chunks(self.columns.len().max(1)),
+                )
+                .enumerate()
+            {
+                let position = visible.first + i;
+                let selected = state.selected == Some(state.order[position]);
+                let row = Rectangle {
+                    y: body.y + position as f32 * self.row_height - state.offset,
+                    height: self.row_height,
+                    ..body
+                };
+
+                let background = if selected {
+                    Some(style.selected_row_background)
+                } else if hovered == Some(position) {
+                    Some(style.hovered_row_background)
+                } else if position % 2 == 1 {
+                    style.alternate_row_background
+                } else {
+                    None
+                };
+
+                if let Some(background) = background {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: row,
+                            ..Default::default()
+                        },
+                        background,
+                    );
+                }
+
+                let text_color = if selected {
+                    style.selected_text_color
+                } else {
+                    style.text_color
+                };
+
+                for (cell, (layout, tree)) in cells.iter().zip(layouts) {
+                    cell.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        &renderer::Style { text_color },
+                        *layout,
+                        cursor,
+                        &body,
+                    );
+                }
+            }
+
+            if let Some(thumb) = self.scrollbar(body, state.offset) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: thumb.shrink(1.),
+                        border: Border::default().rounded(Self::SCROLLBAR_WIDTH / 2.),
+                        ..Default::default()
+                    },
+                    style.scrollbar,
+                );
+            }
+        });
+
+        let header = Rectangle {
+            height: self.header_height,
+            ..bounds
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: header,
+                ..Default::default()
+            },
+            style.header_background,
+        );
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    y: header.y + header.height - 1.,
+                    height: 1.,
+                    ..header
+                },
+                ..Default::default()
+            },
+            style.divider,
+        );
+
+        for (i, (column, (x, width))) in self.columns.iter().zip(spans).enumerate() {
+            let title = match state.sort {
+                Some((sorted, false)) if sorted == i => format!("{} ▲", column.title),
+                Some((sorted, true)) if sorted == i => format!("{} ▼", column.title),
+                _ => column.title.clone(),
+            };
+            let cell = Rectangle {
+                x: header.x + x + self.cell_padding.left,
+                width: (width - self.cell_padding.horizontal()).max(0.),
+                ..header
+            };
+
+            renderer.fill_text(
+                Text {
+                    content: title,
+                    bounds: cell.size(),
+                    size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+                    line_height: text::LineHeight::default(),
+                    font: renderer.default_font(),
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                },
+                Point::new(cell.x, cell.center_y()),
+                style.header_text_color,
+                cell.intersection(viewport).unwrap_or(cell),
+            );
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let visible = self.visible.borrow();
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            visible
+                .cells
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((cell, tree), layout)| {
+                    cell.as_widget().operate(tree, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let body = self.body(bounds);
+
+        let status = {
+            let cursor = if cursor.is_over(body) {
+                cursor
+            } else {
+                mouse::Cursor::Unavailable
+            };
+
+            self.visible
+                .get_mut()
+                .cells
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .map(|((cell, tree), layout)| {
+                    cell.as_widget_mut().on_event(
+                        tree,
+                        event.clone(),
+                        layout,
+                        cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                        &body,
+                    )
+                })
+                .fold(event::Status::Ignored, event::Status::merge)
+        };
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            iced::Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(body) => {
+                let dy = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * self.row_height,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+                let offset = (state.offset - dy).clamp(0., self.max_offset(body.height));
+
+                if offset != state.offset {
+                    state.offset = offset;
+                    shell.invalidate_layout();
+                    return event::Status::Captured;
+                }
+
+                event::Status::Ignored
+            }
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                if let Some(thumb) = self.scrollbar(body, state.offset)
+                    && position.x >= thumb.x
+                    && position.y >= body.y
+                {
+                    let grab = if thumb.contains(position) {
+                        position.y - thumb.y
+                    } else {
+                        thumb.height / 2.
+                    };
+                    state.scrollbar_grab = Some(grab);
+                    self.drag_scrollbar(state, body, position.y, grab);
+                    shell.invalidate_layout();
+                    return event::Status::Captured;
+                }
+
+                if position.y < body.y {
+                    let spans = self.column_spans(bounds.width);
+                    let column = spans
+                        .iter()
+                        .position(|&(x, width)| (x..x + width).contains(&(position.x - bounds.x)));
+
+                    if let Some(column) = column
+                        && self.columns[column].sort.is_some()
+                    {
+                        state.sort = match state.sort {
+                            Some((sorted, descending)) if sorted == column => {
+                                Some((column, !descending))
+                            }
+                            _ => Some((column, false)),
+                        };
+                        shell.invalidate_layout();
+                        return event::Status::Captured;
+                    }
+
+                    return event::Status::Ignored;
+                }
+
+                if let Some(position) = self.hovered_row(body, state.offset, cursor) {
+                    let row = state.order[position];
+                    state.selected = Some(row);
+                    if let Some(on_select) = &self.on_select {
+                        shell.publish(on_select(row));
+                    }
+                    return event::Status::Captured;
+                }
+
+                event::Status::Ignored
+            }
+            iced::Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(grab) = state.scrollbar_grab {
+                    self.drag_scrollbar(state, body, position.y, grab);
+                    shell.invalidate_layout();
+                    return event::Status::Captured;
+                }
+
+                event::Status::Ignored
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerLifted { .. })
+            | iced::Event::Touch(touch::Event::FingerLost { .. })
+                if state.scrollbar_grab.is_some() =>
+            {
+                state.scrollbar_grab = None;
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let body = self.body(bounds);
+        let visible = self.visible.borrow();
+
+        let interaction = visible
+            .cells
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((cell, tree), layout)| {
+                cell.as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction != mouse::Interaction::default() {
+            return interaction;
+        }
+
+        let state = tree.state.downcast_ref::<State>();
+        let sortable_title = cursor.position_over(bounds).is_some_and(|position| {
+            position.y < body.y
+                && self
+                    .column_spans(bounds.width)
+                    .iter()
+                    .zip(&self.columns)
+                    .any(|(&(x, width), column)| {
+                        column.sort.is_some() && (x..x + width).contains(&(position.x - bounds.x))
+                    })
+        });
+
+        if sortable_title || self.hovered_row(body, state.offset, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Row, Message, Theme, Renderer> From<Table<'a, Row, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Row: 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(value: Table<'a, Row, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`Table`] displaying the given rows with the given columns.
+pub fn table<'a, Row, Message, Theme, Renderer>(
+    columns: impl IntoIterator<Item = Column<'a, Row, Message, Theme, Renderer>>,
+    rows: Vec<Row>,
+) -> Table<'a, Row, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    Table::new(columns, rows)
+}
@@ -0,0 +1,534 @@
+//! A view of the output of a terminal program, rendering its ANSI colors.
+//!
+//! The output is fed by the application to a [`TerminalBuffer`], which parses the ANSI escape
+//! sequences. The [`TerminalView`] draws it in a monospaced font, only laying out the visible
+//! lines, so it stays fast with long outputs.
+//!
+//! Like the [`LogView`](crate::log_view::LogView), it follows the end of the output until the user
+//! scrolls up, and follows it again when they scroll back to the bottom.
+
+use iced::{
+    Background, Border, Color, Element, Font, Length, Padding, Pixels, Point, Rectangle, Size,
+    Theme,
+    advanced::{
+        Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, renderer,
+        text::{self, LineHeight, Paragraph, Shaping, Text, Wrapping},
+        widget::{Tree, tree},
+    },
+    alignment, color, event, font, touch,
+};
+
+mod buffer;
+pub use buffer::*;
+
+/// The appearance of a [`TerminalView`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the view.
+    pub background: Background,
+    /// The border of the view.
+    pub border: Border,
+    /// The color of the text without color.
+    pub text_color: Color,
+    /// The 16 colors of the ANSI palette: black, red, green, yellow, blue, magenta, cyan and white,
+    /// then their bright versions.
+    pub palette: [Color; 16],
+    /// The color of the scrollbar.
+    pub scrollbar: Color,
+}
+
+/// The theme catalog of a [`TerminalView`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`TerminalView`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The ANSI palette used by default, readable on both light and dark backgrounds.
+pub const PALETTE: [Color; 16] = [
+    color!(0x00, 0x00, 0x00),
+    color!(0xCD, 0x31, 0x31),
+    color!(0x0D, 0xBC, 0x79),
+    color!(0xE5, 0xE5, 0x10),
+    color!(0x24, 0x72, 0xC8),
+    color!(0xBC, 0x3F, 0xBC),
+    color!(0x11, 0xA8, 0xCD),
+    color!(0xE5, 0xE5, 0xE5),
+    color!(0x66, 0x66, 0x66),
+    color!(0xF1, 0x4C, 0x4C),
+    color!(0x23, 0xD1, 0x8B),
+    color!(0xF5, 0xF5, 0x43),
+    color!(0x3B, 0x8E, 0xEA),
+    color!(0xD6, 0x70, 0xD6),
+    color!(0x29, 0xB8, 0xDB),
+    color!(0xFF, 0xFF, 0xFF),
+];
+
+/// The default style of a [`TerminalView`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.,
+            radius: 2.into(),
+        },
+        text_color: palette.background.base.text,
+        palette: PALETTE,
+        scrollbar: palette.background.strong.color,
+    }
+}
+
+/// The state of a [`TerminalView`].
+#[derive(Debug, Clone)]
+struct State {
+    /// The vertical scroll offset, when not following.
+    offset: f32,
+    /// Whether the view follows the end of the output.
+    follow: bool,
+    /// Where the scrollbar was grabbed, relatively to the top of its thumb, while it is dragged.
+    scrollbar_grab: Option<f32>,
+}
+
+/// A virtualized view of a [`TerminalBuffer`], created with [`terminal_view`].
+pub struct TerminalView<'a, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    buffer: &'a TerminalBuffer,
+    text_size: Option<Pixels>,
+    font: Font,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+    renderer: std::marker::PhantomData<Renderer>,
+}
+
+impl<'a, Theme, Renderer> TerminalView<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    /// The width of the scrollbar.
+    const SCROLLBAR_WIDTH: f32 = 8.;
+
+    /// Creates a new [`TerminalView`] displaying the given [`TerminalBuffer`].
+    pub fn new(buffer: &'a TerminalBuffer) -> Self {
+        Self {
+            buffer,
+            text_size: None,
+            font: Font::MONOSPACE,
+            padding: Padding::new(4.),
+            width: Length::Fill,
+            height: Length::Fill,
+            class: Theme::default(),
+            renderer: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the text size of the [`TerminalView`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the [`TerminalView`]. It should be monospaced.
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the padding of the [`TerminalView`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`TerminalView`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`TerminalView`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`TerminalView`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`TerminalView`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the text size.
+    fn size(&self, renderer: &Renderer) -> Pixels {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    /// Returns the height of a line.
+    fn line_height(&self, renderer: &Renderer) -> f32 {
+        LineHeight::default().to_absolute(self.size(renderer)).0
+    }
+
+    /// Returns the width of a character of the monospaced font.
+    fn cell_width(&self, renderer: &Renderer) -> f32 {
+        Renderer::Paragraph::with_text(Text {
+            content: "M",
+            bounds: Size::INFINITY,
+            size: self.size(renderer),
+            line_height: LineHeight::default(),
+            font: self.font,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: Shaping::Basic,
+            wrapping: Wrapping::None,
+        })
+        .min_bounds()
+        .width
+    }
+
+    /// Returns the height of all the lines.
+    fn content_height(&self, renderer: &Renderer) -> f32 {
+        self.buffer.len() as f32 * self.line_height(renderer) + self.padding.vertical()
+    }
+
+    /// Returns the current scroll offset.
+    fn offset(&self, state: &State, bounds: Rectangle, renderer: &Renderer) -> f32 {
+        let max_offset = (self.content_height(renderer) - bounds.height).max(0.);
+
+        if state.follow {
+            max_offset
+        } else {
+            state.offset.min(max_offset)
+        }
+    }
+
+    /// Scrolls to the given offset, following the output if it is the bottom.
+    fn scroll_to(&self, state: &mut State, bounds: Rectangle, renderer: &Renderer, offset: f32) {
+        let max_offset = (self.content_height(renderer) - bounds.height).max(0.);
+
+        state.offset = offset.clamp(0., max_offset);
+        state.follow = state.offset >= max_offset - 0.5;
+    }
+
+    /// Returns the bounds of the scrollbar thumb, if the lines overflow.
+    fn scrollbar(
+        &self,
+        state: &State,
+        bounds: Rectangle,
+        renderer: &Renderer,
+    ) -> Option<Rectangle> {
+        let content_height = self.content_height(renderer);
+        if content_height <= bounds.height {
+            return None;
+        }
+
+        let max_offset = content_height - bounds.height;
+        let height = (bounds.height * bounds.height / content_height).max(20.);
+        let y =
+            bounds.y + (bounds.height - height) * self.offset(state, bounds, renderer) / max_offset;
+
+        Some(Rectangle {
+            x: bounds.x + bounds.width - Self::SCROLLBAR_WIDTH,
+            y,
+            width: Self::SCROLLBAR_WIDTH,
+            height,
+        })
+    }
+
+    /// Draws a run of cells with the same attributes.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_run(
+        &self,
+        renderer: &mut Renderer,
+        style: &Style,
+        cells: &[Cell],
+        position: Point,
+        cell_width: f32,
+        line_height: f32,
+        clip: Rectangle,
+    ) {
+        let attributes = cells[0].attributes;
+        let width = cells.len() as f32 * cell_width;
+
+        if let Some(background) = attributes.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle::new(position, Size::new(width, line_height)),
+                    ..Default::default()
+                },
+                background.resolve(&style.palette),
+            );
+        }
+
+        let content: String = cells.iter().map(|cell| cell.c).collect();
+        if content.trim().is_empty() {
+            return;
+        }
+
+        let font = if attributes.bold {
+            Font {
+                weight: font::Weight::Bold,
+                ..self.font
+            }
+        } else {
+            self.font
+        };
+
+        renderer.fill_text(
+            Text {
+                content,
+                bounds: Size::new(f32::INFINITY, line_height),
+                size: self.size(renderer),
+                line_height: LineHeight::Absolute(Pixels(line_height)),
+                font,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                shaping: Shaping::Basic,
+                wrapping: Wrapping::None,
+            },
+            position,
+            attributes
+                .foreground
+                .map_or(style.text_color, |color| color.resolve(&style.palette)),
+            clip,
+        );
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TerminalView<'_, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            offset: 0.,
+            follow: true,
+            scrollbar_grab: None,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, _tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let height = self.content_height(renderer);
+
+        Node::new(limits.resolve(self.width, self.height, Size::new(0., height)))
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..Default::default()
+            },
+            style.background,
+        );
+
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let line_height = self.line_height(renderer);
+        let cell_width = self.cell_width(renderer);
+        let top = bounds.y + self.padding.top - self.offset(state, bounds, renderer);
+        let first = ((clip.y - top) / line_height).floor().max(0.) as usize;
+        let last = (((clip.y + clip.height - top) / line_height).ceil().max(0.) as usize)
+            .min(self.buffer.len());
+
+        renderer.with_layer(clip, |renderer| {
+            for row in first..last {
+                let Some(line) = self.buffer.line(row) else {
+                    continue;
+                };
+                let y = top + row as f32 * line_height;
+
+                let mut start = 0;
+                for run in line.chunk_by(|a, b| a.attributes == b.attributes) {
+                    let position =
+                        Point::new(bounds.x + self.padding.left + start as f32 * cell_width, y);
+                    self.draw_run(
+                        renderer,
+                        &style,
+                        run,
+                        position,
+                        cell_width,
+                        line_height,
+                        clip,
+                    );
+                    start += run.len();
+                }
+            }
+
+            if let Some(thumb) = self.scrollbar(state, bounds, renderer) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: thumb.shrink(1.),
+                        border: Border::default().rounded(Self::SCROLLBAR_WIDTH / 2.),
+                        ..Default::default()
+                    },
+                    style.scrollbar,
+                );
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn iced::advanced::Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            iced::Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let dy = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * self.line_height(renderer) * 3.,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+                let offset = self.offset(state, bounds, renderer);
+                self.scroll_to(state, bounds, renderer, offset - dy);
+                shell.request_redraw(iced::window::RedrawRequest::NextFrame);
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+                let Some(thumb) = self.scrollbar(state, bounds, renderer) else {
+                    return event::Status::Ignored;
+                };
+                if position.x < thumb.x {
+                    return event::Status::Ignored;
+                }
+
+                let grab = if thumb.contains(position) {
+                    position.y - thumb.y
+                } else {
+                    thumb.height / 2.
+                };
+                state.scrollbar_grab = Some(grab);
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let Some(grab) = state.scrollbar_grab else {
+                    return event::Status::Ignored;
+                };
+                let Some(thumb) = self.scrollbar(state, bounds, renderer) else {
+                    return event::Status::Ignored;
+                };
+                let track = (bounds.height - thumb.height).max(f32::EPSILON);
+                let ratio = ((position.y - grab - bounds.y) / track).clamp(0., 1.);
+                let max_offset = self.content_height(renderer) - bounds.height;
+
+                self.scroll_to(state, bounds, renderer, ratio * max_offset);
+                shell.request_redraw(iced::window::RedrawRequest::NextFrame);
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerLifted { .. })
+            | iced::Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.scrollbar_grab = None;
+                event::Status::Ignored
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TerminalView<'a, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = Font> + 'a,
+{
+    fn from(value: TerminalView<'a, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`TerminalView`] displaying the given [`TerminalBuffer`].
+///
+/// ```
+/// use more_iced_aw::terminal_view::TerminalBuffer;
+///
+/// let mut buffer = TerminalBuffer::new(1000);
+/// buffer.feed(b"\x1b[1;31merror\x1b[0m: not found\r\n50%\r100%");
+///
+/// assert_eq!(buffer.text(), "error: not found\n100%");
+/// ```
+pub fn terminal_view<'a, Theme, Renderer>(
+    buffer: &'a TerminalBuffer,
+) -> TerminalView<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    TerminalView::new(buffer)
+}
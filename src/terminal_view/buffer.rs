@@ -0,0 +1,371 @@
+use std::collections::VecDeque;
+
+use iced::Color;
+
+/// A color set by an ANSI escape sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AnsiColor {
+    /// A color of the 256 colors palette. The first 16 ones come from the
+    /// [`Style`](super::Style) of the view.
+    Indexed(u8),
+    /// A true color.
+    Rgb(u8, u8, u8),
+}
+
+impl AnsiColor {
+    /// Returns the actual color, taking the first 16 colors from the given palette.
+    pub fn resolve(self, palette: &[Color; 16]) -> Color {
+        match self {
+            Self::Indexed(index @ 0..16) => palette[usize::from(index)],
+            Self::Indexed(index @ 16..232) => {
+                let level = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+                let index = index - 16;
+                Color::from_rgb8(level(index / 36), level(index / 6 % 6), level(index % 6))
+            }
+            Self::Indexed(index) => {
+                let gray = 8 + (index - 232) * 10;
+                Color::from_rgb8(gray, gray, gray)
+            }
+            Self::Rgb(r, g, b) => Color::from_rgb8(r, g, b),
+        }
+    }
+}
+
+/// The attributes of a character of a [`TerminalBuffer`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Attributes {
+    /// The color of the character, or the default one.
+    pub foreground: Option<AnsiColor>,
+    /// The color behind the character, or none.
+    pub background: Option<AnsiColor>,
+    /// Whether the character is bold.
+    pub bold: bool,
+}
+
+/// A character of a [`TerminalBuffer`] with its [`Attributes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cell {
+    /// The character.
+    pub c: char,
+    /// The attributes of the character.
+    pub attributes: Attributes,
+}
+
+impl Cell {
+    const BLANK: Self = Self {
+        c: ' ',
+        attributes: Attributes {
+            foreground: None,
+            background: None,
+            bold: false,
+        },
+    };
+}
+
+/// Where the parser is in an escape sequence.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+enum Parser {
+    #[default]
+    Ground,
+    /// After `ESC`.
+    Escape,
+    /// In a control sequence, after `ESC [`.
+    Csi { params: Vec<u16>, private: bool },
+    /// In an operating system command, after `ESC ]`.
+    Osc,
+    /// After `ESC` in an operating system command.
+    OscEscape,
+}
+
+/// The output displayed by a [`TerminalView`](super::TerminalView).
+///
+/// Bytes are fed with [`feed`](Self::feed), which interprets the ANSI escape sequences:
+/// - the colors (16, 256 and true colors) and bold of the SGR sequence (`ESC [ ... m`),
+/// - the cursor moves in the line (`ESC [ n C`, `ESC [ n D`, `ESC [ n G`),
+/// - the line erasing (`ESC [ n K`),
+/// - carriage returns, backspaces and tabs.
+///
+/// Other sequences are ignored. Line feeds also return to the start of the line, like a terminal
+/// in cooked mode. When the buffer is full, the oldest lines are dropped.
+///
+/// The cursor moves and the tabs stop at the column [`MAX_COLUMN`](Self::MAX_COLUMN), or at the
+/// end of the line if it is longer, so that they cannot grow the lines without bound.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TerminalBuffer {
+    lines: VecDeque<Vec<Cell>>,
+    capacity: usize,
+    column: usize,
+    attributes: Attributes,
+    parser: Parser,
+    utf8: Vec<u8>,
+}
+
+impl TerminalBuffer {
+    /// The width of a tab stop.
+    const TAB: usize = 8;
+
+    /// The column beyond which the cursor moves and the tabs stop.
+    pub const MAX_COLUMN: usize = 1024;
+
+    /// Creates a new empty [`TerminalBuffer`] keeping at most `capacity` lines.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            lines: VecDeque::from([Vec::new()]),
+            capacity: capacity.max(1),
+            column: 0,
+            attributes: Attributes::default(),
+            parser: Parser::Ground,
+            utf8: Vec::new(),
+        }
+    }
+
+    /// Feeds bytes of output.
+    ///
+    /// The bytes can be cut anywhere, even in an escape sequence or a UTF-8 character.
+    pub fn feed(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.byte(byte);
+        }
+    }
+
+    /// Removes all the lines and resets the attributes.
+    pub fn clear(&mut self) {
+        *self = Self::new(self.capacity);
+    }
+
+    /// Returns the lines of the buffer, from the oldest.
+    pub fn lines(&self) -> impl DoubleEndedIterator<Item = &[Cell]> {
+        self.lines.iter().map(Vec::as_slice)
+    }
+
+    /// Returns the line at the given index.
+    pub fn line(&self, index: usize) -> Option<&[Cell]> {
+        self.lines.get(index).map(Vec::as_slice)
+    }
+
+    /// Returns the number of lines in the buffer, including the line being written.
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns whether nothing was written in the buffer.
+    pub fn is_empty(&self) -> bool {
+        self.lines.len() == 1 && self.lines[0].is_empty()
+    }
+
+    /// Returns the maximum number of lines of the buffer.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the text of the buffer, without the attributes.
+    pub fn text(&self) -> String {
+        self.lines
+            .iter()
+            .map(|line| line.iter().map(|cell| cell.c).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn byte(&mut self, byte: u8) {
+        match std::mem::take(&mut self.parser) {
+            Parser::Ground => self.ground(byte),
+            Parser::Escape => {
+                self.parser = match byte {
+                    b'[' => Parser::Csi {
+                        params: Vec::new(),
+                        private: false,
+                    },
+                    b']' => Parser::Osc,
+                    _ => Parser::Ground,
+                }
+            }
+            Parser::Csi {
+                mut params,
+                mut private,
+            } => match byte {
+                b'0'..=b'9' => {
+                    if params.is_empty() {
+                        params.push(0);
+                    }
+                    if let Some(param) = params.last_mut() {
+                        *param = param
+                            .saturating_mul(10)
+                            .saturating_add((byte - b'0').into());
+                    }
+                    self.parser = Parser::Csi { params, private };
+                }
+                b';' => {
+                    if params.is_empty() {
+                        params.push(0);
+                    }
+                    params.push(0);
+                    self.parser = Parser::Csi { params, private };
+                }
+                0x3c..=0x3f | 0x20..=0x2f | b':' => {
+                    private |= byte >= 0x3c;
+                    self.parser = Parser::Csi { params, private };
+                }
+                0x40..=0x7e if !private => self.control(byte, &params),
+                0x40..=0x7e => {}
+                // Malformed sequence.
+                _ => self.ground(byte),
+            },
+            Parser::Osc => {
+                self.parser = match byte {
+                    0x07 => Parser::Ground,
+                    0x1b => Parser::OscEscape,
+                    _ => Parser::Osc,
+                }
+            }
+            Parser::OscEscape => {}
+        }
+    }
+
+    fn ground(&mut self, byte: u8) {
+        if !self.utf8.is_empty() && byte & 0xc0 != 0x80 {
+            // Interrupted UTF-8 character.
+            self.utf8.clear();
+            self.print(char::REPLACEMENT_CHARACTER);
+        }
+
+        match byte {
+            0x1b => self.parser = Parser::Escape,
+            b'\n' => self.new_line(),
+            b'\r' => self.column = 0,
+            b'\t' => self.move_to((self.column / Self::TAB + 1) * Self::TAB),
+            0x08 => self.column = self.column.saturating_sub(1),
+            0x00..0x20 | 0x7f => {}
+            0x20..0x7f => self.print(byte.into()),
+            _ => {
+                self.utf8.push(byte);
+
+                let expected = match self.utf8[0] {
+                    0xc0..0xe0 => 2,
+                    0xe0..0xf0 => 3,
+                    0xf0..0xf8 => 4,
+                    _ => 1,
+                };
+                if self.utf8.len() >= expected {
+                    let c = std::str::from_utf8(&self.utf8)
+                        .ok()
+                        .and_then(|s| s.chars().next())
+                        .unwrap_or(char::REPLACEMENT_CHARACTER);
+                    self.utf8.clear();
+                    self.print(c);
+                }
+            }
+        }
+    }
+
+    /// Executes the final byte of a control sequence.
+    fn control(&mut self, byte: u8, params: &[u16]) {
+        let first = params.first().copied().unwrap_or(0);
+        let count = usize::from(first.max(1));
+
+        match byte {
+            b'm' => self.select_graphic_rendition(params),
+            b'C' => self.move_to(self.column.saturating_add(count)),
+            b'D' => self.column = self.column.saturating_sub(count),
+            b'G' => self.move_to(count - 1),
+            b'K' => {
+                let column = self.column;
+                let line = self.current_line();
+                match first {
+                    0 => line.truncate(column),
+                    1 => line
+                        .iter_mut()
+                        .take(column + 1)
+                        .for_each(|cell| *cell = Cell::BLANK),
+                    _ => line.clear(),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn select_graphic_rendition(&mut self, params: &[u16]) {
+        if params.is_empty() {
+            self.attributes = Attributes::default();
+        }
+
+        let mut params = params.iter().copied();
+
+        while let Some(param) = params.next() {
+            match param {
+                0 => self.attributes = Attributes::default(),
+                1 => self.attributes.bold = true,
+                22 => self.attributes.bold = false,
+                30..=37 => self.attributes.foreground = Some(AnsiColor::Indexed(param as u8 - 30)),
+                38 => self.attributes.foreground = extended_color(&mut params),
+                39 => self.attributes.foreground = None,
+                40..=47 => self.attributes.background = Some(AnsiColor::Indexed(param as u8 - 40)),
+                48 => self.attributes.background = extended_color(&mut params),
+                49 => self.attributes.background = None,
+                90..=97 => {
+                    self.attributes.foreground = Some(AnsiColor::Indexed(param as u8 - 90 + 8))
+                }
+                100..=107 => {
+                    self.attributes.background = Some(AnsiColor::Indexed(param as u8 - 100 + 8))
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn current_line(&mut self) -> &mut Vec<Cell> {
+        if self.lines.is_empty() {
+            self.lines.push_back(Vec::new());
+        }
+        self.lines.back_mut().expect("there is a line")
+    }
+
+    /// Moves the cursor to a column, stopping at [`Self::MAX_COLUMN`] or at the end of the line.
+    fn move_to(&mut self, column: usize) {
+        let end = self.current_line().len().max(Self::MAX_COLUMN);
+        self.column = column.min(end);
+    }
+
+    fn print(&mut self, c: char) {
+        let column = self.column;
+        let cell = Cell {
+            c,
+            attributes: self.attributes,
+        };
+        let line = self.current_line();
+
+        if column < line.len() {
+            line[column] = cell;
+        } else {
+            line.resize(column, Cell::BLANK);
+            line.push(cell);
+        }
+        self.column += 1;
+    }
+
+    fn new_line(&mut self) {
+        if self.lines.len() == self.capacity {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(Vec::new());
+        self.column = 0;
+    }
+}
+
+/// Reads the color of the `38` and `48` SGR parameters.
+fn extended_color(params: &mut impl Iterator<Item = u16>) -> Option<AnsiColor> {
+    let channel = |value: Option<u16>| value.unwrap_or(0).min(255) as u8;
+
+    match params.next() {
+        Some(5) => Some(AnsiColor::Indexed(channel(params.next()))),
+        Some(2) => Some(AnsiColor::Rgb(
+            channel(params.next()),
+            channel(params.next()),
+            channel(params.next()),
+        )),
+        _ => None,
+    }
+}
@@ -0,0 +1,669 @@
+//! A chronological list of entries along a vertical line.
+//!
+//! A [`Timeline`] displays its entries in order, each with a marker on a vertical line. The line
+//! is either at the left or at the right of the entries, or in the middle with the entries
+//! alternating between its sides, see [`Placement`].
+//!
+//! The consecutive entries of the same group, usually a date, are displayed under a header with
+//! the name of the group. Clicking a header collapses or expands the period it starts.
+
+use std::collections::HashSet;
+
+use iced::{
+    Background, Border, Color, Element, Length, Padding, Pixels, Point, Rectangle, Size, Theme,
+    Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        text::{self, LineHeight, Paragraph, Shaping, Text, Wrapping},
+        widget::{Operation, Tree, tree},
+    },
+    alignment, event,
+};
+
+/// Where the entries of a [`Timeline`] are placed relative to its line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Placement {
+    /// The line is at the left, and the entries at its right.
+    #[default]
+    Right,
+    /// The line is at the right, and the entries at its left.
+    Left,
+    /// The line is in the middle, and the entries alternate between its right and its left.
+    Alternate,
+}
+
+/// The appearance of a [`Timeline`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color of the line.
+    pub line: Color,
+    /// The color of the markers.
+    pub marker: Color,
+    /// The border of the markers.
+    pub marker_border: Border,
+    /// The background of the headers.
+    pub header_background: Background,
+    /// The color of the text of the headers.
+    pub header_text: Color,
+}
+
+/// The theme catalog of a [`Timeline`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Timeline`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Timeline`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        line: palette.background.strong.color,
+        marker: palette.primary.base.color,
+        marker_border: Border {
+            color: palette.background.base.color,
+            width: 2.,
+            radius: f32::MAX.into(),
+        },
+        header_background: palette.background.weak.color.into(),
+        header_text: palette.background.weak.text,
+    }
+}
+
+/// The state of a [`Timeline`].
+#[derive(Debug, Clone, Default)]
+struct State {
+    collapsed: HashSet<String>,
+}
+
+/// A vertical timeline of entries, created with [`timeline`].
+pub struct Timeline<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    entries: Vec<(String, Element<'a, Message, Theme, Renderer>)>,
+    placement: Placement,
+    collapsed: Vec<String>,
+    on_toggle: Option<Box<dyn Fn(String, bool) -> Message + 'a>>,
+    spacing: f32,
+    gap: f32,
+    marker_size: f32,
+    text_size: Pixels,
+    font: Option<Renderer::Font>,
+    width: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Timeline<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The padding of the headers.
+    const HEADER_PADDING: Padding = Padding {
+        top: 2.,
+        right: 8.,
+        bottom: 2.,
+        left: 8.,
+    };
+
+    /// Creates a new empty [`Timeline`].
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            placement: Placement::default(),
+            collapsed: Vec::new(),
+            on_toggle: None,
+            spacing: 12.,
+            gap: 16.,
+            marker_size: 12.,
+            text_size: Pixels(14.),
+            font: None,
+            width: Length::Fill,
+            class: Theme::default(),
+        }
+    }
+
+    /// Adds an entry to the [`Timeline`], in the given group.
+    ///
+    /// The entries are displayed in the order they are pushed, so they should be pushed
+    /// chronologically. A header is displayed before each run of entries of the same group.
+    pub fn push(
+        mut self,
+        group: impl Into<String>,
+        entry: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.entries.push((group.into(), entry.into()));
+        self
+    }
+
+    /// Adds entries to the [`Timeline`], with their group.
+    pub fn extend(
+        self,
+        entries: impl IntoIterator<
+            Item = (
+                impl Into<String>,
+                impl Into<Element<'a, Message, Theme, Renderer>>,
+            ),
+        >,
+    ) -> Self {
+        entries
+            .into_iter()
+            .fold(self, |timeline, (group, entry)| timeline.push(group, entry))
+    }
+
+    /// Sets the [`Placement`] of the entries.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the groups that are collapsed when the [`Timeline`] is first displayed.
+    pub fn collapsed(mut self, groups: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.collapsed = groups.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the message produced when a group is collapsed or expanded, with the group and whether
+    /// it is now collapsed.
+    pub fn on_toggle(mut self, on_toggle: impl Fn(String, bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the vertical spacing between the entries.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the horizontal gap between the line and the entries.
+    pub fn gap(mut self, gap: impl Into<Pixels>) -> Self {
+        self.gap = gap.into().0;
+        self
+    }
+
+    /// Sets the size of the markers.
+    pub fn marker_size(mut self, marker_size: impl Into<Pixels>) -> Self {
+        self.marker_size = marker_size.into().0;
+        self
+    }
+
+    /// Sets the text size of the headers.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = text_size.into();
+        self
+    }
+
+    /// Sets the font of the headers.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the width of the [`Timeline`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the style of the [`Timeline`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Timeline`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the groups of consecutive entries, with the range of their entries.
+    fn groups(&self) -> Vec<(&str, std::ops::Range<usize>)> {
+        let mut groups: Vec<(&str, std::ops::Range<usize>)> = Vec::new();
+
+        for (index, (group, _)) in self.entries.iter().enumerate() {
+            match groups.last_mut() {
+                Some((last, range)) if *last == group => range.end = index + 1,
+                _ => groups.push((group, index..index + 1)),
+            }
+        }
+
+        groups
+    }
+
+    /// Returns the horizontal position of the line in a timeline of the given width.
+    fn line_x(&self, width: f32) -> f32 {
+        match self.placement {
+            Placement::Right => self.marker_size / 2. + 1.,
+            Placement::Left => width - self.marker_size / 2. - 1.,
+            Placement::Alternate => width / 2.,
+        }
+    }
+
+    /// Returns whether the entry at the given index is at the right of the line.
+    fn at_right(&self, index: usize) -> bool {
+        match self.placement {
+            Placement::Right => true,
+            Placement::Left => false,
+            Placement::Alternate => index.is_multiple_of(2),
+        }
+    }
+
+    /// Returns the text of the header of a group.
+    fn header_text<T>(&self, content: T, renderer: &Renderer) -> Text<T, Renderer::Font> {
+        Text {
+            content,
+            bounds: Size::INFINITY,
+            size: self.text_size,
+            line_height: LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: Shaping::Advanced,
+            wrapping: Wrapping::None,
+        }
+    }
+
+    /// Returns the label of the header of a group.
+    fn header_label(group: &str, count: usize, collapsed: bool) -> String {
+        if collapsed {
+            format!("▸ {group} ({count})")
+        } else {
+            format!("▾ {group}")
+        }
+    }
+
+    /// Returns the groups with the layouts of their headers.
+    fn headers<'b>(&'b self, layout: Layout<'b>) -> impl Iterator<Item = (&'b str, Layout<'b>)> {
+        self.groups()
+            .into_iter()
+            .map(|(group, _)| group)
+            .zip(layout.children().skip(self.entries.len()))
+    }
+}
+
+impl<Message, Theme, Renderer> Default for Timeline<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Timeline<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            collapsed: self.collapsed.iter().cloned().collect(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.entries
+            .iter()
+            .map(|(_, entry)| Tree::new(entry))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(
+            &self
+                .entries
+                .iter()
+                .map(|(_, entry)| entry)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let state = tree.state.downcast_ref::<State>();
+        let width = limits.width(self.width).max().width;
+        let line_x = self.line_x(width);
+
+        let content_width = match self.placement {
+            Placement::Alternate => width / 2. - self.gap,
+            _ => width - self.marker_size - 2. - self.gap,
+        }
+        .max(0.);
+
+        let mut entries = vec![Node::default(); self.entries.len()];
+        let mut headers = Vec::new();
+        let mut y = 0.;
+
+        for (group, range) in self.groups() {
+            let collapsed = state.collapsed.contains(group);
+
+            let label = Self::header_label(group, range.len(), collapsed);
+            let text = Renderer::Paragraph::with_text(self.header_text(label.as_str(), renderer));
+            let size = text.min_bounds().expand(Self::HEADER_PADDING);
+            let x = match self.placement {
+                Placement::Right => line_x + self.marker_size / 2. + self.gap,
+                Placement::Left => line_x - self.marker_size / 2. - self.gap - size.width,
+                Placement::Alternate => line_x - size.width / 2.,
+            };
+
+            headers.push(Node::new(size).move_to(Point::new(x.max(0.), y)));
+            y += size.height + self.spacing;
+
+            if collapsed {
+                continue;
+            }
+
+            for index in range {
+                let limits = Limits::new(Size::ZERO, Size::new(content_width, f32::INFINITY));
+                let node = self.entries[index].1.as_widget().layout(
+                    &mut tree.children[index],
+                    renderer,
+                    &limits,
+                );
+
+                let x = if self.at_right(index) {
+                    line_x + self.marker_size / 2. + self.gap
+                } else {
+                    line_x - self.marker_size / 2. - self.gap - node.size().width
+                };
+
+                entries[index] = node.move_to(Point::new(x.max(0.), y));
+                y += entries[index].size().height + self.spacing;
+            }
+        }
+
+        let height = (y - self.spacing).max(0.);
+        entries.extend(headers);
+
+        Node::with_children(
+            limits.resolve(self.width, Length::Shrink, Size::new(width, height)),
+            entries,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = theme.style(&self.class);
+        let line_x = bounds.x + self.line_x(bounds.width);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle::new(
+                    Point::new(line_x - 1., bounds.y),
+                    Size::new(2., bounds.height),
+                ),
+                ..Default::default()
+            },
+            appearance.line,
+        );
+
+        for ((group, range), (_, header)) in self.groups().into_iter().zip(self.headers(layout)) {
+            let collapsed = state.collapsed.contains(group);
+            let header_bounds = header.bounds();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: header_bounds,
+                    border: Border::default().rounded(header_bounds.height / 2.),
+                    ..Default::default()
+                },
+                appearance.header_background,
+            );
+            let label = Self::header_label(group, range.len(), collapsed);
+            renderer.fill_text(
+                self.header_text(label, renderer),
+                header_bounds.shrink(Self::HEADER_PADDING).position(),
+                appearance.header_text,
+                *viewport,
+            );
+
+            if collapsed {
+                continue;
+            }
+
+            for index in range {
+                let entry = layout.children().nth(index).expect("Missing entry layout");
+                let center = Point::new(
+                    line_x,
+                    entry.bounds().y + self.marker_size.max(self.text_size.0) / 2.,
+                );
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle::new(
+                            center - Vector::new(self.marker_size, self.marker_size) * 0.5,
+                            Size::new(self.marker_size, self.marker_size),
+                        ),
+                        border: appearance.marker_border,
+                        ..Default::default()
+                    },
+                    appearance.marker,
+                );
+
+                self.entries[index].1.as_widget().draw(
+                    &tree.children[index],
+                    renderer,
+                    theme,
+                    style,
+                    entry,
+                    cursor,
+                    viewport,
+                );
+            }
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.entries
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|(((_, entry), tree), layout)| {
+                    entry.as_widget().operate(tree, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event
+            && let Some(group) = self
+                .headers(layout)
+                .find(|(_, header)| cursor.is_over(header.bounds()))
+                .map(|(group, _)| group.to_owned())
+        {
+            let state = tree.state.downcast_mut::<State>();
+            let collapsed = !state.collapsed.remove(&group);
+            if collapsed {
+                state.collapsed.insert(group.clone());
+            }
+
+            if let Some(on_toggle) = &self.on_toggle {
+                shell.publish(on_toggle(group, collapsed));
+            }
+            shell.invalidate_layout();
+            return event::Status::Captured;
+        }
+
+        let collapsed = &tree.state.downcast_ref::<State>().collapsed;
+        let visible: Vec<bool> = self
+            .entries
+            .iter()
+            .map(|(group, _)| !collapsed.contains(group))
+            .collect();
+
+        self.entries
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .zip(visible)
+            .filter(|(_, visible)| *visible)
+            .map(|((((_, entry), tree), layout), _)| {
+                entry.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self
+            .headers(layout)
+            .any(|(_, header)| cursor.is_over(header.bounds()))
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        let collapsed = &tree.state.downcast_ref::<State>().collapsed;
+
+        self.entries
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .filter(|(((group, _), _), _)| !collapsed.contains(group))
+            .map(|(((_, entry), tree), layout)| {
+                entry
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let collapsed = tree.state.downcast_ref::<State>().collapsed.clone();
+
+        let children = self
+            .entries
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter(|(((group, _), _), _)| !collapsed.contains(group))
+            .filter_map(|(((_, entry), tree), layout)| {
+                entry
+                    .as_widget_mut()
+                    .overlay(tree, layout, renderer, translation)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Timeline<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(value: Timeline<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new empty [`Timeline`].
+///
+/// ```
+/// use iced::widget::text;
+/// use more_iced_aw::timeline::{Placement, Timeline, timeline};
+///
+/// let timeline: Timeline<'_, ()> = timeline()
+///     .placement(Placement::Alternate)
+///     .push("Monday", text("Project started"))
+///     .push("Monday", text("First commit"))
+///     .push("Tuesday", text("First release"));
+/// ```
+pub fn timeline<'a, Message, Theme, Renderer>() -> Timeline<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    Timeline::new()
+}
@@ -0,0 +1,598 @@
+//! A title bar for windows without decorations.
+//!
+//! A [`Titlebar`] replaces the title bar drawn by the system when the window is created with
+//! `decorations: false`. Pressing its empty region drags the window, double clicking it maximizes
+//! the window, and its buttons minimize, maximize and close it. Each interaction produces an
+//! [`Action`], turned into the matching window [`Task`] by [`Action::task`].
+//!
+//! The buttons are on the right, except on macOS where they are on the left, in the order of the
+//! platform (see [`ButtonOrder`]). Menus or tabs can be placed in the title bar as its content.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iced::{Element, Task, widget::{column, text}, window};
+//! use more_iced_aw::titlebar::{Action, titlebar};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Window(Action),
+//! }
+//!
+//! fn update(_state: &mut (), message: Message) -> Task<Message> {
+//!     let Message::Window(action) = message;
+//!     window::get_latest().and_then(move |id| action.task(id))
+//! }
+//!
+//! fn view(_state: &()) -> Element<'_, Message> {
+//!     column![titlebar("My app", Message::Window), text("Hello")].into()
+//! }
+//!
+//! fn main() -> iced::Result {
+//!     iced::application("My app", update, view)
+//!         .decorations(false)
+//!         .run()
+//! }
+//! ```
+
+use iced::{
+    Background, Color, Element, Length, Pixels, Point, Rectangle, Size, Task, Theme, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse::{self, Click, click},
+        overlay, renderer,
+        text::{self, LineHeight, Shaping, Text, Wrapping},
+        widget::{Operation, Tree, tree},
+    },
+    alignment, event, touch, window,
+};
+
+/// An interaction with a [`Titlebar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// The window should be dragged, the mouse button being held.
+    Drag,
+    /// The window should be minimized.
+    Minimize,
+    /// The window should be maximized, or restored if it is.
+    ToggleMaximize,
+    /// The window should be closed.
+    Close,
+}
+
+impl Action {
+    /// Returns the [`Task`] performing the [`Action`] on the given window.
+    pub fn task<T>(self, window: window::Id) -> Task<T> {
+        match self {
+            Action::Drag => window::drag(window),
+            Action::Minimize => window::minimize(window, true),
+            Action::ToggleMaximize => window::toggle_maximize(window),
+            Action::Close => window::close(window),
+        }
+    }
+}
+
+/// The position and order of the buttons of a [`Titlebar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ButtonOrder {
+    /// Close, minimize and maximize, on the left, as on macOS.
+    Leading,
+    /// Minimize, maximize and close, on the right, as on Windows and Linux.
+    Trailing,
+}
+
+impl ButtonOrder {
+    /// Returns the order of the buttons of the current platform.
+    pub fn platform() -> Self {
+        if cfg!(target_os = "macos") {
+            Self::Leading
+        } else {
+            Self::Trailing
+        }
+    }
+
+    /// Returns the buttons, from left to right.
+    fn buttons(self) -> [Action; 3] {
+        match self {
+            Self::Leading => [Action::Close, Action::Minimize, Action::ToggleMaximize],
+            Self::Trailing => [Action::Minimize, Action::ToggleMaximize, Action::Close],
+        }
+    }
+}
+
+impl Default for ButtonOrder {
+    fn default() -> Self {
+        Self::platform()
+    }
+}
+
+/// The appearance of a [`Titlebar`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the [`Titlebar`].
+    pub background: Background,
+    /// The color of the title and of the buttons.
+    pub text_color: Color,
+    /// The background of a hovered button.
+    pub button_hovered: Background,
+    /// The background of the hovered close button.
+    pub close_hovered: Background,
+    /// The color of the hovered close button.
+    pub close_hovered_text: Color,
+}
+
+/// The theme catalog of a [`Titlebar`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Titlebar`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Titlebar`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weak.color.into(),
+        text_color: palette.background.weak.text,
+        button_hovered: palette.background.strong.color.into(),
+        close_hovered: palette.danger.base.color.into(),
+        close_hovered_text: palette.danger.base.text,
+    }
+}
+
+/// The state of a [`Titlebar`].
+#[derive(Debug, Clone, Default)]
+struct State {
+    last_click: Option<Click>,
+}
+
+/// A title bar for a window without decorations, created with [`titlebar`].
+pub struct Titlebar<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    title: String,
+    content: Option<Element<'a, Message, Theme, Renderer>>,
+    on_action: Box<dyn Fn(Action) -> Message + 'a>,
+    order: ButtonOrder,
+    maximized: bool,
+    height: f32,
+    text_size: Pixels,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Titlebar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`Titlebar`] with the given title.
+    ///
+    /// `on_action` is called with the [`Action`] of each interaction.
+    pub fn new(title: impl Into<String>, on_action: impl Fn(Action) -> Message + 'a) -> Self {
+        Self {
+            title: title.into(),
+            content: None,
+            on_action: Box::new(on_action),
+            order: ButtonOrder::platform(),
+            maximized: false,
+            height: 32.,
+            text_size: Pixels(14.),
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the content of the [`Titlebar`], such as menus or tabs, placed next to the buttons.
+    ///
+    /// The events captured by the content do not drag the window.
+    pub fn content(mut self, content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    /// Sets the order of the buttons. The one of the platform by default.
+    pub fn order(mut self, order: ButtonOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sets whether the window is maximized, which shows the restore button instead of the
+    /// maximize one.
+    pub fn maximized(mut self, maximized: bool) -> Self {
+        self.maximized = maximized;
+        self
+    }
+
+    /// Sets the height of the [`Titlebar`].
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = height.into().0;
+        self
+    }
+
+    /// Sets the text size of the title and of the buttons.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = text_size.into();
+        self
+    }
+
+    /// Sets the font of the title.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Titlebar`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Titlebar`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the width of a button.
+    fn button_width(&self) -> f32 {
+        (self.height * 1.4).round()
+    }
+
+    /// Returns the buttons with their bounds.
+    fn buttons(&self, bounds: Rectangle) -> impl Iterator<Item = (Action, Rectangle)> {
+        let width = self.button_width();
+        let start = match self.order {
+            ButtonOrder::Leading => bounds.x,
+            ButtonOrder::Trailing => bounds.x + bounds.width - 3. * width,
+        };
+
+        self.order
+            .buttons()
+            .into_iter()
+            .enumerate()
+            .map(move |(i, action)| {
+                let bounds = Rectangle::new(
+                    Point::new(start + i as f32 * width, bounds.y),
+                    Size::new(width, bounds.height),
+                );
+                (action, bounds)
+            })
+    }
+
+    /// Returns the symbol of a button.
+    fn symbol(&self, action: Action) -> &'static str {
+        match action {
+            Action::Drag => "",
+            Action::Minimize => "–",
+            Action::ToggleMaximize if self.maximized => "❐",
+            Action::ToggleMaximize => "□",
+            Action::Close => "×",
+        }
+    }
+
+    /// Returns the region between the content and the buttons, where the title is drawn.
+    fn title_bounds(&self, layout: Layout<'_>) -> Rectangle {
+        let bounds = layout.bounds();
+        let buttons = 3. * self.button_width();
+        let content = layout
+            .children()
+            .next()
+            .map_or(0., |content| content.bounds().width);
+
+        let x = match self.order {
+            ButtonOrder::Leading => bounds.x + buttons + content,
+            ButtonOrder::Trailing => bounds.x + content,
+        };
+
+        Rectangle::new(
+            Point::new(x, bounds.y),
+            Size::new((bounds.width - buttons - content).max(0.), bounds.height),
+        )
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Titlebar<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.content.iter().collect::<Vec<_>>());
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Fixed(self.height))
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let size = limits.resolve(Length::Fill, self.height, Size::ZERO);
+        let buttons = 3. * self.button_width();
+
+        let children = self
+            .content
+            .iter()
+            .zip(&mut tree.children)
+            .map(|(content, tree)| {
+                let limits = Limits::new(
+                    Size::ZERO,
+                    Size::new((size.width - buttons).max(0.), size.height),
+                );
+                let node = content.as_widget().layout(tree, renderer, &limits);
+                let x = match self.order {
+                    ButtonOrder::Leading => buttons,
+                    ButtonOrder::Trailing => 0.,
+                };
+
+                node.clone()
+                    .move_to(Point::new(x, (size.height - node.size().height) / 2.))
+            })
+            .collect();
+
+        Node::with_children(size, children)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..Default::default()
+            },
+            style.background,
+        );
+
+        let text = |content: &str, bounds: Rectangle| Text {
+            content: content.to_owned(),
+            bounds: bounds.size(),
+            size: self.text_size,
+            line_height: LineHeight::default(),
+            font,
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: Shaping::Advanced,
+            wrapping: Wrapping::None,
+        };
+
+        let title = self.title_bounds(layout);
+        renderer.fill_text(
+            text(&self.title, title),
+            title.center(),
+            style.text_color,
+            title,
+        );
+
+        for (action, button) in self.buttons(bounds) {
+            let mut color = style.text_color;
+
+            if cursor.is_over(button) {
+                let background = if action == Action::Close {
+                    color = style.close_hovered_text;
+                    style.close_hovered
+                } else {
+                    style.button_hovered
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: button,
+                        ..Default::default()
+                    },
+                    background,
+                );
+            }
+
+            renderer.fill_text(
+                text(self.symbol(action), button),
+                button.center(),
+                color,
+                button,
+            );
+        }
+
+        if let (Some(content), Some(layout)) = (&self.content, layout.children().next()) {
+            content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: style.text_color,
+                },
+                layout,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            if let (Some(content), Some(layout)) = (&self.content, layout.children().next()) {
+                content
+                    .as_widget()
+                    .operate(&mut tree.children[0], layout, renderer, operation);
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let (Some(content), Some(content_layout)) = (&mut self.content, layout.children().next())
+            && content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event.clone(),
+                content_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            ) == event::Status::Captured
+        {
+            return event::Status::Captured;
+        }
+
+        let bounds = layout.bounds();
+
+        match event {
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                let action = match self
+                    .buttons(bounds)
+                    .find(|(_, button)| button.contains(position))
+                {
+                    Some((action, _)) => action,
+                    None => {
+                        let state = tree.state.downcast_mut::<State>();
+                        let click = Click::new(position, mouse::Button::Left, state.last_click);
+                        state.last_click = Some(click);
+
+                        if matches!(click.kind(), click::Kind::Double) {
+                            Action::ToggleMaximize
+                        } else {
+                            Action::Drag
+                        }
+                    }
+                };
+
+                shell.publish((self.on_action)(action));
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match (&self.content, layout.children().next()) {
+            (Some(content), Some(layout)) => content.as_widget().mouse_interaction(
+                &tree.children[0],
+                layout,
+                cursor,
+                viewport,
+                renderer,
+            ),
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let content = self.content.as_mut()?;
+
+        content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next()?,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Titlebar<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(value: Titlebar<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`Titlebar`] with the given title.
+///
+/// `on_action` is called with the [`Action`] of each interaction.
+pub fn titlebar<'a, Message, Theme, Renderer>(
+    title: impl Into<String>,
+    on_action: impl Fn(Action) -> Message + 'a,
+) -> Titlebar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    Titlebar::new(title, on_action)
+}
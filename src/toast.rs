@@ -0,0 +1,649 @@
+//! Timed notifications displayed in a corner of the window.
+//!
+//! The application owns the list of [`Toast`]s, and wraps its content in a [`Manager`]
+//! (see [`manager`]) which displays them. A toast is closed when its close button is pressed,
+//! or when its duration expires: in both cases, the `on_close` message of the [`Manager`] is
+//! produced with the index of the toast, which the application should then remove.
+//!
+//! The time at which a toast appeared follows its title, body and severity, so closing a toast
+//! does not restart nor shorten the timers of the other ones.
+//!
+//! The toasts are a layer following the rules of [`layers`](crate::layers): they are above the
+//! overlays of the content, and they are never dismissed by a click outside nor by Escape.
+
+use std::{
+    hash::{DefaultHasher, Hash, Hasher},
+    time::Duration,
+};
+
+use iced::{
+    Border, Color, Element, Length, Point, Rectangle, Size, Theme, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{Operation, Tree, tree},
+    },
+    alignment, event,
+    time::Instant,
+    widget::{Space, button, column, container, row, text},
+    window,
+};
+
+use crate::layers;
+
+/// The severity of a [`Toast`], which changes its style.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Severity {
+    /// A neutral information.
+    #[default]
+    Info,
+    /// The success of an operation.
+    Success,
+    /// Something that may need attention.
+    Warning,
+    /// The failure of an operation.
+    Error,
+}
+
+/// A corner of the window.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Corner {
+    /// The top left corner.
+    TopLeft,
+    /// The top right corner.
+    TopRight,
+    /// The bottom left corner.
+    BottomLeft,
+    /// The bottom right corner.
+    #[default]
+    BottomRight,
+}
+
+/// A notification displayed by a [`Manager`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toast<Message> {
+    /// The title of the [`Toast`].
+    pub title: String,
+    /// The body of the [`Toast`].
+    pub body: String,
+    /// The severity of the [`Toast`].
+    pub severity: Severity,
+    /// The label and the message of the action button of the [`Toast`], if any.
+    pub action: Option<(String, Message)>,
+    /// The time after which the [`Toast`] is closed, or `None` to use the timeout of the [`Manager`].
+    pub duration: Option<Duration>,
+    /// Whether the [`Toast`] stays until it is closed by the user.
+    pub sticky: bool,
+}
+
+impl<Message> Toast<Message> {
+    /// Creates a new [`Toast`] with the given title and body.
+    pub fn new(title: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            body: body.into(),
+            severity: Severity::default(),
+            action: None,
+            duration: None,
+            sticky: false,
+        }
+    }
+
+    /// Sets the severity of the [`Toast`].
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Adds an action button to the [`Toast`], which produces the given message when pressed.
+    pub fn action(mut self, label: impl Into<String>, message: Message) -> Self {
+        self.action = Some((label.into(), message));
+        self
+    }
+
+    /// Sets the time after which the [`Toast`] is closed.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets whether the [`Toast`] stays until it is closed by the user.
+    pub fn sticky(mut self, sticky: bool) -> Self {
+        self.sticky = sticky;
+        self
+    }
+}
+
+/// The default style of a [`Toast`] of the given [`Severity`].
+pub fn style(theme: &Theme, severity: Severity) -> container::Style {
+    let palette = theme.extended_palette();
+
+    let pair = match severity {
+        Severity::Info => palette.primary.weak,
+        Severity::Success => palette.success.weak,
+        Severity::Warning => {
+            iced::theme::palette::Pair::new(Color::from_rgb8(0xF5, 0xB0, 0x41), Color::BLACK)
+        }
+        Severity::Error => palette.danger.weak,
+    };
+
+    container::Style {
+        background: Some(pair.color.into()),
+        text_color: Some(pair.text),
+        border: Border {
+            radius: 6.into(),
+            ..Border::default()
+        },
+        shadow: iced::Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0., 2.),
+            blur_radius: 6.,
+        },
+    }
+}
+
+/// The state of a [`Manager`]: the key of each toast and the time at which it appeared.
+#[derive(Debug, Clone, Default)]
+struct State {
+    shown: Vec<(u64, Instant)>,
+}
+
+impl State {
+    /// Matches the toasts with the ones of the last diff by their keys, so that a toast keeps
+    /// the time at which it appeared when a previous one is closed.
+    ///
+    /// Toasts with the same key are matched in order, new toasts appear now.
+    fn diff(&mut self, keys: &[u64]) {
+        let now = Instant::now();
+        let mut previous = std::mem::take(&mut self.shown);
+
+        self.shown = keys
+            .iter()
+            .map(|&key| {
+                let index = previous.iter().position(|(previous, _)| *previous == key);
+                index.map_or((key, now), |index| previous.remove(index))
+            })
+            .collect();
+    }
+}
+
+/// Displays a list of [`Toast`]s over some content, created with [`manager`].
+pub struct Manager<'a, Message, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    toasts: Vec<Element<'a, Message, Theme, Renderer>>,
+    /// The key of each toast, hashed from its title, body and severity.
+    keys: Vec<u64>,
+    durations: Vec<(Option<Duration>, bool)>,
+    on_close: Box<dyn Fn(usize) -> Message + 'a>,
+    timeout: Duration,
+    corner: Corner,
+    width: f32,
+    spacing: f32,
+    padding: f32,
+}
+
+impl<'a, Message: Clone + 'a, Renderer> Manager<'a, Message, Renderer>
+where
+    Renderer: iced::advanced::text::Renderer + 'a,
+{
+    /// Creates a new [`Manager`] displaying the `toasts` over the `content`.
+    ///
+    /// `on_close` is called with the index of a toast when it is closed.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        toasts: &'a [Toast<Message>],
+        on_close: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        Self::with_style(content, toasts, on_close, style)
+    }
+
+    /// Creates a new [`Manager`] with a custom style for the toasts.
+    pub fn with_style(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        toasts: &'a [Toast<Message>],
+        on_close: impl Fn(usize) -> Message + 'a,
+        style: impl Fn(&Theme, Severity) -> container::Style + Clone + 'a,
+    ) -> Self {
+        let elements = toasts
+            .iter()
+            .enumerate()
+            .map(|(index, toast)| {
+                let severity = toast.severity;
+                let style = style.clone();
+
+                let header = row![
+                    text(&toast.title).size(16),
+                    Space::with_width(Length::Fill),
+                    button(text("×").size(16))
+                        .padding(0)
+                        .style(button::text)
+                        .on_press(on_close(index)),
+                ]
+                .align_y(alignment::Vertical::Center);
+
+                let mut body = column![header, text(&toast.body).size(14)].spacing(4);
+
+                if let Some((label, message)) = &toast.action {
+                    body = body.push(
+                        row![
+                            Space::with_width(Length::Fill),
+                            button(text(label).size(14))
+                                .style(button::secondary)
+                                .on_press(message.clone()),
+                        ]
+                        .align_y(alignment::Vertical::Center),
+                    );
+                }
+
+                container(body)
+                    .padding(10)
+                    .width(Length::Fill)
+                    .style(move |theme| style(theme, severity))
+                    .into()
+            })
+            .collect();
+
+        Self {
+            content: content.into(),
+            toasts: elements,
+            keys: toasts
+                .iter()
+                .map(|toast| {
+                    let mut hasher = DefaultHasher::new();
+                    (&toast.title, &toast.body, toast.severity).hash(&mut hasher);
+                    hasher.finish()
+                })
+                .collect(),
+            durations: toasts
+                .iter()
+                .map(|toast| (toast.duration, toast.sticky))
+                .collect(),
+            on_close: Box::new(on_close),
+            timeout: Duration::from_secs(5),
+            corner: Corner::default(),
+            width: 300.,
+            spacing: 10.,
+            padding: 10.,
+        }
+    }
+
+    /// Sets the default time after which a toast is closed.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the corner of the window where the toasts are displayed.
+    pub fn corner(mut self, corner: Corner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Sets the width of the toasts.
+    pub fn width(mut self, width: impl Into<iced::Pixels>) -> Self {
+        self.width = width.into().0;
+        self
+    }
+
+    /// Sets the spacing between the toasts.
+    pub fn spacing(mut self, spacing: impl Into<iced::Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the distance between the toasts and the edges of the window.
+    pub fn padding(mut self, padding: impl Into<iced::Pixels>) -> Self {
+        self.padding = padding.into().0;
+        self
+    }
+
+    /// Returns the duration of the toast at `index`, if it expires.
+    fn duration(&self, index: usize) -> Option<Duration> {
+        let (duration, sticky) = self.durations.get(index)?;
+        (!sticky).then_some(duration.unwrap_or(self.timeout))
+    }
+}
+
+impl<'a, Message: Clone + 'a, Renderer> Widget<Message, Theme, Renderer>
+    for Manager<'a, Message, Renderer>
+where
+    Renderer: iced::advanced::text::Renderer + 'a,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let mut state = State::default();
+        state.diff(&self.keys);
+        tree::State::new(state)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        std::iter::once(Tree::new(&self.content))
+            .chain(self.toasts.iter().map(Tree::new))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.state.downcast_mut::<State>().diff(&self.keys);
+
+        let elements: Vec<_> = std::iter::once(&self.content).chain(&self.toasts).collect();
+        tree.diff_children(&elements);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let iced::Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+            let mut next: Option<Instant> = None;
+
+            for (index, (_, shown)) in state.shown.iter().enumerate() {
+                let Some(duration) = self.duration(index) else {
+                    continue;
+                };
+                let deadline = *shown + duration;
+
+                if now >= deadline {
+                    // Only one toast is closed at a time, as closing it shifts the indices of the next ones.
+                    shell.publish((self.on_close)(index));
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                    state.shown.remove(index);
+                    next = None;
+                    break;
+                } else {
+                    next = Some(next.map_or(deadline, |next| next.min(deadline)));
+                }
+            }
+
+            if let Some(next) = next {
+                shell.request_redraw(window::RedrawRequest::At(next));
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (content_tree, toasts_tree) = tree.children.split_at_mut(1);
+
+        let content = self.content.as_widget_mut().overlay(
+            &mut content_tree[0],
+            layout,
+            renderer,
+            translation,
+        );
+
+        let toasts = (!self.toasts.is_empty()).then(|| {
+            overlay::Element::new(Box::new(Overlay {
+                toasts: &mut self.toasts,
+                trees: toasts_tree,
+                corner: self.corner,
+                width: self.width,
+                spacing: self.spacing,
+                padding: self.padding,
+            }))
+        });
+
+        layers::group([content, toasts])
+    }
+}
+
+/// The overlay displaying the toasts of a [`Manager`].
+struct Overlay<'a, 'b, Message, Renderer> {
+    toasts: &'b mut [Element<'a, Message, Theme, Renderer>],
+    trees: &'b mut [Tree],
+    corner: Corner,
+    width: f32,
+    spacing: f32,
+    padding: f32,
+}
+
+impl<Message, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Renderer>
+where
+    Renderer: iced::advanced::text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        let width = self.width.min(bounds.width - 2. * self.padding).max(0.);
+        let limits = Limits::new(Size::ZERO, Size::new(width, bounds.height));
+
+        let mut y = 0.;
+        let mut nodes: Vec<Node> = self
+            .toasts
+            .iter()
+            .zip(self.trees.iter_mut())
+            .map(|(toast, tree)| {
+                let node = toast
+                    .as_widget()
+                    .layout(tree, renderer, &limits)
+                    .move_to(Point::new(0., y));
+                y += node.size().height + self.spacing;
+                node
+            })
+            .collect();
+
+        let height = (y - self.spacing).max(0.);
+
+        let x = match self.corner {
+            Corner::TopLeft | Corner::BottomLeft => self.padding,
+            Corner::TopRight | Corner::BottomRight => bounds.width - width - self.padding,
+        };
+        let top = match self.corner {
+            Corner::TopLeft | Corner::TopRight => self.padding,
+            Corner::BottomLeft | Corner::BottomRight => bounds.height - height - self.padding,
+        };
+
+        for node in &mut nodes {
+            node.translate_mut(Vector::new(x, top));
+        }
+
+        Node::with_children(bounds, nodes)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let viewport = layout.bounds();
+
+        for ((toast, tree), layout) in self
+            .toasts
+            .iter()
+            .zip(self.trees.iter())
+            .zip(layout.children())
+        {
+            toast
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, &viewport);
+        }
+    }
+
+    fn operate(&mut self, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for ((toast, tree), layout) in self
+                .toasts
+                .iter()
+                .zip(self.trees.iter_mut())
+                .zip(layout.children())
+            {
+                toast.as_widget().operate(tree, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let viewport = layout.bounds();
+
+        self.toasts
+            .iter_mut()
+            .zip(self.trees.iter_mut())
+            .zip(layout.children())
+            .map(|((toast, tree), layout)| {
+                toast.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    &viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.toasts
+            .iter()
+            .zip(self.trees.iter())
+            .zip(layout.children())
+            .map(|((toast, tree), layout)| {
+                toast
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn is_over(&self, layout: Layout<'_>, _renderer: &Renderer, cursor_position: Point) -> bool {
+        layout
+            .children()
+            .any(|layout| layout.bounds().contains(cursor_position))
+    }
+}
+
+impl<'a, Message: Clone + 'a, Renderer> From<Manager<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::text::Renderer + 'a,
+{
+    fn from(value: Manager<'a, Message, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`Manager`] displaying the `toasts` over the `content`.
+///
+/// `on_close` is called with the index of a toast when it is closed, by the user or because it expired.
+pub fn manager<'a, Message: Clone + 'a, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    toasts: &'a [Toast<Message>],
+    on_close: impl Fn(usize) -> Message + 'a,
+) -> Manager<'a, Message, Renderer>
+where
+    Renderer: iced::advanced::text::Renderer + 'a,
+{
+    Manager::new(content, toasts, on_close)
+}
@@ -0,0 +1,799 @@
+//! A guided tour of an application, highlighting its widgets one after the other.
+//!
+//! A [`Tour`] wraps the content of the application, usually its whole view. Each [`Step`] of the
+//! tour targets a widget by the [`Id`](container::Id) of a [`Container`](container::Container)
+//! around it. While a step is shown, everything but the target is dimmed, and a card next to the
+//! target explains it, with buttons to go to the next or previous step, or to skip the tour.
+//!
+//! The application owns the current step, and updates it with the message of [`Tour::on_step`].
+//! When the target of a step is not found, the card is shown in the center of the window.
+//!
+//! The card is a layer following the rules of [`layers`](crate::layers): the dimmed backdrop is a
+//! scrim blocking the input of everything but the target, and the tour is skipped according to a
+//! [`Dismiss`] policy, by Escape by default (see [`Tour::dismiss`]).
+
+use iced::{
+    Background, Border, Color, Element, Length, Padding, Point, Rectangle, Shadow, Size, Theme,
+    Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer, text as advanced_text,
+        widget::{self, Operation, Tree},
+    },
+    alignment, event,
+    widget::{button, column, container, horizontal_space, row, text},
+};
+
+use crate::layers::{self, Dismiss};
+
+/// The distance between the target and the card, and between the card and the edges of the window.
+const GAP: f32 = 12.;
+
+/// A step of a [`Tour`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    /// The id of the container around the target of the step.
+    pub target: widget::Id,
+    /// The title of the card.
+    pub title: String,
+    /// The explanation of the card.
+    pub text: String,
+}
+
+impl Step {
+    /// Creates a new [`Step`] targeting the container with the given id.
+    pub fn new(
+        target: impl Into<widget::Id>,
+        title: impl Into<String>,
+        text: impl Into<String>,
+    ) -> Self {
+        Self {
+            target: target.into(),
+            title: title.into(),
+            text: text.into(),
+        }
+    }
+}
+
+/// The labels of the buttons of a [`Tour`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Labels {
+    /// The label of the button going to the previous step.
+    pub back: &'static str,
+    /// The label of the button going to the next step.
+    pub next: &'static str,
+    /// The label of the button ending the tour, on the last step.
+    pub done: &'static str,
+    /// The label of the button skipping the tour.
+    pub skip: &'static str,
+}
+
+impl Default for Labels {
+    fn default() -> Self {
+        Self {
+            back: "Back",
+            next: "Next",
+            done: "Done",
+            skip: "Skip",
+        }
+    }
+}
+
+/// The appearance of a [`Tour`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color dimming everything but the target.
+    pub backdrop: Color,
+    /// The border drawn around the target.
+    pub highlight: Border,
+    /// The background of the card.
+    pub background: Background,
+    /// The color of the text of the card.
+    pub text_color: Color,
+    /// The border of the card.
+    pub border: Border,
+    /// The shadow of the card.
+    pub shadow: Shadow,
+}
+
+/// The theme catalog of a [`Tour`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Tour`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Tour`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        backdrop: Color::BLACK.scale_alpha(0.5),
+        highlight: Border {
+            color: palette.primary.base.color,
+            width: 2.,
+            radius: 6.into(),
+        },
+        background: palette.background.base.color.into(),
+        text_color: palette.background.base.text,
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.,
+            radius: 8.into(),
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.3),
+            offset: Vector::new(0., 4.),
+            blur_radius: 16.,
+        },
+    }
+}
+
+/// A guided tour over some content, created with [`tour`].
+pub struct Tour<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    card: Element<'a, Message, Theme, Renderer>,
+    steps: Vec<Step>,
+    current: Option<usize>,
+    on_step: Box<dyn Fn(Option<usize>) -> Message + 'a>,
+    labels: Labels,
+    padding: f32,
+    width: f32,
+    dismiss: Dismiss,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Tour<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + button::Catalog + container::Catalog + text::Catalog + 'a,
+    Renderer: advanced_text::Renderer + 'a,
+{
+    /// Creates a new [`Tour`] over the given content.
+    ///
+    /// It expects:
+    ///   * the steps of the tour
+    ///   * the index of the current step, or [`None`] when the tour is not shown
+    ///   * a function that will be called with the step to show, [`None`] ending the tour.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        steps: impl IntoIterator<Item = Step>,
+        current: Option<usize>,
+        on_step: impl Fn(Option<usize>) -> Message + 'a,
+    ) -> Self {
+        let steps: Vec<_> = steps.into_iter().collect();
+
+        Self {
+            content: content.into(),
+            card: horizontal_space().into(),
+            current: current.filter(|current| *current < steps.len()),
+            steps,
+            on_step: Box::new(on_step),
+            labels: Labels::default(),
+            padding: 6.,
+            width: 320.,
+            dismiss: Dismiss::ESCAPE,
+            class: <Theme as Catalog>::default(),
+        }
+        .rebuild()
+    }
+
+    /// Sets the labels of the buttons of the card.
+    pub fn labels(mut self, labels: Labels) -> Self {
+        self.labels = labels;
+        self.rebuild()
+    }
+
+    /// Sets the maximum width of the card.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the space left around the target in the highlighted area.
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets how the [`Tour`] is skipped, [`Dismiss::ESCAPE`] by default.
+    ///
+    /// A click outside of the card and of the target skips the tour with [`Dismiss::ANY`].
+    pub fn dismiss(mut self, dismiss: Dismiss) -> Self {
+        self.dismiss = dismiss;
+        self
+    }
+
+    /// Sets the style of the [`Tour`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Tour`].
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Builds the card of the current step.
+    fn rebuild(mut self) -> Self {
+        let Some(current) = self.current else {
+            return self;
+        };
+        let step = &self.steps[current];
+        let last = current + 1 == self.steps.len();
+
+        let back = (current > 0)
+            .then(|| button(text(self.labels.back)).on_press((self.on_step)(Some(current - 1))));
+        let next = button(text(if last {
+            self.labels.done
+        } else {
+            self.labels.next
+        }))
+        .on_press((self.on_step)((!last).then_some(current + 1)));
+
+        self.card = column![
+            text(step.title.clone()).size(18),
+            text(step.text.clone()),
+            row![
+                text!("{} / {}", current + 1, self.steps.len()).size(12),
+                horizontal_space(),
+                button(text(self.labels.skip)).on_press((self.on_step)(None)),
+            ]
+            .push_maybe(back)
+            .push(next)
+            .spacing(8)
+            .align_y(alignment::Vertical::Center),
+        ]
+        .spacing(8)
+        .into();
+
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Tour<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content), Tree::new(&self.card)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content, &self.card]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let target = self.current.map(|current| {
+            let mut target = Target::new(self.steps[current].target.clone());
+            self.content
+                .as_widget()
+                .operate(&mut tree.children[0], layout, renderer, &mut target);
+            target.bounds.map(|bounds| bounds + translation)
+        });
+
+        let (content_tree, card_tree) = tree.children.split_at_mut(1);
+
+        let content = self.content.as_widget_mut().overlay(
+            &mut content_tree[0],
+            layout,
+            renderer,
+            translation,
+        );
+
+        let card = target.map(|target| {
+            overlay::Element::new(Box::new(Overlay {
+                card: &mut self.card,
+                tree: &mut card_tree[0],
+                target: target.map(|target| target.expand(self.padding)),
+                width: self.width,
+                on_skip: (self.on_step)(None),
+                dismiss: self.dismiss,
+                class: &self.class,
+            }))
+        });
+
+        layers::group([content, card])
+    }
+}
+
+/// The overlay dimming the content of a [`Tour`] and displaying the card of the current step.
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    card: &'b mut Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    target: Option<Rectangle>,
+    width: f32,
+    on_skip: Message,
+    dismiss: Dismiss,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        let padding = Padding::new(16.);
+        let width = self.width.min(bounds.width - 2. * GAP).max(0.);
+        let limits = Limits::new(Size::ZERO, Size::new(width, bounds.height)).shrink(padding);
+
+        let content = self
+            .card
+            .as_widget()
+            .layout(self.tree, renderer, &limits)
+            .move_to(Point::new(padding.left, padding.top));
+        let size = content.size().expand(padding);
+
+        let position = match self.target {
+            Some(target) => {
+                let x = target.center_x() - size.width / 2.;
+                let below = target.y + target.height + GAP;
+                let above = target.y - GAP - size.height;
+
+                let y = if below + size.height <= bounds.height - GAP {
+                    below
+                } else if above >= GAP {
+                    above
+                } else {
+                    (bounds.height - size.height) / 2.
+                };
+
+                Point::new(x, y)
+            }
+            None => Point::new(
+                (bounds.width - size.width) / 2.,
+                (bounds.height - size.height) / 2.,
+            ),
+        };
+
+        let position = Point::new(
+            position.x.min(bounds.width - GAP - size.width).max(GAP),
+            position.y.min(bounds.height - GAP - size.height).max(GAP),
+        );
+
+        let card = Node::with_children(size, vec![content]).move_to(position);
+        Node::with_children(bounds, vec![card])
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.style(self.class);
+
+        // The backdrop is drawn around the target, in four parts.
+        let backdrop = match self.target {
+            Some(target) => {
+                let left = target.x.max(0.);
+                let right = (target.x + target.width).min(bounds.width);
+                let top = target.y.max(0.);
+                let bottom = (target.y + target.height).min(bounds.height);
+
+                vec![
+                    Rectangle::new(Point::ORIGIN, Size::new(bounds.width, top)),
+                    Rectangle::new(
+                        Point::new(0., bottom),
+                        Size::new(bounds.width, bounds.height - bottom),
+                    ),
+                    Rectangle::new(Point::new(0., top), Size::new(left, bottom - top)),
+                    Rectangle::new(
+                        Point::new(right, top),
+                        Size::new(bounds.width - right, bottom - top),
+                    ),
+                ]
+            }
+            None => vec![bounds],
+        };
+
+        for part in backdrop {
+            if part.width > 0. && part.height > 0. {
+                layers::draw_scrim(renderer, part, appearance.backdrop);
+            }
+        }
+
+        if let Some(target) = self.target {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: target,
+                    border: appearance.highlight,
+                    ..Default::default()
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
+        let Some(card) = layout.children().next() else {
+            return;
+        };
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: card.bounds(),
+                    border: appearance.border,
+                    shadow: appearance.shadow,
+                },
+                appearance.background,
+            );
+
+            self.card.as_widget().draw(
+                self.tree,
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: appearance.text_color,
+                },
+                card.children().next().unwrap(),
+                cursor,
+                &card.bounds(),
+            );
+        });
+    }
+
+    fn operate(&mut self, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        let card = layout.children().next().unwrap();
+
+        self.card.as_widget().operate(
+            self.tree,
+            card.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let card = layout.children().next().unwrap();
+
+        let status = self.card.as_widget_mut().on_event(
+            self.tree,
+            event.clone(),
+            card.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &card.bounds(),
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        let areas: Vec<_> = self.target.into_iter().chain([card.bounds()]).collect();
+
+        if self.dismiss.applies(&event, cursor, &areas) {
+            shell.publish(self.on_skip.clone());
+
+            if Dismiss::status(&event) == event::Status::Captured {
+                return event::Status::Captured;
+            }
+        }
+
+        match event {
+            iced::Event::Mouse(_) | iced::Event::Touch(_)
+                if cursor
+                    .position()
+                    .is_some_and(|position| self.is_blocking(position)) =>
+            {
+                event::Status::Captured
+            }
+            _ => status,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let card = layout.children().next().unwrap();
+
+        self.card.as_widget().mouse_interaction(
+            self.tree,
+            card.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _renderer: &Renderer, cursor_position: Point) -> bool {
+        self.is_blocking(cursor_position)
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        let card = layout.children().next().unwrap();
+
+        self.card.as_widget_mut().overlay(
+            self.tree,
+            card.children().next().unwrap(),
+            renderer,
+            Vector::ZERO,
+        )
+    }
+}
+
+impl<Message, Theme, Renderer> Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Returns whether the overlay blocks the content at the given position: everywhere but on
+    /// the target, which stays interactive.
+    fn is_blocking(&self, position: Point) -> bool {
+        !self.target.is_some_and(|target| target.contains(position))
+    }
+}
+
+/// An operation finding the visible bounds of the container with the given id.
+///
+/// The bounds are clipped and translated by the scrollables around the container.
+struct Target {
+    id: widget::Id,
+    depth: usize,
+    scrollables: Vec<(Vector, Rectangle, usize)>,
+    bounds: Option<Rectangle>,
+}
+
+impl Target {
+    fn new(id: widget::Id) -> Self {
+        Self {
+            id,
+            depth: 0,
+            scrollables: Vec::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl Operation for Target {
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn widget::operation::Scrollable,
+        _id: Option<&widget::Id>,
+        bounds: Rectangle,
+        _content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        let scrollable = match self.scrollables.last() {
+            Some((last_translation, last_viewport, _)) => (
+                translation + *last_translation,
+                last_viewport
+                    .intersection(&(bounds - *last_translation))
+                    .unwrap_or(Rectangle::new(Point::ORIGIN, Size::ZERO)),
+                self.depth,
+            ),
+            None => (translation, bounds, self.depth),
+        };
+
+        self.scrollables.push(scrollable);
+    }
+
+    fn container(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        if self.bounds.is_some() {
+            return;
+        }
+
+        if id == Some(&self.id) {
+            self.bounds = match self.scrollables.last() {
+                Some((translation, viewport, _)) => viewport.intersection(&(bounds - *translation)),
+                None => Some(bounds),
+            };
+            return;
+        }
+
+        self.depth += 1;
+        operate_on_children(self);
+        self.depth -= 1;
+
+        if let Some((_, _, depth)) = self.scrollables.last()
+            && *depth == self.depth
+        {
+            self.scrollables.pop();
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Tour<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(value: Tour<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`Tour`] over the given content.
+///
+/// ```
+/// use iced::widget::{button, column, container, text};
+/// use more_iced_aw::tour::{Step, Tour, tour};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Tour(Option<usize>),
+///     Save,
+/// }
+///
+/// let content = column![
+///     container(text("Welcome")).id(container::Id::new("title")),
+///     container(button("Save").on_press(Message::Save)).id(container::Id::new("save")),
+/// ];
+///
+/// let steps = [
+///     Step::new(container::Id::new("title"), "Welcome", "This is your new workspace."),
+///     Step::new(container::Id::new("save"), "Saving", "Click here to save your work."),
+/// ];
+///
+/// let view: Tour<'_, Message> = tour(content, steps, Some(0), Message::Tour);
+/// ```
+pub fn tour<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    steps: impl IntoIterator<Item = Step>,
+    current: Option<usize>,
+    on_step: impl Fn(Option<usize>) -> Message + 'a,
+) -> Tour<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + button::Catalog + container::Catalog + text::Catalog + 'a,
+    Renderer: advanced_text::Renderer + 'a,
+{
+    Tour::new(content, steps, current, on_step)
+}
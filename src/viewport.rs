@@ -0,0 +1,660 @@
+//! A pannable and zoomable view of a large content.
+//!
+//! The [`Viewport`] displays its content through a [`Transform`]: the content is scaled by the
+//! zoom of the transform, and moved by its offset. The content is panned by dragging it with the
+//! middle button, or with the left button where the content does not use the press, and zoomed
+//! around the cursor with the mouse wheel or with a pinch.
+//!
+//! The viewport keeps its transform itself, unless one is given with [`Viewport::transform`]: the
+//! application then owns the transform, and applies the ones produced by
+//! [`Viewport::on_transform`]. This is how zoom to fit ([`Transform::fit`]) is implemented, and how
+//! the positions of the content and of the screen are converted ([`Transform::to_content`] and
+//! [`Transform::to_screen`]).
+//!
+//! The content is laid out without limits, so it should not fill its parent.
+//!
+//! An [`Overview`] shows the whole content with the region visible in a viewport, and moves the
+//! viewport when this region is dragged.
+
+use iced::{
+    Element, Length, Point, Rectangle, Size, Transformation, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, renderer,
+        widget::{Operation, Tree, tree},
+    },
+    event, touch,
+};
+
+mod overview;
+pub use overview::*;
+
+/// The transform of the content of a [`Viewport`].
+///
+/// A point of the content, relative to its top left corner, is displayed at
+/// `offset + point * zoom` relative to the top left corner of the viewport.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform {
+    /// The position of the top left corner of the content in the viewport.
+    #[cfg_attr(feature = "serde", serde(with = "crate::helpers::serde_iced"))]
+    pub offset: Vector,
+    /// The scale of the content.
+    pub zoom: f32,
+}
+
+impl Transform {
+    /// The identity transform.
+    pub const IDENTITY: Self = Self {
+        offset: Vector::ZERO,
+        zoom: 1.,
+    };
+
+    /// Creates a new [`Transform`].
+    pub fn new(offset: Vector, zoom: f32) -> Self {
+        Self { offset, zoom }
+    }
+
+    /// Returns the transform showing the whole content in a viewport of the given size, centered.
+    ///
+    /// ```
+    /// use iced::{Size, Vector};
+    /// use more_iced_aw::viewport::Transform;
+    ///
+    /// let transform = Transform::fit(Size::new(400., 100.), Size::new(200., 200.));
+    ///
+    /// assert_eq!(transform.zoom, 0.5);
+    /// assert_eq!(transform.offset, Vector::new(0., 75.));
+    /// ```
+    pub fn fit(content: Size, viewport: Size) -> Self {
+        let zoom = (viewport.width / content.width)
+            .min(viewport.height / content.height)
+            .max(f32::EPSILON);
+        let zoom = if zoom.is_finite() { zoom } else { 1. };
+
+        Self {
+            offset: Vector::new(
+                (viewport.width - content.width * zoom) / 2.,
+                (viewport.height - content.height * zoom) / 2.,
+            ),
+            zoom,
+        }
+    }
+
+    /// Converts a position in the viewport to a position in the content.
+    pub fn to_content(&self, point: Point) -> Point {
+        Point::new(
+            (point.x - self.offset.x) / self.zoom,
+            (point.y - self.offset.y) / self.zoom,
+        )
+    }
+
+    /// Converts a position in the content to a position in the viewport.
+    pub fn to_screen(&self, point: Point) -> Point {
+        Point::new(
+            point.x * self.zoom + self.offset.x,
+            point.y * self.zoom + self.offset.y,
+        )
+    }
+
+    /// Returns the region of the content visible in a viewport of the given size.
+    pub fn visible(&self, viewport: Size) -> Rectangle {
+        Rectangle::new(
+            self.to_content(Point::ORIGIN),
+            Size::new(viewport.width / self.zoom, viewport.height / self.zoom),
+        )
+    }
+
+    /// Returns the transform moved by the given vector.
+    pub fn pan(self, delta: Vector) -> Self {
+        Self {
+            offset: self.offset + delta,
+            ..self
+        }
+    }
+
+    /// Returns the transform with the given zoom, keeping the content under the given position
+    /// of the viewport in place.
+    pub fn zoom_at(self, zoom: f32, anchor: Point) -> Self {
+        let content = self.to_content(anchor);
+
+        Self {
+            offset: Vector::new(anchor.x - content.x * zoom, anchor.y - content.y * zoom),
+            zoom,
+        }
+    }
+
+    /// Returns the transform centering the given position of the content in a viewport of the
+    /// given size.
+    pub fn center_on(self, point: Point, viewport: Size) -> Self {
+        Self {
+            offset: Vector::new(
+                viewport.width / 2. - point.x * self.zoom,
+                viewport.height / 2. - point.y * self.zoom,
+            ),
+            ..self
+        }
+    }
+
+    /// Returns the [`Transformation`] drawing content laid out at `origin` in a viewport at
+    /// `origin`.
+    fn transformation(&self, origin: Point) -> Transformation {
+        Transformation::translate(origin.x + self.offset.x, origin.y + self.offset.y)
+            * Transformation::scale(self.zoom)
+            * Transformation::translate(-origin.x, -origin.y)
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// A pan in progress.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Pan {
+    /// The last position of the cursor.
+    last: Point,
+}
+
+/// A pinch in progress.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Pinch {
+    /// The last distance between the fingers.
+    distance: f32,
+    /// The last middle of the fingers.
+    middle: Point,
+}
+
+/// The state of a [`Viewport`].
+#[derive(Debug, Clone, Default)]
+struct State {
+    transform: Transform,
+    pan: Option<Pan>,
+    fingers: Vec<(touch::Finger, Point)>,
+    pinch: Option<Pinch>,
+    size: Option<Size>,
+}
+
+/// A pannable and zoomable view of a content, created with [`viewport`].
+pub struct Viewport<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    transform: Option<Transform>,
+    on_transform: Option<Box<dyn Fn(Transform) -> Message + 'a>>,
+    on_resize: Option<Box<dyn Fn(Size) -> Message + 'a>>,
+    min_zoom: f32,
+    max_zoom: f32,
+    zoom_step: f32,
+    pan_with_left: bool,
+    width: Length,
+    height: Length,
+}
+
+impl<'a, Message, Theme, Renderer> Viewport<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// Creates a new [`Viewport`] of the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            transform: None,
+            on_transform: None,
+            on_resize: None,
+            min_zoom: 0.1,
+            max_zoom: 10.,
+            zoom_step: 1.1,
+            pan_with_left: true,
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+
+    /// Sets the [`Transform`] of the content.
+    ///
+    /// When set, the [`Viewport`] does not change its transform itself: the ones produced by
+    /// [`Viewport::on_transform`] must be given back.
+    pub fn transform(mut self, transform: impl Into<Option<Transform>>) -> Self {
+        self.transform = transform.into();
+        self
+    }
+
+    /// Sets the message produced when the content is panned or zoomed, with the new
+    /// [`Transform`].
+    pub fn on_transform(mut self, on_transform: impl Fn(Transform) -> Message + 'a) -> Self {
+        self.on_transform = Some(Box::new(on_transform));
+        self
+    }
+
+    /// Sets the message produced when the size of the [`Viewport`] changes, with the new size.
+    pub fn on_resize(mut self, on_resize: impl Fn(Size) -> Message + 'a) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    /// Sets the minimal and maximal zoom of the content.
+    pub fn zoom_range(mut self, min: f32, max: f32) -> Self {
+        self.min_zoom = min.max(f32::EPSILON);
+        self.max_zoom = max.max(self.min_zoom);
+        self
+    }
+
+    /// Sets the factor by which a step of the mouse wheel zooms.
+    pub fn zoom_step(mut self, zoom_step: f32) -> Self {
+        self.zoom_step = zoom_step.max(1.);
+        self
+    }
+
+    /// Sets whether the content can be panned with the left button, where the content does not
+    /// use the press. Enabled by default.
+    pub fn pan_with_left(mut self, pan_with_left: bool) -> Self {
+        self.pan_with_left = pan_with_left;
+        self
+    }
+
+    /// Sets the width of the [`Viewport`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Viewport`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Returns the current transform.
+    fn current(&self, state: &State) -> Transform {
+        self.transform.unwrap_or(state.transform)
+    }
+
+    /// Changes the transform, or asks the application to.
+    fn set(&self, state: &mut State, transform: Transform, shell: &mut Shell<'_, Message>) {
+        let transform = Transform {
+            zoom: transform.zoom.clamp(self.min_zoom, self.max_zoom),
+            ..transform
+        };
+        if transform == self.current(state) {
+            return;
+        }
+
+        if self.transform.is_none() {
+            state.transform = transform;
+            shell.request_redraw(iced::window::RedrawRequest::NextFrame);
+        }
+        if let Some(on_transform) = &self.on_transform {
+            shell.publish(on_transform(transform));
+        }
+    }
+
+    /// Zooms by the given factor around a position of the viewport.
+    fn zoom(&self, state: &mut State, factor: f32, anchor: Point, shell: &mut Shell<'_, Message>) {
+        let transform = self.current(state);
+        let zoom = (transform.zoom * factor).clamp(self.min_zoom, self.max_zoom);
+        self.set(state, transform.zoom_at(zoom, anchor), shell);
+    }
+}
+
+/// Converts an absolute position on the screen to the absolute position in the content.
+fn to_content(transform: &Transform, bounds: Rectangle, position: Point) -> Point {
+    let origin = Vector::new(bounds.x, bounds.y);
+    transform.to_content(position - origin) + origin
+}
+
+/// Converts the positions of an event to positions in the content.
+fn transform_event(event: iced::Event, map: impl Fn(Point) -> Point) -> iced::Event {
+    match event {
+        iced::Event::Mouse(mouse::Event::CursorMoved { position }) => {
+            iced::Event::Mouse(mouse::Event::CursorMoved {
+                position: map(position),
+            })
+        }
+        iced::Event::Touch(event) => iced::Event::Touch(match event {
+            touch::Event::FingerPressed { id, position } => touch::Event::FingerPressed {
+                id,
+                position: map(position),
+            },
+            touch::Event::FingerMoved { id, position } => touch::Event::FingerMoved {
+                id,
+                position: map(position),
+            },
+            touch::Event::FingerLifted { id, position } => touch::Event::FingerLifted {
+                id,
+                position: map(position),
+            },
+            touch::Event::FingerLost { id, position } => touch::Event::FingerLost {
+                id,
+                position: map(position),
+            },
+        }),
+        event => event,
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Viewport<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let content = self.content.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &Limits::new(Size::ZERO, Size::INFINITY),
+        );
+
+        Node::with_children(
+            limits.resolve(self.width, self.height, Size::ZERO),
+            vec![content],
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+        let transform = self.current(state);
+
+        let cursor = match cursor.position_over(bounds) {
+            Some(position) => mouse::Cursor::Available(to_content(&transform, bounds, position)),
+            None => mouse::Cursor::Unavailable,
+        };
+        let visible = Rectangle::new(
+            to_content(&transform, bounds, clip.position()),
+            Size::new(clip.width / transform.zoom, clip.height / transform.zoom),
+        );
+
+        renderer.with_layer(clip, |renderer| {
+            renderer.with_transformation(transform.transformation(bounds.position()), |renderer| {
+                self.content.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    layout.children().next().expect("Missing content layout"),
+                    cursor,
+                    &visible,
+                );
+            });
+        });
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout.children().next().expect("Missing content layout"),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let local = |position: Point| position - Vector::new(bounds.x, bounds.y);
+
+        {
+            let state = tree.state.downcast_mut::<State>();
+            if let Some(on_resize) = &self.on_resize
+                && state.size != Some(bounds.size())
+            {
+                state.size = Some(bounds.size());
+                shell.publish(on_resize(bounds.size()));
+            }
+        }
+
+        // Panning and pinching take precedence over the content.
+        match &event {
+            iced::Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+                let steps = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => *y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / 50.,
+                };
+
+                let state = tree.state.downcast_mut::<State>();
+                self.zoom(state, self.zoom_step.powf(steps), local(position), shell);
+                return event::Status::Captured;
+            }
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Middle)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                let state = tree.state.downcast_mut::<State>();
+                state.pan = Some(Pan { last: position });
+                return event::Status::Captured;
+            }
+            iced::Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let state = tree.state.downcast_mut::<State>();
+                if let Some(pan) = &mut state.pan {
+                    let delta = *position - pan.last;
+                    pan.last = *position;
+
+                    let transform = self.current(state).pan(delta);
+                    self.set(state, transform, shell);
+                    return event::Status::Captured;
+                }
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(
+                mouse::Button::Middle | mouse::Button::Left,
+            )) => {
+                let state = tree.state.downcast_mut::<State>();
+                if state.pan.take().is_some() {
+                    return event::Status::Captured;
+                }
+            }
+            iced::Event::Touch(touch::Event::FingerPressed { id, position })
+                if bounds.contains(*position) =>
+            {
+                let state = tree.state.downcast_mut::<State>();
+                state.fingers.retain(|(finger, _)| finger != id);
+                state.fingers.push((*id, *position));
+
+                if let [(_, a), (_, b)] = state.fingers[..] {
+                    state.pan = None;
+                    state.pinch = Some(Pinch {
+                        distance: a.distance(b),
+                        middle: Point::new((a.x + b.x) / 2., (a.y + b.y) / 2.),
+                    });
+                    return event::Status::Captured;
+                }
+            }
+            iced::Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                let state = tree.state.downcast_mut::<State>();
+                if let Some((_, finger)) = state.fingers.iter_mut().find(|(finger, _)| finger == id)
+                {
+                    *finger = *position;
+                }
+
+                if let (Some(pinch), [(_, a), (_, b)]) = (state.pinch, &state.fingers[..]) {
+                    let distance = a.distance(*b);
+                    let middle = Point::new((a.x + b.x) / 2., (a.y + b.y) / 2.);
+                    state.pinch = Some(Pinch { distance, middle });
+
+                    let panned = self.current(state).pan(middle - pinch.middle);
+                    let zoom = (panned.zoom * distance / pinch.distance.max(1.))
+                        .clamp(self.min_zoom, self.max_zoom);
+                    self.set(state, panned.zoom_at(zoom, local(middle)), shell);
+                    return event::Status::Captured;
+                }
+
+                if let Some(pan) = &mut state.pan {
+                    let delta = *position - pan.last;
+                    pan.last = *position;
+
+                    let transform = self.current(state).pan(delta);
+                    self.set(state, transform, shell);
+                    return event::Status::Captured;
+                }
+            }
+            iced::Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) => {
+                let state = tree.state.downcast_mut::<State>();
+                state.fingers.retain(|(finger, _)| finger != id);
+                if state.pinch.take().is_some() | state.pan.take().is_some() {
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        let transform = self.current(tree.state.downcast_ref::<State>());
+        let map = |position| to_content(&transform, bounds, position);
+        let content_cursor = match cursor.position_over(bounds) {
+            Some(position) => mouse::Cursor::Available(map(position)),
+            None => mouse::Cursor::Unavailable,
+        };
+        let visible = Rectangle::new(
+            map(bounds.position()),
+            Size::new(
+                bounds.width / transform.zoom,
+                bounds.height / transform.zoom,
+            ),
+        );
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            transform_event(event.clone(), map),
+            layout.children().next().expect("Missing content layout"),
+            content_cursor,
+            renderer,
+            clipboard,
+            shell,
+            &visible,
+        );
+
+        // Pan with the left button or a finger where the content ignores the press.
+        let position = match event {
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                cursor.position_over(bounds)
+            }
+            iced::Event::Touch(touch::Event::FingerPressed { position, .. }) => {
+                bounds.contains(position).then_some(position)
+            }
+            _ => None,
+        };
+        if status == event::Status::Ignored
+            && self.pan_with_left
+            && let Some(position) = position
+        {
+            let state = tree.state.downcast_mut::<State>();
+            state.pan = Some(Pan { last: position });
+            return event::Status::Captured;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        if state.pan.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+        let Some(position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        let transform = self.current(state);
+        let visible = Rectangle::new(
+            to_content(&transform, bounds, bounds.position()),
+            Size::new(
+                bounds.width / transform.zoom,
+                bounds.height / transform.zoom,
+            ),
+        );
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().expect("Missing content layout"),
+            mouse::Cursor::Available(to_content(&transform, bounds, position)),
+            &visible,
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Viewport<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(value: Viewport<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`Viewport`] of the given content.
+pub fn viewport<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Viewport<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    Viewport::new(content)
+}
@@ -0,0 +1,337 @@
+use iced::{
+    Element, Length, Point, Rectangle, Size, Vector,
+    advanced::{
+        Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, renderer,
+        widget::{Tree, tree},
+    },
+    event, touch,
+};
+
+use crate::minimap::{Catalog, Status, StyleFn};
+
+use super::Transform;
+
+/// The state of an [`Overview`].
+#[derive(Debug, Clone, Copy, Default)]
+struct OverviewState {
+    /// Where the indicator was grabbed, relatively to its top left corner, while it is dragged.
+    grab: Option<Vector>,
+}
+
+/// A scaled down overview of the content of a [`Viewport`](super::Viewport), created with
+/// [`overview`].
+///
+/// The overview draws the whole content, usually a second view of the content of the viewport,
+/// with an indicator of the region visible in the viewport. Dragging the indicator, or clicking
+/// elsewhere in the overview, moves the viewport.
+///
+/// The overview and the viewport are kept in sync by the application, which owns the
+/// [`Transform`]: it gives it to both widgets, with the size of the viewport given by
+/// [`Viewport::on_resize`](super::Viewport::on_resize), and applies the transforms both produce.
+///
+/// The style of the overview is the one of a [`Minimap`](crate::minimap::Minimap).
+pub struct Overview<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    transform: Transform,
+    viewport: Size,
+    on_transform: Box<dyn Fn(Transform) -> Message + 'a>,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Overview<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    /// Creates a new [`Overview`] of the given content, for a viewport of the given size showing
+    /// it with the given [`Transform`].
+    ///
+    /// `on_transform` is called with the transform the viewport should use when the indicator is
+    /// moved.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        transform: Transform,
+        viewport: Size,
+        on_transform: impl Fn(Transform) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            transform,
+            viewport,
+            on_transform: Box::new(on_transform),
+            width: Length::Fixed(160.),
+            height: Length::Fixed(120.),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the width of the [`Overview`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Overview`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`Overview`].
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> crate::minimap::Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Overview`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the transform fitting the content in the overview, and the bounds of the indicator,
+    /// relative to the overview.
+    fn geometry(&self, bounds: Rectangle, content: Size) -> (Transform, Rectangle) {
+        let fit = Transform::fit(content, bounds.size());
+        let visible = self.transform.visible(self.viewport);
+
+        let position = fit.to_screen(visible.position());
+        let indicator = Rectangle::new(position, visible.size() * fit.zoom);
+
+        (fit, indicator)
+    }
+
+    /// Returns the transform of the viewport with the indicator at the given position, relative
+    /// to the overview.
+    fn moved(&self, fit: &Transform, position: Point) -> Transform {
+        let corner = fit.to_content(position);
+
+        Transform {
+            offset: Vector::new(
+                -corner.x * self.transform.zoom,
+                -corner.y * self.transform.zoom,
+            ),
+            ..self.transform
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Overview<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<OverviewState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(OverviewState::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let content = self.content.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &Limits::new(Size::ZERO, Size::INFINITY),
+        );
+
+        Node::with_children(
+            limits.resolve(self.width, self.height, Size::ZERO),
+            vec![content],
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<OverviewState>();
+        let bounds = layout.bounds();
+        let Some(content) = layout.children().next() else {
+            return;
+        };
+        let (fit, indicator) = self.geometry(bounds, content.bounds().size());
+        let indicator = indicator + Vector::new(bounds.x, bounds.y);
+
+        let status = if state.grab.is_some() {
+            Status::Dragged
+        } else if cursor.is_over(indicator) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+        let appearance = theme.style(&self.class, status);
+
+        if let Some(background) = appearance.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..Default::default()
+                },
+                background,
+            );
+        }
+
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        renderer.with_layer(clip, |renderer| {
+            renderer.with_transformation(fit.transformation(bounds.position()), |renderer| {
+                self.content.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    content,
+                    mouse::Cursor::Unavailable,
+                    &content.bounds(),
+                );
+            });
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: indicator,
+                    border: appearance.viewport_border,
+                    ..Default::default()
+                },
+                appearance.viewport,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn iced::advanced::Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<OverviewState>();
+        let bounds = layout.bounds();
+        let Some(content) = layout.children().next() else {
+            return event::Status::Ignored;
+        };
+        let (fit, indicator) = self.geometry(bounds, content.bounds().size());
+        let local = |position: Point| position - Vector::new(bounds.x, bounds.y);
+
+        match event {
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds).map(local) else {
+                    return event::Status::Ignored;
+                };
+
+                if indicator.contains(position) {
+                    state.grab = Some(position - indicator.position());
+                } else {
+                    let grab = Vector::new(indicator.width / 2., indicator.height / 2.);
+                    state.grab = Some(grab);
+                    shell.publish((self.on_transform)(self.moved(&fit, position - grab)));
+                }
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::CursorMoved { position })
+            | iced::Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(grab) = state.grab else {
+                    return event::Status::Ignored;
+                };
+
+                shell.publish((self.on_transform)(
+                    self.moved(&fit, local(position) - grab),
+                ));
+                event::Status::Captured
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerLifted { .. })
+            | iced::Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.grab.take().is_some() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<OverviewState>();
+
+        if state.grab.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Overview<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(value: Overview<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`Overview`] of the given content, for a viewport of the given size showing it
+/// with the given [`Transform`].
+pub fn overview<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    transform: Transform,
+    viewport: Size,
+    on_transform: impl Fn(Transform) -> Message + 'a,
+) -> Overview<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    Overview::new(content, transform, viewport, on_transform)
+}
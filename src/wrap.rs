@@ -0,0 +1,337 @@
+//! A container laying its children in rows, wrapping to the next line when a row is full.
+//!
+//! This is the companion of iced's `Row` and `Column` for tag clouds, toolbars, or any list
+//! of small elements that should use the available width.
+
+use iced::{
+    Element, Length, Padding, Pixels, Point, Rectangle, Size, Vector,
+    advanced::{
+        self, Clipboard, Layout, Shell, Widget,
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{Operation, Tree},
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+};
+
+/// A container laying its children from left to right, and wrapping them to the next line
+/// when there is no more room, created with [`wrap`].
+pub struct Wrap<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    spacing: f32,
+    line_spacing: f32,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    align_x: Horizontal,
+    align_y: Vertical,
+    justify: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Wrap<'a, Message, Theme, Renderer> {
+    /// Creates a new empty [`Wrap`].
+    pub fn new() -> Self {
+        Self::with_children([])
+    }
+
+    /// Creates a new [`Wrap`] with the given children.
+    pub fn with_children(
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            children: children.into_iter().collect(),
+            spacing: 0.,
+            line_spacing: 0.,
+            padding: Padding::ZERO,
+            width: Length::Fill,
+            height: Length::Shrink,
+            align_x: Horizontal::Left,
+            align_y: Vertical::Top,
+            justify: false,
+        }
+    }
+
+    /// Adds a child to the [`Wrap`].
+    pub fn push(mut self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Sets the horizontal spacing between the children of a line.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the vertical spacing between the lines.
+    pub fn line_spacing(mut self, line_spacing: impl Into<Pixels>) -> Self {
+        self.line_spacing = line_spacing.into().0;
+        self
+    }
+
+    /// Sets the padding of the [`Wrap`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Wrap`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Wrap`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the horizontal alignment of each line.
+    pub fn align_x(mut self, align_x: impl Into<Horizontal>) -> Self {
+        self.align_x = align_x.into();
+        self
+    }
+
+    /// Sets the vertical alignment of the children in their line.
+    pub fn align_y(mut self, align_y: impl Into<Vertical>) -> Self {
+        self.align_y = align_y.into();
+        self
+    }
+
+    /// Sets whether the lines are justified: the free space of a line is distributed between
+    /// its children, except for the last line.
+    pub fn justify(mut self, justify: bool) -> Self {
+        self.justify = justify;
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Default for Wrap<'_, Message, Theme, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Wrap<'_, Message, Theme, Renderer>
+where
+    Renderer: advanced::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let max_width = (limits.max().width - self.padding.horizontal()).max(0.);
+        let child_limits = Limits::new(Size::ZERO, Size::new(max_width, f32::INFINITY));
+
+        let mut nodes: Vec<Node> = self
+            .children
+            .iter()
+            .zip(&mut tree.children)
+            .map(|(child, tree)| child.as_widget().layout(tree, renderer, &child_limits))
+            .collect();
+
+        let mut lines: Vec<(usize, usize)> = Vec::new();
+        let mut start = 0;
+        let mut x = 0.;
+
+        for (i, node) in nodes.iter().enumerate() {
+            let width = node.size().width;
+            if i > start && x + width > max_width {
+                lines.push((start, i));
+                start = i;
+                x = 0.;
+            }
+            x += width + self.spacing;
+        }
+        if start < nodes.len() {
+            lines.push((start, nodes.len()));
+        }
+
+        let mut y = self.padding.top;
+        let mut content_width: f32 = 0.;
+        let last_line = lines.len().saturating_sub(1);
+
+        for (line, &(start, end)) in lines.iter().enumerate() {
+            let line_nodes = &mut nodes[start..end];
+            let count = line_nodes.len();
+            let widths: f32 = line_nodes.iter().map(|node| node.size().width).sum();
+            let height = line_nodes
+                .iter()
+                .map(|node| node.size().height)
+                .fold(0., f32::max);
+            let used = widths + self.spacing * count.saturating_sub(1) as f32;
+            let free = (max_width - used).max(0.);
+
+            let (mut x, spacing) = if self.justify && line != last_line && count > 1 {
+                (0., self.spacing + free / (count - 1) as f32)
+            } else {
+                let offset = match self.align_x {
+                    Horizontal::Left => 0.,
+                    Horizontal::Center => free / 2.,
+                    Horizontal::Right => free,
+                };
+                (offset, self.spacing)
+            };
+
+            for node in line_nodes.iter_mut() {
+                let size = node.size();
+                let dy = match self.align_y {
+                    Vertical::Top => 0.,
+                    Vertical::Center => (height - size.height) / 2.,
+                    Vertical::Bottom => height - size.height,
+                };
+
+                node.move_to_mut(Point::new(self.padding.left + x, y + dy));
+                x += size.width + spacing;
+            }
+
+            content_width = content_width.max(used);
+            y += height + self.line_spacing;
+        }
+
+        let content_height = (y - self.line_spacing - self.padding.top).max(0.);
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(content_width, content_height).expand(self.padding),
+        );
+
+        Node::with_children(size, nodes)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, tree), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, tree), layout)| {
+                    child.as_widget().operate(tree, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Wrap<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: advanced::Renderer + 'a,
+{
+    fn from(value: Wrap<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Creates a new [`Wrap`] with the given children.
+pub fn wrap<'a, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = impl Into<Element<'a, Message, Theme, Renderer>>>,
+) -> Wrap<'a, Message, Theme, Renderer> {
+    Wrap::with_children(children.into_iter().map(Into::into))
+}
@@ -0,0 +1,408 @@
+//! A two dimensional pad, drawn on a [`Canvas`].
+//!
+//! Dragging in the [`XyPad`] with the mouse or a finger sets both of its values at once, like a
+//! joystick. With [`XyPad::snap_back`], the values return to the center of their ranges when the
+//! pad is released.
+
+use std::ops::RangeInclusive;
+
+use iced::{
+    Color, Element, Length, Point, Rectangle, Renderer, Size, Theme,
+    mouse::{self, Cursor},
+    touch,
+    widget::canvas::{self, Canvas, Frame, Geometry, Path, Program, Stroke, event},
+};
+
+use crate::helpers::color_states;
+
+/// The possible status of a [`XyPad`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`XyPad`] can be interacted with.
+    Active,
+    /// The [`XyPad`] is being hovered.
+    Hovered,
+    /// The [`XyPad`] is being dragged.
+    Dragged,
+}
+
+/// The appearance of a [`XyPad`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the pad.
+    pub background: Color,
+    /// The color of the border of the pad.
+    pub border: Color,
+    /// The color of the axes through the center of the pad.
+    pub axes: Color,
+    /// The color of the crosshair through the position of the values.
+    pub crosshair: Color,
+    /// The color of the handle at the position of the values.
+    pub handle: Color,
+    /// The radius of the handle.
+    pub handle_radius: f32,
+}
+
+/// The theme catalog of a [`XyPad`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`XyPad`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`XyPad`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let states = color_states(palette.primary.base.color);
+    let handle = match status {
+        Status::Active => states.active,
+        Status::Hovered => states.hovered,
+        Status::Dragged => states.pressed,
+    };
+
+    Style {
+        background: palette.background.weak.color,
+        border: palette.background.strong.color,
+        axes: palette.background.strong.color,
+        crosshair: Color {
+            a: 0.6,
+            ..palette.primary.base.color
+        },
+        handle,
+        handle_radius: 6.,
+    }
+}
+
+/// The state of a [`XyPad`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    /// Whether the pad is dragged by the mouse.
+    mouse: bool,
+    /// The finger dragging the pad.
+    finger: Option<touch::Finger>,
+}
+
+impl State {
+    fn is_dragged(&self) -> bool {
+        self.mouse || self.finger.is_some()
+    }
+}
+
+/// A square pad that selects a pair of values, each in its own range.
+///
+/// The horizontal value increases to the right, and the vertical value increases upwards.
+pub struct XyPad<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    x_range: RangeInclusive<f32>,
+    y_range: RangeInclusive<f32>,
+    value: (f32, f32),
+    on_change: Box<dyn Fn(f32, f32) -> Message + 'a>,
+    snap_back: bool,
+    size: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> XyPad<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default size of a [`XyPad`].
+    pub const DEFAULT_SIZE: f32 = 160.;
+
+    /// Creates a new [`XyPad`].
+    ///
+    /// It expects:
+    ///   * the current values, horizontal then vertical
+    ///   * a function that will be called with the new values while the pad is dragged.
+    ///
+    /// Both values range from -1 to 1 by default.
+    pub fn new(value: (f32, f32), on_change: impl Fn(f32, f32) -> Message + 'a) -> Self {
+        Self {
+            x_range: -1.0..=1.,
+            y_range: -1.0..=1.,
+            value,
+            on_change: Box::new(on_change),
+            snap_back: false,
+            size: Self::DEFAULT_SIZE,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the range of the horizontal value of the [`XyPad`].
+    pub fn x_range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.x_range = range;
+        self
+    }
+
+    /// Sets the range of the vertical value of the [`XyPad`].
+    pub fn y_range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.y_range = range;
+        self
+    }
+
+    /// Sets whether the values return to the center of their ranges when the [`XyPad`] is
+    /// released, like a spring loaded joystick. Disabled by default.
+    pub fn snap_back(mut self, snap_back: bool) -> Self {
+        self.snap_back = snap_back;
+        self
+    }
+
+    /// Sets the size (width and height) of the [`XyPad`].
+    pub fn size(mut self, size: impl Into<iced::Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the style of the [`XyPad`].
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`XyPad`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the position of the values in the pad.
+    fn position(&self, size: Size) -> Point {
+        let fraction = |value: f32, range: &RangeInclusive<f32>| {
+            let (start, end) = (*range.start(), *range.end());
+            if start == end {
+                0.5
+            } else {
+                ((value - start) / (end - start)).clamp(0., 1.)
+            }
+        };
+
+        Point::new(
+            fraction(self.value.0, &self.x_range) * size.width,
+            (1. - fraction(self.value.1, &self.y_range)) * size.height,
+        )
+    }
+
+    /// Produces the change message with the values at the given position, relative to the pad.
+    fn change(&self, size: Size, position: Point) -> Message {
+        let value = |fraction: f32, range: &RangeInclusive<f32>| {
+            let (start, end) = (*range.start(), *range.end());
+            start + fraction.clamp(0., 1.) * (end - start)
+        };
+
+        (self.on_change)(
+            value(position.x / size.width, &self.x_range),
+            value(1. - position.y / size.height, &self.y_range),
+        )
+    }
+
+    /// Produces the change message snapping the values back to the center, if enabled.
+    fn released(&self) -> Option<Message> {
+        let center = |range: &RangeInclusive<f32>| (range.start() + range.end()) / 2.;
+
+        self.snap_back
+            .then(|| (self.on_change)(center(&self.x_range), center(&self.y_range)))
+    }
+}
+
+impl<Message, Theme> Program<Message, Theme> for XyPad<'_, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog,
+{
+    type State = State;
+
+    fn update(
+        &self,
+        state: &mut State,
+        event: canvas::Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> (event::Status, Option<Message>) {
+        let local = |position: Point| Point::new(position.x - bounds.x, position.y - bounds.y);
+
+        match event {
+            canvas::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return (event::Status::Ignored, None);
+                };
+
+                state.mouse = true;
+                (
+                    event::Status::Captured,
+                    Some(self.change(bounds.size(), position)),
+                )
+            }
+            canvas::Event::Mouse(mouse::Event::CursorMoved { position }) if state.mouse => (
+                event::Status::Captured,
+                Some(self.change(bounds.size(), local(position))),
+            ),
+            canvas::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.mouse =>
+            {
+                state.mouse = false;
+                (event::Status::Captured, self.released())
+            }
+            canvas::Event::Touch(touch::Event::FingerPressed { id, position })
+                if state.finger.is_none() && bounds.contains(position) =>
+            {
+                state.finger = Some(id);
+                (
+                    event::Status::Captured,
+                    Some(self.change(bounds.size(), local(position))),
+                )
+            }
+            canvas::Event::Touch(touch::Event::FingerMoved { id, position })
+                if state.finger == Some(id) =>
+            {
+                (
+                    event::Status::Captured,
+                    Some(self.change(bounds.size(), local(position))),
+                )
+            }
+            canvas::Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) if state.finger == Some(id) => {
+                state.finger = None;
+                (event::Status::Captured, self.released())
+            }
+            _ => (event::Status::Ignored, None),
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let style = theme.style(
+            &self.class,
+            if state.is_dragged() {
+                Status::Dragged
+            } else if cursor.is_over(bounds) {
+                Status::Hovered
+            } else {
+                Status::Active
+            },
+        );
+
+        let mut frame = Frame::new(renderer, bounds.size());
+        let size = frame.size();
+        let center = frame.center();
+        let line = |color: Color| Stroke::default().with_color(color).with_width(1.);
+
+        frame.fill_rectangle(Point::ORIGIN, size, style.background);
+
+        frame.stroke(
+            &Path::line(Point::new(center.x, 0.), Point::new(center.x, size.height)),
+            line(style.axes),
+        );
+        frame.stroke(
+            &Path::line(Point::new(0., center.y), Point::new(size.width, center.y)),
+            line(style.axes),
+        );
+
+        let position = self.position(size);
+        frame.stroke(
+            &Path::line(
+                Point::new(position.x, 0.),
+                Point::new(position.x, size.height),
+            ),
+            line(style.crosshair),
+        );
+        frame.stroke(
+            &Path::line(
+                Point::new(0., position.y),
+                Point::new(size.width, position.y),
+            ),
+            line(style.crosshair),
+        );
+        frame.fill(&Path::circle(position, style.handle_radius), style.handle);
+
+        frame.stroke(
+            &Path::rectangle(Point::ORIGIN, size),
+            line(style.border).with_width(2.),
+        );
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &State,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        if state.is_dragged() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(bounds) {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme> From<XyPad<'a, Message, Theme>> for Element<'a, Message, Theme>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+{
+    fn from(value: XyPad<'a, Message, Theme>) -> Self {
+        let size = Length::Fixed(value.size);
+        Element::new(Canvas::new(value).width(size).height(size))
+    }
+}
+
+/// Creates a new [`XyPad`].
+///
+/// ```
+/// use more_iced_aw::xy_pad::{XyPad, xy_pad};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Pan(f32, f32),
+/// }
+///
+/// let pad: XyPad<'_, Message> = xy_pad((0., 0.), Message::Pan)
+///     .x_range(-180.0..=180.)
+///     .y_range(-90.0..=90.)
+///     .snap_back(true);
+/// ```
+pub fn xy_pad<'a, Message, Theme>(
+    value: (f32, f32),
+    on_change: impl Fn(f32, f32) -> Message + 'a,
+) -> XyPad<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    XyPad::new(value, on_change)
+}
@@ -0,0 +1,119 @@
+//! Conversions between RGB colors and the HSL and HSV spaces.
+
+use iced::Color;
+use more_iced_aw::helpers::{ColorExt, Hsl, Hsv};
+use proptest::prelude::*;
+
+/// The tolerance on the channels of the converted colors.
+const EPSILON: f32 = 1e-4;
+
+fn assert_close(a: Color, b: Color) {
+    let close = [a.r - b.r, a.g - b.g, a.b - b.b, a.a - b.a]
+        .iter()
+        .all(|difference| difference.abs() < EPSILON);
+    assert!(close, "{a:?} != {b:?}");
+}
+
+fn rgba() -> impl Strategy<Value = Color> {
+    (0f32..=1., 0f32..=1., 0f32..=1., 0f32..=1.)
+        .prop_map(|(r, g, b, a)| Color::from_rgba(r, g, b, a))
+}
+
+proptest! {
+    #[test]
+    fn colors_round_trip_through_hsl(color in rgba()) {
+        assert_close(Color::from(color.to_hsl()), color);
+    }
+
+    #[test]
+    fn colors_round_trip_through_hsv(color in rgba()) {
+        assert_close(Color::from(color.to_hsv()), color);
+    }
+
+    #[test]
+    fn hues_are_in_range(color in rgba()) {
+        let (hsl, hsv) = (color.to_hsl(), color.to_hsv());
+        prop_assert!((0. ..360.).contains(&hsl.h));
+        prop_assert!((0. ..360.).contains(&hsv.h));
+        prop_assert!((0. ..=1. + EPSILON).contains(&hsl.s));
+        prop_assert!((0. ..=1. + EPSILON).contains(&hsv.s));
+    }
+}
+
+#[test]
+fn primary_colors_have_the_expected_hues() {
+    let red = Color::from_rgb(1., 0., 0.);
+    for (hue, color) in [
+        (0., red),
+        (60., Color::from_rgb(1., 1., 0.)),
+        (120., Color::from_rgb(0., 1., 0.)),
+        (180., Color::from_rgb(0., 1., 1.)),
+        (240., Color::from_rgb(0., 0., 1.)),
+        (300., Color::from_rgb(1., 0., 1.)),
+    ] {
+        assert_eq!(color.to_hsl().h, hue);
+        assert_eq!(color.to_hsv().h, hue);
+        assert_close(Color::from_hsl(hue, 1., 0.5), color);
+        assert_close(Color::from_hsv(hue, 1., 1.), color);
+    }
+
+    // Reds slightly towards magenta stay below 360.
+    let green = f32::from_bits(0.5f32.to_bits() - 1);
+    let color = Color::from_rgb(1., green, 0.5);
+    assert!(color.to_hsl().h < 360.);
+    assert!(color.to_hsv().h < 360.);
+
+    // Hues wrap around.
+    assert_close(Color::from_hsl(360., 1., 0.5), red);
+    assert_close(Color::from_hsv(360., 1., 1.), red);
+    assert_close(Color::from_hsl(720., 1., 0.5), red);
+    assert_close(Color::from_hsv(-120., 1., 1.), Color::from_rgb(0., 0., 1.));
+}
+
+#[test]
+fn grays_have_no_saturation() {
+    for value in [0.2, 0.5, 0.8] {
+        let gray = Color::from_rgb(value, value, value);
+        assert_eq!(
+            gray.to_hsl(),
+            Hsl {
+                h: 0.,
+                s: 0.,
+                l: value,
+                a: 1.
+            }
+        );
+        assert_eq!(
+            gray.to_hsv(),
+            Hsv {
+                h: 0.,
+                s: 0.,
+                v: value,
+                a: 1.
+            }
+        );
+
+        // The hue of a gray does not matter.
+        for hue in [0., 90., 200.] {
+            assert_close(Color::from_hsl(hue, 0., value), gray);
+            assert_close(Color::from_hsv(hue, 0., value), gray);
+        }
+    }
+}
+
+#[test]
+fn black_and_white_convert_whatever_the_hue_and_saturation() {
+    assert_eq!(Color::BLACK.to_hsl().l, 0.);
+    assert_eq!(Color::BLACK.to_hsv().v, 0.);
+    assert_eq!(Color::WHITE.to_hsl().l, 1.);
+    assert_eq!(Color::WHITE.to_hsv().v, 1.);
+    assert_eq!(Color::BLACK.to_hsv().s, 0.);
+    assert_eq!(Color::WHITE.to_hsl().s, 0.);
+
+    for (hue, saturation) in [(0., 0.), (120., 0.5), (360., 1.)] {
+        assert_close(Color::from_hsl(hue, saturation, 0.), Color::BLACK);
+        assert_close(Color::from_hsl(hue, saturation, 1.), Color::WHITE);
+        assert_close(Color::from_hsv(hue, saturation, 0.), Color::BLACK);
+    }
+    assert_close(Color::from_hsv(42., 0., 1.), Color::WHITE);
+}
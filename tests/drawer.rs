@@ -0,0 +1,51 @@
+//! Dismissal of a drawer, which follows the policy of the layers, driven with the headless harness.
+#![cfg(feature = "test-utils")]
+
+use iced::{Point, keyboard::key::Named, widget::Space};
+use more_iced_aw::{
+    drawer::{Drawer, Mode},
+    harness::{Harness, Headless},
+    layers::Dismiss,
+};
+
+/// An open drawer over an empty content, producing `()` when dismissed.
+fn drawer<'a>(mode: Mode, dismiss: Dismiss) -> Drawer<'a, (), iced::Theme, Headless> {
+    Drawer::new(true, Space::new(400, 300), Space::new(100, 300))
+        .mode(mode)
+        .size(100)
+        .on_dismiss(())
+        .dismiss(dismiss)
+}
+
+#[test]
+fn escape_dismisses_unless_the_policy_forbids_it() {
+    let mut harness = Harness::new();
+
+    let mut ui = harness.mount(drawer(Mode::Overlay, Dismiss::ANY));
+    ui.named_key(Named::Escape);
+    assert_eq!(ui.take_messages(), vec![()]);
+
+    let mut ui = harness.mount(drawer(Mode::Overlay, Dismiss::NEVER));
+    ui.named_key(Named::Escape);
+    assert!(ui.take_messages().is_empty());
+}
+
+#[test]
+fn only_the_backdrop_dismisses_on_click() {
+    let mut harness = Harness::new();
+
+    let mut ui = harness.mount(drawer(Mode::Overlay, Dismiss::ANY));
+    ui.click(Point::new(50., 50.));
+    assert!(ui.take_messages().is_empty());
+    ui.click(Point::new(300., 50.));
+    assert_eq!(ui.take_messages(), vec![()]);
+
+    let mut ui = harness.mount(drawer(Mode::Overlay, Dismiss::ESCAPE));
+    ui.click(Point::new(300., 50.));
+    assert!(ui.take_messages().is_empty());
+
+    // Beside the content, there is no backdrop to click on.
+    let mut ui = harness.mount(drawer(Mode::Beside, Dismiss::ANY));
+    ui.click(Point::new(300., 50.));
+    assert!(ui.take_messages().is_empty());
+}
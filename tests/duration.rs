@@ -0,0 +1,82 @@
+//! Parsing and display of human friendly durations.
+
+use std::time::Duration;
+
+use more_iced_aw::parsed_input::{HumanDuration, ParseDurationError};
+
+fn parse(s: &str) -> Result<Duration, ParseDurationError> {
+    s.parse::<HumanDuration>().map(Duration::from)
+}
+
+#[test]
+fn durations_parse_with_any_combination_of_units() {
+    assert_eq!(parse("1h 30m"), Ok(Duration::from_secs(90 * 60)));
+    assert_eq!(parse("90s"), Ok(Duration::from_secs(90)));
+    assert_eq!(parse("1.5h"), Ok(Duration::from_secs(90 * 60)));
+    assert_eq!(
+        parse("2d 250ms"),
+        Ok(Duration::from_millis(2 * 86_400_000 + 250))
+    );
+    assert_eq!(parse("  1 m 1s  "), Ok(Duration::from_secs(61)));
+    assert_eq!(parse("1.0004s"), Ok(Duration::from_secs(1)));
+}
+
+#[test]
+fn durations_are_displayed_canonically_and_round_trip() {
+    let display = |s: &str| s.parse::<HumanDuration>().unwrap().to_string();
+    assert_eq!(display("90s"), "1m 30s");
+    assert_eq!(display("1.5h"), "1h 30m");
+    assert_eq!(display("0ms"), "0s");
+    assert_eq!(display("25h 1001ms"), "1d 1h 1s 1ms");
+
+    for millis in [
+        0,
+        1,
+        999,
+        1_000,
+        61_001,
+        3_600_000,
+        86_400_000,
+        123_456_789_012,
+    ] {
+        let duration = HumanDuration::from(Duration::from_millis(millis));
+        assert_eq!(duration.to_string().parse(), Ok(duration));
+    }
+}
+
+#[test]
+fn invalid_durations_are_rejected() {
+    assert_eq!(parse(""), Err(ParseDurationError::Empty));
+    assert_eq!(parse("   "), Err(ParseDurationError::Empty));
+    assert_eq!(
+        parse("3w"),
+        Err(ParseDurationError::UnknownUnit("w".to_owned()))
+    );
+    assert_eq!(
+        parse("1h 30"),
+        Err(ParseDurationError::MissingUnit("30".to_owned()))
+    );
+    assert_eq!(
+        parse("h"),
+        Err(ParseDurationError::InvalidNumber("h".to_owned()))
+    );
+    assert_eq!(
+        parse("1.2.3s"),
+        Err(ParseDurationError::InvalidNumber("1.2.3".to_owned()))
+    );
+    assert_eq!(
+        parse("-1s"),
+        Err(ParseDurationError::InvalidNumber("-".to_owned()))
+    );
+}
+
+#[test]
+fn too_big_durations_overflow() {
+    assert_eq!(
+        parse("999999999999999999999d"),
+        Err(ParseDurationError::Overflow)
+    );
+
+    let max = Duration::from_millis(u64::MAX / 2);
+    assert!(parse(&HumanDuration::from(max).to_string()).is_ok());
+}
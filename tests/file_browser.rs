@@ -0,0 +1,53 @@
+//! Loading of the listings of the file browser, which are read by tasks.
+
+use std::path::PathBuf;
+
+use more_iced_aw::file_browser::{Action, Content, FileEntry};
+
+/// A directory entry that does not need to exist.
+fn directory(path: &str) -> FileEntry {
+    let path = PathBuf::from(path);
+
+    FileEntry {
+        name: path.file_name().unwrap().to_string_lossy().into_owned(),
+        path,
+        is_dir: true,
+        size: 0,
+        modified: None,
+    }
+}
+
+#[test]
+fn expanded_directories_load_through_actions() {
+    let (mut content, _) = Content::new("/root");
+    assert!(content.is_loading("/root".as_ref()));
+
+    let _ = content.update(Action::Loaded(
+        "/root".into(),
+        Ok(vec![directory("/root/a")]),
+    ));
+    assert!(!content.is_loading("/root".as_ref()));
+
+    let _ = content.update(Action::Toggle("/root/a".into()));
+    assert!(content.is_expanded("/root/a".as_ref()));
+    assert!(content.is_loading("/root/a".as_ref()));
+
+    let _ = content.update(Action::Loaded("/root/a".into(), Ok(Vec::new())));
+    assert!(!content.is_loading("/root/a".as_ref()));
+}
+
+#[test]
+fn listings_of_collapsed_directories_are_dropped() {
+    let (mut content, _) = Content::new("/root");
+    let _ = content.update(Action::Loaded(
+        "/root".into(),
+        Ok(vec![directory("/root/a")]),
+    ));
+
+    let _ = content.update(Action::Toggle("/root/a".into()));
+    let _ = content.update(Action::Toggle("/root/a".into()));
+    let _ = content.update(Action::Loaded("/root/a".into(), Ok(Vec::new())));
+
+    assert!(!content.is_expanded("/root/a".as_ref()));
+    assert!(!content.is_loading("/root/a".as_ref()));
+}
@@ -0,0 +1,145 @@
+//! Regression tests of the sizing of the grid, laid out with the headless harness.
+#![cfg(feature = "test-utils")]
+
+use iced::{Length, Rectangle, Size, widget::Space};
+use more_iced_aw::{
+    grid::Grid,
+    harness::{Harness, Headless},
+    i18n::{self, Direction, Locale},
+};
+
+/// A grid of fixed size cells, given as rows of `(width, height)`.
+fn grid<'a>(rows: &[&[(f32, f32)]]) -> Grid<'a, (), iced::Theme, Headless> {
+    Grid::with_rows(rows.iter().map(|row| {
+        row.iter()
+            .map(|&(width, height)| Space::new(width, height))
+            .collect::<Vec<_>>()
+    }))
+}
+
+/// Lays out a grid and returns its bounds and the ones of its cells.
+fn layout(grid: Grid<'_, (), iced::Theme, Headless>) -> (Rectangle, Vec<Rectangle>) {
+    let mut harness: Harness<()> = Harness::new().size(Size::new(800., 600.));
+    let ui = harness.mount(grid);
+    let layout = ui.layout();
+
+    (
+        layout.bounds(),
+        layout.children().map(|cell| cell.bounds()).collect(),
+    )
+}
+
+#[test]
+fn columns_take_the_width_of_their_widest_cell() {
+    let (bounds, cells) = layout(grid(&[&[(10., 20.), (30., 10.)], &[(40., 5.), (5., 15.)]]));
+
+    assert_eq!(bounds.size(), Size::new(70., 35.));
+    assert_eq!(cells[0].position(), [0., 0.].into());
+    // The rows are vertically centered by default.
+    assert_eq!(cells[1].position(), [40., 5.].into());
+    assert_eq!(cells[2].position(), [0., 25.].into());
+    assert_eq!(cells[3].position(), [40., 20.].into());
+}
+
+#[test]
+fn spacing_and_padding_are_added_between_and_around_cells() {
+    let (bounds, cells) = layout(
+        grid(&[&[(10., 10.), (10., 10.)], &[(10., 10.), (10., 10.)]])
+            .column_spacing(5)
+            .row_spacing(3)
+            .padding(2),
+    );
+
+    assert_eq!(bounds.size(), Size::new(29., 27.));
+    assert_eq!(cells[0].position(), [2., 2.].into());
+    assert_eq!(cells[3].position(), [17., 15.].into());
+}
+
+#[test]
+fn uniform_cells_take_the_size_of_the_largest_one() {
+    let (bounds, cells) =
+        layout(grid(&[&[(10., 20.), (30., 10.)], &[(5., 5.), (5., 5.)]]).uniform(true));
+
+    assert_eq!(bounds.size(), Size::new(60., 40.));
+    assert!(cells.iter().all(|cell| cell.size() == Size::new(30., 20.)));
+}
+
+#[test]
+fn uniform_cells_share_the_fill_evenly() {
+    let grid = Grid::with_rows([[Space::new(0., 0.), Space::new(Length::Fill, 0.)]])
+        .width(Length::Fill)
+        .uniform(true);
+    let (bounds, cells) = layout(grid);
+
+    assert_eq!(bounds.width, 800.);
+    assert_eq!(cells[0].width, 400.);
+    assert_eq!(cells[1].x, 400.);
+    assert_eq!(cells[1].width, 400.);
+}
+
+#[test]
+fn fill_width_takes_the_window() {
+    let (bounds, _) = layout(grid(&[&[(10., 10.)]]).width(Length::Fill));
+
+    assert_eq!(bounds.width, 800.);
+    assert_eq!(bounds.height, 10.);
+}
+
+#[test]
+fn right_to_left_mirrors_the_columns() {
+    let (bounds, cells) = layout(
+        grid(&[&[(10., 10.), (30., 10.)]])
+            .padding([0, 5])
+            .direction(Direction::RightToLeft),
+    );
+
+    assert_eq!(bounds.size(), Size::new(50., 10.));
+    assert_eq!(cells[0].position(), [35., 0.].into());
+    assert_eq!(cells[1].position(), [5., 0.].into());
+}
+
+#[test]
+fn unset_direction_is_read_when_laid_out() {
+    let explicit = grid(&[&[(10., 10.), (30., 10.)]]).direction(Direction::LeftToRight);
+    let unset = grid(&[&[(10., 10.), (30., 10.)]]);
+    i18n::set_format(Locale {
+        direction: Direction::RightToLeft,
+        ..Locale::DEFAULT
+    });
+
+    let (_, explicit) = layout(explicit);
+    let (_, unset) = layout(unset);
+    i18n::set_format(Locale::DEFAULT);
+
+    assert_eq!(explicit[0].position(), [0., 0.].into());
+    assert_eq!(unset[0].position(), [30., 0.].into());
+}
+
+#[test]
+fn auto_fit_reflows_the_cells_in_the_columns_that_fit() {
+    let grid = Grid::with_rows([(0..5).map(|_| Space::new(10., 10.)).collect::<Vec<_>>()])
+        .auto_fit(200)
+        .column_spacing(10);
+    let (bounds, cells) = layout(grid);
+
+    // 800 pixels fit 3 columns of at least 200 pixels, sharing the width.
+    let column = (800. - 20.) / 3.;
+    assert_eq!(bounds.size(), Size::new(800., 20.));
+    assert_eq!(cells[1].position(), [column + 10., 0.].into());
+    assert_eq!(cells[3].position(), [0., 10.].into());
+}
+
+/// A theme implementing none of the catalogs of the crate.
+#[derive(Default)]
+struct Plain;
+
+#[test]
+fn grids_work_with_themes_without_catalog() {
+    let grid: Grid<'_, (), Plain, Headless> = Grid::with_rows([[Space::new(10., 10.)]]);
+
+    let mut harness: Harness<(), Plain> = Harness::new();
+    let mut ui = harness.mount(grid);
+    ui.draw();
+
+    assert_eq!(ui.layout().bounds().size(), Size::new(10., 10.));
+}
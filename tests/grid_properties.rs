@@ -0,0 +1,244 @@
+//! Invariants of the grid layout, checked on randomly generated grids.
+//!
+//! The grids are generated with proptest, which shrinks a failing grid to a minimal one and
+//! reports it, and saves its seed in `proptest-regressions` to replay it first.
+#![cfg(feature = "test-utils")]
+
+use iced::{Length, Rectangle, Size, widget::Space};
+use more_iced_aw::{
+    grid::{Axis, Grid},
+    harness::{Harness, Headless},
+};
+use proptest::prelude::*;
+
+/// The number of generated grids.
+const CASES: u32 = 2000;
+
+/// The size of the window the grids are laid out in.
+const WINDOW: Size = Size::new(800., 600.);
+
+/// The tolerance on the sums of floating point sizes.
+const EPSILON: f32 = 0.01;
+
+/// A randomly generated grid.
+///
+/// The fixed sizes are small enough for the content to always fit in the grid.
+#[derive(Debug, Clone)]
+struct Case {
+    rows: Vec<Vec<(Length, Length)>>,
+    width: Length,
+    height: Length,
+    column_spacing: f32,
+    row_spacing: f32,
+    padding: f32,
+    axis: Axis,
+    uniform: bool,
+}
+
+/// The length of a cell.
+fn cell_length() -> impl Strategy<Value = Length> {
+    prop_oneof![
+        (0..=50u8).prop_map(|size| Length::Fixed(size.into())),
+        Just(Length::Shrink),
+        Just(Length::Fill),
+        (1..4u16).prop_map(Length::FillPortion),
+    ]
+}
+
+/// The length of a grid.
+fn grid_length() -> impl Strategy<Value = Length> {
+    prop_oneof![
+        Just(Length::Shrink),
+        Just(Length::Fill),
+        (320..=500u16).prop_map(|size| Length::Fixed(size.into())),
+    ]
+}
+
+impl Case {
+    /// The strategy generating a [`Case`].
+    fn strategy() -> impl Strategy<Value = Self> {
+        let rows = prop::collection::vec(
+            prop::collection::vec((cell_length(), cell_length()), 0..=5),
+            0..=5,
+        );
+        let axis = prop_oneof![Just(Axis::Horizontal), Just(Axis::Vertical)];
+
+        (
+            rows,
+            grid_length(),
+            grid_length(),
+            0..=10u8,
+            0..=10u8,
+            0..=10u8,
+            axis,
+            prop::bool::weighted(0.25),
+        )
+            .prop_map(
+                |(rows, width, height, column_spacing, row_spacing, padding, axis, uniform)| Self {
+                    rows,
+                    width,
+                    height,
+                    column_spacing: column_spacing.into(),
+                    row_spacing: row_spacing.into(),
+                    padding: padding.into(),
+                    axis,
+                    uniform,
+                },
+            )
+    }
+
+    /// Builds the [`Grid`] of the [`Case`].
+    fn grid<'a>(&self) -> Grid<'a, (), iced::Theme, Headless> {
+        Grid::with_rows(self.rows.iter().map(|row| {
+            row.iter()
+                .map(|&(width, height)| Space::new(width, height))
+                .collect::<Vec<_>>()
+        }))
+        .width(self.width)
+        .height(self.height)
+        .column_spacing(self.column_spacing)
+        .row_spacing(self.row_spacing)
+        .padding(self.padding)
+        .main_axis(self.axis)
+        .uniform(self.uniform)
+    }
+
+    /// Returns the number of columns of the grid.
+    fn columns(&self) -> usize {
+        self.rows.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// Indicates if a cell fills the grid along a dimension.
+    fn fills(&self, dimension: impl Fn(&(Length, Length)) -> Length) -> bool {
+        self.rows
+            .iter()
+            .flatten()
+            .any(|cell| dimension(cell).fill_factor() > 0)
+    }
+}
+
+/// Sums tracks with the spacing between them.
+fn total(tracks: &[f32], spacing: f32) -> f32 {
+    tracks.iter().sum::<f32>() + spacing * tracks.len().saturating_sub(1) as f32
+}
+
+/// Lays out a [`Case`] and checks the invariants of the grid layout.
+fn check(case: &Case) {
+    let mut harness: Harness<()> = Harness::new().size(WINDOW);
+    let ui = harness.mount(case.grid());
+    let layout = ui.layout();
+    let bounds = layout.bounds();
+
+    let mut children = layout.children();
+    let cells: Vec<Vec<Rectangle>> = case
+        .rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|_| children.next().unwrap().bounds())
+                .collect()
+        })
+        .collect();
+
+    let context = format!("{case:#?}\nbounds: {bounds:?}\ncells: {cells:#?}");
+
+    // No NaN nor negative size.
+    for rectangle in std::iter::once(&bounds).chain(cells.iter().flatten()) {
+        assert!(
+            [rectangle.x, rectangle.y, rectangle.width, rectangle.height]
+                .iter()
+                .all(|value| value.is_finite())
+                && rectangle.width >= 0.
+                && rectangle.height >= 0.,
+            "invalid bounds {rectangle:?}, {context}"
+        );
+    }
+
+    let mut columns: Vec<f32> = (0..case.columns())
+        .map(|j| {
+            cells
+                .iter()
+                .filter_map(|row| row.get(j))
+                .map(|cell| cell.width)
+                .fold(0., f32::max)
+        })
+        .collect();
+    let mut rows: Vec<f32> = cells
+        .iter()
+        .map(|row| row.iter().map(|cell| cell.height).fold(0., f32::max))
+        .collect();
+
+    // Uniform tracks all take the size of the cells, even the empty ones.
+    if case.uniform {
+        let width = columns.iter().copied().fold(0., f32::max);
+        let height = rows.iter().copied().fold(0., f32::max);
+        columns.iter_mut().for_each(|column| *column = width);
+        rows.iter_mut().for_each(|row| *row = height);
+    }
+
+    // The children stay within their tracks, inside of the padding.
+    let mut y = case.padding;
+    for (i, row) in cells.iter().enumerate() {
+        let mut x = case.padding;
+        for (j, cell) in row.iter().enumerate() {
+            assert!(
+                (cell.x - x).abs() < EPSILON,
+                "cell ({i}, {j}) is not at the start of its column, {context}"
+            );
+            assert!(
+                cell.y >= y - EPSILON && cell.y + cell.height <= y + rows[i] + EPSILON,
+                "cell ({i}, {j}) is out of its row, {context}"
+            );
+            x += columns[j] + case.column_spacing;
+        }
+        y += rows[i] + case.row_spacing;
+    }
+
+    let content_width = total(&columns, case.column_spacing) + 2. * case.padding;
+    let content_height = total(&rows, case.row_spacing) + 2. * case.padding;
+
+    // The children stay within the bounds of the grid.
+    assert!(
+        content_width <= bounds.width + EPSILON && content_height <= bounds.height + EPSILON,
+        "the content of {content_width}x{content_height} overflows, {context}"
+    );
+
+    // A shrinking grid has the size of its tracks.
+    if case.width == Length::Shrink {
+        assert!(
+            (bounds.width - content_width).abs() < EPSILON,
+            "the width is not the one of the columns ({content_width}), {context}"
+        );
+    }
+    if case.height == Length::Shrink {
+        assert!(
+            (bounds.height - content_height).abs() < EPSILON,
+            "the height is not the one of the rows ({content_height}), {context}"
+        );
+    }
+
+    // The free space of a filling grid is fully given to the filling tracks.
+    if !case.uniform {
+        if case.width != Length::Shrink && case.fills(|cell| cell.0) {
+            assert!(
+                (bounds.width - content_width).abs() < EPSILON,
+                "the width is not fully distributed to the columns ({content_width}), {context}"
+            );
+        }
+        if case.height != Length::Shrink && case.fills(|cell| cell.1) {
+            assert!(
+                (bounds.height - content_height).abs() < EPSILON,
+                "the height is not fully distributed to the rows ({content_height}), {context}"
+            );
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(CASES))]
+
+    #[test]
+    fn random_grids_respect_the_layout_invariants(case in Case::strategy()) {
+        check(&case);
+    }
+}
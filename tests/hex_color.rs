@@ -0,0 +1,68 @@
+//! Parsing and display of hexadecimal colors.
+
+use iced::Color;
+use more_iced_aw::parsed_input::{HexColor, ParseColorError};
+
+fn parse(s: &str) -> Result<Color, ParseColorError> {
+    s.parse::<HexColor>().map(Color::from)
+}
+
+#[test]
+fn all_the_lengths_of_hexadecimal_colors_parse() {
+    let color = Color::from_rgb8(0x11, 0xaa, 0xff);
+    assert_eq!(parse("#1af"), Ok(color));
+    assert_eq!(parse("#11aaff"), Ok(color));
+    assert_eq!(parse("#11AAFF"), Ok(color));
+
+    let color = Color::from_rgba8(0x12, 0x34, 0x56, 0x78 as f32 / 255.);
+    assert_eq!(parse("#12345678"), Ok(color));
+    assert_eq!(
+        parse("#1234"),
+        Ok(Color::from_rgba8(0x11, 0x22, 0x33, 0x44 as f32 / 255.))
+    );
+}
+
+#[test]
+fn the_hash_is_optional() {
+    assert_eq!(parse("1af"), parse("#1af"));
+    assert_eq!(parse("11aaff"), parse("#11aaff"));
+    assert_eq!(parse("  12345678 "), parse("#12345678"));
+}
+
+#[test]
+fn invalid_colors_are_rejected() {
+    assert_eq!(parse("#12345g"), Err(ParseColorError::InvalidDigit('g')));
+    assert_eq!(parse("##123456"), Err(ParseColorError::InvalidDigit('#')));
+    assert_eq!(parse("#12 34 56"), Err(ParseColorError::InvalidDigit(' ')));
+    assert_eq!(parse("#é12"), Err(ParseColorError::InvalidDigit('é')));
+
+    for (s, len) in [
+        ("", 0),
+        ("#", 0),
+        ("#12", 2),
+        ("#12345", 5),
+        ("#1234567", 7),
+    ] {
+        assert_eq!(parse(s), Err(ParseColorError::InvalidLength(len)));
+    }
+    assert_eq!(parse("#123456789"), Err(ParseColorError::InvalidLength(9)));
+}
+
+#[test]
+fn colors_are_displayed_canonically_and_round_trip() {
+    let display = |s: &str| s.parse::<HexColor>().unwrap().to_string();
+    assert_eq!(display("#1AF"), "#11aaff");
+    assert_eq!(display("#11aaffff"), "#11aaff");
+    assert_eq!(display("1234"), "#11223344");
+
+    for color in [
+        Color::BLACK,
+        Color::WHITE,
+        Color::TRANSPARENT,
+        Color::from_rgb8(0x12, 0x34, 0x56),
+        Color::from_rgba8(0xfe, 0x01, 0x80, 0x7f as f32 / 255.),
+    ] {
+        let hex = HexColor::from(color);
+        assert_eq!(hex.to_string().parse(), Ok(hex));
+    }
+}
@@ -0,0 +1,107 @@
+//! Tests of the widgets for iced 0.14, driven without a window.
+#![cfg(feature = "iced_014")]
+
+use iced_widget_014::{
+    Space, Theme,
+    core::{
+        Element, Event, Layout, Length, Point, Rectangle, Shell, Size, clipboard, keyboard,
+        layout::{Limits, Node},
+        mouse,
+        widget::Tree,
+    },
+    text_input,
+};
+use more_iced_aw::{
+    grid::iced_014::Grid,
+    parsed_input::{Content, Parsed, iced_014::ParsedInput},
+};
+
+/// Lays out an element in a window of 800x600.
+fn layout<Message>(element: &mut Element<'_, Message, Theme, ()>, tree: &mut Tree) -> Node {
+    element
+        .as_widget_mut()
+        .layout(tree, &(), &Limits::new(Size::ZERO, Size::new(800., 600.)))
+}
+
+/// The press of a named key.
+fn press(key: keyboard::key::Named) -> Event {
+    Event::Keyboard(keyboard::Event::KeyPressed {
+        key: keyboard::Key::Named(key),
+        modified_key: keyboard::Key::Named(key),
+        physical_key: keyboard::key::Physical::Unidentified(
+            keyboard::key::NativeCode::Unidentified,
+        ),
+        location: keyboard::Location::Standard,
+        modifiers: keyboard::Modifiers::empty(),
+        text: None,
+        repeat: false,
+    })
+}
+
+#[test]
+fn grids_lay_out_like_the_ones_of_iced_013() {
+    let rows: [[(f32, f32); 2]; 2] = [[(10., 20.), (30., 10.)], [(40., 5.), (5., 15.)]];
+    let grid = Grid::with_rows(
+        rows.map(|row| row.map(|(width, height)| Space::new().width(width).height(height))),
+    );
+    let mut element: Element<'_, (), Theme, ()> = grid.into();
+    let mut tree = Tree::new(&element);
+
+    let node = layout(&mut element, &mut tree);
+    let cells: Vec<_> = node.children().iter().map(Node::bounds).collect();
+
+    assert_eq!(node.size(), Size::new(70., 35.));
+    assert_eq!(cells[0].position(), Point::new(0., 0.));
+    assert_eq!(cells[1].position(), Point::new(40., 5.));
+    assert_eq!(cells[2].position(), Point::new(0., 25.));
+    assert_eq!(cells[3].size(), Size::new(5., 15.));
+}
+
+#[test]
+fn filling_grids_shrink_when_compressed() {
+    let grid = Grid::new()
+        .width(Length::Fill)
+        .push_row([Space::new().width(30.).height(10.)]);
+    let mut element: Element<'_, (), Theme, ()> = grid.into();
+    let mut tree = Tree::new(&element);
+
+    let node = element.as_widget_mut().layout(
+        &mut tree,
+        &(),
+        &Limits::new(Size::ZERO, Size::new(800., 600.)).width(Length::Shrink),
+    );
+
+    assert_eq!(node.size().width, 30.);
+}
+
+#[test]
+fn parsed_inputs_step_and_group_their_value() {
+    let content = Content::<i32, std::num::ParseIntError>::new(999);
+    let input = ParsedInput::new("", &content)
+        .on_input(|parsed| parsed)
+        .step(1.)
+        .group_digits(',');
+    let mut element: Element<'_, Parsed<i32, _>, Theme, ()> = input.into();
+    let mut tree = Tree::new(&element);
+    let node = layout(&mut element, &mut tree);
+    tree.state.downcast_mut::<text_input::State<()>>().focus();
+
+    let mut messages = Vec::new();
+    let mut shell = Shell::new(&mut messages);
+    element.as_widget_mut().update(
+        &mut tree,
+        &press(keyboard::key::Named::ArrowUp),
+        Layout::new(&node),
+        mouse::Cursor::Unavailable,
+        &(),
+        &mut clipboard::Null,
+        &mut shell,
+        &Rectangle::with_size(Size::new(800., 600.)),
+    );
+    let captured = shell.is_event_captured();
+
+    assert!(captured);
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].get_string(), "1,000");
+    assert_eq!(messages[0].get_result(), &Ok(1000));
+}
@@ -0,0 +1,74 @@
+//! Parsing and display of lengths.
+
+use iced::Length;
+use more_iced_aw::helpers::{LengthSpec, ParseLengthError};
+
+fn parse(s: &str) -> Result<Length, ParseLengthError> {
+    s.parse::<LengthSpec>().map(Length::from)
+}
+
+#[test]
+fn keywords_and_portions_parse() {
+    assert_eq!(parse("fill"), Ok(Length::Fill));
+    assert_eq!(parse(" Shrink "), Ok(Length::Shrink));
+    assert_eq!(parse("fill:3"), Ok(Length::FillPortion(3)));
+    assert_eq!(parse("FILL: 3"), Ok(Length::FillPortion(3)));
+    assert_eq!(parse("fill(3)"), Ok(Length::FillPortion(3)));
+    assert_eq!(parse("fill( 12 )"), Ok(Length::FillPortion(12)));
+}
+
+#[test]
+fn pixels_parse_with_or_without_a_suffix() {
+    assert_eq!(parse("120px"), Ok(Length::Fixed(120.)));
+    assert_eq!(parse("120"), Ok(Length::Fixed(120.)));
+    assert_eq!(parse("12.5 PX"), Ok(Length::Fixed(12.5)));
+    assert_eq!(parse("0px"), Ok(Length::Fixed(0.)));
+}
+
+#[test]
+fn invalid_lengths_are_rejected() {
+    for s in [
+        "",
+        "px",
+        "full",
+        "fill:",
+        "fill()",
+        "fill(3",
+        "fill:-1",
+        "fill:1.5",
+        "fill:70000",
+        "-1px",
+        "nan",
+        "infpx",
+        "12 em",
+    ] {
+        assert_eq!(
+            parse(s),
+            Err(ParseLengthError {
+                string: s.to_owned()
+            }),
+            "{s:?} was parsed"
+        );
+    }
+}
+
+#[test]
+fn lengths_are_displayed_canonically_and_round_trip() {
+    let display = |s: &str| s.parse::<LengthSpec>().unwrap().to_string();
+    assert_eq!(display("FILL"), "fill");
+    assert_eq!(display("fill(3)"), "fill:3");
+    assert_eq!(display("120"), "120px");
+    assert_eq!(display("12.5px"), "12.5px");
+
+    for length in [
+        Length::Fill,
+        Length::Shrink,
+        Length::FillPortion(7),
+        Length::Fixed(0.),
+        Length::Fixed(0.1),
+        Length::Fixed(1234.5678),
+    ] {
+        let spec = LengthSpec::from(length);
+        assert_eq!(spec.to_string().parse(), Ok(spec));
+    }
+}
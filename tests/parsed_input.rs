@@ -0,0 +1,267 @@
+//! Regression tests of the event handling of the parsed input, driven with the headless harness.
+#![cfg(feature = "test-utils")]
+
+use std::num::ParseIntError;
+
+use iced::{
+    Point,
+    keyboard::{Modifiers, key::Named},
+    widget::text_input,
+};
+use more_iced_aw::{
+    harness::{Harness, Headless},
+    parsed_input::{
+        Content, Parsed, ParsedInput, Vector, VectorError, VectorInput, numeric, vector_input,
+    },
+};
+
+type Number = Content<u32, ParseIntError>;
+
+#[derive(Debug, Clone)]
+enum Message {
+    Input(Parsed<u32, ParseIntError>),
+    Submit,
+}
+
+/// The view of the tests: a numeric input.
+fn view(content: &Number) -> ParsedInput<'_, u32, ParseIntError, Message, iced::Theme, Headless> {
+    ParsedInput::new("Number", content)
+        .id("number")
+        .allowed_chars(numeric())
+        .step(1.)
+        .on_input(Message::Input)
+        .on_paste(Message::Input)
+        .on_submit(Message::Submit)
+}
+
+/// Applies the messages to the content, and returns whether it was submitted.
+fn update(content: &mut Number, messages: Vec<Message>) -> bool {
+    messages
+        .into_iter()
+        .fold(false, |submitted, message| match message {
+            Message::Input(parsed) => {
+                content.update(parsed);
+                submitted
+            }
+            Message::Submit => true,
+        })
+}
+
+/// Types a text in the input, updating the content after each character like an application.
+fn type_text(harness: &mut Harness<Message>, content: &mut Number, text: &str) {
+    for c in text.chars() {
+        let messages = {
+            let mut ui = harness.mount(view(content));
+            ui.type_text(&c.to_string());
+            ui.take_messages()
+        };
+        update(content, messages);
+    }
+}
+
+#[test]
+fn clicking_focuses_and_typing_parses() {
+    let mut harness = Harness::new();
+    let mut content = Number::new(0);
+
+    let messages = {
+        let mut ui = harness.mount(view(&content));
+        ui.click(Point::new(10., 10.));
+        ui.named_key(Named::End);
+        ui.named_key(Named::Backspace);
+        ui.take_messages()
+    };
+    update(&mut content, messages);
+
+    type_text(&mut harness, &mut content, "4a2");
+
+    assert_eq!(*content, 42);
+    assert!(content.is_valid());
+}
+
+#[test]
+fn unfocused_input_ignores_keys() {
+    let mut harness = Harness::new();
+    let mut content = Number::new(7);
+
+    type_text(&mut harness, &mut content, "1");
+    let mut ui = harness.mount(view(&content));
+    ui.named_key(Named::ArrowUp);
+
+    assert!(ui.messages().is_empty());
+    drop(ui);
+    assert_eq!(*content, 7);
+}
+
+#[test]
+fn arrows_step_the_focused_value() {
+    let mut harness = Harness::new();
+    let mut content = Number::new(7);
+
+    let messages = {
+        let mut ui = harness.mount(view(&content));
+        ui.focus(text_input::Id::new("number"));
+        ui.named_key(Named::ArrowUp);
+        ui.take_messages()
+    };
+    update(&mut content, messages);
+    assert_eq!(*content, 8);
+
+    let messages = {
+        let mut ui = harness.mount(view(&content));
+        ui.modifiers(Modifiers::SHIFT);
+        ui.named_key(Named::ArrowDown);
+        ui.take_messages()
+    };
+    update(&mut content, messages);
+    assert!(*content < 8);
+}
+
+#[test]
+fn fine_step_moves_integers_by_one() {
+    let mut harness = Harness::new();
+    let mut content = Number::new(7);
+
+    for modifiers in [Modifiers::CTRL, Modifiers::ALT] {
+        let messages = {
+            let mut ui = harness.mount(view(&content));
+            ui.focus(text_input::Id::new("number"));
+            ui.modifiers(modifiers);
+            ui.named_key(Named::ArrowUp);
+            ui.take_messages()
+        };
+        update(&mut content, messages);
+    }
+
+    assert_eq!(*content, 9);
+}
+
+#[test]
+fn enter_submits() {
+    let mut harness = Harness::new();
+    let content = Number::new(3);
+
+    let mut ui = harness.mount(view(&content));
+    ui.focus(text_input::Id::new("number"));
+    ui.named_key(Named::Enter);
+
+    assert!(matches!(ui.messages(), [Message::Submit]));
+}
+
+#[test]
+fn pasting_is_filtered_and_parsed() {
+    let mut harness = Harness::new();
+    let mut content = Number::new(1);
+    harness.set_clipboard("2x3");
+
+    let messages = {
+        let mut ui = harness.mount(view(&content));
+        ui.focus(text_input::Id::new("number"));
+        ui.modifiers(Modifiers::COMMAND);
+        ui.key(iced::keyboard::Key::Character("v".into()));
+        ui.take_messages()
+    };
+    update(&mut content, messages);
+
+    assert_eq!(*content, 123);
+}
+
+type Vec3 = Content<Vector<i32, 3>, VectorError<ParseIntError>>;
+
+/// The view of the vector tests: a vector input producing its parsed values as messages.
+fn vector_view(
+    content: &Vec3,
+) -> VectorInput<
+    '_,
+    i32,
+    ParseIntError,
+    3,
+    Parsed<Vector<i32, 3>, VectorError<ParseIntError>>,
+    iced::Theme,
+    Headless,
+> {
+    vector_input(["X", "Y", "Z"], content).on_input(|parsed| parsed)
+}
+
+#[test]
+fn commas_do_not_leak_into_other_vector_fields() {
+    let mut harness = Harness::new();
+    let mut content = Vec3::new([1, 2, 3].into());
+
+    {
+        let mut ui = harness.mount(vector_view(&content));
+        ui.click(Point::new(10., 10.));
+        ui.named_key(Named::End);
+    }
+
+    for c in [",", "5"] {
+        let messages = {
+            let mut ui = harness.mount(vector_view(&content));
+            ui.type_text(c);
+            ui.take_messages()
+        };
+        for parsed in messages {
+            content.update(parsed);
+        }
+    }
+
+    assert_eq!(content.components, [15, 2, 3]);
+    assert!(content.is_valid());
+}
+
+/// The content of a regex input.
+#[cfg(feature = "regex")]
+type Pattern =
+    Content<more_iced_aw::parsed_input::PatternString, more_iced_aw::parsed_input::PatternError>;
+
+/// Types a text in a regex input, updating the content after each character, and returns the content.
+#[cfg(feature = "regex")]
+fn type_in_regex_input(regex: &str, text: &str) -> Pattern {
+    fn view<'a>(
+        regex: &str,
+        content: &'a Pattern,
+    ) -> ParsedInput<
+        'a,
+        more_iced_aw::parsed_input::PatternString,
+        more_iced_aw::parsed_input::PatternError,
+        Parsed<more_iced_aw::parsed_input::PatternString, more_iced_aw::parsed_input::PatternError>,
+        iced::Theme,
+        Headless,
+    > {
+        more_iced_aw::parsed_input::regex_input(
+            "",
+            content,
+            regex::Regex::new(regex).unwrap(),
+            "no match",
+        )
+        .id("regex")
+        .on_input(|parsed| parsed)
+    }
+
+    let mut harness = Harness::new();
+    let mut content = Pattern::default();
+
+    harness
+        .mount(view(regex, &content))
+        .focus(text_input::Id::new("regex"));
+    for c in text.chars() {
+        let messages = {
+            let mut ui = harness.mount(view(regex, &content));
+            ui.type_text(&c.to_string());
+            ui.take_messages()
+        };
+        for parsed in messages {
+            content.update(parsed);
+        }
+    }
+    content
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn regex_inputs_match_whole_strings() {
+    assert!(type_in_regex_input("(?x) [a-z]+ # lowercase", "abc").is_valid());
+    assert!(!type_in_regex_input("(?x) [a-z]+ # lowercase", "ab1").is_valid());
+    assert!(type_in_regex_input("a|ab", "ab").is_valid());
+    assert!(!type_in_regex_input("b", "abc").is_valid());
+}
@@ -0,0 +1,67 @@
+//! Parsing, display and stepping of the values of the parsed input presets.
+
+use more_iced_aw::{
+    i18n::Locale,
+    parsed_input::{Fixed, Money, ParseMoneyError, Scientific, Step, decimal, decimal_for},
+};
+
+#[test]
+fn rounded_floats_round_trip() {
+    let fixed: Fixed<f64, 2> = "0.0015".parse().unwrap();
+    assert_eq!(fixed.value, 0.);
+    assert_eq!(fixed.to_string().parse(), Ok(fixed));
+
+    let fixed = Fixed::<f64, 3>::new(1.23456789);
+    assert_eq!(fixed.value, 1.235);
+    assert_eq!(fixed.to_string().parse(), Ok(fixed));
+
+    let scientific: Scientific<f64, 2> = "0.00123456".parse().unwrap();
+    assert_eq!(scientific.value, 1.23e-3);
+    assert_eq!(scientific.to_string().parse(), Ok(scientific));
+}
+
+#[test]
+fn money_rejects_extra_decimals_without_panicking() {
+    assert_eq!("12.5".parse(), Ok(Money::<'$', 2>::new(1250)));
+    assert_eq!(
+        "12.505".parse::<Money<'$', 2>>(),
+        Err(ParseMoneyError::TooPrecise(2))
+    );
+    assert_eq!(
+        "0.1".parse::<Money<'$', 0>>(),
+        Err(ParseMoneyError::TooPrecise(0))
+    );
+    assert_eq!(
+        "1.000000000000000001".parse(),
+        Ok(Money::<'$', 18>::new(1_000_000_000_000_000_001))
+    );
+    assert_eq!(
+        "10".parse::<Money<'$', 18>>(),
+        Err(ParseMoneyError::Overflow)
+    );
+}
+
+#[test]
+fn integers_step_exactly_by_at_least_one() {
+    assert_eq!(5i32.step(0.1), 6);
+    assert_eq!(5i32.step(-0.1), 4);
+    assert_eq!(5i32.step(0.), 5);
+    assert_eq!(5i32.step(2.6), 8);
+
+    let big = (1u64 << 53) + 1;
+    assert_eq!(big.step(1.), big + 1);
+    assert_eq!(u64::MAX.step(10.), u64::MAX);
+    assert_eq!(0u8.step(-1.), 0);
+    assert_eq!(i8::MIN.step(-1000.), i8::MIN);
+}
+
+#[test]
+fn decimal_filters_take_an_explicit_format() {
+    let french = decimal_for(&Locale::FR_FR);
+    assert!(french(','));
+    assert!(french('.'));
+    assert!(!french('a'));
+
+    // The default format of the thread only adds `.`.
+    assert!(!decimal()(','));
+}
@@ -0,0 +1,62 @@
+//! Tests of the widgets reacting to the width allotted to them, laid out with the headless harness.
+#![cfg(feature = "test-utils")]
+
+use iced::{Size, widget::Space};
+use more_iced_aw::{
+    harness::{Harness, Headless},
+    responsive::{SizeClass, adaptive},
+    tab_rail::{entry, tab_rail},
+};
+
+/// Lays out a widget in a window of the given width, and returns the size of its first child.
+fn child_size<'a>(
+    width: f32,
+    widget: impl Into<iced::Element<'a, (), iced::Theme, Headless>>,
+) -> Size {
+    let mut harness: Harness<()> = Harness::new().size(Size::new(width, 600.));
+    let ui = harness.mount(widget);
+    let layout = ui.layout();
+
+    layout
+        .children()
+        .next()
+        .map_or(layout.bounds().size(), |child| child.bounds().size())
+}
+
+#[test]
+fn adaptive_shows_the_alternative_of_its_width() {
+    let widget = || {
+        adaptive(Space::new(10., 10.))
+            .medium(Space::new(20., 20.))
+            .expanded(Space::new(30., 30.))
+    };
+
+    assert_eq!(child_size(400., widget()), Size::new(10., 10.));
+    assert_eq!(child_size(700., widget()), Size::new(20., 20.));
+    assert_eq!(child_size(1000., widget()), Size::new(30., 30.));
+}
+
+#[test]
+fn adaptive_falls_back_to_a_narrower_alternative() {
+    let widget = || adaptive(Space::new(10., 10.)).expanded(Space::new(30., 30.));
+
+    assert_eq!(child_size(700., widget()), Size::new(10., 10.));
+}
+
+#[test]
+fn tab_rail_collapses_below_its_class() {
+    let rail = || {
+        tab_rail(0, |_| ())
+            .push(entry(0, Space::new(16., 16.), "Home"))
+            .collapse_below(SizeClass::Expanded)
+    };
+
+    let width = |window: f32| {
+        let mut harness: Harness<()> = Harness::new().size(Size::new(window, 600.));
+        let ui = harness.mount(rail());
+        ui.layout().bounds().width
+    };
+
+    assert_eq!(width(1000.), 200.);
+    assert_eq!(width(700.), 40.);
+}
@@ -0,0 +1,191 @@
+//! Round trips through serde of the states of the widgets.
+#![cfg(feature = "serde")]
+
+use std::{fmt::Display, str::FromStr};
+
+use iced::{Color, Point, Vector};
+use more_iced_aw::{
+    chart::{Series, Slice},
+    curve_editor::{Curve, Segment},
+    draw_pad::Drawing,
+    file_browser,
+    gantt::Task,
+    grid::Axis,
+    log_view::{Level, LogBuffer},
+    mdi::{self, Arrangement, Placement, WindowState},
+    parsed_input::{Content, Parsed},
+    rich_combo_box,
+    rich_editor::{self, Action},
+    scheduler::Event,
+    viewport::Transform,
+};
+use serde::{Serialize, de::DeserializeOwned};
+
+/// Serializes and deserializes a value.
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+    let json = serde_json::to_string(value).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+/// An even number, whose parsing error can be serialized.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Even(u32);
+
+impl FromStr for Even {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<u32>() {
+            Ok(n) if n.is_multiple_of(2) => Ok(Even(n)),
+            _ => Err(format!("{s} is not even")),
+        }
+    }
+}
+
+impl Display for Even {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[test]
+fn parsed_input_content() {
+    let mut content = Content::<Even, String>::new(Even(4));
+    content.update(Parsed::from_string("5"));
+
+    let back = round_trip(&content);
+    assert_eq!(*back, Even(4));
+    assert_eq!(back.get_error(), content.get_error());
+    assert!(!back.is_valid());
+}
+
+#[test]
+fn grid_axis() {
+    assert_eq!(round_trip(&Axis::Horizontal), Axis::Horizontal);
+    assert_eq!(round_trip(&Axis::Vertical), Axis::Vertical);
+}
+
+#[test]
+fn mdi_content() {
+    let mut placement = Placement::new(2, 10., 20., 300., 200.);
+    placement.state = WindowState::Maximized;
+    let arrangement = Arrangement {
+        windows: vec![Placement::new(1, 0., 0., 200., 100.), placement],
+    };
+    let content = mdi::Content::new(arrangement.clone());
+
+    assert_eq!(round_trip(&content).arrangement(), arrangement);
+    assert_eq!(round_trip(&arrangement), arrangement);
+}
+
+#[test]
+fn drawing() {
+    let mut drawing = Drawing::new();
+    drawing.push(vec![Point::new(1., 2.), Point::new(3.5, 4.)]);
+    drawing.push(vec![Point::new(-1., 0.)]);
+
+    assert_eq!(round_trip(&drawing), drawing);
+}
+
+#[test]
+fn curve() {
+    let mut curve = Curve::new([Point::new(0., 0.), Point::new(1., 1.)]);
+    curve.insert(Point::new(0.5, 0.25));
+    curve.set_segment(0, Segment::ease(Point::new(0., 0.), Point::new(0.5, 0.25)));
+
+    let back = round_trip(&curve);
+    assert_eq!(back, curve);
+    assert_eq!(back.sample(0.75), curve.sample(0.75));
+}
+
+#[test]
+fn transform() {
+    let transform = Transform::new(Vector::new(12., -3.), 1.5);
+
+    assert_eq!(round_trip(&transform), transform);
+}
+
+#[test]
+fn chart_data() {
+    let series = Series {
+        name: "Sales".to_owned(),
+        values: vec![1., 2.5, 3.],
+        color: Some(Color::from_rgba(0.25, 0.5, 0.75, 1.)),
+    };
+    let slice = Slice {
+        label: "Other".to_owned(),
+        value: 4.,
+        color: None,
+    };
+
+    assert_eq!(round_trip(&series), series);
+    assert_eq!(round_trip(&slice), slice);
+}
+
+#[test]
+fn gantt_task() {
+    let task = Task {
+        name: "Build".to_owned(),
+        start: 2,
+        duration: 5,
+        dependencies: vec![0],
+        color: Some(Color::BLACK),
+    };
+
+    assert_eq!(round_trip(&task), task);
+}
+
+#[test]
+fn scheduler_event() {
+    let event = Event {
+        title: "Meeting".to_owned(),
+        day: 3,
+        start: 9 * 60,
+        duration: 45,
+        color: Some(Color::WHITE),
+    };
+
+    assert_eq!(round_trip(&event), event);
+}
+
+#[test]
+fn log_buffer() {
+    let mut buffer = LogBuffer::new(2);
+    buffer.push(Some(Level::Info), "started");
+    buffer.push(None, "working");
+    buffer.push(Some(Level::Error), "failed");
+
+    let back = round_trip(&buffer);
+    assert_eq!(back, buffer);
+    assert_eq!(back.total(), 3);
+}
+
+#[test]
+fn rich_editor_content() {
+    let mut content = rich_editor::Content::with_text("Hello\nWorld");
+    content.perform(Action::SelectAll);
+
+    let back = round_trip(&content);
+    assert_eq!(back, content);
+    assert_eq!(back.selected_text(), content.selected_text());
+}
+
+#[test]
+fn rich_combo_box_state() {
+    let state = rich_combo_box::State::new(vec!["apple".to_owned(), "banana".to_owned()]);
+
+    let back = round_trip(&state);
+    assert_eq!(back.options(), state.options());
+    assert_eq!(back.query(), state.query());
+}
+
+#[test]
+fn file_browser_content() {
+    let root = std::env::temp_dir();
+    let (content, _) = file_browser::Content::new(&root);
+
+    let back = round_trip(&content);
+    assert_eq!(back.root(), root);
+    assert_eq!(back.sort(), content.sort());
+    assert!(back.is_expanded(&root));
+}
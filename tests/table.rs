@@ -0,0 +1,69 @@
+//! Sorting of the rows of a table, driven with the headless harness.
+#![cfg(feature = "test-utils")]
+
+use std::{cell::Cell, rc::Rc};
+
+use iced::{Point, widget::Space};
+use more_iced_aw::{
+    harness::{Harness, Headless},
+    table::{Table, column},
+};
+
+/// A table sortable by the value of its rows, counting the keys it computes, and selecting a row
+/// with its index.
+fn table(rows: Vec<u32>, keys: &Rc<Cell<usize>>) -> Table<'_, u32, usize, iced::Theme, Headless> {
+    Table::new(
+        [
+            column("Value", |_: &u32| Space::new(10, 10).into()).sort_key(|&row| {
+                keys.set(keys.get() + 1);
+                row
+            }),
+        ],
+        rows,
+    )
+    .on_select(|index| index)
+}
+
+/// The center of the title of the column.
+const TITLE: Point = Point::new(10., 10.);
+
+/// Returns the center of the row at the given position in the display order.
+fn row(position: usize) -> Point {
+    Point::new(10., 36. + 32. * position as f32 + 16.)
+}
+
+#[test]
+fn the_order_is_only_sorted_again_when_the_sort_or_the_rows_change() {
+    let keys = Rc::new(Cell::new(0));
+    let mut harness = Harness::new();
+
+    let mut ui = harness.mount(table(vec![3, 1, 2], &keys));
+    ui.click(TITLE);
+    assert_eq!(keys.get(), 3);
+
+    ui.move_cursor(row(2));
+    let mut ui = harness.mount(table(vec![3, 1, 2], &keys));
+    ui.click(row(0));
+    assert_eq!(keys.get(), 3);
+    assert_eq!(ui.take_messages(), vec![1]);
+
+    let mut ui = harness.mount(table(vec![3, 1, 2, 0], &keys));
+    ui.click(row(0));
+    assert_eq!(keys.get(), 7);
+    assert_eq!(ui.take_messages(), vec![3]);
+}
+
+#[test]
+fn descending_sorts_keep_equal_rows_in_order() {
+    let keys = Rc::new(Cell::new(0));
+    let mut harness = Harness::new();
+
+    let mut ui = harness.mount(table(vec![2, 1, 2], &keys));
+    ui.click(TITLE);
+    ui.click(TITLE);
+
+    for position in 0..3 {
+        ui.click(row(position));
+    }
+    assert_eq!(ui.take_messages(), vec![0, 2, 1]);
+}
@@ -0,0 +1,115 @@
+//! Parsing of the output fed to a terminal buffer.
+
+use more_iced_aw::terminal_view::{AnsiColor, Attributes, TerminalBuffer};
+
+/// Returns the attributes of the characters of a line.
+fn attributes(buffer: &TerminalBuffer, line: usize) -> Vec<Attributes> {
+    buffer
+        .line(line)
+        .unwrap()
+        .iter()
+        .map(|cell| cell.attributes)
+        .collect()
+}
+
+#[test]
+fn graphic_renditions_set_and_reset_the_colors() {
+    let mut buffer = TerminalBuffer::new(10);
+    buffer.feed(
+        b"\x1b[1;31;42ma\x1b[38;5;200mb\x1b[48;2;1;2;3mc\x1b[39;49md\x1b[0me\x1b[93mf\x1b[mg",
+    );
+
+    let red = Some(AnsiColor::Indexed(1));
+    let green = Some(AnsiColor::Indexed(2));
+    let indexed = Some(AnsiColor::Indexed(200));
+    let rgb = Some(AnsiColor::Rgb(1, 2, 3));
+    let attributes_of = |foreground, background, bold| Attributes {
+        foreground,
+        background,
+        bold,
+    };
+
+    assert_eq!(buffer.text(), "abcdefg");
+    assert_eq!(
+        attributes(&buffer, 0),
+        vec![
+            attributes_of(red, green, true),
+            attributes_of(indexed, green, true),
+            attributes_of(indexed, rgb, true),
+            attributes_of(None, None, true),
+            Attributes::default(),
+            attributes_of(Some(AnsiColor::Indexed(11)), None, false),
+            Attributes::default(),
+        ]
+    );
+}
+
+#[test]
+fn sequences_can_be_split_across_feeds() {
+    let mut buffer = TerminalBuffer::new(10);
+    for byte in b"\x1b[1;3" {
+        buffer.feed(&[*byte]);
+    }
+    buffer.feed(b"1mred\x1b");
+    buffer.feed(b"[0m.");
+
+    assert_eq!(buffer.text(), "red.");
+    assert_eq!(
+        attributes(&buffer, 0)[0],
+        Attributes {
+            foreground: Some(AnsiColor::Indexed(1)),
+            background: None,
+            bold: true,
+        }
+    );
+    assert_eq!(attributes(&buffer, 0)[3], Attributes::default());
+}
+
+#[test]
+fn characters_can_be_split_across_feeds() {
+    let mut buffer = TerminalBuffer::new(10);
+    let text = "é€😀";
+    for byte in text.as_bytes() {
+        buffer.feed(&[*byte]);
+    }
+    assert_eq!(buffer.text(), text);
+
+    // A character interrupted by another one is replaced.
+    buffer.feed(&[b'\n', 0xe2, 0x82, b'a']);
+    assert_eq!(buffer.line(1).unwrap().len(), 2);
+    assert_eq!(buffer.text(), format!("{text}\n\u{fffd}a"));
+}
+
+#[test]
+fn cursor_moves_stay_in_bounds() {
+    let mut buffer = TerminalBuffer::new(10);
+    buffer.feed(b"abc\x1b[10Dx\x1b[2Cy\x1b[3Gz\rw");
+    assert_eq!(buffer.text(), "wbzy");
+
+    // Moves far to the right stop at the last column.
+    buffer.feed(b"\n\x1b[65535C\x1b[65535C\x1b[65535Cx");
+    assert_eq!(
+        buffer.line(1).unwrap().len(),
+        TerminalBuffer::MAX_COLUMN + 1
+    );
+    buffer.feed(b"\n\x1b[65535G\tx");
+    assert_eq!(
+        buffer.line(2).unwrap().len(),
+        TerminalBuffer::MAX_COLUMN + 1
+    );
+
+    // Longer lines can be moved in up to their end.
+    let long = "a".repeat(TerminalBuffer::MAX_COLUMN + 10);
+    buffer.feed(format!("\n{long}\x1b[20D\x1b[65535Cb").as_bytes());
+    assert_eq!(buffer.line(3).unwrap().len(), long.len() + 1);
+}
+
+#[test]
+fn the_oldest_lines_are_dropped_beyond_the_capacity() {
+    let mut buffer = TerminalBuffer::new(3);
+    buffer.feed(b"1\n2\n3\n4\n5");
+
+    assert_eq!(buffer.len(), 3);
+    assert_eq!(buffer.capacity(), 3);
+    assert_eq!(buffer.text(), "3\n4\n5");
+}
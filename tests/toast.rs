@@ -0,0 +1,41 @@
+//! Expiry of the toasts of a manager, driven with the headless harness.
+#![cfg(feature = "test-utils")]
+
+use std::time::{Duration, Instant};
+
+use iced::widget::Space;
+use more_iced_aw::{
+    harness::{Harness, Headless},
+    toast::{Manager, Toast, manager},
+};
+
+const DURATION: Duration = Duration::from_secs(10);
+
+/// The view of the tests: the toasts over an empty content, closed with their index.
+fn view(toasts: &[Toast<usize>]) -> Manager<'_, usize, Headless> {
+    manager(Space::new(100, 100), toasts, |index| index)
+}
+
+#[test]
+fn closing_a_toast_keeps_the_timers_of_the_next_ones() {
+    let sticky = Toast::new("Sticky", "").sticky(true);
+    let first = Toast::new("First", "").duration(DURATION);
+    let second = Toast::new("Second", "").duration(DURATION);
+    let mut harness = Harness::new();
+
+    harness.mount(view(&[sticky.clone(), first.clone()]));
+    let first_shown = Instant::now();
+    std::thread::sleep(Duration::from_millis(20));
+    harness.mount(view(&[sticky.clone(), first, second.clone()]));
+    let second_shown = Instant::now();
+
+    // The first toast is closed by the user.
+    let toasts = [sticky, second];
+
+    let mut ui = harness.mount(view(&toasts));
+    ui.redraw(first_shown + DURATION + Duration::from_millis(10));
+    assert!(ui.take_messages().is_empty());
+
+    ui.redraw(second_shown + DURATION);
+    assert_eq!(ui.take_messages(), vec![1]);
+}